@@ -1,35 +1,41 @@
+use crate::facts::FactKey;
 use crate::world_state::{FactValue, WorldState};
 
 /// Action template describes preconditions, effects and cost.
 #[derive(Clone, Debug)]
 pub struct ActionTemplate {
     pub name: String,
-    pub preconditions: Vec<(String, FactValue)>,
-    pub effects: Vec<(String, FactValue)>,
+    pub preconditions: Vec<(FactKey, FactValue)>,
+    pub effects: Vec<(FactKey, FactValue)>,
     pub cost: f32,
 }
 
 impl ActionTemplate {
     pub fn is_applicable(&self, state: &WorldState) -> bool {
-        self.preconditions
-            .iter()
-            .all(|(k, v)| state.satisfies(k, v))
+        self.preconditions.iter().all(|(k, v)| state.holds(k, v))
     }
 }
 
 /// Simple planning goal: a single fact key/value pair to be achieved.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Goal {
-    pub key: String,
+    pub key: FactKey,
     pub value: FactValue,
 }
 
+impl Goal {
+    /// Goal for a typed fact key.
+    pub fn new(key: FactKey, value: FactValue) -> Self {
+        Self { key, value }
+    }
+}
+
 /// Grounded (parameterized) action instance with concrete preconditions/effects.
 #[derive(Clone, Debug)]
 pub struct ActionInstance {
     pub name: String,
-    pub preconditions: Vec<(String, FactValue)>,
-    pub effects: Vec<(String, FactValue)>,
+    pub preconditions: Vec<(FactKey, FactValue)>,
+    pub effects: Vec<(FactKey, FactValue)>,
     pub cost: f32,
     /// Optional agent id that this action belongs to (for team-level planning)
     pub agent: Option<String>,
@@ -37,9 +43,7 @@ pub struct ActionInstance {
 
 impl ActionInstance {
     pub fn is_applicable(&self, state: &WorldState) -> bool {
-        self.preconditions
-            .iter()
-            .all(|(k, v)| state.satisfies(k, v))
+        self.preconditions.iter().all(|(k, v)| state.holds(k, v))
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::action::ActionInstance;
-use crate::world_state::{FactValue, HexCoord, WorldState};
+use crate::facts::{EnemyFact, FactKey};
+use crate::world_state::{FactValue, WorldState};
 
 #[derive(Clone, Debug)]
 pub struct AttackTemplate {
@@ -7,6 +8,10 @@ pub struct AttackTemplate {
     pub damage: i32,
     pub cost: f32,
     pub range: i32,
+    /// Damage the attacker expects to take back from the target's
+    /// counter-attack; 0 when the target cannot retaliate (e.g. a melee-only
+    /// target against a ranged attack). Added to the action cost.
+    pub retaliation_damage: i32,
 }
 
 impl AttackTemplate {
@@ -18,70 +23,28 @@ impl AttackTemplate {
         let mut out: Vec<ActionInstance> = Vec::new();
 
         for (k, v) in state.facts.iter() {
-            if k == "EnemyAt" || k.starts_with("EnemyAt:") {
-                // Support both string locations (legacy) and Hex coords
-                let enemy_loc_opt: Option<HexCoord> = match v {
-                    FactValue::Hex(h) => Some(*h),
-                    FactValue::Str(s) => {
-                        // Try parse "q,r" format for convenience, fall back to None
-                        if let Some((qstr, rstr)) = s.split_once(',') {
-                            if let (Ok(q), Ok(r)) =
-                                (qstr.trim().parse::<i32>(), rstr.trim().parse::<i32>())
-                            {
-                                Some(HexCoord { q, r })
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    }
-                    _ => None,
-                };
+            if let FactKey::Enemy(enemy_sym, EnemyFact::At) = k {
+                // Supports both Hex coords and legacy "q,r" string locations
+                if let Some(loc) = v.as_hex() {
+                    let enemy_id = enemy_sym.as_ref().map(|id| id.as_str().to_string());
+                    let alive_key = FactKey::Enemy(enemy_sym.clone(), EnemyFact::Alive);
+                    let health_key = FactKey::Enemy(enemy_sym.clone(), EnemyFact::Health);
 
-                if let Some(loc) = enemy_loc_opt {
-                    let enemy_id = if k == "EnemyAt" {
-                        None
-                    } else {
-                        k.split_once(':').map(|(_, id)| id.to_string())
-                    };
-
-                    let mut preconds: Vec<(String, FactValue)> = Vec::new();
-                    preconds.push(("At".to_string(), FactValue::Hex(loc)));
-                    if let Some(eid) = &enemy_id {
-                        let alive_key = format!("EnemyAlive:{}", eid);
-                        if let Some(FactValue::Bool(true)) = state.get(&alive_key) {
-                            preconds.push((alive_key.clone(), FactValue::Bool(true)));
-                        }
-                    } else if let Some(FactValue::Bool(true)) = state.get("EnemyAlive") {
-                        preconds.push(("EnemyAlive".to_string(), FactValue::Bool(true)));
+                    let mut preconds: Vec<(FactKey, FactValue)> = Vec::new();
+                    preconds.push((FactKey::custom("At"), FactValue::Hex(loc)));
+                    if state.holds(&alive_key, &FactValue::Bool(true)) {
+                        preconds.push((alive_key.clone(), FactValue::Bool(true)));
                     }
 
-                    let mut effects: Vec<(String, FactValue)> = Vec::new();
-                    if let Some(eid) = &enemy_id {
-                        let health_key = format!("EnemyHealth:{}", eid);
-                        if let Some(FactValue::Int(h)) = state.get(&health_key) {
-                            let new_h = (*h - self.damage).max(0);
-                            effects.push((health_key.clone(), FactValue::Int(new_h)));
-                            if new_h <= 0 {
-                                effects.push((
-                                    format!("EnemyAlive:{}", eid.clone()),
-                                    FactValue::Bool(false),
-                                ));
-                            }
-                        } else if state.get("EnemyAlive").is_some() || enemy_id.is_some() {
-                            let id_str = enemy_id.clone().unwrap_or_default();
-                            effects
-                                .push((format!("EnemyAlive:{}", id_str), FactValue::Bool(false)));
-                        }
-                    } else if let Some(FactValue::Int(h)) = state.get("EnemyHealth") {
+                    let mut effects: Vec<(FactKey, FactValue)> = Vec::new();
+                    if let Some(FactValue::Int(h)) = state.fact(&health_key) {
                         let new_h = (*h - self.damage).max(0);
-                        effects.push(("EnemyHealth".to_string(), FactValue::Int(new_h)));
+                        effects.push((health_key, FactValue::Int(new_h)));
                         if new_h <= 0 {
-                            effects.push(("EnemyAlive".to_string(), FactValue::Bool(false)));
+                            effects.push((alive_key.clone(), FactValue::Bool(false)));
                         }
-                    } else if state.get("EnemyAlive").is_some() {
-                        effects.push(("EnemyAlive".to_string(), FactValue::Bool(false)));
+                    } else if enemy_id.is_some() || state.fact(&alive_key).is_some() {
+                        effects.push((alive_key.clone(), FactValue::Bool(false)));
                     }
 
                     let name = if let Some(eid) = &enemy_id {
@@ -94,7 +57,7 @@ impl AttackTemplate {
                         name,
                         preconditions: preconds,
                         effects,
-                        cost: self.cost,
+                        cost: self.cost + self.retaliation_damage.max(0) as f32,
                         agent: agent.clone(),
                     };
                     out.push(instance);
@@ -63,7 +63,7 @@ impl ActionExecutor {
             match r {
                 RuntimeAction::Instant(ai) => {
                     // Apply effects and trigger completion callback
-                    world.apply_effects(&ai.effects);
+                    world.apply_fact_effects(&ai.effects);
                     if let Some(cb) = &self.on_complete {
                         cb(ai);
                     }
@@ -77,7 +77,7 @@ impl ActionExecutor {
                 } => {
                     *elapsed += dt;
                     if *elapsed >= *duration {
-                        world.apply_effects(&instance.effects);
+                        world.apply_fact_effects(&instance.effects);
                         if let Some(cb) = &self.on_complete {
                             cb(instance);
                         }
@@ -0,0 +1,352 @@
+//! Typed world-state fact keys.
+//!
+//! World-state facts used to be addressed by strings such as
+//! `"Unit:<uuid>:Health"`, which made every planner step format, hash and
+//! compare long strings and risked silent typos. [`FactKey`] is a small enum
+//! instead: unit ids and free-form names are interned [`Symbol`]s that carry
+//! their hash, so cloning a key never copies a string, hashing one never
+//! rehashes it and comparing two is a pointer check.
+//!
+//! [`Goal`](crate::Goal)s and actions store their keys as `FactKey`s, built
+//! once when the actions are grounded. Equal names share one interned string,
+//! so even keys built separately for the same fact compare by pointer.
+//!
+//! Every key still has a canonical string form (its [`Display`](fmt::Display)
+//! output), and [`FactKey::parse`] maps any string back to a key. Strings that
+//! do not match the schema become [`FactKey::Custom`], which keeps the old
+//! string-keyed [`WorldState`](crate::WorldState) API working unchanged.
+//!
+//! ## Schema
+//!
+//! | Key                          | String form                   |
+//! |------------------------------|-------------------------------|
+//! | [`FactKey::CurrentTeam`]     | `CurrentTeam`                 |
+//! | [`FactKey::Unit`]            | `Unit:<id>:<UnitFact>`        |
+//! | [`FactKey::Terrain`]         | `Terrain:<q>,<r>:<TerrainFact>` |
+//! | [`FactKey::Team`]            | `Team:<TeamFact>`             |
+//! | [`FactKey::Enemy`]           | `Enemy<EnemyFact>[:<id>]`     |
+//! | [`FactKey::Custom`]          | anything else                 |
+
+use crate::world_state::HexCoord;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Interned symbol strings with their hashes.
+static SYMBOLS: OnceLock<Mutex<HashMap<Arc<str>, u64>>> = OnceLock::new();
+
+/// An interned string (unit id, custom key name, ...).
+///
+/// Every name is stored once in a global table, so equal symbols share one
+/// allocation and compare by pointer, copying a key never copies its string,
+/// and the stored hash means hashing a key never rehashes it. Interned
+/// strings live until the program exits.
+#[derive(Clone)]
+pub struct Symbol {
+    hash: u64,
+    name: Arc<str>,
+}
+
+impl Symbol {
+    /// The symbol for `name`, interning the string on first use.
+    pub fn new(name: &str) -> Self {
+        // A panic while holding the lock cannot leave the table half-written
+        let mut symbols = SYMBOLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some((name, hash)) = symbols.get_key_value(name) {
+            return Self {
+                hash: *hash,
+                name: Arc::clone(name),
+            };
+        }
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        let symbol = Self {
+            hash: hasher.finish(),
+            name: Arc::from(name),
+        };
+        symbols.insert(Arc::clone(&symbol.name), symbol.hash);
+        symbol
+    }
+
+    /// The symbol's string.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.name, &other.name)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Declares a fieldless fact-kind enum with a fixed name per variant.
+macro_rules! fact_kind {
+    ($(#[$meta:meta])* $name:ident { $($(#[$vmeta:meta])* $variant:ident),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant),+
+        }
+
+        impl $name {
+            /// Every variant, in declaration order.
+            pub const ALL: &'static [$name] = &[$($name::$variant),+];
+
+            /// Name used in the string form of a key.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant)),+
+                }
+            }
+
+            /// Looks up a variant by its [`name`](Self::name).
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($variant) => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    };
+}
+
+fact_kind! {
+    /// Per-unit facts.
+    UnitFact {
+        /// Position (`Hex`)
+        At,
+        /// Whether the unit is alive (`Bool`)
+        Alive,
+        /// Owning team name (`Str`)
+        Team,
+        /// On the team the state was extracted for (`Bool`)
+        IsFriendly,
+        Health,
+        MaxHealth,
+        HealthPercent,
+        /// Below half health (`Bool`)
+        IsWounded,
+        MovesLeft,
+        MovementSpeed,
+        AttackPower,
+        AttackedThisTurn,
+        /// Longest attack range (`Int`)
+        AttackRange,
+        ThreatLevel,
+        Defense,
+        /// Allies within two hexes (`Int`)
+        NearbyAllies,
+        /// No allies within two hexes (`Bool`)
+        IsIsolated,
+        NearestEnemyDist,
+        EnemiesInRange,
+        NearbyEnemies,
+        NearbyPlayerUnits,
+        /// Position of the closest player unit (`Hex`)
+        ClosestPlayerUnit,
+        /// Engaged with an enemy (`Bool`)
+        InCombat,
+    }
+}
+
+fact_kind! {
+    /// Per-hex terrain facts.
+    TerrainFact {
+        /// Terrain id from the terrain registry (`Str`)
+        Type,
+        MoveCost,
+    }
+}
+
+fact_kind! {
+    /// Facts about the team the state was extracted for.
+    TeamFact {
+        AllyCount,
+        EnemyCount,
+        AverageHealth,
+    }
+}
+
+fact_kind! {
+    /// Facts about a (possibly anonymous) enemy, used by [`AttackTemplate`](crate::AttackTemplate).
+    EnemyFact {
+        At,
+        Alive,
+        Health,
+    }
+}
+
+/// A typed world-state fact key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FactKey {
+    /// Team whose turn the state describes
+    CurrentTeam,
+    /// A fact about the unit with the given id
+    Unit(Symbol, UnitFact),
+    /// A fact about the terrain at a hex
+    Terrain(HexCoord, TerrainFact),
+    /// A team-level metric
+    Team(TeamFact),
+    /// A fact about an enemy; `None` is the single-enemy prototype form
+    Enemy(Option<Symbol>, EnemyFact),
+    /// Any key outside the schema, stored by its full string
+    Custom(Symbol),
+}
+
+impl FactKey {
+    /// Key for a fact about unit `id`.
+    pub fn unit(id: impl AsRef<str>, fact: UnitFact) -> Self {
+        FactKey::Unit(Symbol::new(id.as_ref()), fact)
+    }
+
+    /// Key for a terrain fact at `hex`.
+    pub fn terrain(hex: HexCoord, fact: TerrainFact) -> Self {
+        FactKey::Terrain(hex, fact)
+    }
+
+    /// Key for a team-level metric.
+    pub fn team(fact: TeamFact) -> Self {
+        FactKey::Team(fact)
+    }
+
+    /// Key for a fact about enemy `id` (or the single prototype enemy).
+    pub fn enemy(id: Option<&str>, fact: EnemyFact) -> Self {
+        FactKey::Enemy(id.map(Symbol::new), fact)
+    }
+
+    /// Key outside the typed schema.
+    ///
+    /// Prefer [`FactKey::parse`] for strings that may match the schema, so the
+    /// same fact never ends up under two different keys.
+    pub fn custom(name: &str) -> Self {
+        FactKey::Custom(Symbol::new(name))
+    }
+
+    /// Parses the string form of a key.
+    ///
+    /// Never fails: strings outside the schema become [`FactKey::Custom`].
+    /// `FactKey::parse(s).to_string() == s` holds for every string.
+    pub fn parse(key: &str) -> Self {
+        Self::parse_typed(key).unwrap_or_else(|| FactKey::custom(key))
+    }
+
+    fn parse_typed(key: &str) -> Option<Self> {
+        if key == "CurrentTeam" {
+            return Some(FactKey::CurrentTeam);
+        }
+
+        if let Some(rest) = key.strip_prefix("Unit:") {
+            let (id, fact) = rest.rsplit_once(':')?;
+            if id.is_empty() || id.contains(':') {
+                return None;
+            }
+            return Some(FactKey::unit(id, UnitFact::from_name(fact)?));
+        }
+
+        if let Some(rest) = key.strip_prefix("Terrain:") {
+            return rest.split_once(':').and_then(|(coord, fact)| {
+                let (q, r) = coord.split_once(',')?;
+                let hex = HexCoord {
+                    q: q.parse().ok()?,
+                    r: r.parse().ok()?,
+                };
+                // Only canonical coordinates, so the string form round-trips.
+                if format!("{},{}", hex.q, hex.r) != coord {
+                    return None;
+                }
+                Some(FactKey::Terrain(hex, TerrainFact::from_name(fact)?))
+            });
+        }
+
+        if let Some(rest) = key.strip_prefix("Team:") {
+            return TeamFact::from_name(rest).map(FactKey::Team);
+        }
+
+        if let Some(rest) = key.strip_prefix("Enemy") {
+            let (fact, id) = match rest.split_once(':') {
+                Some((fact, id)) if !id.is_empty() => (fact, Some(id)),
+                Some(_) => return None,
+                None => (rest, None),
+            };
+            return Some(FactKey::enemy(id, EnemyFact::from_name(fact)?));
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for FactKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactKey::CurrentTeam => f.write_str("CurrentTeam"),
+            FactKey::Unit(id, fact) => write!(f, "Unit:{}:{}", id, fact),
+            FactKey::Terrain(hex, fact) => write!(f, "Terrain:{},{}:{}", hex.q, hex.r, fact),
+            FactKey::Team(fact) => write!(f, "Team:{}", fact),
+            FactKey::Enemy(None, fact) => write!(f, "Enemy{}", fact),
+            FactKey::Enemy(Some(id), fact) => write!(f, "Enemy{}:{}", fact, id),
+            FactKey::Custom(name) => f.write_str(name.as_str()),
+        }
+    }
+}
+
+impl From<&str> for FactKey {
+    fn from(key: &str) -> Self {
+        FactKey::parse(key)
+    }
+}
+
+impl From<String> for FactKey {
+    fn from(key: String) -> Self {
+        FactKey::parse(&key)
+    }
+}
+
+impl From<&String> for FactKey {
+    fn from(key: &String) -> Self {
+        FactKey::parse(key)
+    }
+}
@@ -22,6 +22,7 @@
 //! ```
 
 use crate::action::Goal;
+use crate::facts::{FactKey, UnitFact};
 use crate::world_state::{FactValue, HexCoord, WorldState};
 
 // ============================================================================
@@ -197,14 +198,8 @@ impl ScenarioGoal {
                 destination,
             } => {
                 // Check if escort unit reached destination
-                let pos_key = format!("Unit:{}:At", unit_id);
-                if let Some(FactValue::Str(pos)) = state.get(&pos_key) {
-                    if let Some((q, r)) = parse_position(pos) {
-                        let current = HexCoord { q, r };
-                        return current.distance(*destination) == 0;
-                    }
-                }
-                false
+                unit_position(state, unit_id)
+                    .is_some_and(|current| current.distance(*destination) == 0)
             }
 
             ScenarioGoal::CaptureObjectives { objectives } => {
@@ -890,29 +885,16 @@ impl LongTermGoal {
 // ============================================================================
 
 fn decompose_reach_position(state: &WorldState, unit_id: &str, target: HexCoord) -> Option<Goal> {
-    let pos_key = format!("Unit:{}:At", unit_id);
-    if let Some(FactValue::Str(pos_str)) = state.get(&pos_key) {
-        if let Some((q_str, r_str)) = pos_str.split_once(',') {
-            if let (Ok(q), Ok(r)) = (q_str.parse::<i32>(), r_str.parse::<i32>()) {
-                let current = HexCoord { q, r };
-                let distance = current.distance(target);
-
-                if distance <= 3 {
-                    return Some(Goal {
-                        key: pos_key,
-                        value: FactValue::Str(format!("{},{}", target.q, target.r)),
-                    });
-                } else {
-                    let intermediate = calculate_intermediate_position(current, target);
-                    return Some(Goal {
-                        key: pos_key,
-                        value: FactValue::Str(format!("{},{}", intermediate.q, intermediate.r)),
-                    });
-                }
-            }
-        }
-    }
-    None
+    let current = unit_position(state, unit_id)?;
+    let destination = if current.distance(target) <= 3 {
+        target
+    } else {
+        calculate_intermediate_position(current, target)
+    };
+    Some(Goal::new(
+        FactKey::unit(unit_id, UnitFact::At),
+        FactValue::Hex(destination),
+    ))
 }
 
 fn decompose_kill_all_enemies(
@@ -920,13 +902,11 @@ fn decompose_kill_all_enemies(
     unit_id: &str,
     _search_radius: Option<i32>,
 ) -> Option<Goal> {
-    let enemies_key = format!("Unit:{}:NearbyEnemies", unit_id);
-    if let Some(FactValue::Int(enemy_count)) = state.get(&enemies_key) {
+    if let Some(FactValue::Int(enemy_count)) =
+        state.fact(&FactKey::unit(unit_id, UnitFact::NearbyEnemies))
+    {
         if *enemy_count > 0 {
-            return Some(Goal {
-                key: format!("Unit:{}:InCombat", unit_id),
-                value: FactValue::Bool(true),
-            });
+            return Some(in_combat_goal(unit_id));
         }
     }
     None
@@ -938,26 +918,19 @@ fn decompose_kill_player_units(
     _search_radius: Option<i32>,
 ) -> Option<Goal> {
     // Check for nearby player-controlled units
-    let player_units_key = format!("Unit:{}:NearbyPlayerUnits", unit_id);
-    if let Some(FactValue::Int(player_count)) = state.get(&player_units_key) {
+    if let Some(FactValue::Int(player_count)) =
+        state.fact(&FactKey::unit(unit_id, UnitFact::NearbyPlayerUnits))
+    {
         if *player_count > 0 {
-            return Some(Goal {
-                key: format!("Unit:{}:InCombat", unit_id),
-                value: FactValue::Bool(true),
-            });
+            return Some(in_combat_goal(unit_id));
         }
     }
 
     // Fallback: check for closest player unit position and move toward it
-    let closest_player_key = format!("Unit:{}:ClosestPlayerUnit", unit_id);
-    if let Some(FactValue::Str(player_pos)) = state.get(&closest_player_key) {
-        if let Some((q, r)) = parse_position(player_pos) {
-            let target = HexCoord { q, r };
-            return decompose_reach_position(state, unit_id, target);
-        }
-    }
-
-    None
+    let target = state
+        .fact(&FactKey::unit(unit_id, UnitFact::ClosestPlayerUnit))
+        .and_then(FactValue::as_hex)?;
+    decompose_reach_position(state, unit_id, target)
 }
 
 fn decompose_protect(state: &WorldState, unit_id: &str, targets: &[HexCoord]) -> Option<Goal> {
@@ -966,45 +939,34 @@ fn decompose_protect(state: &WorldState, unit_id: &str, targets: &[HexCoord]) ->
     }
 
     // Find closest target position to move toward
-    let pos_key = format!("Unit:{}:At", unit_id);
-    if let Some(FactValue::Str(pos_str)) = state.get(&pos_key) {
-        if let Some((q, r)) = parse_position(pos_str) {
-            let current = HexCoord { q, r };
-
-            // Find closest target
-            let closest = targets.iter().min_by_key(|t| current.distance(**t))?;
-
-            let distance = current.distance(*closest);
+    let current = unit_position(state, unit_id)?;
+    let closest = targets.iter().min_by_key(|t| current.distance(**t))?;
 
-            // If not at any target, move to closest
-            if distance > 0 {
-                return decompose_reach_position(state, unit_id, *closest);
-            }
-
-            // At a target - check for enemies and engage if present
-            let enemies_key = format!("Unit:{}:NearbyEnemies", unit_id);
-            if let Some(FactValue::Int(enemy_count)) = state.get(&enemies_key) {
-                if *enemy_count > 0 {
-                    return Some(Goal {
-                        key: format!("Unit:{}:InCombat", unit_id),
-                        value: FactValue::Bool(true),
-                    });
-                }
-            }
+    // If not at any target, move to closest
+    if current.distance(*closest) > 0 {
+        return decompose_reach_position(state, unit_id, *closest);
+    }
 
-            // Hold position
-            return Some(Goal {
-                key: pos_key,
-                value: FactValue::Str(pos_str.to_string()),
-            });
+    // At a target - check for enemies and engage if present
+    if let Some(FactValue::Int(enemy_count)) =
+        state.fact(&FactKey::unit(unit_id, UnitFact::NearbyEnemies))
+    {
+        if *enemy_count > 0 {
+            return Some(in_combat_goal(unit_id));
         }
     }
-    None
+
+    // Hold position
+    Some(Goal::new(
+        FactKey::unit(unit_id, UnitFact::At),
+        FactValue::Hex(current),
+    ))
 }
 
 fn are_all_enemies_dead(state: &WorldState, unit_id: &str, _search_radius: Option<i32>) -> bool {
-    let enemies_key = format!("Unit:{}:NearbyEnemies", unit_id);
-    if let Some(FactValue::Int(enemy_count)) = state.get(&enemies_key) {
+    if let Some(FactValue::Int(enemy_count)) =
+        state.fact(&FactKey::unit(unit_id, UnitFact::NearbyEnemies))
+    {
         return *enemy_count == 0;
     }
     true
@@ -1024,15 +986,9 @@ fn is_protecting_area(state: &WorldState, unit_id: &str, targets: &[HexCoord]) -
         return false;
     }
 
-    let pos_key = format!("Unit:{}:At", unit_id);
-    if let Some(FactValue::Str(pos_str)) = state.get(&pos_key) {
-        if let Some((q, r)) = parse_position(pos_str) {
-            let current = HexCoord { q, r };
-            // Check if at any of the target positions
-            return targets.iter().any(|t| current.distance(*t) == 0);
-        }
-    }
-    false
+    // Check if at any of the target positions
+    unit_position(state, unit_id)
+        .is_some_and(|current| targets.iter().any(|t| current.distance(*t) == 0))
 }
 
 fn decompose_reach_area(
@@ -1045,65 +1001,43 @@ fn decompose_reach_area(
     }
 
     // Find closest area center
-    let pos_key = format!("Unit:{}:At", unit_id);
-    if let Some(FactValue::Str(pos_str)) = state.get(&pos_key) {
-        if let Some((q, r)) = parse_position(pos_str) {
-            let current = HexCoord { q, r };
+    let current = unit_position(state, unit_id)?;
+    let closest = area_centers
+        .iter()
+        .min_by_key(|center| current.distance(**center))?;
 
-            let closest = area_centers
-                .iter()
-                .min_by_key(|center| current.distance(**center))?;
-
-            // Move toward closest area center
-            return decompose_reach_position(state, unit_id, *closest);
-        }
-    }
-    None
+    // Move toward closest area center
+    decompose_reach_position(state, unit_id, *closest)
 }
 
 fn decompose_siege_castle(state: &WorldState, unit_id: &str, castle_id: &str) -> Option<Goal> {
     // Get castle position
-    let castle_pos_key = format!("Castle:{}:At", castle_id);
-    if let Some(FactValue::Str(castle_pos)) = state.get(&castle_pos_key) {
-        if let Some((cq, cr)) = parse_position(castle_pos) {
-            let castle_coord = HexCoord { q: cq, r: cr };
-
-            let pos_key = format!("Unit:{}:At", unit_id);
-            if let Some(FactValue::Str(unit_pos)) = state.get(&pos_key) {
-                if let Some((uq, ur)) = parse_position(unit_pos) {
-                    let current = HexCoord { q: uq, r: ur };
-                    let distance = current.distance(castle_coord);
-
-                    // Check if castle is already under siege
-                    let siege_key = format!("Castle:{}:UnderSiege", castle_id);
-                    let under_siege = state.get(&siege_key) == Some(&FactValue::Bool(true));
-
-                    if distance <= 2 && !under_siege {
-                        // Adjacent to castle - begin siege
-                        return Some(Goal {
-                            key: siege_key,
-                            value: FactValue::Bool(true),
-                        });
-                    } else if under_siege {
-                        // Castle under siege - attack it
-                        let castle_hp_key = format!("Castle:{}:HP", castle_id);
-                        if let Some(FactValue::Int(hp)) = state.get(&castle_hp_key) {
-                            if *hp > 0 {
-                                return Some(Goal {
-                                    key: castle_hp_key,
-                                    value: FactValue::Int(0),
-                                });
-                            }
-                        }
-                    } else {
-                        // Too far - move closer
-                        return decompose_reach_position(state, unit_id, castle_coord);
-                    }
-                }
+    let castle_coord = state
+        .get(&format!("Castle:{}:At", castle_id))
+        .and_then(FactValue::as_hex)?;
+    let current = unit_position(state, unit_id)?;
+    let distance = current.distance(castle_coord);
+
+    // Check if castle is already under siege
+    let siege_key = format!("Castle:{}:UnderSiege", castle_id);
+    let under_siege = state.get(&siege_key) == Some(&FactValue::Bool(true));
+
+    if distance <= 2 && !under_siege {
+        // Adjacent to castle - begin siege
+        Some(Goal::new(FactKey::parse(&siege_key), FactValue::Bool(true)))
+    } else if under_siege {
+        // Castle under siege - attack it
+        let castle_hp_key = format!("Castle:{}:HP", castle_id);
+        match state.get(&castle_hp_key) {
+            Some(FactValue::Int(hp)) if *hp > 0 => {
+                Some(Goal::new(FactKey::parse(&castle_hp_key), FactValue::Int(0)))
             }
+            _ => None,
         }
+    } else {
+        // Too far - move closer
+        decompose_reach_position(state, unit_id, castle_coord)
     }
-    None
 }
 
 fn is_in_area(state: &WorldState, unit_id: &str, area_centers: &[HexCoord]) -> bool {
@@ -1111,17 +1045,12 @@ fn is_in_area(state: &WorldState, unit_id: &str, area_centers: &[HexCoord]) -> b
         return false;
     }
 
-    let pos_key = format!("Unit:{}:At", unit_id);
-    if let Some(FactValue::Str(pos_str)) = state.get(&pos_key) {
-        if let Some((q, r)) = parse_position(pos_str) {
-            let current = HexCoord { q, r };
-            // Check if within reasonable distance of any area center (e.g., 3 hexes)
-            return area_centers
-                .iter()
-                .any(|center| current.distance(*center) <= 3);
-        }
-    }
-    false
+    // Check if within reasonable distance of any area center (e.g., 3 hexes)
+    unit_position(state, unit_id).is_some_and(|current| {
+        area_centers
+            .iter()
+            .any(|center| current.distance(*center) <= 3)
+    })
 }
 
 fn is_castle_captured(state: &WorldState, castle_id: &str) -> bool {
@@ -1150,13 +1079,16 @@ fn calculate_intermediate_position(current: HexCoord, target: HexCoord) -> HexCo
     }
 }
 
-fn parse_position(pos: &str) -> Option<(i32, i32)> {
-    let parts: Vec<&str> = pos.split(',').collect();
-    if parts.len() == 2 {
-        let q = parts[0].parse().ok()?;
-        let r = parts[1].parse().ok()?;
-        Some((q, r))
-    } else {
-        None
-    }
+/// Current position of a unit (`Hex`, or a legacy `"q,r"` string).
+fn unit_position(state: &WorldState, unit_id: &str) -> Option<HexCoord> {
+    state
+        .fact(&FactKey::unit(unit_id, UnitFact::At))
+        .and_then(FactValue::as_hex)
+}
+
+fn in_combat_goal(unit_id: &str) -> Goal {
+    Goal::new(
+        FactKey::unit(unit_id, UnitFact::InCombat),
+        FactValue::Bool(true),
+    )
 }
@@ -20,17 +20,23 @@
 //! ## Features
 //!
 //! This crate provides a minimal GOAP planner with:
-//! - Simple WorldState model for representing game state
+//! - WorldState model keyed by typed facts (`facts`), with a
+//!   string-keyed compatibility API
 //! - Action templates/instances for possible actions
 //! - Forward A* planner with bounded search
 //! - Team-based planning for coordinated AI behavior
+//! - Optional tactical expectimax search over cloned game states (`search`)
+//! - Structured per-turn decision traces exportable as JSON (`trace`)
 
 // Crate root: small re-exporting module to hold AI building blocks split across files.
 pub mod action;
 pub mod actions;
 pub mod executor;
+pub mod facts;
 pub mod goals;
 pub mod planner;
+pub mod search;
+pub mod trace;
 pub mod world_state;
 
 pub use action::ground_action_from_template;
@@ -38,10 +44,13 @@ pub use action::{ActionInstance, ActionTemplate, Goal};
 pub use actions::move_template;
 pub use actions::AttackTemplate;
 pub use executor::{ActionExecutor, RuntimeAction};
+pub use facts::{EnemyFact, FactKey, Symbol, TeamFact, TerrainFact, UnitFact};
 pub use goals::LongTermGoal;
 pub use goals::ScenarioGoal;
 pub use goals::Strategy;
 pub use planner::plan_for_team;
-pub use planner::{plan, plan_instances, Plan};
+pub use planner::{plan, plan_for_team_traced, plan_instances, plan_instances_counted, Plan};
+pub use search::{expectimax, SearchBudget, SearchResult, TacticalState};
+pub use trace::{AgentTrace, AiTrace, CandidateAction, TracedCommand, TRACE_TARGET};
 pub use world_state::HexCoord;
 pub use world_state::{FactValue, WorldState};
@@ -1,4 +1,6 @@
 use crate::action::{ActionInstance, ActionTemplate, Goal};
+use crate::facts::FactKey;
+use crate::trace::AiTrace;
 use crate::world_state::{FactValue, WorldState};
 use std::collections::{BinaryHeap, HashMap};
 
@@ -45,9 +47,9 @@ fn heuristic(_state: &WorldState, _goal: &Goal, _actions: &[ActionInstance]) ->
     0.0
 }
 
-fn state_key(state: &WorldState) -> Vec<(String, FactValue)> {
-    let mut v: Vec<(String, FactValue)> = state.facts.clone().into_iter().collect();
-    v.sort_by(|a, b| a.0.cmp(&b.0));
+fn state_key(state: &WorldState) -> Vec<(FactKey, FactValue)> {
+    let mut v: Vec<(FactKey, FactValue)> = state.facts.clone().into_iter().collect();
+    v.sort_by(|(a, _), (b, _)| a.cmp(b));
     v
 }
 
@@ -58,6 +60,16 @@ pub fn plan_instances(
     goal: &Goal,
     max_nodes: usize,
 ) -> Option<Plan> {
+    plan_instances_counted(start, actions, goal, max_nodes).0
+}
+
+/// Same as [`plan_instances`], but also returns the number of nodes expanded.
+pub fn plan_instances_counted(
+    start: &WorldState,
+    actions: &[ActionInstance],
+    goal: &Goal,
+    max_nodes: usize,
+) -> (Option<Plan>, usize) {
     let mut open = BinaryHeap::new();
     let start_h = heuristic(start, goal, actions);
     open.push(SearchNode {
@@ -67,26 +79,27 @@ pub fn plan_instances(
         actions: Vec::new(),
     });
 
-    let mut best_g: HashMap<Vec<(String, FactValue)>, f32> = HashMap::new();
+    let mut best_g: HashMap<Vec<(FactKey, FactValue)>, f32> = HashMap::new();
     let start_key = state_key(start);
     best_g.insert(start_key, 0.0);
 
+    // Every popped node counts as expanded, up to the budget
     let mut nodes = 0usize;
-    while let Some(node) = open.pop() {
-        nodes += 1;
-        if nodes > max_nodes {
+    while nodes < max_nodes {
+        let Some(node) = open.pop() else {
             break;
-        }
+        };
+        nodes += 1;
 
-        if node.state.satisfies(&goal.key, &goal.value) {
-            return Some(node.actions);
+        if node.state.holds(&goal.key, &goal.value) {
+            return (Some(node.actions), nodes);
         }
 
         // For each applicable action, expand
         for (i, a) in actions.iter().enumerate() {
             if a.is_applicable(&node.state) {
                 let mut new_state = node.state.clone();
-                new_state.apply_effects(&a.effects);
+                new_state.apply_fact_effects(&a.effects);
                 let g2 = node.g + a.cost;
                 let key = state_key(&new_state);
                 let recorded = best_g.get(&key).cloned();
@@ -106,7 +119,7 @@ pub fn plan_instances(
         }
     }
 
-    None
+    (None, nodes)
 }
 
 pub fn plan(
@@ -132,6 +145,44 @@ pub fn plan_for_team(
     goals_per_agent: &HashMap<String, Vec<Goal>>,
     agent_order: &[String],
     max_nodes_per_agent: usize,
+) -> HashMap<String, Plan> {
+    plan_for_team_inner(
+        start,
+        actions,
+        goals_per_agent,
+        agent_order,
+        max_nodes_per_agent,
+        None,
+    )
+}
+
+/// Same as [`plan_for_team`], recording goals, plans and node counts per agent
+/// into `trace`.
+pub fn plan_for_team_traced(
+    start: &WorldState,
+    actions: &[ActionInstance],
+    goals_per_agent: &HashMap<String, Vec<Goal>>,
+    agent_order: &[String],
+    max_nodes_per_agent: usize,
+    trace: &mut AiTrace,
+) -> HashMap<String, Plan> {
+    plan_for_team_inner(
+        start,
+        actions,
+        goals_per_agent,
+        agent_order,
+        max_nodes_per_agent,
+        Some(trace),
+    )
+}
+
+fn plan_for_team_inner(
+    start: &WorldState,
+    actions: &[ActionInstance],
+    goals_per_agent: &HashMap<String, Vec<Goal>>,
+    agent_order: &[String],
+    max_nodes_per_agent: usize,
+    mut trace: Option<&mut AiTrace>,
 ) -> HashMap<String, Plan> {
     let mut result: HashMap<String, Plan> = HashMap::new();
     let mut current_state = start.clone();
@@ -149,20 +200,37 @@ pub fn plan_for_team(
 
         if let Some(goals) = goals_per_agent.get(agent) {
             let mut chosen: Option<Plan> = None;
+            let mut nodes_expanded = 0;
             for g in goals {
-                if let Some(p) =
-                    plan_instances(&current_state, &agent_actions, g, max_nodes_per_agent)
-                {
+                let (found, nodes) =
+                    plan_instances_counted(&current_state, &agent_actions, g, max_nodes_per_agent);
+                nodes_expanded += nodes;
+                if let Some(p) = found {
                     chosen = Some(p);
                     break;
                 }
             }
 
+            if let Some(t) = trace.as_deref_mut() {
+                let entry = t.agent_mut(agent);
+                entry.goals = goals
+                    .iter()
+                    .map(|g| format!("{} = {:?}", g.key, g.value))
+                    .collect();
+                entry.nodes_expanded = nodes_expanded;
+                entry.plan_found = chosen.is_some();
+                entry.plan = chosen
+                    .iter()
+                    .flatten()
+                    .map(|&idx| agent_actions[idx].name.clone())
+                    .collect();
+            }
+
             if let Some(plan) = chosen {
                 // apply plan effects to current_state and record
                 for &idx in &plan {
                     let a = &agent_actions[idx];
-                    current_state.apply_effects(&a.effects);
+                    current_state.apply_fact_effects(&a.effects);
                 }
                 result.insert(agent.clone(), plan);
             } else {
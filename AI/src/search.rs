@@ -0,0 +1,230 @@
+//! Tactical search over cloned game states.
+//!
+//! GOAP plans over abstract facts and cannot reason about dice rolls or how the
+//! opponent will respond. This module provides a depth-limited **expectimax**
+//! search that works directly on copies of the real game state:
+//!
+//! - **Max nodes**: the searching side picks its best command
+//! - **Min nodes**: any side not friendly to us picks the command that is
+//!   worst for us; allied sides are treated as max nodes
+//! - **Chance nodes**: every command expands into weighted outcomes (e.g. an
+//!   attack that hits or misses), whose values are averaged by probability
+//!
+//! The game implements [`TacticalState`]; the AI crate never sees game types.
+//! Search is bounded by a [`SearchBudget`] (depth, node count and wall-clock
+//! time) and uses iterative deepening so a usable answer is always available
+//! when the budget runs out.
+
+use std::time::{Duration, Instant};
+
+/// A game state that can be explored by [`expectimax`].
+///
+/// Implementors are expected to be cheap to clone: every expanded node owns a
+/// full copy of the state.
+pub trait TacticalState: Clone {
+    /// A concrete command a side can issue (move, attack, end turn, ...).
+    type Command: Clone + std::fmt::Debug;
+    /// Identifier of a side (team) taking turns.
+    type Side: Clone + PartialEq + std::fmt::Debug;
+
+    /// Side whose turn it is in this state.
+    fn current_side(&self) -> Self::Side;
+
+    /// All commands the current side may legally issue.
+    ///
+    /// Returning commands in a stable order keeps search results reproducible;
+    /// putting the most promising commands first improves pruning by budget.
+    fn legal_commands(&self) -> Vec<Self::Command>;
+
+    /// Applies a command and returns every possible resulting state together
+    /// with its probability. Deterministic commands return a single outcome
+    /// with probability `1.0`; probabilities should sum to `1.0`.
+    fn apply(&self, command: &Self::Command) -> Vec<(f32, Self)>;
+
+    /// Whether `side` plays for `root`: its turns are searched as max nodes.
+    ///
+    /// Defaults to `side == root`; games with alliances override this so that
+    /// allied turns are not assumed to play against the searching side.
+    fn is_friendly(&self, side: &Self::Side, root: &Self::Side) -> bool {
+        side == root
+    }
+
+    /// Static evaluation of the state from `side`'s point of view (higher is better).
+    fn evaluate(&self, side: &Self::Side) -> f32;
+
+    /// Returns true when the game is over and no further commands make sense.
+    fn is_terminal(&self) -> bool;
+}
+
+/// Limits applied to a single search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchBudget {
+    /// Maximum number of plies (commands) to look ahead.
+    pub max_depth: usize,
+    /// Maximum number of states expanded across all iterations.
+    pub max_nodes: usize,
+    /// Optional wall-clock limit for the whole search.
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_nodes: 20_000,
+            time_limit: Some(Duration::from_millis(250)),
+        }
+    }
+}
+
+impl SearchBudget {
+    /// Budget bounded only by depth and node count (deterministic results).
+    pub fn nodes(max_depth: usize, max_nodes: usize) -> Self {
+        Self {
+            max_depth,
+            max_nodes,
+            time_limit: None,
+        }
+    }
+}
+
+/// Result of a tactical search.
+#[derive(Clone, Debug)]
+pub struct SearchResult<C> {
+    /// Best command for the side to move, or `None` if it had no legal command.
+    pub best_command: Option<C>,
+    /// Expected value of `best_command` from the searching side's perspective.
+    pub value: f32,
+    /// Number of states expanded.
+    pub nodes: usize,
+    /// Deepest fully completed iteration.
+    pub depth_reached: usize,
+}
+
+struct SearchContext<'a, S: TacticalState> {
+    root_side: S::Side,
+    budget: &'a SearchBudget,
+    started: Instant,
+    nodes: usize,
+    aborted: bool,
+}
+
+impl<S: TacticalState> SearchContext<'_, S> {
+    fn out_of_budget(&mut self) -> bool {
+        if self.nodes >= self.budget.max_nodes {
+            self.aborted = true;
+        } else if let Some(limit) = self.budget.time_limit {
+            if self.started.elapsed() >= limit {
+                self.aborted = true;
+            }
+        }
+        self.aborted
+    }
+}
+
+/// Runs an iterative-deepening expectimax search from `root`.
+///
+/// Each iteration searches one ply deeper than the previous one. If the budget
+/// runs out mid-iteration, the result of the last completed iteration is kept
+/// (or the partial first iteration when nothing has completed yet).
+pub fn expectimax<S: TacticalState>(root: &S, budget: &SearchBudget) -> SearchResult<S::Command> {
+    let mut ctx = SearchContext::<S> {
+        root_side: root.current_side(),
+        budget,
+        started: Instant::now(),
+        nodes: 0,
+        aborted: false,
+    };
+
+    let commands = root.legal_commands();
+    let mut result = SearchResult {
+        best_command: None,
+        value: root.evaluate(&ctx.root_side),
+        nodes: 0,
+        depth_reached: 0,
+    };
+    if commands.is_empty() || root.is_terminal() {
+        return result;
+    }
+
+    for depth in 1..=budget.max_depth.max(1) {
+        let mut best: Option<(usize, f32)> = None;
+
+        for (index, command) in commands.iter().enumerate() {
+            let value = chance_value(root, command, depth - 1, &mut ctx);
+            if ctx.aborted && best.is_some() {
+                break;
+            }
+            if best.is_none_or(|(_, v)| value > v) {
+                best = Some((index, value));
+            }
+        }
+
+        // A partial iteration is only trusted when no complete one exists yet.
+        if let Some((index, value)) = best {
+            if !ctx.aborted || result.best_command.is_none() {
+                result.best_command = Some(commands[index].clone());
+                result.value = value;
+                if !ctx.aborted {
+                    result.depth_reached = depth;
+                }
+            }
+        }
+
+        if ctx.aborted {
+            break;
+        }
+    }
+
+    result.nodes = ctx.nodes;
+    result
+}
+
+/// Expected value of applying `command` in `state` (chance node).
+fn chance_value<S: TacticalState>(
+    state: &S,
+    command: &S::Command,
+    depth: usize,
+    ctx: &mut SearchContext<S>,
+) -> f32 {
+    let outcomes = state.apply(command);
+    let total_probability: f32 = outcomes.iter().map(|(p, _)| *p).sum();
+    if outcomes.is_empty() || total_probability <= 0.0 {
+        return state.evaluate(&ctx.root_side);
+    }
+
+    let mut expected = 0.0;
+    for (probability, next) in &outcomes {
+        ctx.nodes += 1;
+        expected += probability * decision_value(next, depth, ctx);
+    }
+    expected / total_probability
+}
+
+/// Value of `state` when the side to move picks a command (max or min node).
+fn decision_value<S: TacticalState>(state: &S, depth: usize, ctx: &mut SearchContext<S>) -> f32 {
+    if depth == 0 || state.is_terminal() || ctx.out_of_budget() {
+        return state.evaluate(&ctx.root_side);
+    }
+
+    let commands = state.legal_commands();
+    if commands.is_empty() {
+        return state.evaluate(&ctx.root_side);
+    }
+
+    let maximizing = state.is_friendly(&state.current_side(), &ctx.root_side);
+    let mut best: Option<f32> = None;
+    for command in &commands {
+        let value = chance_value(state, command, depth - 1, ctx);
+        best = Some(match best {
+            None => value,
+            Some(b) if maximizing => b.max(value),
+            Some(b) => b.min(value),
+        });
+        if ctx.aborted {
+            break;
+        }
+    }
+
+    best.unwrap_or_else(|| state.evaluate(&ctx.root_side))
+}
@@ -0,0 +1,193 @@
+//! Structured AI decision trace.
+//!
+//! An [`AiTrace`] records everything the AI considered during one team turn:
+//! the facts it extracted, the candidate actions and their costs, each agent's
+//! goals and plan (or the lack of one), planner node counts, any fallback that
+//! was used and the commands that were finally executed.
+//!
+//! Traces are plain data. The game decides when to collect them; log output
+//! uses the [`TRACE_TARGET`] target so it can be filtered independently of
+//! other game logs, and [`AiTrace::to_json`] exports a trace for offline review.
+
+use crate::world_state::HexCoord;
+use serde::{Deserialize, Serialize};
+
+/// `log` target used for AI trace output.
+pub const TRACE_TARGET: &str = "ai::trace";
+
+/// A grounded action that was available to the planner.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CandidateAction {
+    pub name: String,
+    pub agent: Option<String>,
+    pub cost: f32,
+}
+
+/// What one agent (unit) wanted and what the planner produced for it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentTrace {
+    /// Agent id (unit UUID as a string)
+    pub agent: String,
+    /// Human-readable unit name
+    pub name: String,
+    /// Goals tried, in priority order (`key = value`)
+    pub goals: Vec<String>,
+    /// Names of the planned actions; empty when no plan was found
+    pub plan: Vec<String>,
+    /// Whether the planner found a plan for any goal
+    pub plan_found: bool,
+    /// Planner nodes expanded for this agent
+    pub nodes_expanded: usize,
+    /// Description of the fallback behaviour used, if any
+    pub fallback: Option<String>,
+    /// Hexes the agent intends to walk through (starting position first)
+    pub path: Vec<HexCoord>,
+    /// Hex of the agent's intended target, if any
+    pub target: Option<HexCoord>,
+}
+
+/// A command that was executed on the real game state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TracedCommand {
+    pub agent: Option<String>,
+    pub command: String,
+    pub succeeded: bool,
+    /// Error message when the command failed
+    pub error: Option<String>,
+}
+
+/// Complete record of one AI team turn.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiTrace {
+    /// Team that took the turn
+    pub team: String,
+    /// Turn number the trace was recorded on
+    pub turn: u32,
+    /// Extracted world-state facts as sorted `(key, value)` pairs
+    pub facts: Vec<(String, String)>,
+    /// Candidate actions offered to the planner
+    pub candidates: Vec<CandidateAction>,
+    /// Per-agent goals, plans and fallbacks
+    pub agents: Vec<AgentTrace>,
+    /// Nodes expanded by tactical search (0 when GOAP planning was used)
+    pub search_nodes: usize,
+    /// Commands executed, in order
+    pub commands: Vec<TracedCommand>,
+}
+
+impl AiTrace {
+    /// Creates an empty trace for `team` on `turn`.
+    pub fn new(team: impl Into<String>, turn: u32) -> Self {
+        Self {
+            team: team.into(),
+            turn,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the trace entry for `agent`, creating it if needed.
+    pub fn agent_mut(&mut self, agent: &str) -> &mut AgentTrace {
+        let index = match self.agents.iter().position(|a| a.agent == agent) {
+            Some(index) => index,
+            None => {
+                self.agents.push(AgentTrace {
+                    agent: agent.to_string(),
+                    ..Default::default()
+                });
+                self.agents.len() - 1
+            }
+        };
+        &mut self.agents[index]
+    }
+
+    /// Returns the trace entry for `agent`, if recorded.
+    pub fn agent(&self, agent: &str) -> Option<&AgentTrace> {
+        self.agents.iter().find(|a| a.agent == agent)
+    }
+
+    /// Records an executed command and its result.
+    pub fn record_command(
+        &mut self,
+        agent: Option<&str>,
+        command: impl Into<String>,
+        result: Result<(), String>,
+    ) {
+        let (succeeded, error) = match result {
+            Ok(()) => (true, None),
+            Err(e) => (false, Some(e)),
+        };
+        self.commands.push(TracedCommand {
+            agent: agent.map(str::to_string),
+            command: command.into(),
+            succeeded,
+            error,
+        });
+    }
+
+    /// Total planner nodes expanded across all agents.
+    pub fn planner_nodes(&self) -> usize {
+        self.agents.iter().map(|a| a.nodes_expanded).sum()
+    }
+
+    /// Serializes the trace as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// Parses a trace previously produced by [`AiTrace::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    /// Short text summary, optionally expanded for a single agent.
+    ///
+    /// Used for log output and the in-game AI inspector.
+    pub fn summary_lines(&self, focus: Option<&str>) -> Vec<String> {
+        let mut lines = vec![
+            format!("{} - turn {}", self.team, self.turn + 1),
+            format!(
+                "{} facts, {} candidate actions, {} planner nodes, {} search nodes",
+                self.facts.len(),
+                self.candidates.len(),
+                self.planner_nodes(),
+                self.search_nodes
+            ),
+        ];
+
+        for agent in &self.agents {
+            let status = if agent.plan_found {
+                format!("plan of {} step(s)", agent.plan.len())
+            } else if let Some(fallback) = &agent.fallback {
+                format!("no plan, fallback: {}", fallback)
+            } else {
+                "no plan".to_string()
+            };
+            let marker = if focus == Some(agent.agent.as_str()) {
+                ">"
+            } else {
+                " "
+            };
+            lines.push(format!("{} {}: {}", marker, agent.name, status));
+
+            if focus == Some(agent.agent.as_str()) {
+                for goal in &agent.goals {
+                    lines.push(format!("    goal {}", goal));
+                }
+                for step in &agent.plan {
+                    lines.push(format!("    step {}", step));
+                }
+                lines.push(format!("    nodes expanded {}", agent.nodes_expanded));
+            }
+        }
+
+        lines.push(format!("{} command(s):", self.commands.len()));
+        for command in &self.commands {
+            let result = match &command.error {
+                None => "ok".to_string(),
+                Some(e) => format!("failed: {}", e),
+            };
+            lines.push(format!("  {} ({})", command.command, result));
+        }
+        lines
+    }
+}
@@ -1,22 +1,7 @@
+use crate::facts::FactKey;
+pub use hex::HexCoord;
 use std::collections::HashMap;
 
-/// Small axial hex coordinate used for tile locations in the prototype.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct HexCoord {
-    pub q: i32,
-    pub r: i32,
-}
-
-impl HexCoord {
-    /// Calculate hex distance to another coordinate using axial coordinates.
-    pub fn distance(&self, other: HexCoord) -> i32 {
-        ((self.q - other.q).abs()
-            + (self.q + self.r - other.q - other.r).abs()
-            + (self.r - other.r).abs())
-            / 2
-    }
-}
-
 /// Simple fact value enum for small prototype.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FactValue {
@@ -26,10 +11,32 @@ pub enum FactValue {
     Hex(HexCoord),
 }
 
-/// WorldState: small hashmap from string keys to FactValue.
+impl FactValue {
+    /// Reads a position, accepting both `Hex` values and legacy `"q,r"` strings.
+    pub fn as_hex(&self) -> Option<HexCoord> {
+        match self {
+            FactValue::Hex(hex) => Some(*hex),
+            FactValue::Str(s) => {
+                let (q, r) = s.split_once(',')?;
+                Some(HexCoord {
+                    q: q.trim().parse().ok()?,
+                    r: r.trim().parse().ok()?,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// WorldState: small hashmap from typed [`FactKey`]s to FactValue.
+///
+/// The typed methods (`set`, `fact`, `holds`, `apply_fact_effects`) are the
+/// primary API. The string methods (`insert`, `get`, `satisfies`,
+/// `apply_effects`) are kept for compatibility and parse their keys with
+/// [`FactKey::parse`], so both APIs address the same facts.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WorldState {
-    pub facts: HashMap<String, FactValue>,
+    pub facts: HashMap<FactKey, FactValue>,
 }
 
 impl Default for WorldState {
@@ -45,12 +52,42 @@ impl WorldState {
         }
     }
 
+    /// Sets a typed fact.
+    pub fn set(&mut self, key: FactKey, value: FactValue) {
+        self.facts.insert(key, value);
+    }
+
+    /// Reads a typed fact.
+    pub fn fact(&self, key: &FactKey) -> Option<&FactValue> {
+        self.facts.get(key)
+    }
+
+    /// True when the fact `key` currently equals `value`.
+    pub fn holds(&self, key: &FactKey, value: &FactValue) -> bool {
+        self.fact(key) == Some(value)
+    }
+
+    /// Applies typed effects in order.
+    pub fn apply_fact_effects(&mut self, effects: &[(FactKey, FactValue)]) {
+        for (k, v) in effects {
+            self.facts.insert(k.clone(), v.clone());
+        }
+    }
+
+    /// All facts as `(string key, value)` pairs sorted by key.
+    pub fn string_facts(&self) -> Vec<(String, &FactValue)> {
+        let mut facts: Vec<(String, &FactValue)> =
+            self.facts.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        facts.sort_by(|a, b| a.0.cmp(&b.0));
+        facts
+    }
+
     pub fn insert(&mut self, key: impl Into<String>, value: FactValue) {
-        self.facts.insert(key.into(), value);
+        self.set(FactKey::parse(&key.into()), value);
     }
 
     pub fn get(&self, key: &str) -> Option<&FactValue> {
-        self.facts.get(key)
+        self.fact(&FactKey::parse(key))
     }
 
     pub fn satisfies(&self, key: &str, value: &FactValue) -> bool {
@@ -59,7 +96,7 @@ impl WorldState {
 
     pub fn apply_effects(&mut self, effects: &[(String, FactValue)]) {
         for (k, v) in effects {
-            self.facts.insert(k.clone(), v.clone());
+            self.set(FactKey::parse(k), v.clone());
         }
     }
 }
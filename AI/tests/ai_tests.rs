@@ -41,7 +41,7 @@ fn planner_basic_and_limit() {
 
     let actions = vec![t1, t2, t3];
     let goal = Goal {
-        key: "At".to_string(),
+        key: FactKey::parse("At"),
         value: FactValue::Hex(HexCoord { q: 2, r: 0 }),
     };
 
@@ -60,7 +60,7 @@ fn planner_basic_and_limit() {
     let mut total_cost = 0.0f32;
     for &i in &plan_seq {
         let a = &instances[i];
-        s2.apply_effects(&a.effects);
+        s2.apply_fact_effects(&a.effects);
         total_cost += a.cost;
     }
     assert_eq!(s2.get("At"), Some(&FactValue::Hex(HexCoord { q: 2, r: 0 })));
@@ -69,6 +69,17 @@ fn planner_basic_and_limit() {
     // Too small node budget -> no plan
     let plan_limited = plan(&start, &actions, &goal, 1);
     assert!(plan_limited.is_none());
+
+    // Node counts never exceed the budget and count each popped node once
+    let (found, nodes) = plan_instances_counted(&start, &instances, &goal, 1);
+    assert!(found.is_none());
+    assert_eq!(nodes, 1);
+    let (found, nodes) = plan_instances_counted(&start, &instances, &goal, 0);
+    assert!(found.is_none());
+    assert_eq!(nodes, 0);
+    let (found, nodes) = plan_instances_counted(&start, &instances, &goal, 1000);
+    assert!(found.is_some());
+    assert!(nodes <= 4, "expanded {} nodes", nodes);
 }
 
 #[test]
@@ -81,10 +92,16 @@ fn plan_for_team_sequential_application() {
     // Action visible to a1: move X->Y and set Flag true
     let a1_move = ActionInstance {
         name: "MoveA".to_string(),
-        preconditions: vec![("At".to_string(), FactValue::Hex(HexCoord { q: 0, r: 0 }))],
+        preconditions: vec![(
+            FactKey::parse("At"),
+            FactValue::Hex(HexCoord { q: 0, r: 0 }),
+        )],
         effects: vec![
-            ("At".to_string(), FactValue::Hex(HexCoord { q: 1, r: 0 })),
-            ("Flag".to_string(), FactValue::Bool(true)),
+            (
+                FactKey::parse("At"),
+                FactValue::Hex(HexCoord { q: 1, r: 0 }),
+            ),
+            (FactKey::parse("Flag"), FactValue::Bool(true)),
         ],
         cost: 1.0,
         agent: Some("a1".to_string()),
@@ -93,8 +110,8 @@ fn plan_for_team_sequential_application() {
     // Action visible to a2: requires Flag==true, sets Done=true
     let a2_action = ActionInstance {
         name: "DoB".to_string(),
-        preconditions: vec![("Flag".to_string(), FactValue::Bool(true))],
-        effects: vec![("Done".to_string(), FactValue::Bool(true))],
+        preconditions: vec![(FactKey::parse("Flag"), FactValue::Bool(true))],
+        effects: vec![(FactKey::parse("Done"), FactValue::Bool(true))],
         cost: 1.0,
         agent: Some("a2".to_string()),
     };
@@ -105,14 +122,14 @@ fn plan_for_team_sequential_application() {
     goals.insert(
         "a1".to_string(),
         vec![Goal {
-            key: "Flag".to_string(),
+            key: FactKey::parse("Flag"),
             value: FactValue::Bool(true),
         }],
     );
     goals.insert(
         "a2".to_string(),
         vec![Goal {
-            key: "Done".to_string(),
+            key: FactKey::parse("Done"),
             value: FactValue::Bool(true),
         }],
     );
@@ -137,7 +154,7 @@ fn executor_instant_and_timed_and_abort() {
     let inst = ActionInstance {
         name: "InstantSub".to_string(),
         preconditions: vec![],
-        effects: vec![("X".to_string(), FactValue::Int(5))],
+        effects: vec![(FactKey::parse("X"), FactValue::Int(5))],
         cost: 0.0,
         agent: None,
     };
@@ -153,7 +170,7 @@ fn executor_instant_and_timed_and_abort() {
     let timed = ActionInstance {
         name: "TimedAdd".to_string(),
         preconditions: vec![],
-        effects: vec![("X".to_string(), FactValue::Int(7))],
+        effects: vec![(FactKey::parse("X"), FactValue::Int(7))],
         cost: 0.0,
         agent: None,
     };
@@ -177,7 +194,7 @@ fn executor_instant_and_timed_and_abort() {
     let will_abort = ActionInstance {
         name: "AbortMe".to_string(),
         preconditions: vec![],
-        effects: vec![("Y".to_string(), FactValue::Bool(true))],
+        effects: vec![(FactKey::parse("Y"), FactValue::Bool(true))],
         cost: 0.0,
         agent: None,
     };
@@ -200,7 +217,10 @@ fn executor_callbacks_fire() {
     let inst = ActionInstance {
         name: "InstantMove".to_string(),
         preconditions: vec![],
-        effects: vec![("At".to_string(), FactValue::Hex(HexCoord { q: 1, r: 0 }))],
+        effects: vec![(
+            FactKey::parse("At"),
+            FactValue::Hex(HexCoord { q: 1, r: 0 }),
+        )],
         cost: 0.0,
         agent: None,
     };
@@ -244,6 +264,7 @@ fn attack_template_various_cases() {
         damage: 5,
         cost: 1.0,
         range: 1,
+        retaliation_damage: 0,
     };
 
     // Case 1: global enemy keys
@@ -259,16 +280,17 @@ fn attack_template_various_cases() {
     assert_eq!(instances.len(), 1);
     let ai = &instances[0];
     // preconds require At==B (hex) and EnemyAlive==true
+    assert!(ai.preconditions.contains(&(
+        FactKey::parse("At"),
+        FactValue::Hex(HexCoord { q: 1, r: 0 })
+    )));
     assert!(ai
         .preconditions
-        .contains(&("At".to_string(), FactValue::Hex(HexCoord { q: 1, r: 0 }))));
-    assert!(ai
-        .preconditions
-        .contains(&("EnemyAlive".to_string(), FactValue::Bool(true))));
+        .contains(&(FactKey::parse("EnemyAlive"), FactValue::Bool(true))));
     // effects reduce health from 6 -> 1 and not yet set alive false
     assert!(ai
         .effects
-        .contains(&("EnemyHealth".to_string(), FactValue::Int(1))));
+        .contains(&(FactKey::parse("EnemyHealth"), FactValue::Int(1))));
 
     // Case 2: per-id enemy keys without health but with alive flag
     let mut s2 = WorldState::new();
@@ -284,9 +306,238 @@ fn attack_template_various_cases() {
     // preconds include EnemyAlive:orc==true
     assert!(ai2
         .preconditions
-        .contains(&("EnemyAlive:orc".to_string(), FactValue::Bool(true))));
+        .contains(&(FactKey::parse("EnemyAlive:orc"), FactValue::Bool(true))));
     // effects should set EnemyAlive:orc=false (no health present)
     assert!(ai2
         .effects
-        .contains(&("EnemyAlive:orc".to_string(), FactValue::Bool(false))));
+        .contains(&(FactKey::parse("EnemyAlive:orc"), FactValue::Bool(false))));
+}
+
+#[test]
+fn attack_template_adds_retaliation_to_cost() {
+    let mut state = WorldState::new();
+    state.insert(
+        "EnemyAt".to_string(),
+        FactValue::Hex(HexCoord { q: 2, r: 0 }),
+    );
+    let safe = AttackTemplate {
+        name_base: "Shoot".to_string(),
+        damage: 5,
+        cost: 1.0,
+        range: 3,
+        retaliation_damage: 0,
+    };
+    let answered = AttackTemplate {
+        retaliation_damage: 4,
+        ..safe.clone()
+    };
+
+    assert_eq!(safe.ground_for_state(&state, None)[0].cost, 1.0);
+    assert_eq!(answered.ground_for_state(&state, None)[0].cost, 5.0);
+}
+
+/// Single-player toy game for expectimax: each turn pick a safe +1 or a gamble.
+#[derive(Clone, Debug)]
+struct GambleGame {
+    score: f32,
+    turns_left: u32,
+    win_chance: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum GambleCommand {
+    Safe,
+    Gamble,
+}
+
+impl TacticalState for GambleGame {
+    type Command = GambleCommand;
+    type Side = u8;
+
+    fn current_side(&self) -> u8 {
+        0
+    }
+
+    fn legal_commands(&self) -> Vec<GambleCommand> {
+        if self.turns_left == 0 {
+            Vec::new()
+        } else {
+            vec![GambleCommand::Safe, GambleCommand::Gamble]
+        }
+    }
+
+    fn apply(&self, command: &GambleCommand) -> Vec<(f32, Self)> {
+        let step = |gain: f32| GambleGame {
+            score: self.score + gain,
+            turns_left: self.turns_left - 1,
+            win_chance: self.win_chance,
+        };
+        match command {
+            GambleCommand::Safe => vec![(1.0, step(1.0))],
+            GambleCommand::Gamble => vec![
+                (self.win_chance, step(4.0)),
+                (1.0 - self.win_chance, step(0.0)),
+            ],
+        }
+    }
+
+    fn evaluate(&self, _side: &u8) -> f32 {
+        self.score
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.turns_left == 0
+    }
+}
+
+#[test]
+fn expectimax_weighs_chance_outcomes() {
+    let unlikely = GambleGame {
+        score: 0.0,
+        turns_left: 2,
+        win_chance: 0.2,
+    };
+    let result = expectimax(&unlikely, &SearchBudget::nodes(2, 1_000));
+    assert_eq!(result.best_command, Some(GambleCommand::Safe));
+    assert!((result.value - 2.0).abs() < 1e-4);
+    assert_eq!(result.depth_reached, 2);
+
+    let likely = GambleGame {
+        win_chance: 0.5,
+        ..unlikely
+    };
+    let result = expectimax(&likely, &SearchBudget::nodes(2, 1_000));
+    assert_eq!(result.best_command, Some(GambleCommand::Gamble));
+    assert!((result.value - 4.0).abs() < 1e-4);
+}
+
+#[test]
+fn expectimax_stops_at_node_budget() {
+    let game = GambleGame {
+        score: 0.0,
+        turns_left: 10,
+        win_chance: 0.5,
+    };
+    let result = expectimax(&game, &SearchBudget::nodes(10, 20));
+    assert!(result.best_command.is_some());
+    assert!(result.depth_reached < 10);
+
+    let finished = GambleGame {
+        turns_left: 0,
+        ..game
+    };
+    let result = expectimax(&finished, &SearchBudget::default());
+    assert_eq!(result.best_command, None);
+    assert_eq!(result.nodes, 0);
+}
+
+/// Two-side toy game: every ply the side to move nudges the score up or down.
+#[derive(Clone, Debug)]
+struct AllianceGame {
+    side: u8,
+    score: f32,
+    plies_left: u32,
+    allied: bool,
+}
+
+impl TacticalState for AllianceGame {
+    type Command = i8;
+    type Side = u8;
+
+    fn current_side(&self) -> u8 {
+        self.side
+    }
+
+    fn legal_commands(&self) -> Vec<i8> {
+        if self.plies_left == 0 {
+            Vec::new()
+        } else {
+            vec![1, -1]
+        }
+    }
+
+    fn apply(&self, command: &i8) -> Vec<(f32, Self)> {
+        vec![(
+            1.0,
+            AllianceGame {
+                side: 1 - self.side,
+                score: self.score + *command as f32,
+                plies_left: self.plies_left - 1,
+                allied: self.allied,
+            },
+        )]
+    }
+
+    fn is_friendly(&self, side: &u8, root: &u8) -> bool {
+        side == root || self.allied
+    }
+
+    fn evaluate(&self, _side: &u8) -> f32 {
+        self.score
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.plies_left == 0
+    }
+}
+
+#[test]
+fn expectimax_maximizes_on_allied_turns() {
+    let allied = AllianceGame {
+        side: 0,
+        score: 0.0,
+        plies_left: 2,
+        allied: true,
+    };
+    let result = expectimax(&allied, &SearchBudget::nodes(2, 1_000));
+    assert_eq!(result.best_command, Some(1));
+    assert!((result.value - 2.0).abs() < 1e-4);
+
+    let hostile = AllianceGame {
+        allied: false,
+        ..allied
+    };
+    let result = expectimax(&hostile, &SearchBudget::nodes(2, 1_000));
+    assert!(result.value.abs() < 1e-4);
+}
+
+#[test]
+fn traced_team_planning_records_agents_and_json() {
+    let mut start = WorldState::new();
+    start.insert("At".to_string(), FactValue::Hex(HexCoord { q: 0, r: 0 }));
+
+    let step = ground_action_from_template(
+        &move_template(HexCoord { q: 0, r: 0 }, HexCoord { q: 1, r: 0 }, 1.0),
+        Some("a1".to_string()),
+    );
+    let goal = Goal {
+        key: FactKey::parse("At"),
+        value: FactValue::Hex(HexCoord { q: 1, r: 0 }),
+    };
+    let unreachable = Goal {
+        key: FactKey::parse("At"),
+        value: FactValue::Hex(HexCoord { q: 9, r: 9 }),
+    };
+    let mut goals = HashMap::new();
+    goals.insert("a1".to_string(), vec![goal]);
+    goals.insert("a2".to_string(), vec![unreachable]);
+    let order = vec!["a1".to_string(), "a2".to_string()];
+
+    let mut trace = AiTrace::new("Enemy", 3);
+    let plans = plan_for_team_traced(&start, &[step], &goals, &order, 100, &mut trace);
+    assert_eq!(plans["a1"].len(), 1);
+
+    let a1 = trace.agent("a1").unwrap();
+    assert!(a1.plan_found);
+    assert_eq!(a1.plan.len(), 1);
+    assert!(a1.nodes_expanded > 0);
+    assert!(!trace.agent("a2").unwrap().plan_found);
+
+    trace.record_command(Some("a1"), "Move", Ok(()));
+    trace.record_command(Some("a2"), "Attack", Err("out of range".to_string()));
+    assert!(!trace.commands[1].succeeded);
+
+    let json = trace.to_json().unwrap();
+    assert_eq!(AiTrace::from_json(&json).unwrap(), trace);
+    assert!(trace.summary_lines(Some("a1")).len() > trace.summary_lines(None).len());
 }
@@ -0,0 +1,125 @@
+/// Tests for typed world-state fact keys and the string compatibility API
+use ai::{
+    plan_instances, ActionInstance, EnemyFact, FactKey, FactValue, Goal, HexCoord, Symbol,
+    TeamFact, TerrainFact, UnitFact, WorldState,
+};
+
+#[test]
+fn test_symbols_are_interned() {
+    let a = Symbol::new("orc-1");
+    let b = Symbol::new("orc-1");
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    assert_ne!(a, Symbol::new("orc-2"));
+    assert_eq!(a.as_str(), "orc-1");
+    assert_eq!(a.clone(), b);
+}
+
+#[test]
+fn test_keys_display_as_legacy_strings() {
+    let hex = HexCoord { q: 2, r: -1 };
+    assert_eq!(FactKey::CurrentTeam.to_string(), "CurrentTeam");
+    assert_eq!(
+        FactKey::unit("abc", UnitFact::Health).to_string(),
+        "Unit:abc:Health"
+    );
+    assert_eq!(
+        FactKey::terrain(hex, TerrainFact::MoveCost).to_string(),
+        "Terrain:2,-1:MoveCost"
+    );
+    assert_eq!(
+        FactKey::team(TeamFact::AllyCount).to_string(),
+        "Team:AllyCount"
+    );
+    assert_eq!(FactKey::enemy(None, EnemyFact::At).to_string(), "EnemyAt");
+    assert_eq!(
+        FactKey::enemy(Some("orc"), EnemyFact::Alive).to_string(),
+        "EnemyAlive:orc"
+    );
+}
+
+#[test]
+fn test_parse_roundtrips_and_falls_back_to_custom() {
+    for key in [
+        "CurrentTeam",
+        "Unit:abc:At",
+        "Terrain:0,3:Type",
+        "Team:AverageHealth",
+        "EnemyHealth:orc",
+        "EnemyAlive",
+        "Unit:abc:NotAFact",
+        "Terrain:01,3:Type",
+        "Team:Player:UnitCount",
+        "EnemyCount",
+        "Flag",
+    ] {
+        assert_eq!(FactKey::parse(key).to_string(), key);
+    }
+
+    assert_eq!(
+        FactKey::parse("Unit:abc:At"),
+        FactKey::unit("abc", UnitFact::At)
+    );
+    assert!(matches!(FactKey::parse("Flag"), FactKey::Custom(_)));
+    assert!(matches!(
+        FactKey::parse("Unit:abc:NotAFact"),
+        FactKey::Custom(_)
+    ));
+}
+
+#[test]
+fn test_string_and_typed_apis_share_facts() {
+    let mut ws = WorldState::new();
+    ws.set(FactKey::unit("u1", UnitFact::Health), FactValue::Int(7));
+    ws.insert("Unit:u1:Alive", FactValue::Bool(true));
+
+    assert_eq!(ws.get("Unit:u1:Health"), Some(&FactValue::Int(7)));
+    assert!(ws.holds(
+        &FactKey::unit("u1", UnitFact::Alive),
+        &FactValue::Bool(true)
+    ));
+    assert!(ws.satisfies("Unit:u1:Alive", &FactValue::Bool(true)));
+    assert_eq!(ws.get("Unit:missing-unit-id:Health"), None);
+
+    ws.apply_effects(&[("Unit:u1:Health".to_string(), FactValue::Int(3))]);
+    assert_eq!(
+        ws.fact(&FactKey::unit("u1", UnitFact::Health)),
+        Some(&FactValue::Int(3))
+    );
+    assert_eq!(ws.facts.len(), 2);
+
+    let keys: Vec<String> = ws.string_facts().into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, vec!["Unit:u1:Alive", "Unit:u1:Health"]);
+}
+
+#[test]
+fn test_position_values_accept_hex_and_legacy_strings() {
+    let hex = HexCoord { q: 4, r: -2 };
+    assert_eq!(FactValue::Hex(hex).as_hex(), Some(hex));
+    assert_eq!(FactValue::Str("4, -2".to_string()).as_hex(), Some(hex));
+    assert_eq!(FactValue::Str("nowhere".to_string()).as_hex(), None);
+    assert_eq!(FactValue::Int(4).as_hex(), None);
+}
+
+#[test]
+fn test_planner_reaches_typed_goal() {
+    let at = FactKey::unit("knight", UnitFact::At);
+    let mut start = WorldState::new();
+    start.set(at.clone(), FactValue::Hex(HexCoord { q: 0, r: 0 }));
+
+    let step = |from: i32, to: i32| ActionInstance {
+        name: format!("Move {}->{}", from, to),
+        preconditions: vec![(at.clone(), FactValue::Hex(HexCoord { q: from, r: 0 }))],
+        effects: vec![(at.clone(), FactValue::Hex(HexCoord { q: to, r: 0 }))],
+        cost: 1.0,
+        agent: None,
+    };
+    let actions = vec![step(1, 2), step(0, 1)];
+    let goal = Goal::new(at.clone(), FactValue::Hex(HexCoord { q: 2, r: 0 }));
+
+    assert_eq!(goal.key, FactKey::parse("Unit:knight:At"));
+    assert_eq!(
+        plan_instances(&start, &actions, &goal, 100),
+        Some(vec![1, 0])
+    );
+}
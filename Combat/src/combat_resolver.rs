@@ -1,27 +1,45 @@
-//! # Combat Resolver Module
+//! # Legacy Combat Resolver
 //!
-//! Contains the core combat resolution algorithm that handles turn-based
-//! combat with alternating attacks, hit chance rolls, and damage calculation.
+//! A standalone resolver that fights out an exchange between two units
+//! without a world around them. It is kept for tools and experiments that
+//! only have the units at hand; the game does not use it.
 //!
-//! Combat flows as follows:
-//! 1. Requests to initiate combat are made via the `iniate_combat` function.
-//! 2. The `execute_combat` function executes the combat round, alternating
-//!    attacks between the attacker and defender until both have expended their
-//!    allowed attacks for the turn.
-//! 3. Each attack involves rolling for hit chance twice; both rolls must succeed
-//!    for the attack to hit.
-//! 4. Damage is calculated based on attack damage, modified by any resistances
-//!    the target has against the attack's damage type.
+//! Hit chances come from [`HitChanceBreakdown::for_strike`], like everywhere
+//! else: terrain, buffs/debuffs and magic all apply. Only the structure term
+//! is left out, since structures stand in the world. Game combat is resolved
+//! by `ScenarioWorld` with [`roll_exchange`](crate::roll_exchange) and
+//! forecast with [`forecast`](crate::forecast::forecast).
+//!
+//! The exchange runs as follows:
+//! 1. The attacker strikes once with the given attack.
+//! 2. The defender answers once if one of its attacks may retaliate.
+//! 3. Each strike rolls for hit chance twice; either roll succeeding hits.
+//! 4. Damage is the striker's attack strength, modified by the target's
+//!    resistance to the attack's damage type.
 
 use rand::Rng;
-use units::combat::{CombatResult, DamageType, RangeCategory};
+use units::combat::{CombatResult, HitChanceBreakdown};
 use units::unit_trait::Unit;
+use units::{Attack, Terrain};
 
 /// Resolves a combat encounter between an attacker and defender unit.
-pub fn resolve_combat<U: Unit>(
-    attacker: &mut U,
-    defender: &mut U,
-    damage_type: DamageType,
+///
+/// The attacker strikes with `attack`; the defender answers only if one of
+/// its attacks may retaliate against it (see [`Attack::can_retaliate`]).
+///
+/// # Arguments
+///
+/// * `attacker` - The unit starting the exchange
+/// * `defender` - The unit being attacked
+/// * `attack` - The attacker's chosen attack
+/// * `attacker_terrain` - Defense class of the terrain the attacker stands on
+/// * `defender_terrain` - Defense class of the terrain the defender stands on
+pub fn resolve_combat(
+    attacker: &mut dyn Unit,
+    defender: &mut dyn Unit,
+    attack: &Attack,
+    attacker_terrain: Terrain,
+    defender_terrain: Terrain,
 ) -> CombatResult {
     // If the initiating unit has already attacked this game turn, abort combat.
     if attacker.combat_stats().attacked_this_turn {
@@ -32,12 +50,18 @@ pub fn resolve_combat<U: Unit>(
 
     // Determine total attacks for each combatant, capped at 1 per turn.
     let mut attacker_attacks_remaining: i32 = 1;
-    let mut defender_attacks_remaining: i32 =
-        if attacker.combat_stats().range_category == RangeCategory::Melee {
-            1
-        } else {
-            0 // Ranged attackers don't get counter-attacked
-        };
+    // The defender only answers with an attack of the same range type that
+    // reaches the attacker: melee cannot counter ranged or siege attacks
+    let distance = attacker.position().distance(defender.position());
+    let counter_attack = defender.counter_attack(attack, distance);
+    let mut defender_attacks_remaining: i32 = if counter_attack.is_some() { 1 } else { 0 };
+    let damage_type = attack.damage_type;
+
+    // Both units stand still during the exchange, so the chances are fixed
+    let strike_chance = strike_hit_chance(&*defender, defender_terrain, attack);
+    let counter_chance = counter_attack.as_ref().map_or(0, |counter| {
+        strike_hit_chance(&*attacker, attacker_terrain, counter)
+    });
 
     let mut is_attacker_turn = true; // Attacker goes first
 
@@ -53,20 +77,15 @@ pub fn resolve_combat<U: Unit>(
                 continue;
             }
 
-            // Hit chance: use precomputed terrain/defense field in CombatStats.
-            let hit_chance = attacker.combat_stats().terrain_hit_chance as u32;
-
-            if roll_hit(hit_chance) {
+            if roll_hit(strike_chance) {
                 // true = hit
                 result.attacker_hit = true;
 
-                // Resistance lookup
-                let resistance_pct = defender
+                // Resistances lower the damage, weaknesses raise it
+                let resistance_multiplier = defender
                     .combat_stats()
                     .resistances
-                    .get_resistance(damage_type); // expect 0..=100
-
-                let resistance_multiplier = 1.0 - (resistance_pct as f32 / 100.0);
+                    .damage_multiplier(damage_type);
 
                 let base_attack = attacker.base().cached_attack;
                 let attack_modifier = attacker.combat_stats().attack_modifier;
@@ -94,12 +113,12 @@ pub fn resolve_combat<U: Unit>(
                 continue;
             }
 
-            let hit_chance = defender.combat_stats().terrain_hit_chance as u32;
-
-            if roll_hit(hit_chance) {
+            if roll_hit(counter_chance) {
                 result.defender_hit = true;
-                let resistance = attacker.get_resistance(damage_type);
-                let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
+                let resistance_multiplier = attacker
+                    .combat_stats()
+                    .resistances
+                    .damage_multiplier(damage_type);
                 let modified_strength = (defender.combat_stats().attack_strength as i32
                     + defender.combat_stats().attack_modifier)
                     .max(0) as u32;
@@ -126,6 +145,11 @@ pub fn resolve_combat<U: Unit>(
     result
 }
 
+/// Chance for `attack` to hit `target` on `terrain`, with no structure.
+fn strike_hit_chance(target: &dyn Unit, terrain: Terrain, attack: &Attack) -> u32 {
+    HitChanceBreakdown::for_strike(target, terrain.name(), terrain, None, attack).chance as u32
+}
+
 fn roll_hit(hit_chance: u32) -> bool {
     let mut rng = rand::thread_rng();
     let roll_1 = rng.gen_range(0..100);
@@ -0,0 +1,285 @@
+//! Exact combat outcome forecasts.
+//!
+//! An exchange is a fixed sequence of strikes: the attacker strikes first,
+//! then the two sides alternate until both have used their strikes, and the
+//! exchange ends as soon as either side is defeated. Each strike hits with
+//! its side's hit chance and deals that side's damage per hit.
+//!
+//! [`forecast`] enumerates every hit/miss combination of that sequence and
+//! returns the exact probability of each end state, from which expected
+//! damage, kill and death chances and HP distributions follow.
+//! [`roll_exchange`] plays the same sequence with dice, so a forecast always
+//! describes the outcomes a rolled exchange can produce.
+//!
+//! Example:
+//!
+//! ```rust
+//! use combat::{forecast, ForecastSide};
+//!
+//! // 20 HP attacker striking twice for 10 at 50%, against a 10 HP defender
+//! // that cannot strike back
+//! let attacker = ForecastSide::new(20, 10, 50, 2);
+//! let defender = ForecastSide::new(10, 0, 0, 0);
+//!
+//! let result = forecast(&attacker, &defender);
+//! assert!((result.kill_chance() - 0.75).abs() < 1e-6);
+//! assert_eq!(result.death_chance(), 0.0);
+//! ```
+
+use std::collections::BTreeMap;
+
+/// One side of an exchange, as seen at the moment combat starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForecastSide {
+    /// Current health
+    pub hp: u32,
+    /// Damage dealt per hit, after resistances and bonuses
+    pub damage: u32,
+    /// Chance (0-100) for each strike to hit
+    pub hit_chance: u8,
+    /// Number of strikes in the exchange; 0 for a side that cannot strike
+    pub strikes: u32,
+}
+
+impl ForecastSide {
+    /// Creates a side of an exchange.
+    pub fn new(hp: u32, damage: u32, hit_chance: u8, strikes: u32) -> Self {
+        Self {
+            hp,
+            damage,
+            hit_chance: hit_chance.min(100),
+            strikes,
+        }
+    }
+
+    /// Number of strikes a unit makes with one attack: its attacks per round
+    /// times the attack's strikes per use.
+    pub fn strikes_for(attacks_per_round: u32, attack_times: u32) -> u32 {
+        attacks_per_round.max(1) * attack_times.max(1)
+    }
+}
+
+/// Side making a strike in an exchange.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Striker {
+    Attacker,
+    Defender,
+}
+
+/// Order of the strikes in an exchange: the attacker first, then alternating
+/// while both sides have strikes left.
+pub fn strike_order(attacker_strikes: u32, defender_strikes: u32) -> Vec<Striker> {
+    let mut order = Vec::with_capacity((attacker_strikes + defender_strikes) as usize);
+    for i in 0..attacker_strikes.max(defender_strikes) {
+        if i < attacker_strikes {
+            order.push(Striker::Attacker);
+        }
+        if i < defender_strikes {
+            order.push(Striker::Defender);
+        }
+    }
+    order
+}
+
+/// One possible end state of an exchange.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForecastOutcome {
+    /// Probability of this end state
+    pub probability: f32,
+    /// Number of attacker strikes that hit
+    pub attacker_hits: u32,
+    /// Number of defender strikes that hit
+    pub defender_hits: u32,
+    /// Attacker health after the exchange
+    pub attacker_hp: u32,
+    /// Defender health after the exchange
+    pub defender_hp: u32,
+}
+
+/// Exact probability distribution of an exchange's outcomes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatForecast {
+    /// Attacker health before the exchange
+    pub attacker_start_hp: u32,
+    /// Defender health before the exchange
+    pub defender_start_hp: u32,
+    /// Every reachable end state, most attacker hits first, then most
+    /// defender hits; probabilities sum to 1
+    pub outcomes: Vec<ForecastOutcome>,
+}
+
+impl CombatForecast {
+    /// Expected damage the attacker deals to the defender.
+    pub fn expected_damage_dealt(&self) -> f32 {
+        self.expected(|o| (self.defender_start_hp - o.defender_hp) as f32)
+    }
+
+    /// Expected damage the attacker takes from the defender.
+    pub fn expected_damage_taken(&self) -> f32 {
+        self.expected(|o| (self.attacker_start_hp - o.attacker_hp) as f32)
+    }
+
+    /// Chance that the defender is defeated.
+    pub fn kill_chance(&self) -> f32 {
+        self.expected(|o| if o.defender_hp == 0 { 1.0 } else { 0.0 })
+    }
+
+    /// Chance that the attacker is defeated.
+    pub fn death_chance(&self) -> f32 {
+        self.expected(|o| if o.attacker_hp == 0 { 1.0 } else { 0.0 })
+    }
+
+    /// Distribution of the attacker's health after the exchange, by
+    /// ascending health.
+    pub fn attacker_hp_distribution(&self) -> Vec<(u32, f32)> {
+        Self::distribution(self.outcomes.iter().map(|o| (o.attacker_hp, o.probability)))
+    }
+
+    /// Distribution of the defender's health after the exchange, by
+    /// ascending health.
+    pub fn defender_hp_distribution(&self) -> Vec<(u32, f32)> {
+        Self::distribution(self.outcomes.iter().map(|o| (o.defender_hp, o.probability)))
+    }
+
+    fn expected(&self, value: impl Fn(&ForecastOutcome) -> f32) -> f32 {
+        self.outcomes.iter().map(|o| o.probability * value(o)).sum()
+    }
+
+    fn distribution(values: impl Iterator<Item = (u32, f32)>) -> Vec<(u32, f32)> {
+        let mut by_hp: BTreeMap<u32, f32> = BTreeMap::new();
+        for (hp, probability) in values {
+            *by_hp.entry(hp).or_default() += probability;
+        }
+        by_hp.into_iter().collect()
+    }
+}
+
+/// State of an exchange in progress: health and hits landed on both sides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ExchangeState {
+    attacker_hp: u32,
+    defender_hp: u32,
+    attacker_hits: u32,
+    defender_hits: u32,
+}
+
+impl ExchangeState {
+    fn start(attacker: &ForecastSide, defender: &ForecastSide) -> Self {
+        Self {
+            attacker_hp: attacker.hp,
+            defender_hp: defender.hp,
+            attacker_hits: 0,
+            defender_hits: 0,
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        self.attacker_hp == 0 || self.defender_hp == 0
+    }
+
+    fn hit(mut self, striker: Striker, damage: u32) -> Self {
+        match striker {
+            Striker::Attacker => {
+                self.attacker_hits += 1;
+                self.defender_hp = self.defender_hp.saturating_sub(damage);
+            }
+            Striker::Defender => {
+                self.defender_hits += 1;
+                self.attacker_hp = self.attacker_hp.saturating_sub(damage);
+            }
+        }
+        self
+    }
+}
+
+/// Computes the exact outcome distribution of an exchange.
+///
+/// # Arguments
+///
+/// * `attacker` - The side starting the exchange
+/// * `defender` - The side being attacked
+///
+/// # Returns
+///
+/// Every end state with its probability
+pub fn forecast(attacker: &ForecastSide, defender: &ForecastSide) -> CombatForecast {
+    let mut states: BTreeMap<ExchangeState, f64> = BTreeMap::new();
+    states.insert(ExchangeState::start(attacker, defender), 1.0);
+
+    for striker in strike_order(attacker.strikes, defender.strikes) {
+        let side = match striker {
+            Striker::Attacker => attacker,
+            Striker::Defender => defender,
+        };
+        let p_hit = side.hit_chance as f64 / 100.0;
+        let mut next: BTreeMap<ExchangeState, f64> = BTreeMap::new();
+        for (state, probability) in states {
+            if state.is_over() {
+                *next.entry(state).or_default() += probability;
+                continue;
+            }
+            if p_hit > 0.0 {
+                *next.entry(state.hit(striker, side.damage)).or_default() += probability * p_hit;
+            }
+            if p_hit < 1.0 {
+                *next.entry(state).or_default() += probability * (1.0 - p_hit);
+            }
+        }
+        states = next;
+    }
+
+    let mut outcomes: Vec<ForecastOutcome> = states
+        .into_iter()
+        .map(|(state, probability)| ForecastOutcome {
+            probability: probability as f32,
+            attacker_hits: state.attacker_hits,
+            defender_hits: state.defender_hits,
+            attacker_hp: state.attacker_hp,
+            defender_hp: state.defender_hp,
+        })
+        .collect();
+    outcomes.sort_by(|a, b| {
+        b.attacker_hits
+            .cmp(&a.attacker_hits)
+            .then(b.defender_hits.cmp(&a.defender_hits))
+    });
+
+    CombatForecast {
+        attacker_start_hp: attacker.hp,
+        defender_start_hp: defender.hp,
+        outcomes,
+    }
+}
+
+/// Plays an exchange with dice, in the same strike order as [`forecast`].
+///
+/// # Arguments
+///
+/// * `attacker` - The side starting the exchange
+/// * `defender` - The side being attacked
+/// * `roll` - Rolls one strike with the given hit chance, returning whether
+///   it hits
+///
+/// # Returns
+///
+/// The number of attacker and defender strikes that hit
+pub fn roll_exchange(
+    attacker: &ForecastSide,
+    defender: &ForecastSide,
+    mut roll: impl FnMut(u8) -> bool,
+) -> (u32, u32) {
+    let mut state = ExchangeState::start(attacker, defender);
+    for striker in strike_order(attacker.strikes, defender.strikes) {
+        if state.is_over() {
+            break;
+        }
+        let side = match striker {
+            Striker::Attacker => attacker,
+            Striker::Defender => defender,
+        };
+        if roll(side.hit_chance) {
+            state = state.hit(striker, side.damage);
+        }
+    }
+    (state.attacker_hits, state.defender_hits)
+}
@@ -7,7 +7,8 @@
 //! ## Architecture Role
 //!
 //! - **Pure Logic**: Contains only combat resolution algorithms, no state
-//! - **Consumed by**: `Game/ScenarioWorld` which calls `resolve_combat()`
+//! - **Consumed by**: `Game/ScenarioWorld`, which forecasts exchanges with [`forecast()`] and
+//!   rolls them with [`roll_exchange()`]
 //! - **Not Responsible For**: Unit state, turn management, AI decisions, UI display
 //!
 //! ## Separation of Concerns
@@ -20,29 +21,37 @@
 //! ## Features
 //!
 //! - **Combat Statistics**: Comprehensive unit stats including health, attack, and resistances
-//! - **Damage Types**: Multiple damage types (Slash, Pierce, Blunt, Crush, Fire, Dark) with resistance system
-//! - **Range Categories**: Melee, Range, and Siege combat with counter-attack mechanics
-//! - **Combat Resolution**: Turn-based alternating attacks with hit chance rolls
+//! - **Damage Types**: The shared damage type registry (physical, elemental and divine types) with
+//!   per-type resistances and weaknesses
+//! - **Range Types**: Melee, Ranged, and Siege combat; defenders only counter-attack in kind
+//! - **Combat Resolution**: Turn-based alternating strikes with hit chance rolls
 //! - **Multi-Attack System**: Support for units with multiple attacks per round
+//! - **Combat Forecast**: Exact outcome distributions of an exchange (expected damage, kill and
+//!   death chances, HP after the exchange), see [`forecast`]
 //!
 //! ## Combat Flow
 //!
-//! 1. Attacker and defender stats are prepared by caller (ScenarioWorld)
-//! 2. Combat is resolved using `resolve_combat()` (pure function)
-//! 3. Attacks alternate between combatants based on `attacks_per_round`
-//! 4. Hit chance is rolled for each attack
-//! 5. Damage is calculated with resistance modifiers
-//! 6. Combat continues until all attacks are exhausted or a unit is defeated
-//! 7. Results returned to caller for state updates
+//! 1. The caller (ScenarioWorld) prepares a [`ForecastSide`] for each combatant: health, damage
+//!    per hit after resistances, hit chance and number of strikes
+//! 2. Strikes alternate between combatants in [`strike_order`]
+//! 3. Each strike hits with its side's hit chance
+//! 4. The exchange stops once all strikes are spent or a unit is defeated
+//! 5. [`forecast()`] returns every outcome with its probability; [`roll_exchange()`] rolls one
+//!
+//! [`combat_resolver`] holds the legacy standalone resolver, which works on two units without a
+//! world and so leaves out the structure hit chance term.
 
-mod combat_resolver;
+pub mod combat_resolver;
+mod forecast;
 
-pub use combat_resolver::resolve_combat;
+pub use forecast::{
+    forecast, roll_exchange, strike_order, CombatForecast, ForecastOutcome, ForecastSide, Striker,
+};
 
 // Re-export core combat types from the `units` crate to avoid duplicating
 // type definitions. The `units` crate owns the `combat` module which contains
-// `CombatStats`, `DamageType`, `RangeCategory`, `Resistances`, `CombatResult`,
+// `CombatStats`, `DamageType`, `RangeType`, `Resistances`, `CombatResult`,
 // and `CombatAction`.
 pub use units::combat::{
-    CombatAction, CombatResult, CombatStats, DamageType, RangeCategory, Resistances,
+    CombatAction, CombatResult, CombatStats, DamageType, RangeType, Resistances,
 };
@@ -0,0 +1,92 @@
+//! Tests for counter-attacks in the legacy combat resolver
+
+use combat::combat_resolver::resolve_combat;
+use hex::HexCoord;
+use items::ItemRegistry;
+use std::collections::HashMap;
+use units::ability::{ActiveEffect, ActiveEffectInstance, BuffStat};
+use units::{Terrain, Unit, UnitFactory};
+use uuid::Uuid;
+
+/// A unit of `unit_type` at `q` hexes east of the origin that is always hit
+/// on grasslands.
+fn unit_at(unit_type: &str, q: i32) -> Box<dyn Unit> {
+    let mut unit = UnitFactory::create(unit_type, None, Some(HexCoord::new(q, 0))).unwrap();
+    unit.base_mut().terrain_defenses = Some(HashMap::from([(Terrain::Grasslands, 100)]));
+    unit
+}
+
+/// Fights out an exchange with both units on grasslands.
+fn fight(
+    attacker: &mut dyn Unit,
+    defender: &mut dyn Unit,
+    attack: &units::Attack,
+) -> units::combat::CombatResult {
+    resolve_combat(
+        attacker,
+        defender,
+        attack,
+        Terrain::Grasslands,
+        Terrain::Grasslands,
+    )
+}
+
+/// Equips a crossbow and returns its attack.
+fn equip_crossbow(unit: &mut dyn Unit) -> units::Attack {
+    let crossbow = ItemRegistry::builtin().create("crossbow").unwrap();
+    let crossbow_id = crossbow.id;
+    unit.add_item_to_inventory(crossbow);
+    unit.equip_item(crossbow_id).unwrap();
+    unit.get_attacks()
+        .into_iter()
+        .find(|attack| attack.name == "Bolt")
+        .unwrap()
+}
+
+#[test]
+fn test_melee_unit_with_a_crossbow_is_not_answered_by_melee() {
+    // Both are melee units by race, but the dwarf shoots a ranged attack
+    let mut dwarf = unit_at("Dwarf Warrior", 0);
+    let mut orc = unit_at("Orc Young Swordsman", 2);
+    let bolt = equip_crossbow(dwarf.as_mut());
+
+    let result = fight(dwarf.as_mut(), orc.as_mut(), &bolt);
+    assert!(result.attacker_hit);
+    assert!(!result.defender_hit);
+    assert_eq!(result.defender_damage_dealt, 0);
+}
+
+#[test]
+fn test_crossbow_is_answered_by_crossbow() {
+    let mut attacker = unit_at("Dwarf Warrior", 0);
+    let mut defender = unit_at("Dwarf Warrior", 2);
+    let bolt = equip_crossbow(attacker.as_mut());
+    equip_crossbow(defender.as_mut());
+
+    let result = fight(attacker.as_mut(), defender.as_mut(), &bolt);
+    assert!(result.defender_hit);
+    assert!(result.defender_damage_dealt > 0);
+}
+
+#[test]
+fn test_defense_buff_applies_to_resolver_hit_chance() {
+    let mut attacker = unit_at("Dwarf Warrior", 0);
+    let mut defender = unit_at("Orc Young Swordsman", 1);
+    defender.ability_state_mut().add_effect(
+        Uuid::new_v4(),
+        ActiveEffectInstance::new(
+            ActiveEffect::Buff {
+                stat: BuffStat::Defense,
+                amount: 100,
+                duration: 2,
+            },
+            2,
+            None,
+        ),
+    );
+    let attack = attacker.get_attacks()[0].clone();
+
+    let result = fight(attacker.as_mut(), defender.as_mut(), &attack);
+    assert!(!result.attacker_hit);
+    assert!(result.defender_hit);
+}
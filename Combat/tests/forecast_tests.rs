@@ -0,0 +1,103 @@
+//! Tests for exact combat outcome forecasts
+
+use combat::{forecast, roll_exchange, strike_order, ForecastSide, Striker};
+
+fn total_probability(result: &combat::CombatForecast) -> f32 {
+    result.outcomes.iter().map(|o| o.probability).sum()
+}
+
+#[test]
+fn test_strike_order_alternates_from_attacker() {
+    use Striker::{Attacker, Defender};
+    assert_eq!(
+        strike_order(3, 1),
+        vec![Attacker, Defender, Attacker, Attacker]
+    );
+    assert_eq!(strike_order(1, 2), vec![Attacker, Defender, Defender]);
+    assert_eq!(strike_order(2, 0), vec![Attacker, Attacker]);
+    assert_eq!(ForecastSide::strikes_for(2, 3), 6);
+    assert_eq!(ForecastSide::strikes_for(0, 0), 1);
+}
+
+#[test]
+fn test_single_exchange_distribution() {
+    let attacker = ForecastSide::new(20, 8, 60, 1);
+    let defender = ForecastSide::new(30, 5, 50, 1);
+    let result = forecast(&attacker, &defender);
+
+    assert_eq!(result.outcomes.len(), 4);
+    assert!((total_probability(&result) - 1.0).abs() < 1e-6);
+    assert!((result.expected_damage_dealt() - 0.6 * 8.0).abs() < 1e-5);
+    assert!((result.expected_damage_taken() - 0.5 * 5.0).abs() < 1e-5);
+    assert_eq!(result.kill_chance(), 0.0);
+    assert_eq!(result.death_chance(), 0.0);
+
+    // Most hits first
+    assert_eq!(result.outcomes[0].attacker_hits, 1);
+    assert_eq!(result.outcomes[0].defender_hits, 1);
+    assert_eq!(
+        result.defender_hp_distribution(),
+        vec![(22, 0.6), (30, 0.4)]
+    );
+}
+
+#[test]
+fn test_exchange_stops_when_a_side_falls() {
+    // Two hits kill the defender; it never gets its second strike after that
+    let attacker = ForecastSide::new(10, 5, 100, 2);
+    let defender = ForecastSide::new(10, 4, 100, 2);
+    let result = forecast(&attacker, &defender);
+
+    assert_eq!(result.outcomes.len(), 1);
+    let outcome = result.outcomes[0];
+    assert_eq!((outcome.attacker_hits, outcome.defender_hits), (2, 1));
+    assert_eq!((outcome.attacker_hp, outcome.defender_hp), (6, 0));
+    assert_eq!(result.kill_chance(), 1.0);
+
+    // A dead attacker stops striking too
+    let attacker = ForecastSide::new(3, 1, 100, 3);
+    let defender = ForecastSide::new(10, 3, 100, 1);
+    let result = forecast(&attacker, &defender);
+    assert_eq!(result.death_chance(), 1.0);
+    assert_eq!(result.outcomes[0].attacker_hits, 1);
+}
+
+#[test]
+fn test_kill_and_death_chances_over_many_strikes() {
+    let attacker = ForecastSide::new(12, 6, 50, 3);
+    let defender = ForecastSide::new(12, 6, 50, 3);
+    let result = forecast(&attacker, &defender);
+
+    assert!((total_probability(&result) - 1.0).abs() < 1e-6);
+    assert!(result.kill_chance() > result.death_chance());
+    let kill = result.kill_chance();
+    let survive: f32 = result
+        .defender_hp_distribution()
+        .iter()
+        .filter(|(hp, _)| *hp > 0)
+        .map(|(_, p)| p)
+        .sum();
+    assert!((kill + survive - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_rolled_exchange_matches_a_forecast_outcome() {
+    let attacker = ForecastSide::new(15, 4, 70, 4);
+    let defender = ForecastSide::new(14, 5, 40, 2);
+    let result = forecast(&attacker, &defender);
+
+    // Deterministic dice: hit on every other roll
+    let mut next = false;
+    let hits = roll_exchange(&attacker, &defender, |_| {
+        next = !next;
+        next
+    });
+    assert!(result
+        .outcomes
+        .iter()
+        .any(|o| (o.attacker_hits, o.defender_hits) == hits));
+
+    // Never hitting leaves both sides untouched
+    let hits = roll_exchange(&attacker, &defender, |_| false);
+    assert_eq!(hits, (0, 0));
+}
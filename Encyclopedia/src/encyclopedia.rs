@@ -1,6 +1,7 @@
 //! Main Encyclopedia system that dynamically generates content at runtime
 
-use crate::entries::{EncyclopediaEntry, MechanicEntry, TerrainEntry, UnitEntry};
+use crate::entries::{EncyclopediaEntry, ItemEntry, MechanicEntry, TerrainEntry, UnitEntry};
+use items::{DamageType, ItemRegistry, ItemType};
 use std::collections::HashMap;
 use units::{Race, Terrain, UnitFactory};
 
@@ -9,6 +10,7 @@ pub struct Encyclopedia {
     units: HashMap<String, UnitEntry>,
     terrain: HashMap<String, TerrainEntry>,
     mechanics: HashMap<String, MechanicEntry>,
+    items: HashMap<String, ItemEntry>,
 }
 
 impl Encyclopedia {
@@ -18,6 +20,7 @@ impl Encyclopedia {
             units: HashMap::new(),
             terrain: HashMap::new(),
             mechanics: HashMap::new(),
+            items: HashMap::new(),
         };
 
         // Load all units dynamically from the registry
@@ -29,9 +32,22 @@ impl Encyclopedia {
         // Load game mechanics
         encyclopedia.load_mechanics();
 
+        // Load all items from the item registry
+        encyclopedia.load_items(ItemRegistry::builtin());
+
         encyclopedia
     }
 
+    /// Load every item of an item registry
+    fn load_items(&mut self, registry: &ItemRegistry) {
+        for definition in registry.all() {
+            self.items.insert(
+                definition.id.clone(),
+                ItemEntry::from_definition(definition),
+            );
+        }
+    }
+
     /// Dynamically load all registered units
     fn load_units(&mut self) {
         let unit_types = UnitFactory::list_types();
@@ -132,14 +148,10 @@ impl Encyclopedia {
                 "Damage Types".to_string(),
                 "Combat".to_string(),
                 "Different attacks deal different damage types, which interact with unit resistances.".to_string(),
-                vec![
-                    "Slash: Swords, blades (good vs light armor)".to_string(),
-                    "Pierce: Arrows, spears (penetrates armor)".to_string(),
-                    "Blunt: Hammers, clubs (good vs heavy armor)".to_string(),
-                    "Crush: Overwhelming force attacks".to_string(),
-                    "Fire: Magical fire damage".to_string(),
-                    "Dark: Magical dark/shadow damage".to_string(),
-                ],
+                DamageType::registry()
+                    .iter()
+                    .map(|info| format!("{} ({:?}): {}", info.name, info.category, info.description))
+                    .collect(),
                 vec![
                     "Elf Archer's pierce damage vs heavily armored Dwarf".to_string(),
                     "Human Mage's fire spell vs fire-resistant unit".to_string(),
@@ -156,6 +168,7 @@ impl Encyclopedia {
                 "Units have resistance percentages that reduce incoming damage from specific damage types.".to_string(),
                 vec![
                     "Resistance % reduces damage by that amount".to_string(),
+                    "Negative resistance is a weakness and increases damage".to_string(),
                     "Heavy armor units have high slash/crush resistance".to_string(),
                     "Light armor units have lower physical resistance".to_string(),
                     "Some races naturally resist certain elements".to_string(),
@@ -227,6 +240,11 @@ impl Encyclopedia {
         self.mechanics.get(name)
     }
 
+    /// Get an item entry by item id
+    pub fn get_item_entry(&self, id: &str) -> Option<&ItemEntry> {
+        self.items.get(id)
+    }
+
     /// Get all unit entries
     pub fn all_units(&self) -> Vec<&UnitEntry> {
         self.units.values().collect()
@@ -242,6 +260,25 @@ impl Encyclopedia {
         self.mechanics.values().collect()
     }
 
+    /// Get all item entries, sorted by type, rarity and name
+    pub fn all_items(&self) -> Vec<&ItemEntry> {
+        let type_order = |t: &ItemType| match t {
+            ItemType::Weapon => 0,
+            ItemType::Armor => 1,
+            ItemType::Accessory => 2,
+            ItemType::Consumable => 3,
+        };
+        let mut items: Vec<_> = self.items.values().collect();
+        items.sort_by(|a, b| {
+            (type_order(&a.item_type), a.rarity, &a.name).cmp(&(
+                type_order(&b.item_type),
+                b.rarity,
+                &b.name,
+            ))
+        });
+        items
+    }
+
     /// Search units by race
     pub fn units_by_race(&self, race: Race) -> Vec<&UnitEntry> {
         self.units.values().filter(|u| u.race == race).collect()
@@ -284,6 +321,13 @@ impl Encyclopedia {
             self.mechanics.len()
         );
         println!("║                                                                       ║");
+        println!("║  4. ITEMS                                                             ║");
+        println!("║     Weapons, armor, accessories and consumables                       ║");
+        println!(
+            "║     {} items                                                         ║",
+            self.items.len()
+        );
+        println!("║                                                                       ║");
         println!("╚═══════════════════════════════════════════════════════════════════════╝");
     }
 
@@ -368,6 +412,37 @@ impl Encyclopedia {
         println!("╚═══════════════════════════════════════════════════════════════════════╝");
     }
 
+    /// Display the item index, grouped by item type
+    pub fn display_item_index(&self) {
+        println!("\n╔═══════════════════════════════════════════════════════════════════════╗");
+        println!("║                        🗡️  ITEM CATALOGUE                              ║");
+        println!("╠═══════════════════════════════════════════════════════════════════════╣");
+
+        let mut current_type = None;
+        for item in self.all_items() {
+            if current_type != Some(item.item_type.clone()) {
+                current_type = Some(item.item_type.clone());
+                println!(
+                    "║                                                                       ║"
+                );
+                println!("║ {:<71} ║", item.item_type.name().to_uppercase());
+                println!("║ {:<71} ║", "─".repeat(71));
+            }
+            println!(
+                "║   • {:<66} ║",
+                format!(
+                    "{} ({}, {} gold)",
+                    item.name,
+                    item.rarity.name(),
+                    item.value
+                )
+            );
+        }
+
+        println!("║                                                                       ║");
+        println!("╚═══════════════════════════════════════════════════════════════════════╝");
+    }
+
     /// Search the encyclopedia
     pub fn search(&self, query: &str) -> Vec<EncyclopediaEntry> {
         let query_lower = query.to_lowercase();
@@ -405,6 +480,16 @@ impl Encyclopedia {
             }
         }
 
+        // Search items
+        for item in self.items.values() {
+            if item.name.to_lowercase().contains(&query_lower)
+                || item.id.contains(&query_lower)
+                || item.description.to_lowercase().contains(&query_lower)
+            {
+                results.push(EncyclopediaEntry::Item(item.clone()));
+            }
+        }
+
         results
     }
 }
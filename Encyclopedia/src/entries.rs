@@ -1,6 +1,7 @@
 //! Encyclopedia entry types for different content categories
 
-use combat::{DamageType, RangeCategory, Resistances};
+use combat::{DamageType, RangeType, Resistances};
+use items::{ConsumableEffect, ItemDefinition, ItemProperties, ItemType, Rarity};
 use units::{Race, Terrain, UnitFactory, UnitType};
 
 /// Main encyclopedia entry type
@@ -12,6 +13,8 @@ pub enum EncyclopediaEntry {
     Terrain(TerrainEntry),
     /// Game mechanic explanation entry
     Mechanic(MechanicEntry),
+    /// Item information entry
+    Item(ItemEntry),
 }
 
 impl EncyclopediaEntry {
@@ -21,6 +24,7 @@ impl EncyclopediaEntry {
             EncyclopediaEntry::Unit(entry) => &entry.name,
             EncyclopediaEntry::Terrain(entry) => entry.terrain_type.name(),
             EncyclopediaEntry::Mechanic(entry) => &entry.title,
+            EncyclopediaEntry::Item(entry) => &entry.name,
         }
     }
 
@@ -30,6 +34,7 @@ impl EncyclopediaEntry {
             EncyclopediaEntry::Unit(_) => "Units",
             EncyclopediaEntry::Terrain(_) => "Terrain",
             EncyclopediaEntry::Mechanic(_) => "Mechanics",
+            EncyclopediaEntry::Item(_) => "Items",
         }
     }
 
@@ -39,6 +44,7 @@ impl EncyclopediaEntry {
             EncyclopediaEntry::Unit(entry) => entry.display(),
             EncyclopediaEntry::Terrain(entry) => entry.display(),
             EncyclopediaEntry::Mechanic(entry) => entry.display(),
+            EncyclopediaEntry::Item(entry) => entry.display(),
         }
     }
 }
@@ -64,7 +70,7 @@ pub struct UnitStats {
     pub max_health: i32,
     pub attack_strength: u32,
     pub movement_speed: i32,
-    pub range_category: RangeCategory,
+    pub range_category: RangeType,
     pub resistances: Resistances,
     pub defense: u8,
 }
@@ -191,22 +197,20 @@ impl UnitEntry {
         );
         println!("╠═══════════════════════════════════════════════════════════════════════╣");
         println!("║ RESISTANCES                                                           ║");
-        println!(
-            "║   Blunt: {:>3}%  Pierce: {:>3}%  Slash: {:>3}%  Crush: {:>3}%           ║",
-            self.stats.resistances.blunt,
-            self.stats.resistances.pierce,
-            self.stats.resistances.slash,
-            self.stats.resistances.crush
-        );
-        println!(
-            "║   Fire: {:>4}%  Dark: {:>6}%                                        ║",
-            self.stats.resistances.fire, self.stats.resistances.dark
-        );
+        let resistances: Vec<String> = self
+            .stats
+            .resistances
+            .iter()
+            .map(|(damage_type, value)| format!("{}: {:>4}%", damage_type.name(), value))
+            .collect();
+        for row in resistances.chunks(4) {
+            println!("║   {:<67} ║", row.join("  "));
+        }
         println!("╠═══════════════════════════════════════════════════════════════════════╣");
         println!("║ ATTACKS                                                               ║");
         for attack in &self.attacks {
             println!(
-                "║   • {} (Damage: {}, Range: {}, Type: {:?})                    ",
+                "║   • {} (Damage: {}, Range: {}, Type: {})                    ",
                 attack.name, attack.damage, attack.range, attack.damage_type
             );
         }
@@ -437,3 +441,137 @@ impl MechanicEntry {
         println!("╚═══════════════════════════════════════════════════════════════════════╝");
     }
 }
+
+/// Item information entry, generated from the item registry
+#[derive(Debug, Clone)]
+pub struct ItemEntry {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub item_type: ItemType,
+    pub rarity: Rarity,
+    pub value: u32,
+    /// What the item does, one line per effect
+    pub effects: Vec<String>,
+    /// Who may equip the item, one line per requirement
+    pub requirements: Vec<String>,
+}
+
+impl ItemEntry {
+    /// Create an item entry from an item definition
+    pub fn from_definition(definition: &ItemDefinition) -> Self {
+        let mut effects = Vec::new();
+        let mut bonus = |amount: i32, stat: &str| {
+            if amount != 0 {
+                effects.push(format!("{:+} {}", amount, stat));
+            }
+        };
+        match &definition.properties {
+            ItemProperties::Weapon {
+                attack_bonus,
+                range_modifier,
+                range_type_override,
+                attacks,
+            } => {
+                bonus(*attack_bonus, "attack");
+                bonus(*range_modifier, "range");
+                if let Some(range_type) = range_type_override {
+                    effects.push(format!("{} weapon", range_type));
+                }
+                for attack in attacks {
+                    effects.push(format!(
+                        "{}: {} dmg × {} ({:?})",
+                        attack.name, attack.damage, attack.attack_times, attack.damage_type
+                    ));
+                }
+            }
+            ItemProperties::Armor {
+                defense_bonus,
+                movement_penalty,
+            } => {
+                bonus(*defense_bonus, "defense");
+                bonus(-*movement_penalty, "movement");
+            }
+            ItemProperties::Accessory {
+                health_bonus,
+                attack_bonus,
+                defense_bonus,
+                movement_bonus,
+            } => {
+                bonus(*health_bonus, "max health");
+                bonus(*attack_bonus, "attack");
+                bonus(*defense_bonus, "defense");
+                bonus(*movement_bonus, "movement");
+            }
+            ItemProperties::Consumable { uses, effect } => {
+                effects.push(match effect {
+                    ConsumableEffect::Heal { amount } => format!("Heals {} HP", amount),
+                    ConsumableEffect::Buff {
+                        attack_bonus,
+                        defense_bonus,
+                        duration,
+                    } => format!(
+                        "{:+} attack, {:+} defense for {} turns",
+                        attack_bonus, defense_bonus, duration
+                    ),
+                    ConsumableEffect::Restore { health } => format!("Restores {} HP", health),
+                });
+                effects.push(format!("{} use(s)", uses));
+            }
+        }
+
+        let mut requirements = Vec::new();
+        let needs = &definition.requirements;
+        if needs.min_level > 1 {
+            requirements.push(format!("Level {}+", needs.min_level));
+        }
+        if !needs.races.is_empty() {
+            requirements.push(format!("Race: {}", needs.races.join(", ")));
+        }
+        if !needs.classes.is_empty() {
+            requirements.push(format!("Class: {}", needs.classes.join(", ")));
+        }
+
+        ItemEntry {
+            id: definition.id.clone(),
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+            item_type: definition.item_type(),
+            rarity: definition.rarity,
+            value: definition.value,
+            effects,
+            requirements,
+        }
+    }
+
+    /// Display this item entry
+    pub fn display(&self) {
+        println!("╔═══════════════════════════════════════════════════════════════════════╗");
+        println!("║  🗡️  ITEM: {:<62} ║", self.name);
+        println!(
+            "║  {:<70} ║",
+            format!(
+                "{} {} | {} gold | id: {}",
+                self.rarity.name(),
+                self.item_type.name(),
+                self.value,
+                self.id
+            )
+        );
+        println!("╠═══════════════════════════════════════════════════════════════════════╣");
+        println!("║ {:<71} ║", self.description);
+        println!("╠═══════════════════════════════════════════════════════════════════════╣");
+        println!("║ EFFECTS                                                               ║");
+        for effect in &self.effects {
+            println!("║   • {:<68} ║", effect);
+        }
+        if !self.requirements.is_empty() {
+            println!("╠═══════════════════════════════════════════════════════════════════════╣");
+            println!("║ REQUIREMENTS                                                          ║");
+            for requirement in &self.requirements {
+                println!("║   • {:<68} ║", requirement);
+            }
+        }
+        println!("╚═══════════════════════════════════════════════════════════════════════╝");
+    }
+}
@@ -8,6 +8,7 @@
 //! - **Unit Encyclopedia**: Automatically discovers and documents all registered units
 //! - **Terrain Guide**: Complete information about terrain types and their effects
 //! - **Mechanics Reference**: Combat systems, experience, equipment, and more
+//! - **Item Catalogue**: Every item of the item registry with its effects and requirements
 //! - **Dynamic Generation**: All content generated at runtime from actual game data
 //! - **Search & Filter**: Find entries by category, race, class, or keywords
 //!
@@ -35,4 +36,4 @@ pub mod entries;
 pub mod formatters;
 
 pub use encyclopedia::Encyclopedia;
-pub use entries::{EncyclopediaEntry, MechanicEntry, TerrainEntry, UnitEntry};
+pub use entries::{EncyclopediaEntry, ItemEntry, MechanicEntry, TerrainEntry, UnitEntry};
@@ -42,7 +42,7 @@ fn main() {
     }
 
     let young_warrior_boxed: Box<dyn Unit> = young_warrior;
-    let player_unit = GameUnit::new_with_team(young_warrior_boxed, Team::Player);
+    let player_unit = GameUnit::new_with_team(young_warrior_boxed, Team::PLAYER);
     let player_id = player_unit.id();
     world.add_unit(player_unit);
 
@@ -54,7 +54,7 @@ fn main() {
     println!("  • HP: {}", enemy1.combat_stats().health);
 
     let enemy1_boxed: Box<dyn Unit> = Box::new(enemy1);
-    let mut enemy1_unit = GameUnit::new_with_team(enemy1_boxed, Team::Enemy);
+    let mut enemy1_unit = GameUnit::new_with_team(enemy1_boxed, Team::ENEMY);
     let enemy1_id = enemy1_unit.id();
 
     // Weaken enemy so Thorin can kill it in one hit
@@ -122,7 +122,7 @@ fn main() {
     println!("  • HP: {}", enemy2.combat_stats().health);
 
     let enemy2_boxed: Box<dyn Unit> = Box::new(enemy2);
-    let mut enemy2_unit = GameUnit::new_with_team(enemy2_boxed, Team::Enemy);
+    let mut enemy2_unit = GameUnit::new_with_team(enemy2_boxed, Team::ENEMY);
     let enemy2_id = enemy2_unit.id();
 
     // Weaken enemy so Thorin can kill it
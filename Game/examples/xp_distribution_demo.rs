@@ -32,7 +32,7 @@ fn main() {
         attacker.level(),
         attacker.experience()
     );
-    let attacker_unit = GameUnit::new_with_team(attacker, Team::Player);
+    let attacker_unit = GameUnit::new_with_team(attacker, Team::PLAYER);
     let attacker_id = attacker_unit.id();
     world.add_unit(attacker_unit);
 
@@ -44,7 +44,7 @@ fn main() {
         ally1.level(),
         ally1.experience()
     );
-    let ally1_unit = GameUnit::new_with_team(ally1, Team::Player);
+    let ally1_unit = GameUnit::new_with_team(ally1, Team::PLAYER);
     let ally1_id = ally1_unit.id();
     world.add_unit(ally1_unit);
 
@@ -56,7 +56,7 @@ fn main() {
         ally2.level(),
         ally2.experience()
     );
-    let ally2_unit = GameUnit::new_with_team(ally2, Team::Player);
+    let ally2_unit = GameUnit::new_with_team(ally2, Team::PLAYER);
     let ally2_id = ally2_unit.id();
     world.add_unit(ally2_unit);
 
@@ -68,7 +68,7 @@ fn main() {
         distant.level(),
         distant.experience()
     );
-    let distant_unit = GameUnit::new_with_team(distant, Team::Player);
+    let distant_unit = GameUnit::new_with_team(distant, Team::PLAYER);
     let distant_id = distant_unit.id();
     world.add_unit(distant_unit);
 
@@ -82,7 +82,7 @@ fn main() {
         enemy.combat_stats().health
     );
     let enemy_boxed: Box<dyn Unit> = Box::new(enemy);
-    let mut enemy_unit = GameUnit::new_with_team(enemy_boxed, Team::Enemy);
+    let mut enemy_unit = GameUnit::new_with_team(enemy_boxed, Team::ENEMY);
     let enemy_id = enemy_unit.id();
 
     // Weaken the enemy so one hit will kill it
@@ -0,0 +1,260 @@
+//! # Consumables
+//!
+//! Using consumable items from a unit's inventory in a [`ScenarioWorld`].
+//!
+//! A unit may use one consumable per turn, on itself or on an adjacent ally;
+//! doing so takes the place of its attack. Each use spends one of the item's
+//! uses and the item is removed from the inventory once none are left.
+//!
+//! - [`ConsumableEffect::Heal`] and [`ConsumableEffect::Restore`] heal the
+//!   target, up to its maximum health
+//! - [`ConsumableEffect::Buff`] grants timed attack and defense buffs, which
+//!   are counted down by the status-effect tracker (see [`crate::status_effects`])
+//!
+//! AI teams drink healing consumables when a unit is low on health
+//! (see [`LOW_HEALTH_PERCENT`]).
+
+use crate::events::GameEvent;
+use crate::objects::{GameObject, Team};
+use crate::progression::heal;
+use crate::scenario_instance::ScenarioWorld;
+use items::ItemId;
+use units::ability::{ActiveEffect, ActiveEffectInstance, BuffStat};
+use units::ConsumableEffect;
+use uuid::Uuid;
+
+/// Health (in percent of maximum) below which a unit counts as low on health.
+pub const LOW_HEALTH_PERCENT: i32 = 50;
+
+impl ScenarioWorld {
+    /// Uses a consumable from a unit's inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - UUID of the unit holding the item
+    /// * `item_id` - Id of the consumable in the user's inventory
+    /// * `target_id` - UUID of the unit receiving the effect: the user itself
+    ///   or an adjacent ally
+    ///
+    /// # Returns
+    ///
+    /// A description of what the item did, or `Err(String)` if the user has
+    /// already acted this turn, the item is not a usable consumable, the
+    /// target is out of reach, or a healing item is used on a healthy unit
+    pub fn use_item(
+        &mut self,
+        user_id: Uuid,
+        item_id: ItemId,
+        target_id: Uuid,
+    ) -> Result<String, String> {
+        let user = self.units.get(&user_id).ok_or("Unit not found")?;
+        let target = self.units.get(&target_id).ok_or("Target not found")?;
+
+        if user.unit().combat_stats().attacked_this_turn {
+            return Err(format!("{} has already acted this turn", user.name()));
+        }
+        let item = user
+            .unit()
+            .inventory()
+            .iter()
+            .find(|item| item.id == item_id)
+            .ok_or("Item not found in inventory")?;
+        let effect = item
+            .consumable_effect()
+            .ok_or_else(|| format!("{} is not a consumable", item.name))?
+            .clone();
+        if item.remaining_uses().unwrap_or(0) <= 0 {
+            return Err(format!("{} has no uses left", item.name));
+        }
+        if target_id != user_id {
+            if user.position().distance(target.position()) != 1 {
+                return Err("Items can only be used on adjacent units".to_string());
+            }
+            if !self.teams.are_allied(user.team(), target.team()) {
+                return Err("Items can only be used on allies".to_string());
+            }
+        }
+        let stats = target.unit().combat_stats();
+        if matches!(
+            effect,
+            ConsumableEffect::Heal { .. } | ConsumableEffect::Restore { .. }
+        ) && stats.health >= stats.max_health
+        {
+            return Err(format!("{} is already at full health", target.name()));
+        }
+
+        let user_name = user.name();
+        let user_team = user.team();
+        let target_name = target.name();
+
+        // Spend the use, dropping the item once it is used up
+        let user = self.units.get_mut(&user_id).ok_or("Unit not found")?;
+        user.unit_mut().combat_stats_mut().attacked_this_turn = true;
+        let inventory = user.unit_mut().inventory_mut();
+        let slot = inventory
+            .iter()
+            .position(|item| item.id == item_id)
+            .ok_or("Item not found in inventory")?;
+        let item_name = inventory[slot].name.clone();
+        inventory[slot].consume_use();
+        if inventory[slot].remaining_uses() == Some(0) {
+            inventory.remove(slot);
+        }
+        self.emit(GameEvent::ItemUsed {
+            unit_id: user_id,
+            team: user_team,
+            item_name: item_name.clone(),
+            target_id,
+        });
+
+        let target = self.units.get_mut(&target_id).ok_or("Target not found")?;
+        let result = match effect {
+            ConsumableEffect::Heal { amount } | ConsumableEffect::Restore { health: amount } => {
+                let before = target.unit().combat_stats().health;
+                heal(target.unit_mut(), amount);
+                let restored = target.unit().combat_stats().health - before;
+                let position = target.position();
+                self.emit(GameEvent::UnitHealed {
+                    unit_id: target_id,
+                    position,
+                    amount: restored as u32,
+                });
+                format!("restored {} HP", restored)
+            }
+            ConsumableEffect::Buff {
+                attack_bonus,
+                defense_bonus,
+                duration,
+            } => {
+                let duration = duration.max(1) as u32;
+                for (stat, amount) in [
+                    (BuffStat::Attack, attack_bonus),
+                    (BuffStat::Defense, defense_bonus),
+                ] {
+                    if amount == 0 {
+                        continue;
+                    }
+                    let effect = ActiveEffect::Buff {
+                        stat,
+                        amount,
+                        duration,
+                    };
+                    target.unit_mut().ability_state_mut().add_effect(
+                        Uuid::new_v4(),
+                        ActiveEffectInstance::new(effect, duration, Some(user_id)),
+                    );
+                }
+                format!(
+                    "attack {:+}, defense {:+} for {} turns",
+                    attack_bonus, defense_bonus, duration
+                )
+            }
+        };
+
+        let message = if target_id == user_id {
+            format!("{} used {}: {}", user_name, item_name, result)
+        } else {
+            format!(
+                "{} used {} on {}: {}",
+                user_name, item_name, target_name, result
+            )
+        };
+        Ok(message)
+    }
+
+    /// Units a unit could use an item on: itself and its adjacent allies.
+    ///
+    /// # Returns
+    ///
+    /// The unit's own id first, then its adjacent allies sorted by id; empty
+    /// if the unit does not exist
+    pub fn item_targets(&self, user_id: Uuid) -> Vec<Uuid> {
+        let Some(user) = self.units.get(&user_id) else {
+            return Vec::new();
+        };
+        let mut allies: Vec<Uuid> = self
+            .units
+            .values()
+            .filter(|u| u.id() != user_id)
+            .filter(|u| u.position().distance(user.position()) == 1)
+            .filter(|u| self.teams.are_allied(user.team(), u.team()))
+            .map(|u| u.id())
+            .collect();
+        allies.sort();
+        allies.insert(0, user_id);
+        allies
+    }
+
+    /// Whether a unit's health is below [`LOW_HEALTH_PERCENT`] of its maximum.
+    pub fn is_low_health(&self, unit_id: Uuid) -> bool {
+        self.units.get(&unit_id).is_some_and(|unit| {
+            let stats = unit.unit().combat_stats();
+            stats.health * 100 < stats.max_health * LOW_HEALTH_PERCENT
+        })
+    }
+
+    /// The healing consumable a unit low on health should drink, if any.
+    ///
+    /// Picks the item whose healing is closest to the health the unit is
+    /// missing, so strong potions are kept for bad wounds.
+    ///
+    /// # Returns
+    ///
+    /// The item's id, or `None` if the unit is not low on health, has already
+    /// acted this turn or carries no healing consumable
+    pub fn emergency_consumable(&self, unit_id: Uuid) -> Option<ItemId> {
+        if !self.is_low_health(unit_id) {
+            return None;
+        }
+        let unit = self.units.get(&unit_id)?.unit();
+        let stats = unit.combat_stats();
+        if stats.attacked_this_turn {
+            return None;
+        }
+        let missing = stats.max_health - stats.health;
+
+        unit.inventory()
+            .iter()
+            .filter(|item| item.remaining_uses().unwrap_or(0) > 0)
+            .filter_map(|item| match item.consumable_effect()? {
+                ConsumableEffect::Heal { amount }
+                | ConsumableEffect::Restore { health: amount } => {
+                    Some(((amount - missing).abs(), &item.name, item.id))
+                }
+                ConsumableEffect::Buff { .. } => None,
+            })
+            .min()
+            .map(|(_, _, id)| id)
+    }
+
+    /// Lets every unit of an AI team that is low on health drink a healing
+    /// consumable, recording each use in `trace`.
+    pub(crate) fn use_emergency_consumables(&mut self, team: Team, trace: &mut ai::AiTrace) {
+        let mut unit_ids: Vec<Uuid> = self
+            .units
+            .values()
+            .filter(|u| u.team() == team)
+            .map(|u| u.id())
+            .collect();
+        unit_ids.sort();
+
+        for unit_id in unit_ids {
+            let Some(item_id) = self.emergency_consumable(unit_id) else {
+                continue;
+            };
+            let item_name = self.units[&unit_id]
+                .unit()
+                .inventory()
+                .iter()
+                .find(|item| item.id == item_id)
+                .map(|item| item.name.clone())
+                .unwrap_or_default();
+            let result = self.use_item(unit_id, item_id, unit_id).map(|_| ());
+            trace.record_command(
+                Some(&unit_id.to_string()),
+                format!("Use {}", item_name),
+                result,
+            );
+        }
+    }
+}
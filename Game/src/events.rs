@@ -0,0 +1,191 @@
+//! # Game Events
+//!
+//! Typed events a [`ScenarioWorld`] emits as the game changes, so the
+//! presentation layer can react to what happened (combat log, animations,
+//! floating damage numbers, statistics) without diffing world state.
+//!
+//! Events collect in the world's [`EventQueue`] in the order they happen, and
+//! [`ScenarioWorld::drain_events`] takes them out, typically once per frame.
+//! The queue is shared by its clones, so closures may hold a handle and push
+//! events; a cloned `ScenarioWorld` (tactical search) gets a queue of its own,
+//! so simulated actions never reach the real world's queue.
+//!
+//! | Event | Emitted by |
+//! |-------|------------|
+//! | [`GameEvent::UnitMoved`] | `move_unit`, and a victorious attacker taking the defender's hex |
+//! | [`GameEvent::StrikeResolved`] | Every rolled strike of a combat exchange |
+//! | [`GameEvent::UnitDied`] | Units defeated in combat or by abilities |
+//! | [`GameEvent::UnitHealed`] | Healing consumables |
+//! | [`GameEvent::ItemUsed`] | `use_item` |
+//! | [`GameEvent::LeveledUp`] | Level-ups and evolutions |
+//! | [`GameEvent::ItemPickedUp`] | `pickup_item` |
+//! | [`GameEvent::ItemEquipped`] | `equip_item`, `unequip_item` and auto-equipping on pickup |
+//! | [`GameEvent::ItemDropped`] | `drop_item` |
+//! | [`GameEvent::ItemGiven`] | `give_item` |
+//! | [`GameEvent::UnitRecruited`] | `recruit_unit` |
+//! | [`GameEvent::TurnStarted`] | The start of every team's turn |
+//! | [`GameEvent::StructureDamaged`] | `damage_structure` |
+
+use crate::objects::Team;
+use crate::scenario_instance::ScenarioWorld;
+use hex::HexCoord;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Something that happened in a [`ScenarioWorld`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    /// A unit moved to another hex
+    UnitMoved {
+        unit_id: Uuid,
+        from: HexCoord,
+        to: HexCoord,
+    },
+    /// One strike of a combat exchange was rolled
+    StrikeResolved {
+        /// Unit making the strike
+        striker_id: Uuid,
+        striker_team: Team,
+        /// Unit being struck
+        target_id: Uuid,
+        target_team: Team,
+        /// Hex of the unit being struck
+        target_position: HexCoord,
+        /// Name of the attack used
+        attack_name: String,
+        /// Whether the strike is part of the defender's counter-attack
+        counter: bool,
+        hit: bool,
+        /// Damage dealt; 0 for a miss
+        damage: u32,
+    },
+    /// A unit was defeated and removed from the world
+    UnitDied {
+        unit_id: Uuid,
+        name: String,
+        team: Team,
+        position: HexCoord,
+        /// Unit that defeated it, if any
+        killer_id: Option<Uuid>,
+    },
+    /// A unit regained health
+    UnitHealed {
+        unit_id: Uuid,
+        position: HexCoord,
+        amount: u32,
+    },
+    /// A unit used a consumable on itself or an ally
+    ItemUsed {
+        unit_id: Uuid,
+        team: Team,
+        item_name: String,
+        /// Unit the item was used on; the user itself for self-use
+        target_id: Uuid,
+    },
+    /// A unit gained a level, by levelling up or evolving
+    LeveledUp {
+        unit_id: Uuid,
+        name: String,
+        team: Team,
+        level: i32,
+    },
+    /// A unit picked up an item from the ground
+    ItemPickedUp {
+        unit_id: Uuid,
+        team: Team,
+        item_name: String,
+        position: HexCoord,
+    },
+    /// A unit equipped or unequipped an item it carries
+    ItemEquipped {
+        unit_id: Uuid,
+        team: Team,
+        item_name: String,
+        /// `false` when the item went back to the inventory
+        equipped: bool,
+    },
+    /// A unit dropped an item onto its hex
+    ItemDropped {
+        unit_id: Uuid,
+        team: Team,
+        item_name: String,
+        position: HexCoord,
+    },
+    /// A unit handed an item to an adjacent ally
+    ItemGiven {
+        giver_id: Uuid,
+        receiver_id: Uuid,
+        team: Team,
+        item_name: String,
+    },
+    /// A team recruited a new unit
+    UnitRecruited {
+        unit_id: Uuid,
+        name: String,
+        team: Team,
+        position: HexCoord,
+    },
+    /// A team's turn began
+    TurnStarted { team: Team, turn: u32 },
+    /// A structure took damage
+    StructureDamaged {
+        structure_id: Uuid,
+        position: HexCoord,
+        damage: u32,
+        /// Durability left after the damage
+        durability: u32,
+        destroyed: bool,
+    },
+}
+
+/// Queue of game events waiting to be drained.
+///
+/// Clones share the same queue.
+#[derive(Clone, Debug, Default)]
+pub struct EventQueue {
+    events: Arc<Mutex<Vec<GameEvent>>>,
+}
+
+impl EventQueue {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an event to the end of the queue.
+    pub fn push(&self, event: GameEvent) {
+        self.lock().push(event);
+    }
+
+    /// Takes every queued event, oldest first, leaving the queue empty.
+    pub fn drain(&self) -> Vec<GameEvent> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Number of queued events.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no events are queued.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<GameEvent>> {
+        // A panic while holding the lock cannot leave the vector half-written
+        self.events.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ScenarioWorld {
+    /// Takes every event emitted since the last call, oldest first.
+    pub fn drain_events(&self) -> Vec<GameEvent> {
+        self.events.drain()
+    }
+
+    /// Adds an event to the world's queue.
+    pub(crate) fn emit(&self, event: GameEvent) {
+        self.events.push(event);
+    }
+}
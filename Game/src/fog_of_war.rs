@@ -0,0 +1,88 @@
+//! # Fog of War
+//!
+//! What a team can currently see of the scenario. A team sees through the
+//! eyes of its own units and structures and those of its allies; everything
+//! else on the map is fogged. Terrain that
+//! [blocks line of sight](crate::TerrainTile::blocks_line_of_sight) hides the
+//! hexes behind it (see [`hex::fov`]).
+//!
+//! A unit sees one hex further than it can move, so anything it could reach
+//! and attack this turn is visible. Structures see their neighbours, plus
+//! their [`vision_bonus`](units::structures::Structure::vision_bonus).
+//!
+//! Each team's view is computed on first use and cached until a unit or
+//! structure is added, moved or removed or the terrain changes (see
+//! [`ScenarioWorld::invalidate_visibility`]).
+
+use crate::objects::{GameObject, Team};
+use crate::scenario_instance::ScenarioWorld;
+use hex::fov::field_of_view;
+use hex::HexCoord;
+use std::collections::HashSet;
+
+impl ScenarioWorld {
+    /// Vision radius of a unit: its movement plus one hex.
+    pub fn unit_vision_range(&self, unit_id: uuid::Uuid) -> Option<u32> {
+        let unit = self.units.get(&unit_id)?;
+        let movement = unit.unit().combat_stats().movement_speed.max(0) as u32;
+        Some(movement + 1)
+    }
+
+    /// All hexes of the map that `team` or its allies can currently see.
+    ///
+    /// Hexes outside the scenario's terrain are never returned.
+    pub fn visible_hexes(&self, team: Team) -> HashSet<HexCoord> {
+        self.with_visible_hexes(team, HashSet::clone)
+    }
+
+    /// Whether `team` or one of its allies can currently see `position`.
+    pub fn is_visible_to(&self, team: Team, position: HexCoord) -> bool {
+        self.with_visible_hexes(team, |visible| visible.contains(&position))
+    }
+
+    /// Drops every cached team view so the next query recomputes it.
+    ///
+    /// Called whenever units or structures appear, move or disappear, or the
+    /// terrain changes.
+    pub(crate) fn invalidate_visibility(&mut self) {
+        self.visibility.get_mut().clear();
+    }
+
+    /// Runs `f` on the cached view of `team`, computing it first if needed.
+    fn with_visible_hexes<R>(&self, team: Team, f: impl FnOnce(&HashSet<HexCoord>) -> R) -> R {
+        let mut cache = self.visibility.borrow_mut();
+        let visible = cache
+            .entry(team)
+            .or_insert_with(|| self.compute_visible_hexes(team));
+        f(visible)
+    }
+
+    /// Computes the hexes `team` and its allies see from scratch.
+    fn compute_visible_hexes(&self, team: Team) -> HashSet<HexCoord> {
+        let blocks_sight = |hex: HexCoord| {
+            self.terrain
+                .get(&hex)
+                .is_some_and(|tile| tile.blocks_line_of_sight())
+        };
+
+        let unit_eyes = self
+            .units
+            .values()
+            .filter(|unit| self.teams.are_allied(team, unit.team()))
+            .filter_map(|unit| Some((unit.position(), self.unit_vision_range(unit.id())?)));
+        let structure_eyes = self
+            .structures
+            .values()
+            .filter(|structure| self.teams.are_allied(team, structure.team()))
+            .map(|structure| {
+                let radius = 1 + structure.vision_bonus().max(0) as u32;
+                (structure.position(), radius)
+            });
+
+        unit_eyes
+            .chain(structure_eyes)
+            .flat_map(|(origin, radius)| field_of_view(origin, radius, blocks_sight))
+            .filter(|hex| self.terrain.contains_key(hex))
+            .collect()
+    }
+}
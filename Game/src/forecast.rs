@@ -0,0 +1,178 @@
+//! # Combat Forecast
+//!
+//! Exact outcome forecasts for attacks in a [`ScenarioWorld`], built on
+//! [`combat::forecast`].
+//!
+//! In an exchange each side strikes its attacks per round times the chosen
+//! attack's strikes per use (see [`units::Attack::attack_times`]); the
+//! defender strikes with the counter-attack it answers the attack with, or
+//! not at all. Each hit deals the striker's damage after the time of day,
+//! attack buffs and the target's resistance.
+//!
+//! - [`ScenarioWorld::combat_forecast`] uses the real hit chances, and is
+//!   what the combat dialog shows for each attack option
+//! - [`ScenarioWorld::planned_forecast`] uses the same hit chances with the
+//!   attacker on any hex it could move to, and is what AI attack scoring uses
+//! - Rolled combat and tactical search play the same strike sequence, so a
+//!   forecast lists exactly the outcomes combat can produce
+
+use crate::objects::GameObject;
+use crate::scenario_instance::ScenarioWorld;
+use combat::{CombatForecast, ForecastSide};
+use hex::HexCoord;
+use units::Attack;
+use uuid::Uuid;
+
+impl ScenarioWorld {
+    /// Forecast of attacking a unit with one of the attacker's attacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `attacker_id` - UUID of the attacking unit
+    /// * `defender_id` - UUID of the defending unit
+    /// * `selected_attack_idx` - Index of the attacker's chosen attack
+    ///
+    /// # Returns
+    ///
+    /// The exact outcome distribution of the exchange, or `Err(String)` if
+    /// either unit or the attack does not exist
+    pub fn combat_forecast(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        selected_attack_idx: usize,
+    ) -> Result<CombatForecast, String> {
+        let (attacker, defender) =
+            self.exchange_sides(attacker_id, defender_id, selected_attack_idx)?;
+        Ok(combat::forecast(&attacker, &defender))
+    }
+
+    /// Forecast of attacking from a hex the attacker could move to, as used by
+    /// the AI planner.
+    ///
+    /// Hit chances are computed like in [`ScenarioWorld::combat_forecast`],
+    /// with the attacker standing on `from`; the defender's counter-attack is
+    /// chosen for the distance from there.
+    ///
+    /// # Arguments
+    ///
+    /// * `attacker_id` - The attacking unit
+    /// * `defender_id` - The unit being attacked
+    /// * `attack` - The attack being used
+    /// * `from` - Hex the attacker attacks from
+    ///
+    /// # Returns
+    ///
+    /// The estimated outcome distribution, or `None` if either unit does not
+    /// exist
+    pub fn planned_forecast(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        attack: &Attack,
+        from: HexCoord,
+    ) -> Option<CombatForecast> {
+        let defender = self.units.get(&defender_id)?;
+        let distance = from.distance(defender.position());
+        let strike = self.strike_hit_chance(defender_id, attack).ok()?;
+        let counter = match defender.unit().counter_attack(attack, distance) {
+            Some(counter) => {
+                let chance = self
+                    .strike_hit_chance_at(attacker_id, from, &counter)
+                    .ok()?;
+                Some((counter, chance.chance))
+            }
+            None => None,
+        };
+
+        let (attacker, defender) = self
+            .forecast_sides(
+                attacker_id,
+                defender_id,
+                (attack, strike.chance),
+                counter.as_ref().map(|(counter, chance)| (counter, *chance)),
+            )
+            .ok()?;
+        Some(combat::forecast(&attacker, &defender))
+    }
+
+    /// Damage of one hit by a unit on another: the attack's strike damage
+    /// reduced by the target's resistance to its damage type (at least 1).
+    pub(crate) fn hit_damage(
+        &self,
+        striker_id: Uuid,
+        target_id: Uuid,
+        attack: &Attack,
+    ) -> Result<u32, String> {
+        let target = self.units.get(&target_id).ok_or("Target not found")?;
+        let resistance_multiplier = target
+            .unit()
+            .combat_stats()
+            .resistances
+            .damage_multiplier(attack.damage_type);
+        let damage = self.strike_damage(striker_id, attack.damage);
+        Ok(((damage as f32 * resistance_multiplier) as i32).max(1) as u32)
+    }
+
+    /// Both sides of an exchange with the selected attack, with the real hit
+    /// chances (see [`ScenarioWorld::combat_hit_chances`]).
+    pub(crate) fn exchange_sides(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        selected_attack_idx: usize,
+    ) -> Result<(ForecastSide, ForecastSide), String> {
+        let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
+        let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+        let attack = attacker
+            .unit()
+            .get_attacks()
+            .get(selected_attack_idx)
+            .ok_or("Selected attack not found")?
+            .clone();
+        let distance = attacker.position().distance(defender.position());
+        let counter = defender.unit().counter_attack(&attack, distance);
+
+        let (attack_chance, counter_chance) =
+            self.exchange_hit_chances(attacker_id, defender_id, selected_attack_idx)?;
+        let counter = counter.as_ref().zip(counter_chance);
+        self.forecast_sides(attacker_id, defender_id, (&attack, attack_chance), counter)
+    }
+
+    /// Both sides of an exchange from each side's attack and hit chance; a
+    /// defender without a counter-attack does not strike.
+    fn forecast_sides(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        (attack, attack_chance): (&Attack, u8),
+        counter: Option<(&Attack, u8)>,
+    ) -> Result<(ForecastSide, ForecastSide), String> {
+        let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
+        let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+        let hp = |unit: &crate::GameUnit| unit.unit().combat_stats().health.max(0) as u32;
+        let strikes = |unit: &crate::GameUnit, attack: &Attack| {
+            ForecastSide::strikes_for(
+                unit.unit().combat_stats().attacks_per_round,
+                attack.attack_times,
+            )
+        };
+
+        let attacker_side = ForecastSide::new(
+            hp(attacker),
+            self.hit_damage(attacker_id, defender_id, attack)?,
+            attack_chance,
+            strikes(attacker, attack),
+        );
+        let defender_side = match counter {
+            Some((counter, counter_chance)) => ForecastSide::new(
+                hp(defender),
+                self.hit_damage(defender_id, attacker_id, counter)?,
+                counter_chance,
+                strikes(defender, counter),
+            ),
+            None => ForecastSide::new(hp(defender), 0, 0, 0),
+        };
+        Ok((attacker_side, defender_side))
+    }
+}
@@ -0,0 +1,137 @@
+//! # Hit Chances
+//!
+//! Computes the chance for each strike of a combat exchange to hit, from the
+//! defender's point of view (see [`units::combat::hit_chance`]):
+//!
+//! - The defender's hit chance on the terrain of its current hex
+//! - Minus the defense bonus of a structure standing on that hex
+//! - Adjusted by the defender's active defense/dodge buffs and debuffs
+//! - Magical attacks always hit with [`MAGIC_HIT_CHANCE`](units::combat::MAGIC_HIT_CHANCE)
+//!
+//! The full [`HitChanceBreakdown`] is exposed so the combat confirmation dialog
+//! can explain the numbers. Each unit's cached
+//! [`CombatStats::terrain_hit_chance`](units::CombatStats) is kept in sync with
+//! the terrain it stands on.
+
+use crate::objects::{GameObject, TerrainTile};
+use crate::scenario_instance::ScenarioWorld;
+use hex::HexCoord;
+use units::combat::HitChanceBreakdown;
+use units::{Attack, Terrain, TerrainRegistry};
+use uuid::Uuid;
+
+impl ScenarioWorld {
+    /// Computes the chance for `attack` to hit a unit where it currently stands.
+    ///
+    /// # Arguments
+    ///
+    /// * `defender_id` - UUID of the unit being struck
+    /// * `attack` - The attack being used
+    ///
+    /// # Returns
+    ///
+    /// The hit chance breakdown, or `Err(String)` if the defender does not exist
+    pub fn strike_hit_chance(
+        &self,
+        defender_id: Uuid,
+        attack: &Attack,
+    ) -> Result<HitChanceBreakdown, String> {
+        let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+        self.strike_hit_chance_at(defender_id, defender.position(), attack)
+    }
+
+    /// Computes the chance for `attack` to hit a unit if it stood on
+    /// `position`, as for a planned move.
+    ///
+    /// # Returns
+    ///
+    /// The hit chance breakdown, or `Err(String)` if the defender does not exist
+    pub(crate) fn strike_hit_chance_at(
+        &self,
+        defender_id: Uuid,
+        position: HexCoord,
+        attack: &Attack,
+    ) -> Result<HitChanceBreakdown, String> {
+        let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+        let (terrain_name, defense_class) = self.defense_terrain(position);
+        let structure = self
+            .get_structure_at_position(position)
+            .filter(|s| !s.is_destroyed());
+
+        Ok(HitChanceBreakdown::for_strike(
+            defender.unit(),
+            &terrain_name,
+            defense_class,
+            structure,
+            attack,
+        ))
+    }
+
+    /// Computes the hit chances of one exchange with the selected attack.
+    ///
+    /// # Arguments
+    ///
+    /// * `attacker_id` - UUID of the attacking unit
+    /// * `defender_id` - UUID of the defending unit
+    /// * `selected_attack_idx` - Index of the attacker's chosen attack
+    ///
+    /// # Returns
+    ///
+    /// The attacker's strike breakdown and the defender's counter-attack
+    /// breakdown, which is `None` when the defender has no attack of the same
+    /// range type that reaches the attacker (see [`units::Attack::can_retaliate`])
+    pub fn combat_hit_chances(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        selected_attack_idx: usize,
+    ) -> Result<(HitChanceBreakdown, Option<HitChanceBreakdown>), String> {
+        let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
+        let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+        let attacks = attacker.unit().get_attacks();
+        let selected_attack = attacks
+            .get(selected_attack_idx)
+            .ok_or("Selected attack not found")?;
+
+        let strike = self.strike_hit_chance(defender_id, selected_attack)?;
+        let distance = attacker.position().distance(defender.position());
+        let counter = match defender.unit().counter_attack(selected_attack, distance) {
+            Some(counter_attack) => Some(self.strike_hit_chance(attacker_id, &counter_attack)?),
+            None => None,
+        };
+
+        Ok((strike, counter))
+    }
+
+    /// Updates a unit's cached terrain hit chance for the hex it stands on.
+    pub(crate) fn refresh_terrain_hit_chance(&mut self, unit_id: Uuid) {
+        let Some(position) = self.units.get(&unit_id).map(|u| u.position()) else {
+            return;
+        };
+        let (_, defense_class) = self.defense_terrain(position);
+        if let Some(unit) = self.units.get_mut(&unit_id) {
+            let chance = unit.unit().get_terrain_hit_chance(defense_class);
+            unit.unit_mut()
+                .combat_stats_mut()
+                .set_terrain_hit_chance(chance);
+        }
+    }
+
+    /// Name and defense class of the terrain at `position`.
+    fn defense_terrain(&self, position: HexCoord) -> (String, Terrain) {
+        tile_defense(self.terrain.get(&position))
+    }
+}
+
+/// Name and defense class of a terrain tile.
+///
+/// A missing tile counts as the default terrain.
+pub(crate) fn tile_defense(tile: Option<&TerrainTile>) -> (String, Terrain) {
+    match tile {
+        Some(tile) => (tile.terrain().name.clone(), tile.defense_class()),
+        None => TerrainRegistry::builtin()
+            .default_terrain()
+            .map(|t| (t.name.clone(), t.defense_class))
+            .unwrap_or(("Grasslands".to_string(), Terrain::Grasslands)),
+    }
+}
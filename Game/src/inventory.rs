@@ -0,0 +1,242 @@
+//! # Inventory Management
+//!
+//! Equipping, unequipping, dropping and handing over the items a unit carries
+//! in a [`ScenarioWorld`].
+//!
+//! A unit's carried items are its equipped items (weapon, armor, then
+//! accessories) followed by its inventory; [`ScenarioWorld::carried_items`]
+//! lists them in that order, and lockstep commands address items by their
+//! index in it.
+//!
+//! - Equipping and unequipping are free and may be done any number of times
+//! - A dropped item is left on the unit's hex as an item pickup
+//! - Giving an item to an adjacent ally takes the place of the giver's attack
+
+use crate::events::GameEvent;
+use crate::objects::{GameObject, InteractiveObject};
+use crate::scenario_instance::ScenarioWorld;
+use items::{EquipmentDelta, Item, ItemId, ItemType};
+use uuid::Uuid;
+
+impl ScenarioWorld {
+    /// Items a unit carries: its equipped items first, then its inventory.
+    ///
+    /// # Returns
+    ///
+    /// Each item with whether it is equipped; empty if the unit does not exist
+    pub fn carried_items(&self, unit_id: Uuid) -> Vec<(&Item, bool)> {
+        let Some(unit) = self.units.get(&unit_id) else {
+            return Vec::new();
+        };
+        let equipped = unit.unit().equipment().get_all_equipped();
+        let inventory = unit.unit().inventory();
+        equipped
+            .into_iter()
+            .map(|item| (item, true))
+            .chain(inventory.iter().map(|item| (item, false)))
+            .collect()
+    }
+
+    /// Equips an item from a unit's inventory.
+    ///
+    /// An item displaced from the same slot goes back to the inventory.
+    ///
+    /// # Returns
+    ///
+    /// A description of the change, or `Err(String)` if the item is not in
+    /// the inventory, is a consumable, or the unit does not meet its
+    /// requirements
+    pub fn equip_item(&mut self, unit_id: Uuid, item_id: ItemId) -> Result<String, String> {
+        let unit = self.units.get_mut(&unit_id).ok_or("Unit not found")?;
+        let item = unit
+            .unit()
+            .inventory()
+            .iter()
+            .find(|item| item.id == item_id)
+            .ok_or("Item not found in inventory")?;
+        if item.item_type == ItemType::Consumable {
+            return Err(format!("{} cannot be equipped", item.name));
+        }
+        let delta = unit.unit().equipment().delta_if_equipped(item);
+        let item_name = item.name.clone();
+
+        unit.unit_mut().equip_item(item_id)?;
+        let message = format!(
+            "{} equipped {} ({})",
+            unit.name(),
+            item_name,
+            delta.summary()
+        );
+        let event = GameEvent::ItemEquipped {
+            unit_id,
+            team: unit.team(),
+            item_name,
+            equipped: true,
+        };
+        self.emit(event);
+        Ok(message)
+    }
+
+    /// Unequips one of a unit's equipped items into its inventory.
+    ///
+    /// # Returns
+    ///
+    /// A description of the change, or `Err(String)` if the item is not
+    /// equipped
+    pub fn unequip_item(&mut self, unit_id: Uuid, item_id: ItemId) -> Result<String, String> {
+        let unit = self.units.get_mut(&unit_id).ok_or("Unit not found")?;
+        let delta = unit.unit().equipment().delta_if_unequipped(item_id);
+        unit.unit_mut().unequip_item(item_id)?;
+        let item_name = unit
+            .unit()
+            .inventory()
+            .iter()
+            .find(|item| item.id == item_id)
+            .map(|item| item.name.clone())
+            .unwrap_or_default();
+
+        let message = format!(
+            "{} unequipped {} ({})",
+            unit.name(),
+            item_name,
+            delta.summary()
+        );
+        let event = GameEvent::ItemEquipped {
+            unit_id,
+            team: unit.team(),
+            item_name,
+            equipped: false,
+        };
+        self.emit(event);
+        Ok(message)
+    }
+
+    /// Stat changes from toggling an item a unit carries: equipping it if it
+    /// is in the inventory, unequipping it if it is equipped.
+    ///
+    /// # Returns
+    ///
+    /// The delta, or `None` if the unit does not carry the item or it is a
+    /// consumable
+    pub fn item_delta(&self, unit_id: Uuid, item_id: ItemId) -> Option<EquipmentDelta> {
+        let (item, equipped) = self
+            .carried_items(unit_id)
+            .into_iter()
+            .find(|(item, _)| item.id == item_id)?;
+        let equipment = self.units.get(&unit_id)?.unit().equipment();
+        if equipped {
+            Some(equipment.delta_if_unequipped(item_id))
+        } else if item.item_type == ItemType::Consumable {
+            None
+        } else {
+            Some(equipment.delta_if_equipped(item))
+        }
+    }
+
+    /// Drops an item a unit carries onto its hex, unequipping it first.
+    ///
+    /// # Returns
+    ///
+    /// The UUID of the new item pickup, or `Err(String)` if the unit does not
+    /// carry the item or an item already lies on its hex
+    pub fn drop_item(&mut self, unit_id: Uuid, item_id: ItemId) -> Result<Uuid, String> {
+        let position = self.units.get(&unit_id).ok_or("Unit not found")?.position();
+        if self
+            .interactive_objects
+            .values()
+            .any(|object| object.position() == position && object.has_item())
+        {
+            return Err(format!("An item already lies on {:?}", position));
+        }
+
+        let (item, _) = self.take_carried_item(unit_id, item_id)?;
+        let team = self.units.get(&unit_id).ok_or("Unit not found")?.team();
+        self.emit(GameEvent::ItemDropped {
+            unit_id,
+            team,
+            item_name: item.name.clone(),
+            position,
+        });
+        Ok(self.add_interactive_object(InteractiveObject::new_item_pickup(position, item)))
+    }
+
+    /// Gives an item a unit carries to an adjacent ally, unequipping it
+    /// first. This uses up the giver's action for the turn.
+    ///
+    /// The item goes into the receiver's inventory; the receiver can equip it
+    /// afterwards.
+    ///
+    /// # Returns
+    ///
+    /// A description of the hand-over, or `Err(String)` if the giver has
+    /// already acted this turn, does not carry the item, or the receiver is
+    /// not an adjacent ally
+    pub fn give_item(
+        &mut self,
+        giver_id: Uuid,
+        item_id: ItemId,
+        receiver_id: Uuid,
+    ) -> Result<String, String> {
+        let giver = self.units.get(&giver_id).ok_or("Unit not found")?;
+        let receiver = self.units.get(&receiver_id).ok_or("Receiver not found")?;
+        if giver_id == receiver_id {
+            return Err("A unit cannot give an item to itself".to_string());
+        }
+        if giver.unit().combat_stats().attacked_this_turn {
+            return Err(format!("{} has already acted this turn", giver.name()));
+        }
+        if giver.position().distance(receiver.position()) != 1 {
+            return Err("Items can only be given to adjacent units".to_string());
+        }
+        if !self.teams.are_allied(giver.team(), receiver.team()) {
+            return Err("Items can only be given to allies".to_string());
+        }
+
+        let (item, giver_name) = self.take_carried_item(giver_id, item_id)?;
+        let item_name = item.name.clone();
+        let giver = self.units.get_mut(&giver_id).ok_or("Unit not found")?;
+        giver.unit_mut().combat_stats_mut().attacked_this_turn = true;
+        let receiver = self
+            .units
+            .get_mut(&receiver_id)
+            .ok_or("Receiver not found")?;
+        receiver.unit_mut().add_item_to_inventory(item);
+
+        let message = format!("{} gave {} to {}", giver_name, item_name, receiver.name());
+        let event = GameEvent::ItemGiven {
+            giver_id,
+            receiver_id,
+            team: receiver.team(),
+            item_name,
+        };
+        self.emit(event);
+        Ok(message)
+    }
+
+    /// Removes an item from a unit's equipment or inventory.
+    ///
+    /// # Returns
+    ///
+    /// The item and the unit's name
+    fn take_carried_item(
+        &mut self,
+        unit_id: Uuid,
+        item_id: ItemId,
+    ) -> Result<(Item, String), String> {
+        let unit = self.units.get_mut(&unit_id).ok_or("Unit not found")?;
+        if unit
+            .unit()
+            .equipment()
+            .get_all_equipped()
+            .iter()
+            .any(|item| item.id == item_id)
+        {
+            unit.unit_mut().unequip_item(item_id)?;
+        }
+        let item = unit
+            .unit_mut()
+            .remove_item_from_inventory(item_id)
+            .ok_or("Item not carried by unit")?;
+        Ok((item, unit.name()))
+    }
+}
@@ -8,19 +8,40 @@
 //! - **Game Objects**: Base trait system for all interactive game entities
 //! - **Game World**: Manages terrain, units, and interactive objects in a hex-based grid
 //! - **Combat System**: Handles combat initiation, confirmation, and resolution
-//! - **Team Management**: Tracks unit affiliations (Player, Enemy, Neutral)
+//! - **Combat Forecast**: Exact attack outcome odds for the combat dialog and AI scoring
+//! - **Game Events**: A typed event stream of moves, strikes, deaths, level-ups and turns for the UI
+//! - **Consumables**: Potions and tonics used from a unit's inventory, with timed buffs
+//! - **Inventory Management**: Equipping, dropping and handing items to adjacent allies
+//! - **Team Management**: Scenario teams with colors, alliances and turn order
+//! - **Fog of War**: Per-team visibility from units, structures and allies
+//! - **Networked Multiplayer**: Lockstep player commands exchanged over TCP
+//! - **Progression**: Kill experience, level-ups and branching evolution
+//! - **Tactical Search**: Lets the AI search over cloned scenario worlds
+//! - **Time of Day**: A per-scenario day/night cycle that scales damage by alignment
 //!
 //! ## Module Organization
 //!
 //! - [`objects`]: Defines the `GameObject` trait and implementations for terrain, units, and interactive objects
 //! - [`world`]: Provides the `GameWorld` structure for managing all game entities and interactions
 //! - [`turn_system`]: Manages turn-based gameplay mechanics
+//! - [`events`]: `GameEvent` and the event queue `ScenarioWorld` emits into
+//! - [`forecast`]: Combat outcome forecasts for attacks in `ScenarioWorld`
+//! - [`consumables`]: Using consumable items from inventories in `ScenarioWorld`
+//! - [`fog_of_war`]: What each team can see in `ScenarioWorld`
+//! - [`hit_chance`]: Per-strike hit chances and their breakdown in `ScenarioWorld`
+//! - [`inventory`]: Equipping, unequipping, dropping and giving carried items in `ScenarioWorld`
+//! - [`lockstep`]: `PlayerCommand`, command application and state hashes for `ScenarioWorld`
+//! - [`network`]: Hosting and joining lockstep games over TCP
+//! - [`progression`]: Experience, level-up and evolution choices in `ScenarioWorld`
+//! - [`status_effects`]: Timed buffs and debuffs and their turn-boundary countdown
+//! - [`tactical`]: `GameCommand` and the `ai::TacticalState` implementation for `ScenarioWorld`
+//! - [`time_of_day`]: The day/night cycle and time-of-day damage in `ScenarioWorld`
 //!
 //! ## Examples
 //!
 //! ```no_run
 //! use game::{GameWorld, GameUnit, Team};
-//! use graphics::HexCoord;
+//! use hex::HexCoord;
 //!
 //! // Create a new game world
 //! let mut world = GameWorld::new(10);
@@ -31,18 +52,38 @@
 //! // world.add_unit(unit);
 //! ```
 
+pub mod consumables;
+pub mod events;
+pub mod fog_of_war;
+pub mod forecast;
+pub mod hit_chance;
+pub mod inventory;
+pub mod lockstep;
+pub mod network;
 pub mod objects;
+pub mod progression;
 pub mod scenario_helpers;
 pub mod scenario_instance;
+pub mod status_effects;
+pub mod tactical;
+pub mod time_of_day;
 pub mod turn_system;
 pub mod world;
 
+pub use events::{EventQueue, GameEvent};
+pub use lockstep::PlayerCommand;
 pub use objects::*;
+pub use progression::PendingEvolution;
+pub use tactical::GameCommand;
+pub use time_of_day::{DayCycle, TimeOfDay};
 pub use turn_system::*;
 pub use world::*;
 // `scenario_instance` module is available as `game::scenario_instance`.
 // Avoid re-exporting its types at the crate root to prevent name collisions.
 
 // Re-export commonly used types from dependencies
-pub use graphics::{HexCoord, SpriteType, Vec2};
-pub use units::{Item, Race, Unit};
+pub use ai::{AgentTrace, AiTrace};
+pub use combat::CombatForecast;
+pub use graphics::{SpriteType, Vec2};
+pub use hex::HexCoord;
+pub use units::{Alignment, Item, Race, Unit};
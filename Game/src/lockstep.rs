@@ -0,0 +1,300 @@
+//! # Lockstep Commands
+//!
+//! Everything a player does to a [`ScenarioWorld`] expressed as a
+//! [`PlayerCommand`]. Peers of a networked game (see [`crate::network`]) only
+//! exchange these commands: each side applies them to its own world in the
+//! same order and, with the same map and RNG seed
+//! ([`ScenarioWorld::seed_rng`]), ends up in the same state.
+//!
+//! Commands address units by hex rather than by UUID, because every peer
+//! generates its own unit ids when it loads the map.
+//!
+//! [`ScenarioWorld::state_hash`] summarizes the state that commands can change,
+//! so peers can compare hashes to detect a desync.
+
+use crate::objects::{GameObject, Team};
+use crate::scenario_instance::ScenarioWorld;
+use hex::HexCoord;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use uuid::Uuid;
+
+/// A single player action, as sent between peers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayerCommand {
+    /// Move the unit on `from` to `to`
+    Move { from: HexCoord, to: HexCoord },
+    /// Attack the unit on `defender` with the attack at `attack_index`
+    Attack {
+        attacker: HexCoord,
+        defender: HexCoord,
+        attack_index: usize,
+    },
+    /// End the current team's turn
+    EndTurn,
+    /// Pick up the item lying under the unit on `unit`
+    Pickup { unit: HexCoord },
+    /// Recruit a unit of `unit_type` onto an empty hex
+    Recruit { unit_type: String, at: HexCoord },
+    /// Resolve the pending level-up with evolution branch `option`
+    ChooseEvolution { option: usize },
+    /// Use the consumable in inventory slot `slot` of the unit on `unit` on
+    /// the unit on `target` (itself or an adjacent ally)
+    UseItem {
+        unit: HexCoord,
+        slot: usize,
+        target: HexCoord,
+    },
+    /// Equip the item at `slot` of the carried items of the unit on `unit`
+    /// (see [`ScenarioWorld::carried_items`])
+    Equip { unit: HexCoord, slot: usize },
+    /// Unequip the item at `slot` of the carried items of the unit on `unit`
+    Unequip { unit: HexCoord, slot: usize },
+    /// Drop the item at `slot` of the carried items of the unit on `unit`
+    Drop { unit: HexCoord, slot: usize },
+    /// Give the item at `slot` of the carried items of the unit on `unit` to
+    /// the adjacent ally on `to`
+    Give {
+        unit: HexCoord,
+        slot: usize,
+        to: HexCoord,
+    },
+}
+
+impl ScenarioWorld {
+    /// Applies a command issued by `team`.
+    ///
+    /// Every command but [`PlayerCommand::ChooseEvolution`] requires that it
+    /// is `team`'s turn and that the units it names belong to `team`; an
+    /// evolution may only be chosen by the team owning the leveled-up unit.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the command was applied, or `Err(String)` explaining why
+    /// it was rejected. Rejected commands leave the world unchanged.
+    pub fn apply_command(&mut self, team: Team, command: &PlayerCommand) -> Result<(), String> {
+        if !self.turn_system.is_game_started() {
+            return Err("The game has not started".to_string());
+        }
+        if let PlayerCommand::ChooseEvolution { option } = command {
+            let pending = self.pending_evolution().ok_or("No evolution pending")?;
+            let owner = self.units.get(&pending.unit_id).map(|u| u.team());
+            if owner != Some(team) {
+                return Err(format!(
+                    "{} has no evolution to choose",
+                    self.teams.name(team)
+                ));
+            }
+            return self.choose_evolution(*option);
+        }
+        if self.current_turn_team() != team {
+            return Err(format!("It is not {}'s turn", self.teams.name(team)));
+        }
+
+        match command {
+            PlayerCommand::Move { from, to } => {
+                let unit_id = self.own_unit_at(team, *from)?;
+                if self.hostile_unit_at(team, *to).is_some() {
+                    return Err(format!("{:?} holds an enemy; attack it instead", to));
+                }
+                self.move_unit(unit_id, *to)
+            }
+            PlayerCommand::Attack {
+                attacker,
+                defender,
+                attack_index,
+            } => {
+                let attacker_id = self.own_unit_at(team, *attacker)?;
+                let defender_id = self
+                    .hostile_unit_at(team, *defender)
+                    .ok_or_else(|| format!("No enemy on {:?}", defender))?;
+                let attack = self.units[&attacker_id]
+                    .unit()
+                    .get_attacks()
+                    .get(*attack_index)
+                    .cloned()
+                    .ok_or("Selected attack not found")?;
+                let distance = attacker.distance(*defender);
+                if distance < 1 || distance > attack.range {
+                    return Err(format!("{:?} is out of range of {}", defender, attack.name));
+                }
+
+                self.request_combat(attacker_id, defender_id)?;
+                let pending = self
+                    .pending_combat
+                    .as_mut()
+                    .ok_or("Attacker has already attacked this turn")?;
+                pending.selected_attack_index = *attack_index;
+                self.execute_pending_combat()
+            }
+            PlayerCommand::EndTurn => {
+                self.end_current_turn();
+                Ok(())
+            }
+            PlayerCommand::Pickup { unit } => {
+                let unit_id = self.own_unit_at(team, *unit)?;
+                let object_id = self
+                    .interactive_objects
+                    .values()
+                    .find(|object| object.position() == *unit && object.has_item())
+                    .map(|object| object.id())
+                    .ok_or_else(|| format!("No item on {:?}", unit))?;
+                self.pickup_item(unit_id, object_id).map(|_| ())
+            }
+            PlayerCommand::Recruit { unit_type, at } => {
+                self.recruit_unit(team, unit_type, *at).map(|_| ())
+            }
+            PlayerCommand::UseItem { unit, slot, target } => {
+                let unit_id = self.own_unit_at(team, *unit)?;
+                let item_id = self.units[&unit_id]
+                    .unit()
+                    .inventory()
+                    .get(*slot)
+                    .map(|item| item.id)
+                    .ok_or_else(|| format!("No item in slot {}", slot))?;
+                let target_id = self
+                    .units
+                    .values()
+                    .find(|u| u.position() == *target)
+                    .map(|u| u.id())
+                    .ok_or_else(|| format!("No unit on {:?}", target))?;
+                self.use_item(unit_id, item_id, target_id).map(|_| ())
+            }
+            PlayerCommand::Equip { unit, slot } => {
+                let (unit_id, item_id) = self.own_carried_item(team, *unit, *slot)?;
+                self.equip_item(unit_id, item_id).map(|_| ())
+            }
+            PlayerCommand::Unequip { unit, slot } => {
+                let (unit_id, item_id) = self.own_carried_item(team, *unit, *slot)?;
+                self.unequip_item(unit_id, item_id).map(|_| ())
+            }
+            PlayerCommand::Drop { unit, slot } => {
+                let (unit_id, item_id) = self.own_carried_item(team, *unit, *slot)?;
+                self.drop_item(unit_id, item_id).map(|_| ())
+            }
+            PlayerCommand::Give { unit, slot, to } => {
+                let (unit_id, item_id) = self.own_carried_item(team, *unit, *slot)?;
+                let receiver_id = self
+                    .units
+                    .values()
+                    .find(|u| u.position() == *to)
+                    .map(|u| u.id())
+                    .ok_or_else(|| format!("No unit on {:?}", to))?;
+                self.give_item(unit_id, item_id, receiver_id).map(|_| ())
+            }
+            PlayerCommand::ChooseEvolution { .. } => unreachable!("handled above"),
+        }
+    }
+
+    /// Hash of the state commands can change, independent of unit ids.
+    ///
+    /// Covers the turn, the active team, pending choices, every unit (type,
+    /// team, position, health, experience, moves, inventory, equipment and
+    /// status-effect durations)
+    /// and every item left on the ground. Equal worlds hash alike on every
+    /// peer running the same build.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.turn_number().hash(&mut hasher);
+        self.current_turn_team().hash(&mut hasher);
+        self.pending_evolution()
+            .map(|p| p.options.len())
+            .hash(&mut hasher);
+
+        let mut units: Vec<_> = self
+            .units
+            .values()
+            .map(|unit| {
+                let stats = unit.unit().combat_stats();
+                let inventory: Vec<(&str, Option<i32>)> = unit
+                    .unit()
+                    .inventory()
+                    .iter()
+                    .map(|item| (item.name.as_str(), item.remaining_uses()))
+                    .collect();
+                let mut effects: Vec<u32> = unit
+                    .unit()
+                    .ability_state()
+                    .active_effects
+                    .values()
+                    .map(|effect| effect.duration)
+                    .collect();
+                effects.sort();
+                let equipped: Vec<&str> = unit
+                    .unit()
+                    .equipment()
+                    .get_all_equipped()
+                    .into_iter()
+                    .map(|item| item.name.as_str())
+                    .collect();
+                (
+                    unit.position(),
+                    unit.team(),
+                    unit.unit().unit_type(),
+                    stats.health,
+                    stats.attacked_this_turn,
+                    unit.unit().experience(),
+                    unit.moves_left(),
+                    inventory,
+                    equipped,
+                    effects,
+                )
+            })
+            .collect();
+        units.sort();
+        units.hash(&mut hasher);
+
+        let mut items: Vec<(HexCoord, &str)> = self
+            .interactive_objects
+            .values()
+            .map(|object| (object.position(), object.name()))
+            .collect();
+        items.sort();
+        items.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// The unit on `position`, if it belongs to `team`.
+    fn own_unit_at(&self, team: Team, position: HexCoord) -> Result<Uuid, String> {
+        let unit = self
+            .units
+            .values()
+            .find(|u| u.position() == position)
+            .ok_or_else(|| format!("No unit on {:?}", position))?;
+        if unit.team() != team {
+            return Err(format!(
+                "The unit on {:?} does not belong to {}",
+                position,
+                self.teams.name(team)
+            ));
+        }
+        Ok(unit.id())
+    }
+
+    /// The unit of `team` on `position` and the id of its carried item at
+    /// `slot`.
+    fn own_carried_item(
+        &self,
+        team: Team,
+        position: HexCoord,
+        slot: usize,
+    ) -> Result<(Uuid, items::ItemId), String> {
+        let unit_id = self.own_unit_at(team, position)?;
+        let item_id = self
+            .carried_items(unit_id)
+            .get(slot)
+            .map(|(item, _)| item.id)
+            .ok_or_else(|| format!("No item in slot {}", slot))?;
+        Ok((unit_id, item_id))
+    }
+
+    /// The unit on `position`, if it is hostile to `team`.
+    fn hostile_unit_at(&self, team: Team, position: HexCoord) -> Option<Uuid> {
+        self.units
+            .values()
+            .find(|u| u.position() == position && self.teams.are_hostile(team, u.team()))
+            .map(|u| u.id())
+    }
+}
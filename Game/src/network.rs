@@ -0,0 +1,641 @@
+//! # Networked Multiplayer
+//!
+//! Two peers play one scenario over TCP in lockstep. The host owns the
+//! authoritative [`GameSetup`]: the map, the RNG seed, which teams each peer
+//! plays and the log of every [`PlayerCommand`] applied so far. A guest joins
+//! by address and rebuilds the host's world from that setup.
+//!
+//! After that only commands cross the wire. Each peer applies its own
+//! commands locally, sends them to the other side, and applies the commands
+//! it receives in log order, so both [`ScenarioWorld`]s stay identical.
+//!
+//! ## Teams
+//!
+//! Networked games have no AI: the AI plans over peer-local unit ids, so the
+//! two peers could not be trusted to make the same choices. Every fighting
+//! team (see [`ScenarioWorld::fighting_teams`]) must be played by one of the
+//! peers, and [`GameSetup::build_world`] rejects setups that leave one to the
+//! AI. Bystander teams have nobody to fight, so their turns pass at once.
+//!
+//! ## Desyncs and reconnection
+//!
+//! Whenever a turn ends, the peer that ended it also sends its
+//! [`ScenarioWorld::state_hash`]. When the hashes differ, or a peer has to
+//! reject a command the other side accepted, the host's state wins: the host
+//! resends its setup and the guest rebuilds its world from it, reporting
+//! [`NetEvent::ResyncFailed`] if it cannot. A guest that lost its connection
+//! calls [`NetSession::reconnect`]; the host resyncs every guest that
+//! connects.
+//!
+//! ## Protocol
+//!
+//! Messages are [`serde_json`] objects, one per line. Each connection has a
+//! reader thread that forwards incoming messages to the session, which
+//! handles them in [`NetSession::poll`]; nothing happens to the world outside
+//! `poll` and [`NetSession::submit`].
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use game::lockstep::PlayerCommand;
+//! use game::network::{GameSetup, NetSession};
+//! use std::time::Duration;
+//!
+//! let map = std::fs::read_to_string("Maps/test_map.json").unwrap();
+//! let setup = GameSetup::new(map, 42);
+//! let (mut host, mut world) = NetSession::host("0.0.0.0:7777", setup).unwrap();
+//!
+//! // Elsewhere: let (guest, guest_world) = NetSession::join("host:7777", Duration::from_secs(5))?;
+//! loop {
+//!     for event in host.poll(&mut world) {
+//!         println!("{:?}", event);
+//!     }
+//!     if host.is_connected() {
+//!         let team = world.current_turn_team();
+//!         if host.is_local_team(team) {
+//!             host.submit(&mut world, team, PlayerCommand::EndTurn).unwrap();
+//!         }
+//!     }
+//!     # break;
+//! }
+//! ```
+
+use crate::lockstep::PlayerCommand;
+use crate::objects::Team;
+use crate::scenario_instance::ScenarioWorld;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Port used when a host or join address leaves it out.
+pub const DEFAULT_PORT: u16 = 7777;
+
+/// Everything needed to rebuild a networked game from scratch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameSetup {
+    /// Scenario map JSON
+    pub map_json: String,
+    /// Seed of the combat dice
+    pub seed: u64,
+    /// Teams played at the host
+    pub host_teams: Vec<Team>,
+    /// Teams played at the guest
+    pub guest_teams: Vec<Team>,
+    /// Every command applied so far, in order, with the team that issued it
+    pub commands: Vec<(Team, PlayerCommand)>,
+}
+
+impl GameSetup {
+    /// Creates a setup for a new game, dealing the scenario's fighting
+    /// teams (see [`ScenarioWorld::fighting_teams`]) alternately to the host
+    /// and the guest, starting with the host.
+    pub fn new(map_json: String, seed: u64) -> Self {
+        let fighting = ScenarioWorld::new(map_json.clone()).fighting_teams();
+        let (host, guest): (Vec<_>, Vec<_>) = fighting
+            .into_iter()
+            .enumerate()
+            .partition(|(i, _)| i % 2 == 0);
+        Self {
+            map_json,
+            seed,
+            host_teams: host.into_iter().map(|(_, team)| team).collect(),
+            guest_teams: guest.into_iter().map(|(_, team)| team).collect(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Builds the world this setup describes by replaying its command log.
+    ///
+    /// Every team is marked player-controlled so no AI or turn timer ever
+    /// acts on its own; turns of bystander teams neither peer plays end at
+    /// once. The events of the replay are discarded, so the world starts with
+    /// an empty event queue.
+    ///
+    /// # Returns
+    ///
+    /// The world, or `Err(String)` if a fighting team is played by neither
+    /// peer or a logged command is rejected
+    pub fn build_world(&self) -> Result<ScenarioWorld, String> {
+        let mut world = ScenarioWorld::new(self.map_json.clone());
+        if let Some(team) = world
+            .fighting_teams()
+            .into_iter()
+            .find(|team| !self.is_played(*team))
+        {
+            return Err(format!(
+                "team '{}' would be AI-controlled; networked games need a player for every fighting team",
+                world.teams.name(team)
+            ));
+        }
+        world.seed_rng(self.seed);
+        for team in world.teams.turn_order().to_vec() {
+            world.set_team_control(team, true);
+        }
+        world.start_turn_based_game();
+        self.skip_unplayed_turns(&mut world);
+
+        for (seq, (team, command)) in self.commands.iter().enumerate() {
+            world
+                .apply_command(*team, command)
+                .map_err(|e| format!("command {} ({:?}) failed: {}", seq, command, e))?;
+            self.skip_unplayed_turns(&mut world);
+        }
+        // The replayed history was already shown; only new events count
+        world.drain_events();
+        Ok(world)
+    }
+
+    /// Whether either peer plays `team`.
+    pub fn is_played(&self, team: Team) -> bool {
+        self.host_teams.contains(&team) || self.guest_teams.contains(&team)
+    }
+
+    /// Ends bystander turns until a team that one of the peers plays is active.
+    fn skip_unplayed_turns(&self, world: &mut ScenarioWorld) {
+        for _ in 0..world.teams.len() {
+            if self.is_played(world.current_turn_team()) {
+                break;
+            }
+            world.end_current_turn();
+        }
+    }
+}
+
+/// Which end of the connection a session is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetRole {
+    /// Owns the authoritative setup and accepts the guest
+    Host,
+    /// Joined the host by address
+    Guest,
+}
+
+/// Something that happened while polling a session.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NetEvent {
+    /// The other peer connected (host: a guest joined or rejoined)
+    PeerConnected,
+    /// The connection to the other peer was lost
+    PeerDisconnected,
+    /// A command from the other peer was applied to the world
+    CommandApplied { team: Team, command: PlayerCommand },
+    /// A command from the other peer could not be applied
+    CommandRejected {
+        team: Team,
+        command: PlayerCommand,
+        reason: String,
+    },
+    /// The peers' state hashes differ after command `seq`
+    Desync { seq: usize },
+    /// The world was rebuilt from the host's setup
+    Resynced,
+    /// The host's setup could not be rebuilt; the world is still out of sync
+    ResyncFailed { reason: String },
+}
+
+/// Wire messages, one JSON object per line.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum NetMessage {
+    /// Host to guest: the full setup to (re)build the world from
+    Welcome(GameSetup),
+    /// A command the sender applied as entry `seq` of the command log
+    Command {
+        seq: usize,
+        team: Team,
+        command: PlayerCommand,
+    },
+    /// The sender's state hash after applying entry `seq`
+    StateHash { seq: usize, hash: u64 },
+    /// Guest to host: please send a fresh `Welcome`
+    ResyncRequest,
+}
+
+/// What reader threads report back to the session.
+enum Inbound {
+    Message(u64, NetMessage),
+    Closed(u64),
+}
+
+/// The connection to the other peer.
+struct Peer {
+    /// Distinguishes this connection's reader thread from stale ones
+    id: u64,
+    stream: TcpStream,
+}
+
+/// One end of a networked game.
+pub struct NetSession {
+    role: NetRole,
+    setup: GameSetup,
+    /// Host only: accepts (re)connecting guests
+    listener: Option<TcpListener>,
+    /// Guest only: where to reconnect to
+    host_addr: Option<SocketAddr>,
+    peer: Option<Peer>,
+    next_peer_id: u64,
+    inbox: Receiver<Inbound>,
+    inbox_tx: Sender<Inbound>,
+    /// Own state hash after each turn-ending command, by log entry
+    hashes: HashMap<usize, u64>,
+    desynced: bool,
+}
+
+impl NetSession {
+    /// Starts hosting a game and waits for a guest in the background.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address to listen on (port 0 picks a free port)
+    /// * `setup` - The game to host
+    ///
+    /// # Returns
+    ///
+    /// The session and the host's world, or an error if the setup's command
+    /// log does not replay or the address cannot be bound
+    pub fn host(addr: impl ToSocketAddrs, setup: GameSetup) -> io::Result<(Self, ScenarioWorld)> {
+        let world = setup
+            .build_world()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let mut session = Self::new(NetRole::Host, setup);
+        session.listener = Some(listener);
+        Ok((session, world))
+    }
+
+    /// Joins a hosted game and rebuilds the host's world.
+    ///
+    /// Blocks until the host's setup arrives (the host must be polling).
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address of the host
+    /// * `timeout` - How long to wait for the connection and the setup
+    pub fn join(addr: impl ToSocketAddrs, timeout: Duration) -> io::Result<(Self, ScenarioWorld)> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address to join"))?;
+        let placeholder = GameSetup {
+            map_json: String::new(),
+            seed: 0,
+            host_teams: Vec::new(),
+            guest_teams: Vec::new(),
+            commands: Vec::new(),
+        };
+        let mut session = Self::new(NetRole::Guest, placeholder);
+        session.host_addr = Some(addr);
+        session.connect_to_host(timeout)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match session.inbox.recv_timeout(remaining) {
+                Ok(Inbound::Message(_, NetMessage::Welcome(setup))) => {
+                    let world = setup
+                        .build_world()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    session.adopt_setup(setup);
+                    return Ok((session, world));
+                }
+                Ok(Inbound::Message(..)) => {}
+                Ok(Inbound::Closed(_)) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::ConnectionAborted,
+                        "host closed the connection",
+                    ))
+                }
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "no game setup from host",
+                    ))
+                }
+            }
+        }
+    }
+
+    fn new(role: NetRole, setup: GameSetup) -> Self {
+        let (inbox_tx, inbox) = mpsc::channel();
+        Self {
+            role,
+            setup,
+            listener: None,
+            host_addr: None,
+            peer: None,
+            next_peer_id: 0,
+            inbox,
+            inbox_tx,
+            hashes: HashMap::new(),
+            desynced: false,
+        }
+    }
+
+    /// Which end of the connection this session is.
+    pub fn role(&self) -> NetRole {
+        self.role
+    }
+
+    /// Address the host listens on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.as_ref()?.local_addr().ok()
+    }
+
+    /// The game setup, including the log of every command applied so far.
+    pub fn setup(&self) -> &GameSetup {
+        &self.setup
+    }
+
+    /// Teams played at this end.
+    pub fn local_teams(&self) -> &[Team] {
+        match self.role {
+            NetRole::Host => &self.setup.host_teams,
+            NetRole::Guest => &self.setup.guest_teams,
+        }
+    }
+
+    /// Whether `team` is played at this end.
+    pub fn is_local_team(&self, team: Team) -> bool {
+        self.local_teams().contains(&team)
+    }
+
+    /// Whether the other peer is connected.
+    pub fn is_connected(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    /// Guest: whether the peers disagree on the game state (until the next resync).
+    pub fn is_desynced(&self) -> bool {
+        self.desynced
+    }
+
+    /// Applies a command for one of this end's teams and sends it to the peer.
+    ///
+    /// # Returns
+    ///
+    /// `Err(String)` if the team is not played here, the peer is not
+    /// connected, the game is desynced or the world rejects the command
+    pub fn submit(
+        &mut self,
+        world: &mut ScenarioWorld,
+        team: Team,
+        command: PlayerCommand,
+    ) -> Result<(), String> {
+        if !self.is_local_team(team) {
+            return Err(format!(
+                "{} is played by the other peer",
+                world.teams.name(team)
+            ));
+        }
+        if !self.is_connected() {
+            return Err("The other player is not connected".to_string());
+        }
+        if self.desynced {
+            return Err("Waiting for the game to resync".to_string());
+        }
+        world.apply_command(team, &command)?;
+
+        let seq = self.setup.commands.len();
+        self.send(&NetMessage::Command {
+            seq,
+            team,
+            command: command.clone(),
+        });
+        if let Some(hash) = self.record(world, team, command) {
+            self.send(&NetMessage::StateHash { seq, hash });
+        }
+        Ok(())
+    }
+
+    /// Handles everything that arrived from the network since the last poll.
+    ///
+    /// Call this every frame: the host accepts guests here, remote commands
+    /// are applied to `world` here, and a resync replaces `world` entirely.
+    pub fn poll(&mut self, world: &mut ScenarioWorld) -> Vec<NetEvent> {
+        let mut events = Vec::new();
+        self.accept_guests(&mut events);
+
+        while let Ok(inbound) = self.inbox.try_recv() {
+            match inbound {
+                Inbound::Message(id, message) if self.is_current_peer(id) => {
+                    self.handle_message(message, world, &mut events);
+                }
+                Inbound::Closed(id) if self.is_current_peer(id) => {
+                    self.peer = None;
+                    events.push(NetEvent::PeerDisconnected);
+                }
+                // Leftovers from a replaced connection
+                _ => {}
+            }
+        }
+        events
+    }
+
+    /// Guest: asks the host to send its setup again.
+    pub fn request_resync(&mut self) {
+        if self.role == NetRole::Guest {
+            self.send(&NetMessage::ResyncRequest);
+        }
+    }
+
+    /// Guest: connects to the host again after losing the connection.
+    ///
+    /// The host resyncs the guest once it accepts; the rebuilt world arrives
+    /// through [`poll`](Self::poll) as [`NetEvent::Resynced`].
+    pub fn reconnect(&mut self, timeout: Duration) -> io::Result<()> {
+        if self.role != NetRole::Guest {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "only a guest can reconnect",
+            ));
+        }
+        self.disconnect();
+        self.connect_to_host(timeout)
+    }
+
+    /// Closes the connection to the other peer, if any.
+    pub fn disconnect(&mut self) {
+        if let Some(peer) = self.peer.take() {
+            let _ = peer.stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn connect_to_host(&mut self, timeout: Duration) -> io::Result<()> {
+        let addr = self
+            .host_addr
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "no host address"))?;
+        let stream = TcpStream::connect_timeout(&addr, timeout)?;
+        self.attach_peer(stream)?;
+        Ok(())
+    }
+
+    /// Host: accepts every waiting guest, replacing any previous connection.
+    fn accept_guests(&mut self, events: &mut Vec<NetEvent>) {
+        let Some(listener) = &self.listener else {
+            return;
+        };
+        let mut accepted = Vec::new();
+        while let Ok((stream, _)) = listener.accept() {
+            accepted.push(stream);
+        }
+        for stream in accepted {
+            self.disconnect();
+            if stream.set_nonblocking(false).is_ok() && self.attach_peer(stream).is_ok() {
+                self.send(&NetMessage::Welcome(self.setup.clone()));
+                events.push(NetEvent::PeerConnected);
+            }
+        }
+    }
+
+    /// Makes `stream` the peer connection and starts its reader thread.
+    fn attach_peer(&mut self, stream: TcpStream) -> io::Result<()> {
+        stream.set_nodelay(true)?;
+        let reader = BufReader::new(stream.try_clone()?);
+        let id = self.next_peer_id;
+        self.next_peer_id += 1;
+        let inbox = self.inbox_tx.clone();
+        thread::spawn(move || {
+            for line in reader.lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                let Ok(message) = serde_json::from_str(&line) else {
+                    break;
+                };
+                if inbox.send(Inbound::Message(id, message)).is_err() {
+                    return;
+                }
+            }
+            let _ = inbox.send(Inbound::Closed(id));
+        });
+        self.peer = Some(Peer { id, stream });
+        Ok(())
+    }
+
+    fn is_current_peer(&self, id: u64) -> bool {
+        self.peer.as_ref().is_some_and(|peer| peer.id == id)
+    }
+
+    fn handle_message(
+        &mut self,
+        message: NetMessage,
+        world: &mut ScenarioWorld,
+        events: &mut Vec<NetEvent>,
+    ) {
+        match message {
+            NetMessage::Welcome(setup) if self.role == NetRole::Guest => {
+                match setup.build_world() {
+                    Ok(rebuilt) => {
+                        *world = rebuilt;
+                        self.adopt_setup(setup);
+                        events.push(NetEvent::Resynced);
+                    }
+                    Err(reason) => events.push(NetEvent::ResyncFailed { reason }),
+                }
+            }
+            NetMessage::ResyncRequest if self.role == NetRole::Host => {
+                self.send(&NetMessage::Welcome(self.setup.clone()));
+            }
+            NetMessage::Command { seq, team, command } => {
+                if self.desynced {
+                    return;
+                }
+                let result = if self.is_local_team(team) {
+                    Err(format!("{} is played here", world.teams.name(team)))
+                } else if seq != self.setup.commands.len() {
+                    Err(format!(
+                        "expected command {}, got {}",
+                        self.setup.commands.len(),
+                        seq
+                    ))
+                } else {
+                    world.apply_command(team, &command)
+                };
+                match result {
+                    Ok(()) => {
+                        self.record(world, team, command.clone());
+                        events.push(NetEvent::CommandApplied { team, command });
+                    }
+                    Err(reason) => {
+                        events.push(NetEvent::CommandRejected {
+                            team,
+                            command,
+                            reason,
+                        });
+                        self.mark_desynced();
+                    }
+                }
+            }
+            NetMessage::StateHash { seq, hash } => {
+                if !self.desynced && self.hashes.get(&seq).is_some_and(|own| *own != hash) {
+                    events.push(NetEvent::Desync { seq });
+                    self.mark_desynced();
+                }
+            }
+            // Messages meant for the other role
+            NetMessage::Welcome(_) | NetMessage::ResyncRequest => {}
+        }
+    }
+
+    /// Appends an applied command to the log, skipping unplayed turns.
+    ///
+    /// # Returns
+    ///
+    /// The state hash if the command ended a turn
+    fn record(
+        &mut self,
+        world: &mut ScenarioWorld,
+        team: Team,
+        command: PlayerCommand,
+    ) -> Option<u64> {
+        let seq = self.setup.commands.len();
+        let ends_turn = command == PlayerCommand::EndTurn;
+        self.setup.commands.push((team, command));
+        self.setup.skip_unplayed_turns(world);
+        if !ends_turn {
+            return None;
+        }
+        let hash = world.state_hash();
+        self.hashes.insert(seq, hash);
+        Some(hash)
+    }
+
+    /// Takes over a setup received from the host.
+    fn adopt_setup(&mut self, setup: GameSetup) {
+        self.setup = setup;
+        self.hashes.clear();
+        self.desynced = false;
+    }
+
+    /// Reacts to the peers disagreeing on the game state.
+    ///
+    /// The host is authoritative: it resends its setup. A guest stops
+    /// accepting commands and asks the host for that setup.
+    fn mark_desynced(&mut self) {
+        match self.role {
+            NetRole::Host => self.send(&NetMessage::Welcome(self.setup.clone())),
+            NetRole::Guest => {
+                self.desynced = true;
+                self.request_resync();
+            }
+        }
+    }
+
+    fn send(&mut self, message: &NetMessage) {
+        let Some(peer) = &mut self.peer else {
+            return;
+        };
+        let Ok(mut line) = serde_json::to_string(message) else {
+            return;
+        };
+        line.push('\n');
+        if peer.stream.write_all(line.as_bytes()).is_err() {
+            // The reader thread reports the closed connection
+            let _ = peer.stream.shutdown(Shutdown::Both);
+        }
+    }
+}
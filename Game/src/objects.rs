@@ -5,7 +5,7 @@
 //!
 //! ## Object Types
 //!
-//! - **TerrainTile**: Represents terrain hexes with movement costs and properties
+//! - **TerrainTile**: Terrain hexes: a terrain definition, overlay features and a sprite
 //! - **GameUnit**: Wrapper around unit trait objects with team affiliation and game logic
 //! - **InteractiveObject**: Items, pickups, and other interactable world objects
 //!
@@ -14,36 +14,14 @@
 //! The `GameObject` trait provides a unified interface for all entities in the game world,
 //! enabling polymorphic behavior and consistent position/rendering management.
 
-use graphics::{HexCoord, SpriteType};
+use graphics::SpriteType;
+use hex::HexCoord;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use units::{MovementType, Terrain, TerrainDefinition, TerrainFeature, TerrainRegistry};
+pub use units::{Stance, Team, TeamInfo, TeamRoster};
 use uuid::Uuid;
 
-/// Team affiliation for units in the game world.
-///
-/// Determines friend-or-foe relationships for combat and movement validation.
-/// Units of the same team cannot move onto each other's positions, while
-/// units of different teams can engage in combat.
-///
-/// # Examples
-///
-/// ```
-/// use game::Team;
-///
-/// let player_team = Team::Player;
-/// let enemy_team = Team::Enemy;
-/// assert_ne!(player_team, enemy_team);
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Team {
-    /// Player-controlled units
-    Player,
-    /// Enemy-controlled units that can be attacked
-    Enemy,
-    /// Neutral units that don't participate in combat
-    Neutral,
-}
-
 /// Base trait for all game objects in the world.
 ///
 /// `GameObject` provides a unified interface for all entities that exist in the game world,
@@ -70,8 +48,8 @@ pub enum Team {
 ///
 /// ```
 /// use game::{TerrainTile, GameObject};
-/// use graphics::HexCoord;
 /// use graphics::SpriteType;
+/// use hex::HexCoord;
 ///
 /// let tile = TerrainTile::new(HexCoord::new(0, 0), SpriteType::Grasslands);
 /// assert_eq!(tile.position(), HexCoord::new(0, 0));
@@ -142,33 +120,112 @@ pub trait GameObject {
     }
 }
 
+/// A map tile: gameplay terrain, overlay features and the sprite that renders it.
+///
+/// The terrain comes from a [`TerrainDefinition`] (see
+/// [`units::TerrainRegistry`]); the sprite is only used for rendering.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TerrainTile {
     id: Uuid,
     position: HexCoord,
     sprite_type: SpriteType,
+    terrain: TerrainDefinition,
+    features: Vec<TerrainFeature>,
     movement_cost: f32,
-    can_block: bool,
     metadata: HashMap<String, String>,
 }
 
 impl TerrainTile {
+    /// Creates a tile from a sprite, using the terrain that sprite renders.
+    ///
+    /// Sprites without a terrain definition (e.g. `SpriteType::None`) get the
+    /// registry's default terrain but keep their sprite.
     pub fn new(position: HexCoord, sprite_type: SpriteType) -> Self {
-        // Use canonical movement cost from SpriteType so costs are centralized
-        let movement_cost = sprite_type.movement_cost() as f32;
+        let registry = TerrainRegistry::builtin();
+        let terrain = registry
+            .for_sprite(sprite_type.name())
+            .or_else(|| registry.default_terrain())
+            .cloned()
+            .expect("built-in terrain registry has a default terrain");
 
-        let can_block = matches!(sprite_type, SpriteType::Mountain);
+        let mut tile = Self::from_terrain(position, terrain);
+        tile.sprite_type = sprite_type;
+        tile
+    }
 
+    /// Creates a tile of the given terrain, rendered with the terrain's sprite.
+    pub fn from_terrain(position: HexCoord, terrain: TerrainDefinition) -> Self {
         Self {
             id: Uuid::new_v4(),
             position,
-            sprite_type,
-            movement_cost,
-            can_block,
+            sprite_type: SpriteType::from_name(&terrain.sprite).unwrap_or(SpriteType::None),
+            movement_cost: terrain.movement_cost as f32,
+            terrain,
+            features: Vec::new(),
             metadata: HashMap::new(),
         }
     }
 
+    /// Creates a tile of a built-in terrain looked up by id.
+    ///
+    /// # Returns
+    ///
+    /// `Err(String)` if no built-in terrain has this id
+    pub fn with_terrain_id(position: HexCoord, terrain_id: &str) -> Result<Self, String> {
+        let terrain = TerrainRegistry::builtin()
+            .get(terrain_id)
+            .cloned()
+            .ok_or_else(|| format!("Unknown terrain: '{}'", terrain_id))?;
+        Ok(Self::from_terrain(position, terrain))
+    }
+
+    /// Returns the gameplay terrain definition of this tile.
+    pub fn terrain(&self) -> &TerrainDefinition {
+        &self.terrain
+    }
+
+    /// Returns the defense class used for race-specific hit chances.
+    pub fn defense_class(&self) -> Terrain {
+        self.terrain.defense_class
+    }
+
+    /// Returns whether this tile blocks line of sight.
+    pub fn blocks_line_of_sight(&self) -> bool {
+        self.terrain.blocks_line_of_sight
+    }
+
+    /// Returns whether structures can be built on this tile.
+    pub fn is_buildable(&self) -> bool {
+        self.terrain.buildable_with(&self.features)
+    }
+
+    /// Returns the overlay features on this tile.
+    pub fn features(&self) -> &[TerrainFeature] {
+        &self.features
+    }
+
+    /// Returns whether this tile has the given overlay feature.
+    pub fn has_feature(&self, feature: TerrainFeature) -> bool {
+        self.features.contains(&feature)
+    }
+
+    /// Adds an overlay feature and updates the movement cost accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Feature to add (ignored if already present)
+    pub fn add_feature(&mut self, feature: TerrainFeature) {
+        if !self.has_feature(feature) {
+            self.features.push(feature);
+            self.movement_cost = self.terrain.movement_cost_with(&self.features) as f32;
+        }
+    }
+
+    /// Sets the sprite used to render this tile without changing its terrain.
+    pub fn set_sprite_type(&mut self, sprite_type: SpriteType) {
+        self.sprite_type = sprite_type;
+    }
+
     /// Returns the movement cost for this terrain.
     ///
     /// Movement cost affects pathfinding and determines how many action points
@@ -177,6 +234,19 @@ impl TerrainTile {
         self.movement_cost as i32
     }
 
+    /// Returns the movement cost of this tile for a movement type.
+    ///
+    /// Uses the movement type's cost table for this tile's terrain and
+    /// features (see [`MovementType::movement_cost`]).
+    ///
+    /// # Returns
+    ///
+    /// The movement cost, or `None` if units of that movement type cannot
+    /// enter the tile
+    pub fn movement_cost_for(&self, movement_type: MovementType) -> Option<i32> {
+        movement_type.movement_cost(&self.terrain, &self.features)
+    }
+
     /// Sets the movement cost for this terrain.
     ///
     /// # Arguments
@@ -224,7 +294,7 @@ impl GameObject for TerrainTile {
     }
 
     fn name(&self) -> String {
-        format!("{:?}", self.sprite_type)
+        self.terrain.name.clone()
     }
 
     fn position(&self) -> HexCoord {
@@ -240,7 +310,7 @@ impl GameObject for TerrainTile {
     }
 
     fn blocks_movement(&self) -> bool {
-        self.can_block
+        self.terrain.impassable
     }
 
     fn type_name(&self) -> &'static str {
@@ -266,6 +336,25 @@ pub struct GameUnit {
     pub ai_plan_horizon: usize,
 }
 
+impl Clone for GameUnit {
+    /// Clones the unit state. The AI executor holds callbacks that cannot be
+    /// cloned, so the copy starts without one.
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            unit: self.unit.clone(),
+            team: self.team,
+            last_action_time: self.last_action_time,
+            action_cooldown: self.action_cooldown,
+            moves_left: self.moves_left,
+            ai_executor: None,
+            ai_plan: self.ai_plan.clone(),
+            ai_long_term_goal: self.ai_long_term_goal.clone(),
+            ai_plan_horizon: self.ai_plan_horizon,
+        }
+    }
+}
+
 impl GameUnit {
     pub fn new(unit: Box<dyn units::Unit>) -> Self {
         // Initialize moves_left from the underlying unit's combat stats
@@ -273,7 +362,7 @@ impl GameUnit {
         Self {
             id: Uuid::new_v4(),
             unit,
-            team: Team::Player, // Default to Player team
+            team: Team::PLAYER, // Default to Player team
             last_action_time: 0.0,
             action_cooldown: 1.0, // 1 second default cooldown
             moves_left: movement,
@@ -342,6 +431,22 @@ impl GameUnit {
         &mut *self.unit
     }
 
+    /// Replaces the underlying unit, keeping this game unit's id, team,
+    /// remaining moves and AI state.
+    ///
+    /// Used when a unit evolves into a new unit type.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit that takes the old unit's place
+    ///
+    /// # Returns
+    ///
+    /// The unit that was replaced
+    pub fn replace_unit(&mut self, unit: Box<dyn units::Unit>) -> Box<dyn units::Unit> {
+        std::mem::replace(&mut self.unit, unit)
+    }
+
     /// Checks if the unit can perform an action based on cooldown.
     ///
     /// # Arguments
@@ -525,7 +630,8 @@ impl GameObject for GameUnit {
 ///
 /// ```
 /// use game::InteractiveObject;
-/// use graphics::{HexCoord, SpriteType};
+/// use graphics::SpriteType;
+/// use hex::HexCoord;
 ///
 /// // Create a generic interactive object
 /// let chest = InteractiveObject::new(
@@ -563,7 +669,7 @@ impl InteractiveObject {
     ///
     /// ```
     /// use game::InteractiveObject;
-    /// use graphics::HexCoord;
+    /// use hex::HexCoord;
     /// use items::item_definitions::create_iron_sword;
     ///
     /// // Use helper from item_definitions to create a concrete Item
@@ -601,7 +707,8 @@ impl InteractiveObject {
     ///
     /// ```
     /// use game::InteractiveObject;
-    /// use graphics::{HexCoord, SpriteType};
+    /// use graphics::SpriteType;
+    /// use hex::HexCoord;
     ///
     /// let shrine = InteractiveObject::new(
     ///     HexCoord::new(0, 0),
@@ -679,6 +786,11 @@ impl InteractiveObject {
     pub fn has_item(&self) -> bool {
         self.item.is_some()
     }
+
+    /// The item waiting to be picked up, if any.
+    pub fn item(&self) -> Option<&units::Item> {
+        self.item.as_ref()
+    }
 }
 
 impl GameObject for InteractiveObject {
@@ -0,0 +1,321 @@
+//! # Experience and Evolution
+//!
+//! Awards experience for kills in [`ScenarioWorld`] and levels units up.
+//!
+//! ## Experience
+//!
+//! When a unit is defeated (by combat or by an ability), with `L` being the
+//! defeated unit's level:
+//!
+//! - The killer gains `L²` XP
+//! - Every ally adjacent to the killer gains `2 × L` XP
+//!
+//! ## Level-up
+//!
+//! A unit that reaches its XP threshold levels up:
+//!
+//! - **No evolution target**: incremental level-up (more health and damage)
+//! - **One target**: the unit evolves into it
+//! - **Several targets**: units of player-controlled teams wait for the player
+//!   to pick a branch ([`ScenarioWorld::pending_evolution`] and
+//!   [`ScenarioWorld::choose_evolution`]); AI teams pick the branch with the
+//!   best [`evolution_score`]
+//!
+//! Evolved units keep their id, team, remaining moves and whether they have
+//! already attacked this turn.
+
+use crate::events::GameEvent;
+use crate::objects::GameObject;
+use crate::scenario_instance::ScenarioWorld;
+use hex::HexCoord;
+use units::ability::{Ability, ActiveEffect};
+use units::{AbilityId, UnitFactory, UnitType};
+use uuid::Uuid;
+
+/// A level-up waiting for the player to choose an evolution branch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingEvolution {
+    /// Unit that leveled up
+    pub unit_id: Uuid,
+    /// Evolution targets to choose from (in `Unit::evolution_next` order)
+    pub options: Vec<UnitType>,
+}
+
+/// Score used by AI teams to pick an evolution branch (higher is better).
+///
+/// The score is the evolved form's maximum health plus twice the damage of
+/// its strongest attack. Returns `None` if the unit type cannot be created.
+pub fn evolution_score(unit_type: UnitType) -> Option<i32> {
+    let unit = UnitFactory::create(unit_type.as_str(), None, None).ok()?;
+    let best_damage = unit
+        .get_attacks()
+        .iter()
+        .map(|attack| attack.damage as i32)
+        .max()
+        .unwrap_or(0);
+    Some(unit.combat_stats().max_health + 2 * best_damage)
+}
+
+/// Index of the evolution branch an AI team picks.
+///
+/// Picks the option with the highest [`evolution_score`]; ties go to the
+/// earlier option. Returns `None` when `options` is empty.
+pub fn ai_evolution_choice(options: &[UnitType]) -> Option<usize> {
+    let mut best: Option<(usize, i32)> = None;
+    for (index, unit_type) in options.iter().enumerate() {
+        let score = evolution_score(*unit_type).unwrap_or(i32::MIN);
+        if best.is_none_or(|(_, b)| score > b) {
+            best = Some((index, score));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+impl ScenarioWorld {
+    /// Distributes experience for a kill.
+    ///
+    /// The killer gains `level²` XP and its adjacent allies `2 × level` XP,
+    /// where `level` is the defeated unit's level. Units that reach their
+    /// XP threshold level up immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `killer_id` - UUID of the unit that landed the killing blow
+    /// * `defeated_level` - Level of the defeated unit
+    pub(crate) fn award_kill_experience(&mut self, killer_id: Uuid, defeated_level: i32) {
+        let Some(killer) = self.units.get(&killer_id) else {
+            return;
+        };
+        let killer_pos = killer.position();
+        let killer_team = killer.team();
+
+        let killer_xp = defeated_level * defeated_level;
+        let ally_xp = 2 * defeated_level;
+
+        let mut allies: Vec<(HexCoord, Uuid)> = self
+            .units
+            .values()
+            .filter(|u| {
+                u.id() != killer_id
+                    && self.teams.are_allied(u.team(), killer_team)
+                    && u.position().distance(killer_pos) == 1
+            })
+            .map(|u| (u.position(), u.id()))
+            .collect();
+        // Hexes, unlike UUIDs, are the same on every networked peer, so
+        // level-ups (and pending evolutions) queue in the same order.
+        allies.sort_by_key(|(pos, _)| (pos.q, pos.r));
+
+        self.grant_experience(killer_id, killer_xp);
+        for (_, ally_id) in allies {
+            self.grant_experience(ally_id, ally_xp);
+        }
+    }
+
+    /// Removes a defeated unit, reporting its death and granting its killer
+    /// (and the killer's adjacent allies) kill experience.
+    pub(crate) fn defeat_unit(&mut self, unit_id: Uuid, killer_id: Uuid) {
+        let Some(unit) = self.remove_unit(unit_id) else {
+            return;
+        };
+        self.emit(GameEvent::UnitDied {
+            unit_id,
+            name: unit.name(),
+            team: unit.team(),
+            position: unit.position(),
+            killer_id: Some(killer_id),
+        });
+        self.award_kill_experience(killer_id, unit.unit().level());
+    }
+
+    /// Adds experience to a unit and levels it up when it reaches the threshold.
+    fn grant_experience(&mut self, unit_id: Uuid, xp: i32) {
+        let Some(unit) = self.units.get_mut(&unit_id) else {
+            return;
+        };
+        if unit.unit_mut().add_experience(xp) {
+            self.level_up(unit_id);
+        }
+    }
+
+    /// Levels up a unit that reached its XP threshold.
+    fn level_up(&mut self, unit_id: Uuid) {
+        let Some(unit) = self.units.get_mut(&unit_id) else {
+            return;
+        };
+        let options = unit.unit().evolution_next();
+        let team = unit.team();
+
+        match options.len() {
+            0 => {
+                unit.unit_mut().perform_level_up_incremental(true);
+                let event = GameEvent::LeveledUp {
+                    unit_id,
+                    name: unit.name(),
+                    team,
+                    level: unit.unit().level(),
+                };
+                self.emit(event);
+                self.invalidate_visibility();
+            }
+            1 => {
+                let _ = self.evolve_unit(unit_id, 0);
+            }
+            _ if self.turn_system.is_team_player_controlled(team) => {
+                // Wait for the player; a unit is only queued once
+                if !self.pending_evolutions.iter().any(|p| p.unit_id == unit_id) {
+                    self.pending_evolutions
+                        .push_back(PendingEvolution { unit_id, options });
+                }
+            }
+            _ => {
+                let index = ai_evolution_choice(&options).unwrap_or(0);
+                let _ = self.evolve_unit(unit_id, index);
+            }
+        }
+    }
+
+    /// Replaces a unit with its evolved form, keeping id, team and turn state.
+    fn evolve_unit(&mut self, unit_id: Uuid, index: usize) -> Result<(), String> {
+        let unit = self.units.get_mut(&unit_id).ok_or("Unit not found")?;
+        let mut evolved = unit
+            .unit()
+            .evolve(index, true)
+            .ok_or("Evolution target not available")?;
+        evolved.combat_stats_mut().attacked_this_turn =
+            unit.unit().combat_stats().attacked_this_turn;
+
+        unit.replace_unit(evolved);
+        let event = GameEvent::LeveledUp {
+            unit_id,
+            name: unit.name(),
+            team: unit.team(),
+            level: unit.unit().level(),
+        };
+        self.emit(event);
+        // The evolved unit may see further
+        self.invalidate_visibility();
+        Ok(())
+    }
+
+    /// The next level-up waiting for the player to choose an evolution branch.
+    pub fn pending_evolution(&self) -> Option<&PendingEvolution> {
+        self.pending_evolutions.front()
+    }
+
+    /// Resolves the pending evolution with the player's choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index into [`PendingEvolution::options`]
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the unit evolved, `Err(String)` if nothing is pending or
+    /// the index is out of range (the choice stays pending)
+    pub fn choose_evolution(&mut self, index: usize) -> Result<(), String> {
+        let pending = self
+            .pending_evolutions
+            .front()
+            .ok_or("No evolution pending")?;
+        if index >= pending.options.len() {
+            return Err(format!("Evolution option {} does not exist", index));
+        }
+        let unit_id = pending.unit_id;
+        self.pending_evolutions.pop_front();
+
+        // The unit may have died while the choice was pending
+        if !self.units.contains_key(&unit_id) {
+            return Err("Unit not found".to_string());
+        }
+        self.evolve_unit(unit_id, index)
+    }
+
+    /// Uses a unit's active ability on a target unit.
+    ///
+    /// Supports damage (reduced by resistances) and healing effects. A target
+    /// killed by the ability is removed and the caster earns kill experience.
+    ///
+    /// # Arguments
+    ///
+    /// * `caster_id` - UUID of the unit using the ability
+    /// * `ability_id` - Id of one of the caster's active abilities
+    /// * `target_id` - UUID of the targeted unit
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the target was defeated, `Ok(false)` otherwise, or
+    /// `Err(String)` if the ability cannot be used on the target
+    pub fn use_ability(
+        &mut self,
+        caster_id: Uuid,
+        ability_id: AbilityId,
+        target_id: Uuid,
+    ) -> Result<bool, String> {
+        let caster = self.units.get(&caster_id).ok_or("Caster not found")?;
+        let target = self.units.get(&target_id).ok_or("Target not found")?;
+
+        let ability = match caster.unit().find_ability(ability_id) {
+            Some(Ability::Active(active)) => active.clone(),
+            Some(_) => return Err("Not an active ability".to_string()),
+            None => return Err("Ability not found".to_string()),
+        };
+        if caster.position().distance(target.position()) > ability.range {
+            return Err("Target is out of range".to_string());
+        }
+        let hostile = self.teams.are_hostile(caster.team(), target.team());
+
+        match &ability.effect {
+            ActiveEffect::Damage { .. } if !hostile => {
+                return Err("Damage abilities must target an enemy".to_string())
+            }
+            ActiveEffect::Heal { .. } | ActiveEffect::HealPercent { .. } if hostile => {
+                return Err("Healing abilities must target an ally".to_string())
+            }
+            ActiveEffect::Damage { .. }
+            | ActiveEffect::Heal { .. }
+            | ActiveEffect::HealPercent { .. } => {}
+            _ => return Err(format!("{} cannot be used on a unit yet", ability.name)),
+        }
+
+        self.units
+            .get_mut(&caster_id)
+            .ok_or("Caster not found")?
+            .unit_mut()
+            .use_active_ability(ability_id)?;
+
+        let target = self.units.get_mut(&target_id).ok_or("Target not found")?;
+        match ability.effect {
+            ActiveEffect::Damage {
+                amount,
+                damage_type,
+            } => {
+                let resistance_multiplier = target
+                    .unit()
+                    .combat_stats()
+                    .resistances
+                    .damage_multiplier(damage_type);
+                let final_damage = ((amount as f32 * resistance_multiplier) as i32).max(1);
+                target.unit_mut().take_damage(final_damage as u32);
+            }
+            ActiveEffect::Heal { amount } => heal(target.unit_mut(), amount),
+            ActiveEffect::HealPercent { percent } => {
+                let amount = target.unit().combat_stats().max_health * percent as i32 / 100;
+                heal(target.unit_mut(), amount);
+            }
+            _ => {}
+        }
+
+        let defeated = !target.unit().is_alive();
+        if defeated {
+            self.defeat_unit(target_id, caster_id);
+        }
+        Ok(defeated)
+    }
+}
+
+/// Restores health without exceeding the unit's maximum.
+pub(crate) fn heal(unit: &mut dyn units::Unit, amount: i32) {
+    let stats = unit.combat_stats_mut();
+    stats.health = (stats.health + amount.max(0)).min(stats.max_health);
+}
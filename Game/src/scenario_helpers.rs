@@ -1,15 +1,15 @@
-use crate::objects::Team;
 use crate::objects::*;
 use crate::scenario_instance::ScenarioWorld;
+use crate::time_of_day::{DayCycle, TimeOfDay};
 use crate::world::GameWorld;
-use graphics::HexCoord;
 use graphics::SpriteType;
+use hex::HexCoord;
 use log::warn;
 use serde_json::Value;
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
 use units::structures::{Structure, StructureFactory};
-use units::UnitFactory;
+use units::{TerrainFeature, UnitFactory};
 use uuid::Uuid;
 
 /// Team configuration from map JSON.
@@ -18,6 +18,8 @@ pub struct TeamConfig {
     pub name: String,
     pub is_player_controlled: bool,
     pub goal: String,
+    /// Optional `Color` as `[r, g, b]` with components in `0.0..=1.0`
+    pub color: Option<[f32; 3]>,
 }
 
 /// Scenario information from map JSON.
@@ -37,15 +39,23 @@ pub struct ScenarioInfo {
 pub struct ParsedMap {
     pub scenario: Option<ScenarioInfo>,
     pub teams: Vec<TeamConfig>,
+    /// Team roster built from `Teams`, `Diplomacy` and `TurnOrder`
+    /// (the default Player/Enemy/Neutral roster when the map has no teams)
+    pub roster: TeamRoster,
     pub terrain: HashMap<HexCoord, TerrainTile>,
     pub units: Vec<(Uuid, HexCoord, Value)>,
     pub items: Vec<(Uuid, HexCoord, Value)>,
     pub structures: Vec<(Uuid, HexCoord, Value)>,
+    /// Day/night cycle from the optional top-level `DayCycle` array
+    pub day_cycle: Option<DayCycle>,
 }
 
 impl ScenarioWorld {
     /// Dijkstra-like reachable calculation using integer costs.
     ///
+    /// Step costs come from the moving unit's movement type (see
+    /// [`ScenarioWorld::unit_movement_cost`]).
+    ///
     /// This was previously a nested function inside `generate_team_actions`.
     /// Moving it here makes it testable and keeps `generate_team_actions`
     /// focused on high-level logic.
@@ -78,31 +88,23 @@ impl ScenarioWorld {
                     }
                 }
 
-                // Skip impassable terrain
-                if let Some(terrain) = self.get_terrain(*nb) {
-                    if terrain.blocks_movement() {
-                        continue;
-                    }
-                }
-
-                // Skip occupied tiles by friendly units (enemy units are valid targets for combat)
+                // Skip tiles occupied by non-hostile units (enemy units are valid targets for combat)
                 let moving_unit = self.get_unit(unit_id);
                 if let Some(mover) = moving_unit {
                     let mover_team = mover.team();
                     let units_there = self.get_units_at_position(*nb);
-                    let blocked_by_friendly = units_there
-                        .iter()
-                        .any(|u| u.id() != unit_id && u.team() == mover_team);
+                    let blocked_by_friendly = units_there.iter().any(|u| {
+                        u.id() != unit_id && !self.teams.are_hostile(u.team(), mover_team)
+                    });
                     if blocked_by_friendly {
                         continue;
                     }
                 }
 
-                let step_cost = self.get_movement_cost(*nb);
-                // Skip if terrain is impassable (i32::MAX cost)
-                if step_cost == i32::MAX {
+                // Skip tiles the mover's movement type cannot enter
+                let Some(step_cost) = self.unit_movement_cost(unit_id, *nb) else {
                     continue;
-                }
+                };
                 let new_cost = cost + step_cost;
                 if new_cost > max_cost {
                     continue;
@@ -146,6 +148,14 @@ impl ScenarioWorld {
     /// Parse the provided map JSON into terrain tiles, units, items and structures.
     ///
     /// Supports both legacy array format and new object format with Scenario, Teams, and Map.
+    /// The object format may also carry a `DayCycle` array of `{"Name", "LawfulBonus"}` phases.
+    /// Teams may give a `Color`; a `Diplomacy` array of `{"Teams": [a, b], "Stance"}` entries
+    /// sets alliances (teams are hostile otherwise) and a `TurnOrder` array of team names
+    /// overrides the order of `Teams`. Units and structures name their team by its `Name`.
+    /// Cells name their terrain with `Terrain` (a terrain registry id) plus optional
+    /// `Features`; cells that only have the older `SpriteType` field still load.
+    /// An `Item` is an item registry id (`"iron_sword"`) or an object whose
+    /// `definition` names one.
     /// Returns a `ParsedMap` containing scenario info, team configs, terrain tiles, and
     /// vectors of `(Uuid, HexCoord, Value)` entries for units, items and structures.
     pub fn parse_map_json(map_json: &str) -> Result<ParsedMap, serde_json::Error> {
@@ -153,8 +163,12 @@ impl ScenarioWorld {
         struct RawCell {
             #[serde(rename = "HexCoord")]
             hex: HexCoord,
-            #[serde(rename = "SpriteType")]
-            sprite: String,
+            #[serde(rename = "SpriteType", default)]
+            sprite: Option<String>,
+            #[serde(rename = "Terrain", default)]
+            terrain: Option<String>,
+            #[serde(rename = "Features", default)]
+            features: Vec<String>,
             #[serde(rename = "Unit")]
             unit: Option<Value>,
             #[serde(rename = "Item")]
@@ -166,7 +180,7 @@ impl ScenarioWorld {
         // Try to parse as new format first (object with Scenario, Teams, Map)
         let root: Value = serde_json::from_str(map_json)?;
 
-        let (scenario, teams, cells) = if root.is_object() {
+        let (scenario, teams, roster, day_cycle, cells) = if root.is_object() {
             // New format with Scenario, Teams, and Map
             let scenario = root.get("Scenario").and_then(|s| {
                 let name = s.get("Name")?.as_str()?.to_string();
@@ -174,7 +188,7 @@ impl ScenarioWorld {
                 Some(ScenarioInfo { name, description })
             });
 
-            let teams = root
+            let teams: Vec<TeamConfig> = root
                 .get("Teams")
                 .and_then(|t| t.as_array())
                 .map(|arr| {
@@ -183,15 +197,39 @@ impl ScenarioWorld {
                             let name = team.get("Name")?.as_str()?.to_string();
                             let is_player_controlled = team.get("IsPlayerControlled")?.as_bool()?;
                             let goal = team.get("Goal")?.as_str()?.to_string();
+                            let color = team.get("Color").and_then(|c| {
+                                serde_json::from_value::<[f32; 3]>(c.clone())
+                                    .map_err(|e| {
+                                        warn!("Ignoring invalid Color of '{}': {}", name, e)
+                                    })
+                                    .ok()
+                            });
                             Some(TeamConfig {
                                 name,
                                 is_player_controlled,
                                 goal,
+                                color,
                             })
                         })
                         .collect()
                 })
                 .unwrap_or_default();
+            let roster = Self::build_roster(&root, &teams).map_err(|e| {
+                serde_json::Error::io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+            })?;
+
+            let day_cycle = root.get("DayCycle").and_then(|c| {
+                let phases = match serde_json::from_value::<Vec<TimeOfDay>>(c.clone()) {
+                    Ok(phases) => phases,
+                    Err(e) => {
+                        warn!("Ignoring invalid DayCycle: {}", e);
+                        return None;
+                    }
+                };
+                DayCycle::new(phases)
+                    .map_err(|e| warn!("Ignoring invalid DayCycle: {}", e))
+                    .ok()
+            });
 
             let map_array = match root.get("Map") {
                 Some(arr) => arr,
@@ -204,11 +242,11 @@ impl ScenarioWorld {
             };
             let cells: Vec<RawCell> = serde_json::from_value(map_array.clone())?;
 
-            (scenario, teams, cells)
+            (scenario, teams, roster, day_cycle, cells)
         } else {
             // Legacy format (just an array of cells)
             let cells: Vec<RawCell> = serde_json::from_value(root)?;
-            (None, Vec::new(), cells)
+            (None, Vec::new(), TeamRoster::default(), None, cells)
         };
 
         let mut terrain: HashMap<HexCoord, TerrainTile> = HashMap::new();
@@ -217,24 +255,35 @@ impl ScenarioWorld {
         let mut structures: Vec<(Uuid, HexCoord, Value)> = Vec::new();
 
         for cell in cells {
-            let sprite_type = match cell.sprite.as_str() {
-                "Forest" => SpriteType::Forest,
-                "Forest2" => SpriteType::Forest2,
-                "Grasslands" => SpriteType::Grasslands,
-                "HauntedWoods" => SpriteType::HauntedWoods,
-                "Hills" => SpriteType::Hills,
-                "Mountain" => SpriteType::Mountain,
-                "Swamp" => SpriteType::Swamp,
-                "Unit" => SpriteType::Unit,
-                "Item" => SpriteType::Item,
-                "DwarfWarrior" => SpriteType::DwarfWarrior,
-                "OrcWarrior" => SpriteType::OrcWarrior,
-                "House" => SpriteType::House,
-                "Wall" => SpriteType::Wall,
-                _ => SpriteType::None,
+            let sprite_type = cell
+                .sprite
+                .as_deref()
+                .map(|sprite| SpriteType::from_name(sprite).unwrap_or(SpriteType::None));
+
+            // `Terrain` names the gameplay terrain; a `SpriteType` next to it only
+            // overrides how the tile looks. Legacy cells derive terrain from the sprite.
+            let mut tile = match cell.terrain.as_deref() {
+                Some(terrain_id) => match TerrainTile::with_terrain_id(cell.hex, terrain_id) {
+                    Ok(mut tile) => {
+                        if let Some(sprite_type) = sprite_type {
+                            tile.set_sprite_type(sprite_type);
+                        }
+                        tile
+                    }
+                    Err(e) => {
+                        warn!("{} at {:?}", e, cell.hex);
+                        TerrainTile::new(cell.hex, sprite_type.unwrap_or(SpriteType::None))
+                    }
+                },
+                None => TerrainTile::new(cell.hex, sprite_type.unwrap_or(SpriteType::None)),
             };
+            for name in &cell.features {
+                match TerrainFeature::from_name(name) {
+                    Some(feature) => tile.add_feature(feature),
+                    None => warn!("Unknown terrain feature '{}' at {:?}", name, cell.hex),
+                }
+            }
 
-            let tile = TerrainTile::new(cell.hex, sprite_type);
             let pos = cell.hex;
             terrain.insert(pos, tile);
 
@@ -287,6 +336,13 @@ impl ScenarioWorld {
 
             if let Some(it) = cell.item {
                 if !it.is_null() {
+                    // Handle string format: "iron_sword" (an item registry id)
+                    let it = match it {
+                        Value::String(definition) => {
+                            serde_json::json!({ "definition": definition })
+                        }
+                        other => other,
+                    };
                     let id = match it.get("id") {
                         Some(Value::String(s)) => {
                             Uuid::parse_str(s).unwrap_or_else(|_| Uuid::new_v4())
@@ -372,23 +428,125 @@ impl ScenarioWorld {
         Ok(ParsedMap {
             scenario,
             teams,
+            roster,
             terrain,
             units,
             items,
             structures,
+            day_cycle,
         })
     }
 
+    /// Build the team roster for a map's `Teams`, `Diplomacy` and `TurnOrder`.
+    ///
+    /// Maps without teams get the default roster. Invalid diplomacy entries and
+    /// turn orders are logged and ignored; more teams than a roster can hold
+    /// is an error.
+    fn build_roster(root: &Value, teams: &[TeamConfig]) -> Result<TeamRoster, String> {
+        if teams.is_empty() {
+            return Ok(TeamRoster::default());
+        }
+
+        let mut roster = TeamRoster::new();
+        for config in teams {
+            if roster.find(&config.name).is_some() {
+                warn!("Ignoring duplicate team '{}'", config.name);
+                continue;
+            }
+            let color = config.color.unwrap_or_else(|| roster.next_default_color());
+            let team = roster.add_team(config.name.clone(), color)?;
+            if let Some(info) = roster.get_mut(team) {
+                info.player_controlled = config.is_player_controlled;
+                info.goal = config.goal.clone();
+            }
+        }
+
+        for entry in root
+            .get("Diplomacy")
+            .and_then(|d| d.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let names: Vec<&str> = entry
+                .get("Teams")
+                .and_then(|t| t.as_array())
+                .map(|arr| arr.iter().filter_map(|n| n.as_str()).collect())
+                .unwrap_or_default();
+            let stance = entry
+                .get("Stance")
+                .and_then(|s| s.as_str())
+                .and_then(Stance::from_name);
+            match (names.as_slice(), stance) {
+                ([a, b], Some(stance)) => match (roster.find(a), roster.find(b)) {
+                    (Some(a), Some(b)) => roster.set_stance(a, b, stance),
+                    _ => warn!("Ignoring Diplomacy entry for unknown teams {} and {}", a, b),
+                },
+                _ => warn!("Ignoring invalid Diplomacy entry: {}", entry),
+            }
+        }
+
+        if let Some(order) = root.get("TurnOrder").and_then(|o| o.as_array()) {
+            let order: Option<Vec<Team>> = order
+                .iter()
+                .map(|name| name.as_str().and_then(|name| roster.find(name)))
+                .collect();
+            let result = order
+                .ok_or_else(|| "turn order names unknown team".to_string())
+                .and_then(|order| roster.set_turn_order(order));
+            if let Err(e) = result {
+                warn!("Ignoring invalid TurnOrder: {}", e);
+            }
+        }
+
+        Ok(roster)
+    }
+
+    /// Resolve the team named by a unit or structure entry.
+    ///
+    /// Missing or unknown names fall back to `fallback`.
+    fn resolve_team(
+        roster: &TeamRoster,
+        obj: &serde_json::Map<String, Value>,
+        fallback: Team,
+    ) -> Team {
+        match obj.get("team").and_then(|t| t.as_str()) {
+            Some(name) => roster.find(name).unwrap_or_else(|| {
+                warn!("Unknown team '{}', using '{}'", name, roster.name(fallback));
+                fallback
+            }),
+            None => fallback,
+        }
+    }
+
+    /// Team owning units whose entry names no known team: the first team in the roster.
+    fn default_unit_team(roster: &TeamRoster) -> Team {
+        roster.teams().first().map_or(Team::PLAYER, |t| t.id)
+    }
+
+    /// Team owning structures whose entry names no known team: the `Neutral`
+    /// team when the roster has one, else the first team.
+    fn default_structure_team(roster: &TeamRoster) -> Team {
+        roster
+            .find("Neutral")
+            .unwrap_or_else(|| Self::default_unit_team(roster))
+    }
+
     /// Apply a previously-parsed map into the provided `GameWorld`.
     ///
     /// This will merge terrain tiles, attempt to construct units via
     /// `units::UnitFactory` and insert them as `GameUnit`s (preserving UUID
     /// when possible), and create simple `InteractiveObject`s for items and
-    /// structures when a direct factory is not available.
+    /// structures when a direct factory is not available. Maps with a `Teams`
+    /// section replace the world's team roster and turn order.
     pub fn apply_parsed_map_to_world(
         world: &mut GameWorld,
         parsed: ParsedMap,
     ) -> Result<(), String> {
+        if !parsed.teams.is_empty() {
+            world.set_teams(parsed.roster.clone());
+        }
+        let default_team = Self::default_unit_team(&world.teams);
+
         // Merge terrain (overwrite existing tiles at the same positions)
         for (pos, tile) in parsed.terrain.into_iter() {
             world.terrain.insert(pos, tile);
@@ -410,15 +568,7 @@ impl ScenarioWorld {
                 .get("name")
                 .and_then(|s| s.as_str())
                 .map(|s| s.to_string());
-            let team = obj
-                .get("team")
-                .and_then(|t| t.as_str())
-                .map(|s| match s {
-                    "Enemy" => Team::Enemy,
-                    "Neutral" => Team::Neutral,
-                    _ => Team::Player,
-                })
-                .unwrap_or(Team::Player);
+            let team = Self::resolve_team(&world.teams, obj, default_team);
 
             let type_name = match type_name_opt {
                 Some(t) => t,
@@ -451,18 +601,14 @@ impl ScenarioWorld {
             let obj = v.as_object().unwrap();
 
             if let Some(def) = obj.get("definition").and_then(|s| s.as_str()) {
-                // Known definitions may be mapped here. Example: "IronSword" -> items::item_definitions::create_iron_sword()
-                match def {
-                    "IronSword" | "Iron Sword" => {
-                        let item = items::item_definitions::create_iron_sword();
+                // Definitions are item ids (or names) from the item registry
+                match items::ItemRegistry::builtin().create(def) {
+                    Some(item) => {
                         let pickup = InteractiveObject::new_item_pickup(pos, item);
                         world.add_interactive_object(pickup);
                     }
-                    other => {
-                        warn!(
-                            "Unknown item definition '{}', creating generic object",
-                            other
-                        );
+                    None => {
+                        warn!("Unknown item definition '{}', creating generic object", def);
                         let name = obj
                             .get("name")
                             .and_then(|s| s.as_str())
@@ -521,9 +667,14 @@ impl ScenarioWorld {
 
     /// Populate units from parsed data into HashMaps (for ScenarioWorld).
     ///
-    /// Returns a HashMap of GameUnit indexed by UUID.
-    pub fn populate_units(parsed_units: Vec<(Uuid, HexCoord, Value)>) -> HashMap<Uuid, GameUnit> {
+    /// Units join the roster team named by their `team` field (the first
+    /// team when missing or unknown). Returns a HashMap of GameUnit indexed by UUID.
+    pub fn populate_units(
+        parsed_units: Vec<(Uuid, HexCoord, Value)>,
+        roster: &TeamRoster,
+    ) -> HashMap<Uuid, GameUnit> {
         let mut units = HashMap::new();
+        let default_team = Self::default_unit_team(roster);
 
         for (id, pos, v) in parsed_units {
             if !v.is_object() {
@@ -539,15 +690,7 @@ impl ScenarioWorld {
                 .get("name")
                 .and_then(|s| s.as_str())
                 .map(|s| s.to_string());
-            let team = obj
-                .get("team")
-                .and_then(|t| t.as_str())
-                .map(|s| match s {
-                    "Enemy" => Team::Enemy,
-                    "Neutral" => Team::Neutral,
-                    _ => Team::Player,
-                })
-                .unwrap_or(Team::Player);
+            let team = Self::resolve_team(roster, obj, default_team);
 
             if let Some(type_name) = type_name_opt {
                 match UnitFactory::create(type_name, name_opt, Some(pos)) {
@@ -570,6 +713,7 @@ impl ScenarioWorld {
 
     /// Populate interactive objects from parsed items.
     ///
+    /// Item definitions are looked up in [`items::ItemRegistry::builtin`].
     /// Returns a HashMap of InteractiveObject indexed by UUID.
     pub fn populate_items(
         parsed_items: Vec<(Uuid, HexCoord, Value)>,
@@ -584,18 +728,14 @@ impl ScenarioWorld {
             let obj = v.as_object().unwrap();
 
             if let Some(def) = obj.get("definition").and_then(|s| s.as_str()) {
-                match def {
-                    "IronSword" | "Iron Sword" => {
-                        let item = items::item_definitions::create_iron_sword();
+                match items::ItemRegistry::builtin().create(def) {
+                    Some(item) => {
                         let pickup = InteractiveObject::new_item_pickup(pos, item);
                         let pickup_id = pickup.id();
                         interactive_objects.insert(pickup_id, pickup);
                     }
-                    other => {
-                        warn!(
-                            "Unknown item definition '{}', creating generic object",
-                            other
-                        );
+                    None => {
+                        warn!("Unknown item definition '{}', creating generic object", def);
                         let name = obj
                             .get("name")
                             .and_then(|s| s.as_str())
@@ -634,11 +774,14 @@ impl ScenarioWorld {
     /// Populate structures from parsed structure data.
     ///
     /// Returns a HashMap of boxed Structure trait objects indexed by UUID.
-    /// Supports structure types: House, StoneWall (Wall)
+    /// Supports structure types: House, StoneWall (Wall). Structures without a
+    /// known `team` belong to the `Neutral` team (or the first team).
     pub fn populate_structures(
         parsed_structures: Vec<(Uuid, HexCoord, Value)>,
+        roster: &TeamRoster,
     ) -> HashMap<Uuid, Box<dyn Structure>> {
         let mut structures: HashMap<Uuid, Box<dyn Structure>> = HashMap::new();
+        let default_team = Self::default_structure_team(roster);
 
         for (_id, pos, v) in parsed_structures {
             if !v.is_object() {
@@ -654,16 +797,7 @@ impl ScenarioWorld {
                 .or_else(|| obj.get("name").and_then(|s| s.as_str()))
                 .unwrap_or("House");
 
-            // Get team
-            let team = obj
-                .get("team")
-                .and_then(|t| t.as_str())
-                .map(|s| match s {
-                    "Enemy" => units::Team::Enemy,
-                    "Player" => units::Team::Player,
-                    _ => units::Team::Neutral,
-                })
-                .unwrap_or(units::Team::Neutral);
+            let team = Self::resolve_team(roster, obj, default_team);
 
             // Create structure based on type
             let structure: Option<Box<dyn Structure>> = match type_name {
@@ -31,15 +31,23 @@
 //! - Coordinates interactions between presentation layer and game logic
 //! - Ensures single source of truth for game state
 
+use crate::events::{EventQueue, GameEvent};
 use crate::objects::*;
+use crate::progression::PendingEvolution;
+use crate::time_of_day::DayCycle;
 use crate::world::PendingCombat;
 use ai::{
-    ActionInstance as AiActionInstance, FactValue as AiFactValue, Goal as AiGoal,
-    WorldState as AiWorldState,
+    ActionInstance as AiActionInstance, AiTrace, CandidateAction, FactKey,
+    FactValue as AiFactValue, Goal as AiGoal, TeamFact, TerrainFact, UnitFact,
+    WorldState as AiWorldState, TRACE_TARGET,
 };
-use graphics::HexCoord;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use hex::HexCoord;
+use log::{debug, log_enabled, Level};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 use units::structures::Structure;
 use uuid::Uuid;
 
@@ -47,17 +55,27 @@ use uuid::Uuid;
 const ATTACK_EXPECTED_UTILITY_WEIGHT: f32 = 1.0;
 const MIN_ACTION_COST: f32 = 0.01;
 
-/// Game event emitted by AI executors for tracking actions
-#[derive(Clone, Debug)]
-pub enum GameEvent {
-    ActionStarted {
-        unit_id: Uuid,
-        action: ai::ActionInstance,
-    },
-    ActionCompleted {
-        unit_id: Uuid,
-        action: ai::ActionInstance,
-    },
+/// Destination hex of a grounded move action (its first effect).
+pub(crate) fn move_destination(action: &AiActionInstance) -> Option<HexCoord> {
+    let (_, value) = action.effects.first()?;
+    value.as_hex()
+}
+
+/// Result of a single attack/counter-attack exchange.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExchangeOutcome {
+    /// Number of the attacker's strikes that hit
+    pub attacker_hits: u32,
+    /// Number of the defender's counter-attack strikes that hit
+    pub defender_hits: u32,
+    /// Total damage dealt by the attacker
+    pub attacker_damage: u32,
+    /// Total damage dealt by the defender's counter-attack
+    pub defender_damage: u32,
+    /// Whether the attacker died from the counter-attack
+    pub attacker_defeated: bool,
+    /// Whether the defender died from the attack
+    pub defender_defeated: bool,
 }
 
 /// ScenarioWorld: Single source of truth for game state
@@ -66,8 +84,11 @@ pub enum GameEvent {
 /// and QuestApp (presentation/controls). All game state mutations go through
 /// this structure to maintain consistency.
 pub struct ScenarioWorld {
-    /// All terrain tiles in the world, indexed by hex coordinate
-    pub terrain: HashMap<HexCoord, TerrainTile>,
+    /// All terrain tiles in the world, indexed by hex coordinate.
+    ///
+    /// Shared between clones: terrain rarely changes during play, so
+    /// simulation copies only pay for it when a tile is actually modified.
+    pub terrain: Arc<HashMap<HexCoord, TerrainTile>>,
 
     /// All units in the world, indexed by UUID
     pub units: HashMap<Uuid, GameUnit>,
@@ -80,15 +101,60 @@ pub struct ScenarioWorld {
 
     /// Pending combat awaiting player confirmation
     pub pending_combat: Option<PendingCombat>,
-    /// Queue of AI events emitted by executors (start/complete). Protected by mutex
-    /// so executor callbacks can push events from closures.
-    pub ai_event_queue: Arc<Mutex<Vec<GameEvent>>>,
+    /// Game events waiting for the presentation layer (see [`crate::events`])
+    pub events: EventQueue,
+
+    /// Scenario teams: names, colors, diplomacy and turn order
+    pub teams: TeamRoster,
 
     /// Turn-based gameplay system
     pub turn_system: crate::turn_system::TurnSystem,
     /// Last known active team (used to detect auto-advanced turns so we can
     /// reset per-team movement points when TurnSystem advances the turn)
     last_known_team: Option<Team>,
+    /// When set, AI turns are played by tactical search with this budget
+    /// instead of the GOAP planner (see `crate::tactical`)
+    pub tactical_search: Option<ai::SearchBudget>,
+    /// Collect a detailed `AiTrace` for every AI turn (for the AI inspector)
+    pub ai_trace_enabled: bool,
+    /// Trace of the most recent AI turn (only kept while tracing is active)
+    last_ai_trace: Option<AiTrace>,
+    /// Level-ups waiting for the player to choose an evolution branch
+    pub(crate) pending_evolutions: VecDeque<PendingEvolution>,
+    /// Day/night phases the scenario cycles through, one per turn
+    pub day_cycle: DayCycle,
+    /// Source of every combat roll; seed it to make a game reproducible
+    rng: StdRng,
+    /// Hexes each team currently sees (see `crate::fog_of_war`)
+    pub(crate) visibility: RefCell<HashMap<Team, HashSet<HexCoord>>>,
+}
+
+impl Clone for ScenarioWorld {
+    /// Clones the full game state for simulation (e.g. tactical search).
+    ///
+    /// Terrain is shared with the original until either side modifies it.
+    /// The clone gets its own empty event queue so simulated actions never
+    /// leak events into the real world, and starts without an AI trace.
+    fn clone(&self) -> Self {
+        Self {
+            terrain: Arc::clone(&self.terrain),
+            units: self.units.clone(),
+            interactive_objects: self.interactive_objects.clone(),
+            structures: self.structures.clone(),
+            pending_combat: self.pending_combat.clone(),
+            events: EventQueue::new(),
+            teams: self.teams.clone(),
+            turn_system: self.turn_system.clone(),
+            last_known_team: self.last_known_team,
+            tactical_search: self.tactical_search.clone(),
+            ai_trace_enabled: self.ai_trace_enabled,
+            last_ai_trace: None,
+            pending_evolutions: self.pending_evolutions.clone(),
+            day_cycle: self.day_cycle.clone(),
+            rng: self.rng.clone(),
+            visibility: self.visibility.clone(),
+        }
+    }
 }
 
 impl ScenarioWorld {
@@ -97,18 +163,23 @@ impl ScenarioWorld {
         let units: HashMap<Uuid, GameUnit>;
         let interactive_objects: HashMap<Uuid, InteractiveObject>;
         let structures: HashMap<Uuid, Box<dyn Structure>>;
+        let teams: TeamRoster;
+        let day_cycle: DayCycle;
 
         // Parse the map JSON
         match ScenarioWorld::parse_map_json(&map_json) {
             Ok(parsed) => {
                 terrain = parsed.terrain;
-                units = ScenarioWorld::populate_units(parsed.units);
+                teams = parsed.roster;
+                units = ScenarioWorld::populate_units(parsed.units, &teams);
 
                 // Items become interactive objects
                 interactive_objects = ScenarioWorld::populate_items(parsed.items);
 
                 // Structures are now proper Structure trait objects
-                structures = ScenarioWorld::populate_structures(parsed.structures);
+                structures = ScenarioWorld::populate_structures(parsed.structures, &teams);
+
+                day_cycle = parsed.day_cycle.unwrap_or_default();
             }
             Err(e) => {
                 eprintln!("Failed to parse map JSON: {}", e);
@@ -116,25 +187,37 @@ impl ScenarioWorld {
                 units = HashMap::new();
                 interactive_objects = HashMap::new();
                 structures = HashMap::new();
+                teams = TeamRoster::default();
+                day_cycle = DayCycle::default();
             }
         }
 
-        // Create a standard turn system for all scenarios
-        let mut turn_system = crate::turn_system::TurnSystem::new();
-        turn_system.set_team_control(Team::Player, true);
-        turn_system.set_team_control(Team::Enemy, false);
-        turn_system.set_team_control(Team::Neutral, false);
+        // Turn order and player control come from the scenario's teams
+        let turn_system = crate::turn_system::TurnSystem::from_roster(&teams);
 
-        Self {
-            terrain,
+        let mut world = Self {
+            terrain: Arc::new(terrain),
             units,
             interactive_objects,
             structures,
             pending_combat: None,
-            ai_event_queue: Arc::new(Mutex::new(Vec::new())),
+            events: EventQueue::new(),
+            teams,
             turn_system,
             last_known_team: None,
+            tactical_search: None,
+            ai_trace_enabled: false,
+            last_ai_trace: None,
+            pending_evolutions: VecDeque::new(),
+            day_cycle,
+            rng: StdRng::from_entropy(),
+            visibility: RefCell::default(),
+        };
+        let unit_ids: Vec<Uuid> = world.units.keys().copied().collect();
+        for unit_id in unit_ids {
+            world.refresh_terrain_hit_chance(unit_id);
         }
+        world
     }
     /// Extract detailed world state with comprehensive tactical information.
     ///
@@ -153,9 +236,9 @@ impl ScenarioWorld {
         let mut ws = AiWorldState::new();
 
         // === TEAM METADATA ===
-        ws.insert(
-            "CurrentTeam".to_string(),
-            AiFactValue::Str(format!("{:?}", team)),
+        ws.set(
+            FactKey::CurrentTeam,
+            AiFactValue::Str(self.teams.name(team).to_string()),
         );
 
         let mut friendly_positions: Vec<(Uuid, HexCoord)> = Vec::new();
@@ -166,91 +249,88 @@ impl ScenarioWorld {
             let id_str = id.to_string();
             let pos = unit.position();
             let stats = unit.unit().combat_stats();
-            let is_friendly = unit.team() == team;
+            let is_friendly = self.teams.are_allied(unit.team(), team);
 
             // Basic position and status
-            ws.insert(
-                format!("Unit:{}:At", id_str),
-                AiFactValue::Str(format!("{},{}", pos.q, pos.r)),
-            );
-            ws.insert(
-                format!("Unit:{}:Alive", id_str),
+            ws.set(FactKey::unit(&id_str, UnitFact::At), AiFactValue::Hex(pos));
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Alive),
                 AiFactValue::Bool(unit.unit().is_alive()),
             );
-            ws.insert(
-                format!("Unit:{}:Team", id_str),
-                AiFactValue::Str(format!("{:?}", unit.team())),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Team),
+                AiFactValue::Str(self.teams.name(unit.team()).to_string()),
             );
-            ws.insert(
-                format!("Unit:{}:IsFriendly", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsFriendly),
                 AiFactValue::Bool(is_friendly),
             );
 
             // Health information
-            ws.insert(
-                format!("Unit:{}:Health", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Health),
                 AiFactValue::Int(stats.health),
             );
-            ws.insert(
-                format!("Unit:{}:MaxHealth", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MaxHealth),
                 AiFactValue::Int(stats.max_health),
             );
             let health_pct = (stats.health * 100) / stats.max_health.max(1);
-            ws.insert(
-                format!("Unit:{}:HealthPercent", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::HealthPercent),
                 AiFactValue::Int(health_pct),
             );
-            ws.insert(
-                format!("Unit:{}:IsWounded", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsWounded),
                 AiFactValue::Bool(health_pct < 50),
             );
 
             // Movement information
-            ws.insert(
-                format!("Unit:{}:MovesLeft", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MovesLeft),
                 AiFactValue::Int(unit.moves_left()),
             );
-            ws.insert(
-                format!("Unit:{}:MovementSpeed", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MovementSpeed),
                 AiFactValue::Int(stats.movement_speed),
             );
 
             // Combat information
-            ws.insert(
-                format!("Unit:{}:AttackPower", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackPower),
                 AiFactValue::Int(stats.base_attack as i32),
             );
-            ws.insert(
-                format!("Unit:{}:AttackedThisTurn", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackedThisTurn),
                 AiFactValue::Bool(stats.attacked_this_turn),
             );
 
             // Attack range
             let attacks = unit.unit().get_attacks();
             let max_range = attacks.iter().map(|a| a.range).max().unwrap_or(1);
-            ws.insert(
-                format!("Unit:{}:AttackRange", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackRange),
                 AiFactValue::Int(max_range),
             );
 
             // Threat level calculation
             let threat = self.calculate_threat_level(unit);
-            ws.insert(
-                format!("Unit:{}:ThreatLevel", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::ThreatLevel),
                 AiFactValue::Int(threat),
             );
 
             // Terrain defense
             let defense = unit.unit().get_defense();
-            ws.insert(
-                format!("Unit:{}:Defense", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Defense),
                 AiFactValue::Int(defense as i32),
             );
 
-            // Store positions for clustering calculations
+            // Store positions for clustering calculations (neutral units are neither)
             if is_friendly {
                 friendly_positions.push((*id, pos));
-            } else {
+            } else if self.teams.are_hostile(unit.team(), team) {
                 enemy_positions.push((*id, pos));
             }
         }
@@ -259,15 +339,12 @@ impl ScenarioWorld {
         // Include terrain for all unit positions
         for (_, pos) in friendly_positions.iter().chain(enemy_positions.iter()) {
             if let Some(terrain_tile) = self.get_terrain(*pos) {
-                let pos_key = format!("{},{}", pos.q, pos.r);
-                let terrain_type = terrain_tile.sprite_type();
-
-                ws.insert(
-                    format!("Terrain:{}:Type", pos_key),
-                    AiFactValue::Str(format!("{:?}", terrain_type)),
+                ws.set(
+                    FactKey::terrain(*pos, TerrainFact::Type),
+                    AiFactValue::Str(terrain_tile.terrain().id.clone()),
                 );
-                ws.insert(
-                    format!("Terrain:{}:MoveCost", pos_key),
+                ws.set(
+                    FactKey::terrain(*pos, TerrainFact::MoveCost),
                     AiFactValue::Int(terrain_tile.movement_cost()),
                 );
             }
@@ -283,12 +360,12 @@ impl ScenarioWorld {
                 .filter(|(other_id, other_pos)| other_id != id && pos.distance(*other_pos) <= 2)
                 .count();
 
-            ws.insert(
-                format!("Unit:{}:NearbyAllies", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::NearbyAllies),
                 AiFactValue::Int(nearby_allies as i32),
             );
-            ws.insert(
-                format!("Unit:{}:IsIsolated", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsIsolated),
                 AiFactValue::Bool(nearby_allies == 0),
             );
 
@@ -298,8 +375,8 @@ impl ScenarioWorld {
                 .map(|(_, epos)| pos.distance(*epos))
                 .min()
             {
-                ws.insert(
-                    format!("Unit:{}:NearestEnemyDist", id_str),
+                ws.set(
+                    FactKey::unit(&id_str, UnitFact::NearestEnemyDist),
                     AiFactValue::Int(nearest_dist),
                 );
             }
@@ -314,20 +391,20 @@ impl ScenarioWorld {
                     .filter(|(_, epos)| pos.distance(*epos) <= max_range)
                     .count();
 
-                ws.insert(
-                    format!("Unit:{}:EnemiesInRange", id_str),
+                ws.set(
+                    FactKey::unit(&id_str, UnitFact::EnemiesInRange),
                     AiFactValue::Int(enemies_in_range as i32),
                 );
             }
         }
 
         // === TEAM-LEVEL METRICS ===
-        ws.insert(
-            "Team:AllyCount".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::AllyCount),
             AiFactValue::Int(friendly_positions.len() as i32),
         );
-        ws.insert(
-            "Team:EnemyCount".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::EnemyCount),
             AiFactValue::Int(enemy_positions.len() as i32),
         );
 
@@ -335,7 +412,7 @@ impl ScenarioWorld {
         let total_health: i32 = self
             .units
             .iter()
-            .filter(|(_, u)| u.team() == team)
+            .filter(|(_, u)| self.teams.are_allied(u.team(), team))
             .map(|(_, u)| u.unit().combat_stats().health)
             .sum();
         let avg_health = if !friendly_positions.is_empty() {
@@ -343,8 +420,8 @@ impl ScenarioWorld {
         } else {
             0
         };
-        ws.insert(
-            "Team:AverageHealth".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::AverageHealth),
             AiFactValue::Int(avg_health),
         );
 
@@ -385,6 +462,7 @@ impl ScenarioWorld {
 
         threat
     }
+
     pub fn generate_team_actions(&self, team: Team) -> Vec<AiActionInstance> {
         let mut out: Vec<AiActionInstance> = Vec::new();
 
@@ -397,6 +475,8 @@ impl ScenarioWorld {
             }
 
             let uid_str = id.to_string();
+            // Built once and cloned, so every action shares the key's symbol
+            let at_key = FactKey::unit(&uid_str, UnitFact::At);
             let pos = unit.position();
             let moves_left = unit.moves_left();
 
@@ -408,14 +488,8 @@ impl ScenarioWorld {
                     continue;
                 }
 
-                let preconds = vec![(
-                    format!("Unit:{}:At", id),
-                    AiFactValue::Str(format!("{},{}", pos.q, pos.r)),
-                )];
-                let effects = vec![(
-                    format!("Unit:{}:At", id),
-                    AiFactValue::Str(format!("{},{}", tile.q, tile.r)),
-                )];
+                let preconds = vec![(at_key.clone(), AiFactValue::Hex(pos))];
+                let effects = vec![(at_key.clone(), AiFactValue::Hex(*tile))];
 
                 out.push(AiActionInstance {
                     name: format!("Move-{}->{},{}", uid_str, tile.q, tile.r),
@@ -428,10 +502,11 @@ impl ScenarioWorld {
 
             // Ground Attack actions for reachable attack positions based on unit's available attacks and ranges
             for (other_id, other_unit) in &self.units {
-                if other_unit.team() == team {
+                if !self.teams.are_hostile(other_unit.team(), team) {
                     continue;
                 }
                 let enemy_pos = other_unit.position();
+                let alive_key = FactKey::unit(other_id.to_string(), UnitFact::Alive);
 
                 // Candidate attacker positions: current position + reachable tiles
                 let mut candidate_positions: Vec<HexCoord> = reachable.keys().cloned().collect();
@@ -457,30 +532,19 @@ impl ScenarioWorld {
                         }
 
                         let preconds = vec![
-                            (
-                                format!("Unit:{}:At", id),
-                                AiFactValue::Str(format!("{},{}", from.q, from.r)),
-                            ),
-                            (format!("Unit:{}:Alive", other_id), AiFactValue::Bool(true)),
+                            (at_key.clone(), AiFactValue::Hex(*from)),
+                            (alive_key.clone(), AiFactValue::Bool(true)),
                         ];
-                        let effects =
-                            vec![(format!("Unit:{}:Alive", other_id), AiFactValue::Bool(false))];
+                        let effects = vec![(alive_key.clone(), AiFactValue::Bool(false))];
 
-                        // Estimate expected damage and hit chance to convert into an expected-utility cost.
-                        // Hit chance is approximated using the same formula as combat resolver:
-                        // final_hit_chance = (defender.get_defense() as i32).clamp(10,95)
-                        let defender_def = other_unit.unit().get_defense() as i32;
-                        let final_hit_chance = defender_def.clamp(10, 95) as u8;
-                        let hit_prob = final_hit_chance as f32 / 100.0;
-
-                        // Compute expected damage on hit using attack.damage and defender resistances
-                        let def_stats = other_unit.unit().combat_stats();
-                        let resistance =
-                            def_stats.resistances.get_resistance(attack.damage_type) as f32;
-                        let resist_mul = 1.0 - (resistance / 100.0);
-                        let damage_on_hit = (attack.damage as f32 * resist_mul).max(1.0);
-
-                        let expected_damage = hit_prob * damage_on_hit;
+                        // Convert the forecast exchange into an expected-utility
+                        // cost: damage dealt less the damage taken back if the
+                        // enemy can retaliate against this attack from there
+                        let expected_damage = self
+                            .planned_forecast(*id, *other_id, attack, *from)
+                            .map_or(0.0, |forecast| {
+                                forecast.expected_damage_dealt() - forecast.expected_damage_taken()
+                            });
 
                         // Movement cost to get to `from` (0 if already at pos)
                         let movement_cost = if *from == pos {
@@ -525,41 +589,63 @@ impl ScenarioWorld {
     /// This method coordinates AI planning by:
     /// 1. Extracting world state for AI crate via `extract_detailed_world_state()`
     /// 2. Generating possible actions via `generate_team_actions()`
-    /// 3. Delegating planning to AI crate via `plan_for_team()`
+    /// 3. Delegating planning to AI crate via `plan_for_team_traced()`
     /// 4. Executing planned actions (movement, combat) via ScenarioWorld methods
     ///
     /// The AI crate provides planning logic, while ScenarioWorld handles execution.
+    /// Decisions are logged on the `ai::trace` log target and recorded in an
+    /// [`AiTrace`] available from `last_ai_trace()` when tracing is active.
     ///
     /// # Only runs for AI-controlled teams
     pub fn run_ai_for_current_team(&mut self) {
         let current_team = self.turn_system.current_team();
-        println!(
-            "🤖 [AI DEBUG] run_ai_for_current_team called for team {:?}",
-            current_team
+        debug!(
+            target: TRACE_TARGET,
+            "run_ai_for_current_team called for team {:?}", current_team
         );
 
         if self.turn_system.is_current_team_player_controlled() {
-            println!("🤖 [AI DEBUG] Team is player-controlled, skipping AI");
+            debug!(target: TRACE_TARGET, "Team is player-controlled, skipping AI");
             return; // Player team handled by UI
         }
 
-        // Count units on this team
-        let team_units: Vec<_> = self
-            .units
-            .iter()
-            .filter(|(_, u)| u.team() == current_team)
-            .collect();
-        println!("🤖 [AI DEBUG] Team has {} units", team_units.len());
-        for (_id, unit) in &team_units {
-            println!("🤖 [AI DEBUG]   - {} at {:?}", unit.name(), unit.position());
+        if let Some(budget) = self.tactical_search.clone() {
+            self.run_tactical_turn(&budget);
+            return;
         }
 
+        let mut trace = AiTrace::new(self.teams.name(current_team), self.turn_number());
+
+        // Units low on health drink a healing potion before planning
+        self.use_emergency_consumables(current_team, &mut trace);
+
         // Prepare AI world state and actions
         let ws = self.extract_detailed_world_state(current_team);
-        println!("🤖 [AI DEBUG] World state extracted");
-
         let actions = self.generate_team_actions(current_team);
-        println!("🤖 [AI DEBUG] Generated {} possible actions", actions.len());
+        debug!(
+            target: TRACE_TARGET,
+            "Extracted {} facts, generated {} possible actions ({} moves, {} attacks)",
+            ws.facts.len(),
+            actions.len(),
+            actions.iter().filter(|a| a.name.starts_with("Move-")).count(),
+            actions.iter().filter(|a| a.name.starts_with("Attack-")).count()
+        );
+
+        if self.ai_trace_active() {
+            trace.facts = ws
+                .string_facts()
+                .into_iter()
+                .map(|(k, v)| (k, format!("{:?}", v)))
+                .collect();
+            trace.candidates = actions
+                .iter()
+                .map(|a| CandidateAction {
+                    name: a.name.clone(),
+                    agent: a.agent.clone(),
+                    cost: a.cost,
+                })
+                .collect();
+        }
 
         // Build goals: naive goal is to kill any enemy unit found (per agent we add goals later)
         use std::collections::HashMap as StdHashMap;
@@ -573,293 +659,225 @@ impl ScenarioWorld {
             let aid = id.to_string();
             agent_order.push(aid.clone());
 
+            let entry = trace.agent_mut(&aid);
+            entry.name = unit.name();
+            entry.path = vec![unit.position()];
+
             // Goals for agent: find closest enemy and set goal to kill them
             let mut goals: Vec<AiGoal> = Vec::new();
 
-            // Find all enemy units and their distances
-            let mut enemies_with_distance: Vec<(Uuid, &GameUnit, i32)> = Vec::new();
-            for (enemy_id, enemy_unit) in &self.units {
-                if enemy_unit.team() != current_team {
-                    let distance = unit.position().distance(enemy_unit.position());
-                    enemies_with_distance.push((*enemy_id, enemy_unit, distance));
-                }
-            }
-
-            // Sort by distance (closest first)
-            enemies_with_distance.sort_by_key(|(_, _, dist)| *dist);
+            // Find the closest enemy unit
+            let closest_enemy = self
+                .units
+                .iter()
+                .filter(|(_, enemy)| self.teams.are_hostile(enemy.team(), current_team))
+                .map(|(enemy_id, enemy)| {
+                    (*enemy_id, enemy, unit.position().distance(enemy.position()))
+                })
+                .min_by_key(|(_, _, distance)| *distance);
 
             // Set goal to kill the closest enemy
-            if let Some((enemy_id, enemy_unit, distance)) = enemies_with_distance.first() {
-                goals.push(AiGoal {
-                    key: format!("Unit:{}:Alive", enemy_id),
-                    value: AiFactValue::Bool(false),
-                });
-                println!(
-                    "🤖 [AI DEBUG] Unit {} targeting closest enemy {} at distance {}",
+            if let Some((enemy_id, enemy_unit, distance)) = closest_enemy {
+                goals.push(AiGoal::new(
+                    FactKey::unit(enemy_id.to_string(), UnitFact::Alive),
+                    AiFactValue::Bool(false),
+                ));
+                entry.target = Some(enemy_unit.position());
+                debug!(
+                    target: TRACE_TARGET,
+                    "Unit {} targeting closest enemy {} at distance {}",
                     unit.name(),
                     enemy_unit.name(),
                     distance
                 );
             }
 
-            println!(
-                "🤖 [AI DEBUG] Unit {} has {} goals",
-                unit.name(),
-                goals.len()
-            );
             goals_per_agent.insert(aid, goals);
         }
 
-        // Call team planner (bounded search per agent)
-        println!(
-            "🤖 [AI DEBUG] Calling planner for {} agents...",
-            agent_order.len()
-        );
-
-        // Debug: Count action types
-        let move_count = actions
-            .iter()
-            .filter(|a| a.name.starts_with("Move-"))
-            .count();
-        let attack_count = actions
-            .iter()
-            .filter(|a| a.name.starts_with("Attack-"))
-            .count();
-        println!(
-            "🤖 [AI DEBUG] Action breakdown: {} moves, {} attacks",
-            move_count, attack_count
-        );
-
-        // Debug: Print first few actions to see what's available
-        println!("🤖 [AI DEBUG] Sample actions (first 10):");
-        for (i, action) in actions.iter().take(10).enumerate() {
-            println!(
-                "🤖 [AI DEBUG]   {}. {} (cost: {})",
-                i, action.name, action.cost
-            );
-        }
-
-        // Debug: Show any attack actions if they exist
-        let sample_attacks: Vec<_> = actions
-            .iter()
-            .filter(|a| a.name.starts_with("Attack-"))
-            .take(3)
-            .collect();
-        if !sample_attacks.is_empty() {
-            println!("🤖 [AI DEBUG] Sample attack actions:");
-            for action in sample_attacks {
-                println!("🤖 [AI DEBUG]   - {} (cost: {})", action.name, action.cost);
-                println!(
-                    "🤖 [AI DEBUG]      Preconditions: {:?}",
-                    action.preconditions
-                );
-                println!("🤖 [AI DEBUG]      Effects: {:?}", action.effects);
-            }
-        } else {
-            println!("🤖 [AI DEBUG] ⚠️ NO ATTACK ACTIONS GENERATED! Units may be out of range.");
-        }
-
-        // Debug: Print goals
-        println!("🤖 [AI DEBUG] Goals per agent:");
-        for (agent, goals) in &goals_per_agent {
-            println!("🤖 [AI DEBUG]   Agent {}: {} goals", agent, goals.len());
-            for goal in goals {
-                println!("🤖 [AI DEBUG]      {} = {:?}", goal.key, goal.value);
-            }
-        }
-
-        // Increase planner depth limit significantly
-        let plans = ai::plan_for_team(&ws, &actions, &goals_per_agent, &agent_order, 5000);
-        println!(
-            "🤖 [AI DEBUG] Planner returned plans for {} agents",
-            plans.len()
+        let plans = ai::plan_for_team_traced(
+            &ws,
+            &actions,
+            &goals_per_agent,
+            &agent_order,
+            5000,
+            &mut trace,
         );
 
         // Execute plans per agent
-        // executed_actions_count removed — we end AI turn immediately after executing plans
         let mut total_actions_executed = 0;
-        for (agent, plan) in plans {
-            println!(
-                "🤖 [AI DEBUG] Agent {} has plan with {} steps",
+        for agent in &agent_order {
+            let Ok(uuid) = Uuid::parse_str(agent) else {
+                continue;
+            };
+            let plan = plans.get(agent).cloned().unwrap_or_default();
+            debug!(
+                target: TRACE_TARGET,
+                "Agent {} has plan with {} steps",
                 agent,
                 plan.len()
             );
 
-            // find unit uuid
-            if let Ok(uuid) = Uuid::parse_str(&agent) {
-                if plan.is_empty() {
-                    println!("🤖 [AI DEBUG] Agent {} has empty plan, using fallback: move toward nearest enemy", agent);
-
-                    // FALLBACK: Move toward nearest enemy
-                    if let Some(unit) = self.units.get(&uuid) {
-                        let unit_pos = unit.position();
-                        let unit_team = unit.team();
-
-                        // Find nearest enemy
-                        let mut nearest_enemy: Option<(Uuid, &GameUnit, i32)> = None;
-                        for (enemy_id, enemy_unit) in &self.units {
-                            if enemy_unit.team() != unit_team {
-                                let distance = unit_pos.distance(enemy_unit.position());
-                                if nearest_enemy.is_none() || distance < nearest_enemy.unwrap().2 {
-                                    nearest_enemy = Some((*enemy_id, enemy_unit, distance));
-                                }
-                            }
-                        }
+            // Actions visible to this agent; plan indices refer to this list
+            let agent_actions: Vec<AiActionInstance> = actions
+                .iter()
+                .filter(|a| a.agent.as_ref().map(|s| s == agent).unwrap_or(false))
+                .cloned()
+                .collect();
 
-                        if let Some((_, enemy, distance)) = nearest_enemy {
-                            println!(
-                                "🤖 [AI DEBUG] Fallback: Moving toward enemy at distance {}",
-                                distance
-                            );
-                            let enemy_pos = enemy.position();
-
-                            // Find the best move action that gets us closer
-                            let agent_actions: Vec<AiActionInstance> = actions
-                                .iter()
-                                .filter(|a| a.agent.as_ref().map(|s| s == &agent).unwrap_or(false))
-                                .cloned()
-                                .collect();
-
-                            let mut best_move: Option<(usize, i32)> = None; // (action_index, new_distance)
-                            for (idx, action) in agent_actions.iter().enumerate() {
-                                if action.name.starts_with("Move-") {
-                                    // Extract destination from effects
-                                    if let Some((_, AiFactValue::Str(dest))) =
-                                        action.effects.first()
-                                    {
-                                        let parts: Vec<&str> = dest.split(',').collect();
-                                        if parts.len() == 2 {
-                                            if let (Ok(q), Ok(r)) =
-                                                (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                            {
-                                                let dest_coord = HexCoord::new(q, r);
-                                                let new_distance = dest_coord.distance(enemy_pos);
-
-                                                if best_move.is_none()
-                                                    || new_distance < best_move.unwrap().1
-                                                {
-                                                    best_move = Some((idx, new_distance));
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-
-                            // Execute best move
-                            if let Some((idx, new_dist)) = best_move {
-                                if let Some(action) = agent_actions.get(idx) {
-                                    println!("🤖 [AI DEBUG] Executing fallback move: {} (new distance: {})", action.name, new_dist);
-                                    if let Some((_, AiFactValue::Str(dest))) =
-                                        action.effects.first()
-                                    {
-                                        let parts: Vec<&str> = dest.split(',').collect();
-                                        if parts.len() == 2 {
-                                            if let (Ok(q), Ok(r)) =
-                                                (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                            {
-                                                let dest_coord = HexCoord::new(q, r);
-                                                match self.move_unit(uuid, dest_coord) {
-                                                    Ok(()) => {
-                                                        println!("🤖 [AI DEBUG] Fallback move successful!");
-                                                        total_actions_executed += 1;
-                                                    }
-                                                    Err(e) => println!(
-                                                        "🤖 [AI DEBUG] Fallback move failed: {}",
-                                                        e
-                                                    ),
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+            if plan.is_empty() {
+                // FALLBACK: Move toward nearest enemy
+                let Some(unit) = self.units.get(&uuid) else {
                     continue;
-                }
-                // For simplicity, perform actions in plan for this agent
-                let agent_actions: Vec<AiActionInstance> = actions
+                };
+                let unit_pos = unit.position();
+                let unit_team = unit.team();
+
+                let nearest_enemy_pos = self
+                    .units
+                    .values()
+                    .filter(|enemy| self.teams.are_hostile(enemy.team(), unit_team))
+                    .map(|enemy| enemy.position())
+                    .min_by_key(|pos| unit_pos.distance(*pos));
+                let Some(enemy_pos) = nearest_enemy_pos else {
+                    continue;
+                };
+
+                // Find the move action that gets us closest
+                let best_move = agent_actions
                     .iter()
-                    .filter(|a| a.agent.as_ref().map(|s| s == &agent).unwrap_or(false))
-                    .cloned()
-                    .collect();
-                println!(
-                    "🤖 [AI DEBUG] Agent {} has {} available actions",
-                    agent,
-                    agent_actions.len()
+                    .filter(|a| a.name.starts_with("Move-"))
+                    .filter_map(move_destination)
+                    .min_by_key(|dest| dest.distance(enemy_pos));
+
+                let entry = trace.agent_mut(agent);
+                entry.target = Some(enemy_pos);
+                let Some(dest) = best_move else {
+                    entry.fallback = Some("no move available".to_string());
+                    continue;
+                };
+                entry.fallback = Some(format!(
+                    "move toward nearest enemy (distance {} -> {})",
+                    unit_pos.distance(enemy_pos),
+                    dest.distance(enemy_pos)
+                ));
+                entry.path.push(dest);
+
+                let result = self.move_unit(uuid, dest);
+                if result.is_ok() {
+                    total_actions_executed += 1;
+                }
+                trace.record_command(
+                    Some(agent),
+                    format!("Move to ({}, {})", dest.q, dest.r),
+                    result,
                 );
+                continue;
+            }
 
-                for &idx in &plan {
-                    if let Some(a) = agent_actions.get(idx) {
-                        println!("🤖 [AI DEBUG] Executing action: {}", a.name);
-                        // If action is Move (name starts with Move-), parse target and call move_unit
-                        if a.name.starts_with("Move-") {
-                            // effects contain Unit:{id}:At -> "q,r"
-                            if let Some((_, AiFactValue::Str(dest))) = a.effects.first() {
-                                let parts: Vec<&str> = dest.split(',').collect();
-                                if parts.len() == 2 {
-                                    if let (Ok(q), Ok(r)) =
-                                        (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                    {
-                                        let dest_coord = HexCoord::new(q, r);
-                                        println!("🤖 [AI DEBUG] Moving to ({}, {})", q, r);
-                                        // Use move_unit; ignore errors for prototype
-                                        match self.move_unit(uuid, dest_coord) {
-                                            Ok(()) => {
-                                                println!("🤖 [AI DEBUG] Move successful!");
-                                                total_actions_executed += 1;
-                                            }
-                                            Err(e) => println!("🤖 [AI DEBUG] Move failed: {}", e),
-                                        }
-                                    }
-                                }
-                            }
-                        } else if a.name.starts_with("Attack-") {
-                            // effects contain Unit:{other}:Alive=false; extract other id
-                            if let Some((k, _)) = a.effects.first() {
-                                if k.starts_with("Unit:") && k.ends_with(":Alive") {
-                                    let mid = &k[5..k.len() - 6];
-                                    if let Ok(target_uuid) = Uuid::parse_str(mid) {
-                                        println!("🤖 [AI DEBUG] Attacking target {}", target_uuid);
-                                        // Request combat (this will set pending_combat). If the
-                                        // request fails (e.g., attacker already attacked), skip
-                                        // executing combat.
-                                        // request_combat now returns Ok(()) even when it silently
-                                        // skips creating a pending combat (attacker already
-                                        // attacked). Only execute if a pending combat was
-                                        // actually created.
-                                        let _ = self.request_combat(uuid, target_uuid);
-                                        if self.pending_combat.is_some() {
-                                            println!("🤖 [AI DEBUG] Executing combat...");
-                                            // execute_pending_combat may set state; count it as an executed action
-                                            let _ = self.execute_pending_combat();
-                                            total_actions_executed += 1;
-                                        } else {
-                                            println!("🤖 [AI DEBUG] Combat request failed (unit may have already attacked)");
-                                        }
-                                    }
-                                }
-                            }
+            for &idx in &plan {
+                let Some(a) = agent_actions.get(idx) else {
+                    debug!(target: TRACE_TARGET, "Invalid action index {} in plan", idx);
+                    continue;
+                };
+
+                if a.name.starts_with("Move-") {
+                    // effects contain Unit:{id}:At -> Hex
+                    let Some(dest) = move_destination(a) else {
+                        continue;
+                    };
+                    trace.agent_mut(agent).path.push(dest);
+
+                    let result = self.move_unit(uuid, dest);
+                    if result.is_ok() {
+                        total_actions_executed += 1;
+                    }
+                    trace.record_command(
+                        Some(agent),
+                        format!("Move to ({}, {})", dest.q, dest.r),
+                        result,
+                    );
+                } else if a.name.starts_with("Attack-") {
+                    // effects contain Unit:{other}:Alive=false; extract other id
+                    let Some(target_uuid) = a.effects.first().and_then(|(k, _)| match k {
+                        FactKey::Unit(target, UnitFact::Alive) => {
+                            Uuid::parse_str(target.as_str()).ok()
+                        }
+                        _ => None,
+                    }) else {
+                        continue;
+                    };
+                    let target_name = match self.units.get(&target_uuid) {
+                        Some(target) => {
+                            trace.agent_mut(agent).target = Some(target.position());
+                            target.name()
+                        }
+                        None => target_uuid.to_string(),
+                    };
+
+                    // request_combat returns Ok(()) even when it silently skips
+                    // creating a pending combat (attacker already attacked).
+                    // Only execute if a pending combat was actually created.
+                    let result = self.request_combat(uuid, target_uuid).and_then(|()| {
+                        if self.pending_combat.is_some() {
+                            self.execute_pending_combat()
+                        } else {
+                            Err("Attacker has already attacked this turn".to_string())
                         }
-                    } else {
-                        println!("🤖 [AI DEBUG] Invalid action index {} in plan", idx);
+                    });
+                    if result.is_ok() {
+                        total_actions_executed += 1;
                     }
+                    trace.record_command(Some(agent), format!("Attack {}", target_name), result);
                 }
             }
         }
 
-        println!(
-            "🤖 [AI DEBUG] AI executed {} total actions",
-            total_actions_executed
+        debug!(
+            target: TRACE_TARGET,
+            "AI executed {} total actions", total_actions_executed
         );
+        self.finish_ai_trace(trace);
+
         // End AI turn after actions (use GameWorld API so unit moves are reset)
         self.end_current_turn();
     }
 
+    /// Returns true when AI turns should collect a detailed [`AiTrace`].
+    ///
+    /// Traces are collected when `ai_trace_enabled` is set or when debug
+    /// logging is enabled for the `ai::trace` log target.
+    pub fn ai_trace_active(&self) -> bool {
+        self.ai_trace_enabled || log_enabled!(target: TRACE_TARGET, Level::Debug)
+    }
+
+    /// Returns the trace recorded during the most recent AI turn, if collected.
+    pub fn last_ai_trace(&self) -> Option<&AiTrace> {
+        self.last_ai_trace.as_ref()
+    }
+
+    /// Logs a completed AI turn trace and keeps it when tracing is active.
+    pub(crate) fn finish_ai_trace(&mut self, trace: AiTrace) {
+        if log_enabled!(target: TRACE_TARGET, Level::Debug) {
+            for line in trace.summary_lines(None) {
+                debug!(target: TRACE_TARGET, "{}", line);
+            }
+        }
+        if log_enabled!(target: TRACE_TARGET, Level::Trace) {
+            if let Ok(json) = trace.to_json() {
+                log::trace!(target: TRACE_TARGET, "{}", json);
+            }
+        }
+        if self.ai_trace_active() {
+            self.last_ai_trace = Some(trace);
+        }
+    }
+
     pub fn add_unit(&mut self, unit: GameUnit) -> Uuid {
         let id = unit.id();
         self.units.insert(id, unit);
+        self.refresh_terrain_hit_chance(id);
+        self.invalidate_visibility();
         id
     }
 
@@ -873,6 +891,8 @@ impl ScenarioWorld {
     ///
     /// `Some(GameUnit)` if the unit existed, `None` otherwise
     pub fn remove_unit(&mut self, id: Uuid) -> Option<GameUnit> {
+        self.pending_evolutions.retain(|p| p.unit_id != id);
+        self.invalidate_visibility();
         self.units.remove(&id)
     }
 
@@ -899,6 +919,8 @@ impl ScenarioWorld {
     ///
     /// `Some(&mut GameUnit)` if found, `None` otherwise
     pub fn get_unit_mut(&mut self, id: Uuid) -> Option<&mut GameUnit> {
+        // The caller may move the unit
+        self.invalidate_visibility();
         self.units.get_mut(&id)
     }
 
@@ -982,6 +1004,96 @@ impl ScenarioWorld {
         &self.interactive_objects
     }
 
+    /// Moves the item of an item pickup into a unit's inventory.
+    ///
+    /// Equipment is equipped straight away when its slot allows it;
+    /// consumables stay in the inventory. The emptied pickup is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - UUID of the unit picking the item up
+    /// * `object_id` - UUID of the item pickup
+    ///
+    /// # Returns
+    ///
+    /// The picked up item's name, or `Err(String)` if the unit or item is missing
+    pub fn pickup_item(&mut self, unit_id: Uuid, object_id: Uuid) -> Result<String, String> {
+        if !self.units.contains_key(&unit_id) {
+            return Err("Unit not found".to_string());
+        }
+        let item = self
+            .interactive_objects
+            .get_mut(&object_id)
+            .ok_or("Item object not found")?
+            .take_item()
+            .ok_or("Item no longer available")?;
+        self.remove_interactive_object(object_id);
+
+        let game_unit = self.units.get(&unit_id).ok_or("Unit not found")?;
+        let (team, position) = (game_unit.team(), game_unit.position());
+        let item_name = item.name.clone();
+        self.emit(GameEvent::ItemPickedUp {
+            unit_id,
+            team,
+            item_name: item_name.clone(),
+            position,
+        });
+        let item_internal_id = item.id;
+        let item_type = item.item_type.clone();
+
+        // Add to inventory first, then auto-equip; consumables and items
+        // whose requirements are not met stay in the inventory
+        let game_unit = self.units.get_mut(&unit_id).ok_or("Unit not found")?;
+        game_unit.unit_mut().add_item_to_inventory(item);
+        if item_type != items::ItemType::Consumable
+            && game_unit.unit_mut().equip_item(item_internal_id).is_ok()
+        {
+            self.emit(GameEvent::ItemEquipped {
+                unit_id,
+                team,
+                item_name: item_name.clone(),
+                equipped: true,
+            });
+        }
+        Ok(item_name)
+    }
+
+    /// Recruits a new unit for a team onto an empty hex.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team the recruit joins
+    /// * `unit_type` - Unit type name understood by [`units::UnitFactory`]
+    /// * `position` - Hex to place the recruit on
+    ///
+    /// # Returns
+    ///
+    /// The new unit's UUID, or `Err(String)` if the hex is off the map or
+    /// occupied, or the unit type is unknown
+    pub fn recruit_unit(
+        &mut self,
+        team: Team,
+        unit_type: &str,
+        position: HexCoord,
+    ) -> Result<Uuid, String> {
+        if !self.terrain.contains_key(&position) {
+            return Err(format!("{:?} is not on the map", position));
+        }
+        if !self.get_units_at_position(position).is_empty() {
+            return Err(format!("{:?} is already occupied", position));
+        }
+        let unit = units::UnitFactory::create(unit_type, None, Some(position))?;
+        let name = unit.name().to_string();
+        let unit_id = self.add_unit(GameUnit::new_with_team(unit, team));
+        self.emit(GameEvent::UnitRecruited {
+            unit_id,
+            name,
+            team,
+            position,
+        });
+        Ok(unit_id)
+    }
+
     /// Returns a reference to terrain at the specified position.
     ///
     /// # Arguments
@@ -1005,7 +1117,8 @@ impl ScenarioWorld {
     ///
     /// `Some(&mut TerrainTile)` if terrain exists, `None` otherwise
     pub fn get_terrain_mut(&mut self, position: HexCoord) -> Option<&mut TerrainTile> {
-        self.terrain.get_mut(&position)
+        self.invalidate_visibility();
+        Arc::make_mut(&mut self.terrain).get_mut(&position)
     }
 
     /// Returns a reference to all terrain tiles in the world.
@@ -1022,6 +1135,7 @@ impl ScenarioWorld {
 
     /// Returns a mutable reference to a structure by ID.
     pub fn get_structure_mut(&mut self, id: Uuid) -> Option<&mut Box<dyn Structure>> {
+        self.invalidate_visibility();
         self.structures.get_mut(&id)
     }
 
@@ -1052,10 +1166,42 @@ impl ScenarioWorld {
     pub fn add_structure(&mut self, structure: Box<dyn Structure>) {
         let id = structure.id();
         self.structures.insert(id, structure);
+        self.invalidate_visibility();
+    }
+
+    /// Damages a structure; siege damage ignores part of its defense.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - UUID of the structure
+    /// * `damage` - Damage before the structure's defenses
+    /// * `is_siege` - Whether the damage comes from a siege attack
+    ///
+    /// # Returns
+    ///
+    /// The damage actually dealt, or `Err(String)` if the structure does not exist
+    pub fn damage_structure(
+        &mut self,
+        id: Uuid,
+        damage: u32,
+        is_siege: bool,
+    ) -> Result<u32, String> {
+        let structure = self.structures.get_mut(&id).ok_or("Structure not found")?;
+        let dealt = structure.take_damage(damage, is_siege);
+        let event = GameEvent::StructureDamaged {
+            structure_id: id,
+            position: structure.position(),
+            damage: dealt,
+            durability: structure.current_durability(),
+            destroyed: structure.is_destroyed(),
+        };
+        self.emit(event);
+        Ok(dealt)
     }
 
     /// Removes a structure from the world by ID.
     pub fn remove_structure(&mut self, id: Uuid) -> Option<Box<dyn Structure>> {
+        self.invalidate_visibility();
         self.structures.remove(&id)
     }
 
@@ -1078,6 +1224,7 @@ impl ScenarioWorld {
             if prev_team != Some(current_team) {
                 self.reset_moves_for_team(current_team);
                 self.last_known_team = Some(current_team);
+                self.emit_turn_started();
             }
         }
 
@@ -1153,13 +1300,18 @@ impl ScenarioWorld {
         self.reset_moves_for_team(current_team);
         // Track the active team so that future auto-advances can be detected
         self.last_known_team = Some(current_team);
+        self.emit_turn_started();
     }
 
     /// Ends the current turn and advances to the next team
     pub fn end_current_turn(&mut self) {
         // End the turn in the turn system (advances to next team)
         self.turn_system.end_turn();
+        self.begin_team_turn();
+    }
 
+    /// Resets per-turn state after the turn system has advanced to a new team.
+    pub(crate) fn begin_team_turn(&mut self) {
         // Reset per-turn combat flags for all units since the team has advanced.
         // This ensures `attacked_this_turn` is cleared and units may act again.
         for unit in self.units.values_mut() {
@@ -1169,8 +1321,19 @@ impl ScenarioWorld {
         // Reset movement points for units on the new current team
         let current_team = self.turn_system.current_team();
         self.reset_moves_for_team(current_team);
+        // Count down buffs, debuffs and cooldowns of the team whose turn begins
+        self.tick_status_effects(current_team);
         // Update last known team to avoid duplicate resets
         self.last_known_team = Some(current_team);
+        self.emit_turn_started();
+    }
+
+    /// Reports the start of the current team's turn.
+    fn emit_turn_started(&self) {
+        self.emit(GameEvent::TurnStarted {
+            team: self.turn_system.current_team(),
+            turn: self.turn_number(),
+        });
     }
 
     /// Returns all legal move positions for a unit.
@@ -1222,7 +1385,7 @@ impl ScenarioWorld {
     /// Checks if a unit can move to the specified position.
     ///
     /// Validates movement based on:
-    /// - Terrain passability and movement cost
+    /// - Terrain passability and movement cost for the unit's movement type
     /// - Unit's remaining movement points
     /// - Position occupancy by other units
     /// - Distance from unit's current position (must be reachable via pathfinding)
@@ -1246,21 +1409,22 @@ impl ScenarioWorld {
             return Ok(0);
         }
 
-        // Check if target terrain exists and is passable
-        let target_terrain = self
-            .get_terrain(target_position)
+        // Check if target terrain exists and is passable for this unit
+        self.get_terrain(target_position)
             .ok_or("Target position out of bounds")?;
 
-        if target_terrain.blocks_movement() {
+        if self.unit_movement_cost(unit_id, target_position).is_none() {
             return Err("Target position is impassable".to_string());
         }
 
-        // Check if target position is occupied by a friendly unit
+        // Check if target position is occupied by a friendly (non-hostile) unit
         // (Enemy units are allowed - combat will be initiated)
         let moving_unit_team = unit.team();
         let units_at_target = self.get_units_at_position(target_position);
         for target_unit in units_at_target.iter() {
-            if target_unit.id() != unit_id && target_unit.team() == moving_unit_team {
+            if target_unit.id() != unit_id
+                && !self.teams.are_hostile(target_unit.team(), moving_unit_team)
+            {
                 return Err("Target position is occupied by friendly unit".to_string());
             }
         }
@@ -1299,7 +1463,7 @@ impl ScenarioWorld {
         let units_at_target = self.get_units_at_position(new_position);
         if let Some(target_unit) = units_at_target.first() {
             // There's a unit at the target position
-            if target_unit.team() != moving_unit_team {
+            if self.teams.are_hostile(target_unit.team(), moving_unit_team) {
                 // It's an enemy - initiate combat instead of moving
                 return self.request_combat(unit_id, target_unit.id());
             } else {
@@ -1320,7 +1484,15 @@ impl ScenarioWorld {
         }
 
         // Update position
+        let from = unit.position();
         unit.set_position(new_position);
+        self.refresh_terrain_hit_chance(unit_id);
+        self.invalidate_visibility();
+        self.emit(GameEvent::UnitMoved {
+            unit_id,
+            from,
+            to: new_position,
+        });
 
         Ok(())
     }
@@ -1355,29 +1527,51 @@ impl ScenarioWorld {
         let attacker_stats = attacker.unit().combat_stats();
         let defender_stats = defender.unit().combat_stats();
 
-        // Get attacker's available attacks
-        let attacker_attacks = attacker
-            .unit()
-            .get_attacks()
+        // Each side's attacks, with the chance to hit the other side where it
+        // stands (see `crate::hit_chance`) and, for the attacker, the
+        // counter-attack each one draws and its forecast (see `crate::forecast`)
+        let distance = attacker.position().distance(defender.position());
+        let attack_infos = |striker: &GameUnit, target: &GameUnit, counters: bool| {
+            let target_attacks = target.unit().get_attacks();
+            striker
+                .unit()
+                .get_attacks()
+                .iter()
+                .enumerate()
+                .map(|(index, attack)| {
+                    let in_reach = attack.can_reach(distance);
+                    Ok(crate::world::AttackInfo {
+                        name: attack.name.clone(),
+                        damage: self.strike_damage(striker.id(), attack.damage),
+                        time_of_day_bonus: self.time_of_day_bonus(striker.id()),
+                        range: attack.range,
+                        range_type: attack.range_type,
+                        in_reach,
+                        counter_index: counters
+                            .then(|| {
+                                crate::world::AttackInfo::counter_index(
+                                    &target_attacks,
+                                    attack,
+                                    distance,
+                                )
+                            })
+                            .flatten(),
+                        hit_chance: self.strike_hit_chance(target.id(), attack)?,
+                        forecast: if counters && in_reach {
+                            Some(self.combat_forecast(striker.id(), target.id(), index)?)
+                        } else {
+                            None
+                        },
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()
+        };
+        let attacker_attacks = attack_infos(attacker, defender, true)?;
+        let defender_attacks = attack_infos(defender, attacker, false)?;
+        let selected_attack_index = attacker_attacks
             .iter()
-            .map(|attack| crate::world::AttackInfo {
-                name: attack.name.clone(),
-                damage: attack.damage,
-                range: attack.range,
-            })
-            .collect();
-
-        // Get defender attacks
-        let defender_attacks = defender
-            .unit()
-            .get_attacks()
-            .iter()
-            .map(|attack| crate::world::AttackInfo {
-                name: attack.name.clone(),
-                damage: attack.damage,
-                range: attack.range,
-            })
-            .collect();
+            .position(|attack| attack.in_reach)
+            .unwrap_or(0);
 
         let pending = PendingCombat {
             attacker_id,
@@ -1386,17 +1580,23 @@ impl ScenarioWorld {
             attacker_hp: attacker_stats.health as u32,
             attacker_max_hp: attacker_stats.max_health as u32,
             attacker_attack: attacker_stats.get_total_attack(),
-            attacker_defense: attacker_stats.resistances.slash as u32,
+            attacker_defense: attacker_stats
+                .resistances
+                .get_resistance(combat::DamageType::Slash)
+                .max(0) as u32,
             attacker_attacks_per_round: attacker_stats.attacks_per_round,
             attacker_attacks,
             defender_name: defender.name(),
             defender_hp: defender_stats.health as u32,
             defender_max_hp: defender_stats.max_health as u32,
             defender_attack: defender_stats.get_total_attack(),
-            defender_defense: defender_stats.resistances.slash as u32,
+            defender_defense: defender_stats
+                .resistances
+                .get_resistance(combat::DamageType::Slash)
+                .max(0) as u32,
             defender_attacks_per_round: defender_stats.attacks_per_round,
             defender_attacks,
-            selected_attack_index: 0,
+            selected_attack_index,
         };
 
         self.pending_combat = Some(pending);
@@ -1432,14 +1632,11 @@ impl ScenarioWorld {
 
     /// Executes combat between two units with the selected attack.
     ///
-    /// ScenarioWorld detects when two units want to fight and passes them
-    /// to the Combat crate's resolve_combat function which handles all combat logic.
-    ///
     /// This method:
-    /// 1. Extracts the two units who want to fight
-    /// 2. Passes them to combat::resolve_combat with the selected attack's damage type
-    /// 3. Combat crate handles hit rolls, damage calculation, resistances, and counter-attacks
-    /// 4. Processes the combat result (removes defeated units, moves winner to defender's position)
+    /// 1. Extracts the two units who want to fight and the counter-attack
+    /// 2. Rolls the exchange's strikes with the Combat crate's `roll_exchange`
+    /// 3. Emits a [`GameEvent::StrikeResolved`] for every strike
+    /// 4. Applies the exchange (removes defeated units, moves winner to defender's position)
     ///
     /// # Arguments
     ///
@@ -1456,14 +1653,13 @@ impl ScenarioWorld {
         defender_id: Uuid,
         selected_attack_idx: usize,
     ) -> Result<(), String> {
-        // Get unit info and selected attack before combat
-        let (attacker_name, defender_name, defender_pos, selected_attack) = {
+        // Get unit info, the selected attack and the counter-attack before combat
+        let (attacker, defender, selected_attack, counter_name) = {
             let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
             let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
 
             // Prevent starting combat if the attacker already attacked this game turn
             if attacker.unit().combat_stats().attacked_this_turn {
-                println!("⛔ Combat canceled: attacker has already attacked this turn");
                 return Err("Attacker has already attacked this turn".to_string());
             }
 
@@ -1473,70 +1669,156 @@ impl ScenarioWorld {
                 .get(selected_attack_idx)
                 .ok_or("Selected attack not found")?
                 .clone();
+            let distance = attacker.position().distance(defender.position());
+            if !attack.can_reach(distance) {
+                return Err(format!(
+                    "{} is out of reach of {}",
+                    defender.name(),
+                    attack.name
+                ));
+            }
+            let counter_name = defender
+                .unit()
+                .counter_attack(&attack, distance)
+                .map(|counter| counter.name)
+                .unwrap_or_default();
 
             (
-                attacker.name(),
-                defender.name(),
-                defender.position(),
+                (attacker.team(), attacker.position()),
+                (defender.team(), defender.position()),
                 attack,
+                counter_name,
             )
         };
 
-        println!("\n╔════════════════════════════════════════╗");
-        println!("║          ⚔️  COMBAT INITIATED  ⚔️          ║");
-        println!("╠════════════════════════════════════════╣");
-        println!("║  Attacker: {:<28} ║", attacker_name);
-        println!("║  Defender: {:<28} ║", defender_name);
-        println!("║  Attack:   {:<28} ║", selected_attack.name);
-        println!("╚════════════════════════════════════════╝\n");
-
-        // Execute combat: ScenarioWorld detects two units want to fight
-        // and executes the combat logic
-        let mut attacker_damage = 0;
-        let mut defender_damage = 0;
-        let mut attacker_hit = false;
-        let mut defender_hit = false;
-
-        // Get combat stats before battle
-        let (attacker_hit_chance, defender_hit_chance, defender_counter_attack) = {
+        // Roll the strikes in order until both sides are done or one falls;
+        // see `crate::forecast` for the odds of each outcome
+        let (attacker_side, defender_side) =
+            self.exchange_sides(attacker_id, defender_id, selected_attack_idx)?;
+        let mut rolls = Vec::new();
+        let rng = &mut self.rng;
+        let (attacker_hits, defender_hits) =
+            combat::roll_exchange(&attacker_side, &defender_side, |chance| {
+                let hit = rng.gen_range(0..100) < chance;
+                rolls.push(hit);
+                hit
+            });
+
+        // Report every strike before applying them, since the exchange may
+        // remove a defeated unit
+        let strikes = combat::strike_order(attacker_side.strikes, defender_side.strikes);
+        for (striker, hit) in strikes.into_iter().zip(rolls) {
+            let counter = striker == combat::Striker::Defender;
+            let (striker_id, striker_team, target_id, (target_team, target_position), side) =
+                if counter {
+                    (
+                        defender_id,
+                        defender.0,
+                        attacker_id,
+                        attacker,
+                        &defender_side,
+                    )
+                } else {
+                    (
+                        attacker_id,
+                        attacker.0,
+                        defender_id,
+                        defender,
+                        &attacker_side,
+                    )
+                };
+            self.emit(GameEvent::StrikeResolved {
+                striker_id,
+                striker_team,
+                target_id,
+                target_team,
+                target_position,
+                attack_name: if counter {
+                    counter_name.clone()
+                } else {
+                    selected_attack.name.clone()
+                },
+                counter,
+                hit,
+                damage: if hit { side.damage } else { 0 },
+            });
+        }
+
+        self.resolve_exchange(
+            attacker_id,
+            defender_id,
+            selected_attack_idx,
+            attacker_hits,
+            defender_hits,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the hit chances for one exchange with the selected attack.
+    ///
+    /// The first value is the attacker's chance to hit; the second is the
+    /// defender's counter-attack chance, or `None` when no counter happens
+    /// (the defender has no attack that may answer the selected one). See
+    /// [`ScenarioWorld::combat_hit_chances`] for the full breakdown.
+    pub(crate) fn exchange_hit_chances(
+        &self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        selected_attack_idx: usize,
+    ) -> Result<(u8, Option<u8>), String> {
+        let (strike, counter) =
+            self.combat_hit_chances(attacker_id, defender_id, selected_attack_idx)?;
+        Ok((strike.chance, counter.map(|c| c.chance)))
+    }
+
+    /// Applies one attack/counter-attack exchange with predetermined hit counts.
+    ///
+    /// This is the deterministic core of combat: `initiate_combat` rolls the
+    /// strikes and calls it, while tactical search calls it once per outcome
+    /// of the exchange's forecast (see [`crate::forecast`]). The hit counts
+    /// must come from the exchange's strike sequence, which ends when either
+    /// side falls. Damage is reduced by resistances, defeated units are
+    /// removed (granting kill experience, see [`crate::progression`]), and a
+    /// victorious attacker moves onto the defender's hex.
+    pub(crate) fn resolve_exchange(
+        &mut self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        selected_attack_idx: usize,
+        attacker_hits: u32,
+        defender_hits: u32,
+    ) -> Result<ExchangeOutcome, String> {
+        let (defender_pos, selected_attack, defender_counter_attack) = {
             let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
             let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
 
-            let attacker_hit_chance = attacker.unit().combat_stats().terrain_hit_chance;
-            let defender_hit_chance = defender.unit().combat_stats().terrain_hit_chance;
-            let counter = if selected_attack.range == 1 && !defender.unit().get_attacks().is_empty()
-            {
-                Some(defender.unit().get_attacks()[0].clone())
-            } else {
-                None
-            };
+            let attack = attacker
+                .unit()
+                .get_attacks()
+                .get(selected_attack_idx)
+                .ok_or("Selected attack not found")?
+                .clone();
+            let distance = attacker.position().distance(defender.position());
+            let counter = defender.unit().counter_attack(&attack, distance);
 
-            (attacker_hit_chance, defender_hit_chance, counter)
+            (defender.position(), attack, counter)
         };
 
-        // Attacker's turn
-        {
-            let hit_roll: u8 = rand::random::<u8>() % 100;
-
-            if hit_roll < attacker_hit_chance {
-                attacker_hit = true;
-
-                let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
-                let resistance = defender
-                    .unit()
-                    .combat_stats()
-                    .resistances
-                    .get_resistance(selected_attack.damage_type);
-                let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
-                let final_damage =
-                    ((selected_attack.damage as f32 * resistance_multiplier) as i32).max(1);
+        let mut outcome = ExchangeOutcome::default();
 
+        // Attacker's strikes
+        {
+            if attacker_hits > 0 {
+                let damage =
+                    attacker_hits * self.hit_damage(attacker_id, defender_id, &selected_attack)?;
                 let defender = self
                     .units
                     .get_mut(&defender_id)
                     .ok_or("Defender not found")?;
-                defender.unit_mut().take_damage(final_damage as u32);
-                attacker_damage = final_damage as u32;
+                defender.unit_mut().take_damage(damage);
+                outcome.attacker_hits = attacker_hits;
+                outcome.attacker_damage = damage;
             }
 
             // Mark attacker as having attacked
@@ -1547,85 +1829,55 @@ impl ScenarioWorld {
             attacker.unit_mut().combat_stats_mut().attacked_this_turn = true;
         }
 
-        // Defender counter-attack (only if melee range and defender is alive)
+        // Defender counter-attack (only with a matching range type); the hit
+        // count already stops at the strike that defeats either side
         if let Some(counter_attack) = defender_counter_attack {
-            let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
-            if defender.unit().is_alive() {
-                let hit_roll: u8 = rand::random::<u8>() % 100;
-
-                if hit_roll < defender_hit_chance {
-                    defender_hit = true;
-
-                    let attacker = self.units.get(&attacker_id).ok_or("Attacker not found")?;
-                    let resistance = attacker
-                        .unit()
-                        .combat_stats()
-                        .resistances
-                        .get_resistance(counter_attack.damage_type);
-                    let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
-                    let final_damage =
-                        ((counter_attack.damage as f32 * resistance_multiplier) as i32).max(1);
-
-                    let attacker = self
-                        .units
-                        .get_mut(&attacker_id)
-                        .ok_or("Attacker not found")?;
-                    attacker.unit_mut().take_damage(final_damage as u32);
-                    defender_damage = final_damage as u32;
-                }
+            if defender_hits > 0 {
+                let damage =
+                    defender_hits * self.hit_damage(defender_id, attacker_id, &counter_attack)?;
+                let attacker = self
+                    .units
+                    .get_mut(&attacker_id)
+                    .ok_or("Attacker not found")?;
+                attacker.unit_mut().take_damage(damage);
+                outcome.defender_hits = defender_hits;
+                outcome.defender_damage = damage;
             }
         }
 
-        // Process combat results
-        println!("⚔️  Combat Results:");
-        if attacker_hit {
-            println!("   ✓ {} hit for {} damage", attacker_name, attacker_damage);
-        } else {
-            println!("   ✗ {} missed", attacker_name);
-        }
-
-        if selected_attack.range > 1 {
-            println!("   ⚠ {} could not counter-attack (ranged)", defender_name);
-        } else if defender_hit {
-            println!(
-                "   ✓ {} counter-attacked for {} damage",
-                defender_name, defender_damage
-            );
-        } else {
-            println!("   ✗ {} missed counter-attack", defender_name);
-        }
-
         // Check if defender was defeated
-        let defender_defeated = {
+        outcome.defender_defeated = {
             let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
             !defender.unit().is_alive()
         };
 
-        if defender_defeated {
-            println!("💀 {} was defeated!", defender_name);
-            self.remove_unit(defender_id);
+        if outcome.defender_defeated {
+            self.defeat_unit(defender_id, attacker_id);
 
             // Move attacker to defender's position
             if let Some(attacker) = self.units.get_mut(&attacker_id) {
+                let from = attacker.position();
                 attacker.set_position(defender_pos);
-                println!("➡️  {} moves to {:?}", attacker_name, defender_pos);
+                self.emit(GameEvent::UnitMoved {
+                    unit_id: attacker_id,
+                    from,
+                    to: defender_pos,
+                });
             }
+            self.refresh_terrain_hit_chance(attacker_id);
+            self.invalidate_visibility();
         }
 
         // Check if attacker was defeated (rare but possible via counter-attack)
-        let attacker_defeated = {
-            self.units
-                .get(&attacker_id)
-                .is_some_and(|a| !a.unit().is_alive())
-        };
-        if attacker_defeated {
-            println!("💀 {} was defeated by counter-attack!", attacker_name);
-            self.remove_unit(attacker_id);
+        outcome.attacker_defeated = self
+            .units
+            .get(&attacker_id)
+            .is_some_and(|a| !a.unit().is_alive());
+        if outcome.attacker_defeated {
+            self.defeat_unit(attacker_id, defender_id);
         }
 
-        println!("╚════════════════════════════════════════╝\n");
-
-        Ok(())
+        Ok(outcome)
     }
 
     /// Resets movement points for all units belonging to a given team.
@@ -1646,6 +1898,22 @@ impl ScenarioWorld {
         }
     }
 
+    /// Returns the cost for a unit to enter a hex, using its movement type.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - UUID of the moving unit
+    /// * `position` - Hex coordinate to enter
+    ///
+    /// # Returns
+    ///
+    /// The movement cost, or `None` if the unit or terrain does not exist or
+    /// the unit's movement type cannot enter the terrain
+    pub fn unit_movement_cost(&self, unit_id: Uuid, position: HexCoord) -> Option<i32> {
+        let movement_type = self.units.get(&unit_id)?.unit().movement_type();
+        self.get_terrain(position)?.movement_cost_for(movement_type)
+    }
+
     /// Returns the team whose turn it currently is
     pub fn current_turn_team(&self) -> Team {
         self.turn_system.current_team()
@@ -1677,8 +1945,63 @@ impl ScenarioWorld {
             .set_team_control(team, is_player_controlled);
     }
 
+    /// Teams that fight, in turn order.
+    ///
+    /// A team fights if it has units and is hostile to another team with
+    /// units; bystanders such as neutral structures do not.
+    pub fn fighting_teams(&self) -> Vec<Team> {
+        let armies: Vec<Team> = self
+            .teams
+            .turn_order()
+            .iter()
+            .copied()
+            .filter(|team| self.units.values().any(|u| u.team() == *team))
+            .collect();
+        armies
+            .iter()
+            .copied()
+            .filter(|team| {
+                armies
+                    .iter()
+                    .any(|other| self.teams.are_hostile(*team, *other))
+            })
+            .collect()
+    }
+
+    /// Hands every fighting team (see [`Self::fighting_teams`]) to a human
+    /// for a local hot-seat game; bystanders stay AI-controlled.
+    ///
+    /// # Returns
+    ///
+    /// The human teams, in turn order
+    pub fn enable_hot_seat(&mut self) -> Vec<Team> {
+        let humans = self.fighting_teams();
+        for team in self.teams.turn_order().to_vec() {
+            let human = humans.contains(&team);
+            if let Some(info) = self.teams.get_mut(team) {
+                info.player_controlled = human;
+            }
+            self.set_team_control(team, human);
+        }
+        humans
+    }
+
+    /// Reseeds the combat dice.
+    ///
+    /// Two worlds loaded from the same map, seeded alike and given the same
+    /// commands roll the same combat results.
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     /// Sets the AI turn delay
     pub fn set_ai_turn_delay(&mut self, delay: f32) {
         self.turn_system.set_ai_turn_delay(delay);
     }
+
+    /// Plays AI turns with tactical search (default budget) instead of the
+    /// GOAP planner, or switches back to the planner.
+    pub fn set_tactical_ai(&mut self, enabled: bool) {
+        self.tactical_search = enabled.then(ai::SearchBudget::default);
+    }
 }
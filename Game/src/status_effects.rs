@@ -0,0 +1,87 @@
+//! # Status Effects
+//!
+//! Timed buffs and debuffs on units in a [`ScenarioWorld`]. Each unit tracks
+//! its effects in its [`AbilityState`](units::ability::AbilityState); the world
+//! counts them down at turn boundaries and applies them in play:
+//!
+//! - Effects (and ability cooldowns) of a team's units tick down when that
+//!   team's turn begins, so a 3-turn buff lasts through the turn it was
+//!   applied in and the owner's next two turns
+//! - Attack buffs and debuffs add to the damage of every strike
+//! - Defense and dodge buffs change hit chances (see [`crate::hit_chance`])
+
+use crate::objects::Team;
+use crate::scenario_instance::ScenarioWorld;
+use units::ability::{ActiveEffect, BuffStat};
+use uuid::Uuid;
+
+impl ScenarioWorld {
+    /// Counts down the status effects and cooldowns of a team's units.
+    ///
+    /// Expired effects are removed.
+    pub(crate) fn tick_status_effects(&mut self, team: Team) {
+        for unit in self.units.values_mut().filter(|u| u.team() == team) {
+            unit.unit_mut().tick_abilities();
+        }
+    }
+
+    /// Net attack bonus a unit gets from its active buffs and debuffs.
+    ///
+    /// # Returns
+    ///
+    /// The bonus, or 0 if the unit does not exist
+    pub fn attack_buff(&self, unit_id: Uuid) -> i32 {
+        self.units.get(&unit_id).map_or(0, |unit| {
+            unit.unit().ability_state().stat_modifier(&BuffStat::Attack)
+        })
+    }
+
+    /// Damage of one strike by a unit: the attack's damage scaled by the time
+    /// of day, plus the unit's attack buffs (never below 0).
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - The attacking unit
+    /// * `damage` - Base damage of the attack
+    pub fn strike_damage(&self, unit_id: Uuid, damage: u32) -> u32 {
+        let scaled = self.time_of_day_damage(unit_id, damage) as i32;
+        (scaled + self.attack_buff(unit_id)).max(0) as u32
+    }
+
+    /// Active buffs and debuffs on a unit, for display.
+    ///
+    /// # Returns
+    ///
+    /// One line per effect, such as `"Attack +2 (3 turns)"`, sorted so the
+    /// order does not depend on the tracker's hash map. Empty if the unit
+    /// does not exist or has no effects.
+    pub fn status_effect_summary(&self, unit_id: Uuid) -> Vec<String> {
+        let Some(unit) = self.units.get(&unit_id) else {
+            return Vec::new();
+        };
+        let mut lines: Vec<String> = unit
+            .unit()
+            .ability_state()
+            .active_effects
+            .values()
+            .filter_map(|instance| {
+                let (stat, amount) = match &instance.effect {
+                    ActiveEffect::Buff { stat, amount, .. } => (stat, *amount),
+                    ActiveEffect::Debuff { stat, amount, .. } => (stat, -*amount),
+                    _ => return None,
+                };
+                let turns = if instance.duration == 1 {
+                    "turn"
+                } else {
+                    "turns"
+                };
+                Some(format!(
+                    "{:?} {:+} ({} {})",
+                    stat, amount, instance.duration, turns
+                ))
+            })
+            .collect();
+        lines.sort();
+        lines
+    }
+}
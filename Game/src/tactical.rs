@@ -0,0 +1,342 @@
+//! # Tactical Search Integration
+//!
+//! Connects [`ScenarioWorld`] to the AI crate's expectimax search
+//! ([`ai::TacticalState`]). The search explores cloned worlds, so nothing here
+//! touches the real game state until [`ScenarioWorld::run_tactical_turn`]
+//! executes the chosen command.
+//!
+//! ## Commands
+//!
+//! - [`GameCommand::Move`]: move a unit to a reachable hex
+//! - [`GameCommand::Attack`]: attack an enemy in range with a specific attack
+//! - [`GameCommand::UseItem`]: drink a healing consumable (only offered to
+//!   units low on health, see [`ScenarioWorld::emergency_consumable`])
+//! - [`GameCommand::EndTurn`]: pass the turn to the next team
+//!
+//! Attacks are chance nodes: every end state of the exchange's strikes is a
+//! separate outcome, weighted by its probability in the attack's combat
+//! forecast (see [`crate::forecast`]).
+
+use crate::objects::{GameObject, Team};
+use crate::scenario_instance::ScenarioWorld;
+use hex::HexCoord;
+use log::debug;
+use uuid::Uuid;
+
+/// Score bonus for every living unit, so kills matter more than raw damage.
+const UNIT_ALIVE_VALUE: f32 = 20.0;
+
+/// Score penalty per hex between a unit and its nearest enemy.
+///
+/// Keeps units advancing when no attack is available, like the GOAP fallback.
+const DISTANCE_PENALTY: f32 = 0.1;
+
+/// Safety cap on commands executed during a single tactical AI turn.
+const MAX_COMMANDS_PER_TURN: usize = 64;
+
+/// A single command a team can issue on its turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameCommand {
+    /// Move a unit to a destination hex
+    Move { unit_id: Uuid, to: HexCoord },
+    /// Attack a unit using the attacker's attack at `attack_index`
+    Attack {
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        attack_index: usize,
+    },
+    /// Use a consumable from the unit's own inventory on itself
+    UseItem { unit_id: Uuid, item_id: Uuid },
+    /// End the current team's turn
+    EndTurn,
+}
+
+impl ScenarioWorld {
+    /// Unit ids of a team in a stable order (sorted by UUID).
+    fn sorted_team_units(&self, team: Team) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self
+            .units
+            .values()
+            .filter(|u| u.team() == team)
+            .map(|u| u.id())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Distance from `pos` to the closest unit hostile to `team`, if any.
+    fn distance_to_nearest_enemy(&self, team: Team, pos: HexCoord) -> Option<i32> {
+        self.units
+            .values()
+            .filter(|u| self.teams.are_hostile(u.team(), team))
+            .map(|u| pos.distance(u.position()))
+            .min()
+    }
+
+    /// Resolves an attack the search chose, like a confirmed combat dialog.
+    ///
+    /// Any combat still waiting for confirmation is discarded first, so the
+    /// attack index is applied to the combat requested here.
+    fn execute_attack(
+        &mut self,
+        attacker_id: Uuid,
+        defender_id: Uuid,
+        attack_index: usize,
+    ) -> Result<(), String> {
+        self.cancel_pending_combat();
+        self.request_combat(attacker_id, defender_id)?;
+        let pending = self
+            .pending_combat
+            .as_mut()
+            .ok_or("Attacker has already attacked this turn")?;
+        pending.selected_attack_index = attack_index;
+        self.execute_pending_combat()
+    }
+
+    /// Plays the current AI team's turn using tactical search.
+    ///
+    /// Repeatedly searches for the best command and executes it on the real
+    /// world until the search chooses to end the turn (or nothing is left to
+    /// do), then ends the turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Search limits applied to every individual decision
+    pub fn run_tactical_turn(&mut self, budget: &ai::SearchBudget) {
+        let team = self.turn_system.current_team();
+        let team_name = self.teams.name(team).to_string();
+        let mut trace = ai::AiTrace::new(team_name.clone(), self.turn_number());
+
+        for _ in 0..MAX_COMMANDS_PER_TURN {
+            let result = ai::expectimax(&*self, budget);
+            trace.search_nodes += result.nodes;
+            debug!(
+                target: ai::TRACE_TARGET,
+                "{}: {:?} (value {:.1}, {} nodes, depth {})",
+                team_name,
+                result.best_command,
+                result.value,
+                result.nodes,
+                result.depth_reached
+            );
+
+            let Some(command) = result.best_command else {
+                break;
+            };
+            let outcome = match &command {
+                GameCommand::EndTurn => break,
+                GameCommand::Move { unit_id, to } => {
+                    self.trace_unit(&mut trace, *unit_id).path.push(*to);
+                    self.move_unit(*unit_id, *to)
+                }
+                GameCommand::Attack {
+                    attacker_id,
+                    defender_id,
+                    attack_index,
+                } => {
+                    if let Some(defender) = self.units.get(defender_id) {
+                        let target = defender.position();
+                        self.trace_unit(&mut trace, *attacker_id).target = Some(target);
+                    }
+                    self.execute_attack(*attacker_id, *defender_id, *attack_index)
+                }
+                GameCommand::UseItem { unit_id, item_id } => {
+                    self.trace_unit(&mut trace, *unit_id);
+                    self.use_item(*unit_id, *item_id, *unit_id).map(|_| ())
+                }
+            };
+
+            let agent = match &command {
+                GameCommand::Move { unit_id, .. } | GameCommand::UseItem { unit_id, .. } => {
+                    Some(unit_id.to_string())
+                }
+                GameCommand::Attack { attacker_id, .. } => Some(attacker_id.to_string()),
+                GameCommand::EndTurn => None,
+            };
+            let failed = outcome.is_err();
+            trace.record_command(agent.as_deref(), format!("{:?}", command), outcome);
+            if failed {
+                break;
+            }
+        }
+
+        self.finish_ai_trace(trace);
+        self.end_current_turn();
+    }
+
+    /// Trace entry for a unit, initialised with its name and starting hex.
+    fn trace_unit<'t>(&self, trace: &'t mut ai::AiTrace, unit_id: Uuid) -> &'t mut ai::AgentTrace {
+        let entry = trace.agent_mut(&unit_id.to_string());
+        if entry.path.is_empty() {
+            if let Some(unit) = self.units.get(&unit_id) {
+                entry.name = unit.name();
+                entry.path.push(unit.position());
+            }
+        }
+        entry
+    }
+}
+
+impl ai::TacticalState for ScenarioWorld {
+    type Command = GameCommand;
+    type Side = Team;
+
+    fn current_side(&self) -> Team {
+        self.turn_system.current_team()
+    }
+
+    /// Healing items for units low on health come first, then attacks from
+    /// the current position, then moves, then `EndTurn`.
+    fn legal_commands(&self) -> Vec<GameCommand> {
+        let team = self.current_side();
+        let own_units = self.sorted_team_units(team);
+        let mut enemies: Vec<(Uuid, HexCoord)> = self
+            .units
+            .values()
+            .filter(|u| self.teams.are_hostile(u.team(), team))
+            .map(|u| (u.id(), u.position()))
+            .collect();
+        enemies.sort_by_key(|(id, _)| *id);
+
+        let mut item_uses = Vec::new();
+        let mut attacks = Vec::new();
+        let mut moves = Vec::new();
+
+        for unit_id in own_units {
+            let Some(unit) = self.units.get(&unit_id) else {
+                continue;
+            };
+            let pos = unit.position();
+
+            if let Some(item_id) = self.emergency_consumable(unit_id) {
+                item_uses.push(GameCommand::UseItem { unit_id, item_id });
+            }
+
+            if !unit.unit().combat_stats().attacked_this_turn {
+                for (attack_index, attack) in unit.unit().get_attacks().iter().enumerate() {
+                    for (enemy_id, enemy_pos) in &enemies {
+                        if attack.can_reach(pos.distance(*enemy_pos)) {
+                            attacks.push(GameCommand::Attack {
+                                attacker_id: unit_id,
+                                defender_id: *enemy_id,
+                                attack_index,
+                            });
+                        }
+                    }
+                }
+            }
+
+            let mut destinations: Vec<HexCoord> = self
+                .all_legal_moves(unit_id)
+                .into_iter()
+                .map(|(hex, _)| hex)
+                .filter(|hex| *hex != pos && self.get_units_at_position(*hex).is_empty())
+                .collect();
+            destinations.sort_by_key(|hex| (hex.q, hex.r));
+            moves.extend(
+                destinations
+                    .into_iter()
+                    .map(|to| GameCommand::Move { unit_id, to }),
+            );
+        }
+
+        item_uses.extend(attacks);
+        item_uses.extend(moves);
+        item_uses.push(GameCommand::EndTurn);
+        item_uses
+    }
+
+    fn apply(&self, command: &GameCommand) -> Vec<(f32, Self)> {
+        match command {
+            GameCommand::Move { unit_id, to } => {
+                let mut next = self.clone();
+                let _ = next.move_unit(*unit_id, *to);
+                vec![(1.0, next)]
+            }
+            GameCommand::Attack {
+                attacker_id,
+                defender_id,
+                attack_index,
+            } => {
+                let Ok(forecast) = self.combat_forecast(*attacker_id, *defender_id, *attack_index)
+                else {
+                    return vec![(1.0, self.clone())];
+                };
+
+                // One branch per end state of the exchange (see `crate::forecast`)
+                let mut outcomes = Vec::with_capacity(forecast.outcomes.len());
+                for outcome in &forecast.outcomes {
+                    if outcome.probability <= 0.0 {
+                        continue;
+                    }
+                    let mut next = self.clone();
+                    if next
+                        .resolve_exchange(
+                            *attacker_id,
+                            *defender_id,
+                            *attack_index,
+                            outcome.attacker_hits,
+                            outcome.defender_hits,
+                        )
+                        .is_ok()
+                    {
+                        outcomes.push((outcome.probability, next));
+                    }
+                }
+
+                if outcomes.is_empty() {
+                    outcomes.push((1.0, self.clone()));
+                }
+                outcomes
+            }
+            GameCommand::UseItem { unit_id, item_id } => {
+                let mut next = self.clone();
+                let _ = next.use_item(*unit_id, *item_id, *unit_id);
+                vec![(1.0, next)]
+            }
+            GameCommand::EndTurn => {
+                let mut next = self.clone();
+                next.turn_system.advance_team();
+                next.begin_team_turn();
+                vec![(1.0, next)]
+            }
+        }
+    }
+
+    /// Allied teams count as friendly, matching [`ai::TacticalState::evaluate`].
+    fn is_friendly(&self, side: &Team, root: &Team) -> bool {
+        self.teams.are_allied(*side, *root)
+    }
+
+    /// Own and allied health and unit count minus the enemies', with a small
+    /// penalty for allied units standing far from the enemy. Neutral units
+    /// do not count.
+    fn evaluate(&self, side: &Team) -> f32 {
+        self.units
+            .values()
+            .map(|unit| {
+                let value = unit.unit().combat_stats().health.max(0) as f32 + UNIT_ALIVE_VALUE;
+                if self.teams.are_allied(unit.team(), *side) {
+                    let distance = self
+                        .distance_to_nearest_enemy(*side, unit.position())
+                        .unwrap_or(0);
+                    value - distance as f32 * DISTANCE_PENALTY
+                } else if self.teams.are_hostile(unit.team(), *side) {
+                    -value
+                } else {
+                    0.0
+                }
+            })
+            .sum()
+    }
+
+    /// The game is over once no two remaining units are hostile to each other.
+    fn is_terminal(&self) -> bool {
+        let mut teams: Vec<Team> = self.units.values().map(|u| u.team()).collect();
+        teams.sort();
+        teams.dedup();
+        !teams
+            .iter()
+            .any(|a| teams.iter().any(|b| self.teams.are_hostile(*a, *b)))
+    }
+}
@@ -0,0 +1,145 @@
+//! # Time of Day
+//!
+//! A scenario cycles through a [`DayCycle`] of [`TimeOfDay`] phases, one phase
+//! per game turn (see [`TurnSystem::turn_number`](crate::TurnSystem::turn_number)).
+//! Each phase has a lawful bonus that scales unit damage according to the
+//! unit's [`Alignment`](units::Alignment): lawful units are stronger by day,
+//! chaotic units at night.
+//!
+//! The cycle defaults to [`DayCycle::default`] and can be overridden per
+//! scenario with a top-level `DayCycle` array in the map JSON:
+//!
+//! ```json
+//! "DayCycle": [
+//!     {"Name": "Day", "LawfulBonus": 25},
+//!     {"Name": "Night", "LawfulBonus": -25}
+//! ]
+//! ```
+
+use crate::scenario_instance::ScenarioWorld;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// One phase of the day/night cycle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeOfDay {
+    /// Display name (e.g. "Dawn", "Second Watch")
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// Damage bonus for lawful units in percent; chaotic units get the opposite
+    #[serde(rename = "LawfulBonus", default)]
+    pub lawful_bonus: i32,
+}
+
+impl TimeOfDay {
+    /// Creates a time-of-day phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Display name of the phase
+    /// * `lawful_bonus` - Damage bonus for lawful units in percent
+    pub fn new(name: impl Into<String>, lawful_bonus: i32) -> Self {
+        Self {
+            name: name.into(),
+            lawful_bonus,
+        }
+    }
+}
+
+/// The ordered phases a scenario cycles through, one per turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayCycle {
+    phases: Vec<TimeOfDay>,
+}
+
+impl DayCycle {
+    /// Creates a cycle from its phases.
+    ///
+    /// # Returns
+    ///
+    /// The cycle, or an error if `phases` is empty
+    pub fn new(phases: Vec<TimeOfDay>) -> Result<Self, String> {
+        if phases.is_empty() {
+            return Err("A day cycle needs at least one phase".to_string());
+        }
+        Ok(Self { phases })
+    }
+
+    /// All phases in order.
+    pub fn phases(&self) -> &[TimeOfDay] {
+        &self.phases
+    }
+
+    /// Index of the phase active on a turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn` - The 0-based turn number
+    pub fn phase_index(&self, turn: u32) -> usize {
+        turn as usize % self.phases.len()
+    }
+
+    /// The phase active on a turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn` - The 0-based turn number
+    pub fn phase_for_turn(&self, turn: u32) -> &TimeOfDay {
+        &self.phases[self.phase_index(turn)]
+    }
+}
+
+impl Default for DayCycle {
+    /// Six phases: dawn, two day phases, dusk and two night watches.
+    fn default() -> Self {
+        Self {
+            phases: vec![
+                TimeOfDay::new("Dawn", 0),
+                TimeOfDay::new("Morning", 25),
+                TimeOfDay::new("Afternoon", 25),
+                TimeOfDay::new("Dusk", 0),
+                TimeOfDay::new("First Watch", -25),
+                TimeOfDay::new("Second Watch", -25),
+            ],
+        }
+    }
+}
+
+impl ScenarioWorld {
+    /// The phase of the day/night cycle for the current turn.
+    pub fn time_of_day(&self) -> &TimeOfDay {
+        self.day_cycle
+            .phase_for_turn(self.turn_system.turn_number())
+    }
+
+    /// Damage bonus (in percent) the current time of day gives a unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - The unit whose alignment is used
+    ///
+    /// # Returns
+    ///
+    /// The bonus, or 0 if the unit does not exist
+    pub fn time_of_day_bonus(&self, unit_id: Uuid) -> i32 {
+        self.units.get(&unit_id).map_or(0, |unit| {
+            unit.unit()
+                .alignment()
+                .damage_bonus(self.time_of_day().lawful_bonus)
+        })
+    }
+
+    /// Scales an attack's damage by the current time of day for a unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - The attacking unit
+    /// * `damage` - Base damage of the attack
+    pub fn time_of_day_damage(&self, unit_id: Uuid, damage: u32) -> u32 {
+        self.units.get(&unit_id).map_or(damage, |unit| {
+            unit.unit()
+                .alignment()
+                .scale_damage(damage, self.time_of_day().lawful_bonus)
+        })
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! ## Features
 //!
-//! - Team-based turns (Player, Enemy, Neutral)
+//! - Team-based turns in the scenario's turn order (any number of teams)
 //! - Automatic AI turn progression with configurable delay
 //! - Unit activation tracking (which units have acted)
 //! - Turn phase management
@@ -22,13 +22,13 @@
 /// turn_system.start_game();
 /// assert!(turn_system.phase() == TurnPhase::Active);
 /// // Simulate passing time for AI teams (default AI delay is 3.0s)
-/// turn_system.set_team_control(Team::Player, true);
-/// turn_system.set_team_control(Team::Enemy, false);
+/// turn_system.set_team_control(Team::PLAYER, true);
+/// turn_system.set_team_control(Team::ENEMY, false);
 /// // Ensure player turn doesn't auto-advance
 /// turn_system.update(5.0);
-/// assert_eq!(turn_system.current_team(), Team::Player);
+/// assert_eq!(turn_system.current_team(), Team::PLAYER);
 /// ```
-use crate::Team;
+use crate::{Team, TeamRoster};
 use std::collections::HashSet;
 use uuid::Uuid;
 
@@ -52,7 +52,7 @@ pub enum TurnPhase {
 ///
 /// 1. **Player Turn**: Players can move any number of their units
 /// 2. **AI Turn**: After 3 seconds, automatically advances to next team
-/// 3. **Cycle**: Continues through all teams in order (Player → Enemy → Neutral)
+/// 3. **Cycle**: Continues through all teams in turn order (by default Player → Enemy → Neutral)
 ///
 /// # Examples
 ///
@@ -61,11 +61,12 @@ pub enum TurnPhase {
 /// use game::Team;
 ///
 /// let mut turn_system = TurnSystem::new();
-/// turn_system.set_team_control(Team::Player, true);
-/// turn_system.set_team_control(Team::Enemy, false);
+/// turn_system.set_team_control(Team::PLAYER, true);
+/// turn_system.set_team_control(Team::ENEMY, false);
 /// turn_system.start_game();
-/// assert!(turn_system.is_team_turn(Team::Player));
+/// assert!(turn_system.is_team_turn(Team::PLAYER));
 /// ```
+#[derive(Clone)]
 pub struct TurnSystem {
     /// List of teams participating in the turn order
     teams: Vec<Team>,
@@ -97,11 +98,27 @@ impl TurnSystem {
     ///
     /// By default:
     /// - AI teams wait 3 seconds before passing turn
-    /// - Turn order: Player → Enemy → Neutral
-    /// - All teams are AI-controlled (must call `add_team` to set player control)
+    /// - Turn order: Player → Enemy → Neutral (the teams of `TeamRoster::default()`)
+    /// - All teams are AI-controlled (must call `set_team_control` to set player control)
     pub fn new() -> Self {
+        Self::with_order(vec![Team::PLAYER, Team::ENEMY, Team::NEUTRAL])
+    }
+
+    /// Creates a turn system cycling through the given teams
+    ///
+    /// All teams start AI-controlled.
+    ///
+    /// # Arguments
+    ///
+    /// * `teams` - Teams in the order they take their turns
+    ///
+    /// # Panics
+    ///
+    /// Panics if `teams` is empty.
+    pub fn with_order(teams: Vec<Team>) -> Self {
+        assert!(!teams.is_empty(), "turn order must name at least one team");
         Self {
-            teams: vec![Team::Player, Team::Enemy, Team::Neutral],
+            teams,
             player_controlled: HashSet::new(),
             current_team_index: 0,
             phase: TurnPhase::NotStarted,
@@ -112,6 +129,21 @@ impl TurnSystem {
         }
     }
 
+    /// Creates a turn system from a scenario's team roster
+    ///
+    /// Uses the roster's turn order and marks its player-controlled teams.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the roster's turn order is empty.
+    pub fn from_roster(roster: &TeamRoster) -> Self {
+        let mut turn_system = Self::with_order(roster.turn_order().to_vec());
+        for info in roster.teams() {
+            turn_system.set_team_control(info.id, info.player_controlled);
+        }
+        turn_system
+    }
+
     /// Sets whether a team is player-controlled
     ///
     /// # Arguments
@@ -159,13 +191,30 @@ impl TurnSystem {
             return;
         }
 
+        println!("⏭️  {:?} turn ended", self.current_team());
+
+        self.advance_team();
+
+        println!(
+            "▶️  {:?}'s turn (Turn {})",
+            self.current_team(),
+            self.turn_count + 1
+        );
+    }
+
+    /// Advances to the next team without logging.
+    ///
+    /// Shared by `end_turn` and by simulated turns during tactical search.
+    pub(crate) fn advance_team(&mut self) {
+        if self.phase == TurnPhase::NotStarted {
+            return;
+        }
+
         // Clear acted units
         self.units_acted_this_turn.clear();
 
         self.phase = TurnPhase::Ending;
 
-        println!("⏭️  {:?} turn ended", self.current_team());
-
         // Advance to next team
         self.current_team_index = (self.current_team_index + 1) % self.teams.len();
 
@@ -177,12 +226,11 @@ impl TurnSystem {
         // Reset timer and start next turn
         self.turn_timer = 0.0;
         self.phase = TurnPhase::Active;
+    }
 
-        println!(
-            "▶️  {:?}'s turn (Turn {})",
-            self.current_team(),
-            self.turn_count + 1
-        );
+    /// Returns the teams in turn order
+    pub fn teams(&self) -> &[Team] {
+        &self.teams
     }
 
     /// Returns the team whose turn it currently is
@@ -208,6 +256,15 @@ impl TurnSystem {
         self.player_controlled.contains(&self.current_team())
     }
 
+    /// Checks if a specific team is player-controlled
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - The team to check
+    pub fn is_team_player_controlled(&self, team: Team) -> bool {
+        self.player_controlled.contains(&team)
+    }
+
     /// Returns the current turn phase
     pub fn phase(&self) -> TurnPhase {
         self.phase
@@ -296,7 +353,7 @@ mod tests {
         turn_system.start_game();
 
         assert_eq!(turn_system.phase(), TurnPhase::Active);
-        assert_eq!(turn_system.current_team(), Team::Player);
+        assert_eq!(turn_system.current_team(), Team::PLAYER);
     }
 
     #[test]
@@ -304,37 +361,58 @@ mod tests {
         let mut turn_system = TurnSystem::new();
         turn_system.start_game();
 
-        assert_eq!(turn_system.current_team(), Team::Player);
+        assert_eq!(turn_system.current_team(), Team::PLAYER);
 
         turn_system.end_turn();
-        assert_eq!(turn_system.current_team(), Team::Enemy);
+        assert_eq!(turn_system.current_team(), Team::ENEMY);
 
         turn_system.end_turn();
-        assert_eq!(turn_system.current_team(), Team::Neutral);
+        assert_eq!(turn_system.current_team(), Team::NEUTRAL);
 
         turn_system.end_turn();
-        assert_eq!(turn_system.current_team(), Team::Player);
+        assert_eq!(turn_system.current_team(), Team::PLAYER);
         assert_eq!(turn_system.turn_number(), 1);
     }
 
     #[test]
     fn test_player_control() {
         let mut turn_system = TurnSystem::new();
-        turn_system.set_team_control(Team::Player, true);
-        turn_system.set_team_control(Team::Enemy, false);
+        turn_system.set_team_control(Team::PLAYER, true);
+        turn_system.set_team_control(Team::ENEMY, false);
 
         turn_system.start_game();
 
         // Player turn should not auto-advance
         turn_system.update(5.0);
-        assert_eq!(turn_system.current_team(), Team::Player);
+        assert_eq!(turn_system.current_team(), Team::PLAYER);
 
         // Move to enemy turn
         turn_system.end_turn();
-        assert_eq!(turn_system.current_team(), Team::Enemy);
+        assert_eq!(turn_system.current_team(), Team::ENEMY);
 
         // Enemy turn should auto-advance after 3 seconds
         turn_system.update(3.5);
-        assert_eq!(turn_system.current_team(), Team::Neutral);
+        assert_eq!(turn_system.current_team(), Team::NEUTRAL);
+    }
+
+    #[test]
+    fn test_roster_turn_order_and_control() {
+        let mut roster = TeamRoster::new();
+        let red = roster.add_team("Red", [1.0, 0.0, 0.0]).unwrap();
+        let blue = roster.add_team("Blue", [0.0, 0.0, 1.0]).unwrap();
+        roster.get_mut(blue).unwrap().player_controlled = true;
+        roster.set_turn_order(vec![blue, red]).unwrap();
+
+        let mut turn_system = TurnSystem::from_roster(&roster);
+        turn_system.start_game();
+
+        assert_eq!(turn_system.teams(), &[blue, red]);
+        assert!(turn_system.is_current_team_player_controlled());
+        turn_system.end_turn();
+        assert_eq!(turn_system.current_team(), red);
+        assert!(!turn_system.is_current_team_player_controlled());
+        turn_system.end_turn();
+        assert_eq!(turn_system.current_team(), blue);
+        assert_eq!(turn_system.turn_number(), 1);
     }
 }
@@ -20,14 +20,19 @@
 //! 4. Combat is executed with damage calculations and counter-attacks
 //! 5. Defeated units are removed from the world
 
+use crate::events::EventQueue;
+use crate::hit_chance::tile_defense;
 use crate::objects::*;
+use crate::scenario_instance::move_destination;
 use ai::{
-    ActionInstance as AiActionInstance, FactValue as AiFactValue, Goal as AiGoal,
-    WorldState as AiWorldState,
+    ActionInstance as AiActionInstance, FactKey, FactValue as AiFactValue, Goal as AiGoal,
+    TeamFact, TerrainFact, UnitFact, WorldState as AiWorldState, TRACE_TARGET,
 };
-use graphics::{HexCoord, SpriteType};
+use graphics::SpriteType;
+use hex::HexCoord;
+use log::debug;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use units::combat::{HitChanceBreakdown, RangeType};
 use uuid::Uuid;
 
 // Weight applied to expected damage when converting to a negative cost (higher -> more aggressive)
@@ -42,10 +47,38 @@ const MIN_ACTION_COST: f32 = 0.01;
 pub struct AttackInfo {
     /// Display name of the attack
     pub name: String,
-    /// Base damage value
+    /// Damage per hit, after the time-of-day bonus and attack buffs
     pub damage: u32,
+    /// Time-of-day damage bonus already applied to `damage`, in percent
+    pub time_of_day_bonus: i32,
     /// Attack range (1 for melee, higher for ranged)
     pub range: i32,
+    /// Melee, ranged or siege
+    pub range_type: RangeType,
+    /// Whether the attack reaches the opposing unit from where it stands
+    pub in_reach: bool,
+    /// Index in the opposing unit's attacks of the counter-attack this
+    /// attack draws, or `None` if the opposing unit cannot retaliate
+    pub counter_index: Option<usize>,
+    /// Chance for this attack to hit the opposing unit, with its breakdown
+    pub hit_chance: HitChanceBreakdown,
+    /// Outcome forecast of attacking with this attack, for the attacker's
+    /// attacks that are in reach (see [`combat::forecast`])
+    pub forecast: Option<combat::CombatForecast>,
+}
+
+impl AttackInfo {
+    /// Index of the attack `defender_attacks` answers `incoming` with from
+    /// `distance` hexes away (see [`units::Attack::can_retaliate`]).
+    pub fn counter_index(
+        defender_attacks: &[units::Attack],
+        incoming: &units::Attack,
+        distance: i32,
+    ) -> Option<usize> {
+        defender_attacks
+            .iter()
+            .position(|attack| attack.can_retaliate(incoming, distance))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -86,19 +119,6 @@ pub struct PendingCombat {
     pub selected_attack_index: usize,
 }
 
-/// Events emitted by the AI executor for the game to react to.
-#[derive(Clone, Debug)]
-pub enum GameEvent {
-    ActionStarted {
-        unit_id: Uuid,
-        action: ai::ActionInstance,
-    },
-    ActionCompleted {
-        unit_id: Uuid,
-        action: ai::ActionInstance,
-    },
-}
-
 pub struct GameWorld {
     /// All terrain tiles in the world, indexed by hex coordinate
     pub terrain: HashMap<HexCoord, TerrainTile>,
@@ -117,9 +137,11 @@ pub struct GameWorld {
 
     /// Pending combat awaiting player confirmation
     pub pending_combat: Option<PendingCombat>,
-    /// Queue of AI events emitted by executors (start/complete). Protected by mutex
-    /// so executor callbacks can push events from closures.
-    pub ai_event_queue: Arc<Mutex<Vec<GameEvent>>>,
+    /// Queue of game events for the presentation layer (see [`crate::events`])
+    pub events: EventQueue,
+
+    /// Teams: names, colors, diplomacy and turn order
+    pub teams: TeamRoster,
 
     /// Turn-based gameplay system
     pub turn_system: crate::turn_system::TurnSystem,
@@ -130,11 +152,9 @@ pub struct GameWorld {
 
 impl GameWorld {
     pub fn new(world_radius: i32) -> Self {
-        let mut turn_system = crate::turn_system::TurnSystem::new();
-        // By default, only Player team is player-controlled
-        turn_system.set_team_control(Team::Player, true);
-        turn_system.set_team_control(Team::Enemy, false);
-        turn_system.set_team_control(Team::Neutral, false);
+        // By default, only the Player team of the default roster is player-controlled
+        let teams = TeamRoster::default();
+        let turn_system = crate::turn_system::TurnSystem::from_roster(&teams);
 
         let mut world = Self {
             terrain: HashMap::new(),
@@ -143,7 +163,8 @@ impl GameWorld {
             units: HashMap::new(),
             interactive_objects: HashMap::new(),
             pending_combat: None,
-            ai_event_queue: Arc::new(Mutex::new(Vec::new())),
+            events: EventQueue::new(),
+            teams,
             turn_system,
             last_known_team: None,
         };
@@ -171,28 +192,26 @@ impl GameWorld {
 
     /// Extract a minimal AI world state for the given team.
     ///
-    /// Prototype: encode unit positions and alive flags as simple string facts.
+    /// Prototype: encode unit positions and alive flags.
     pub fn extract_world_state_for_team(&self, team: Team) -> AiWorldState {
         let mut ws = AiWorldState::new();
 
         for (id, unit) in &self.units {
-            // fact keys: "Unit:{id}:At" => "q,r" string
-            let pos = unit.position();
-            let key_pos = format!("Unit:{}:At", id);
-            ws.insert(
-                key_pos.clone(),
-                AiFactValue::Str(format!("{},{}", pos.q, pos.r)),
+            let id_str = id.to_string();
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::At),
+                AiFactValue::Hex(unit.position()),
+            );
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Alive),
+                AiFactValue::Bool(true),
             );
-
-            // alive flag
-            let alive_key = format!("Unit:{}:Alive", id);
-            ws.insert(alive_key, AiFactValue::Bool(true));
         }
 
         // Additionally include a team marker
-        ws.insert(
-            "CurrentTeam".to_string(),
-            AiFactValue::Str(format!("{:?}", team)),
+        ws.set(
+            FactKey::CurrentTeam,
+            AiFactValue::Str(self.teams.name(team).to_string()),
         );
 
         ws
@@ -215,9 +234,9 @@ impl GameWorld {
         let mut ws = AiWorldState::new();
 
         // === TEAM METADATA ===
-        ws.insert(
-            "CurrentTeam".to_string(),
-            AiFactValue::Str(format!("{:?}", team)),
+        ws.set(
+            FactKey::CurrentTeam,
+            AiFactValue::Str(self.teams.name(team).to_string()),
         );
 
         let mut friendly_positions: Vec<(Uuid, HexCoord)> = Vec::new();
@@ -228,91 +247,88 @@ impl GameWorld {
             let id_str = id.to_string();
             let pos = unit.position();
             let stats = unit.unit().combat_stats();
-            let is_friendly = unit.team() == team;
+            let is_friendly = self.teams.are_allied(unit.team(), team);
 
             // Basic position and status
-            ws.insert(
-                format!("Unit:{}:At", id_str),
-                AiFactValue::Str(format!("{},{}", pos.q, pos.r)),
-            );
-            ws.insert(
-                format!("Unit:{}:Alive", id_str),
+            ws.set(FactKey::unit(&id_str, UnitFact::At), AiFactValue::Hex(pos));
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Alive),
                 AiFactValue::Bool(unit.unit().is_alive()),
             );
-            ws.insert(
-                format!("Unit:{}:Team", id_str),
-                AiFactValue::Str(format!("{:?}", unit.team())),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Team),
+                AiFactValue::Str(self.teams.name(unit.team()).to_string()),
             );
-            ws.insert(
-                format!("Unit:{}:IsFriendly", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsFriendly),
                 AiFactValue::Bool(is_friendly),
             );
 
             // Health information
-            ws.insert(
-                format!("Unit:{}:Health", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Health),
                 AiFactValue::Int(stats.health),
             );
-            ws.insert(
-                format!("Unit:{}:MaxHealth", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MaxHealth),
                 AiFactValue::Int(stats.max_health),
             );
             let health_pct = (stats.health * 100) / stats.max_health.max(1);
-            ws.insert(
-                format!("Unit:{}:HealthPercent", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::HealthPercent),
                 AiFactValue::Int(health_pct),
             );
-            ws.insert(
-                format!("Unit:{}:IsWounded", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsWounded),
                 AiFactValue::Bool(health_pct < 50),
             );
 
             // Movement information
-            ws.insert(
-                format!("Unit:{}:MovesLeft", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MovesLeft),
                 AiFactValue::Int(unit.moves_left()),
             );
-            ws.insert(
-                format!("Unit:{}:MovementSpeed", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::MovementSpeed),
                 AiFactValue::Int(stats.movement_speed),
             );
 
             // Combat information
-            ws.insert(
-                format!("Unit:{}:AttackPower", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackPower),
                 AiFactValue::Int(stats.base_attack as i32),
             );
-            ws.insert(
-                format!("Unit:{}:AttackedThisTurn", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackedThisTurn),
                 AiFactValue::Bool(stats.attacked_this_turn),
             );
 
             // Attack range
             let attacks = unit.unit().get_attacks();
             let max_range = attacks.iter().map(|a| a.range).max().unwrap_or(1);
-            ws.insert(
-                format!("Unit:{}:AttackRange", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::AttackRange),
                 AiFactValue::Int(max_range),
             );
 
             // Threat level calculation
             let threat = self.calculate_threat_level(unit);
-            ws.insert(
-                format!("Unit:{}:ThreatLevel", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::ThreatLevel),
                 AiFactValue::Int(threat),
             );
 
             // Terrain defense
             let defense = unit.unit().get_defense();
-            ws.insert(
-                format!("Unit:{}:Defense", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::Defense),
                 AiFactValue::Int(defense as i32),
             );
 
-            // Store positions for clustering calculations
+            // Store positions for clustering calculations (neutral units are neither)
             if is_friendly {
                 friendly_positions.push((*id, pos));
-            } else {
+            } else if self.teams.are_hostile(unit.team(), team) {
                 enemy_positions.push((*id, pos));
             }
         }
@@ -321,15 +337,12 @@ impl GameWorld {
         // Include terrain for all unit positions
         for (_, pos) in friendly_positions.iter().chain(enemy_positions.iter()) {
             if let Some(terrain_tile) = self.get_terrain(*pos) {
-                let pos_key = format!("{},{}", pos.q, pos.r);
-                let terrain_type = terrain_tile.sprite_type();
-
-                ws.insert(
-                    format!("Terrain:{}:Type", pos_key),
-                    AiFactValue::Str(format!("{:?}", terrain_type)),
+                ws.set(
+                    FactKey::terrain(*pos, TerrainFact::Type),
+                    AiFactValue::Str(terrain_tile.terrain().id.clone()),
                 );
-                ws.insert(
-                    format!("Terrain:{}:MoveCost", pos_key),
+                ws.set(
+                    FactKey::terrain(*pos, TerrainFact::MoveCost),
                     AiFactValue::Int(terrain_tile.movement_cost()),
                 );
             }
@@ -345,12 +358,12 @@ impl GameWorld {
                 .filter(|(other_id, other_pos)| other_id != id && pos.distance(*other_pos) <= 2)
                 .count();
 
-            ws.insert(
-                format!("Unit:{}:NearbyAllies", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::NearbyAllies),
                 AiFactValue::Int(nearby_allies as i32),
             );
-            ws.insert(
-                format!("Unit:{}:IsIsolated", id_str),
+            ws.set(
+                FactKey::unit(&id_str, UnitFact::IsIsolated),
                 AiFactValue::Bool(nearby_allies == 0),
             );
 
@@ -360,8 +373,8 @@ impl GameWorld {
                 .map(|(_, epos)| pos.distance(*epos))
                 .min()
             {
-                ws.insert(
-                    format!("Unit:{}:NearestEnemyDist", id_str),
+                ws.set(
+                    FactKey::unit(&id_str, UnitFact::NearestEnemyDist),
                     AiFactValue::Int(nearest_dist),
                 );
             }
@@ -376,20 +389,20 @@ impl GameWorld {
                     .filter(|(_, epos)| pos.distance(*epos) <= max_range)
                     .count();
 
-                ws.insert(
-                    format!("Unit:{}:EnemiesInRange", id_str),
+                ws.set(
+                    FactKey::unit(&id_str, UnitFact::EnemiesInRange),
                     AiFactValue::Int(enemies_in_range as i32),
                 );
             }
         }
 
         // === TEAM-LEVEL METRICS ===
-        ws.insert(
-            "Team:AllyCount".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::AllyCount),
             AiFactValue::Int(friendly_positions.len() as i32),
         );
-        ws.insert(
-            "Team:EnemyCount".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::EnemyCount),
             AiFactValue::Int(enemy_positions.len() as i32),
         );
 
@@ -397,7 +410,7 @@ impl GameWorld {
         let total_health: i32 = self
             .units
             .iter()
-            .filter(|(_, u)| u.team() == team)
+            .filter(|(_, u)| self.teams.are_allied(u.team(), team))
             .map(|(_, u)| u.unit().combat_stats().health)
             .sum();
         let avg_health = if !friendly_positions.is_empty() {
@@ -405,8 +418,8 @@ impl GameWorld {
         } else {
             0
         };
-        ws.insert(
-            "Team:AverageHealth".to_string(),
+        ws.set(
+            FactKey::team(TeamFact::AverageHealth),
             AiFactValue::Int(avg_health),
         );
 
@@ -529,6 +542,8 @@ impl GameWorld {
             }
 
             let uid_str = id.to_string();
+            // Built once and cloned, so every action shares the key's symbol
+            let at_key = FactKey::unit(&uid_str, UnitFact::At);
             let pos = unit.position();
             let moves_left = unit.moves_left();
 
@@ -540,14 +555,8 @@ impl GameWorld {
                     continue;
                 }
 
-                let preconds = vec![(
-                    format!("Unit:{}:At", id),
-                    AiFactValue::Str(format!("{},{}", pos.q, pos.r)),
-                )];
-                let effects = vec![(
-                    format!("Unit:{}:At", id),
-                    AiFactValue::Str(format!("{},{}", tile.q, tile.r)),
-                )];
+                let preconds = vec![(at_key.clone(), AiFactValue::Hex(pos))];
+                let effects = vec![(at_key.clone(), AiFactValue::Hex(*tile))];
 
                 out.push(AiActionInstance {
                     name: format!("Move-{}->{},{}", uid_str, tile.q, tile.r),
@@ -560,10 +569,11 @@ impl GameWorld {
 
             // Ground Attack actions for reachable attack positions based on unit's available attacks and ranges
             for (other_id, other_unit) in &self.units {
-                if other_unit.team() == team {
+                if !self.teams.are_hostile(other_unit.team(), team) {
                     continue;
                 }
                 let enemy_pos = other_unit.position();
+                let alive_key = FactKey::unit(other_id.to_string(), UnitFact::Alive);
 
                 // Candidate attacker positions: current position + reachable tiles
                 let mut candidate_positions: Vec<HexCoord> = reachable.keys().cloned().collect();
@@ -589,14 +599,10 @@ impl GameWorld {
                         }
 
                         let preconds = vec![
-                            (
-                                format!("Unit:{}:At", id),
-                                AiFactValue::Str(format!("{},{}", from.q, from.r)),
-                            ),
-                            (format!("Unit:{}:Alive", other_id), AiFactValue::Bool(true)),
+                            (at_key.clone(), AiFactValue::Hex(*from)),
+                            (alive_key.clone(), AiFactValue::Bool(true)),
                         ];
-                        let effects =
-                            vec![(format!("Unit:{}:Alive", other_id), AiFactValue::Bool(false))];
+                        let effects = vec![(alive_key.clone(), AiFactValue::Bool(false))];
 
                         // Estimate expected damage and hit chance to convert into an expected-utility cost.
                         // Hit chance is approximated using the same formula as combat resolver:
@@ -606,10 +612,11 @@ impl GameWorld {
                         let hit_prob = final_hit_chance as f32 / 100.0;
 
                         // Compute expected damage on hit using attack.damage and defender resistances
-                        let def_stats = other_unit.unit().combat_stats();
-                        let resistance =
-                            def_stats.resistances.get_resistance(attack.damage_type) as f32;
-                        let resist_mul = 1.0 - (resistance / 100.0);
+                        let resist_mul = other_unit
+                            .unit()
+                            .combat_stats()
+                            .resistances
+                            .damage_multiplier(attack.damage_type);
                         let damage_on_hit = (attack.damage as f32 * resist_mul).max(1.0);
 
                         let expected_damage = hit_prob * damage_on_hit;
@@ -658,13 +665,14 @@ impl GameWorld {
     /// then end the turn.
     pub fn run_ai_for_current_team(&mut self) {
         let current_team = self.turn_system.current_team();
-        println!(
-            "🤖 [AI DEBUG] run_ai_for_current_team called for team {:?}",
+        debug!(
+            target: TRACE_TARGET,
+            "run_ai_for_current_team called for team {:?}",
             current_team
         );
 
         if self.turn_system.is_current_team_player_controlled() {
-            println!("🤖 [AI DEBUG] Team is player-controlled, skipping AI");
+            debug!(target: TRACE_TARGET, "Team is player-controlled, skipping AI");
             return; // Player team handled by UI
         }
 
@@ -674,17 +682,17 @@ impl GameWorld {
             .iter()
             .filter(|(_, u)| u.team() == current_team)
             .collect();
-        println!("🤖 [AI DEBUG] Team has {} units", team_units.len());
+        debug!(target: TRACE_TARGET, "Team has {} units", team_units.len());
         for (_id, unit) in &team_units {
-            println!("🤖 [AI DEBUG]   - {} at {:?}", unit.name(), unit.position());
+            debug!(target: TRACE_TARGET, "  - {} at {:?}", unit.name(), unit.position());
         }
 
         // Prepare AI world state and actions
         let ws = self.extract_world_state_for_team(current_team);
-        println!("🤖 [AI DEBUG] World state extracted");
+        debug!(target: TRACE_TARGET, "World state extracted");
 
         let actions = self.generate_team_actions(current_team);
-        println!("🤖 [AI DEBUG] Generated {} possible actions", actions.len());
+        debug!(target: TRACE_TARGET, "Generated {} possible actions", actions.len());
 
         // Build goals: naive goal is to kill any enemy unit found (per agent we add goals later)
         use std::collections::HashMap as StdHashMap;
@@ -704,7 +712,7 @@ impl GameWorld {
             // Find all enemy units and their distances
             let mut enemies_with_distance: Vec<(Uuid, &GameUnit, i32)> = Vec::new();
             for (enemy_id, enemy_unit) in &self.units {
-                if enemy_unit.team() != current_team {
+                if self.teams.are_hostile(enemy_unit.team(), current_team) {
                     let distance = unit.position().distance(enemy_unit.position());
                     enemies_with_distance.push((*enemy_id, enemy_unit, distance));
                 }
@@ -715,20 +723,22 @@ impl GameWorld {
 
             // Set goal to kill the closest enemy
             if let Some((enemy_id, enemy_unit, distance)) = enemies_with_distance.first() {
-                goals.push(AiGoal {
-                    key: format!("Unit:{}:Alive", enemy_id),
-                    value: AiFactValue::Bool(false),
-                });
-                println!(
-                    "🤖 [AI DEBUG] Unit {} targeting closest enemy {} at distance {}",
+                goals.push(AiGoal::new(
+                    FactKey::unit(enemy_id.to_string(), UnitFact::Alive),
+                    AiFactValue::Bool(false),
+                ));
+                debug!(
+                    target: TRACE_TARGET,
+                    "Unit {} targeting closest enemy {} at distance {}",
                     unit.name(),
                     enemy_unit.name(),
                     distance
                 );
             }
 
-            println!(
-                "🤖 [AI DEBUG] Unit {} has {} goals",
+            debug!(
+                target: TRACE_TARGET,
+                "Unit {} has {} goals",
                 unit.name(),
                 goals.len()
             );
@@ -736,8 +746,9 @@ impl GameWorld {
         }
 
         // Call team planner (bounded search per agent)
-        println!(
-            "🤖 [AI DEBUG] Calling planner for {} agents...",
+        debug!(
+            target: TRACE_TARGET,
+            "Calling planner for {} agents...",
             agent_order.len()
         );
 
@@ -750,16 +761,18 @@ impl GameWorld {
             .iter()
             .filter(|a| a.name.starts_with("Attack-"))
             .count();
-        println!(
-            "🤖 [AI DEBUG] Action breakdown: {} moves, {} attacks",
+        debug!(
+            target: TRACE_TARGET,
+            "Action breakdown: {} moves, {} attacks",
             move_count, attack_count
         );
 
         // Debug: Print first few actions to see what's available
-        println!("🤖 [AI DEBUG] Sample actions (first 10):");
+        debug!(target: TRACE_TARGET, "Sample actions (first 10):");
         for (i, action) in actions.iter().take(10).enumerate() {
-            println!(
-                "🤖 [AI DEBUG]   {}. {} (cost: {})",
+            debug!(
+                target: TRACE_TARGET,
+                "  {}. {} (cost: {})",
                 i, action.name, action.cost
             );
         }
@@ -771,32 +784,34 @@ impl GameWorld {
             .take(3)
             .collect();
         if !sample_attacks.is_empty() {
-            println!("🤖 [AI DEBUG] Sample attack actions:");
+            debug!(target: TRACE_TARGET, "Sample attack actions:");
             for action in sample_attacks {
-                println!("🤖 [AI DEBUG]   - {} (cost: {})", action.name, action.cost);
-                println!(
-                    "🤖 [AI DEBUG]      Preconditions: {:?}",
+                debug!(target: TRACE_TARGET, "  - {} (cost: {})", action.name, action.cost);
+                debug!(
+                    target: TRACE_TARGET,
+                    "     Preconditions: {:?}",
                     action.preconditions
                 );
-                println!("🤖 [AI DEBUG]      Effects: {:?}", action.effects);
+                debug!(target: TRACE_TARGET, "     Effects: {:?}", action.effects);
             }
         } else {
-            println!("🤖 [AI DEBUG] ⚠️ NO ATTACK ACTIONS GENERATED! Units may be out of range.");
+            debug!(target: TRACE_TARGET, "⚠️ NO ATTACK ACTIONS GENERATED! Units may be out of range.");
         }
 
         // Debug: Print goals
-        println!("🤖 [AI DEBUG] Goals per agent:");
+        debug!(target: TRACE_TARGET, "Goals per agent:");
         for (agent, goals) in &goals_per_agent {
-            println!("🤖 [AI DEBUG]   Agent {}: {} goals", agent, goals.len());
+            debug!(target: TRACE_TARGET, "  Agent {}: {} goals", agent, goals.len());
             for goal in goals {
-                println!("🤖 [AI DEBUG]      {} = {:?}", goal.key, goal.value);
+                debug!(target: TRACE_TARGET, "     {} = {:?}", goal.key, goal.value);
             }
         }
 
         // Increase planner depth limit significantly
         let plans = ai::plan_for_team(&ws, &actions, &goals_per_agent, &agent_order, 5000);
-        println!(
-            "🤖 [AI DEBUG] Planner returned plans for {} agents",
+        debug!(
+            target: TRACE_TARGET,
+            "Planner returned plans for {} agents",
             plans.len()
         );
 
@@ -804,8 +819,9 @@ impl GameWorld {
         // executed_actions_count removed — we end AI turn immediately after executing plans
         let mut total_actions_executed = 0;
         for (agent, plan) in plans {
-            println!(
-                "🤖 [AI DEBUG] Agent {} has plan with {} steps",
+            debug!(
+                target: TRACE_TARGET,
+                "Agent {} has plan with {} steps",
                 agent,
                 plan.len()
             );
@@ -813,7 +829,7 @@ impl GameWorld {
             // find unit uuid
             if let Ok(uuid) = Uuid::parse_str(&agent) {
                 if plan.is_empty() {
-                    println!("🤖 [AI DEBUG] Agent {} has empty plan, using fallback: move toward nearest enemy", agent);
+                    debug!(target: TRACE_TARGET, "Agent {} has empty plan, using fallback: move toward nearest enemy", agent);
 
                     // FALLBACK: Move toward nearest enemy
                     if let Some(unit) = self.units.get(&uuid) {
@@ -823,7 +839,7 @@ impl GameWorld {
                         // Find nearest enemy
                         let mut nearest_enemy: Option<(Uuid, &GameUnit, i32)> = None;
                         for (enemy_id, enemy_unit) in &self.units {
-                            if enemy_unit.team() != unit_team {
+                            if self.teams.are_hostile(enemy_unit.team(), unit_team) {
                                 let distance = unit_pos.distance(enemy_unit.position());
                                 if nearest_enemy.is_none() || distance < nearest_enemy.unwrap().2 {
                                     nearest_enemy = Some((*enemy_id, enemy_unit, distance));
@@ -832,8 +848,9 @@ impl GameWorld {
                         }
 
                         if let Some((_, enemy, distance)) = nearest_enemy {
-                            println!(
-                                "🤖 [AI DEBUG] Fallback: Moving toward enemy at distance {}",
+                            debug!(
+                                target: TRACE_TARGET,
+                                "Fallback: Moving toward enemy at distance {}",
                                 distance
                             );
                             let enemy_pos = enemy.position();
@@ -849,23 +866,13 @@ impl GameWorld {
                             for (idx, action) in agent_actions.iter().enumerate() {
                                 if action.name.starts_with("Move-") {
                                     // Extract destination from effects
-                                    if let Some((_, AiFactValue::Str(dest))) =
-                                        action.effects.first()
-                                    {
-                                        let parts: Vec<&str> = dest.split(',').collect();
-                                        if parts.len() == 2 {
-                                            if let (Ok(q), Ok(r)) =
-                                                (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                            {
-                                                let dest_coord = HexCoord::new(q, r);
-                                                let new_distance = dest_coord.distance(enemy_pos);
-
-                                                if best_move.is_none()
-                                                    || new_distance < best_move.unwrap().1
-                                                {
-                                                    best_move = Some((idx, new_distance));
-                                                }
-                                            }
+                                    if let Some(dest_coord) = move_destination(action) {
+                                        let new_distance = dest_coord.distance(enemy_pos);
+
+                                        if best_move.is_none()
+                                            || new_distance < best_move.unwrap().1
+                                        {
+                                            best_move = Some((idx, new_distance));
                                         }
                                     }
                                 }
@@ -874,27 +881,18 @@ impl GameWorld {
                             // Execute best move
                             if let Some((idx, new_dist)) = best_move {
                                 if let Some(action) = agent_actions.get(idx) {
-                                    println!("🤖 [AI DEBUG] Executing fallback move: {} (new distance: {})", action.name, new_dist);
-                                    if let Some((_, AiFactValue::Str(dest))) =
-                                        action.effects.first()
-                                    {
-                                        let parts: Vec<&str> = dest.split(',').collect();
-                                        if parts.len() == 2 {
-                                            if let (Ok(q), Ok(r)) =
-                                                (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                            {
-                                                let dest_coord = HexCoord::new(q, r);
-                                                match self.move_unit(uuid, dest_coord) {
-                                                    Ok(()) => {
-                                                        println!("🤖 [AI DEBUG] Fallback move successful!");
-                                                        total_actions_executed += 1;
-                                                    }
-                                                    Err(e) => println!(
-                                                        "🤖 [AI DEBUG] Fallback move failed: {}",
-                                                        e
-                                                    ),
-                                                }
+                                    debug!(target: TRACE_TARGET, "Executing fallback move: {} (new distance: {})", action.name, new_dist);
+                                    if let Some(dest_coord) = move_destination(action) {
+                                        match self.move_unit(uuid, dest_coord) {
+                                            Ok(()) => {
+                                                debug!(target: TRACE_TARGET, "Fallback move successful!");
+                                                total_actions_executed += 1;
                                             }
+                                            Err(e) => debug!(
+                                                target: TRACE_TARGET,
+                                                "Fallback move failed: {}",
+                                                e
+                                            ),
                                         }
                                     }
                                 }
@@ -909,73 +907,73 @@ impl GameWorld {
                     .filter(|a| a.agent.as_ref().map(|s| s == &agent).unwrap_or(false))
                     .cloned()
                     .collect();
-                println!(
-                    "🤖 [AI DEBUG] Agent {} has {} available actions",
+                debug!(
+                    target: TRACE_TARGET,
+                    "Agent {} has {} available actions",
                     agent,
                     agent_actions.len()
                 );
 
                 for &idx in &plan {
                     if let Some(a) = agent_actions.get(idx) {
-                        println!("🤖 [AI DEBUG] Executing action: {}", a.name);
+                        debug!(target: TRACE_TARGET, "Executing action: {}", a.name);
                         // If action is Move (name starts with Move-), parse target and call move_unit
                         if a.name.starts_with("Move-") {
-                            // effects contain Unit:{id}:At -> "q,r"
-                            if let Some((_, AiFactValue::Str(dest))) = a.effects.first() {
-                                let parts: Vec<&str> = dest.split(',').collect();
-                                if parts.len() == 2 {
-                                    if let (Ok(q), Ok(r)) =
-                                        (parts[0].parse::<i32>(), parts[1].parse::<i32>())
-                                    {
-                                        let dest_coord = HexCoord::new(q, r);
-                                        println!("🤖 [AI DEBUG] Moving to ({}, {})", q, r);
-                                        // Use move_unit; ignore errors for prototype
-                                        match self.move_unit(uuid, dest_coord) {
-                                            Ok(()) => {
-                                                println!("🤖 [AI DEBUG] Move successful!");
-                                                total_actions_executed += 1;
-                                            }
-                                            Err(e) => println!("🤖 [AI DEBUG] Move failed: {}", e),
-                                        }
+                            // effects contain Unit:{id}:At -> Hex
+                            if let Some(dest_coord) = move_destination(a) {
+                                debug!(
+                                    target: TRACE_TARGET,
+                                    "Moving to ({}, {})",
+                                    dest_coord.q,
+                                    dest_coord.r
+                                );
+                                // Use move_unit; ignore errors for prototype
+                                match self.move_unit(uuid, dest_coord) {
+                                    Ok(()) => {
+                                        debug!(target: TRACE_TARGET, "Move successful!");
+                                        total_actions_executed += 1;
+                                    }
+                                    Err(e) => {
+                                        debug!(target: TRACE_TARGET, "Move failed: {}", e)
                                     }
                                 }
                             }
                         } else if a.name.starts_with("Attack-") {
                             // effects contain Unit:{other}:Alive=false; extract other id
-                            if let Some((k, _)) = a.effects.first() {
-                                if k.starts_with("Unit:") && k.ends_with(":Alive") {
-                                    let mid = &k[5..k.len() - 6];
-                                    if let Ok(target_uuid) = Uuid::parse_str(mid) {
-                                        println!("🤖 [AI DEBUG] Attacking target {}", target_uuid);
-                                        // Request combat (this will set pending_combat). If the
-                                        // request fails (e.g., attacker already attacked), skip
-                                        // executing combat.
-                                        // request_combat now returns Ok(()) even when it silently
-                                        // skips creating a pending combat (attacker already
-                                        // attacked). Only execute if a pending combat was
-                                        // actually created.
-                                        let _ = self.request_combat(uuid, target_uuid);
-                                        if self.pending_combat.is_some() {
-                                            println!("🤖 [AI DEBUG] Executing combat...");
-                                            // execute_pending_combat may set state; count it as an executed action
-                                            let _ = self.execute_pending_combat();
-                                            total_actions_executed += 1;
-                                        } else {
-                                            println!("🤖 [AI DEBUG] Combat request failed (unit may have already attacked)");
-                                        }
+                            if let Some((FactKey::Unit(target, UnitFact::Alive), _)) =
+                                a.effects.first()
+                            {
+                                if let Ok(target_uuid) = Uuid::parse_str(target.as_str()) {
+                                    debug!(target: TRACE_TARGET, "Attacking target {}", target_uuid);
+                                    // Request combat (this will set pending_combat). If the
+                                    // request fails (e.g., attacker already attacked), skip
+                                    // executing combat.
+                                    // request_combat now returns Ok(()) even when it silently
+                                    // skips creating a pending combat (attacker already
+                                    // attacked). Only execute if a pending combat was
+                                    // actually created.
+                                    let _ = self.request_combat(uuid, target_uuid);
+                                    if self.pending_combat.is_some() {
+                                        debug!(target: TRACE_TARGET, "Executing combat...");
+                                        // execute_pending_combat may set state; count it as an executed action
+                                        let _ = self.execute_pending_combat();
+                                        total_actions_executed += 1;
+                                    } else {
+                                        debug!(target: TRACE_TARGET, "Combat request failed (unit may have already attacked)");
                                     }
                                 }
                             }
                         }
                     } else {
-                        println!("🤖 [AI DEBUG] Invalid action index {} in plan", idx);
+                        debug!(target: TRACE_TARGET, "Invalid action index {} in plan", idx);
                     }
                 }
             }
         }
 
-        println!(
-            "🤖 [AI DEBUG] AI executed {} total actions",
+        debug!(
+            target: TRACE_TARGET,
+            "AI executed {} total actions",
             total_actions_executed
         );
         // End AI turn after actions (use GameWorld API so unit moves are reset)
@@ -1198,11 +1196,11 @@ impl GameWorld {
                         // There's another unit at this position
                         // Check if it's an enemy (allow) or ally (block)
                         if let Some(moving_unit) = self.units.get(&moving_unit_id) {
-                            if moving_unit.team() == unit.team() {
-                                // Same team - block movement
+                            if !self.teams.are_hostile(moving_unit.team(), unit.team()) {
+                                // Allied or neutral - block movement
                                 return false;
                             }
-                            // Different team - allow movement (combat will be initiated)
+                            // Hostile team - allow movement (combat will be initiated)
                         } else {
                             // Moving unit not found - block to be safe
                             return false;
@@ -1255,7 +1253,7 @@ impl GameWorld {
             let target_unit_team = self.units.get(&target_id).map(|u| u.team());
 
             if let (Some(mover_team), Some(target_team)) = (moving_unit_team, target_unit_team) {
-                if mover_team != target_team {
+                if self.teams.are_hostile(mover_team, target_team) {
                     // Enemy units - request combat confirmation!
                     return self.request_combat(unit_id, target_id);
                 } else {
@@ -1321,30 +1319,66 @@ impl GameWorld {
         let attacker_stats = attacker.unit().combat_stats();
         let defender_stats = defender.unit().combat_stats();
 
+        // Each side's attacks, with the chance to hit the other side where it stands
+        let (attacker_terrain, attacker_class) =
+            tile_defense(self.get_terrain(attacker.position()));
+        let (defender_terrain, defender_class) =
+            tile_defense(self.get_terrain(defender.position()));
+
+        let distance = attacker.position().distance(defender.position());
+        let attacker_unit_attacks = attacker.unit().get_attacks();
+        let defender_unit_attacks = defender.unit().get_attacks();
+
         // Get attacker's available attacks
-        let attacker_attacks = attacker
-            .unit()
-            .get_attacks()
+        let attacker_attacks: Vec<AttackInfo> = attacker_unit_attacks
             .iter()
             .map(|attack| AttackInfo {
                 name: attack.name.clone(),
                 damage: attack.damage,
+                time_of_day_bonus: 0,
                 range: attack.range,
+                range_type: attack.range_type,
+                in_reach: attack.can_reach(distance),
+                counter_index: AttackInfo::counter_index(&defender_unit_attacks, attack, distance),
+                forecast: None,
+                hit_chance: HitChanceBreakdown::for_strike(
+                    defender.unit(),
+                    &defender_terrain,
+                    defender_class,
+                    None,
+                    attack,
+                ),
             })
             .collect();
 
         // Get defender attacks
-        let defender_attacks = defender
-            .unit()
-            .get_attacks()
+        let defender_attacks = defender_unit_attacks
             .iter()
             .map(|attack| AttackInfo {
                 name: attack.name.clone(),
                 damage: attack.damage,
+                time_of_day_bonus: 0,
                 range: attack.range,
+                range_type: attack.range_type,
+                in_reach: attack.can_reach(distance),
+                counter_index: None,
+                forecast: None,
+                hit_chance: HitChanceBreakdown::for_strike(
+                    attacker.unit(),
+                    &attacker_terrain,
+                    attacker_class,
+                    None,
+                    attack,
+                ),
             })
             .collect();
 
+        // Default to the first attack in reach, will be updated by UI
+        let selected_attack_index = attacker_attacks
+            .iter()
+            .position(|attack| attack.in_reach)
+            .unwrap_or(0);
+
         let pending = PendingCombat {
             attacker_id,
             defender_id,
@@ -1352,17 +1386,23 @@ impl GameWorld {
             attacker_hp: attacker_stats.health as u32,
             attacker_max_hp: attacker_stats.max_health as u32,
             attacker_attack: attacker_stats.get_total_attack(),
-            attacker_defense: attacker_stats.resistances.slash as u32, // Use slash resistance as defense for display
+            attacker_defense: attacker_stats
+                .resistances
+                .get_resistance(combat::DamageType::Slash)
+                .max(0) as u32, // Use slash resistance as defense for display
             attacker_attacks_per_round: attacker_stats.attacks_per_round,
             attacker_attacks,
             defender_name: defender.name(),
             defender_hp: defender_stats.health as u32,
             defender_max_hp: defender_stats.max_health as u32,
             defender_attack: defender_stats.get_total_attack(),
-            defender_defense: defender_stats.resistances.slash as u32,
+            defender_defense: defender_stats
+                .resistances
+                .get_resistance(combat::DamageType::Slash)
+                .max(0) as u32,
             defender_attacks_per_round: defender_stats.attacks_per_round,
             defender_attacks,
-            selected_attack_index: 0, // Default to first attack, will be updated by UI
+            selected_attack_index,
         };
 
         self.pending_combat = Some(pending);
@@ -1497,10 +1537,9 @@ impl GameWorld {
             }
 
             // Calculate damage with resistance
-            let defender_resistance = defender_stats
+            let resistance_multiplier = defender_stats
                 .resistances
-                .get_resistance(selected_attack.damage_type);
-            let resistance_multiplier = 1.0 - (defender_resistance as f32 / 100.0);
+                .damage_multiplier(selected_attack.damage_type);
             let damage = ((selected_attack.damage as f32 * resistance_multiplier) as u32).max(1);
 
             // Apply damage to defender
@@ -1515,6 +1554,10 @@ impl GameWorld {
                     combat::DamageType::Crush => "🔨",
                     combat::DamageType::Fire => "🔥",
                     combat::DamageType::Dark => "🌑",
+                    combat::DamageType::Ice => "❄️ ",
+                    combat::DamageType::Lightning => "⚡",
+                    combat::DamageType::Poison => "☠️ ",
+                    combat::DamageType::Holy => "✨",
                 };
 
                 println!(
@@ -1545,84 +1588,79 @@ impl GameWorld {
             // Calculate actual distance between units
             let combat_distance = attacker_pos.distance(defender_pos);
 
-            // Defender can counter-attack if combat happened at melee range (distance 1)
-            if combat_distance == 1 {
-                // Get defender's melee attack (range 1)
-                let defender_melee_attack = {
-                    let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
-                    defender
-                        .unit()
-                        .get_attacks()
-                        .iter()
-                        .find(|a| a.range == 1) // Find melee attack
-                        .cloned()
-                };
-
-                if let Some(defender_attack) = defender_melee_attack {
-                    let defender_attacks_per_round = defender_stats.attacks_per_round;
+            // Defender counter-attacks with an attack of the same range type
+            // that reaches the attacker
+            let defender_counter_attack = {
+                let defender = self.units.get(&defender_id).ok_or("Defender not found")?;
+                defender
+                    .unit()
+                    .counter_attack(&selected_attack, combat_distance)
+            };
 
-                    println!(
-                        "\n🛡️  {} counter-attacks {} times with {}:",
-                        defender_name, defender_attacks_per_round, defender_attack.name
-                    );
+            if let Some(defender_attack) = defender_counter_attack {
+                let defender_attacks_per_round = defender_stats.attacks_per_round;
 
-                    let mut total_defender_damage = 0;
-                    for i in 1..=defender_attacks_per_round {
-                        if let Some(attacker) = self.units.get(&attacker_id) {
-                            if attacker.unit().combat_stats().health == 0 {
-                                break; // Attacker already dead
-                            }
-                        }
+                println!(
+                    "\n🛡️  {} counter-attacks {} times with {}:",
+                    defender_name, defender_attacks_per_round, defender_attack.name
+                );
 
-                        // Calculate damage with resistance
-                        let attacker_resistance = attacker_stats
-                            .resistances
-                            .get_resistance(defender_attack.damage_type);
-                        let resistance_multiplier = 1.0 - (attacker_resistance as f32 / 100.0);
-                        let damage =
-                            ((defender_attack.damage as f32 * resistance_multiplier) as u32).max(1);
-
-                        // Apply damage to attacker
-                        if let Some(attacker) = self.units.get_mut(&attacker_id) {
-                            attacker.unit_mut().take_damage(damage);
-                            total_defender_damage += damage;
-
-                            let damage_type_str = match defender_attack.damage_type {
-                                combat::DamageType::Slash => "⚔️ ",
-                                combat::DamageType::Pierce => "🗡️ ",
-                                combat::DamageType::Blunt => "🔨",
-                                combat::DamageType::Crush => "🔨",
-                                combat::DamageType::Fire => "🔥",
-                                combat::DamageType::Dark => "🌑",
-                            };
-
-                            println!(
-                                "  {}Attack {}/{}: {} damage",
-                                damage_type_str, i, defender_attacks_per_round, damage
-                            );
+                let mut total_defender_damage = 0;
+                for i in 1..=defender_attacks_per_round {
+                    if let Some(attacker) = self.units.get(&attacker_id) {
+                        if attacker.unit().combat_stats().health == 0 {
+                            break; // Attacker already dead
                         }
                     }
 
-                    println!(
-                        "\n📊 Total counter-attack damage: {}",
-                        total_defender_damage
-                    );
-                    // Mark defender as having attacked this turn (after performing counters)
-                    if let Some(defender_unit) = self.units.get_mut(&defender_id) {
-                        defender_unit
-                            .unit_mut()
-                            .combat_stats_mut()
-                            .attacked_this_turn = true;
+                    // Calculate damage with resistance
+                    let resistance_multiplier = attacker_stats
+                        .resistances
+                        .damage_multiplier(defender_attack.damage_type);
+                    let damage =
+                        ((defender_attack.damage as f32 * resistance_multiplier) as u32).max(1);
+
+                    // Apply damage to attacker
+                    if let Some(attacker) = self.units.get_mut(&attacker_id) {
+                        attacker.unit_mut().take_damage(damage);
+                        total_defender_damage += damage;
+
+                        let damage_type_str = match defender_attack.damage_type {
+                            combat::DamageType::Slash => "⚔️ ",
+                            combat::DamageType::Pierce => "🗡️ ",
+                            combat::DamageType::Blunt => "🔨",
+                            combat::DamageType::Crush => "🔨",
+                            combat::DamageType::Fire => "🔥",
+                            combat::DamageType::Dark => "🌑",
+                            combat::DamageType::Ice => "❄️ ",
+                            combat::DamageType::Lightning => "⚡",
+                            combat::DamageType::Poison => "☠️ ",
+                            combat::DamageType::Holy => "✨",
+                        };
+
+                        println!(
+                            "  {}Attack {}/{}: {} damage",
+                            damage_type_str, i, defender_attacks_per_round, damage
+                        );
                     }
-                } else {
-                    println!(
-                        "\n🛡️  {} has no melee weapon to counter-attack!",
-                        defender_name
-                    );
+                }
+
+                println!(
+                    "\n📊 Total counter-attack damage: {}",
+                    total_defender_damage
+                );
+                // Mark defender as having attacked this turn (after performing counters)
+                if let Some(defender_unit) = self.units.get_mut(&defender_id) {
+                    defender_unit
+                        .unit_mut()
+                        .combat_stats_mut()
+                        .attacked_this_turn = true;
                 }
             } else {
                 println!(
-                    "\n🏹 Combat at range {} - no counter-attack possible",
+                    "\n🛡️  {} has no {} attack to counter-attack at range {}!",
+                    defender_name,
+                    selected_attack.range_type.name(),
                     combat_distance
                 );
             }
@@ -1691,7 +1729,7 @@ impl GameWorld {
                 .units
                 .get(&attacker_id)
                 .map(|u| u.team())
-                .unwrap_or(Team::Player);
+                .unwrap_or(Team::PLAYER);
 
             for neighbor_coord in attacker_pos.neighbors().iter() {
                 // Get all units at this adjacent position
@@ -1701,7 +1739,7 @@ impl GameWorld {
                     .filter(|(id, unit)| {
                         **id != attacker_id
                             && unit.position() == *neighbor_coord
-                            && unit.team() == attacker_team
+                            && self.teams.are_allied(unit.team(), attacker_team)
                     })
                     .map(|(id, _)| *id)
                     .collect();
@@ -1829,7 +1867,7 @@ impl GameWorld {
                 .units
                 .get(&defender_id)
                 .map(|u| u.team())
-                .unwrap_or(Team::Enemy);
+                .unwrap_or(Team::ENEMY);
 
             for neighbor_coord in defender_pos.neighbors().iter() {
                 // Get all units at this adjacent position
@@ -1839,7 +1877,7 @@ impl GameWorld {
                     .filter(|(id, unit)| {
                         **id != defender_id
                             && unit.position() == *neighbor_coord
-                            && unit.team() == defender_team
+                            && self.teams.are_allied(unit.team(), defender_team)
                     })
                     .map(|(id, _)| *id)
                     .collect();
@@ -2098,6 +2136,13 @@ impl GameWorld {
         }
     }
 
+    /// Replaces the team roster and restarts the turn order from its first team
+    pub fn set_teams(&mut self, teams: TeamRoster) {
+        self.turn_system = crate::turn_system::TurnSystem::from_roster(&teams);
+        self.teams = teams;
+        self.last_known_team = None;
+    }
+
     /// Sets whether a team is player-controlled
     pub fn set_team_control(&mut self, team: Team, is_player_controlled: bool) {
         self.turn_system
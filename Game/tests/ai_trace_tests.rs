@@ -0,0 +1,99 @@
+/// Tests for the per-turn AI decision trace recorded by ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, Team};
+
+const SKIRMISH_MAP: &str = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "SpriteType": "Grasslands", "Unit": ["Dwarf Warrior", "Player"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "SpriteType": "Grasslands", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 2, "r": 0}, "SpriteType": "Grasslands", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 3, "r": 0}, "SpriteType": "Grasslands", "Unit": ["Orc Young Swordsman", "Enemy"], "Item": null, "Structure": null}
+  ]
+}"#;
+
+fn world_on_enemy_turn() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new(SKIRMISH_MAP.to_string());
+    world.start_turn_based_game();
+    world.end_current_turn();
+    assert_eq!(world.current_turn_team(), Team::ENEMY);
+    world
+}
+
+#[test]
+fn test_trace_not_kept_when_disabled() {
+    let mut world = world_on_enemy_turn();
+    world.run_ai_for_current_team();
+    assert!(world.last_ai_trace().is_none());
+}
+
+#[test]
+fn test_trace_records_planning_and_commands() {
+    let mut world = world_on_enemy_turn();
+    world.ai_trace_enabled = true;
+    world.run_ai_for_current_team();
+
+    let trace = world.last_ai_trace().expect("trace should be recorded");
+    assert_eq!(trace.team, "Enemy");
+    assert!(!trace.facts.is_empty());
+    assert!(!trace.candidates.is_empty());
+    assert_eq!(trace.agents.len(), 1);
+
+    let agent = &trace.agents[0];
+    assert!(!agent.goals.is_empty());
+    assert_eq!(agent.target, Some(ai::HexCoord { q: 0, r: 0 }));
+    assert_eq!(agent.path.first(), Some(&HexCoord::new(3, 0)));
+    assert!(agent.plan_found || agent.fallback.is_some());
+    assert!(!trace.commands.is_empty());
+
+    let json = trace.to_json().unwrap();
+    assert!(json.contains("\"candidates\""));
+}
+
+#[test]
+fn test_tactical_turn_records_search_nodes() {
+    let mut world = world_on_enemy_turn();
+    world.ai_trace_enabled = true;
+    world.tactical_search = Some(ai::SearchBudget::nodes(1, 500));
+    world.run_ai_for_current_team();
+
+    let trace = world.last_ai_trace().expect("trace should be recorded");
+    assert!(trace.search_nodes > 0);
+    // Named like GOAP traces, by the team's scenario name
+    assert_eq!(trace.team, world.teams.name(Team::ENEMY));
+}
+
+#[test]
+fn test_world_state_uses_typed_facts() {
+    use ai::{FactKey, FactValue, HexCoord, TeamFact, TerrainFact, UnitFact};
+
+    let world = world_on_enemy_turn();
+    let ws = world.extract_detailed_world_state(Team::ENEMY);
+    let orc = world
+        .units
+        .values()
+        .find(|u| u.team() == Team::ENEMY)
+        .expect("enemy unit");
+    let orc_id = orc.id().to_string();
+    let orc_hex = HexCoord { q: 3, r: 0 };
+
+    assert_eq!(
+        ws.fact(&FactKey::unit(&orc_id, UnitFact::At)),
+        Some(&FactValue::Hex(orc_hex))
+    );
+    assert_eq!(
+        ws.fact(&FactKey::unit(&orc_id, UnitFact::IsFriendly)),
+        Some(&FactValue::Bool(true))
+    );
+    assert_eq!(
+        ws.fact(&FactKey::team(TeamFact::EnemyCount)),
+        Some(&FactValue::Int(1))
+    );
+    assert!(ws
+        .fact(&FactKey::terrain(orc_hex, TerrainFact::MoveCost))
+        .is_some());
+    // The string API reads the same facts.
+    assert_eq!(
+        ws.get(&format!("Unit:{}:At", orc_id)),
+        Some(&FactValue::Hex(orc_hex))
+    );
+}
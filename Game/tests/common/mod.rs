@@ -0,0 +1,136 @@
+//! Shared fixtures for the scenario world tests
+
+// Every test crate uses only some of the helpers
+#![allow(dead_code)]
+
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord};
+use items::ItemRegistry;
+use uuid::Uuid;
+
+/// Scenario map built hex by hex, for tests that need a small custom map.
+///
+/// ```ignore
+/// let world = TestMap::new()
+///     .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+///     .unit(1, 0, "hills", "Orc Young Swordsman", "Enemy")
+///     .world();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TestMap {
+    hexes: Vec<String>,
+    day_cycle: Option<String>,
+}
+
+impl TestMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an empty hex.
+    pub fn hex(self, q: i32, r: i32, terrain: &str) -> Self {
+        self.cell(q, r, terrain, "null", "null")
+    }
+
+    /// Adds a hex holding a `unit_type` unit of `team`.
+    pub fn unit(self, q: i32, r: i32, terrain: &str, unit_type: &str, team: &str) -> Self {
+        let unit = format!(r#"["{}", "{}"]"#, unit_type, team);
+        self.cell(q, r, terrain, &unit, "null")
+    }
+
+    /// Adds a hex with the item of registry id `item` lying on it.
+    pub fn item(self, q: i32, r: i32, terrain: &str, item: &str) -> Self {
+        let item = format!(r#""{}""#, item);
+        self.cell(q, r, terrain, "null", &item)
+    }
+
+    /// Sets the map's `DayCycle`, a JSON array of phases.
+    pub fn day_cycle(mut self, phases: &str) -> Self {
+        self.day_cycle = Some(phases.to_string());
+        self
+    }
+
+    fn cell(mut self, q: i32, r: i32, terrain: &str, unit: &str, item: &str) -> Self {
+        self.hexes.push(format!(
+            r#"{{"HexCoord": {{"q": {q}, "r": {r}}}, "Terrain": "{terrain}", "Unit": {unit}, "Item": {item}, "Structure": null}}"#
+        ));
+        self
+    }
+
+    /// The map as scenario JSON.
+    pub fn to_json(&self) -> String {
+        let day_cycle = self
+            .day_cycle
+            .as_ref()
+            .map(|phases| format!(r#""DayCycle": {},"#, phases))
+            .unwrap_or_default();
+        format!(
+            "{{\n  {}\n  \"Map\": [\n    {}\n  ]\n}}",
+            day_cycle,
+            self.hexes.join(",\n    ")
+        )
+    }
+
+    /// Loads the map and starts the turn-based game.
+    pub fn world(&self) -> ScenarioWorld {
+        let mut world = ScenarioWorld::new(self.to_json());
+        world.start_turn_based_game();
+        world
+    }
+}
+
+/// Id of the unit standing on `hex`.
+///
+/// # Panics
+///
+/// Panics if the hex is empty.
+pub fn unit_at(world: &ScenarioWorld, hex: HexCoord) -> Uuid {
+    world
+        .units()
+        .values()
+        .find(|u| u.position() == hex)
+        .map(|u| u.id())
+        .expect("unit on hex")
+}
+
+pub struct Setup {
+    pub world: ScenarioWorld,
+    pub hero: Uuid,
+    pub ally: Uuid,
+    pub far_ally: Uuid,
+    pub enemy: Uuid,
+}
+
+/// Player dwarf at (0,0), an adjacent player ally at (0,1), a distant player
+/// ally at (-2,0), an adjacent enemy at (1,0) and empty hexes at (-1,0) and
+/// (2,0).
+pub fn setup() -> Setup {
+    let world = TestMap::new()
+        .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(0, 1, "grasslands", "Dwarf Warrior", "Player")
+        .unit(-2, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy")
+        .hex(-1, 0, "grasslands")
+        .hex(2, 0, "grasslands")
+        .world();
+
+    Setup {
+        hero: unit_at(&world, HexCoord::new(0, 0)),
+        ally: unit_at(&world, HexCoord::new(0, 1)),
+        far_ally: unit_at(&world, HexCoord::new(-2, 0)),
+        enemy: unit_at(&world, HexCoord::new(1, 0)),
+        world,
+    }
+}
+
+/// Puts a fresh copy of the item with registry id `item` into the inventory.
+pub fn give(world: &mut ScenarioWorld, unit_id: Uuid, item: &str) -> Uuid {
+    let item = ItemRegistry::builtin().create(item).unwrap();
+    let item_id = item.id;
+    world
+        .get_unit_mut(unit_id)
+        .unwrap()
+        .unit_mut()
+        .add_item_to_inventory(item);
+    item_id
+}
@@ -0,0 +1,254 @@
+/// Tests for using consumables and timed status effects in ScenarioWorld
+use ai::TacticalState;
+use game::scenario_instance::ScenarioWorld;
+use game::{GameCommand, GameEvent, HexCoord, PlayerCommand, Team};
+use uuid::Uuid;
+
+mod common;
+
+use common::{give, setup, Setup};
+
+fn health(world: &ScenarioWorld, unit_id: Uuid) -> i32 {
+    world
+        .get_unit(unit_id)
+        .unwrap()
+        .unit()
+        .combat_stats()
+        .health
+}
+
+fn wound(world: &mut ScenarioWorld, unit_id: Uuid, damage: u32) {
+    world
+        .get_unit_mut(unit_id)
+        .unwrap()
+        .unit_mut()
+        .take_damage(damage);
+}
+
+fn inventory_len(world: &ScenarioWorld, unit_id: Uuid) -> usize {
+    world.get_unit(unit_id).unwrap().unit().inventory().len()
+}
+
+/// Ends turns until the player's next turn begins.
+fn next_player_turn(world: &mut ScenarioWorld) {
+    world.end_current_turn();
+    while world.current_turn_team() != Team::PLAYER {
+        world.end_current_turn();
+    }
+}
+
+#[test]
+fn test_potion_heals_and_is_removed_when_used_up() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let potion = give(&mut world, hero, "health_potion");
+    wound(&mut world, hero, 15);
+    let before = health(&world, hero);
+
+    let message = world.use_item(hero, potion, hero).unwrap();
+
+    assert_eq!(health(&world, hero), before + 10);
+    assert!(message.contains("restored 10 HP"), "{}", message);
+    assert_eq!(inventory_len(&world, hero), 0);
+    assert!(
+        world
+            .get_unit(hero)
+            .unwrap()
+            .unit()
+            .combat_stats()
+            .attacked_this_turn
+    );
+}
+
+#[test]
+fn test_using_an_item_emits_events() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let potion = give(&mut world, hero, "health_potion");
+    wound(&mut world, hero, 15);
+    world.drain_events();
+
+    world.use_item(hero, potion, hero).unwrap();
+    let events = world.drain_events();
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::ItemUsed {
+                unit_id: hero,
+                team: Team::PLAYER,
+                item_name: "Health Potion".to_string(),
+                target_id: hero,
+            },
+            GameEvent::UnitHealed {
+                unit_id: hero,
+                position: HexCoord::new(0, 0),
+                amount: 10,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_multi_use_item_keeps_remaining_uses() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let draught = give(&mut world, hero, "restoration_draught");
+    wound(&mut world, hero, 20);
+
+    world.use_item(hero, draught, hero).unwrap();
+
+    let inventory = world.get_unit(hero).unwrap().unit().inventory();
+    assert_eq!(inventory.len(), 1);
+    assert_eq!(inventory[0].remaining_uses(), Some(1));
+}
+
+#[test]
+fn test_one_item_use_per_turn() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let first = give(&mut world, hero, "health_potion");
+    let second = give(&mut world, hero, "health_potion");
+    wound(&mut world, hero, 25);
+
+    world.use_item(hero, first, hero).unwrap();
+    assert!(world.use_item(hero, second, hero).is_err());
+
+    next_player_turn(&mut world);
+    assert!(world.use_item(hero, second, hero).is_ok());
+}
+
+#[test]
+fn test_item_targets_are_self_and_adjacent_allies() {
+    let Setup {
+        mut world,
+        hero,
+        ally,
+        far_ally,
+        enemy,
+    } = setup();
+    assert_eq!(world.item_targets(hero), vec![hero, ally]);
+
+    let potion = give(&mut world, hero, "health_potion");
+    for target in [ally, far_ally, enemy] {
+        wound(&mut world, target, 10);
+    }
+
+    assert!(world.use_item(hero, potion, far_ally).is_err());
+    assert!(world.use_item(hero, potion, enemy).is_err());
+    let before = health(&world, ally);
+    world.use_item(hero, potion, ally).unwrap();
+    assert_eq!(health(&world, ally), before + 10);
+}
+
+#[test]
+fn test_healing_requires_missing_health_and_consumable() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let potion = give(&mut world, hero, "health_potion");
+    let sword = give(&mut world, hero, "iron_sword");
+
+    assert!(world.use_item(hero, potion, hero).is_err());
+    wound(&mut world, hero, 5);
+    assert!(world.use_item(hero, sword, hero).is_err());
+    assert_eq!(inventory_len(&world, hero), 2);
+}
+
+#[test]
+fn test_buff_raises_damage_until_it_expires() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let elixir = give(&mut world, hero, "elixir_of_strength");
+    let base = world.strike_damage(hero, 10);
+
+    world.use_item(hero, elixir, hero).unwrap();
+    assert_eq!(world.attack_buff(hero), 2);
+    assert_eq!(world.strike_damage(hero, 10), base + 2);
+    assert_eq!(
+        world.status_effect_summary(hero),
+        vec!["Attack +2 (3 turns)".to_string()]
+    );
+
+    // Lasts through the turn it was used in and the next two player turns
+    next_player_turn(&mut world);
+    next_player_turn(&mut world);
+    assert_eq!(world.attack_buff(hero), 2);
+    next_player_turn(&mut world);
+    assert_eq!(world.attack_buff(hero), 0);
+    assert!(world.status_effect_summary(hero).is_empty());
+}
+
+#[test]
+fn test_ai_drinks_potion_when_low_on_health() {
+    let Setup {
+        mut world, enemy, ..
+    } = setup();
+    give(&mut world, enemy, "health_potion");
+    let max_health = world
+        .get_unit(enemy)
+        .unwrap()
+        .unit()
+        .combat_stats()
+        .max_health;
+    wound(&mut world, enemy, (max_health - 5) as u32);
+    assert!(world.is_low_health(enemy));
+
+    world.end_current_turn();
+    assert_eq!(world.current_turn_team(), Team::ENEMY);
+    world.run_ai_for_current_team();
+
+    assert_eq!(health(&world, enemy), 15);
+    assert_eq!(inventory_len(&world, enemy), 0);
+}
+
+#[test]
+fn test_tactical_search_offers_item_use_only_when_low() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let potion = give(&mut world, hero, "health_potion");
+    let use_potion = GameCommand::UseItem {
+        unit_id: hero,
+        item_id: potion,
+    };
+    assert!(!world.legal_commands().contains(&use_potion));
+
+    let max_health = health(&world, hero);
+    wound(&mut world, hero, (max_health - 5) as u32);
+    assert_eq!(world.legal_commands().first(), Some(&use_potion));
+
+    let (_, next) = &world.apply(&use_potion)[0];
+    assert_eq!(health(next, hero), 15);
+}
+
+#[test]
+fn test_use_item_command_addresses_inventory_slot() {
+    let Setup {
+        mut world,
+        hero,
+        ally,
+        ..
+    } = setup();
+    give(&mut world, hero, "iron_sword");
+    give(&mut world, hero, "health_potion");
+    wound(&mut world, ally, 10);
+    let before = health(&world, ally);
+    let hash = world.state_hash();
+
+    let command = PlayerCommand::UseItem {
+        unit: HexCoord::new(0, 0),
+        slot: 1,
+        target: HexCoord::new(0, 1),
+    };
+    assert!(world.apply_command(Team::ENEMY, &command).is_err());
+    world.apply_command(Team::PLAYER, &command).unwrap();
+
+    assert_eq!(health(&world, ally), before + 10);
+    assert_eq!(inventory_len(&world, hero), 1);
+    assert_ne!(world.state_hash(), hash);
+}
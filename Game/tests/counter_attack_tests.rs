@@ -0,0 +1,100 @@
+/// Tests for attack reach and counter-attack rules by range type
+use game::scenario_instance::ScenarioWorld;
+use game::HexCoord;
+use items::ItemRegistry;
+use units::combat::RangeType;
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// Player unit at (0,0) on grasslands and an enemy `enemy_q` hexes east of it.
+fn world_with(player_unit: &str, enemy_unit: &str, enemy_q: i32) -> (ScenarioWorld, Uuid, Uuid) {
+    let world = TestMap::new()
+        .unit(0, 0, "grasslands", player_unit, "Player")
+        .unit(enemy_q, 0, "grasslands", enemy_unit, "Enemy")
+        .world();
+    let hero = unit_at(&world, HexCoord::new(0, 0));
+    let enemy = unit_at(&world, HexCoord::new(enemy_q, 0));
+    (world, hero, enemy)
+}
+
+#[test]
+fn test_ranged_attack_is_answered_by_ranged_attack() {
+    let (world, hero, enemy) = world_with("Elf Archer", "Elf Archer", 3);
+
+    // Elven Longbow (range 4) draws the defender's own longbow
+    let (_, counter) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+    assert!(counter.is_some());
+}
+
+#[test]
+fn test_melee_cannot_answer_ranged_attack() {
+    let (world, hero, enemy) = world_with("Elf Archer", "Orc Young Swordsman", 1);
+
+    let (_, counter) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+    assert!(counter.is_none(), "melee does not answer a bow shot");
+
+    // The archer's dagger is melee and is answered in kind
+    let (_, counter) = world.combat_hit_chances(hero, enemy, 2).unwrap();
+    assert!(counter.is_some());
+}
+
+#[test]
+fn test_combat_request_marks_reach_and_counters() {
+    let (mut world, hero, enemy) = world_with("Orc Young Swordsman", "Elf Archer", 1);
+    world.request_combat(hero, enemy).unwrap();
+    let pending = world.pending_combat.as_ref().unwrap();
+
+    // The archer answers a sword with its dagger, not its bows
+    let counter = pending.attacker_attacks[0].counter_index.unwrap();
+    assert_eq!(pending.defender_attacks[counter].name, "Dagger");
+    assert_eq!(
+        pending.defender_attacks[counter].range_type,
+        RangeType::Melee
+    );
+    assert!(pending
+        .attacker_attacks
+        .iter()
+        .all(|attack| attack.in_reach));
+
+    // From three hexes away only the archer's bows reach
+    let (mut world, hero, enemy) = world_with("Elf Archer", "Orc Young Swordsman", 3);
+    world.request_combat(hero, enemy).unwrap();
+    let pending = world.pending_combat.as_ref().unwrap();
+    let reach: Vec<bool> = pending
+        .attacker_attacks
+        .iter()
+        .map(|attack| attack.in_reach)
+        .collect();
+    assert_eq!(reach, vec![true, true, false]);
+    assert!(pending
+        .attacker_attacks
+        .iter()
+        .all(|attack| attack.counter_index.is_none()));
+}
+
+#[test]
+fn test_equipped_crossbow_attacks_are_ranged() {
+    let (mut world, hero, enemy) = world_with("Dwarf Warrior", "Orc Young Swordsman", 2);
+    let crossbow = ItemRegistry::builtin().create("crossbow").unwrap();
+    let crossbow_id = crossbow.id;
+    let unit = world.get_unit_mut(hero).unwrap().unit_mut();
+    unit.add_item_to_inventory(crossbow);
+    unit.equip_item(crossbow_id).unwrap();
+
+    let attacks = world.get_unit(hero).unwrap().unit().get_attacks();
+    let bolt = attacks.iter().position(|a| a.name == "Bolt").unwrap();
+    assert_eq!(attacks[bolt].range_type, RangeType::Ranged);
+    assert_eq!(attacks[bolt].range, RangeType::Ranged.base_range());
+
+    // The crossbow reaches two hexes, and the orc cannot shoot back
+    let (_, counter) = world.combat_hit_chances(hero, enemy, bolt).unwrap();
+    assert!(counter.is_none());
+
+    // A melee attack out of reach is refused
+    world.request_combat(hero, enemy).unwrap();
+    world.pending_combat.as_mut().unwrap().selected_attack_index = 0;
+    assert!(world.execute_pending_combat().is_err());
+}
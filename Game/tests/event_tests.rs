@@ -0,0 +1,172 @@
+/// Tests for the game events a scenario world emits
+use ai::TacticalState;
+use game::scenario_instance::ScenarioWorld;
+use game::{GameCommand, GameEvent, GameObject, HexCoord, Team};
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// A dwarf at (0,0) next to an orc at (1,0), with a potion at (0,1).
+fn world() -> (ScenarioWorld, Uuid, Uuid) {
+    let mut world = TestMap::new()
+        .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy")
+        .item(0, 1, "grasslands", "health_potion")
+        .hex(-1, 0, "grasslands")
+        .world();
+    world.seed_rng(7);
+    let dwarf = unit_at(&world, HexCoord::new(0, 0));
+    let orc = unit_at(&world, HexCoord::new(1, 0));
+    (world, dwarf, orc)
+}
+
+#[test]
+fn test_game_start_emits_turn_started() {
+    let (world, _, _) = world();
+    let events = world.drain_events();
+    assert!(matches!(
+        events.as_slice(),
+        [GameEvent::TurnStarted { turn: 0, .. }]
+    ));
+    assert!(
+        world.drain_events().is_empty(),
+        "draining empties the queue"
+    );
+}
+
+#[test]
+fn test_move_emits_unit_moved() {
+    let (mut world, dwarf, _) = world();
+    world.drain_events();
+
+    world.move_unit(dwarf, HexCoord::new(-1, 0)).unwrap();
+    assert_eq!(
+        world.drain_events(),
+        vec![GameEvent::UnitMoved {
+            unit_id: dwarf,
+            from: HexCoord::new(0, 0),
+            to: HexCoord::new(-1, 0),
+        }]
+    );
+}
+
+#[test]
+fn test_combat_emits_every_strike_and_the_death() {
+    let (mut world, dwarf, orc) = world();
+    let stats = world
+        .get_unit_mut(dwarf)
+        .unwrap()
+        .unit_mut()
+        .combat_stats_mut();
+    stats.attacks_per_round = 50;
+    world
+        .get_unit_mut(orc)
+        .unwrap()
+        .unit_mut()
+        .combat_stats_mut()
+        .health = 1;
+    world.drain_events();
+
+    world.request_combat(dwarf, orc).unwrap();
+    world.execute_pending_combat().unwrap();
+    let events = world.drain_events();
+
+    let strikes: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::StrikeResolved {
+                striker_id,
+                striker_team,
+                hit,
+                damage,
+                ..
+            } => Some((*striker_id, *striker_team, *hit, *damage)),
+            _ => None,
+        })
+        .collect();
+    assert!(!strikes.is_empty());
+    let (_, _, last_hit, last_damage) = *strikes.last().unwrap();
+    assert!(last_hit && last_damage > 0, "the exchange ends on the kill");
+    for (striker, team, hit, damage) in &strikes {
+        assert!(*striker == dwarf && *team == Team::PLAYER || *striker == orc);
+        assert_eq!(*hit, *damage > 0);
+    }
+
+    assert!(events.contains(&GameEvent::UnitDied {
+        unit_id: orc,
+        name: "Orc Young Swordsman".to_string(),
+        team: Team::ENEMY,
+        position: HexCoord::new(1, 0),
+        killer_id: Some(dwarf),
+    }));
+}
+
+#[test]
+fn test_end_turn_and_pickup_emit_events() {
+    let (mut world, dwarf, _) = world();
+    let (object_id, _) = world
+        .interactive_objects()
+        .iter()
+        .next()
+        .map(|(id, object)| (*id, object.position()))
+        .unwrap();
+    world.drain_events();
+
+    let name = world.pickup_item(dwarf, object_id).unwrap();
+    world.end_current_turn();
+    let events = world.drain_events();
+    assert_eq!(
+        events[0],
+        GameEvent::ItemPickedUp {
+            unit_id: dwarf,
+            team: Team::PLAYER,
+            item_name: name,
+            position: HexCoord::new(0, 0),
+        }
+    );
+    assert!(matches!(
+        events[1],
+        GameEvent::TurnStarted {
+            team: Team::ENEMY,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_cloned_worlds_keep_their_events() {
+    let (world, dwarf, orc) = world();
+    world.drain_events();
+
+    let outcomes = world.apply(&GameCommand::Attack {
+        attacker_id: dwarf,
+        defender_id: orc,
+        attack_index: 0,
+    });
+    assert!(!outcomes.is_empty());
+    assert!(
+        world.drain_events().is_empty(),
+        "simulated combat does not reach the real world's queue"
+    );
+}
+
+#[test]
+fn test_recruiting_emits_unit_recruited() {
+    let (mut world, _, _) = world();
+    world.drain_events();
+
+    let recruit = world
+        .recruit_unit(Team::PLAYER, "Dwarf Warrior", HexCoord::new(-1, 0))
+        .unwrap();
+    assert_eq!(
+        world.drain_events(),
+        vec![GameEvent::UnitRecruited {
+            unit_id: recruit,
+            name: world.get_unit(recruit).unwrap().name(),
+            team: Team::PLAYER,
+            position: HexCoord::new(-1, 0),
+        }]
+    );
+}
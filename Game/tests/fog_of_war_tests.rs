@@ -0,0 +1,128 @@
+/// Tests for per-team fog of war and hot-seat team control in ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, Team};
+use uuid::Uuid;
+
+/// A single row of grassland from q = -10 to q = 10 with a forest at (2, 0).
+/// North stands at (0, 0), its ally South at (-10, 0) and East at (8, 0).
+/// Bystanders are at peace with everyone.
+fn corridor_map() -> String {
+    let mut cells = Vec::new();
+    for q in -10..=10 {
+        let terrain = if q == 2 { "forest" } else { "grasslands" };
+        let unit = match q {
+            0 => r#"["Dwarf Warrior", "North"]"#,
+            -10 => r#"["Dwarf Warrior", "South"]"#,
+            8 => r#"["Orc Young Swordsman", "East"]"#,
+            10 => r#"["Dwarf Warrior", "Bystanders"]"#,
+            _ => "null",
+        };
+        cells.push(format!(
+            r#"{{"HexCoord": {{"q": {q}, "r": 0}}, "Terrain": "{terrain}", "Unit": {unit}, "Item": null, "Structure": null}}"#
+        ));
+    }
+    format!(
+        r#"{{
+  "Teams": [
+    {{"Name": "North", "IsPlayerControlled": true, "Goal": "Hold"}},
+    {{"Name": "South", "IsPlayerControlled": false, "Goal": "Hold"}},
+    {{"Name": "East", "IsPlayerControlled": false, "Goal": "Take"}},
+    {{"Name": "Bystanders", "IsPlayerControlled": false, "Goal": "Watch"}}
+  ],
+  "Diplomacy": [
+    {{"Teams": ["North", "South"], "Stance": "Allied"}},
+    {{"Teams": ["Bystanders", "North"], "Stance": "Neutral"}},
+    {{"Teams": ["Bystanders", "South"], "Stance": "Neutral"}},
+    {{"Teams": ["Bystanders", "East"], "Stance": "Neutral"}}
+  ],
+  "Map": [{}]
+}}"#,
+        cells.join(",\n")
+    )
+}
+
+fn unit_at(world: &ScenarioWorld, q: i32) -> Uuid {
+    world
+        .units()
+        .values()
+        .find(|u| u.position() == HexCoord::new(q, 0))
+        .map(|u| u.id())
+        .expect("unit on hex")
+}
+
+fn team(world: &ScenarioWorld, name: &str) -> Team {
+    world.teams.find(name).expect("team in roster")
+}
+
+#[test]
+fn test_units_see_one_hex_beyond_their_movement() {
+    let world = ScenarioWorld::new(corridor_map());
+    let north = team(&world, "North");
+    let range = world.unit_vision_range(unit_at(&world, 0)).unwrap() as i32;
+    assert!(
+        (3..8).contains(&range),
+        "corridor assumes a mid-range dwarf"
+    );
+
+    assert!(world.is_visible_to(north, HexCoord::new(-range, 0)));
+    assert!(!world.is_visible_to(north, HexCoord::new(-range - 1, 0)));
+}
+
+#[test]
+fn test_forest_hides_the_hexes_behind_it() {
+    let world = ScenarioWorld::new(corridor_map());
+    let north = team(&world, "North");
+
+    assert!(world.is_visible_to(north, HexCoord::new(1, 0)));
+    assert!(world.is_visible_to(north, HexCoord::new(2, 0)));
+    assert!(!world.is_visible_to(north, HexCoord::new(3, 0)));
+}
+
+#[test]
+fn test_allies_share_vision_but_enemies_do_not() {
+    let world = ScenarioWorld::new(corridor_map());
+    let north = world.visible_hexes(team(&world, "North"));
+    let east = world.visible_hexes(team(&world, "East"));
+
+    // South's surroundings are visible to North
+    assert!(north.contains(&HexCoord::new(-10, 0)));
+    assert!(!north.contains(&HexCoord::new(8, 0)));
+    assert!(east.contains(&HexCoord::new(8, 0)));
+    assert!(!east.contains(&HexCoord::new(0, 0)));
+    // Nothing off the map is ever visible
+    assert!(north.iter().all(|hex| world.terrain.contains_key(hex)));
+}
+
+#[test]
+fn test_hot_seat_gives_every_fighting_team_to_a_human() {
+    let mut world = ScenarioWorld::new(corridor_map());
+    let humans = world.enable_hot_seat();
+
+    let names: Vec<&str> = humans.iter().map(|t| world.teams.name(*t)).collect();
+    assert_eq!(names, ["North", "South", "East"]);
+
+    world.start_turn_based_game();
+    for expected in [true, true, true, false] {
+        assert_eq!(world.is_current_team_player_controlled(), expected);
+        world.end_current_turn();
+    }
+    let bystanders = team(&world, "Bystanders");
+    assert!(!world.teams.get(bystanders).unwrap().player_controlled);
+}
+
+#[test]
+fn test_cached_views_follow_moves_and_deaths() {
+    let mut world = ScenarioWorld::new(corridor_map());
+    world.start_turn_based_game();
+    let north = team(&world, "North");
+    let dwarf = unit_at(&world, 0);
+    let range = world.unit_vision_range(dwarf).unwrap() as i32;
+    assert!(!world.is_visible_to(north, HexCoord::new(-range - 1, 0)));
+    assert!(world.is_visible_to(north, HexCoord::new(-10, 0)));
+
+    world.move_unit(dwarf, HexCoord::new(-1, 0)).unwrap();
+    assert!(world.is_visible_to(north, HexCoord::new(-range - 1, 0)));
+
+    world.remove_unit(unit_at(&world, -10));
+    assert!(!world.is_visible_to(north, HexCoord::new(-10, 0)));
+}
@@ -0,0 +1,117 @@
+/// Tests for combat forecasts in the scenario world
+use ai::TacticalState;
+use game::scenario_instance::ScenarioWorld;
+use game::{GameCommand, HexCoord};
+use units::ability::{ActiveEffect, ActiveEffectInstance, BuffStat};
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// A dwarf at (0,0) next to an orc at (1,0), both on grasslands.
+fn world() -> (ScenarioWorld, Uuid, Uuid) {
+    let mut world = TestMap::new()
+        .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy")
+        .world();
+    world.seed_rng(7);
+    let dwarf = unit_at(&world, HexCoord::new(0, 0));
+    let orc = unit_at(&world, HexCoord::new(1, 0));
+    (world, dwarf, orc)
+}
+
+#[test]
+fn test_forecast_counts_attacks_per_round() {
+    let (mut world, dwarf, orc) = world();
+    let single = world.combat_forecast(dwarf, orc, 0).unwrap();
+    let max_hits = |f: &game::CombatForecast| f.outcomes.iter().map(|o| o.attacker_hits).max();
+    assert_eq!(max_hits(&single), Some(1));
+
+    world
+        .get_unit_mut(dwarf)
+        .unwrap()
+        .unit_mut()
+        .combat_stats_mut()
+        .attacks_per_round = 2;
+    let double = world.combat_forecast(dwarf, orc, 0).unwrap();
+    assert_eq!(max_hits(&double), Some(2));
+    assert!(double.expected_damage_dealt() > single.expected_damage_dealt());
+
+    let total: f32 = double.outcomes.iter().map(|o| o.probability).sum();
+    assert!((total - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_combat_request_carries_forecasts() {
+    let (mut world, dwarf, orc) = world();
+    world.request_combat(dwarf, orc).unwrap();
+    let pending = world.pending_combat.as_ref().unwrap();
+
+    for (index, attack) in pending.attacker_attacks.iter().enumerate() {
+        assert_eq!(
+            attack.forecast.is_some(),
+            attack.in_reach,
+            "only attacks in reach are forecast"
+        );
+        if let Some(forecast) = &attack.forecast {
+            assert_eq!(forecast, &world.combat_forecast(dwarf, orc, index).unwrap());
+        }
+    }
+    assert!(pending
+        .defender_attacks
+        .iter()
+        .all(|attack| attack.forecast.is_none()));
+}
+
+#[test]
+fn test_tactical_outcomes_follow_forecast() {
+    let (world, dwarf, orc) = world();
+    let forecast = world.combat_forecast(dwarf, orc, 0).unwrap();
+    let outcomes = world.apply(&GameCommand::Attack {
+        attacker_id: dwarf,
+        defender_id: orc,
+        attack_index: 0,
+    });
+    assert_eq!(outcomes.len(), forecast.outcomes.len());
+
+    let health = |w: &ScenarioWorld, id: Uuid| {
+        w.get_unit(id)
+            .map_or(0, |u| u.unit().combat_stats().health.max(0) as u32)
+    };
+    for ((probability, next), expected) in outcomes.iter().zip(&forecast.outcomes) {
+        assert_eq!(*probability, expected.probability);
+        assert_eq!(health(next, orc), expected.defender_hp);
+        assert_eq!(health(next, dwarf), expected.attacker_hp);
+    }
+}
+
+#[test]
+fn test_planned_forecast_uses_real_hit_chances() {
+    let (mut world, dwarf, orc) = world();
+    // A dodge buff the old planner estimate did not see
+    world
+        .get_unit_mut(orc)
+        .unwrap()
+        .unit_mut()
+        .ability_state_mut()
+        .add_effect(
+            Uuid::new_v4(),
+            ActiveEffectInstance::new(
+                ActiveEffect::Buff {
+                    stat: BuffStat::DodgeChance,
+                    amount: 20,
+                    duration: 2,
+                },
+                2,
+                None,
+            ),
+        );
+    let attack = world.get_unit(dwarf).unwrap().unit().get_attacks()[0].clone();
+
+    let planned = world
+        .planned_forecast(dwarf, orc, &attack, HexCoord::new(0, 0))
+        .unwrap();
+    let real = world.combat_forecast(dwarf, orc, 0).unwrap();
+    assert_eq!(planned.outcomes, real.outcomes);
+}
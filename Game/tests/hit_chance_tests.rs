@@ -0,0 +1,161 @@
+/// Tests for per-strike hit chances in ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::HexCoord;
+use units::ability::{ActiveEffect, ActiveEffectInstance, BuffStat};
+use units::combat::MAGIC_HIT_CHANCE;
+use units::structures::{Structure, StructureFactory};
+use units::Terrain;
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// Player unit at (0,0) on grasslands, enemy at (1,0) on hills and an empty
+/// hills hex at (0,1).
+fn world_with(player_unit: &str) -> (ScenarioWorld, Uuid, Uuid) {
+    let world = TestMap::new()
+        .unit(0, 0, "grasslands", player_unit, "Player")
+        .unit(1, 0, "hills", "Orc Young Swordsman", "Enemy")
+        .hex(0, 1, "hills")
+        .world();
+    let hero = unit_at(&world, HexCoord::new(0, 0));
+    let enemy = unit_at(&world, HexCoord::new(1, 0));
+    (world, hero, enemy)
+}
+
+fn terrain_chance(world: &ScenarioWorld, unit_id: Uuid, terrain: Terrain) -> u8 {
+    world
+        .get_unit(unit_id)
+        .unwrap()
+        .unit()
+        .get_terrain_hit_chance(terrain)
+}
+
+#[test]
+fn test_strikes_use_each_defenders_current_terrain() {
+    let (world, hero, enemy) = world_with("Dwarf Warrior");
+
+    let (strike, counter) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+    let counter = counter.expect("melee attack is countered");
+
+    assert_eq!(strike.terrain, "Hills");
+    assert_eq!(strike.chance, terrain_chance(&world, enemy, Terrain::Hills));
+    assert_eq!(counter.terrain, "Grasslands");
+    assert_eq!(
+        counter.chance,
+        terrain_chance(&world, hero, Terrain::Grasslands)
+    );
+}
+
+#[test]
+fn test_cached_terrain_hit_chance_follows_movement() {
+    let (mut world, hero, _) = world_with("Dwarf Warrior");
+    let cached = |world: &ScenarioWorld| {
+        world
+            .get_unit(hero)
+            .unwrap()
+            .unit()
+            .combat_stats()
+            .terrain_hit_chance
+    };
+    assert_eq!(
+        cached(&world),
+        terrain_chance(&world, hero, Terrain::Grasslands)
+    );
+
+    world.move_unit(hero, HexCoord::new(0, 1)).unwrap();
+
+    assert_eq!(cached(&world), terrain_chance(&world, hero, Terrain::Hills));
+}
+
+#[test]
+fn test_structure_on_defender_hex_lowers_hit_chance() {
+    let (mut world, hero, enemy) = world_with("Dwarf Warrior");
+    let house = StructureFactory::create_house(HexCoord::new(1, 0), units::Team::ENEMY);
+    let bonus = house.defense_bonus();
+    world.add_structure(house);
+
+    let (strike, _) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+
+    assert_eq!(
+        strike.chance as i32,
+        terrain_chance(&world, enemy, Terrain::Hills) as i32 - bonus
+    );
+    assert_eq!(strike.modifiers.len(), 1);
+    assert_eq!(strike.modifiers[0].amount, -bonus);
+}
+
+#[test]
+fn test_defense_buffs_and_debuffs_adjust_hit_chance() {
+    let (mut world, hero, enemy) = world_with("Dwarf Warrior");
+    let base = terrain_chance(&world, enemy, Terrain::Hills) as i32;
+    let effects = world
+        .get_unit_mut(enemy)
+        .unwrap()
+        .unit_mut()
+        .ability_state_mut();
+    effects.add_effect(
+        Uuid::new_v4(),
+        ActiveEffectInstance::new(
+            ActiveEffect::Buff {
+                stat: BuffStat::Defense,
+                amount: 20,
+                duration: 2,
+            },
+            2,
+            None,
+        ),
+    );
+    effects.add_effect(
+        Uuid::new_v4(),
+        ActiveEffectInstance::new(
+            ActiveEffect::Debuff {
+                stat: BuffStat::DodgeChance,
+                amount: 5,
+                duration: 2,
+            },
+            2,
+            None,
+        ),
+    );
+
+    let (strike, _) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+
+    assert_eq!(strike.chance as i32, (base - 20 + 5).clamp(0, 100));
+    assert_eq!(strike.modifiers.len(), 2);
+    assert_eq!(
+        strike.lines().last().unwrap(),
+        &format!("Hit chance: {}%", strike.chance)
+    );
+}
+
+#[test]
+fn test_magic_attacks_always_use_magic_hit_chance() {
+    let (mut world, hero, enemy) = world_with("Elf Mage");
+    world.add_structure(StructureFactory::create_stone_wall(
+        HexCoord::new(1, 0),
+        units::Team::ENEMY,
+    ));
+
+    let (strike, counter) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+
+    assert!(strike.magical);
+    assert_eq!(strike.chance, MAGIC_HIT_CHANCE);
+    assert!(counter.is_none(), "ranged attacks are not countered");
+}
+
+#[test]
+fn test_combat_request_carries_hit_chance_breakdown() {
+    let (mut world, hero, enemy) = world_with("Dwarf Warrior");
+
+    world.move_unit(hero, HexCoord::new(1, 0)).unwrap();
+
+    let pending = world.pending_combat.as_ref().expect("combat requested");
+    let (strike, counter) = world.combat_hit_chances(hero, enemy, 0).unwrap();
+    assert_eq!(pending.attacker_attacks[0].hit_chance, strike);
+    assert_eq!(
+        Some(&pending.defender_attacks[0].hit_chance),
+        counter.as_ref()
+    );
+}
@@ -0,0 +1,212 @@
+/// Tests for equipping, dropping and giving carried items in ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::{GameEvent, GameObject, HexCoord, PlayerCommand, Team};
+use uuid::Uuid;
+
+mod common;
+
+use common::{give, setup, Setup};
+
+fn carried_names(world: &ScenarioWorld, unit_id: Uuid) -> Vec<(String, bool)> {
+    world
+        .carried_items(unit_id)
+        .into_iter()
+        .map(|(item, equipped)| (item.name.clone(), equipped))
+        .collect()
+}
+
+#[test]
+fn test_equip_and_unequip_change_stats() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let boots = give(&mut world, hero, "boots_of_speed");
+    let movement = |world: &ScenarioWorld| {
+        world
+            .get_unit(hero)
+            .unwrap()
+            .unit()
+            .combat_stats()
+            .movement_speed
+    };
+    let before = movement(&world);
+
+    assert_eq!(world.item_delta(hero, boots).unwrap().movement, 1);
+    let message = world.equip_item(hero, boots).unwrap();
+    assert!(message.contains("Movement +1"), "{}", message);
+    assert_eq!(movement(&world), before + 1);
+    assert_eq!(
+        carried_names(&world, hero),
+        vec![("Boots of Speed".to_string(), true)]
+    );
+
+    assert_eq!(world.item_delta(hero, boots).unwrap().movement, -1);
+    world.unequip_item(hero, boots).unwrap();
+    assert_eq!(movement(&world), before);
+    assert!(world.unequip_item(hero, boots).is_err());
+}
+
+#[test]
+fn test_consumables_cannot_be_equipped() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let potion = give(&mut world, hero, "health_potion");
+
+    assert!(world.item_delta(hero, potion).is_none());
+    assert!(world.equip_item(hero, potion).is_err());
+}
+
+#[test]
+fn test_drop_leaves_item_on_the_ground() {
+    let Setup {
+        mut world, hero, ..
+    } = setup();
+    let sword = give(&mut world, hero, "iron_sword");
+    let ring = give(&mut world, hero, "ring_of_vigor");
+    world.equip_item(hero, sword).unwrap();
+
+    let object_id = world.drop_item(hero, sword).unwrap();
+    let object = world.get_interactive_object(object_id).unwrap();
+    assert_eq!(object.position(), HexCoord::new(0, 0));
+    assert_eq!(object.item().unwrap().name, "Iron Sword");
+    assert_eq!(
+        carried_names(&world, hero),
+        vec![("Ring of Vigor".to_string(), false)]
+    );
+
+    // Only one item fits on a hex; the dropped one can be picked up again
+    assert!(world.drop_item(hero, ring).is_err());
+    world.pickup_item(hero, object_id).unwrap();
+    assert!(world.interactive_objects().is_empty());
+}
+
+#[test]
+fn test_give_to_adjacent_ally_uses_the_turn() {
+    let Setup {
+        mut world,
+        hero,
+        ally,
+        far_ally,
+        enemy,
+    } = setup();
+    let sword = give(&mut world, hero, "iron_sword");
+    let potion = give(&mut world, hero, "health_potion");
+    world.equip_item(hero, sword).unwrap();
+
+    assert!(world.give_item(hero, sword, far_ally).is_err());
+    assert!(world.give_item(hero, sword, enemy).is_err());
+    assert!(world.give_item(hero, sword, hero).is_err());
+
+    world.give_item(hero, sword, ally).unwrap();
+    assert!(world
+        .get_unit(hero)
+        .unwrap()
+        .unit()
+        .equipment()
+        .weapon
+        .is_none());
+    assert_eq!(
+        carried_names(&world, ally),
+        vec![("Iron Sword".to_string(), false)]
+    );
+
+    // Giving takes the giver's action for the turn
+    assert!(world.give_item(hero, potion, ally).is_err());
+}
+
+#[test]
+fn test_inventory_changes_emit_events() {
+    let Setup {
+        mut world,
+        hero,
+        ally,
+        ..
+    } = setup();
+    let sword = give(&mut world, hero, "iron_sword");
+    let ring = give(&mut world, hero, "ring_of_vigor");
+    world.drain_events();
+
+    world.equip_item(hero, sword).unwrap();
+    world.unequip_item(hero, sword).unwrap();
+    world.drop_item(hero, ring).unwrap();
+    world.give_item(hero, sword, ally).unwrap();
+
+    let item = |name: &str| name.to_string();
+    assert_eq!(
+        world.drain_events(),
+        vec![
+            GameEvent::ItemEquipped {
+                unit_id: hero,
+                team: Team::PLAYER,
+                item_name: item("Iron Sword"),
+                equipped: true,
+            },
+            GameEvent::ItemEquipped {
+                unit_id: hero,
+                team: Team::PLAYER,
+                item_name: item("Iron Sword"),
+                equipped: false,
+            },
+            GameEvent::ItemDropped {
+                unit_id: hero,
+                team: Team::PLAYER,
+                item_name: item("Ring of Vigor"),
+                position: HexCoord::new(0, 0),
+            },
+            GameEvent::ItemGiven {
+                giver_id: hero,
+                receiver_id: ally,
+                team: Team::PLAYER,
+                item_name: item("Iron Sword"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_inventory_commands_address_carried_slots() {
+    let Setup {
+        mut world,
+        hero,
+        ally,
+        ..
+    } = setup();
+    give(&mut world, hero, "health_potion");
+    give(&mut world, hero, "boots_of_speed");
+    let unit = HexCoord::new(0, 0);
+
+    let equip = PlayerCommand::Equip { unit, slot: 1 };
+    assert!(world.apply_command(Team::ENEMY, &equip).is_err());
+    world.apply_command(Team::PLAYER, &equip).unwrap();
+    assert_eq!(
+        carried_names(&world, hero),
+        vec![
+            ("Boots of Speed".to_string(), true),
+            ("Health Potion".to_string(), false)
+        ]
+    );
+
+    world
+        .apply_command(Team::PLAYER, &PlayerCommand::Unequip { unit, slot: 0 })
+        .unwrap();
+    let hash = world.state_hash();
+    world
+        .apply_command(
+            Team::PLAYER,
+            &PlayerCommand::Give {
+                unit,
+                slot: 1,
+                to: HexCoord::new(0, 1),
+            },
+        )
+        .unwrap();
+    assert_eq!(world.carried_items(ally).len(), 1);
+    assert_ne!(world.state_hash(), hash);
+
+    world
+        .apply_command(Team::PLAYER, &PlayerCommand::Drop { unit, slot: 0 })
+        .unwrap();
+    assert!(world.carried_items(hero).is_empty());
+    assert_eq!(world.interactive_objects().len(), 1);
+}
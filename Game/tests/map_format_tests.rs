@@ -1,5 +1,7 @@
 /// Tests for the new map JSON format with scenario info and team declarations
 use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, SpriteType};
+use units::{Terrain, TerrainFeature};
 
 #[test]
 fn test_new_format_parses_scenario_info() {
@@ -38,7 +40,7 @@ fn test_new_format_parses_scenario_info() {
     // Check teams
     assert_eq!(parsed.teams.len(), 1);
     assert_eq!(parsed.teams[0].name, "Player");
-    assert_eq!(parsed.teams[0].is_player_controlled, true);
+    assert!(parsed.teams[0].is_player_controlled);
     assert_eq!(parsed.teams[0].goal, "Win the battle");
 
     // Check map parsed correctly
@@ -149,3 +151,80 @@ fn test_new_format_without_scenario_info() {
     // But teams should still be there
     assert_eq!(parsed.teams.len(), 1);
 }
+
+#[test]
+fn test_terrain_field_and_features() {
+    let map_json = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "Terrain": "dense_forest", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "Terrain": "hills", "Features": ["Road"], "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 2, "r": 0}, "Terrain": "grasslands", "SpriteType": "Forest", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 3, "r": 0}, "SpriteType": "Forest2", "Unit": null, "Item": null, "Structure": null}
+  ]
+}"#;
+
+    let parsed = ScenarioWorld::parse_map_json(map_json).expect("map parses");
+    let tile = |q| &parsed.terrain[&HexCoord::new(q, 0)];
+
+    assert_eq!(tile(0).terrain().id, "dense_forest");
+    assert_eq!(tile(0).defense_class(), Terrain::Forest1);
+    assert_eq!(tile(0).sprite_type(), SpriteType::Forest2);
+
+    assert!(tile(1).has_feature(TerrainFeature::Road));
+    assert_eq!(tile(1).movement_cost(), 1);
+
+    // SpriteType next to Terrain only changes the look
+    assert_eq!(tile(2).terrain().id, "grasslands");
+    assert_eq!(tile(2).sprite_type(), SpriteType::Forest);
+    assert_eq!(tile(2).movement_cost(), 1);
+
+    // Legacy sprite-only cells keep Forest2 distinct from Forest
+    assert_eq!(tile(3).terrain().id, "dense_forest");
+    assert_eq!(tile(3).defense_class(), Terrain::Forest1);
+}
+
+#[test]
+fn test_items_reference_registry_ids() {
+    let map_json = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": "chain_mail", "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": {"definition": "health_potion"}, "Structure": null},
+    {"HexCoord": {"q": 2, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": {"definition": "Iron Sword"}, "Structure": null},
+    {"HexCoord": {"q": 3, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": {"definition": "no_such_item", "name": "Odd Rock"}, "Structure": null}
+  ]
+}"#;
+
+    let world = ScenarioWorld::new(map_json.to_string());
+    let item_at = |q| {
+        world
+            .interactive_objects
+            .values()
+            .find(|object| object.position() == HexCoord::new(q, 0))
+            .unwrap()
+    };
+
+    assert_eq!(item_at(0).item().unwrap().name, "Chain Mail");
+    assert_eq!(item_at(1).item().unwrap().name, "Health Potion");
+    // Older maps name items instead of using ids
+    assert_eq!(item_at(2).item().unwrap().name, "Iron Sword");
+    // Unknown ids still place a (non-pickup) object
+    assert!(item_at(3).item().is_none());
+    assert_eq!(item_at(3).name(), "Odd Rock");
+}
+
+#[test]
+fn test_terrain_and_unit_sprite_names_resolve() {
+    for terrain in units::TerrainRegistry::builtin().all() {
+        let sprite = SpriteType::from_name(&terrain.sprite)
+            .unwrap_or_else(|| panic!("unknown sprite {} for {}", terrain.sprite, terrain.id));
+        assert!(sprite.is_terrain());
+        assert_eq!(sprite.name(), terrain.sprite);
+    }
+
+    let dwarf =
+        units::UnitFactory::create("Dwarf Warrior", None, Some(HexCoord::new(0, 0))).unwrap();
+    assert_eq!(
+        SpriteType::from_name(dwarf.sprite()),
+        Some(SpriteType::DwarfWarrior)
+    );
+}
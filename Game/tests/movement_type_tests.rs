@@ -0,0 +1,70 @@
+/// Tests for movement-type-aware pathfinding in ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, Team};
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// A single player unit at (0,0) on grasslands, forest at (1,0) and (2,0),
+/// a mountain at (0,1) and grasslands beyond it at (0,2).
+fn world_with(player_unit: &str) -> (ScenarioWorld, Uuid) {
+    let world = TestMap::new()
+        .unit(0, 0, "grasslands", player_unit, "Player")
+        .hex(1, 0, "forest")
+        .hex(2, 0, "forest")
+        .hex(0, 1, "mountain")
+        .hex(0, 2, "grasslands")
+        .world();
+    let hero = unit_at(&world, HexCoord::new(0, 0));
+    (world, hero)
+}
+
+#[test]
+fn test_forest_cost_depends_on_movement_type() {
+    let (elf_world, elf) = world_with("Elf Warrior");
+    let (squire_world, squire) = world_with("Human Squire");
+    let (knight_world, knight) = world_with("Human Knight");
+
+    assert_eq!(elf_world.can_move_to(elf, HexCoord::new(1, 0)), Ok(1));
+    assert_eq!(elf_world.can_move_to(elf, HexCoord::new(2, 0)), Ok(2));
+    assert_eq!(squire_world.can_move_to(squire, HexCoord::new(1, 0)), Ok(2));
+    assert_eq!(knight_world.can_move_to(knight, HexCoord::new(1, 0)), Ok(3));
+}
+
+#[test]
+fn test_mountains_only_passable_for_mountain_folk() {
+    let (mut dwarf_world, dwarf) = world_with("Dwarf Warrior");
+    let (orc_world, orc) = world_with("Orc Young Swordsman");
+
+    assert_eq!(dwarf_world.can_move_to(dwarf, HexCoord::new(0, 1)), Ok(3));
+    assert!(orc_world.can_move_to(orc, HexCoord::new(0, 1)).is_err());
+    assert!(!orc_world
+        .all_legal_moves(orc)
+        .iter()
+        .any(|(hex, _)| *hex == HexCoord::new(0, 1)));
+
+    dwarf_world.move_unit(dwarf, HexCoord::new(0, 1)).unwrap();
+    assert_eq!(
+        dwarf_world.get_unit(dwarf).unwrap().position(),
+        HexCoord::new(0, 1)
+    );
+}
+
+#[test]
+fn test_ai_move_grounding_uses_unit_movement_type() {
+    let (dwarf_world, dwarf) = world_with("Dwarf Warrior");
+    let (orc_world, _) = world_with("Orc Young Swordsman");
+    let mountain_move = |world: &ScenarioWorld| {
+        world
+            .generate_team_actions(Team::PLAYER)
+            .into_iter()
+            .find(|a| a.name.starts_with("Move-") && a.name.ends_with("->0,1"))
+    };
+
+    let action = mountain_move(&dwarf_world).expect("dwarf can climb the mountain");
+    assert_eq!(action.agent, Some(dwarf.to_string()));
+    assert_eq!(action.cost, 3.0);
+    assert!(mountain_move(&orc_world).is_none());
+}
@@ -0,0 +1,269 @@
+/// Tests for networked lockstep sessions over localhost TCP
+use game::network::{GameSetup, NetEvent, NetSession};
+use game::scenario_instance::ScenarioWorld;
+use game::{HexCoord, PlayerCommand, Team};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A Player dwarf at (0,0) next to an Enemy orc at (1,0), with room to move.
+const DUEL: &str = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "Terrain": "grasslands", "Unit": ["Dwarf Warrior", "Player"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "Terrain": "grasslands", "Unit": ["Orc Young Swordsman", "Enemy"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": -1, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 2, "r": 0}, "Terrain": "grasslands", "Unit": null, "Item": null, "Structure": null}
+  ]
+}"#;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+struct Game {
+    host: NetSession,
+    host_world: ScenarioWorld,
+    guest: NetSession,
+    guest_world: ScenarioWorld,
+}
+
+/// Hosts the duel on a free localhost port and joins it.
+fn connect() -> Game {
+    let setup = GameSetup::new(DUEL.to_string(), 7);
+    let (mut host, mut host_world) = NetSession::host("127.0.0.1:0", setup).unwrap();
+    let addr = host.local_addr().unwrap();
+
+    // Worlds stay on their thread; the guest's is rebuilt from its setup
+    let joining = thread::spawn(move || NetSession::join(addr, TIMEOUT).map(|(guest, _)| guest));
+    let deadline = Instant::now() + TIMEOUT;
+    while !joining.is_finished() && Instant::now() < deadline {
+        host.poll(&mut host_world);
+        thread::sleep(Duration::from_millis(5));
+    }
+    let guest = joining.join().unwrap().unwrap();
+    let guest_world = guest.setup().build_world().unwrap();
+    Game {
+        host,
+        host_world,
+        guest,
+        guest_world,
+    }
+}
+
+impl Game {
+    /// Polls both ends until `done` holds for the collected events.
+    fn pump_until(
+        &mut self,
+        done: impl Fn(&[NetEvent], &[NetEvent]) -> bool,
+    ) -> (Vec<NetEvent>, Vec<NetEvent>) {
+        let (mut host_events, mut guest_events) = (Vec::new(), Vec::new());
+        let deadline = Instant::now() + TIMEOUT;
+        while !done(&host_events, &guest_events) {
+            assert!(
+                Instant::now() < deadline,
+                "timed out: {:?} / {:?}",
+                host_events,
+                guest_events
+            );
+            host_events.extend(self.host.poll(&mut self.host_world));
+            guest_events.extend(self.guest.poll(&mut self.guest_world));
+            thread::sleep(Duration::from_millis(5));
+        }
+        (host_events, guest_events)
+    }
+
+    fn hashes_match(&self) -> bool {
+        self.host_world.state_hash() == self.guest_world.state_hash()
+    }
+}
+
+fn applied(events: &[NetEvent], count: usize) -> bool {
+    events
+        .iter()
+        .filter(|e| matches!(e, NetEvent::CommandApplied { .. }))
+        .count()
+        >= count
+}
+
+#[test]
+fn test_built_world_has_no_replayed_events() {
+    let mut setup = GameSetup::new(DUEL.to_string(), 7);
+    setup.commands.push((
+        Team::PLAYER,
+        PlayerCommand::Attack {
+            attacker: HexCoord::new(0, 0),
+            defender: HexCoord::new(1, 0),
+            attack_index: 0,
+        },
+    ));
+    setup.commands.push((Team::PLAYER, PlayerCommand::EndTurn));
+
+    let world = setup.build_world().unwrap();
+    assert!(world.events.is_empty());
+}
+
+#[test]
+fn test_guest_joins_with_host_world() {
+    let game = connect();
+    assert!(game.host.is_connected());
+    assert!(game.guest.is_connected());
+    assert_eq!(game.host.local_teams(), &[Team::PLAYER]);
+    assert_eq!(game.guest.local_teams(), &[Team::ENEMY]);
+    assert!(game.hashes_match());
+}
+
+#[test]
+fn test_commands_keep_peers_in_lockstep() {
+    let mut game = connect();
+    let attack = PlayerCommand::Attack {
+        attacker: HexCoord::new(0, 0),
+        defender: HexCoord::new(1, 0),
+        attack_index: 0,
+    };
+    game.host
+        .submit(&mut game.host_world, Team::PLAYER, attack)
+        .unwrap();
+    game.host
+        .submit(&mut game.host_world, Team::PLAYER, PlayerCommand::EndTurn)
+        .unwrap();
+    game.pump_until(|_, guest| applied(guest, 2));
+    assert!(game.hashes_match());
+    // Neutral has no units, so the turn passes straight to the guest
+    assert_eq!(game.guest_world.current_turn_team(), Team::ENEMY);
+
+    if !game
+        .guest_world
+        .get_units_at_position(HexCoord::new(1, 0))
+        .is_empty()
+    {
+        game.guest
+            .submit(
+                &mut game.guest_world,
+                Team::ENEMY,
+                PlayerCommand::Move {
+                    from: HexCoord::new(1, 0),
+                    to: HexCoord::new(2, 0),
+                },
+            )
+            .unwrap();
+        game.guest
+            .submit(&mut game.guest_world, Team::ENEMY, PlayerCommand::EndTurn)
+            .unwrap();
+        game.pump_until(|host, _| applied(host, 2));
+    }
+    assert!(game.hashes_match());
+    assert_eq!(game.host.setup().commands, game.guest.setup().commands);
+}
+
+#[test]
+fn test_remote_teams_cannot_be_commanded_locally() {
+    let mut game = connect();
+    let result = game
+        .guest
+        .submit(&mut game.guest_world, Team::PLAYER, PlayerCommand::EndTurn);
+    assert!(result.is_err());
+    // Out of turn for the guest's own team
+    let result = game
+        .guest
+        .submit(&mut game.guest_world, Team::ENEMY, PlayerCommand::EndTurn);
+    assert!(result.is_err());
+    assert!(game.guest.setup().commands.is_empty());
+}
+
+#[test]
+fn test_desync_is_detected_and_resynced() {
+    let mut game = connect();
+    // The guest's world drifts without a command
+    game.guest_world
+        .apply_command(
+            Team::PLAYER,
+            &PlayerCommand::Move {
+                from: HexCoord::new(0, 0),
+                to: HexCoord::new(-1, 0),
+            },
+        )
+        .unwrap();
+    assert!(!game.hashes_match());
+
+    game.host
+        .submit(&mut game.host_world, Team::PLAYER, PlayerCommand::EndTurn)
+        .unwrap();
+    let (_, guest_events) = game.pump_until(|_, guest| guest.contains(&NetEvent::Resynced));
+    assert!(guest_events.iter().any(|e| matches!(
+        e,
+        NetEvent::Desync { .. } | NetEvent::CommandRejected { .. }
+    )));
+    assert!(!game.guest.is_desynced());
+    assert!(game.hashes_match());
+}
+
+#[test]
+fn test_guest_reconnects_and_resyncs() {
+    let mut game = connect();
+    game.guest.disconnect();
+    game.pump_until(|host, _| host.contains(&NetEvent::PeerDisconnected));
+    assert!(!game.host.is_connected());
+
+    // The game goes on without the guest
+    game.host
+        .submit(&mut game.host_world, Team::PLAYER, PlayerCommand::EndTurn)
+        .unwrap_err();
+
+    game.guest.reconnect(TIMEOUT).unwrap();
+    let (host_events, _) = game.pump_until(|host, guest| {
+        host.contains(&NetEvent::PeerConnected) && guest.contains(&NetEvent::Resynced)
+    });
+    assert!(host_events.contains(&NetEvent::PeerConnected));
+    assert!(game.hashes_match());
+
+    game.host
+        .submit(&mut game.host_world, Team::PLAYER, PlayerCommand::EndTurn)
+        .unwrap();
+    game.pump_until(|_, guest| applied(guest, 1));
+    assert!(game.hashes_match());
+}
+
+#[test]
+fn test_setups_leaving_a_fighting_team_to_the_ai_are_rejected() {
+    let mut setup = GameSetup::new(DUEL.to_string(), 7);
+    setup.guest_teams.clear();
+
+    let Err(error) = setup.build_world() else {
+        panic!("the Enemy team has no player");
+    };
+    assert!(error.contains("Enemy"));
+    assert!(NetSession::host("127.0.0.1:0", setup).is_err());
+}
+
+#[test]
+fn test_failed_resync_is_reported() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    // A host that welcomes the guest, then sends a setup nobody can rebuild
+    let host = thread::spawn(move || {
+        use std::io::Write;
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut setup = GameSetup::new(DUEL.to_string(), 7);
+        for _ in 0..2 {
+            writeln!(
+                stream,
+                r#"{{"Welcome": {}}}"#,
+                serde_json::to_string(&setup).unwrap()
+            )
+            .unwrap();
+            setup.guest_teams.clear();
+        }
+        stream
+    });
+
+    let (mut guest, mut guest_world) = NetSession::join(addr, TIMEOUT).unwrap();
+    let _stream = host.join().unwrap();
+    let deadline = Instant::now() + TIMEOUT;
+    let mut events = Vec::new();
+    while events.is_empty() {
+        assert!(Instant::now() < deadline, "no event for the bad setup");
+        events.extend(guest.poll(&mut guest_world));
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(matches!(
+        events.as_slice(),
+        [NetEvent::ResyncFailed { reason }] if reason.contains("Enemy")
+    ));
+}
@@ -0,0 +1,212 @@
+/// Tests for experience, level-up and evolution in ScenarioWorld
+use game::progression::{ai_evolution_choice, evolution_score};
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, Team};
+use units::{AbilityId, ActiveAbility, ActiveEffect, TargetType, UnitType};
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// Player unit at (0,0), an adjacent player ally at (0,1) and an enemy at (1,0).
+fn world_with(player_unit: &str) -> (ScenarioWorld, Uuid, Uuid, Uuid) {
+    let world = TestMap::new()
+        .unit(0, 0, "grasslands", player_unit, "Player")
+        .unit(0, 1, "grasslands", "Dwarf Warrior", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy")
+        .world();
+    let hero = unit_at(&world, HexCoord::new(0, 0));
+    let ally = unit_at(&world, HexCoord::new(0, 1));
+    let enemy = unit_at(&world, HexCoord::new(1, 0));
+    (world, hero, ally, enemy)
+}
+
+/// Gives `unit_id` a lethal single-target damage ability and returns its id.
+fn give_lethal_ability(world: &mut ScenarioWorld, unit_id: Uuid) -> AbilityId {
+    let ability = ActiveAbility::new(
+        "Smite",
+        "Deals overwhelming damage",
+        3,
+        1,
+        TargetType::SingleEnemy,
+        ActiveEffect::Damage {
+            amount: 1000,
+            damage_type: units::combat::DamageType::Blunt,
+        },
+    );
+    let id = ability.id;
+    world
+        .get_unit_mut(unit_id)
+        .unwrap()
+        .unit_mut()
+        .add_ability(units::Ability::Active(ability));
+    id
+}
+
+fn experience(world: &ScenarioWorld, unit_id: Uuid) -> i32 {
+    world.get_unit(unit_id).unwrap().unit().experience()
+}
+
+#[test]
+fn test_ability_kill_awards_experience_to_killer_and_adjacent_allies() {
+    let (mut world, hero, ally, enemy) = world_with("Dwarf Warrior");
+    let enemy_level = world.get_unit(enemy).unwrap().unit().level();
+    let (hero_xp, ally_xp) = (experience(&world, hero), experience(&world, ally));
+    let ability = give_lethal_ability(&mut world, hero);
+
+    assert_eq!(world.use_ability(hero, ability, enemy), Ok(true));
+
+    assert!(world.get_unit(enemy).is_none());
+    assert_eq!(
+        experience(&world, hero),
+        hero_xp + enemy_level * enemy_level
+    );
+    assert_eq!(experience(&world, ally), ally_xp + 2 * enemy_level);
+}
+
+#[test]
+fn test_ability_on_cooldown_cannot_be_reused() {
+    let (mut world, hero, ally, enemy) = world_with("Dwarf Warrior");
+    let ability = give_lethal_ability(&mut world, hero);
+
+    assert!(world.use_ability(hero, ability, ally).is_err());
+    assert_eq!(world.use_ability(hero, ability, enemy), Ok(true));
+    assert!(world.use_ability(hero, ability, ally).is_err());
+}
+
+#[test]
+fn test_player_branching_evolution_waits_for_choice() {
+    let (mut world, hero, _, enemy) = world_with("Human Knight");
+    let threshold = world
+        .get_unit(hero)
+        .unwrap()
+        .unit()
+        .xp_required_for_level(2);
+    world
+        .get_unit_mut(hero)
+        .unwrap()
+        .unit_mut()
+        .add_experience(threshold - 1);
+    let ability = give_lethal_ability(&mut world, hero);
+
+    world.use_ability(hero, ability, enemy).unwrap();
+
+    let pending = world.pending_evolution().expect("evolution choice pending");
+    assert_eq!(pending.unit_id, hero);
+    assert_eq!(
+        pending.options,
+        vec![UnitType::HumanKnightCommander, UnitType::HumanGrandKnight]
+    );
+    assert_eq!(
+        world.get_unit(hero).unwrap().unit().unit_type(),
+        "Human Knight"
+    );
+
+    assert!(world.choose_evolution(5).is_err());
+    assert!(world.pending_evolution().is_some());
+
+    world.choose_evolution(1).unwrap();
+    let evolved = world.get_unit(hero).expect("same id after evolving");
+    assert_eq!(evolved.unit().unit_type(), "Human Grand Knight");
+    assert_eq!(evolved.team(), Team::PLAYER);
+    assert_eq!(evolved.position(), HexCoord::new(0, 0));
+    assert!(world.pending_evolution().is_none());
+}
+
+#[test]
+fn test_ai_team_picks_evolution_by_score() {
+    let (mut world, hero, _, enemy) = world_with("Human Knight");
+    world.set_team_control(Team::PLAYER, false);
+    let threshold = world
+        .get_unit(hero)
+        .unwrap()
+        .unit()
+        .xp_required_for_level(2);
+    world
+        .get_unit_mut(hero)
+        .unwrap()
+        .unit_mut()
+        .add_experience(threshold - 1);
+    let ability = give_lethal_ability(&mut world, hero);
+
+    world.use_ability(hero, ability, enemy).unwrap();
+
+    let options = [UnitType::HumanKnightCommander, UnitType::HumanGrandKnight];
+    let expected = options[ai_evolution_choice(&options).unwrap()];
+    assert!(world.pending_evolution().is_none());
+    assert_eq!(
+        world.get_unit(hero).unwrap().unit().unit_type(),
+        expected.as_str()
+    );
+}
+
+#[test]
+fn test_ai_evolution_choice_prefers_highest_score() {
+    let options = [UnitType::HumanKnightCommander, UnitType::HumanGrandKnight];
+    let scores: Vec<i32> = options
+        .iter()
+        .map(|t| evolution_score(*t).unwrap())
+        .collect();
+    let best = ai_evolution_choice(&options).unwrap();
+
+    assert!(scores.iter().all(|s| *s <= scores[best]));
+    assert_eq!(ai_evolution_choice(&[]), None);
+}
+
+#[test]
+fn test_single_evolution_target_evolves_immediately() {
+    let (mut world, hero, _, enemy) = world_with("Dwarf Young Warrior");
+    let threshold = world
+        .get_unit(hero)
+        .unwrap()
+        .unit()
+        .xp_required_for_level(2);
+    world
+        .get_unit_mut(hero)
+        .unwrap()
+        .unit_mut()
+        .add_experience(threshold - 1);
+    let ability = give_lethal_ability(&mut world, hero);
+
+    world.use_ability(hero, ability, enemy).unwrap();
+
+    assert!(world.pending_evolution().is_none());
+    assert_eq!(
+        world.get_unit(hero).unwrap().unit().unit_type(),
+        "Dwarf Warrior"
+    );
+}
+
+#[test]
+fn test_choose_evolution_without_pending_choice_fails() {
+    let (mut world, _, _, _) = world_with("Dwarf Warrior");
+    assert!(world.choose_evolution(0).is_err());
+}
+
+#[test]
+fn test_ally_evolutions_queue_in_hex_order() {
+    let mut world = TestMap::new()
+        .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(0, 1, "grasslands", "Human Knight", "Player")
+        .unit(-1, 0, "grasslands", "Human Knight", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy")
+        .world();
+    let hero = unit_at(&world, HexCoord::new(0, 0));
+    let enemy = unit_at(&world, HexCoord::new(1, 0));
+    let west = unit_at(&world, HexCoord::new(-1, 0));
+    let south = unit_at(&world, HexCoord::new(0, 1));
+    for knight in [west, south] {
+        let unit = world.get_unit_mut(knight).unwrap().unit_mut();
+        let threshold = unit.xp_required_for_level(2);
+        unit.add_experience(threshold - 1);
+    }
+    let ability = give_lethal_ability(&mut world, hero);
+
+    world.use_ability(hero, ability, enemy).unwrap();
+
+    // Queued by position, not by the peer-local UUIDs
+    assert_eq!(world.pending_evolution().unwrap().unit_id, west);
+    world.choose_evolution(0).unwrap();
+    assert_eq!(world.pending_evolution().unwrap().unit_id, south);
+}
@@ -0,0 +1,192 @@
+/// Tests for tactical search over cloned scenario worlds
+use ai::{expectimax, SearchBudget, TacticalState};
+use game::scenario_instance::ScenarioWorld;
+use game::{GameCommand, GameObject, HexCoord, Team};
+use uuid::Uuid;
+
+const DUEL_MAP: &str = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "SpriteType": "Grasslands", "Unit": ["Dwarf Warrior", "Player"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "SpriteType": "Grasslands", "Unit": ["Orc Young Swordsman", "Enemy"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 0, "r": 1}, "SpriteType": "Grasslands", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": -1, "r": 0}, "SpriteType": "Grasslands", "Unit": null, "Item": null, "Structure": null},
+    {"HexCoord": {"q": 2, "r": 0}, "SpriteType": "Grasslands", "Unit": null, "Item": null, "Structure": null}
+  ]
+}"#;
+
+fn duel_world() -> (ScenarioWorld, Uuid, Uuid) {
+    let mut world = ScenarioWorld::new(DUEL_MAP.to_string());
+    world.start_turn_based_game();
+
+    let player = world
+        .units()
+        .values()
+        .find(|u| u.team() == Team::PLAYER)
+        .map(|u| u.id())
+        .expect("player unit");
+    let enemy = world
+        .units()
+        .values()
+        .find(|u| u.team() == Team::ENEMY)
+        .map(|u| u.id())
+        .expect("enemy unit");
+    (world, player, enemy)
+}
+
+#[test]
+fn test_cloned_world_is_independent() {
+    let (world, player, _) = duel_world();
+    let original_health = world.get_unit(player).unwrap().unit().combat_stats().health;
+
+    let mut copy = world.clone();
+    copy.get_unit_mut(player).unwrap().unit_mut().take_damage(5);
+    copy.get_unit_mut(player)
+        .unwrap()
+        .set_position(HexCoord::new(-1, 0));
+
+    let unit = world.get_unit(player).unwrap();
+    assert_eq!(unit.unit().combat_stats().health, original_health);
+    assert_eq!(unit.position(), HexCoord::new(0, 0));
+}
+
+#[test]
+fn test_cloned_world_shares_terrain_until_modified() {
+    let (world, _, _) = duel_world();
+    let mut copy = world.clone();
+    assert!(std::sync::Arc::ptr_eq(&world.terrain, &copy.terrain));
+
+    copy.get_terrain_mut(HexCoord::new(0, 1))
+        .unwrap()
+        .add_feature(units::TerrainFeature::Road);
+
+    assert!(!std::sync::Arc::ptr_eq(&world.terrain, &copy.terrain));
+    assert!(world
+        .get_terrain(HexCoord::new(0, 1))
+        .unwrap()
+        .features()
+        .is_empty());
+}
+
+#[test]
+fn test_legal_commands_include_attack_and_end_turn() {
+    let (world, player, enemy) = duel_world();
+    let commands = world.legal_commands();
+
+    assert!(commands.iter().any(|c| matches!(
+        c,
+        GameCommand::Attack { attacker_id, defender_id, .. }
+            if *attacker_id == player && *defender_id == enemy
+    )));
+    assert_eq!(commands.last(), Some(&GameCommand::EndTurn));
+    // Moves never target occupied hexes
+    assert!(!commands.contains(&GameCommand::Move {
+        unit_id: player,
+        to: HexCoord::new(1, 0),
+    }));
+}
+
+#[test]
+fn test_attack_outcome_probabilities_sum_to_one() {
+    let (world, player, enemy) = duel_world();
+    let outcomes = world.apply(&GameCommand::Attack {
+        attacker_id: player,
+        defender_id: enemy,
+        attack_index: 0,
+    });
+
+    assert!(outcomes.len() > 1, "attack should be a chance node");
+    let total: f32 = outcomes.iter().map(|(p, _)| *p).sum();
+    assert!((total - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_search_finishes_weakened_enemy() {
+    let (mut world, player, enemy) = duel_world();
+    let enemy_health = world.get_unit(enemy).unwrap().unit().combat_stats().health;
+    world
+        .get_unit_mut(enemy)
+        .unwrap()
+        .unit_mut()
+        .take_damage((enemy_health - 1) as u32);
+
+    let result = expectimax(&world, &SearchBudget::nodes(1, 10_000));
+    assert!(matches!(
+        result.best_command,
+        Some(GameCommand::Attack { attacker_id, defender_id, .. })
+            if attacker_id == player && defender_id == enemy
+    ));
+    assert_eq!(result.depth_reached, 1);
+}
+
+#[test]
+fn test_search_respects_node_budget() {
+    let (world, _, _) = duel_world();
+    let result = expectimax(&world, &SearchBudget::nodes(6, 50));
+
+    assert!(result.best_command.is_some());
+    // Budget is checked before expanding, so at most one chance node overshoots it
+    assert!(result.nodes <= 54, "expanded {} nodes", result.nodes);
+    assert!(result.depth_reached < 6);
+}
+
+#[test]
+fn test_tactical_turn_ends_turn() {
+    let (mut world, _, _) = duel_world();
+    world.end_current_turn();
+    assert_eq!(world.current_turn_team(), Team::ENEMY);
+
+    world.tactical_search = Some(SearchBudget::nodes(1, 2_000));
+    world.run_ai_for_current_team();
+
+    assert_ne!(world.current_turn_team(), Team::ENEMY);
+}
+
+#[test]
+fn test_tactical_turn_discards_stale_pending_combat() {
+    let (mut world, player, enemy) = duel_world();
+    let player_health = world.get_unit(player).unwrap().unit().combat_stats().health;
+    world
+        .get_unit_mut(player)
+        .unwrap()
+        .unit_mut()
+        .take_damage((player_health - 1) as u32);
+    world.end_current_turn();
+    world.request_combat(player, enemy).unwrap();
+
+    world.tactical_search = Some(SearchBudget::nodes(1, 2_000));
+    world.run_ai_for_current_team();
+
+    assert!(world.pending_combat.is_none());
+    let player_stats = world
+        .get_unit(player)
+        .map(|u| u.unit().combat_stats().clone());
+    assert!(player_stats.is_none_or(|stats| !stats.attacked_this_turn));
+}
+
+#[test]
+fn test_tactical_ai_setting_toggles_search() {
+    let (mut world, _, _) = duel_world();
+    assert!(world.tactical_search.is_none());
+
+    world.set_tactical_ai(true);
+    assert_eq!(world.tactical_search, Some(SearchBudget::default()));
+
+    world.set_tactical_ai(false);
+    assert!(world.tactical_search.is_none());
+}
+
+#[test]
+fn test_simulated_move_matches_real_move() {
+    let (world, player, _) = duel_world();
+    let to = HexCoord::new(-1, 0);
+
+    let simulated = world.apply(&GameCommand::Move {
+        unit_id: player,
+        to,
+    });
+    let mut real = world.clone();
+    real.move_unit(player, to).unwrap();
+
+    assert_eq!(simulated.len(), 1);
+    assert_eq!(simulated[0].1.state_hash(), real.state_hash());
+}
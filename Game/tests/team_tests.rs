@@ -0,0 +1,190 @@
+/// Tests for scenario-defined teams, diplomacy and turn order in ScenarioWorld
+use game::scenario_instance::ScenarioWorld;
+use game::{GameObject, HexCoord, Team};
+use uuid::Uuid;
+
+/// A 2v2 scenario: North and South are allied against East and West.
+/// North stands at (0,0) between its ally South at (1,0) and East at (0,1);
+/// West waits at (-1,0). West takes the first turn.
+const TWO_VS_TWO: &str = r#"{
+  "Teams": [
+    {"Name": "North", "IsPlayerControlled": true, "Goal": "Hold", "Color": [0.1, 0.2, 0.9]},
+    {"Name": "South", "IsPlayerControlled": false, "Goal": "Hold"},
+    {"Name": "East", "IsPlayerControlled": false, "Goal": "Take"},
+    {"Name": "West", "IsPlayerControlled": false, "Goal": "Take"}
+  ],
+  "Diplomacy": [
+    {"Teams": ["North", "South"], "Stance": "Allied"},
+    {"Teams": ["East", "West"], "Stance": "Allied"}
+  ],
+  "TurnOrder": ["West", "North", "East", "South"],
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "Terrain": "grasslands", "Unit": ["Dwarf Warrior", "North"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "Terrain": "grasslands", "Unit": ["Dwarf Warrior", "South"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 0, "r": 1}, "Terrain": "grasslands", "Unit": ["Orc Young Swordsman", "East"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": -1, "r": 0}, "Terrain": "grasslands", "Unit": ["Orc Young Swordsman", "West"], "Item": null, "Structure": null}
+  ]
+}"#;
+
+fn unit_at(world: &ScenarioWorld, q: i32, r: i32) -> Uuid {
+    world
+        .units()
+        .values()
+        .find(|u| u.position() == HexCoord::new(q, r))
+        .map(|u| u.id())
+        .expect("unit on hex")
+}
+
+fn team(world: &ScenarioWorld, name: &str) -> Team {
+    world.teams.find(name).expect("team in roster")
+}
+
+#[test]
+fn test_units_join_teams_by_scenario_name() {
+    let world = ScenarioWorld::new(TWO_VS_TWO.to_string());
+
+    assert_eq!(world.teams.len(), 4);
+    for (q, r, name) in [
+        (0, 0, "North"),
+        (1, 0, "South"),
+        (0, 1, "East"),
+        (-1, 0, "West"),
+    ] {
+        let unit = world.get_unit(unit_at(&world, q, r)).unwrap();
+        assert_eq!(world.teams.name(unit.team()), name);
+    }
+    assert_eq!(world.teams.color(team(&world, "North")), [0.1, 0.2, 0.9]);
+    assert_ne!(
+        world.teams.color(team(&world, "South")),
+        world.teams.color(team(&world, "East"))
+    );
+}
+
+#[test]
+fn test_turn_order_and_control_come_from_scenario() {
+    let mut world = ScenarioWorld::new(TWO_VS_TWO.to_string());
+    world.start_turn_based_game();
+
+    let expected = ["West", "North", "East", "South", "West"];
+    for name in expected {
+        assert_eq!(world.teams.name(world.current_turn_team()), name);
+        assert_eq!(world.is_current_team_player_controlled(), name == "North");
+        world.end_current_turn();
+    }
+    assert_eq!(world.turn_number(), 1);
+}
+
+#[test]
+fn test_allies_block_and_enemies_trigger_combat() {
+    let mut world = ScenarioWorld::new(TWO_VS_TWO.to_string());
+    world.start_turn_based_game();
+    let north = unit_at(&world, 0, 0);
+    let east = unit_at(&world, 0, 1);
+
+    assert!(world.move_unit(north, HexCoord::new(1, 0)).is_err());
+    assert!(world.pending_combat.is_none());
+
+    world.move_unit(north, HexCoord::new(0, 1)).unwrap();
+    let combat = world.pending_combat.as_ref().expect("combat requested");
+    assert_eq!(combat.defender_id, east);
+}
+
+#[test]
+fn test_ai_only_targets_hostile_teams() {
+    let world = ScenarioWorld::new(TWO_VS_TWO.to_string());
+    let south = unit_at(&world, 1, 0);
+    let north = unit_at(&world, 0, 0);
+
+    let actions = world.generate_team_actions(team(&world, "East"));
+    let targets_team = |id: Uuid| {
+        actions.iter().any(|a| {
+            a.effects
+                .iter()
+                .any(|(key, _)| key.to_string().contains(&id.to_string()))
+        })
+    };
+    assert!(targets_team(north));
+    assert!(targets_team(south));
+
+    let actions = world.generate_team_actions(team(&world, "North"));
+    assert!(!actions.iter().any(|a| a
+        .effects
+        .iter()
+        .any(|(key, _)| key.to_string().contains(&south.to_string()))));
+}
+
+#[test]
+fn test_maps_without_teams_use_default_roster() {
+    let map = r#"{
+  "Map": [
+    {"HexCoord": {"q": 0, "r": 0}, "Terrain": "grasslands", "Unit": ["Dwarf Warrior", "Player"], "Item": null, "Structure": null},
+    {"HexCoord": {"q": 1, "r": 0}, "Terrain": "grasslands", "Unit": ["Dwarf Warrior", "Nobody"], "Item": null, "Structure": ["House", "Enemy"]}
+  ]
+}"#;
+    let world = ScenarioWorld::new(map.to_string());
+
+    assert_eq!(
+        world.turn_system.teams(),
+        &[Team::PLAYER, Team::ENEMY, Team::NEUTRAL]
+    );
+    // Unknown team names fall back to the first team
+    let unit = world.get_unit(unit_at(&world, 1, 0)).unwrap();
+    assert_eq!(unit.team(), Team::PLAYER);
+    let house = world.structures.values().next().unwrap();
+    assert_eq!(house.team(), Team::ENEMY);
+}
+
+#[test]
+fn test_invalid_turn_order_keeps_team_order() {
+    let map = TWO_VS_TWO.replace(
+        r#""TurnOrder": ["West", "North", "East", "South"]"#,
+        r#""TurnOrder": ["West", "Nowhere"]"#,
+    );
+    let world = ScenarioWorld::new(map);
+    let names: Vec<&str> = world
+        .turn_system
+        .teams()
+        .iter()
+        .map(|t| world.teams.name(*t))
+        .collect();
+    assert_eq!(names, ["North", "South", "East", "West"]);
+}
+
+#[test]
+fn test_maps_with_too_many_teams_are_rejected() {
+    let teams: Vec<String> = (0..=units::TeamRoster::MAX_TEAMS)
+        .map(|i| {
+            format!(
+                r#"{{"Name": "Team {}", "IsPlayerControlled": false, "Goal": "Win"}}"#,
+                i
+            )
+        })
+        .collect();
+    let map = format!(
+        r#"{{
+  "Teams": [{}],
+  "Map": [
+    {{"HexCoord": {{"q": 0, "r": 0}}, "Terrain": "grasslands", "Unit": null, "Item": null, "Structure": null}}
+  ]
+}}"#,
+        teams.join(", ")
+    );
+
+    let Err(error) = ScenarioWorld::parse_map_json(&map) else {
+        panic!("map with too many teams was accepted");
+    };
+    assert!(error.to_string().contains("at most 256 teams"), "{}", error);
+}
+
+#[test]
+fn test_tactical_search_treats_allies_as_friendly() {
+    use ai::TacticalState;
+
+    let world = ScenarioWorld::new(TWO_VS_TWO.to_string());
+    let north = team(&world, "North");
+
+    assert!(world.is_friendly(&north, &north));
+    assert!(world.is_friendly(&team(&world, "South"), &north));
+    assert!(!world.is_friendly(&team(&world, "East"), &north));
+    assert!(!world.is_friendly(&team(&world, "West"), &north));
+}
@@ -0,0 +1,124 @@
+/// Tests for the day/night cycle in ScenarioWorld
+use ai::TacticalState;
+use game::scenario_instance::ScenarioWorld;
+use game::{GameCommand, HexCoord};
+use uuid::Uuid;
+
+mod common;
+
+use common::{unit_at, TestMap};
+
+/// A lawful dwarf at (0,0) next to a chaotic orc at (1,0). `day_cycle` is
+/// inserted as the map's top-level `DayCycle` (or omitted when empty).
+fn world_with(day_cycle: &str) -> (ScenarioWorld, Uuid, Uuid) {
+    let mut map = TestMap::new()
+        .unit(0, 0, "grasslands", "Dwarf Warrior", "Player")
+        .unit(1, 0, "grasslands", "Orc Young Swordsman", "Enemy");
+    if !day_cycle.is_empty() {
+        map = map.day_cycle(day_cycle);
+    }
+    let world = map.world();
+    let dwarf = unit_at(&world, HexCoord::new(0, 0));
+    let orc = unit_at(&world, HexCoord::new(1, 0));
+    (world, dwarf, orc)
+}
+
+const NOON: &str = r#"[{"Name": "Noon", "LawfulBonus": 25}]"#;
+const MIDNIGHT: &str = r#"[{"Name": "Midnight", "LawfulBonus": -25}]"#;
+
+/// Damage dealt by each side when both strikes of an exchange hit.
+fn exchange_damage(world: &ScenarioWorld, dwarf: Uuid, orc: Uuid) -> (i32, i32) {
+    let health = |w: &ScenarioWorld, id: Uuid| w.get_unit(id).unwrap().unit().combat_stats().health;
+    let command = GameCommand::Attack {
+        attacker_id: dwarf,
+        defender_id: orc,
+        attack_index: 0,
+    };
+    let outcomes = world.apply(&command);
+    // Outcomes are ordered attacker hit/defender hit first
+    let (_, both_hit) = &outcomes[0];
+    (
+        health(world, orc) - health(both_hit, orc),
+        health(world, dwarf) - health(both_hit, dwarf),
+    )
+}
+
+#[test]
+fn test_default_cycle_advances_with_turns() {
+    let (mut world, _, _) = world_with("");
+    assert_eq!(world.time_of_day().name, "Dawn");
+    assert_eq!(world.time_of_day().lawful_bonus, 0);
+
+    // One full round of Player, Enemy and Neutral turns
+    for _ in 0..3 {
+        world.turn_system.end_turn();
+    }
+
+    assert_eq!(world.turn_number(), 1);
+    assert_eq!(world.time_of_day().name, "Morning");
+    let phases = world.day_cycle.phases().len() as u32;
+    assert_eq!(world.day_cycle.phase_for_turn(phases).name, "Dawn");
+}
+
+#[test]
+fn test_scenario_configures_day_cycle() {
+    let (world, dwarf, orc) = world_with(NOON);
+
+    assert_eq!(world.day_cycle.phases().len(), 1);
+    assert_eq!(world.time_of_day().name, "Noon");
+    assert_eq!(world.time_of_day_bonus(dwarf), 25);
+    assert_eq!(world.time_of_day_bonus(orc), -25);
+
+    let (invalid, _, _) = world_with("[]");
+    assert_eq!(invalid.day_cycle, game::DayCycle::default());
+}
+
+#[test]
+fn test_combat_preview_shows_time_of_day_damage() {
+    let (mut world, dwarf, orc) = world_with(NOON);
+    let base = |id: Uuid, w: &ScenarioWorld| w.get_unit(id).unwrap().unit().get_attacks()[0].damage;
+    let dwarf_base = base(dwarf, &world);
+    let orc_base = base(orc, &world);
+
+    world.request_combat(dwarf, orc).unwrap();
+
+    let pending = world.pending_combat.as_ref().expect("combat requested");
+    let strike = &pending.attacker_attacks[0];
+    let counter = &pending.defender_attacks[0];
+    assert_eq!(strike.time_of_day_bonus, 25);
+    assert_eq!(
+        strike.damage,
+        units::Alignment::Lawful.scale_damage(dwarf_base, 25)
+    );
+    assert_eq!(counter.time_of_day_bonus, -25);
+    assert_eq!(
+        counter.damage,
+        units::Alignment::Chaotic.scale_damage(orc_base, 25)
+    );
+}
+
+#[test]
+fn test_exchange_damage_depends_on_time_of_day() {
+    let (noon, dwarf, orc) = world_with(NOON);
+    let (midnight, night_dwarf, night_orc) = world_with(MIDNIGHT);
+
+    let (dwarf_by_day, orc_by_day) = exchange_damage(&noon, dwarf, orc);
+    let (dwarf_by_night, orc_by_night) = exchange_damage(&midnight, night_dwarf, night_orc);
+
+    assert!(dwarf_by_day > dwarf_by_night);
+    assert!(orc_by_day < orc_by_night);
+}
+
+#[test]
+fn test_forecast_expected_damage_uses_time_of_day() {
+    let expected = |day_cycle: &str| {
+        let (world, dwarf, orc) = world_with(day_cycle);
+        world
+            .combat_forecast(orc, dwarf, 0)
+            .unwrap()
+            .expected_damage_dealt()
+    };
+
+    // The chaotic orc expects to deal more damage at night
+    assert!(expected(MIDNIGHT) > expected(NOON));
+}
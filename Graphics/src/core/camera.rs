@@ -1,15 +1,38 @@
 use crate::core::HexCoord;
 use crate::math::Vec2;
 
+/// Closest zoom level (hexes drawn at 2.5x their size)
+pub const MAX_ZOOM: f32 = 2.5;
+
+/// Farthest zoom level (hexes drawn at half their size)
+pub const MIN_ZOOM: f32 = 0.5;
+
+/// How quickly the camera catches up with its pan target, per second
+const PAN_SMOOTHING: f32 = 10.0;
+
+/// Width in pixels of the band along the view edges that scrolls the map
+pub const EDGE_SCROLL_MARGIN: f32 = 20.0;
+
+/// Edge scrolling speed in clip-space units per second at zoom 1.0
+pub const EDGE_SCROLL_SPEED: f32 = 1.5;
+
 /// Camera for the HexGrid rendering system.
 ///
 /// This camera operates in clip-space coordinates (-1.0..1.0) which the renderer
-/// uses directly. It is intentionally lightweight: it provides visibility checks
-/// and simple screen/world conversions for UI and picking logic. For higher-precision
+/// uses directly, scaled by the zoom level around the camera position. It is
+/// intentionally lightweight: it provides visibility checks and simple
+/// screen/world conversions for UI and picking logic. For higher-precision
 /// pixel-space calculations (e.g. lookup tables), the `WorldHexLookupTable` should be used.
+///
+/// Panning is smoothed: `move_by` and `pan_to` set a target the camera glides
+/// towards in `update`, while `jump_to` moves it at once. When bounds are set,
+/// the camera center never leaves them.
 pub struct Camera {
     pub position: Vec2,
     pub view_distance: f32,
+    zoom: f32,
+    target: Vec2,
+    bounds: Option<(Vec2, Vec2)>,
 }
 
 impl Camera {
@@ -18,6 +41,9 @@ impl Camera {
         Self {
             position: Vec2::new(0.0, 0.0),
             view_distance: 3.0,
+            zoom: 1.0,
+            target: Vec2::new(0.0, 0.0),
+            bounds: None,
         }
     }
 }
@@ -30,9 +56,11 @@ impl Default for Camera {
 
 impl Camera {
     /// Check whether a world position is visible from the camera.
+    ///
+    /// The view distance grows as the camera zooms out.
     pub fn can_see(&self, world_pos: Vec2) -> bool {
         let distance = self.position.distance(&world_pos);
-        distance <= self.view_distance
+        distance <= self.view_distance / self.zoom
     }
 
     // Convert camera position to hex coordinate (useful for coarse culling).
@@ -55,16 +83,19 @@ impl Camera {
         let ndc_x = (screen_pos.x / window_size.x) * 2.0 - 1.0;
         let ndc_y = -((screen_pos.y / window_size.y) * 2.0 - 1.0); // Flip Y
 
-        // In the renderer, world coordinates are used directly as clip space coordinates
-        Vec2::new(ndc_x + self.position.x, ndc_y + self.position.y)
+        // Undo the zoom around the camera position
+        Vec2::new(
+            ndc_x / self.zoom + self.position.x,
+            ndc_y / self.zoom + self.position.y,
+        )
     }
 
     /// Convert world coordinates (clip space) to screen coordinates.
     ///
     /// Useful for placing UI elements that correspond to world positions.
     pub fn world_to_screen(&self, world_pos: Vec2, window_size: Vec2) -> Vec2 {
-        // Convert from world/clip space to camera-relative coordinates
-        let camera_relative = world_pos - self.position;
+        // Convert from world/clip space to zoomed camera-relative coordinates
+        let camera_relative = (world_pos - self.position) * self.zoom;
 
         // Convert from NDC (-1 to 1) to screen coordinates
         let screen_x = (camera_relative.x + 1.0) * 0.5 * window_size.x;
@@ -73,9 +104,120 @@ impl Camera {
         Vec2::new(screen_x, screen_y)
     }
 
-    /// Move camera by delta in clip-space units.
+    /// Move the pan target by delta in clip-space units.
+    ///
+    /// The camera glides there over the next frames (see `update`).
     pub fn move_by(&mut self, dx: f32, dy: f32) {
-        self.position.x += dx;
-        self.position.y += dy;
+        self.target = self.clamp(Vec2::new(self.target.x + dx, self.target.y + dy));
+    }
+
+    /// Smoothly pan so that `world_pos` ends up at the center of the view.
+    pub fn pan_to(&mut self, world_pos: Vec2) {
+        self.target = self.clamp(world_pos);
+    }
+
+    /// Center the view on `world_pos` at once, without gliding.
+    pub fn jump_to(&mut self, world_pos: Vec2) {
+        self.target = self.clamp(world_pos);
+        self.position = self.target;
+    }
+
+    /// Where the camera is panning to (its position once it settles).
+    pub fn target(&self) -> Vec2 {
+        self.target
+    }
+
+    /// Whether the camera is still gliding towards its target.
+    pub fn is_panning(&self) -> bool {
+        self.position.distance(&self.target) > f32::EPSILON
+    }
+
+    /// Advance smooth panning by `delta_time` seconds.
+    pub fn update(&mut self, delta_time: f32) {
+        let blend = 1.0 - (-PAN_SMOOTHING * delta_time).exp();
+        self.position += (self.target - self.position) * blend;
+        if self.position.distance(&self.target) < 0.001 {
+            self.position = self.target;
+        }
+    }
+
+    /// Current zoom level; 1.0 draws hexes at their natural size.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Set the zoom level around the view center, clamped to `MIN_ZOOM..=MAX_ZOOM`.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    /// Multiply the zoom by `factor`, keeping the world point under
+    /// `screen_pos` fixed on screen (zooming towards the mouse cursor).
+    pub fn zoom_at(&mut self, factor: f32, screen_pos: Vec2, window_size: Vec2) {
+        let anchor = self.screen_to_world(screen_pos, window_size);
+        let old_zoom = self.zoom;
+        self.set_zoom(self.zoom * factor);
+
+        // The anchor's offset from the center shrinks as the zoom grows
+        let shift = (anchor - self.position) * (1.0 - old_zoom / self.zoom);
+        self.position = self.clamp(self.position + shift);
+        self.target = self.clamp(self.target + shift);
+    }
+
+    /// Keep the camera center inside `min..=max` (world coordinates).
+    pub fn set_bounds(&mut self, min: Vec2, max: Vec2) {
+        self.bounds = Some((min, max));
+        self.position = self.clamp(self.position);
+        self.target = self.clamp(self.target);
+    }
+
+    /// Let the camera move anywhere.
+    pub fn clear_bounds(&mut self) {
+        self.bounds = None;
+    }
+
+    /// The area the camera center is kept in, if any.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    /// Scroll the map when the cursor rests near an edge of the view.
+    ///
+    /// * `cursor` - Mouse position in screen pixels (origin at top-left)
+    /// * `view_size` - Size of the map view in pixels, starting at the window's
+    ///   top-left corner; a cursor outside it (e.g. over a side panel) does not scroll
+    /// * `delta_time` - Seconds since the last frame
+    ///
+    /// Returns whether the camera scrolled.
+    pub fn edge_scroll(&mut self, cursor: Vec2, view_size: Vec2, delta_time: f32) -> bool {
+        if cursor.x < 0.0 || cursor.y < 0.0 || cursor.x > view_size.x || cursor.y > view_size.y {
+            return false;
+        }
+
+        let axis = |pos: f32, size: f32| {
+            if pos < EDGE_SCROLL_MARGIN {
+                -1.0
+            } else if pos > size - EDGE_SCROLL_MARGIN {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let dx = axis(cursor.x, view_size.x);
+        let dy = -axis(cursor.y, view_size.y); // Screen Y points down
+        if dx == 0.0 && dy == 0.0 {
+            return false;
+        }
+
+        let step = EDGE_SCROLL_SPEED * delta_time / self.zoom;
+        self.move_by(dx * step, dy * step);
+        true
+    }
+
+    fn clamp(&self, pos: Vec2) -> Vec2 {
+        match self.bounds {
+            Some((min, max)) => Vec2::new(pos.x.clamp(min.x, max.x), pos.y.clamp(min.y, max.y)),
+            None => pos,
+        }
     }
 }
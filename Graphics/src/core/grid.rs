@@ -1,6 +1,6 @@
 use crate::core::{Camera, HexCoord, Hexagon, HighlightType, SpriteType};
 use crate::math::Vec2;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// In-memory hex grid used by the renderer and game UI.
 ///
@@ -34,12 +34,14 @@ impl HexGrid {
             }
         }
 
-        Self {
+        let mut grid = Self {
             hexagons,
             camera: Camera::new(),
             hex_size,
             grid_radius,
-        }
+        };
+        grid.update_camera_bounds();
+        grid
     }
 
     /// Create an empty `HexGrid` with no hexagons.
@@ -59,7 +61,8 @@ impl HexGrid {
     /// Create a `HexGrid` from a list of coordinates with their sprites.
     ///
     /// Only the specified coordinates will have hexagons created for them.
-    /// This is the recommended way to create a grid from map data.
+    /// This is the recommended way to create a grid from map data. The camera
+    /// is kept over the map (see `update_camera_bounds`).
     ///
     /// # Arguments
     ///
@@ -87,12 +90,14 @@ impl HexGrid {
             hexagons.insert(coord, hexagon);
         }
 
-        Self {
+        let mut grid = Self {
             hexagons,
             camera: Camera::new(),
             hex_size,
             grid_radius: 0, // Not applicable for custom grids
-        }
+        };
+        grid.update_camera_bounds();
+        grid
     }
 
     /// Add a single hexagon at the specified coordinate.
@@ -139,7 +144,8 @@ impl HexGrid {
     pub fn get_visible_hexagons(&self) -> Vec<&Hexagon> {
         // Get approximate camera hex coordinate for more efficient culling
         let cam_hex = self.camera.to_hex_coord(self.hex_size);
-        let view_radius = (self.camera.view_distance / self.hex_size).ceil() as i32 + 1;
+        let view_radius =
+            (self.camera.view_distance / self.camera.zoom() / self.hex_size).ceil() as i32 + 1;
 
         self.hexagons
             .values()
@@ -159,6 +165,33 @@ impl HexGrid {
         self.camera.move_by(dx, dy);
     }
 
+    /// Smoothly pan the camera to center a hex, if it is on the grid.
+    pub fn focus_camera_on(&mut self, coord: HexCoord) {
+        if let Some(hex) = self.hexagons.get(&coord) {
+            self.camera.pan_to(hex.world_pos);
+        }
+    }
+
+    /// Keep the camera center over the map: bound it by the centers of the
+    /// outermost hexes.
+    ///
+    /// Called by `new` and `from_tiles`; call it again after adding or
+    /// removing hexes. An empty grid leaves the camera unbounded.
+    pub fn update_camera_bounds(&mut self) {
+        let mut centers = self.hexagons.values().map(|hex| hex.world_pos);
+        let Some(first) = centers.next() else {
+            self.camera.clear_bounds();
+            return;
+        };
+        let (min, max) = centers.fold((first, first), |(min, max), pos| {
+            (
+                Vec2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Vec2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        });
+        self.camera.set_bounds(min, max);
+    }
+
     /// Get hexagon at specific coordinate (useful for game logic).
     #[allow(dead_code)]
     pub fn get_hex_at(&self, coord: HexCoord) -> Option<&Hexagon> {
@@ -178,6 +211,27 @@ impl HexGrid {
         }
     }
 
+    /// Set the team color of the unit at a coordinate.
+    pub fn set_unit_tint_at(&mut self, coord: HexCoord, tint: [f32; 3]) {
+        if let Some(hex) = self.hexagons.get_mut(&coord) {
+            hex.set_unit_tint(Some(tint));
+        }
+    }
+
+    /// Fog every hex except those in `visible`.
+    pub fn set_fog(&mut self, visible: &HashSet<HexCoord>) {
+        for (coord, hex) in self.hexagons.iter_mut() {
+            hex.set_fogged(!visible.contains(coord));
+        }
+    }
+
+    /// Lift the fog from every hex.
+    pub fn clear_fog(&mut self) {
+        for hex in self.hexagons.values_mut() {
+            hex.set_fogged(false);
+        }
+    }
+
     /// Set an item sprite at a coordinate (preserving terrain and unit sprites).
     pub fn set_item_at(&mut self, coord: HexCoord, item_sprite: SpriteType) {
         if let Some(hex) = self.hexagons.get_mut(&coord) {
@@ -192,10 +246,11 @@ impl HexGrid {
         }
     }
 
-    /// Remove unit sprite at a coordinate.
+    /// Remove unit sprite (and its team tint) at a coordinate.
     pub fn remove_unit_at(&mut self, coord: HexCoord) {
         if let Some(hex) = self.hexagons.get_mut(&coord) {
             hex.set_unit_sprite(None);
+            hex.set_unit_tint(None);
         }
     }
 
@@ -277,6 +332,7 @@ impl HexGrid {
     /// * `window_size`: Window dimensions (width, height in pixels)
     ///
     /// Returns the hex coordinate at that screen position, or `None` if out of the grid bounds.
+    /// Takes the camera position and zoom into account.
     pub fn screen_to_hex_coord(&self, screen_pos: Vec2, window_size: Vec2) -> Option<HexCoord> {
         // Convert screen coordinates to world coordinates using camera
         let world_pos = self.camera.screen_to_world(screen_pos, window_size);
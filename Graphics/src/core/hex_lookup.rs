@@ -32,7 +32,8 @@
 //! rendering or handling mouse input.
 
 use crate::core::hexagon::SpriteType;
-use crate::core::HexCoord;
+use crate::core::{Camera, HexCoord};
+use crate::math::Vec2;
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::HashMap;
@@ -269,7 +270,7 @@ impl WorldHexLookupTable {
         if !hex_map.is_empty() {
             println!("🔍 Hex world position samples:");
             for (i, hex_coord) in hex_map.keys().take(3).enumerate() {
-                let world_pos = hex_coord.to_world_pos(hex_size);
+                let world_pos = Vec2::from(hex_coord.to_pixel(hex_size));
                 println!(
                     "   [{}] {:?} -> world({:.1}, {:.1})",
                     i, hex_coord, world_pos.x, world_pos.y
@@ -312,7 +313,7 @@ impl WorldHexLookupTable {
 
         // Calculate bounds from actual hex coordinates in the map
         for hex_coord in hex_map.keys() {
-            let world_pos = hex_coord.to_world_pos(hex_size);
+            let world_pos = Vec2::from(hex_coord.to_pixel(hex_size));
 
             // Account for hex radius when calculating bounds
             let hex_radius = hex_size; // removed padding *1.1
@@ -354,7 +355,7 @@ impl WorldHexLookupTable {
 
         // Check all hexagons in the map
         for hex_coord in hex_map.keys() {
-            let hex_world_pos = hex_coord.to_world_pos(hex_size);
+            let hex_world_pos = Vec2::from(hex_coord.to_pixel(hex_size));
             let distance =
                 ((world_x - hex_world_pos.x).powi(2) + (world_y - hex_world_pos.y).powi(2)).sqrt();
 
@@ -408,6 +409,20 @@ impl WorldHexLookupTable {
         }
     }
 
+    /// Get hex coordinate under a screen position seen through `camera`
+    ///
+    /// The camera converts the position to world coordinates, so the lookup
+    /// stays correct at every pan offset and zoom level.
+    pub fn get_hex_at_screen_pos(
+        &self,
+        camera: &Camera,
+        screen_pos: Vec2,
+        window_size: Vec2,
+    ) -> Option<HexCoord> {
+        let world_pos = camera.screen_to_world(screen_pos, window_size);
+        self.get_hex_at_world_pos(world_pos.x, world_pos.y)
+    }
+
     /// Get map entry for hex coordinate
     pub fn get_hex_entry(&self, hex_coord: &HexCoord) -> Option<&MapHexEntry> {
         self.hex_entries.get(hex_coord)
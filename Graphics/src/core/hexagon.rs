@@ -1,271 +1,6 @@
+pub use super::sprite::SpriteType;
 use crate::math::Vec2;
-use serde::{Deserialize, Serialize};
-
-/// Axial coordinates for hexagonal grid (flat-top orientation).
-///
-/// `HexCoord` uses axial coordinates (q, r) where q is the column and r is the row.
-/// This type is used across the graphics and game logic to index hexagons.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct HexCoord {
-    pub q: i32, // column
-    pub r: i32, // row
-}
-
-impl HexCoord {
-    /// Construct a new `HexCoord`.
-    pub fn new(q: i32, r: i32) -> Self {
-        Self { q, r }
-    }
-
-    /// Convert axial coordinates to world position (flat-top hexagons).
-    ///
-    /// The returned `Vec2` is the pixel/world position that corresponds to the
-    /// hexagon center for rendering or spatial lookup calculations.
-    pub fn to_world_pos(self, hex_size: f32) -> Vec2 {
-        let x = hex_size * (3.0 / 2.0 * self.q as f32);
-        let y = hex_size * (3.0_f32.sqrt() * (self.r as f32 + self.q as f32 / 2.0));
-        Vec2::new(x, y)
-    }
-
-    /// Robust rounding function for axial coordinates.
-    ///
-    /// Converts fractional axial coordinates to the nearest integer `HexCoord`.
-    pub fn axial_round(q: f32, r: f32) -> Self {
-        // Convert to cube coordinates for easier rounding
-        let x = q;
-        let z = r;
-        let y = -x - z;
-
-        // Round each coordinate
-        let mut rx = x.round();
-        let mut ry = y.round();
-        let mut rz = z.round();
-
-        // Calculate rounding errors
-        let x_diff = (rx - x).abs();
-        let y_diff = (ry - y).abs();
-        let z_diff = (rz - z).abs();
-
-        // Correct the coordinate with the largest error to maintain x + y + z = 0
-        if x_diff > y_diff && x_diff > z_diff {
-            rx = -ry - rz;
-        } else if y_diff > z_diff {
-            ry = -rx - rz;
-        } else {
-            rz = -rx - ry;
-        }
-
-        // Suppress unused assignment warning for `ry` when it's only used in corrections
-        let _ = ry;
-
-        // Convert back to axial coordinates
-        Self::new(rx as i32, rz as i32)
-    }
-
-    /// Get distance between two hex coordinates.
-    #[allow(dead_code)]
-    pub fn distance(self, other: HexCoord) -> i32 {
-        ((self.q - other.q).abs()
-            + (self.q + self.r - other.q - other.r).abs()
-            + (self.r - other.r).abs())
-            / 2
-    }
-
-    /// Get neighboring coordinates (flat-top orientation).
-    #[allow(dead_code)]
-    pub fn neighbors(self) -> [HexCoord; 6] {
-        [
-            HexCoord::new(self.q, self.r - 1),     // North
-            HexCoord::new(self.q + 1, self.r - 1), // Northeast
-            HexCoord::new(self.q + 1, self.r),     // Southeast
-            HexCoord::new(self.q, self.r + 1),     // South
-            HexCoord::new(self.q - 1, self.r + 1), // Southwest
-            HexCoord::new(self.q - 1, self.r),     // Northwest
-        ]
-    }
-}
-
-/// Sprite data for hexagons (terrain, units and items).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-pub enum SpriteType {
-    None,
-    Forest,       // forest.png
-    Forest2,      // forest2.png
-    Grasslands,   // grasslands.png
-    HauntedWoods, // haunted_woods.png
-    Hills,        // hills.png
-    Mountain,     // mountain.png
-    Swamp,        // swamp.png
-    Unit,         // Generic unit (fallback, red circle)
-    Item,         // Gold/yellow circle for items
-    // Specific unit types with textures
-    DwarfWarrior, // unit_sprites/dwarf_warrior.png
-    OrcWarrior,   // unit_sprites/orc_warrior.png
-    House,        // unit_sprites/house.png
-    Wall,         // unit_sprites/wall.png
-}
-
-impl SpriteType {
-    /// Get texture coordinates for sprite (UV mapping).
-    #[allow(dead_code)]
-    pub fn get_texture_coords(self) -> [f32; 8] {
-        match self {
-            SpriteType::None => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture
-            SpriteType::Forest => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0], // Full texture for now
-            SpriteType::Forest2 => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Grasslands => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::HauntedWoods => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Hills => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Mountain => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Swamp => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Unit => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture --- IGNORE ---
-            SpriteType::Item => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture --- IGNORE ---
-            SpriteType::DwarfWarrior => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::OrcWarrior => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::House => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-            SpriteType::Wall => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
-        }
-    }
-
-    /// Get texture file path for sprite if available.
-    pub fn get_texture_path(self) -> Option<&'static str> {
-        match self {
-            SpriteType::None => None,
-            SpriteType::Forest => Some("terrain_sprites/forest.png"),
-            SpriteType::Forest2 => Some("terrain_sprites/forest2.png"),
-            SpriteType::Grasslands => Some("terrain_sprites/grasslands.png"),
-            SpriteType::HauntedWoods => Some("terrain_sprites/haunted_woods.png"),
-            SpriteType::Hills => Some("terrain_sprites/hills.png"),
-            SpriteType::Mountain => Some("terrain_sprites/mountain.png"),
-            SpriteType::Swamp => Some("terrain_sprites/swamp.png"),
-            SpriteType::Unit => None, // Generic unit - colored circle fallback
-            SpriteType::Item => Some("item_sprites/sword.png"),
-            SpriteType::DwarfWarrior => Some("unit_sprites/dwarf_warrior.png"),
-            SpriteType::OrcWarrior => Some("unit_sprites/orc_warrior.png"),
-            SpriteType::House => Some("structure_sprites/house.png"),
-            SpriteType::Wall => Some("structure_sprites/wall.png"),
-        }
-    }
-
-    /// Get color tint for sprite (fallback when textures aren't loaded).
-    pub fn get_color_tint(self) -> [f32; 3] {
-        match self {
-            SpriteType::None => [1.0, 1.0, 1.0],         // White (no tint)
-            SpriteType::Forest => [0.2, 0.7, 0.2],       // Dark green
-            SpriteType::Forest2 => [0.3, 0.8, 0.3],      // Medium green
-            SpriteType::Grasslands => [0.4, 0.9, 0.3],   // Light green
-            SpriteType::HauntedWoods => [0.4, 0.2, 0.6], // Dark purple
-            SpriteType::Hills => [0.7, 0.6, 0.4],        // Brown
-            SpriteType::Mountain => [0.6, 0.6, 0.7],     // Gray-blue
-            SpriteType::Swamp => [0.3, 0.5, 0.2],        // Dark green-brown
-            SpriteType::Unit => [0.9, 0.2, 0.2],         // Bright red for generic units
-            SpriteType::Item => [1.0, 0.84, 0.0],        // Gold/yellow for items
-            SpriteType::DwarfWarrior => [1.0, 1.0, 1.0], // White tint (show texture as-is)
-            SpriteType::OrcWarrior => [1.0, 1.0, 1.0],   // White tint
-            SpriteType::House => [1.0, 1.0, 1.0],        // White tint
-            SpriteType::Wall => [1.0, 1.0, 1.0],         // White tint
-        }
-    }
-
-    /// Get all terrain sprite types (excluding None).
-    pub fn all_terrain() -> [SpriteType; 7] {
-        [
-            SpriteType::Forest,
-            SpriteType::Forest2,
-            SpriteType::Grasslands,
-            SpriteType::HauntedWoods,
-            SpriteType::Hills,
-            SpriteType::Mountain,
-            SpriteType::Swamp,
-        ]
-    }
-
-    /// Get all unit sprite types (with textures).
-    pub fn all_units() -> [SpriteType; 2] {
-        [SpriteType::DwarfWarrior, SpriteType::OrcWarrior]
-    }
-
-    /// Get all structure sprite types (with textures).
-    pub fn all_structures() -> [SpriteType; 2] {
-        [SpriteType::House, SpriteType::Wall]
-    }
-
-    /// Get a deterministic pseudo-random terrain sprite given a seed.
-    pub fn random_terrain(seed: i32) -> SpriteType {
-        let all = Self::all_terrain();
-        all[(seed.abs() % 7) as usize]
-    }
-
-    /// Check if this sprite type is a unit (any unit type including generic)
-    pub fn is_unit(&self) -> bool {
-        matches!(
-            self,
-            SpriteType::Unit
-                | SpriteType::DwarfWarrior
-                | SpriteType::OrcWarrior
-                | SpriteType::House
-                | SpriteType::Wall
-        )
-    }
-
-    /// Check if this sprite type is a terrain type
-    pub fn is_terrain(&self) -> bool {
-        matches!(
-            self,
-            SpriteType::Forest
-                | SpriteType::Forest2
-                | SpriteType::Grasslands
-                | SpriteType::HauntedWoods
-                | SpriteType::Hills
-                | SpriteType::Mountain
-                | SpriteType::Swamp
-        )
-    }
-
-    /// Get texture array index for OpenGL shader.
-    pub fn get_texture_id(&self) -> f32 {
-        match self {
-            SpriteType::None => -1.0, // No texture
-            SpriteType::Forest => 0.0,
-            SpriteType::Forest2 => 1.0,
-            SpriteType::Grasslands => 2.0,
-            SpriteType::HauntedWoods => 3.0,
-            SpriteType::Hills => 4.0,
-            SpriteType::Mountain => 5.0,
-            SpriteType::Swamp => 6.0,
-            SpriteType::Item => 7.0, // Item texture at unit 7
-            // Unit sprite textures (slots 8-11)
-            SpriteType::DwarfWarrior => 8.0,
-            SpriteType::OrcWarrior => 9.0,
-            SpriteType::House => 10.0,
-            SpriteType::Wall => 11.0,
-            SpriteType::Unit => -1.0, // Generic unit - use color rendering fallback
-        }
-    }
-
-    /// Returns a default movement cost for this sprite/terrain.
-    ///
-    /// Costs are expressed in the same units the Game uses for movement points.
-    pub fn movement_cost(&self) -> i32 {
-        match self {
-            SpriteType::None => 1,
-            SpriteType::Forest => 2,
-            SpriteType::Forest2 => 2,
-            SpriteType::Grasslands => 1,
-            SpriteType::HauntedWoods => 2,
-            SpriteType::Hills => 3,
-            SpriteType::Mountain => 4,
-            SpriteType::Swamp => 3,
-            // Non-terrain types — default to 1 so they don't block movement cost logic
-            SpriteType::Unit => 1,
-            SpriteType::Item => 1,
-            SpriteType::DwarfWarrior => 1,
-            SpriteType::OrcWarrior => 1,
-            SpriteType::House => 1,
-            SpriteType::Wall => 1,
-        }
-    }
-}
+pub use hex::HexCoord;
 
 /// Types of highlighting applied to hexagons for UI feedback.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -275,10 +10,17 @@ pub enum HighlightType {
     MovementRange, // Blue highlight for movement range
 }
 
+/// How strongly a team tint colors a unit sprite (0 = not at all, 1 = fully).
+const UNIT_TINT_STRENGTH: f32 = 0.5;
+
+/// Brightness kept by hexes under fog of war.
+const FOG_BRIGHTNESS: f32 = 0.4;
+
 /// A renderable hexagon used by the `HexGrid` and renderer.
 ///
 /// Contains rendering state such as terrain `sprite`, optional `unit_sprite`/
-/// `item_sprite` overlays, the hex center `world_pos` and visual highlight state.
+/// `item_sprite` overlays and the unit's team tint, the hex center `world_pos`
+/// and visual highlight state. Fogged hexes are drawn darkened.
 #[derive(Clone)]
 pub struct Hexagon {
     pub coord: HexCoord,
@@ -287,15 +29,17 @@ pub struct Hexagon {
     pub sprite: SpriteType,                   // Base terrain sprite
     pub structure_sprite: Option<SpriteType>, // Optional structure sprite (rendered on terrain)
     pub unit_sprite: Option<SpriteType>,      // Optional unit sprite on top
+    pub unit_tint: Option<[f32; 3]>,          // Optional team color of the unit
     pub item_sprite: Option<SpriteType>,      // Optional item sprite on top
     pub highlight: HighlightType,             // Highlight state
     pub text_overlay: Option<String>, // Optional text to display on the tile (e.g., defense value)
+    pub fogged: bool,                 // Hidden from the viewing team by fog of war
 }
 
 impl Hexagon {
     /// Create a new `Hexagon` with sensible defaults and a deterministic seed-based terrain.
     pub fn new(coord: HexCoord, hex_size: f32) -> Self {
-        let world_pos = coord.to_world_pos(hex_size);
+        let world_pos = Vec2::from(coord.to_pixel(hex_size));
 
         // Generate base color based on coordinate for visual debugging
         let base_color = [
@@ -315,9 +59,11 @@ impl Hexagon {
             sprite,
             structure_sprite: None, // No structure by default
             unit_sprite: None,      // No unit by default
+            unit_tint: None,        // No team tint by default
             item_sprite: None,      // No item by default
             highlight: HighlightType::None,
             text_overlay: None, // No text overlay by default
+            fogged: false,      // Everything is visible by default
         }
     }
 
@@ -331,6 +77,22 @@ impl Hexagon {
         self.unit_sprite = unit_sprite;
     }
 
+    /// Set the team color blended into the unit sprite
+    pub fn set_unit_tint(&mut self, unit_tint: Option<[f32; 3]>) {
+        self.unit_tint = unit_tint;
+    }
+
+    /// Color of the unit sprite: its own tint blended with the team tint, if any
+    pub fn unit_color(&self) -> Option<[f32; 3]> {
+        let sprite_color = self.unit_sprite?.get_color_tint();
+        let Some(tint) = self.unit_tint else {
+            return Some(sprite_color);
+        };
+        Some(std::array::from_fn(|i| {
+            sprite_color[i] * (1.0 - UNIT_TINT_STRENGTH + UNIT_TINT_STRENGTH * tint[i])
+        }))
+    }
+
     /// Set item sprite (rendered on top of terrain and units)
     pub fn set_item_sprite(&mut self, item_sprite: Option<SpriteType>) {
         self.item_sprite = item_sprite;
@@ -361,6 +123,11 @@ impl Hexagon {
         self.text_overlay = None;
     }
 
+    /// Set whether the hex is under fog of war
+    pub fn set_fogged(&mut self, fogged: bool) {
+        self.fogged = fogged;
+    }
+
     /// Get the display sprite (unit takes priority, then structure, then terrain)
     pub fn get_display_sprite(&self) -> SpriteType {
         self.unit_sprite
@@ -378,8 +145,18 @@ impl Hexagon {
         self.structure_sprite.is_some()
     }
 
-    /// Get the final display color (blends terrain tint and highlights)
+    /// Get the final display color (blends terrain tint and highlights, darkened by fog)
     pub fn get_display_color(&self) -> [f32; 3] {
+        let color = self.lit_color();
+        if self.fogged {
+            color.map(|c| c * FOG_BRIGHTNESS)
+        } else {
+            color
+        }
+    }
+
+    /// Display color before fog is applied
+    fn lit_color(&self) -> [f32; 3] {
         let display_sprite = self.get_display_sprite();
 
         let base_color = if display_sprite == SpriteType::None {
@@ -2,6 +2,7 @@ pub mod camera;
 pub mod grid;
 pub mod hex_lookup;
 pub mod hexagon;
+mod sprite;
 
 pub use camera::Camera;
 pub use grid::HexGrid;
@@ -0,0 +1,211 @@
+//! Sprites and the textures and tints that draw them.
+//!
+//! Gameplay crates do not know about rendering; they name a sprite by its
+//! [`SpriteType::name`] (terrain definitions, unit types) and the renderer
+//! turns the name back into a [`SpriteType`] with [`SpriteType::from_name`].
+
+use serde::{Deserialize, Serialize};
+
+/// Sprite kinds shown on map hexes (terrain, units, structures and items).
+///
+/// The renderer maps each kind to a texture slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpriteType {
+    None,
+    Forest,       // forest.png
+    Forest2,      // forest2.png
+    Grasslands,   // grasslands.png
+    HauntedWoods, // haunted_woods.png
+    Hills,        // hills.png
+    Mountain,     // mountain.png
+    Swamp,        // swamp.png
+    Unit,         // Generic unit (fallback, red circle)
+    Item,         // Gold/yellow circle for items
+    // Specific unit types with textures
+    DwarfWarrior, // unit_sprites/dwarf_warrior.png
+    OrcWarrior,   // unit_sprites/orc_warrior.png
+    House,        // unit_sprites/house.png
+    Wall,         // unit_sprites/wall.png
+}
+
+impl SpriteType {
+    /// Every sprite type, in declaration order.
+    pub const ALL: [SpriteType; 14] = [
+        SpriteType::None,
+        SpriteType::Forest,
+        SpriteType::Forest2,
+        SpriteType::Grasslands,
+        SpriteType::HauntedWoods,
+        SpriteType::Hills,
+        SpriteType::Mountain,
+        SpriteType::Swamp,
+        SpriteType::Unit,
+        SpriteType::Item,
+        SpriteType::DwarfWarrior,
+        SpriteType::OrcWarrior,
+        SpriteType::House,
+        SpriteType::Wall,
+    ];
+
+    /// Name gameplay data and map files use for this sprite.
+    pub fn name(self) -> &'static str {
+        match self {
+            SpriteType::None => "None",
+            SpriteType::Forest => "Forest",
+            SpriteType::Forest2 => "Forest2",
+            SpriteType::Grasslands => "Grasslands",
+            SpriteType::HauntedWoods => "HauntedWoods",
+            SpriteType::Hills => "Hills",
+            SpriteType::Mountain => "Mountain",
+            SpriteType::Swamp => "Swamp",
+            SpriteType::Unit => "Unit",
+            SpriteType::Item => "Item",
+            SpriteType::DwarfWarrior => "DwarfWarrior",
+            SpriteType::OrcWarrior => "OrcWarrior",
+            SpriteType::House => "House",
+            SpriteType::Wall => "Wall",
+        }
+    }
+
+    /// Looks up a sprite by its [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sprite| sprite.name() == name)
+    }
+
+    /// Get texture coordinates for sprite (UV mapping).
+    #[allow(dead_code)]
+    pub fn get_texture_coords(self) -> [f32; 8] {
+        match self {
+            SpriteType::None => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture
+            SpriteType::Forest => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0], // Full texture for now
+            SpriteType::Forest2 => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Grasslands => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::HauntedWoods => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Hills => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Mountain => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Swamp => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Unit => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture --- IGNORE ---
+            SpriteType::Item => [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], // No texture --- IGNORE ---
+            SpriteType::DwarfWarrior => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::OrcWarrior => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::House => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            SpriteType::Wall => [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+        }
+    }
+
+    /// Get texture file path for sprite if available.
+    pub fn get_texture_path(self) -> Option<&'static str> {
+        match self {
+            SpriteType::None => None,
+            SpriteType::Forest => Some("terrain_sprites/forest.png"),
+            SpriteType::Forest2 => Some("terrain_sprites/forest2.png"),
+            SpriteType::Grasslands => Some("terrain_sprites/grasslands.png"),
+            SpriteType::HauntedWoods => Some("terrain_sprites/haunted_woods.png"),
+            SpriteType::Hills => Some("terrain_sprites/hills.png"),
+            SpriteType::Mountain => Some("terrain_sprites/mountain.png"),
+            SpriteType::Swamp => Some("terrain_sprites/swamp.png"),
+            SpriteType::Unit => None, // Generic unit - colored circle fallback
+            SpriteType::Item => Some("item_sprites/sword.png"),
+            SpriteType::DwarfWarrior => Some("unit_sprites/dwarf_warrior.png"),
+            SpriteType::OrcWarrior => Some("unit_sprites/orc_warrior.png"),
+            SpriteType::House => Some("structure_sprites/house.png"),
+            SpriteType::Wall => Some("structure_sprites/wall.png"),
+        }
+    }
+
+    /// Get color tint for sprite (fallback when textures aren't loaded).
+    pub fn get_color_tint(self) -> [f32; 3] {
+        match self {
+            SpriteType::None => [1.0, 1.0, 1.0],         // White (no tint)
+            SpriteType::Forest => [0.2, 0.7, 0.2],       // Dark green
+            SpriteType::Forest2 => [0.3, 0.8, 0.3],      // Medium green
+            SpriteType::Grasslands => [0.4, 0.9, 0.3],   // Light green
+            SpriteType::HauntedWoods => [0.4, 0.2, 0.6], // Dark purple
+            SpriteType::Hills => [0.7, 0.6, 0.4],        // Brown
+            SpriteType::Mountain => [0.6, 0.6, 0.7],     // Gray-blue
+            SpriteType::Swamp => [0.3, 0.5, 0.2],        // Dark green-brown
+            SpriteType::Unit => [0.9, 0.2, 0.2],         // Bright red for generic units
+            SpriteType::Item => [1.0, 0.84, 0.0],        // Gold/yellow for items
+            SpriteType::DwarfWarrior => [1.0, 1.0, 1.0], // White tint (show texture as-is)
+            SpriteType::OrcWarrior => [1.0, 1.0, 1.0],   // White tint
+            SpriteType::House => [1.0, 1.0, 1.0],        // White tint
+            SpriteType::Wall => [1.0, 1.0, 1.0],         // White tint
+        }
+    }
+
+    /// Get all terrain sprite types (excluding None).
+    pub fn all_terrain() -> [SpriteType; 7] {
+        [
+            SpriteType::Forest,
+            SpriteType::Forest2,
+            SpriteType::Grasslands,
+            SpriteType::HauntedWoods,
+            SpriteType::Hills,
+            SpriteType::Mountain,
+            SpriteType::Swamp,
+        ]
+    }
+
+    /// Get all unit sprite types (with textures).
+    pub fn all_units() -> [SpriteType; 2] {
+        [SpriteType::DwarfWarrior, SpriteType::OrcWarrior]
+    }
+
+    /// Get all structure sprite types (with textures).
+    pub fn all_structures() -> [SpriteType; 2] {
+        [SpriteType::House, SpriteType::Wall]
+    }
+
+    /// Get a deterministic pseudo-random terrain sprite given a seed.
+    pub fn random_terrain(seed: i32) -> SpriteType {
+        let all = Self::all_terrain();
+        all[(seed.abs() % 7) as usize]
+    }
+
+    /// Check if this sprite type is a unit (any unit type including generic)
+    pub fn is_unit(&self) -> bool {
+        matches!(
+            self,
+            SpriteType::Unit
+                | SpriteType::DwarfWarrior
+                | SpriteType::OrcWarrior
+                | SpriteType::House
+                | SpriteType::Wall
+        )
+    }
+
+    /// Check if this sprite type is a terrain type
+    pub fn is_terrain(&self) -> bool {
+        matches!(
+            self,
+            SpriteType::Forest
+                | SpriteType::Forest2
+                | SpriteType::Grasslands
+                | SpriteType::HauntedWoods
+                | SpriteType::Hills
+                | SpriteType::Mountain
+                | SpriteType::Swamp
+        )
+    }
+
+    /// Get texture array index for OpenGL shader.
+    pub fn get_texture_id(&self) -> f32 {
+        match self {
+            SpriteType::None => -1.0, // No texture
+            SpriteType::Forest => 0.0,
+            SpriteType::Forest2 => 1.0,
+            SpriteType::Grasslands => 2.0,
+            SpriteType::HauntedWoods => 3.0,
+            SpriteType::Hills => 4.0,
+            SpriteType::Mountain => 5.0,
+            SpriteType::Swamp => 6.0,
+            SpriteType::Item => 7.0, // Item texture at unit 7
+            // Unit sprite textures (slots 8-11)
+            SpriteType::DwarfWarrior => 8.0,
+            SpriteType::OrcWarrior => 9.0,
+            SpriteType::House => 10.0,
+            SpriteType::Wall => 11.0,
+            SpriteType::Unit => -1.0, // Generic unit - use color rendering fallback
+        }
+    }
+}
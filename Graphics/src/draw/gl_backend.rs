@@ -0,0 +1,555 @@
+//! OpenGL Backend
+//!
+//! Draws a [`DrawList`] into the current OpenGL context. Consecutive commands
+//! of the same kind are batched into one draw call:
+//!
+//! - Rectangles, borders and text share a pipeline whose texture is the
+//!   [`GlyphAtlas`] of the shared [`FontStack`]; shapes sample its solid
+//!   block. Glyphs are rasterized on first use and the texture is re-uploaded
+//!   before the next draw call that needs them
+//! - Hexagons and sprites use the textured hexagon shader from
+//!   [`setup_dynamic_hexagons`](crate::rendering::setup_dynamic_hexagons)
+//!   and the sprite textures of a [`TextureManager`], once
+//!   [`GlBackend::set_sprite_pipeline`] provides them
+//!
+//! Commands are drawn in list order with depth testing off and alpha blending
+//! on.
+
+use super::text::{FontStack, GlyphAtlas};
+use super::{Color, DrawBackend, DrawCommand, DrawList};
+use crate::core::SpriteType;
+use crate::rendering::TextureManager;
+use gl::types::*;
+use std::ffi::CString;
+
+/// Side of the glyph atlas texture
+const ATLAS_SIZE: usize = 1024;
+
+/// Floats per vertex of the UI pipeline: position, texture coordinates, color
+const UI_VERTEX_FLOATS: usize = 8;
+
+/// Floats per vertex of the sprite pipeline: position with depth, texture
+/// coordinates, texture id, color
+const SPRITE_VERTEX_FLOATS: usize = 9;
+
+/// Which pipeline the pending vertices belong to
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Batch {
+    Ui,
+    Sprites,
+}
+
+/// Textured hexagon shader with the sprite textures it samples
+struct SpritePipeline {
+    vao: GLuint,
+    shader_program: GLuint,
+    vbo: GLuint,
+    textures: TextureManager,
+}
+
+/// Draw backend rendering with OpenGL.
+pub struct GlBackend {
+    screen_width: f32,
+    screen_height: f32,
+    shader_program: GLuint,
+    vao: GLuint,
+    vbo: GLuint,
+    font_texture: GLuint,
+    glyphs: GlyphAtlas,
+    /// Texture coordinates of the atlas' solid block
+    solid_uv: [f32; 2],
+    sprites: Option<SpritePipeline>,
+    ui_vertices: Vec<f32>,
+    sprite_vertices: Vec<f32>,
+}
+
+impl GlBackend {
+    /// Creates the UI pipeline and glyph atlas in the current OpenGL context.
+    ///
+    /// Hexagons and sprites are skipped until
+    /// [`GlBackend::set_sprite_pipeline`] is called.
+    pub fn new(screen_width: f32, screen_height: f32) -> Result<Self, String> {
+        let (shader_program, vao, vbo) = unsafe { Self::setup_ui_pipeline()? };
+        let glyphs = GlyphAtlas::new(ATLAS_SIZE, ATLAS_SIZE);
+        let font_texture = unsafe { Self::create_atlas_texture(&glyphs) };
+        let solid = glyphs.solid_region();
+        let solid_uv = [
+            (solid.x as f32 + solid.width as f32 / 2.0) / ATLAS_SIZE as f32,
+            (solid.y as f32 + solid.height as f32 / 2.0) / ATLAS_SIZE as f32,
+        ];
+
+        Ok(Self {
+            screen_width,
+            screen_height,
+            shader_program,
+            vao,
+            vbo,
+            font_texture,
+            glyphs,
+            solid_uv,
+            sprites: None,
+            ui_vertices: Vec::new(),
+            sprite_vertices: Vec::new(),
+        })
+    }
+
+    /// Uses the textured hexagon shader and its textures for hexagons and
+    /// sprites.
+    ///
+    /// # Arguments
+    ///
+    /// * `vao`, `shader_program`, `vbo` - Resources from
+    ///   [`setup_dynamic_hexagons`](crate::rendering::setup_dynamic_hexagons)
+    /// * `textures` - Loaded sprite textures
+    pub fn set_sprite_pipeline(
+        &mut self,
+        vao: GLuint,
+        shader_program: GLuint,
+        vbo: GLuint,
+        textures: TextureManager,
+    ) {
+        unsafe {
+            // Texture units 0-6 hold terrain, 7 items and 8-11 units and structures
+            gl::UseProgram(shader_program);
+            for i in 0..12 {
+                let uniform_name = CString::new(format!("textures[{}]", i)).unwrap();
+                let location = gl::GetUniformLocation(shader_program, uniform_name.as_ptr());
+                if location != -1 {
+                    gl::Uniform1i(location, i);
+                }
+            }
+        }
+        self.sprites = Some(SpritePipeline {
+            vao,
+            shader_program,
+            vbo,
+            textures,
+        });
+    }
+
+    /// Updates the size of the drawing surface (e.g. on window resize).
+    pub fn set_screen_size(&mut self, screen_width: f32, screen_height: f32) {
+        self.screen_width = screen_width;
+        self.screen_height = screen_height;
+    }
+
+    unsafe fn setup_ui_pipeline() -> Result<(GLuint, GLuint, GLuint), String> {
+        let vertex_src = r#"
+            #version 330 core
+            layout (location = 0) in vec2 position;
+            layout (location = 1) in vec2 texCoords;
+            layout (location = 2) in vec4 color;
+            out vec2 TexCoords;
+            out vec4 Color;
+            uniform vec2 screenSize;
+
+            void main() {
+                vec2 ndc = (position / screenSize) * 2.0 - 1.0;
+                ndc.y = -ndc.y;
+                gl_Position = vec4(ndc, 0.0, 1.0);
+                TexCoords = texCoords;
+                Color = color;
+            }
+        "#;
+
+        let fragment_src = r#"
+            #version 330 core
+            in vec2 TexCoords;
+            in vec4 Color;
+            out vec4 FragColor;
+            uniform sampler2D fontTexture;
+
+            void main() {
+                float coverage = texture(fontTexture, TexCoords).r;
+                FragColor = vec4(Color.rgb, Color.a * coverage);
+            }
+        "#;
+
+        let shader_program = compile_program(vertex_src, fragment_src)?;
+
+        let mut vao = 0;
+        let mut vbo = 0;
+        gl::GenVertexArrays(1, &mut vao);
+        gl::GenBuffers(1, &mut vbo);
+        gl::BindVertexArray(vao);
+        gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+
+        let stride = (UI_VERTEX_FLOATS * std::mem::size_of::<f32>()) as GLsizei;
+        for (location, size, offset) in [(0, 2, 0), (1, 2, 2), (2, 4, 4)] {
+            gl::VertexAttribPointer(
+                location,
+                size,
+                gl::FLOAT,
+                gl::FALSE,
+                stride,
+                (offset * std::mem::size_of::<f32>()) as *const _,
+            );
+            gl::EnableVertexAttribArray(location);
+        }
+        gl::BindVertexArray(0);
+
+        Ok((shader_program, vao, vbo))
+    }
+
+    /// Creates the single-channel texture the glyph atlas is uploaded to.
+    unsafe fn create_atlas_texture(atlas: &GlyphAtlas) -> GLuint {
+        let mut texture = 0;
+        gl::GenTextures(1, &mut texture);
+        gl::BindTexture(gl::TEXTURE_2D, texture);
+        gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+        gl::TexImage2D(
+            gl::TEXTURE_2D,
+            0,
+            gl::RED as i32,
+            atlas.width() as i32,
+            atlas.height() as i32,
+            0,
+            gl::RED,
+            gl::UNSIGNED_BYTE,
+            atlas.pixels().as_ptr() as *const _,
+        );
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as i32);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as i32);
+        // Glyph quads are pixel-aligned, so texels map one-to-one to pixels
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST as i32);
+        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::NEAREST as i32);
+        texture
+    }
+
+    /// Queues the glyphs of a line of text, caching glyphs the atlas lacks.
+    fn push_text(&mut self, text: &str, x: f32, y: f32, size: f32, color: Color) {
+        let fonts = FontStack::shared();
+        for glyph in fonts.layout(text, x, y, size) {
+            // When the atlas is full, draw what uses the cached glyphs and
+            // start the cache over
+            let region = self.glyphs.region(fonts, glyph.key).or_else(|| {
+                self.flush_ui();
+                self.glyphs.reset();
+                self.glyphs.region(fonts, glyph.key)
+            });
+            let Some(region) = region else {
+                continue;
+            };
+            let size = ATLAS_SIZE as f32;
+            self.push_ui_quad(
+                [
+                    glyph.x,
+                    glyph.y,
+                    glyph.x + region.width as f32,
+                    glyph.y + region.height as f32,
+                ],
+                [
+                    region.x as f32 / size,
+                    region.y as f32 / size,
+                    (region.x + region.width) as f32 / size,
+                    (region.y + region.height) as f32 / size,
+                ],
+                color,
+            );
+        }
+    }
+
+    fn push_ui_quad(
+        &mut self,
+        [x0, y0, x1, y1]: [f32; 4],
+        [u0, v0, u1, v1]: [f32; 4],
+        color: Color,
+    ) {
+        let [r, g, b, a] = color;
+        for (x, y, u, v) in [
+            (x0, y0, u0, v0),
+            (x1, y0, u1, v0),
+            (x0, y1, u0, v1),
+            (x1, y0, u1, v0),
+            (x1, y1, u1, v1),
+            (x0, y1, u0, v1),
+        ] {
+            self.ui_vertices
+                .extend_from_slice(&[x, y, u, v, r, g, b, a]);
+        }
+    }
+
+    fn push_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        let [u, v] = self.solid_uv;
+        self.push_ui_quad([x, y, x + width, y + height], [u, v, u, v], color);
+    }
+
+    /// Converts screen pixels to normalized device coordinates.
+    fn to_ndc(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            (x / self.screen_width) * 2.0 - 1.0,
+            1.0 - (y / self.screen_height) * 2.0,
+        )
+    }
+
+    fn push_sprite_vertex(
+        &mut self,
+        (x, y): (f32, f32),
+        (u, v): (f32, f32),
+        sprite: SpriteType,
+        tint: [f32; 3],
+    ) {
+        let (x, y) = self.to_ndc(x, y);
+        let [r, g, b] = tint;
+        self.sprite_vertices.extend_from_slice(&[
+            x,
+            y,
+            0.0,
+            u,
+            v,
+            sprite.get_texture_id(),
+            r,
+            g,
+            b,
+        ]);
+    }
+
+    /// Adds a command's vertices to its batch, flushing the other batch first
+    /// so commands stay in order.
+    fn queue(&mut self, command: &DrawCommand) {
+        let batch = match command {
+            DrawCommand::Sprite { .. } | DrawCommand::Hexagon { .. } => Batch::Sprites,
+            _ => Batch::Ui,
+        };
+        match batch {
+            Batch::Ui if !self.sprite_vertices.is_empty() => self.flush_sprites(),
+            Batch::Sprites if !self.ui_vertices.is_empty() => self.flush_ui(),
+            _ => {}
+        }
+
+        match command {
+            DrawCommand::Rect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => self.push_rect(*x, *y, *width, *height, *color),
+            DrawCommand::Border {
+                x,
+                y,
+                width,
+                height,
+                thickness,
+                color,
+            } => {
+                let t = thickness.min(*width / 2.0).min(*height / 2.0);
+                self.push_rect(*x, *y, *width, t, *color);
+                self.push_rect(*x, y + height - t, *width, t, *color);
+                self.push_rect(*x, y + t, t, height - 2.0 * t, *color);
+                self.push_rect(x + width - t, y + t, t, height - 2.0 * t, *color);
+            }
+            DrawCommand::Text {
+                text,
+                x,
+                y,
+                size,
+                color,
+            } => {
+                self.push_text(text, *x, *y, *size, *color);
+            }
+            DrawCommand::Sprite {
+                sprite,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let (x1, y1) = (x + width, y + height);
+                for (position, uv) in [
+                    ((*x, *y), (0.0, 0.0)),
+                    ((x1, *y), (1.0, 0.0)),
+                    ((*x, y1), (0.0, 1.0)),
+                    ((x1, *y), (1.0, 0.0)),
+                    ((x1, y1), (1.0, 1.0)),
+                    ((*x, y1), (0.0, 1.0)),
+                ] {
+                    self.push_sprite_vertex(position, uv, *sprite, [1.0, 1.0, 1.0]);
+                }
+            }
+            DrawCommand::Hexagon {
+                x,
+                y,
+                radius_x,
+                radius_y,
+                sprite,
+                tint,
+            } => {
+                let corner = |i: usize| {
+                    let angle = i as f32 * std::f32::consts::PI / 3.0;
+                    (
+                        (x + radius_x * angle.cos(), y - radius_y * angle.sin()),
+                        (0.5 + 0.4 * angle.cos(), 1.0 - (0.5 + 0.4 * angle.sin())),
+                    )
+                };
+                for i in 0..6 {
+                    let (a, a_uv) = corner(i);
+                    let (b, b_uv) = corner(i + 1);
+                    self.push_sprite_vertex((*x, *y), (0.5, 0.5), *sprite, *tint);
+                    self.push_sprite_vertex(a, a_uv, *sprite, *tint);
+                    self.push_sprite_vertex(b, b_uv, *sprite, *tint);
+                }
+            }
+        }
+    }
+
+    fn flush_ui(&mut self) {
+        if self.ui_vertices.is_empty() {
+            return;
+        }
+        unsafe {
+            gl::UseProgram(self.shader_program);
+            let screen_loc = gl::GetUniformLocation(self.shader_program, c"screenSize".as_ptr());
+            gl::Uniform2f(screen_loc, self.screen_width, self.screen_height);
+            let texture_loc = gl::GetUniformLocation(self.shader_program, c"fontTexture".as_ptr());
+            gl::Uniform1i(texture_loc, 0);
+            gl::ActiveTexture(gl::TEXTURE0);
+            gl::BindTexture(gl::TEXTURE_2D, self.font_texture);
+            if self.glyphs.take_dirty() {
+                gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+                gl::TexSubImage2D(
+                    gl::TEXTURE_2D,
+                    0,
+                    0,
+                    0,
+                    self.glyphs.width() as i32,
+                    self.glyphs.height() as i32,
+                    gl::RED,
+                    gl::UNSIGNED_BYTE,
+                    self.glyphs.pixels().as_ptr() as *const _,
+                );
+            }
+
+            gl::BindVertexArray(self.vao);
+            upload(self.vbo, &self.ui_vertices);
+            let vertex_count = (self.ui_vertices.len() / UI_VERTEX_FLOATS) as GLsizei;
+            gl::DrawArrays(gl::TRIANGLES, 0, vertex_count);
+            gl::BindVertexArray(0);
+        }
+        self.ui_vertices.clear();
+    }
+
+    fn flush_sprites(&mut self) {
+        if let Some(pipeline) = &self.sprites {
+            unsafe {
+                pipeline.textures.bind_all_textures();
+                gl::UseProgram(pipeline.shader_program);
+                gl::BindVertexArray(pipeline.vao);
+                upload(pipeline.vbo, &self.sprite_vertices);
+                let vertex_count = (self.sprite_vertices.len() / SPRITE_VERTEX_FLOATS) as GLsizei;
+                gl::DrawArrays(gl::TRIANGLES, 0, vertex_count);
+                gl::BindVertexArray(0);
+            }
+        }
+        self.sprite_vertices.clear();
+    }
+}
+
+impl DrawBackend for GlBackend {
+    fn submit(&mut self, list: &DrawList) {
+        unsafe {
+            gl::Disable(gl::DEPTH_TEST);
+            gl::Enable(gl::BLEND);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+        }
+        for command in list.commands() {
+            self.queue(command);
+        }
+        self.flush_ui();
+        self.flush_sprites();
+    }
+
+    fn clear(&mut self, color: Color) {
+        let [r, g, b, a] = color;
+        unsafe {
+            gl::ClearColor(r, g, b, a);
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+        }
+    }
+
+    fn screen_size(&self) -> (f32, f32) {
+        (self.screen_width, self.screen_height)
+    }
+}
+
+impl Drop for GlBackend {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteTextures(1, &self.font_texture);
+            gl::DeleteProgram(self.shader_program);
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteVertexArrays(1, &self.vao);
+        }
+    }
+}
+
+/// Uploads vertices into a buffer for drawing.
+unsafe fn upload(vbo: GLuint, vertices: &[f32]) {
+    gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+    gl::BufferData(
+        gl::ARRAY_BUFFER,
+        std::mem::size_of_val(vertices) as GLsizeiptr,
+        vertices.as_ptr() as *const _,
+        gl::DYNAMIC_DRAW,
+    );
+}
+
+/// Compiles and links a shader program, returning the info log on failure.
+unsafe fn compile_program(vertex_src: &str, fragment_src: &str) -> Result<GLuint, String> {
+    let compile = |kind: GLenum, source: &str| -> Result<GLuint, String> {
+        let shader = gl::CreateShader(kind);
+        let c_str = CString::new(source).unwrap();
+        gl::ShaderSource(shader, 1, &c_str.as_ptr(), std::ptr::null());
+        gl::CompileShader(shader);
+
+        let mut success = gl::FALSE as GLint;
+        gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
+        if success != gl::TRUE as GLint {
+            let mut len = 0;
+            gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut len);
+            let mut log = vec![0u8; len.max(1) as usize];
+            gl::GetShaderInfoLog(
+                shader,
+                len,
+                std::ptr::null_mut(),
+                log.as_mut_ptr() as *mut _,
+            );
+            gl::DeleteShader(shader);
+            return Err(format!(
+                "Shader compilation failed: {}",
+                String::from_utf8_lossy(&log)
+            ));
+        }
+        Ok(shader)
+    };
+
+    let vertex_shader = compile(gl::VERTEX_SHADER, vertex_src)?;
+    let fragment_shader = compile(gl::FRAGMENT_SHADER, fragment_src)?;
+
+    let shader_program = gl::CreateProgram();
+    gl::AttachShader(shader_program, vertex_shader);
+    gl::AttachShader(shader_program, fragment_shader);
+    gl::LinkProgram(shader_program);
+    gl::DeleteShader(vertex_shader);
+    gl::DeleteShader(fragment_shader);
+
+    let mut success = gl::FALSE as GLint;
+    gl::GetProgramiv(shader_program, gl::LINK_STATUS, &mut success);
+    if success != gl::TRUE as GLint {
+        let mut len = 0;
+        gl::GetProgramiv(shader_program, gl::INFO_LOG_LENGTH, &mut len);
+        let mut log = vec![0u8; len.max(1) as usize];
+        gl::GetProgramInfoLog(
+            shader_program,
+            len,
+            std::ptr::null_mut(),
+            log.as_mut_ptr() as *mut _,
+        );
+        gl::DeleteProgram(shader_program);
+        return Err(format!(
+            "Shader program linking failed: {}",
+            String::from_utf8_lossy(&log)
+        ));
+    }
+    Ok(shader_program)
+}
@@ -0,0 +1,320 @@
+//! # Draw Commands
+//!
+//! Backend-agnostic drawing. The map renderer and every UI panel describe what
+//! they show as a [`DrawList`] of [`DrawCommand`]s, and a [`DrawBackend`] turns
+//! the list into pixels:
+//!
+//! - [`GlBackend`] draws into the current OpenGL context (the game)
+//! - [`SoftwareBackend`] rasterizes on the CPU into an RGBA image that can be
+//!   saved as a PNG, so rendering and UI layout can be tested without a GPU
+//!
+//! Commands use screen pixels with the origin at the top-left corner and `y`
+//! growing down, and are drawn in list order, later commands on top. Text is
+//! drawn with the TrueType fonts of [`text::FontStack::shared`], so both
+//! backends draw the same glyphs and panels can measure text exactly with
+//! [`text::text_width`].
+//!
+//! ```rust
+//! use graphics::draw::{DrawBackend, DrawList, SoftwareBackend};
+//!
+//! let mut list = DrawList::new();
+//! list.rect(10.0, 10.0, 100.0, 40.0, [0.2, 0.2, 0.3, 1.0]);
+//! list.text("Hello", 20.0, 20.0, 14.0, [1.0, 1.0, 1.0, 1.0]);
+//!
+//! let mut backend = SoftwareBackend::new(200, 100);
+//! backend.submit(&list);
+//! assert_ne!(backend.pixel(15, 15), backend.pixel(150, 80));
+//! ```
+
+pub mod gl_backend;
+pub mod software;
+pub mod text;
+
+pub use gl_backend::GlBackend;
+pub use software::SoftwareBackend;
+
+use crate::core::SpriteType;
+
+/// RGBA color with components in `0.0..=1.0`
+pub type Color = [f32; 4];
+
+/// Background color of the game map
+pub const CLEAR_COLOR: Color = [0.05, 0.05, 0.1, 1.0];
+
+/// One drawing operation, in screen pixels.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    /// Filled rectangle
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: Color,
+    },
+    /// Rectangle outline drawn inside the given bounds
+    Border {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        thickness: f32,
+        color: Color,
+    },
+    /// Line of text with its top-left corner at (`x`, `y`); `size` is the
+    /// font size in pixels
+    Text {
+        text: String,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+    },
+    /// Sprite stretched over a rectangle
+    Sprite {
+        sprite: SpriteType,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    /// Flat-top hexagon centered on (`x`, `y`) showing a sprite tinted by
+    /// `tint`.
+    ///
+    /// The radii are the center-to-corner distances along each axis, which
+    /// differ when the map is stretched to a non-square window. Textured
+    /// sprites are multiplied by the tint, [`SpriteType::Unit`] and
+    /// [`SpriteType::None`] draw a tinted disc, and other sprites without an
+    /// image draw the plain tint.
+    Hexagon {
+        x: f32,
+        y: f32,
+        radius_x: f32,
+        radius_y: f32,
+        sprite: SpriteType,
+        tint: [f32; 3],
+    },
+}
+
+/// Ordered list of draw commands making up (part of) a frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DrawList {
+    commands: Vec<DrawCommand>,
+}
+
+impl DrawList {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Commands in drawing order.
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Number of commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Whether the list has no commands.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Removes every command.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Appends a command.
+    pub fn push(&mut self, command: DrawCommand) {
+        self.commands.push(command);
+    }
+
+    /// Appends a filled rectangle.
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        self.push(DrawCommand::Rect {
+            x,
+            y,
+            width,
+            height,
+            color,
+        });
+    }
+
+    /// Appends a rectangle outline `thickness` pixels wide.
+    pub fn border(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        thickness: f32,
+        color: Color,
+    ) {
+        self.push(DrawCommand::Border {
+            x,
+            y,
+            width,
+            height,
+            thickness,
+            color,
+        });
+    }
+
+    /// Appends a line of text.
+    pub fn text(&mut self, text: impl Into<String>, x: f32, y: f32, size: f32, color: Color) {
+        self.push(DrawCommand::Text {
+            text: text.into(),
+            x,
+            y,
+            size,
+            color,
+        });
+    }
+
+    /// Appends a line of text with a drop shadow `offset` pixels down-right.
+    pub fn shadowed_text(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+        offset: f32,
+    ) {
+        self.text(
+            text,
+            x + offset,
+            y + offset,
+            size,
+            [0.0, 0.0, 0.0, color[3]],
+        );
+        self.text(text, x, y, size, color);
+    }
+
+    /// Appends a sprite stretched over a rectangle.
+    pub fn sprite(&mut self, sprite: SpriteType, x: f32, y: f32, width: f32, height: f32) {
+        self.push(DrawCommand::Sprite {
+            sprite,
+            x,
+            y,
+            width,
+            height,
+        });
+    }
+
+    /// Appends a sprite-filled hexagon.
+    pub fn hexagon(
+        &mut self,
+        (x, y): (f32, f32),
+        (radius_x, radius_y): (f32, f32),
+        sprite: SpriteType,
+        tint: [f32; 3],
+    ) {
+        self.push(DrawCommand::Hexagon {
+            x,
+            y,
+            radius_x,
+            radius_y,
+            sprite,
+            tint,
+        });
+    }
+
+    /// Appends every command of another list.
+    pub fn append(&mut self, other: &DrawList) {
+        self.commands.extend_from_slice(&other.commands);
+    }
+
+    /// Appends the commands of another list, scaled by `scale` around the
+    /// top-left corner of the screen.
+    pub fn append_scaled(&mut self, other: &DrawList, scale: f32) {
+        self.commands
+            .extend(other.commands.iter().map(|command| match command.clone() {
+                DrawCommand::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => DrawCommand::Rect {
+                    x: x * scale,
+                    y: y * scale,
+                    width: width * scale,
+                    height: height * scale,
+                    color,
+                },
+                DrawCommand::Border {
+                    x,
+                    y,
+                    width,
+                    height,
+                    thickness,
+                    color,
+                } => DrawCommand::Border {
+                    x: x * scale,
+                    y: y * scale,
+                    width: width * scale,
+                    height: height * scale,
+                    thickness: thickness * scale,
+                    color,
+                },
+                DrawCommand::Text {
+                    text,
+                    x,
+                    y,
+                    size,
+                    color,
+                } => DrawCommand::Text {
+                    text,
+                    x: x * scale,
+                    y: y * scale,
+                    size: size * scale,
+                    color,
+                },
+                DrawCommand::Sprite {
+                    sprite,
+                    x,
+                    y,
+                    width,
+                    height,
+                } => DrawCommand::Sprite {
+                    sprite,
+                    x: x * scale,
+                    y: y * scale,
+                    width: width * scale,
+                    height: height * scale,
+                },
+                DrawCommand::Hexagon {
+                    x,
+                    y,
+                    radius_x,
+                    radius_y,
+                    sprite,
+                    tint,
+                } => DrawCommand::Hexagon {
+                    x: x * scale,
+                    y: y * scale,
+                    radius_x: radius_x * scale,
+                    radius_y: radius_y * scale,
+                    sprite,
+                    tint,
+                },
+            }));
+    }
+}
+
+/// Something that can draw a [`DrawList`].
+pub trait DrawBackend {
+    /// Draws the commands of `list` in order, on top of what is already drawn.
+    fn submit(&mut self, list: &DrawList);
+
+    /// Fills the whole surface with `color`, discarding what is drawn.
+    fn clear(&mut self, color: Color);
+
+    /// Size of the drawing surface in pixels.
+    fn screen_size(&self) -> (f32, f32);
+}
@@ -0,0 +1,263 @@
+//! Software Backend
+//!
+//! CPU rasterizer drawing a [`DrawList`] into an RGBA image. It needs no GPU or
+//! window, so screenshot tests can render the map and the UI in CI and compare
+//! the result with golden PNGs.
+//!
+//! Pixels are covered when their centers fall inside a shape, and colors are
+//! alpha-blended over what is already drawn, like the OpenGL backend. Sprite
+//! images are optional: until [`SoftwareBackend::load_sprites`] is called,
+//! sprites draw as their flat tint, which keeps screenshots independent of the
+//! art assets. Text is rasterized from the shared [`FontStack`] with the same
+//! glyph cache as the OpenGL backend.
+
+use super::text::{FontStack, GlyphAtlas};
+use super::{Color, DrawBackend, DrawCommand, DrawList, CLEAR_COLOR};
+use crate::core::SpriteType;
+use crate::rendering::texture_manager::load_sprite_image;
+use image::{Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Half the height of a flat-top hexagon with a circumradius of 1
+const HALF_HEIGHT: f32 = 0.866_025_4;
+
+/// Side of the glyph cache image
+const ATLAS_SIZE: usize = 512;
+
+/// Draw backend rendering into an in-memory image.
+pub struct SoftwareBackend {
+    image: RgbaImage,
+    sprites: HashMap<SpriteType, RgbaImage>,
+    glyphs: GlyphAtlas,
+}
+
+impl SoftwareBackend {
+    /// Creates a `width` x `height` image filled with [`CLEAR_COLOR`].
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            image: RgbaImage::from_pixel(width, height, to_rgba(CLEAR_COLOR)),
+            sprites: HashMap::new(),
+            glyphs: GlyphAtlas::new(ATLAS_SIZE, ATLAS_SIZE),
+        }
+    }
+
+    /// Loads the images of every textured sprite, so hexagons and sprites
+    /// draw their art instead of a flat tint.
+    pub fn load_sprites(&mut self) -> Result<(), String> {
+        let sprites = SpriteType::all_terrain()
+            .into_iter()
+            .chain([SpriteType::Item])
+            .chain(SpriteType::all_units())
+            .chain(SpriteType::all_structures());
+        for sprite in sprites {
+            if let Some(image) = load_sprite_image(sprite)? {
+                self.sprites.insert(sprite, image);
+            }
+        }
+        Ok(())
+    }
+
+    /// The rendered image.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// RGBA value of one pixel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.image.get_pixel(x, y).0
+    }
+
+    /// Saves the image as a PNG.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        self.image
+            .save(path)
+            .map_err(|e| format!("Failed to save {}: {}", path.display(), e))
+    }
+
+    fn draw(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::Rect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => self.fill_rect(*x, *y, *width, *height, *color),
+            DrawCommand::Border {
+                x,
+                y,
+                width,
+                height,
+                thickness,
+                color,
+            } => {
+                let t = thickness.min(*width / 2.0).min(*height / 2.0);
+                self.fill_rect(*x, *y, *width, t, *color);
+                self.fill_rect(*x, y + height - t, *width, t, *color);
+                self.fill_rect(*x, y + t, t, height - 2.0 * t, *color);
+                self.fill_rect(x + width - t, y + t, t, height - 2.0 * t, *color);
+            }
+            DrawCommand::Text {
+                text,
+                x,
+                y,
+                size,
+                color,
+            } => {
+                let fonts = FontStack::shared();
+                for glyph in fonts.layout(text, *x, *y, *size) {
+                    // Nothing waits on the cache here, so a full cache just starts over
+                    let region = self.glyphs.region(fonts, glyph.key).or_else(|| {
+                        self.glyphs.reset();
+                        self.glyphs.region(fonts, glyph.key)
+                    });
+                    let Some(region) = region else {
+                        continue;
+                    };
+                    for row in 0..region.height {
+                        for column in 0..region.width {
+                            let coverage = self.glyphs.coverage(region.x + column, region.y + row);
+                            let (px, py) = (glyph.x + column as f32, glyph.y + row as f32);
+                            if coverage == 0 || !self.on_image(px, py) {
+                                continue;
+                            }
+                            let [r, g, b, a] = *color;
+                            self.blend(px + 0.5, py + 0.5, [r, g, b, a * coverage as f32 / 255.0]);
+                        }
+                    }
+                }
+            }
+            DrawCommand::Sprite {
+                sprite,
+                x,
+                y,
+                width,
+                height,
+            } => {
+                for (px, py) in self.centers(*x, *y, *width, *height) {
+                    let u = (px - x) / width;
+                    let v = (py - y) / height;
+                    if let Some(color) = self.sprite_color(*sprite, u, v, [1.0, 1.0, 1.0]) {
+                        self.blend(px, py, color);
+                    }
+                }
+            }
+            DrawCommand::Hexagon {
+                x,
+                y,
+                radius_x,
+                radius_y,
+                sprite,
+                tint,
+            } => {
+                let (left, top) = (x - radius_x, y - radius_y * HALF_HEIGHT);
+                let (width, height) = (radius_x * 2.0, radius_y * HALF_HEIGHT * 2.0);
+                for (px, py) in self.centers(left, top, width, height) {
+                    let dx = (px - x) / radius_x;
+                    let dy = (py - y) / radius_y;
+                    let inside = dy.abs() <= HALF_HEIGHT
+                        && dx.abs() * HALF_HEIGHT * 2.0 + dy.abs() <= HALF_HEIGHT * 2.0;
+                    if !inside {
+                        continue;
+                    }
+                    // Same texture mapping as the OpenGL hexagon fans
+                    let (u, v) = (0.5 + 0.4 * dx, 0.5 + 0.4 * dy);
+                    if let Some(color) = self.sprite_color(*sprite, u, v, *tint) {
+                        self.blend(px, py, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Color of a sprite at texture coordinates (`u`, `v`), or `None` where the
+    /// sprite is transparent.
+    fn sprite_color(&self, sprite: SpriteType, u: f32, v: f32, tint: [f32; 3]) -> Option<Color> {
+        if let Some(image) = self.sprites.get(&sprite) {
+            let tx = ((u * image.width() as f32) as u32).min(image.width() - 1);
+            let ty = ((v * image.height() as f32) as u32).min(image.height() - 1);
+            let [r, g, b, a] = image.get_pixel(tx, ty).0.map(|c| c as f32 / 255.0);
+            return Some([r * tint[0], g * tint[1], b * tint[2], a]);
+        }
+        if matches!(sprite, SpriteType::Unit | SpriteType::None) {
+            // Generic units are a disc mixing the tint with red
+            let distance = ((u - 0.5).powi(2) + (v - 0.5).powi(2)).sqrt();
+            return (distance < 0.4).then(|| {
+                let [r, g, b] = tint;
+                [r * 0.7 + 0.27, g * 0.7 + 0.06, b * 0.7 + 0.06, 1.0]
+            });
+        }
+        Some([tint[0], tint[1], tint[2], 1.0])
+    }
+
+    fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        for (px, py) in self.centers(x, y, width, height) {
+            self.blend(px, py, color);
+        }
+    }
+
+    /// Blends a color over the pixel whose center is at (`px`, `py`).
+    fn blend(&mut self, px: f32, py: f32, color: Color) {
+        let pixel = self.image.get_pixel_mut(px as u32, py as u32);
+        let alpha = color[3].clamp(0.0, 1.0);
+        for (channel, value) in pixel.0.iter_mut().zip(color).take(3) {
+            let below = *channel as f32 / 255.0;
+            let blended = value * alpha + below * (1.0 - alpha);
+            *channel = (blended.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        let below = pixel.0[3] as f32 / 255.0;
+        pixel.0[3] = ((alpha + below * (1.0 - alpha)) * 255.0).round() as u8;
+    }
+
+    /// Whether the pixel whose top-left corner is at (`px`, `py`) is in the image.
+    fn on_image(&self, px: f32, py: f32) -> bool {
+        px >= 0.0 && py >= 0.0 && px < self.image.width() as f32 && py < self.image.height() as f32
+    }
+
+    /// Centers of the image pixels inside a rectangle.
+    fn centers(&self, x: f32, y: f32, width: f32, height: f32) -> Vec<(f32, f32)> {
+        let columns = pixel_range(x, width, self.image.width());
+        let rows = pixel_range(y, height, self.image.height());
+        rows.flat_map(|row| {
+            columns
+                .clone()
+                .map(move |column| (column as f32 + 0.5, row as f32 + 0.5))
+        })
+        .collect()
+    }
+}
+
+impl DrawBackend for SoftwareBackend {
+    fn submit(&mut self, list: &DrawList) {
+        for command in list.commands() {
+            self.draw(command);
+        }
+    }
+
+    fn clear(&mut self, color: Color) {
+        let pixel = to_rgba(color);
+        self.image.pixels_mut().for_each(|p| *p = pixel);
+    }
+
+    fn screen_size(&self) -> (f32, f32) {
+        (self.image.width() as f32, self.image.height() as f32)
+    }
+}
+
+/// Pixel indices whose centers lie in `start..start + length`, clipped to
+/// `0..limit`.
+fn pixel_range(start: f32, length: f32, limit: u32) -> std::ops::Range<u32> {
+    let first = (start - 0.5).ceil().max(0.0);
+    let end = (start + length - 0.5).ceil().clamp(0.0, limit as f32);
+    (first.min(end) as u32)..(end as u32)
+}
+
+fn to_rgba(color: Color) -> Rgba<u8> {
+    Rgba(color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+}
@@ -0,0 +1,476 @@
+//! Text
+//!
+//! TrueType/OpenType text shared by every backend:
+//!
+//! - [`FontStack`] - A primary font and fallbacks tried in order for
+//!   characters the primary lacks; [`FontStack::shared`] is the stack all
+//!   panels and backends use, DejaVu Sans plus any symbol fonts found on the
+//!   system
+//! - Kerned layout and measurement ([`text_width`], [`wrap_text`],
+//!   [`truncate_text`]) so panels can center and wrap text exactly as it draws
+//! - [`GlyphAtlas`] - Rasterized glyphs packed into one single-channel image,
+//!   cached per font, glyph and size; the OpenGL backend uploads it as a
+//!   texture
+//!
+//! Text `size` is the font size in pixels (the em height) and text `y` is the
+//! top of the line: the baseline sits one ascent below it.
+
+use fontdue::{Font, FontSettings};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// The bundled primary font, DejaVu Sans (see `fonts/LICENSE`)
+pub const DEFAULT_FONT: &[u8] = include_bytes!("../../fonts/DejaVuSans.ttf");
+
+/// Symbol and emoji fonts appended to the shared stack when installed
+#[cfg(target_os = "windows")]
+const SYSTEM_FALLBACKS: &[&str] = &[
+    "C:\\Windows\\Fonts\\seguiemj.ttf",
+    "C:\\Windows\\Fonts\\seguisym.ttf",
+];
+
+/// Symbol and emoji fonts appended to the shared stack when installed
+#[cfg(target_os = "macos")]
+const SYSTEM_FALLBACKS: &[&str] = &[
+    "/System/Library/Fonts/Apple Symbols.ttf",
+    "/Library/Fonts/Symbola.ttf",
+];
+
+/// Symbol and emoji fonts appended to the shared stack when installed
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const SYSTEM_FALLBACKS: &[&str] = &[
+    "/usr/share/fonts/truetype/noto/NotoEmoji-Regular.ttf",
+    "/usr/share/fonts/noto/NotoEmoji-Regular.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansSymbols2-Regular.ttf",
+    "/usr/share/fonts/noto/NotoSansSymbols2-Regular.ttf",
+    "/usr/share/fonts/truetype/ancient-scripts/Symbola_hint.ttf",
+    "/usr/share/fonts/TTF/Symbola.ttf",
+];
+
+/// Largest font size glyphs are rasterized at
+const MAX_SIZE: f32 = 256.0;
+
+/// Appended to text shortened by [`truncate_text`]
+const ELLIPSIS: &str = "…";
+
+static SHARED: OnceLock<FontStack> = OnceLock::new();
+
+/// A glyph of one font in the stack
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlyphId {
+    /// Position of the font in the stack (0 is the primary font)
+    pub font: usize,
+    /// Glyph index in that font; 0 is the font's missing-glyph box
+    pub index: u16,
+}
+
+/// A glyph rasterized at one size, the unit the atlas caches
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub glyph: GlyphId,
+    /// Font size in whole pixels
+    pub size: u16,
+}
+
+/// A glyph placed by [`FontStack::layout`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionedGlyph {
+    pub key: GlyphKey,
+    /// Left edge of the glyph bitmap in pixels
+    pub x: f32,
+    /// Top edge of the glyph bitmap in pixels
+    pub y: f32,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// Fonts tried in order for each character.
+pub struct FontStack {
+    fonts: Vec<Font>,
+}
+
+impl FontStack {
+    /// Creates a stack whose primary font is parsed from TTF/OTF data.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        Ok(Self {
+            fonts: vec![parse_font(data)?],
+        })
+    }
+
+    /// Creates a stack with the bundled DejaVu Sans as its only font.
+    pub fn bundled() -> Self {
+        Self::from_bytes(DEFAULT_FONT).expect("the bundled font is valid")
+    }
+
+    /// Adds a fallback font, tried after the fonts already in the stack.
+    pub fn push_fallback(&mut self, data: &[u8]) -> Result<(), String> {
+        self.fonts.push(parse_font(data)?);
+        Ok(())
+    }
+
+    /// Adds a fallback font loaded from a TTF/OTF file.
+    pub fn push_fallback_file(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .map_err(|e| format!("Failed to read font {}: {}", path.display(), e))?;
+        self.push_fallback(&data)
+    }
+
+    /// Adds the symbol and emoji fonts found on this system as fallbacks.
+    ///
+    /// Returns how many were added.
+    pub fn push_system_fallbacks(&mut self) -> usize {
+        SYSTEM_FALLBACKS
+            .iter()
+            .filter(|path| Path::new(path).exists())
+            .filter(|path| self.push_fallback_file(path).is_ok())
+            .count()
+    }
+
+    /// Number of fonts in the stack, the primary included.
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Whether the stack has no fonts (never true; stacks start with a primary font).
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+
+    /// The stack panels and backends draw and measure text with.
+    ///
+    /// Unless [`FontStack::install`] ran first, this is the bundled font
+    /// followed by the system fallbacks.
+    pub fn shared() -> &'static FontStack {
+        SHARED.get_or_init(|| {
+            let mut stack = Self::bundled();
+            stack.push_system_fallbacks();
+            stack
+        })
+    }
+
+    /// Makes `stack` the shared stack. Fails, handing the stack back, once
+    /// text has been drawn or measured with the shared stack.
+    pub fn install(stack: FontStack) -> Result<(), FontStack> {
+        SHARED.set(stack)
+    }
+
+    /// The glyph drawn for a character: from the first font that has it, or
+    /// the primary font's missing-glyph box.
+    pub fn resolve(&self, ch: char) -> GlyphId {
+        self.fonts
+            .iter()
+            .enumerate()
+            .find_map(|(font, face)| {
+                let index = face.lookup_glyph_index(ch);
+                (index != 0).then_some(GlyphId { font, index })
+            })
+            .unwrap_or(GlyphId { font: 0, index: 0 })
+    }
+
+    /// Distance in pixels from the top of a line to its baseline.
+    pub fn ascent(&self, size: f32) -> f32 {
+        let size = glyph_size(size) as f32;
+        self.fonts[0]
+            .horizontal_line_metrics(size)
+            .map_or(size, |metrics| metrics.ascent)
+            .round()
+    }
+
+    /// Distance in pixels between the tops of consecutive lines.
+    pub fn line_height(&self, size: f32) -> f32 {
+        let size = glyph_size(size) as f32;
+        self.fonts[0]
+            .horizontal_line_metrics(size)
+            .map_or(size * 1.2, |metrics| metrics.new_line_size)
+            .ceil()
+    }
+
+    /// Places the glyphs of one line of text whose top-left corner is
+    /// (`x`, `y`), applying kerning between glyphs of the same font.
+    ///
+    /// Glyphs without ink (spaces) are left out.
+    pub fn layout(&self, text: &str, x: f32, y: f32, size: f32) -> Vec<PositionedGlyph> {
+        let mut glyphs = Vec::new();
+        let baseline = (y + self.ascent(size)).round();
+        self.walk(text, size, |glyph, pen_x, metrics| {
+            if metrics.width > 0 && metrics.height > 0 {
+                glyphs.push(PositionedGlyph {
+                    key: GlyphKey {
+                        glyph,
+                        size: glyph_size(size),
+                    },
+                    x: (x + pen_x + metrics.xmin as f32).round(),
+                    y: baseline - (metrics.ymin + metrics.height as i32) as f32,
+                    width: metrics.width,
+                    height: metrics.height,
+                });
+            }
+        });
+        glyphs
+    }
+
+    /// Width in pixels of one line of text.
+    pub fn measure(&self, text: &str, size: f32) -> f32 {
+        let mut width = 0.0;
+        self.walk(text, size, |_, pen_x, metrics| {
+            width = pen_x + metrics.advance_width;
+        });
+        width
+    }
+
+    /// Breaks text into lines no wider than `max_width`, at spaces where
+    /// possible. Explicit line breaks are kept; words wider than a line are
+    /// split between characters.
+    pub fn wrap(&self, text: &str, size: f32, max_width: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{} {}", line, word)
+                };
+                if self.measure(&candidate, size) <= max_width {
+                    line = candidate;
+                    continue;
+                }
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                // Split words that do not fit on a line of their own
+                for ch in word.chars() {
+                    line.push(ch);
+                    if self.measure(&line, size) > max_width && line.chars().count() > 1 {
+                        line.pop();
+                        lines.push(std::mem::replace(&mut line, ch.to_string()));
+                    }
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Shortens text to fit `max_width`, ending it with an ellipsis when
+    /// characters had to be removed.
+    pub fn truncate(&self, text: &str, size: f32, max_width: f32) -> String {
+        if self.measure(text, size) <= max_width {
+            return text.to_string();
+        }
+        let mut shortened: String = text.to_string();
+        while shortened.pop().is_some() {
+            let candidate = format!("{}{}", shortened.trim_end(), ELLIPSIS);
+            if self.measure(&candidate, size) <= max_width {
+                return candidate;
+            }
+        }
+        String::new()
+    }
+
+    /// Coverage bitmap (one byte per pixel, row by row) of a glyph.
+    pub fn rasterize(&self, key: GlyphKey) -> (usize, usize, Vec<u8>) {
+        let font = &self.fonts[key.glyph.font];
+        let (metrics, coverage) = font.rasterize_indexed(key.glyph.index, key.size as f32);
+        (metrics.width, metrics.height, coverage)
+    }
+
+    /// Calls `visit` with each glyph, the pen position before it and its metrics.
+    fn walk(&self, text: &str, size: f32, mut visit: impl FnMut(GlyphId, f32, &fontdue::Metrics)) {
+        let size = glyph_size(size) as f32;
+        let mut pen_x = 0.0;
+        let mut previous: Option<GlyphId> = None;
+        for ch in text.chars().filter(|ch| !is_invisible(*ch)) {
+            let glyph = self.resolve(ch);
+            let font = &self.fonts[glyph.font];
+            if let Some(previous) = previous.filter(|previous| previous.font == glyph.font) {
+                pen_x += font
+                    .horizontal_kern_indexed(previous.index, glyph.index, size)
+                    .unwrap_or(0.0);
+            }
+            let metrics = font.metrics_indexed(glyph.index, size);
+            visit(glyph, pen_x, &metrics);
+            pen_x += metrics.advance_width;
+            previous = Some(glyph);
+        }
+    }
+}
+
+/// Width in pixels of one line of text drawn with the shared font stack.
+pub fn text_width(text: &str, size: f32) -> f32 {
+    FontStack::shared().measure(text, size)
+}
+
+/// [`FontStack::wrap`] with the shared font stack.
+pub fn wrap_text(text: &str, size: f32, max_width: f32) -> Vec<String> {
+    FontStack::shared().wrap(text, size, max_width)
+}
+
+/// [`FontStack::truncate`] with the shared font stack.
+pub fn truncate_text(text: &str, size: f32, max_width: f32) -> String {
+    FontStack::shared().truncate(text, size, max_width)
+}
+
+/// Font size in whole pixels, the sizes glyphs are cached at.
+fn glyph_size(size: f32) -> u16 {
+    size.clamp(1.0, MAX_SIZE).round() as u16
+}
+
+/// Characters that only select how neighbors render (emoji variation
+/// selectors, joiners) and draw nothing on their own.
+fn is_invisible(ch: char) -> bool {
+    matches!(ch, '\u{200B}'..='\u{200F}' | '\u{FE00}'..='\u{FE0F}') || ch.is_control()
+}
+
+fn parse_font(data: &[u8]) -> Result<Font, String> {
+    Font::from_bytes(data, FontSettings::default())
+        .map_err(|e| format!("Failed to load font: {}", e))
+}
+
+/// Part of the atlas image holding one glyph
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AtlasRegion {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// Cache of rasterized glyphs packed into one coverage image.
+///
+/// Glyphs are packed on shelves (rows as tall as their tallest glyph) with a
+/// pixel of padding. The top-left corner holds a solid block that backends
+/// sample to fill shapes. When the image is full, [`GlyphAtlas::region`]
+/// returns `None`; the caller draws what it has queued, calls
+/// [`GlyphAtlas::reset`] and asks again.
+pub struct GlyphAtlas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+    regions: HashMap<GlyphKey, AtlasRegion>,
+    shelf_x: usize,
+    shelf_y: usize,
+    shelf_height: usize,
+    dirty: bool,
+}
+
+/// Side of the solid block in the atlas' top-left corner
+const SOLID_SIZE: usize = 2;
+
+/// Gap between packed glyphs
+const PADDING: usize = 1;
+
+impl GlyphAtlas {
+    /// Creates an empty `width` x `height` atlas.
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut atlas = Self {
+            width,
+            height,
+            pixels: vec![0; width * height],
+            regions: HashMap::new(),
+            shelf_x: 0,
+            shelf_y: 0,
+            shelf_height: 0,
+            dirty: true,
+        };
+        atlas.reset();
+        atlas
+    }
+
+    /// Removes every glyph, keeping the solid block.
+    pub fn reset(&mut self) {
+        self.pixels.fill(0);
+        for y in 0..SOLID_SIZE {
+            self.pixels[y * self.width..y * self.width + SOLID_SIZE].fill(255);
+        }
+        self.regions.clear();
+        self.shelf_x = SOLID_SIZE + PADDING;
+        self.shelf_y = 0;
+        self.shelf_height = SOLID_SIZE;
+        self.dirty = true;
+    }
+
+    /// Width of the atlas image.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the atlas image.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Coverage values, row by row.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Coverage at one pixel of the atlas.
+    pub fn coverage(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    /// The fully covered block shapes are filled with.
+    pub fn solid_region(&self) -> AtlasRegion {
+        AtlasRegion {
+            x: 0,
+            y: 0,
+            width: SOLID_SIZE,
+            height: SOLID_SIZE,
+        }
+    }
+
+    /// Number of cached glyphs.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Whether no glyph is cached.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Returns whether the image changed since the last call.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+
+    /// Where a glyph is in the atlas, rasterizing and packing it on first use.
+    ///
+    /// Returns `None` when the atlas has no room left for it.
+    pub fn region(&mut self, fonts: &FontStack, key: GlyphKey) -> Option<AtlasRegion> {
+        if let Some(region) = self.regions.get(&key) {
+            return Some(*region);
+        }
+
+        let (width, height, coverage) = fonts.rasterize(key);
+        if self.shelf_x + width > self.width {
+            // Start a new shelf below the current one
+            self.shelf_y += self.shelf_height + PADDING;
+            self.shelf_x = 0;
+            self.shelf_height = 0;
+        }
+        if self.shelf_x + width > self.width || self.shelf_y + height > self.height {
+            return None;
+        }
+
+        let region = AtlasRegion {
+            x: self.shelf_x,
+            y: self.shelf_y,
+            width,
+            height,
+        };
+        for row in 0..height {
+            let start = (region.y + row) * self.width + region.x;
+            self.pixels[start..start + width]
+                .copy_from_slice(&coverage[row * width..(row + 1) * width]);
+        }
+        self.shelf_x += width + PADDING;
+        self.shelf_height = self.shelf_height.max(height);
+        self.regions.insert(key, region);
+        self.dirty = true;
+        Some(region)
+    }
+}
@@ -5,6 +5,7 @@
 //! This crate provides:
 //! - Core types for hexagonal maps and coordinate transforms (`core`)
 //! - Math primitives used by rendering and gameplay (`math`)
+//! - Backend-agnostic draw commands with OpenGL and software backends (`draw`)
 //! - Renderer and shader helpers (`rendering`)
 //! - Simple UI widgets used by the demo app (`ui`)
 //!
@@ -21,6 +22,7 @@
 //!
 //! The crate re-exports commonly-used types from submodules for convenience.
 pub mod core;
+pub mod draw;
 pub mod math;
 pub mod movement_animation;
 pub mod rendering;
@@ -30,12 +32,15 @@ pub mod ui;
 pub use core::{
     Camera, HexCoord, HexGrid, Hexagon, HighlightType, SpriteType, WorldHexLookupTable,
 };
+pub use draw::{DrawBackend, DrawCommand, DrawList, GlBackend, SoftwareBackend};
 pub use math::Vec2;
 pub use movement_animation::{find_path, UnitAnimation};
 pub use rendering::{
-    setup_dynamic_hexagons, AttackOption, CombatConfirmation, MenuAction, Renderer,
+    setup_dynamic_hexagons, AttackForecast, AttackOption, CombatConfirmation, CombatLogEntryType,
+    MenuAction, Renderer,
 };
 pub use ui::{
-    AttackDisplayInfo, EncyclopediaCategory, EncyclopediaPanel, RecruitmentPanel, SubmenuPanel,
+    AiInspectorPanel, AttackDisplayInfo, EncyclopediaCategory, EncyclopediaPanel, EvolutionPanel,
+    InventoryPanel, ItemUsePanel, Minimap, RecruitmentPanel, SubmenuPanel, TimeOfDayDisplayInfo,
     UiPanel, UnitDisplayInfo,
 };
@@ -79,7 +79,6 @@ impl ApplicationHandler for App {
 
         unsafe {
             gl::Viewport(0, 0, 1200, 800);
-            gl::ClearColor(0.05, 0.05, 0.1, 1.0);
         }
 
         let (vao, shader_program, dynamic_vbo) = unsafe { setup_dynamic_hexagons() };
@@ -115,29 +114,27 @@ impl ApplicationHandler for App {
             WindowEvent::CloseRequested => {
                 event_loop.exit();
             }
-            WindowEvent::KeyboardInput { event, .. } => {
-                if event.state == winit::event::ElementState::Pressed {
-                    let move_speed = 0.1;
-                    match event.physical_key {
-                        winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowUp) => {
-                            self.hex_grid.move_camera(0.0, move_speed);
-                        }
-                        winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowDown) => {
-                            self.hex_grid.move_camera(0.0, -move_speed);
-                        }
-                        winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowLeft) => {
-                            self.hex_grid.move_camera(-move_speed, 0.0);
-                        }
-                        winit::keyboard::PhysicalKey::Code(
-                            winit::keyboard::KeyCode::ArrowRight,
-                        ) => {
-                            self.hex_grid.move_camera(move_speed, 0.0);
-                        }
-                        _ => {}
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == winit::event::ElementState::Pressed =>
+            {
+                let move_speed = 0.1;
+                match event.physical_key {
+                    winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowUp) => {
+                        self.hex_grid.move_camera(0.0, move_speed);
                     }
-                    if let Some(window) = &self.window {
-                        window.request_redraw();
+                    winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowDown) => {
+                        self.hex_grid.move_camera(0.0, -move_speed);
                     }
+                    winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowLeft) => {
+                        self.hex_grid.move_camera(-move_speed, 0.0);
+                    }
+                    winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowRight) => {
+                        self.hex_grid.move_camera(move_speed, 0.0);
+                    }
+                    _ => {}
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
                 }
             }
             WindowEvent::RedrawRequested => {
@@ -22,6 +22,13 @@ impl Vec2 {
     }
 }
 
+// Conversion from an (x, y) tuple, e.g. `HexCoord::to_pixel`
+impl From<(f32, f32)> for Vec2 {
+    fn from((x, y): (f32, f32)) -> Vec2 {
+        Vec2::new(x, y)
+    }
+}
+
 // Addition
 impl std::ops::Add for Vec2 {
     type Output = Vec2;
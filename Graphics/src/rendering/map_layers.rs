@@ -0,0 +1,174 @@
+//! Map Layers
+//!
+//! Builds the draw commands for the hex map: terrain, structures, units,
+//! items and tile text overlays, bottom to top.
+
+use crate::core::{HexGrid, Hexagon, SpriteType};
+use crate::draw::text::text_width;
+use crate::draw::DrawList;
+use crate::math::Vec2;
+
+/// Hexagon scale of structures, relative to the terrain hexagon
+const STRUCTURE_SCALE: f32 = 0.7;
+
+/// Hexagon scale of units
+const UNIT_SCALE: f32 = 0.6;
+
+/// Hexagon scale of items on an empty hex
+const ITEM_SCALE: f32 = 0.5;
+
+/// Hexagon scale of items shown next to a unit
+const ITEM_BESIDE_UNIT_SCALE: f32 = 0.25;
+
+/// Font size of tile text overlays (e.g. defense values)
+const TILE_TEXT_SIZE: f32 = 24.0;
+
+/// Appends the visible part of the map as seen by the grid's camera.
+///
+/// # Arguments
+///
+/// * `list` - Draw list to append to
+/// * `hex_grid` - Map to draw
+/// * `screen_width`, `screen_height` - Size of the window in pixels
+pub fn draw_map(list: &mut DrawList, hex_grid: &HexGrid, screen_width: f32, screen_height: f32) {
+    let mut visible_hexagons = hex_grid.get_visible_hexagons();
+    // Hash map order varies between runs; sort so frames are reproducible
+    visible_hexagons.sort_by_key(|hex| (hex.coord.q, hex.coord.r));
+
+    let view = MapView {
+        hex_grid,
+        screen_width,
+        screen_height,
+    };
+
+    // Terrain (bottom layer)
+    for hex in &visible_hexagons {
+        if hex.sprite != SpriteType::None {
+            view.hexagon(
+                list,
+                hex,
+                (0.0, 0.0),
+                1.0,
+                hex.sprite,
+                hex.get_display_color(),
+            );
+        }
+    }
+
+    // Structures, on top of terrain
+    for hex in &visible_hexagons {
+        if let Some(structure_sprite) = hex.structure_sprite {
+            view.hexagon(
+                list,
+                hex,
+                (0.0, 0.0),
+                STRUCTURE_SCALE,
+                structure_sprite,
+                structure_sprite.get_color_tint(),
+            );
+        }
+    }
+
+    // Units, tinted with their team color
+    for hex in &visible_hexagons {
+        if let Some(unit_sprite) = hex.unit_sprite {
+            if unit_sprite != SpriteType::Item {
+                let tint = hex
+                    .unit_color()
+                    .unwrap_or_else(|| unit_sprite.get_color_tint());
+                view.hexagon(list, hex, (0.0, 0.0), UNIT_SCALE, unit_sprite, tint);
+            }
+        }
+    }
+
+    // Items, moved to the upper-right corner when a unit stands on them
+    for hex in &visible_hexagons {
+        if hex.item_sprite != Some(SpriteType::Item) {
+            continue;
+        }
+        let (scale, offset) = if hex.unit_sprite == Some(SpriteType::Unit) {
+            let distance = hex_grid.hex_size * 0.4;
+            let angle = std::f32::consts::PI / 6.0;
+            (
+                ITEM_BESIDE_UNIT_SCALE,
+                (distance * angle.cos(), -distance * angle.sin()),
+            )
+        } else {
+            (ITEM_SCALE, (0.0, 0.0))
+        };
+        view.hexagon(
+            list,
+            hex,
+            offset,
+            scale,
+            SpriteType::Item,
+            SpriteType::Item.get_color_tint(),
+        );
+    }
+
+    // Tile text overlays with a black outline for contrast
+    let window_size = Vec2::new(screen_width, screen_height);
+    for hex in &visible_hexagons {
+        let Some(text) = &hex.text_overlay else {
+            continue;
+        };
+        let screen_pos = hex_grid.camera.world_to_screen(hex.world_pos, window_size);
+        let x = screen_pos.x - text_width(text, TILE_TEXT_SIZE) / 2.0;
+        let y = screen_pos.y - TILE_TEXT_SIZE / 2.0;
+
+        for dx in [-2.0, -1.0, 0.0, 1.0, 2.0] {
+            for dy in [-2.0, -1.0, 0.0, 1.0, 2.0] {
+                if dx != 0.0 || dy != 0.0 {
+                    list.text(
+                        text.as_str(),
+                        x + dx,
+                        y + dy,
+                        TILE_TEXT_SIZE,
+                        [0.0, 0.0, 0.0, 1.0],
+                    );
+                }
+            }
+        }
+        list.text(text.as_str(), x, y, TILE_TEXT_SIZE, [1.0, 1.0, 0.0, 1.0]);
+    }
+}
+
+/// Converts map positions (normalized device coordinates relative to the
+/// camera, scaled by its zoom) to screen pixels.
+struct MapView<'a> {
+    hex_grid: &'a HexGrid,
+    screen_width: f32,
+    screen_height: f32,
+}
+
+impl MapView<'_> {
+    /// Appends a hexagon at `scale` times the hex size, shifted from the hex
+    /// center by `offset` in map units (`y` up).
+    fn hexagon(
+        &self,
+        list: &mut DrawList,
+        hex: &Hexagon,
+        offset: (f32, f32),
+        scale: f32,
+        sprite: SpriteType,
+        tint: [f32; 3],
+    ) {
+        let camera = &self.hex_grid.camera;
+        let zoom = camera.zoom();
+        let center_x = (hex.world_pos.x - camera.position.x + offset.0) * zoom;
+        let center_y = (hex.world_pos.y - camera.position.y + offset.1) * zoom;
+        let radius = self.hex_grid.hex_size * scale * zoom;
+        list.hexagon(
+            (
+                (center_x + 1.0) * 0.5 * self.screen_width,
+                (1.0 - center_y) * 0.5 * self.screen_height,
+            ),
+            (
+                radius * 0.5 * self.screen_width,
+                radius * 0.5 * self.screen_height,
+            ),
+            sprite,
+            tint,
+        );
+    }
+}
@@ -1,9 +1,11 @@
 // pub mod guide_display; // Guide display logic moved to encyclopedia_panel.rs
+pub mod map_layers;
 pub mod renderer;
 pub mod shaders;
 pub mod texture_manager;
 pub mod vertex_buffer;
 
+pub use map_layers::draw_map;
 pub use renderer::*;
 pub use shaders::setup_dynamic_hexagons;
 pub use texture_manager::TextureManager;
@@ -1,11 +1,16 @@
-use crate::core::{HexGrid, Hexagon};
-use crate::rendering::{TextureManager, VertexBuffer};
-use crate::ui::combat_panel::CombatPanel;
-use crate::ui::TextRenderer;
+use crate::core::HexGrid;
+use crate::draw::text::text_width;
+use crate::draw::{DrawBackend, DrawList, GlBackend, CLEAR_COLOR};
+use crate::rendering::map_layers::draw_map;
+use crate::rendering::TextureManager;
+use crate::ui::combat_panel::{
+    CombatPanel, ATTACK_OPTION, COMBAT_CANCEL, COMBAT_DIALOG, COMBAT_OK,
+};
+use crate::ui::widget::{Align, Anchor, ButtonStyle, Insets, Length, Rect, Ui, Widget, WidgetId};
+use crate::ui::Minimap;
 // use crate::rendering::guide_display::*;
 // ...existing code...
 use gl::types::*;
-use std::ffi::CString;
 
 // GuideEntry and GuideDisplay now handled in encyclopedia_panel.rs
 
@@ -20,43 +25,38 @@ pub enum MenuAction {
     ExitGame,
 }
 
-/// Menu button with position and size
+/// Entry of the game menu
 #[derive(Clone, Debug)]
 pub struct MenuButton {
     pub label: String,
     pub action: MenuAction,
-    pub position: (f32, f32), // Screen position (x, y)
-    pub size: (f32, f32),     // Width and height
-    pub hovered: bool,
     pub enabled: bool,
 }
 
 impl MenuButton {
-    pub fn new(label: impl Into<String>, action: MenuAction, position: (f32, f32)) -> Self {
+    pub fn new(label: impl Into<String>, action: MenuAction) -> Self {
         Self {
             label: label.into(),
             action,
-            position,
-            size: (200.0, 50.0), // Default button size
-            hovered: false,
             enabled: true,
         }
     }
-
-    /// Check if a point (screen coordinates) is inside the button
-    pub fn contains_point(&self, x: f32, y: f32) -> bool {
-        let (bx, by) = self.position;
-        let (bw, bh) = self.size;
-        x >= bx && x <= bx + bw && y >= by && y <= by + bh
-    }
 }
 
+/// The game menu's panel
+const MENU_PANEL: WidgetId = WidgetId::new("menu_panel");
+
+/// One of the game menu's buttons
+const MENU_BUTTON: WidgetId = WidgetId::new("menu_button");
+
 /// Menu display state
 pub struct MenuDisplay {
     pub active: bool,
     pub buttons: Vec<MenuButton>,
     pub position: (f32, f32), // Menu panel position
     pub size: (f32, f32),     // Menu panel size
+    /// Laid-out menu
+    ui: Ui,
 }
 
 impl Default for MenuDisplay {
@@ -66,72 +66,86 @@ impl Default for MenuDisplay {
 }
 
 impl MenuDisplay {
+    /// Creates the menu laid out for a 1920x1080 window; call
+    /// [`MenuDisplay::update_for_screen_size`] with the actual size
     pub fn new() -> Self {
-        // Create default menu buttons - will be repositioned when window size is known
-        // Initial positions are placeholders
-        let center_x = 500.0;
-        let start_y = 150.0;
-        let button_spacing = 60.0;
-
         let buttons = vec![
-            MenuButton::new("Continue", MenuAction::Continue, (center_x, start_y)),
-            MenuButton::new(
-                "Settings",
-                MenuAction::Settings,
-                (center_x, start_y + button_spacing),
-            ),
-            MenuButton::new(
-                "Save",
-                MenuAction::Save,
-                (center_x, start_y + button_spacing * 2.0),
-            ),
-            MenuButton::new(
-                "Load",
-                MenuAction::Load,
-                (center_x, start_y + button_spacing * 3.0),
-            ),
-            MenuButton::new(
-                "Exit to Main Menu",
-                MenuAction::ExitToMainMenu,
-                (center_x, start_y + button_spacing * 4.0),
-            ),
-            MenuButton::new(
-                "Exit Game",
-                MenuAction::ExitGame,
-                (center_x, start_y + button_spacing * 5.0),
-            ),
+            MenuButton::new("Continue", MenuAction::Continue),
+            MenuButton::new("Settings", MenuAction::Settings),
+            MenuButton::new("Save Game", MenuAction::Save),
+            MenuButton::new("Load Game", MenuAction::Load),
+            MenuButton::new("Main Menu", MenuAction::ExitToMainMenu),
+            MenuButton::new("Exit Game", MenuAction::ExitGame),
         ];
 
-        Self {
+        let mut menu = Self {
             active: false,
             buttons,
-            position: (300.0, 100.0),
+            position: (0.0, 0.0),
             size: (600.0, 600.0),
-        }
+            ui: Ui::empty(1920.0, 1080.0),
+        };
+        menu.rebuild();
+        menu
+    }
+
+    /// Rebuilds the widget tree after the buttons changed
+    pub fn rebuild(&mut self) {
+        let style = ButtonStyle {
+            hovered_text: [1.0, 1.0, 0.8, 1.0], // Light yellow when hovered
+            ..ButtonStyle::default()
+        };
+        let buttons = self.buttons.iter().enumerate().map(|(index, button)| {
+            Widget::button(
+                WidgetId::indexed(MENU_BUTTON.name, index),
+                button.label.as_str(),
+                22.0,
+                style,
+            )
+            .size(300.0, 50.0)
+            .enabled(button.enabled)
+        });
+
+        let panel = Widget::column(10.0)
+            .with_id(MENU_PANEL)
+            .size(600.0, 600.0)
+            .anchor(Anchor::Center)
+            .align(Align::Center)
+            .padding(Insets::new(30.0, 0.0, 0.0, 0.0))
+            .background([0.15, 0.15, 0.2, 0.95])
+            .child(
+                Widget::label("GAME MENU", 35.0, [1.0, 0.9, 0.4, 1.0]) // Gold color
+                    .height(Length::Fixed(60.0)),
+            )
+            .children(buttons);
+
+        // Dim the whole screen behind the menu
+        self.ui.set_root(
+            Widget::stack()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .background([0.0, 0.0, 0.0, 0.7])
+                .child(panel),
+        );
+        self.update_bounds();
+    }
+
+    fn update_bounds(&mut self) {
+        let panel = self.ui.rect(MENU_PANEL).unwrap_or_default();
+        self.position = (panel.x, panel.y);
+        self.size = (panel.width, panel.height);
     }
 
     /// Update menu positions based on actual window dimensions
     pub fn update_for_screen_size(&mut self, screen_width: f32, screen_height: f32) {
-        // Center the menu panel on screen
-        let panel_width = 600.0;
-        let panel_height = 600.0;
-        self.position = (
-            (screen_width - panel_width) / 2.0,
-            (screen_height - panel_height) / 2.0,
-        );
-        self.size = (panel_width, panel_height);
-
-        // Position buttons within the panel
-        let button_width = 300.0;
-        let button_height = 50.0;
-        let button_start_x = self.position.0 + (panel_width - button_width) / 2.0;
-        let button_start_y = self.position.1 + 100.0;
-        let button_spacing = 60.0;
-
-        for (i, button) in self.buttons.iter_mut().enumerate() {
-            button.position = (button_start_x, button_start_y + i as f32 * button_spacing);
-            button.size = (button_width, button_height);
-        }
+        self.ui.resize(screen_width, screen_height);
+        self.update_bounds();
+    }
+
+    /// Draws the menu `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.update_bounds();
     }
 
     pub fn show(&mut self) {
@@ -148,19 +162,51 @@ impl MenuDisplay {
 
     /// Update hover state for buttons based on mouse position
     pub fn update_hover(&mut self, mouse_x: f32, mouse_y: f32) {
-        for button in &mut self.buttons {
-            button.hovered = button.contains_point(mouse_x, mouse_y);
-        }
+        self.ui.cursor_moved(mouse_x, mouse_y);
+    }
+
+    /// Where a button is on the screen
+    pub fn button_rect(&self, index: usize) -> Option<Rect> {
+        self.ui.rect(WidgetId::indexed(MENU_BUTTON.name, index))
     }
 
     /// Get the action of the button at the given position, if any
     pub fn get_button_action(&self, x: f32, y: f32) -> Option<MenuAction> {
-        for button in &self.buttons {
-            if button.enabled && button.contains_point(x, y) {
-                return Some(button.action.clone());
-            }
+        self.ui
+            .hit_test(x, y)
+            .filter(|id| id.name == MENU_BUTTON.name)
+            .and_then(|id| self.buttons.get(id.index))
+            .map(|button| button.action.clone())
+    }
+
+    /// Appends the menu overlay, panel and buttons (nothing when the menu is
+    /// hidden).
+    pub fn draw(&self, list: &mut DrawList) {
+        if self.active {
+            self.ui.draw(list);
         }
-        None
+    }
+}
+
+/// Text floating up from a hex, such as a damage number.
+#[derive(Clone, Debug)]
+pub struct FloatingText {
+    pub text: String,
+    pub hex: crate::core::HexCoord,
+    pub color: [f32; 3],
+    /// Seconds since the text appeared
+    pub age: f32,
+}
+
+impl FloatingText {
+    /// Seconds a floating text stays on screen
+    pub const LIFETIME: f32 = 1.2;
+    /// Pixels a floating text rises over its lifetime
+    pub const RISE: f32 = 40.0;
+
+    /// Opacity of the text, fading out over its lifetime.
+    pub fn alpha(&self) -> f32 {
+        (1.0 - self.age / Self::LIFETIME).clamp(0.0, 1.0)
     }
 }
 
@@ -168,12 +214,14 @@ impl MenuDisplay {
 #[derive(Default)]
 pub struct EffectsDisplay {
     pub active: bool,
+    /// Floating damage numbers and similar texts over the map
+    pub floating_texts: Vec<FloatingText>,
     // Future: Add effect particles, animations, etc.
 }
 
 impl EffectsDisplay {
     pub fn new() -> Self {
-        Self { active: false }
+        Self::default()
     }
 
     pub fn show(&mut self) {
@@ -183,6 +231,56 @@ impl EffectsDisplay {
     pub fn hide(&mut self) {
         self.active = false;
     }
+
+    /// Shows a text rising from a hex, such as `"-7"` over a struck unit.
+    pub fn spawn_floating_text(
+        &mut self,
+        hex: crate::core::HexCoord,
+        text: impl Into<String>,
+        color: [f32; 3],
+    ) {
+        self.floating_texts.push(FloatingText {
+            text: text.into(),
+            hex,
+            color,
+            age: 0.0,
+        });
+    }
+
+    /// Ages floating texts and drops the ones that have faded out.
+    pub fn update(&mut self, delta_time: f32) {
+        for text in &mut self.floating_texts {
+            text.age += delta_time;
+        }
+        self.floating_texts
+            .retain(|text| text.age < FloatingText::LIFETIME);
+    }
+
+    /// Appends the floating texts, rising from their hexes as they fade out.
+    pub fn draw(
+        &self,
+        list: &mut DrawList,
+        hex_grid: &HexGrid,
+        window_width: f32,
+        window_height: f32,
+    ) {
+        let window_size = crate::math::Vec2::new(window_width, window_height);
+        let font_size = 20.0;
+        for text in &self.floating_texts {
+            let Some(hex) = hex_grid.get_hex_at(text.hex) else {
+                continue;
+            };
+            let screen_pos = hex_grid.camera.world_to_screen(hex.world_pos, window_size);
+            let progress = text.age / FloatingText::LIFETIME;
+            let x = screen_pos.x - text_width(&text.text, font_size) / 2.0;
+            let y = screen_pos.y - font_size - progress * FloatingText::RISE;
+            let [r, g, b] = text.color;
+            list.shadowed_text(&text.text, x, y, font_size, [r, g, b, text.alpha()], 1.0);
+        }
+
+        // Future: particle systems, unit action animations and weather while
+        // `active`
+    }
 }
 
 /// Combat log entry
@@ -203,12 +301,94 @@ pub enum CombatLogEntryType {
     Info,
 }
 
+impl CombatLogEntryType {
+    /// Text color of log entries of this type.
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            CombatLogEntryType::Attack => [1.0, 1.0, 0.8, 1.0],
+            CombatLogEntryType::Damage => [1.0, 0.5, 0.4, 1.0],
+            CombatLogEntryType::Heal => [0.5, 1.0, 0.5, 1.0],
+            CombatLogEntryType::Miss => [0.7, 0.7, 0.7, 1.0],
+            CombatLogEntryType::Death => [1.0, 0.3, 0.3, 1.0],
+            CombatLogEntryType::Info => [0.7, 0.85, 1.0, 1.0],
+        }
+    }
+}
+
 /// Attack option for combat dialog
 #[derive(Clone, Debug)]
 pub struct AttackOption {
     pub name: String,
     pub damage: u32,
     pub range: i32,
+    /// Range type name ("Melee", "Ranged" or "Siege")
+    pub range_type: String,
+    /// Whether the attack reaches the opposing unit
+    pub in_reach: bool,
+    /// Index in the opposing unit's attacks of the counter-attack this attack
+    /// draws, if any
+    pub counter_index: Option<usize>,
+    /// Time-of-day damage bonus (percent) already included in `damage`
+    pub time_of_day_bonus: i32,
+    /// Chance (0-100) to hit the opposing unit
+    pub hit_chance: u8,
+    /// Lines explaining how the hit chance was computed
+    pub hit_chance_breakdown: Vec<String>,
+    /// Outcome forecast of attacking with this attack (attacker attacks in
+    /// reach only)
+    pub forecast: Option<AttackForecast>,
+}
+
+/// Combat forecast of one attack option, for the combat dialog
+#[derive(Clone, Debug, Default)]
+pub struct AttackForecast {
+    /// Expected damage dealt to the defender
+    pub expected_damage_dealt: f32,
+    /// Expected damage taken from counter-attacks
+    pub expected_damage_taken: f32,
+    /// Chance (0-1) that the defender is defeated
+    pub kill_chance: f32,
+    /// Chance (0-1) that the attacker is defeated
+    pub death_chance: f32,
+    /// Defender health after the exchange and its chance (0-1), by ascending health
+    pub defender_hp_distribution: Vec<(u32, f32)>,
+    /// Attacker health after the exchange and its chance (0-1), by ascending health
+    pub attacker_hp_distribution: Vec<(u32, f32)>,
+}
+
+impl AttackForecast {
+    /// Short summary for an attack option label, such as `"~7.5 dmg, 25% kill"`.
+    pub fn summary(&self) -> String {
+        format!(
+            "~{:.1} dmg, {:.0}% kill",
+            self.expected_damage_dealt,
+            self.kill_chance * 100.0
+        )
+    }
+
+    /// Detailed lines for the selected attack: expected damage both ways,
+    /// kill and death chances, and both HP distributions.
+    pub fn lines(&self) -> Vec<String> {
+        let distribution = |hp: &[(u32, f32)]| {
+            hp.iter()
+                .map(|(hp, chance)| format!("{} ({:.0}%)", hp, chance * 100.0))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        vec![
+            format!(
+                "Expected: {:.1} dealt, {:.1} taken",
+                self.expected_damage_dealt, self.expected_damage_taken
+            ),
+            format!(
+                "Kill: {:.0}%  Death: {:.0}%",
+                self.kill_chance * 100.0,
+                self.death_chance * 100.0
+            ),
+            format!("Enemy HP: {}", distribution(&self.defender_hp_distribution)),
+            format!("Your HP: {}", distribution(&self.attacker_hp_distribution)),
+        ]
+    }
 }
 
 /// Combat confirmation dialog data
@@ -230,33 +410,6 @@ pub struct CombatConfirmation {
     pub defender_attacks: Vec<AttackOption>,
 }
 
-/// Combat confirmation button
-#[derive(Clone, Debug)]
-pub struct CombatButton {
-    pub position: (f32, f32),
-    pub size: (f32, f32),
-    pub label: String,
-    pub hovered: bool,
-}
-
-impl CombatButton {
-    pub fn new(x: f32, y: f32, width: f32, height: f32, label: &str) -> Self {
-        Self {
-            position: (x, y),
-            size: (width, height),
-            label: label.to_string(),
-            hovered: false,
-        }
-    }
-
-    pub fn contains_point(&self, x: f32, y: f32) -> bool {
-        x >= self.position.0
-            && x <= self.position.0 + self.size.0
-            && y >= self.position.1
-            && y <= self.position.1 + self.size.1
-    }
-}
-
 /// Combat log display
 pub struct CombatLogDisplay {
     pub active: bool,
@@ -266,10 +419,9 @@ pub struct CombatLogDisplay {
     pub size: (f32, f32),     // Width and height
     pub auto_hide: bool,      // Auto-hide when no combat
     pub pending_combat: Option<CombatConfirmation>,
-    pub ok_button: CombatButton,
-    pub cancel_button: CombatButton,
     pub selected_attack_index: Option<usize>, // Which attacker attack is selected
-    pub attacker_attack_buttons: Vec<CombatButton>, // Clickable attack options for attacker
+    /// Laid-out confirmation dialog
+    ui: Ui,
 }
 
 impl Default for CombatLogDisplay {
@@ -281,48 +433,19 @@ impl Default for CombatLogDisplay {
 
 impl CombatLogDisplay {
     pub fn new(window_width: f32, window_height: f32) -> Self {
-        // Dialog is 70% of the window height, making it larger
-        let dialog_size = window_height * 0.7; // 560x560 for 800px height
-        let dialog_width = dialog_size;
-        let dialog_height = dialog_size;
-
-        // Center the dialog on screen
-        let dialog_x = (window_width - dialog_width) / 2.0;
-        let dialog_y = (window_height - dialog_height) / 2.0;
-
-        // Create buttons at bottom of dialog
-        let button_width = 120.0;
-        let button_height = 50.0;
-        let button_spacing = 20.0;
-        let button_margin_bottom = 30.0;
-
-        // Position buttons centered at bottom
-        let total_button_width = button_width * 2.0 + button_spacing;
-        let buttons_start_x = dialog_x + (dialog_width - total_button_width) / 2.0;
-        let buttons_y = dialog_y + dialog_height - button_height - button_margin_bottom;
-
-        let ok_x = buttons_start_x;
-        let cancel_x = buttons_start_x + button_width + button_spacing;
-
-        Self {
+        let mut display = Self {
             active: false,
             entries: Vec::new(),
             max_entries: 10,
-            position: (dialog_x, dialog_y),
-            size: (dialog_width, dialog_height),
+            position: (0.0, 0.0),
+            size: (0.0, 0.0),
             auto_hide: false,
             pending_combat: None,
-            ok_button: CombatButton::new(ok_x, buttons_y, button_width, button_height, "OK"),
-            cancel_button: CombatButton::new(
-                cancel_x,
-                buttons_y,
-                button_width,
-                button_height,
-                "Cancel",
-            ),
             selected_attack_index: Some(0), // Default to first attack
-            attacker_attack_buttons: Vec::new(), // Will be populated when combat is shown
-        }
+            ui: Ui::empty(window_width, window_height),
+        };
+        display.rebuild();
+        display
     }
 
     pub fn show(&mut self) {
@@ -337,68 +460,76 @@ impl CombatLogDisplay {
         self.active = !self.active;
     }
 
+    /// Lays the confirmation dialog out for a new window size
+    pub fn resize(&mut self, window_width: f32, window_height: f32) {
+        self.ui.resize(window_width, window_height);
+        self.rebuild();
+    }
+
+    /// Draws the dialog's text and buttons `scale` times their default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.rebuild();
+    }
+
+    /// Rebuilds the dialog for the pending combat and selected attack
+    fn rebuild(&mut self) {
+        // Dialog is 70% of the window height, making it larger
+        let (_, window_height) = self.ui.screen_size();
+        let dialog_size = window_height * 0.7;
+        let root = match &self.pending_combat {
+            Some(confirmation) => {
+                CombatPanel::build(confirmation, self.selected_attack_index, dialog_size)
+            }
+            None => Widget::spacer(),
+        };
+        self.ui.set_root(root);
+        let dialog = self.ui.rect(COMBAT_DIALOG).unwrap_or_else(|| {
+            let (window_width, window_height) = self.ui.screen_size();
+            Rect::new(
+                (window_width - dialog_size) / 2.0,
+                (window_height - dialog_size) / 2.0,
+                dialog_size,
+                dialog_size,
+            )
+        });
+        self.position = (dialog.x, dialog.y);
+        self.size = (dialog.width, dialog.height);
+    }
+
     pub fn show_combat_confirmation(&mut self, confirmation: CombatConfirmation) {
-        // Calculate attack button positions
-        let attack_box_height = 30.0;
-        let attack_box_spacing = 5.0;
-        let title_height = 50.0;
-        let sprite_area_height = 120.0;
-        let text_line_height = 25.0;
-        let panel_padding = 15.0;
-        let panel_height = text_line_height * 6.0 + panel_padding * 2.0;
-        let panel_margin = 30.0;
-        let panel_spacing = 20.0;
-        let panel_width = (self.size.0 - 2.0 * panel_margin - panel_spacing) / 2.0;
-
-        let panel_y = self.position.1 + title_height + sprite_area_height + 10.0;
-        let attack_section_y = panel_y + panel_height + 10.0;
-        let attacker_x = self.position.0 + panel_margin;
-
-        // Create clickable buttons for attacker's attacks
-        let mut attack_buttons = Vec::new();
-        println!(
-            "🎮 Creating {} attack buttons:",
-            confirmation.attacker_attacks.len()
+        // Default to the first attack that reaches the defender
+        self.selected_attack_index = Some(
+            confirmation
+                .attacker_attacks
+                .iter()
+                .position(|attack| attack.in_reach)
+                .unwrap_or(0),
         );
-        for i in 0..confirmation.attacker_attacks.len() {
-            let attack_y = attack_section_y + (i as f32) * (attack_box_height + attack_box_spacing);
-            let button = CombatButton::new(
-                attacker_x,
-                attack_y,
-                panel_width,
-                attack_box_height,
-                &confirmation.attacker_attacks[i].name,
-            );
-            println!(
-                "  Button {}: {} at ({}, {}) size {}x{}",
-                i, button.label, button.position.0, button.position.1, button.size.0, button.size.1
-            );
-            attack_buttons.push(button);
-        }
-
-        self.attacker_attack_buttons = attack_buttons;
-        self.selected_attack_index = Some(0); // Default to first attack
         self.pending_combat = Some(confirmation);
         self.active = true;
+        self.rebuild();
     }
 
     pub fn clear_combat_confirmation(&mut self) {
         self.pending_combat = None;
         self.active = false;
-        self.attacker_attack_buttons.clear();
         self.selected_attack_index = None;
+        self.rebuild();
     }
 
     /// Check if an attack button was clicked and return its index
+    ///
+    /// Attacks out of reach cannot be selected.
     pub fn check_attack_click(&mut self, x: f32, y: f32) -> Option<usize> {
-        for (i, button) in self.attacker_attack_buttons.iter().enumerate() {
-            if button.contains_point(x, y) {
-                self.selected_attack_index = Some(i);
-                println!("🎯 Attack option {} clicked: {}", i, button.label);
-                return Some(i);
-            }
-        }
-        None
+        let index = self
+            .ui
+            .hit_test(x, y)
+            .filter(|id| id.name == ATTACK_OPTION.name)?
+            .index;
+        self.selected_attack_index = Some(index);
+        self.rebuild();
+        Some(index)
     }
 
     /// Get the currently selected attack index
@@ -410,26 +541,36 @@ impl CombatLogDisplay {
         self.pending_combat.is_some()
     }
 
+    /// Highlights the button under the cursor
     pub fn update_button_hover(&mut self, mouse_x: f32, mouse_y: f32) {
-        self.ok_button.hovered = self.ok_button.contains_point(mouse_x, mouse_y);
-        self.cancel_button.hovered = self.cancel_button.contains_point(mouse_x, mouse_y);
+        self.ui.cursor_moved(mouse_x, mouse_y);
     }
 
+    /// Where a dialog button is on the screen
+    pub fn button_rect(&self, id: WidgetId) -> Option<Rect> {
+        self.ui.rect(id)
+    }
+
+    /// Handles a click on the dialog: `Some(true)` when OK was clicked,
+    /// `Some(false)` when Cancel was (which also closes the dialog)
     pub fn handle_click(&mut self, mouse_x: f32, mouse_y: f32) -> Option<bool> {
         if !self.has_pending_combat() {
             return None;
         }
 
-        if self.ok_button.contains_point(mouse_x, mouse_y) {
-            return Some(true); // Combat confirmed
-        }
-
-        if self.cancel_button.contains_point(mouse_x, mouse_y) {
-            self.clear_combat_confirmation();
-            return Some(false); // Combat cancelled
+        match self.ui.hit_test(mouse_x, mouse_y) {
+            Some(COMBAT_OK) => Some(true), // Combat confirmed
+            Some(COMBAT_CANCEL) => {
+                self.clear_combat_confirmation();
+                Some(false) // Combat cancelled
+            }
+            _ => None,
         }
+    }
 
-        None
+    /// Appends the confirmation dialog's draw commands
+    pub fn draw_dialog(&self, list: &mut DrawList) {
+        self.ui.draw(list);
     }
 
     pub fn add_entry(&mut self, message: String, entry_type: CombatLogEntryType) {
@@ -458,17 +599,16 @@ impl CombatLogDisplay {
 }
 
 pub struct Renderer {
-    pub vao: GLuint,
-    pub shader_program: GLuint,
-    pub vertex_buffer: VertexBuffer,
-    pub texture_manager: TextureManager,
+    /// Backend drawing the frame into the window's OpenGL context
+    pub backend: GlBackend,
     // pub guide_display: GuideDisplay, // Now handled in EncyclopediaPanel
     pub menu_display: MenuDisplay,
     pub submenu_panel: Option<crate::ui::SubmenuPanel>,
     pub recruitment_panel: Option<crate::ui::RecruitmentPanel>,
     pub effects_display: EffectsDisplay,
     pub combat_log_display: CombatLogDisplay,
-    pub text_renderer: TextRenderer,
+    /// Map overview in the bottom-left corner
+    pub minimap: Minimap,
     pub window_width: f32,
     pub window_height: f32,
 }
@@ -503,36 +643,20 @@ impl Renderer {
             texture_manager
                 .load_structure_sprites()
                 .map_err(|e| format!("Failed to load structure sprites: {}", e))?;
-
-            // Set up texture uniforms
-            gl::UseProgram(shader_program);
-
-            // Bind texture units to shader uniforms (0-6 for terrain, 7 for items, 8-11 for units)
-            for i in 0..12 {
-                let uniform_name = format!("textures[{}]", i);
-                let uniform_name_c = CString::new(uniform_name).unwrap();
-                let uniform_location =
-                    gl::GetUniformLocation(shader_program, uniform_name_c.as_ptr());
-                if uniform_location != -1 {
-                    gl::Uniform1i(uniform_location, i);
-                }
-            }
         }
 
-        let text_renderer = TextRenderer::new()?;
+        let mut backend = GlBackend::new(window_width, window_height)?;
+        backend.set_sprite_pipeline(vao, shader_program, vbo, texture_manager);
 
         let mut renderer = Self {
-            vao,
-            shader_program,
-            vertex_buffer: VertexBuffer::new(vbo),
-            texture_manager,
+            backend,
             // guide_display: GuideDisplay::new(),
             menu_display: MenuDisplay::new(),
             submenu_panel: None,
             recruitment_panel: None,
             effects_display: EffectsDisplay::new(),
             combat_log_display: CombatLogDisplay::new(window_width, window_height),
-            text_renderer,
+            minimap: Minimap::new(window_width, window_height),
             window_width,
             window_height,
         };
@@ -545,882 +669,87 @@ impl Renderer {
         Ok(renderer)
     }
 
-    pub fn render(&mut self, hex_grid: &HexGrid) {
-        let visible_hexagons = hex_grid.get_visible_hexagons();
-
-        unsafe {
-            // Clear screen and enable depth testing for proper layering
-            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
-            gl::Enable(gl::DEPTH_TEST);
-            gl::DepthFunc(gl::LEQUAL); // Use LEQUAL to allow same-depth overlays
-
-            // Enable alpha blending for transparent sprites
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-
-            if !visible_hexagons.is_empty() {
-                // Bind all textures
-                self.texture_manager.bind_all_textures();
-                gl::UseProgram(self.shader_program);
-                gl::BindVertexArray(self.vao);
-
-                // LAYER 1: Render terrain (bottom layer, z = 0.0)
-                self.render_terrain_layer(&visible_hexagons, hex_grid);
-
-                // LAYER 1.5: Render structures (on top of terrain, z = -0.3)
-                self.render_structure_sprites_layer(&visible_hexagons, hex_grid);
-
-                // LAYER 2: Render units (middle layer, z = -0.5)
-                self.render_unit_sprites_layer(&visible_hexagons, hex_grid);
-
-                // LAYER 3: Render items (top layer, z = -0.6)
-                self.render_item_sprites_layer(&visible_hexagons, hex_grid);
-            }
-
-            // LAYER 4: Render guide/encyclopedia (UI overlay, no depth test)
-            gl::Disable(gl::DEPTH_TEST);
-
-            // LAYER 3.5: Render text overlays on tiles (after disabling depth test)
-            self.render_tile_text_overlays(&visible_hexagons, hex_grid);
-
-            // Guide/encyclopedia rendering moved into UI modules (handled elsewhere)
-            // previously: self.render_guide_layer();
-
-            // LAYER 5: Render menu (topmost UI layer, no depth test)
-            self.render_menu_layer();
-
-            // LAYER 6: Render effects (visual effects layer, no depth test)
-            self.render_effects_layer();
-
-            // LAYER 7: Render combat log (combat information layer, no depth test)
-            // Delegate combat rendering to UI's CombatPanel (geometry + text)
-            CombatPanel::render_layer(
-                self.window_width,
-                self.window_height,
-                &self.combat_log_display,
-                &mut self.text_renderer,
-                |vertices: &[f32]| {
-                    // Ensure shader program and VAO are bound, then upload vertices and draw
-                    gl::UseProgram(self.shader_program);
-                    gl::BindVertexArray(self.vao);
-                    gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-                    gl::BufferData(
-                        gl::ARRAY_BUFFER,
-                        std::mem::size_of_val(vertices) as GLsizeiptr,
-                        vertices.as_ptr() as *const _,
-                        gl::DYNAMIC_DRAW,
-                    );
-
-                    let vertex_count = (vertices.len() / 9) as GLsizei; // 9 floats per vertex
-                    gl::DrawArrays(gl::TRIANGLES, 0, vertex_count);
-                },
-            );
-        }
-    }
-
-    /// Renders a sprite at screen coordinates for UI purposes.
-    ///
-    /// # Arguments
-    ///
-    /// Renders a sprite at a specific screen position.
-    ///
-    /// # Arguments
-    ///
-    /// * `sprite_type` - The type of sprite to render
-    /// * `x` - X coordinate in screen space (pixels)
-    /// * `y` - Y coordinate in screen space (pixels)  
-    /// * `width` - Width in screen space (pixels)
-    /// * `height` - Height in screen space (pixels)
-    /// * `screen_dims` - Tuple of (screen_width, screen_height)
-    ///
-    /// # Safety
-    ///
-    /// This function is unsafe because it makes raw OpenGL calls that:
-    /// - Modify global OpenGL state (blend mode, depth testing, shaders)
-    /// - Access GPU memory through buffer operations
-    /// - Must be called from a thread with an active OpenGL context
-    pub unsafe fn render_sprite_at_screen_pos(
-        &self,
-        sprite_type: crate::core::hexagon::SpriteType,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        screen_dims: (f32, f32),
-    ) {
-        let (screen_width, screen_height) = screen_dims;
-        // Save current OpenGL state
-        let mut prev_program: GLint = 0;
-        let mut prev_vao: GLint = 0;
-        let mut prev_vbo: GLint = 0;
-
-        gl::GetIntegerv(gl::CURRENT_PROGRAM, &mut prev_program);
-        gl::GetIntegerv(gl::VERTEX_ARRAY_BINDING, &mut prev_vao);
-        gl::GetIntegerv(gl::ARRAY_BUFFER_BINDING, &mut prev_vbo);
-        let prev_blend_enabled = gl::IsEnabled(gl::BLEND);
-        let prev_depth_enabled = gl::IsEnabled(gl::DEPTH_TEST);
-
-        // Set up our rendering state
-        gl::Disable(gl::DEPTH_TEST);
-        gl::Enable(gl::BLEND);
-        gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-
-        gl::UseProgram(self.shader_program);
-        gl::BindVertexArray(self.vao);
-
-        // Bind texture
-        self.texture_manager.bind_texture(sprite_type);
-
-        // Convert screen coordinates to NDC
-        let x1 = (x / screen_width) * 2.0 - 1.0;
-        let y1 = 1.0 - (y / screen_height) * 2.0;
-        let x2 = ((x + width) / screen_width) * 2.0 - 1.0;
-        let y2 = 1.0 - ((y + height) / screen_height) * 2.0;
-
-        let texture_id = sprite_type.get_texture_id();
-        let depth = -0.95; // On top of everything
-
-        // Create a quad (two triangles)
-        #[rustfmt::skip]
-        let vertices: Vec<f32> = vec![
-            // Triangle 1
-            x1, y1, depth, 0.0, 0.0, texture_id, 1.0, 1.0, 1.0, // Top-left
-            x2, y1, depth, 1.0, 0.0, texture_id, 1.0, 1.0, 1.0, // Top-right
-            x1, y2, depth, 0.0, 1.0, texture_id, 1.0, 1.0, 1.0, // Bottom-left
-            // Triangle 2
-            x2, y1, depth, 1.0, 0.0, texture_id, 1.0, 1.0, 1.0, // Top-right
-            x2, y2, depth, 1.0, 1.0, texture_id, 1.0, 1.0, 1.0, // Bottom-right
-            x1, y2, depth, 0.0, 1.0, texture_id, 1.0, 1.0, 1.0, // Bottom-left
-        ];
-
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            std::mem::size_of_val(vertices.as_slice()) as isize,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        gl::DrawArrays(gl::TRIANGLES, 0, 6);
-
-        // Restore previous OpenGL state
-        gl::UseProgram(prev_program as GLuint);
-        gl::BindVertexArray(prev_vao as GLuint);
-        gl::BindBuffer(gl::ARRAY_BUFFER, prev_vbo as GLuint);
-
-        if prev_blend_enabled == gl::TRUE {
-            gl::Enable(gl::BLEND);
-        } else {
-            gl::Disable(gl::BLEND);
-        }
-
-        if prev_depth_enabled == gl::TRUE {
-            gl::Enable(gl::DEPTH_TEST);
-        } else {
-            gl::Disable(gl::DEPTH_TEST);
-        }
-    }
-
-    unsafe fn render_terrain_layer(&self, visible_hexagons: &[&Hexagon], hex_grid: &HexGrid) {
-        // Build vertices for terrain only
-        let mut vertices = Vec::new();
-
-        for hex in visible_hexagons {
-            let terrain_sprite = hex.sprite;
-
-            // Skip if no terrain
-            if terrain_sprite == crate::core::hexagon::SpriteType::None {
-                continue;
-            }
-
-            let center_x = hex.world_pos.x - hex_grid.camera.position.x;
-            let center_y = hex.world_pos.y - hex_grid.camera.position.y;
-            let texture_id = terrain_sprite.get_texture_id();
-            let color = hex.get_display_color();
-
-            // Center vertex (z = 0.0 for terrain layer)
-            vertices.extend_from_slice(&[
-                center_x, center_y, 0.0, // position with depth
-                0.5, 0.5,        // uv
-                texture_id, // texture id
-                color[0], color[1], color[2], // RGB color
-            ]);
-
-            // Outer vertices
-            for i in 0..=6 {
-                let angle = (i as f32) * std::f32::consts::PI / 3.0;
-                let x = center_x + hex_grid.hex_size * angle.cos();
-                let y = center_y + hex_grid.hex_size * angle.sin();
-                let u = 0.5 + 0.4 * angle.cos();
-                let v = 1.0 - (0.5 + 0.4 * angle.sin());
-
-                vertices.extend_from_slice(&[
-                    x, y, 0.0, // position with depth
-                    u, v,          // uv
-                    texture_id, // texture id
-                    color[0], color[1], color[2], // RGB color
-                ]);
-            }
-        }
-
-        // Upload and draw terrain
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            std::mem::size_of_val(vertices.as_slice()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        let hex_count = vertices.len() / (8 * 9); // 8 vertices, 9 floats each
-        for i in 0..hex_count {
-            let vertex_offset = (i * 8) as GLint;
-            gl::DrawArrays(gl::TRIANGLE_FAN, vertex_offset, 8);
-        }
-    }
-
-    unsafe fn render_unit_sprites_layer(&self, visible_hexagons: &[&Hexagon], hex_grid: &HexGrid) {
-        // Build vertices for units only
-        let mut vertices = Vec::new();
-
-        for hex in visible_hexagons {
-            if let Some(unit_sprite) = hex.unit_sprite {
-                // Render any unit sprite (but skip items)
-                if unit_sprite != crate::core::hexagon::SpriteType::Item {
-                    let center_x = hex.world_pos.x - hex_grid.camera.position.x;
-                    let center_y = hex.world_pos.y - hex_grid.camera.position.y;
-                    let texture_id = unit_sprite.get_texture_id();
-                    let sprite_color = unit_sprite.get_color_tint();
-
-                    // Scale factor for units (60% of hex size)
-                    let scale_factor = 0.6;
-                    let small_hex_size = hex_grid.hex_size * scale_factor;
-
-                    // Center vertex (z = -0.5 to render on top of terrain)
-                    vertices.extend_from_slice(&[
-                        center_x,
-                        center_y,
-                        -0.5, // position with depth
-                        0.5,
-                        0.5,        // uv
-                        texture_id, // texture id
-                        sprite_color[0],
-                        sprite_color[1],
-                        sprite_color[2], // RGB color
-                    ]);
-
-                    // Outer vertices
-                    for i in 0..=6 {
-                        let angle = (i as f32) * std::f32::consts::PI / 3.0;
-                        let x = center_x + small_hex_size * angle.cos();
-                        let y = center_y + small_hex_size * angle.sin();
-                        let u = 0.5 + 0.4 * angle.cos();
-                        let v = 1.0 - (0.5 + 0.4 * angle.sin());
-
-                        vertices.extend_from_slice(&[
-                            x,
-                            y,
-                            -0.5, // position with depth
-                            u,
-                            v,          // uv
-                            texture_id, // texture id
-                            sprite_color[0],
-                            sprite_color[1],
-                            sprite_color[2], // RGB color
-                        ]);
-                    }
-                }
-            }
-        }
-
-        if vertices.is_empty() {
-            return;
-        }
-
-        // Upload and draw unit sprites
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            std::mem::size_of_val(vertices.as_slice()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        let hex_count = vertices.len() / (8 * 9); // 8 vertices, 9 floats each
-        for i in 0..hex_count {
-            let vertex_offset = (i * 8) as GLint;
-            gl::DrawArrays(gl::TRIANGLE_FAN, vertex_offset, 8);
-        }
-    }
-
-    /// Render text overlays on tiles (e.g., defense values)
-    /// Must be called AFTER disabling depth test
-    unsafe fn render_tile_text_overlays(
-        &mut self,
-        visible_hexagons: &[&Hexagon],
-        hex_grid: &HexGrid,
-    ) {
-        let mut text_count = 0;
-        for hex in visible_hexagons {
-            if let Some(ref text) = hex.text_overlay {
-                text_count += 1;
-                // Convert world position to screen coordinates using camera
-                let window_size = crate::math::Vec2::new(self.window_width, self.window_height);
-                let screen_pos = hex_grid.camera.world_to_screen(hex.world_pos, window_size);
-
-                println!(
-                    "🎯 Rendering '{}' at screen pos ({}, {})",
-                    text, screen_pos.x, screen_pos.y
-                );
-
-                // Center the text on the tile with larger font
-                let font_size = 24.0; // Large font size for visibility
-                let char_width = font_size * 0.6; // Approximate character width
-                let text_width = text.len() as f32 * char_width;
-                let centered_x = screen_pos.x - text_width / 2.0;
-                let centered_y = screen_pos.y - font_size / 2.0; // Vertically center
-
-                // First render a black shadow/outline for contrast (8 directions)
-                for dx in [-2.0, -1.0, 0.0, 1.0, 2.0].iter() {
-                    for dy in [-2.0, -1.0, 0.0, 1.0, 2.0].iter() {
-                        if *dx != 0.0 || *dy != 0.0 {
-                            self.text_renderer.render_text(
-                                text,
-                                centered_x + dx,
-                                centered_y + dy,
-                                font_size,
-                                [0.0, 0.0, 0.0, 1.0], // solid black shadow
-                                self.window_width,
-                                self.window_height,
-                            );
-                        }
-                    }
-                }
-
-                // Then render the bright yellow text on top
-                self.text_renderer.render_text(
-                    text,
-                    centered_x,
-                    centered_y,
-                    font_size,
-                    [1.0, 1.0, 0.0, 1.0], // bright yellow color for high contrast
-                    self.window_width,
-                    self.window_height,
-                );
-            }
-        }
-        if text_count > 0 {
-            println!("📝 Rendered {} text overlays", text_count);
-        }
-    }
-
-    unsafe fn render_structure_sprites_layer(
-        &self,
-        visible_hexagons: &[&Hexagon],
-        hex_grid: &HexGrid,
-    ) {
-        // Build vertices for structures only
-        let mut vertices = Vec::new();
-
-        for hex in visible_hexagons {
-            if let Some(structure_sprite) = hex.structure_sprite {
-                let center_x = hex.world_pos.x - hex_grid.camera.position.x;
-                let center_y = hex.world_pos.y - hex_grid.camera.position.y;
-                let texture_id = structure_sprite.get_texture_id();
-                let sprite_color = structure_sprite.get_color_tint();
-
-                // Scale factor for structures (70% of hex size - slightly larger than units)
-                let scale_factor = 0.7;
-                let small_hex_size = hex_grid.hex_size * scale_factor;
-
-                // Center vertex (z = -0.3 to render on top of terrain but below units)
-                vertices.extend_from_slice(&[
-                    center_x,
-                    center_y,
-                    -0.3, // position with depth
-                    0.5,
-                    0.5,        // uv
-                    texture_id, // texture id
-                    sprite_color[0],
-                    sprite_color[1],
-                    sprite_color[2], // RGB color
-                ]);
-
-                // Outer vertices
-                for i in 0..=6 {
-                    let angle = (i as f32) * std::f32::consts::PI / 3.0;
-                    let x = center_x + small_hex_size * angle.cos();
-                    let y = center_y + small_hex_size * angle.sin();
-                    let u = 0.5 + 0.4 * angle.cos();
-                    let v = 1.0 - (0.5 + 0.4 * angle.sin());
-
-                    vertices.extend_from_slice(&[
-                        x,
-                        y,
-                        -0.3, // position with depth
-                        u,
-                        v,          // uv
-                        texture_id, // texture id
-                        sprite_color[0],
-                        sprite_color[1],
-                        sprite_color[2], // RGB color
-                    ]);
-                }
-            }
-        }
-
-        if vertices.is_empty() {
-            return;
-        }
-
-        // Upload and draw structure sprites
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            (vertices.len() * std::mem::size_of::<f32>()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        let hex_count = vertices.len() / (8 * 9); // 8 vertices, 9 floats each
-        for i in 0..hex_count {
-            let vertex_offset = (i * 8) as GLint;
-            gl::DrawArrays(gl::TRIANGLE_FAN, vertex_offset, 8);
-        }
-    }
-
-    unsafe fn render_item_sprites_layer(&self, visible_hexagons: &[&Hexagon], hex_grid: &HexGrid) {
-        // Build vertices for items only
-        let mut vertices = Vec::new();
-
-        for hex in visible_hexagons {
-            // Check if there's an item on this hex using the dedicated item_sprite field
-            if let Some(item_sprite) = hex.item_sprite {
-                if item_sprite == crate::core::hexagon::SpriteType::Item {
-                    // Check if there's also a unit on this hex
-                    let has_unit = hex.unit_sprite.is_some()
-                        && matches!(
-                            hex.unit_sprite,
-                            Some(crate::core::hexagon::SpriteType::Unit)
-                        );
-
-                    let center_x = hex.world_pos.x - hex_grid.camera.position.x;
-                    let center_y = hex.world_pos.y - hex_grid.camera.position.y;
-                    let texture_id = item_sprite.get_texture_id();
-                    let sprite_color = item_sprite.get_color_tint();
-
-                    // Determine size and position based on whether unit is present
-                    let (scale_factor, offset_x, offset_y) = if has_unit {
-                        // Smaller size and shifted to upper-right corner
-                        let scale = 0.25; // 25% of hex size (smaller when unit present)
-                        let offset_distance = hex_grid.hex_size * 0.4; // Distance from center
-                        let angle = std::f32::consts::PI / 6.0; // 30 degrees (upper-right)
-                        let x_offset = offset_distance * angle.cos();
-                        let y_offset = -offset_distance * angle.sin(); // Negative for "up"
-                        (scale, x_offset, y_offset)
-                    } else {
-                        // Normal size, centered
-                        (0.5, 0.0, 0.0)
-                    };
-
-                    let small_hex_size = hex_grid.hex_size * scale_factor;
-                    let item_x = center_x + offset_x;
-                    let item_y = center_y + offset_y;
-
-                    // Center vertex (z = -0.6 to render on top of units)
-                    vertices.extend_from_slice(&[
-                        item_x,
-                        item_y,
-                        -0.6, // Slightly closer than units (-0.5) so it renders on top
-                        0.5,
-                        0.5,        // uv
-                        texture_id, // texture id
-                        sprite_color[0],
-                        sprite_color[1],
-                        sprite_color[2], // RGB color
-                    ]);
-
-                    // Outer vertices
-                    for i in 0..=6 {
-                        let angle = (i as f32) * std::f32::consts::PI / 3.0;
-                        let x = item_x + small_hex_size * angle.cos();
-                        let y = item_y + small_hex_size * angle.sin();
-                        let u = 0.5 + 0.4 * angle.cos();
-                        let v = 1.0 - (0.5 + 0.4 * angle.sin());
-
-                        vertices.extend_from_slice(&[
-                            x,
-                            y,
-                            -0.6, // Same depth as center
-                            u,
-                            v,          // uv
-                            texture_id, // texture id
-                            sprite_color[0],
-                            sprite_color[1],
-                            sprite_color[2], // RGB color
-                        ]);
-                    }
-                }
-            }
-        }
-
-        if vertices.is_empty() {
-            return;
+    /// Lays the map, menu, combat dialog and minimap out for a resized window.
+    pub fn resize(&mut self, window_width: f32, window_height: f32) {
+        self.window_width = window_width;
+        self.window_height = window_height;
+        self.backend.set_screen_size(window_width, window_height);
+        self.menu_display
+            .update_for_screen_size(window_width, window_height);
+        self.combat_log_display.resize(window_width, window_height);
+        self.minimap.resize(window_width, window_height);
+        if let Some(panel) = &mut self.submenu_panel {
+            panel.resize(window_width, window_height);
         }
-
-        // Upload and draw item sprites
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            std::mem::size_of_val(vertices.as_slice()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        let hex_count = vertices.len() / (8 * 9); // 8 vertices, 9 floats each
-        for i in 0..hex_count {
-            let vertex_offset = (i * 8) as GLint;
-            gl::DrawArrays(gl::TRIANGLE_FAN, vertex_offset, 8);
+        if let Some(panel) = &mut self.recruitment_panel {
+            panel.resize(window_width, window_height);
         }
     }
 
-    /// Render the menu UI overlay
-    unsafe fn render_menu_layer(&mut self) {
-        // Only render if menu is active
-        if !self.menu_display.active {
-            return;
-        }
-
-        let (panel_x, panel_y) = self.menu_display.position;
-        let (panel_width, panel_height) = self.menu_display.size;
-
-        // Use actual screen dimensions
-        let screen_width = self.window_width;
-        let screen_height = self.window_height;
-
-        let mut vertices = Vec::new();
-
-        // ========================================
-        // 1. Render semi-transparent background overlay (full screen)
-        // ========================================
-        let overlay_color = [0.0, 0.0, 0.0, 0.7]; // Black with 70% opacity
-        let depth = -0.95; // Very close to camera
-
-        // Full-screen overlay (2 triangles)
-        let overlay_vertices = [
-            // Triangle 1
-            -1.0,
-            1.0,
-            depth,
-            0.0,
-            0.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-            1.0,
-            1.0,
-            depth,
-            1.0,
-            0.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-            -1.0,
-            -1.0,
-            depth,
-            0.0,
-            1.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-            // Triangle 2
-            1.0,
-            1.0,
-            depth,
-            1.0,
-            0.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-            1.0,
-            -1.0,
-            depth,
-            1.0,
-            1.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-            -1.0,
-            -1.0,
-            depth,
-            0.0,
-            1.0,
-            -1.0,
-            overlay_color[0],
-            overlay_color[1],
-            overlay_color[2],
-        ];
-
-        vertices.extend_from_slice(&overlay_vertices);
-
-        // ========================================
-        // 2. Render menu background panel
-        // ========================================
-        let bg_color = [0.15, 0.15, 0.2, 0.95]; // Dark blue-gray with high opacity
-        let panel_depth = -0.96; // Slightly closer than overlay
-
-        // Convert screen coordinates to NDC
-        let x1 = (panel_x / screen_width) * 2.0 - 1.0;
-        let y1 = 1.0 - (panel_y / screen_height) * 2.0;
-        let x2 = ((panel_x + panel_width) / screen_width) * 2.0 - 1.0;
-        let y2 = 1.0 - ((panel_y + panel_height) / screen_height) * 2.0;
-
-        let panel_vertices = [
-            // Triangle 1
-            x1,
-            y1,
-            panel_depth,
-            0.0,
-            0.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-            x2,
-            y1,
-            panel_depth,
-            1.0,
-            0.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-            x1,
-            y2,
-            panel_depth,
-            0.0,
-            1.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-            // Triangle 2
-            x2,
-            y1,
-            panel_depth,
-            1.0,
-            0.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-            x2,
-            y2,
-            panel_depth,
-            1.0,
-            1.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-            x1,
-            y2,
-            panel_depth,
-            0.0,
-            1.0,
-            -1.0,
-            bg_color[0],
-            bg_color[1],
-            bg_color[2],
-        ];
-
-        vertices.extend_from_slice(&panel_vertices);
-
-        // ========================================
-        // 3. Render menu buttons
-        // ========================================
-        let button_depth = -0.97; // Even closer than panel
-
-        for button in &self.menu_display.buttons {
-            let (bx, by) = button.position;
-            let (bw, bh) = button.size;
-
-            // Button color (different for hovered/normal)
-            let button_color = if button.hovered {
-                [0.3, 0.4, 0.6, 1.0] // Lighter blue when hovered
-            } else {
-                [0.2, 0.25, 0.35, 1.0] // Dark blue-gray
-            };
-
-            // Convert button coordinates to NDC
-            let bx1 = (bx / screen_width) * 2.0 - 1.0;
-            let by1 = 1.0 - (by / screen_height) * 2.0;
-            let bx2 = ((bx + bw) / screen_width) * 2.0 - 1.0;
-            let by2 = 1.0 - ((by + bh) / screen_height) * 2.0;
-
-            let button_vertices = [
-                // Triangle 1
-                bx1,
-                by1,
-                button_depth,
-                0.0,
-                0.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-                bx2,
-                by1,
-                button_depth,
-                1.0,
-                0.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-                bx1,
-                by2,
-                button_depth,
-                0.0,
-                1.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-                // Triangle 2
-                bx2,
-                by1,
-                button_depth,
-                1.0,
-                0.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-                bx2,
-                by2,
-                button_depth,
-                1.0,
-                1.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-                bx1,
-                by2,
-                button_depth,
-                0.0,
-                1.0,
-                -1.0,
-                button_color[0],
-                button_color[1],
-                button_color[2],
-            ];
-
-            vertices.extend_from_slice(&button_vertices);
+    /// Scales the game menu, combat dialog and the submenu and recruitment
+    /// panels.
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.menu_display.set_ui_scale(scale);
+        self.combat_log_display.set_ui_scale(scale);
+        if let Some(panel) = &mut self.submenu_panel {
+            panel.set_ui_scale(scale);
         }
-
-        // Upload and draw all vertices
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vertex_buffer.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            std::mem::size_of_val(vertices.as_slice()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        // Draw overlay (6 vertices)
-        gl::DrawArrays(gl::TRIANGLES, 0, 6);
-
-        // Draw panel (6 vertices)
-        gl::DrawArrays(gl::TRIANGLES, 6, 6);
-
-        // Draw buttons (6 vertices each)
-        let button_count = self.menu_display.buttons.len();
-        for i in 0..button_count {
-            let vertex_offset = (12 + i * 6) as GLint;
-            gl::DrawArrays(gl::TRIANGLES, vertex_offset, 6);
+        if let Some(panel) = &mut self.recruitment_panel {
+            panel.set_ui_scale(scale);
         }
-
-        // Render text labels
-        self.render_menu_text();
     }
 
-    unsafe fn render_menu_text(&mut self) {
-        let window_width = self.window_width;
-        let window_height = self.window_height;
-
-        // Render title
-        let title = "GAME MENU";
-        let title_size = 35.0;
-        let panel_width = self.menu_display.size.0;
-        let title_x = self.menu_display.position.0
-            + (panel_width - title.len() as f32 * title_size * 0.6) / 2.0;
-        let title_y = self.menu_display.position.1 + 30.0;
-
-        self.text_renderer.render_text(
-            title,
-            title_x,
-            title_y,
-            title_size,
-            [1.0, 0.9, 0.4, 1.0], // Gold color
-            window_width,
-            window_height,
+    /// Clears the window and draws the map with the menu, effects and combat
+    /// layers on top.
+    pub fn render(&mut self, hex_grid: &HexGrid) {
+        let mut list = DrawList::new();
+        self.draw(&mut list, hex_grid);
+        self.backend.clear(CLEAR_COLOR);
+        self.backend.submit(&list);
+    }
+
+    /// Appends the commands [`Renderer::render`] draws.
+    pub fn draw(&self, list: &mut DrawList, hex_grid: &HexGrid) {
+        draw_frame(
+            list,
+            hex_grid,
+            (self.window_width, self.window_height),
+            &self.menu_display,
+            &self.effects_display,
+            &self.combat_log_display,
+            Some(&self.minimap),
         );
-
-        // Render button labels
-        for button in &self.menu_display.buttons {
-            let button_label = match button.action {
-                MenuAction::Continue => "Continue",
-                MenuAction::Settings => "Settings",
-                MenuAction::Save => "Save Game",
-                MenuAction::Load => "Load Game",
-                MenuAction::ExitToMainMenu => "Main Menu",
-                MenuAction::ExitGame => "Exit Game",
-            };
-
-            let text_size = 22.0;
-            let char_width = text_size * 0.6;
-            let text_width = button_label.len() as f32 * char_width;
-
-            // Center text in button
-            let text_x = button.position.0 + (button.size.0 - text_width) / 2.0;
-            let text_y = button.position.1 + (button.size.1 - text_size) / 2.0 + 5.0;
-
-            // Color based on hover state
-            let text_color = if button.hovered {
-                [1.0, 1.0, 0.8, 1.0] // Light yellow when hovered
-            } else {
-                [1.0, 1.0, 1.0, 1.0] // White normally
-            };
-
-            self.text_renderer.render_text(
-                button_label,
-                text_x,
-                text_y,
-                text_size,
-                text_color,
-                window_width,
-                window_height,
-            );
-        }
-    }
-
-    unsafe fn render_effects_layer(&self) {
-        // Layer 6: Visual effects (particles, animations, spell effects, etc.)
-        if self.effects_display.active {
-            // TODO: Implement visual effects rendering
-            // - Particle systems for explosions, magic spells
-            // - Animation overlays for unit actions
-            // - Environmental effects (rain, snow, fire)
-            // For now, this layer is transparent/empty
-        }
     }
+}
 
-    // Combat rendering moved into `ui::combat_panel::CombatPanel`
+/// Appends a full frame: the map and minimap, then the menu, effects and
+/// combat layers. Pass `None` for `minimap` to leave it out.
+///
+/// This is what [`Renderer::render`] draws, available without an OpenGL
+/// context so frames can be rendered by any [`DrawBackend`](crate::draw::DrawBackend).
+pub fn draw_frame(
+    list: &mut DrawList,
+    hex_grid: &HexGrid,
+    (window_width, window_height): (f32, f32),
+    menu_display: &MenuDisplay,
+    effects_display: &EffectsDisplay,
+    combat_log_display: &CombatLogDisplay,
+    minimap: Option<&Minimap>,
+) {
+    // LAYERS 1-3.5: Terrain, structures, units, items and tile text
+    draw_map(list, hex_grid, window_width, window_height);
+
+    // LAYER 4: Minimap
+    if let Some(minimap) = minimap {
+        minimap.draw(list, hex_grid);
+    }
+
+    // LAYER 5: Menu (topmost game UI)
+    menu_display.draw(list);
+
+    // LAYER 6: Effects (floating texts)
+    effects_display.draw(list, hex_grid, window_width, window_height);
+
+    // LAYER 7: Combat dialog or combat log
+    CombatPanel::draw_layer(list, window_width, window_height, combat_log_display);
 }
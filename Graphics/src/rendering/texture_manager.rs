@@ -19,42 +19,7 @@ impl TextureManager {
     /// Must be called with a valid OpenGL context. All OpenGL texture operations
     /// are unsafe and require proper context management.
     pub unsafe fn load_terrain_sprites(&mut self) -> Result<(), String> {
-        // Load each terrain sprite
-        for sprite_type in SpriteType::all_terrain() {
-            if let Some(path) = sprite_type.get_texture_path() {
-                // Try multiple possible paths
-                let paths_to_try = [
-                    path.to_string(),
-                    format!("Graphics/{}", path),
-                    format!("C:/Users/eero/Documents/QuestQuest/Graphics/{}", path),
-                ];
-
-                let mut last_error = String::new();
-                let mut texture_id = None;
-
-                for attempt_path in &paths_to_try {
-                    match self.load_texture_from_file(attempt_path) {
-                        Ok(id) => {
-                            texture_id = Some(id);
-                            break;
-                        }
-                        Err(e) => {
-                            last_error = e;
-                        }
-                    }
-                }
-
-                let texture_id = texture_id.ok_or_else(|| {
-                    format!(
-                        "Failed to load {} from any path. Last error: {}",
-                        path, last_error
-                    )
-                })?;
-
-                self.textures.insert(sprite_type, texture_id);
-            }
-        }
-        Ok(())
+        self.load_sprites(SpriteType::all_terrain())
     }
 
     /// Load all item sprites
@@ -64,43 +29,7 @@ impl TextureManager {
     /// are unsafe and require proper context management.
     pub unsafe fn load_item_sprites(&mut self) -> Result<(), String> {
         // Load item sprites (currently just Item/sword)
-        let item_sprites = [SpriteType::Item];
-
-        for sprite_type in item_sprites {
-            if let Some(path) = sprite_type.get_texture_path() {
-                // Try multiple possible paths
-                let paths_to_try = [
-                    path.to_string(),
-                    format!("Graphics/{}", path),
-                    format!("C:/Users/eero/Documents/QuestQuest/Graphics/{}", path),
-                ];
-
-                let mut last_error = String::new();
-                let mut texture_id = None;
-
-                for attempt_path in &paths_to_try {
-                    match self.load_texture_from_file(attempt_path) {
-                        Ok(id) => {
-                            texture_id = Some(id);
-                            break;
-                        }
-                        Err(e) => {
-                            last_error = e;
-                        }
-                    }
-                }
-
-                let texture_id = texture_id.ok_or_else(|| {
-                    format!(
-                        "Failed to load {} from any path. Last error: {}",
-                        path, last_error
-                    )
-                })?;
-
-                self.textures.insert(sprite_type, texture_id);
-            }
-        }
-        Ok(())
+        self.load_sprites([SpriteType::Item])
     }
 
     /// Load all unit sprites
@@ -110,41 +39,7 @@ impl TextureManager {
     /// are unsafe and require proper context management.
     pub unsafe fn load_unit_sprites(&mut self) -> Result<(), String> {
         // Load unit sprites from unit_sprites folder
-        for sprite_type in SpriteType::all_units() {
-            if let Some(path) = sprite_type.get_texture_path() {
-                // Try multiple possible paths
-                let paths_to_try = [
-                    path.to_string(),
-                    format!("Graphics/{}", path),
-                    format!("C:/Users/eero/Documents/QuestQuest/Graphics/{}", path),
-                ];
-
-                let mut last_error = String::new();
-                let mut texture_id = None;
-
-                for attempt_path in &paths_to_try {
-                    match self.load_texture_from_file(attempt_path) {
-                        Ok(id) => {
-                            texture_id = Some(id);
-                            break;
-                        }
-                        Err(e) => {
-                            last_error = e;
-                        }
-                    }
-                }
-
-                let texture_id = texture_id.ok_or_else(|| {
-                    format!(
-                        "Failed to load {} from any path. Last error: {}",
-                        path, last_error
-                    )
-                })?;
-
-                self.textures.insert(sprite_type, texture_id);
-            }
-        }
-        Ok(())
+        self.load_sprites(SpriteType::all_units())
     }
 
     /// Load all structure sprites
@@ -154,50 +49,25 @@ impl TextureManager {
     /// are unsafe and require proper context management.
     pub unsafe fn load_structure_sprites(&mut self) -> Result<(), String> {
         // Load structure sprites from structure_sprites folder
-        for sprite_type in SpriteType::all_structures() {
-            if let Some(path) = sprite_type.get_texture_path() {
-                // Try multiple possible paths
-                let paths_to_try = [
-                    path.to_string(),
-                    format!("Graphics/{}", path),
-                    format!("C:/Users/eero/Documents/QuestQuest/Graphics/{}", path),
-                ];
-
-                let mut last_error = String::new();
-                let mut texture_id = None;
-
-                for attempt_path in &paths_to_try {
-                    match self.load_texture_from_file(attempt_path) {
-                        Ok(id) => {
-                            texture_id = Some(id);
-                            break;
-                        }
-                        Err(e) => {
-                            last_error = e;
-                        }
-                    }
-                }
-
-                let texture_id = texture_id.ok_or_else(|| {
-                    format!(
-                        "Failed to load {} from any path. Last error: {}",
-                        path, last_error
-                    )
-                })?;
+        self.load_sprites(SpriteType::all_structures())
+    }
 
+    /// Load the textures of the given sprites
+    unsafe fn load_sprites(
+        &mut self,
+        sprite_types: impl IntoIterator<Item = SpriteType>,
+    ) -> Result<(), String> {
+        for sprite_type in sprite_types {
+            if let Some(image) = load_sprite_image(sprite_type)? {
+                let texture_id = self.upload_texture(image);
                 self.textures.insert(sprite_type, texture_id);
             }
         }
         Ok(())
     }
 
-    /// Load a single texture from file
-    unsafe fn load_texture_from_file(&self, path: &str) -> Result<GLuint, String> {
-        // Load image using the image crate
-        let img = image::open(path)
-            .map_err(|e| format!("Failed to load image {}: {}", path, e))?
-            .to_rgba8();
-
+    /// Upload an image as a texture
+    unsafe fn upload_texture(&self, img: image::RgbaImage) -> GLuint {
         let (width, height) = img.dimensions();
         let data = img.into_raw();
 
@@ -228,7 +98,7 @@ impl TextureManager {
         // Generate mipmaps
         gl::GenerateMipmap(gl::TEXTURE_2D);
 
-        Ok(texture_id)
+        texture_id
     }
 
     /// Get texture ID for a sprite type
@@ -322,6 +192,38 @@ impl TextureManager {
     }
 }
 
+/// Loads the image of a sprite, trying the paths the game may run from.
+///
+/// Returns `Ok(None)` for sprites without an image (such as the generic unit
+/// circle).
+pub(crate) fn load_sprite_image(
+    sprite_type: SpriteType,
+) -> Result<Option<image::RgbaImage>, String> {
+    let Some(path) = sprite_type.get_texture_path() else {
+        return Ok(None);
+    };
+
+    // Try multiple possible paths
+    let paths_to_try = [
+        path.to_string(),
+        format!("Graphics/{}", path),
+        format!("C:/Users/eero/Documents/QuestQuest/Graphics/{}", path),
+    ];
+
+    let mut last_error = String::new();
+    for attempt_path in &paths_to_try {
+        match image::open(attempt_path) {
+            Ok(img) => return Ok(Some(img.to_rgba8())),
+            Err(e) => last_error = format!("Failed to load image {}: {}", attempt_path, e),
+        }
+    }
+
+    Err(format!(
+        "Failed to load {} from any path. Last error: {}",
+        path, last_error
+    ))
+}
+
 impl Default for TextureManager {
     fn default() -> Self {
         Self::new()
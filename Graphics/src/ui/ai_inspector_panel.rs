@@ -0,0 +1,168 @@
+//! AI Inspector Panel UI Component
+//!
+//! Displays the decision trace of the last AI turn in a side panel: facts and
+//! candidate counts, each agent's goals and plan, and the executed commands.
+
+use super::widget::{Anchor, Insets, Length, Ui, Widget, WidgetId};
+use crate::draw::text::truncate_text;
+use crate::draw::{DrawBackend, DrawList};
+
+/// Text size for inspector lines
+const TEXT_SIZE: f32 = 10.0;
+
+/// Title text size
+const TITLE_SIZE: f32 = 14.0;
+
+/// Vertical distance between lines
+const LINE_HEIGHT: f32 = 16.0;
+
+/// Panel width in pixels
+const PANEL_WIDTH: f32 = 420.0;
+
+/// Horizontal padding of the title, lines and footer
+const MARGIN: f32 = 15.0;
+
+/// The scrolling list of trace lines
+const TRACE: WidgetId = WidgetId::new("ai_trace");
+
+/// AI inspector panel shown along the right edge of the screen
+pub struct AiInspectorPanel {
+    /// X coordinate of the panel's top-left corner
+    pub x: f32,
+    /// Y coordinate of the panel's top-left corner
+    pub y: f32,
+    /// Width of the panel in pixels
+    pub width: f32,
+    /// Height of the panel in pixels
+    pub height: f32,
+    /// Laid-out panel
+    ui: Ui,
+    /// Trace lines the panel was built for
+    lines: Option<Vec<String>>,
+}
+
+impl AiInspectorPanel {
+    /// Creates a new inspector panel docked to the right edge of the screen
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        let mut panel = Self {
+            x: 0.0,
+            y: 0.0,
+            width: PANEL_WIDTH,
+            height: 0.0,
+            ui: Ui::empty(screen_width, screen_height),
+            lines: None,
+        };
+        panel.set_lines(&[]);
+        panel
+    }
+
+    /// Shows the given trace summary lines
+    ///
+    /// Lines that do not fit the panel can be scrolled to; long lines are
+    /// truncated.
+    pub fn set_lines(&mut self, lines: &[String]) {
+        if self.lines.as_deref() == Some(lines) {
+            return;
+        }
+        self.lines = Some(lines.to_vec());
+        self.ui.set_root(Self::build(lines));
+        let rect = self.ui.root_rect();
+        self.x = rect.x;
+        self.y = rect.y;
+        self.width = rect.width;
+        self.height = rect.height;
+    }
+
+    fn build(lines: &[String]) -> Widget {
+        let line_width = PANEL_WIDTH - MARGIN * 2.0;
+        let trace = Widget::column(0.0).children(lines.iter().map(|line| {
+            // Focused agent lines start with '>' and are drawn highlighted
+            let color = if line.starts_with('>') {
+                [1.0, 0.9, 0.4, 1.0]
+            } else {
+                [0.85, 0.88, 0.92, 1.0]
+            };
+            Widget::label(truncate_text(line, TEXT_SIZE, line_width), TEXT_SIZE, color)
+                .height(Length::Fixed(LINE_HEIGHT))
+        }));
+
+        Widget::column(0.0)
+            .width(Length::Fixed(PANEL_WIDTH))
+            .height(Length::Fill)
+            .anchor(Anchor::Right)
+            .margin(Insets::new(60.0, 10.0, 60.0, 0.0))
+            .background([0.12, 0.14, 0.18, 0.92])
+            .border(3.0, [0.35, 0.45, 0.60, 1.0])
+            .child(
+                Widget::label("AI Inspector", TITLE_SIZE, [1.0, 1.0, 1.0, 1.0])
+                    .width(Length::Fill)
+                    .height(Length::Fixed(40.0))
+                    .padding(Insets::symmetric(MARGIN, 0.0))
+                    .background([0.20, 0.26, 0.36, 1.0]),
+            )
+            .child(
+                Widget::scroll_view(
+                    TRACE,
+                    trace.padding(Insets::symmetric(MARGIN, 0.0)),
+                    LINE_HEIGHT,
+                )
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(Insets::new(12.0, 0.0, 0.0, 0.0)),
+            )
+            .child(
+                Widget::label(
+                    "[Tab] Next unit  [J] Export JSON  [T] Close",
+                    TEXT_SIZE - 1.0,
+                    [0.7, 0.75, 0.8, 1.0],
+                )
+                .width(Length::Fill)
+                .height(Length::Fixed(30.0))
+                .padding(Insets::symmetric(MARGIN, 0.0)),
+            )
+    }
+
+    /// Lays the panel out for a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.ui.resize(screen_width, screen_height);
+        let rect = self.ui.root_rect();
+        self.x = rect.x;
+        self.y = rect.y;
+        self.width = rect.width;
+        self.height = rect.height;
+    }
+
+    /// Draws the panel `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        let rect = self.ui.root_rect();
+        self.x = rect.x;
+        self.y = rect.y;
+        self.width = rect.width;
+        self.height = rect.height;
+    }
+
+    /// Checks if a point is inside the panel
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        self.ui.contains(x, y)
+    }
+
+    /// Scrolls the trace by `lines` lines (positive scrolls down).
+    ///
+    /// Returns whether the trace moved.
+    pub fn scroll(&mut self, lines: f32) -> bool {
+        self.ui.scroll(TRACE, lines)
+    }
+
+    /// Appends the panel's draw commands
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
+    }
+
+    /// Draws the panel with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend) {
+        let mut list = DrawList::new();
+        self.draw(&mut list);
+        backend.submit(&list);
+    }
+}
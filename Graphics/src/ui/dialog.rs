@@ -0,0 +1,134 @@
+//! Dialog Widgets
+//!
+//! The brown dialog frame shared by the choice panels (recruitment, evolution,
+//! item use and inventory): a title bar, an instruction line, a body of
+//! options and a footer with the keyboard hint.
+
+use super::widget::{Align, Anchor, ButtonStyle, Insets, Length, Widget, WidgetId};
+use crate::draw::Color;
+
+/// Text size for instructions and details
+pub(crate) const TEXT_SIZE: f32 = 12.0;
+
+/// Title text size
+pub(crate) const TITLE_SIZE: f32 = 16.0;
+
+/// The dialog box itself, inside any overlay
+pub(crate) const DIALOG: WidgetId = WidgetId::new("dialog");
+
+/// One of the options listed in the dialog body
+pub(crate) const OPTION: WidgetId = WidgetId::new("option");
+
+const PANEL_COLOR: Color = [0.45, 0.38, 0.30, 1.0];
+const BORDER_COLOR: Color = [0.25, 0.18, 0.10, 1.0];
+const TITLE_BAR_COLOR: Color = [0.38, 0.28, 0.16, 1.0];
+const TITLE_LINE_COLOR: Color = [0.5, 0.4, 0.25, 1.0];
+const FOOTER_COLOR: Color = [0.42, 0.35, 0.28, 1.0];
+const OVERLAY_COLOR: Color = [0.0, 0.0, 0.0, 0.5];
+
+/// Color of the highlighted option
+pub(crate) const SELECTED_COLOR: Color = [0.60, 0.50, 0.32, 1.0];
+
+/// Option color when it is neither hovered nor selected
+pub(crate) const OPTION_TEXT_COLOR: Color = [1.0, 1.0, 0.8, 1.0];
+
+/// Looks of an option: only its text until hovered or selected
+pub(crate) fn option_style(selected: bool, text: Color) -> ButtonStyle {
+    ButtonStyle {
+        background: if selected {
+            SELECTED_COLOR
+        } else {
+            [0.0, 0.0, 0.0, 0.0]
+        },
+        hovered: SELECTED_COLOR,
+        pressed: [0.52, 0.42, 0.26, 1.0],
+        text,
+        hovered_text: [1.0, 1.0, 1.0, 1.0],
+        border: None,
+    }
+}
+
+/// A full-width clickable option of the dialog body
+pub(crate) fn option(index: usize, label: String, size: f32, style: ButtonStyle) -> Widget {
+    Widget::button(WidgetId::indexed(OPTION.name, index), label, size, style)
+        .align(Align::Start)
+        .width(Length::Fill)
+        .padding(Insets::symmetric(20.0, 8.0))
+}
+
+/// Numbered options, one per line
+pub(crate) fn numbered_options<'a>(options: impl IntoIterator<Item = &'a str>) -> Widget {
+    Widget::column(4.0).children(options.into_iter().enumerate().map(|(index, label)| {
+        option(
+            index,
+            format!("{}. {}", index + 1, label),
+            TEXT_SIZE + 2.0,
+            option_style(false, OPTION_TEXT_COLOR),
+        )
+    }))
+}
+
+/// The dialog box: title bar, instruction, `body` filling the middle and the
+/// footer hint
+pub(crate) fn dialog(
+    title: impl Into<String>,
+    instruction: impl Into<String>,
+    body: Widget,
+    footer: &str,
+    width: f32,
+    height: f32,
+) -> Widget {
+    let title_bar = Widget::column(0.0)
+        .width(Length::Fill)
+        .background(TITLE_BAR_COLOR)
+        .child(
+            Widget::label(title, TITLE_SIZE, [1.0, 1.0, 1.0, 1.0])
+                .width(Length::Fill)
+                .height(Length::Fixed(48.0))
+                .padding(Insets::symmetric(20.0, 0.0)),
+        )
+        .child(
+            Widget::spacer()
+                .width(Length::Fill)
+                .height(Length::Fixed(2.0))
+                .background(TITLE_LINE_COLOR),
+        );
+
+    Widget::column(0.0)
+        .with_id(DIALOG)
+        .size(width, height)
+        .background(PANEL_COLOR)
+        .border(3.0, BORDER_COLOR)
+        .child(title_bar)
+        .child(
+            Widget::label(instruction, TEXT_SIZE, [0.9, 0.9, 0.9, 1.0])
+                .width(Length::Fill)
+                .padding(Insets::new(12.0, 20.0, 10.0, 20.0)),
+        )
+        .child(
+            body.width(Length::Fill)
+                .height(Length::Fill)
+                .padding(Insets::symmetric(10.0, 0.0)),
+        )
+        .child(
+            Widget::label(footer, TEXT_SIZE - 2.0, [0.8, 0.8, 0.8, 1.0])
+                .width(Length::Fill)
+                .height(Length::Fixed(35.0))
+                .padding(Insets::symmetric(20.0, 0.0))
+                .background(FOOTER_COLOR),
+        )
+}
+
+/// Centers a dialog over a dimmed screen
+pub(crate) fn modal(dialog: Widget) -> Widget {
+    Widget::stack()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .background(OVERLAY_COLOR)
+        .child(dialog.anchor(Anchor::Center))
+}
+
+/// Index of the option under a widget id
+pub(crate) fn option_index(hit: Option<WidgetId>) -> Option<usize> {
+    hit.filter(|id| id.name == OPTION.name).map(|id| id.index)
+}
@@ -3,8 +3,9 @@
 //! Displays the in-game encyclopedia with scrollable text content showing
 //! units, terrain, and game mechanics information.
 
-use super::text_renderer::TextRenderer;
-use gl::types::*;
+use super::widget::{Align, Anchor, Insets, Length, Ui, Widget, WidgetId};
+use crate::draw::text::{text_width, truncate_text, wrap_text};
+use crate::draw::{Color, DrawBackend, DrawList};
 
 /// Width of the encyclopedia panel as percentage of screen width
 const ENCYCLOPEDIA_WIDTH_RATIO: f32 = 0.70;
@@ -18,9 +19,27 @@ const LINE_HEIGHT: f32 = 16.0;
 /// Text size for encyclopedia content
 const TEXT_SIZE: f32 = 10.0;
 
+/// Gap between the panel edges and the content text
+const CONTENT_MARGIN: f32 = 15.0;
+
+/// Text size of content lines
+const CONTENT_TEXT_SIZE: f32 = TEXT_SIZE - 1.0;
+
 /// Text size for titles
 const TITLE_TEXT_SIZE: f32 = 14.0;
 
+/// Height of the title bar in pixels
+const TITLE_BAR_HEIGHT: f32 = 45.0;
+
+/// Height of the footer in pixels
+const FOOTER_HEIGHT: f32 = 35.0;
+
+/// Gap between the content area and the title bar and footer
+const CONTENT_PADDING: f32 = 10.0;
+
+/// The scrolling content area
+const CONTENT: WidgetId = WidgetId::new("encyclopedia_content");
+
 /// Categories available in the encyclopedia
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EncyclopediaCategory {
@@ -30,6 +49,8 @@ pub enum EncyclopediaCategory {
     Terrain,
     /// Game mechanics
     Mechanics,
+    /// Item catalogue
+    Items,
 }
 
 /// Encyclopedia panel that displays wiki-style game information
@@ -42,22 +63,18 @@ pub struct EncyclopediaPanel {
     pub width: f32,
     /// Height of the panel in pixels
     pub height: f32,
-    /// OpenGL Vertex Array Object for rendering
-    vao: GLuint,
-    /// OpenGL Vertex Buffer Object for rendering
-    vbo: GLuint,
-    /// Compiled shader program for UI rendering
-    shader_program: GLuint,
-    /// Text renderer for all text in the encyclopedia
-    text_renderer: TextRenderer,
     /// Current scroll offset (in lines)
     pub scroll_offset: i32,
     /// Current category being displayed
     pub current_category: EncyclopediaCategory,
+    /// Content as given, before fitting it to the panel width
+    source_lines: Vec<String>,
     /// Cached content lines for current view
     content_lines: Vec<String>,
     /// Maximum scroll offset based on content
     max_scroll: i32,
+    /// Laid-out panel
+    ui: Ui,
 }
 
 impl EncyclopediaPanel {
@@ -70,100 +87,75 @@ impl EncyclopediaPanel {
     ///
     /// # Returns
     ///
-    /// A `Result` containing the initialized `EncyclopediaPanel` or an error message
-    pub fn new(screen_width: f32, screen_height: f32) -> Result<Self, String> {
-        let width = screen_width * ENCYCLOPEDIA_WIDTH_RATIO;
-        let height = screen_height * ENCYCLOPEDIA_HEIGHT_RATIO;
-        let x = (screen_width - width) / 2.0;
-        let y = (screen_height - height) / 2.0;
-
-        let (vao, vbo, shader_program) = unsafe { Self::setup_rendering()? };
-        let text_renderer = TextRenderer::new()?;
-
-        Ok(Self {
-            x,
-            y,
-            width,
-            height,
-            vao,
-            vbo,
-            shader_program,
-            text_renderer,
+    /// The initialized `EncyclopediaPanel`
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        let mut panel = Self {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
             scroll_offset: 0,
             current_category: EncyclopediaCategory::Units,
+            source_lines: Vec::new(),
             content_lines: Vec::new(),
             max_scroll: 0,
-        })
+            ui: Ui::empty(screen_width, screen_height),
+        };
+        panel.resize(screen_width, screen_height);
+        panel
+    }
+
+    /// Resizes the panel to its share of a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.ui.resize(screen_width, screen_height);
+        self.update_content(self.source_lines.clone());
+    }
+
+    /// Draws the panel `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.update_content(self.source_lines.clone());
     }
 
-    /// Sets up OpenGL resources for encyclopedia rendering
-    unsafe fn setup_rendering() -> Result<(GLuint, GLuint, GLuint), String> {
-        let vertex_src = r#"
-            #version 330 core
-            layout (location = 0) in vec2 position;
-            uniform vec2 screenSize;
-            
-            void main() {
-                vec2 ndc = (position / screenSize) * 2.0 - 1.0;
-                ndc.y = -ndc.y;
-                gl_Position = vec4(ndc, 0.0, 1.0);
-            }
-        "#;
-
-        let fragment_src = r#"
-            #version 330 core
-            out vec4 FragColor;
-            uniform vec4 color;
-            
-            void main() {
-                FragColor = color;
-            }
-        "#;
-
-        let vertex_shader = gl::CreateShader(gl::VERTEX_SHADER);
-        let c_str = std::ffi::CString::new(vertex_src).unwrap();
-        gl::ShaderSource(vertex_shader, 1, &c_str.as_ptr(), std::ptr::null());
-        gl::CompileShader(vertex_shader);
-
-        let fragment_shader = gl::CreateShader(gl::FRAGMENT_SHADER);
-        let c_str = std::ffi::CString::new(fragment_src).unwrap();
-        gl::ShaderSource(fragment_shader, 1, &c_str.as_ptr(), std::ptr::null());
-        gl::CompileShader(fragment_shader);
-
-        let shader_program = gl::CreateProgram();
-        gl::AttachShader(shader_program, vertex_shader);
-        gl::AttachShader(shader_program, fragment_shader);
-        gl::LinkProgram(shader_program);
-
-        gl::DeleteShader(vertex_shader);
-        gl::DeleteShader(fragment_shader);
-
-        let mut vao = 0;
-        let mut vbo = 0;
-        gl::GenVertexArrays(1, &mut vao);
-        gl::GenBuffers(1, &mut vbo);
-
-        // Return in the order (vao, vbo, shader_program) so callers get the correct handles
-        Ok((vao, vbo, shader_program))
+    /// Size of the panel in layout units
+    fn layout_size(&self) -> (f32, f32) {
+        let (screen_width, screen_height) = self.ui.screen_size();
+        (
+            screen_width * ENCYCLOPEDIA_WIDTH_RATIO,
+            screen_height * ENCYCLOPEDIA_HEIGHT_RATIO,
+        )
     }
 
     /// Update the content to display based on current category
+    ///
+    /// Lines wider than the panel are wrapped, keeping their indentation;
+    /// box-drawing rules are cut off at the panel edge instead.
     pub fn update_content(&mut self, content: Vec<String>) {
-        self.content_lines = content;
+        let (width, height) = self.layout_size();
+        let max_width = width - CONTENT_MARGIN * 2.0;
+        self.content_lines = content
+            .iter()
+            .flat_map(|line| fit_line(line, max_width))
+            .collect();
+        self.source_lines = content;
+        let viewport_height = height - TITLE_BAR_HEIGHT - FOOTER_HEIGHT - CONTENT_PADDING * 2.0;
         self.max_scroll = (self.content_lines.len() as i32)
-            .saturating_sub((self.height / LINE_HEIGHT) as i32)
+            .saturating_sub((viewport_height / LINE_HEIGHT) as i32)
             .max(0);
         self.scroll_offset = self.scroll_offset.clamp(0, self.max_scroll);
+        self.rebuild();
     }
 
     /// Scroll the content up by the specified number of lines
     pub fn scroll_up(&mut self, lines: i32) {
         self.scroll_offset = (self.scroll_offset - lines).max(0);
+        self.rebuild();
     }
 
     /// Scroll the content down by the specified number of lines
     pub fn scroll_down(&mut self, lines: i32) {
         self.scroll_offset = (self.scroll_offset + lines).min(self.max_scroll);
+        self.rebuild();
     }
 
     /// Switch to a different category
@@ -171,343 +163,144 @@ impl EncyclopediaPanel {
         if self.current_category != category {
             self.current_category = category;
             self.scroll_offset = 0;
+            self.rebuild();
         }
     }
 
-    /// Render the encyclopedia panel
-    pub fn render(&mut self, screen_width: f32, screen_height: f32) {
-        unsafe {
-            // Enable blending for transparency (for panel, borders, etc.)
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-
-            // Draw the panel as an overlay on-top of the game scene.
-            // IMPORTANT: do NOT clear the framebuffer here (that would erase the already-rendered game).
-            // Disable depth testing and depth writes so the panel doesn't modify the depth buffer
-            // (we want it to lay on top of the scene). Text is rendered after the boxes so it appears above.
-            gl::Disable(gl::DEPTH_TEST);
-            gl::DepthMask(gl::FALSE);
-
-            // Render panel background (light brown - fully opaque, non-transparent)
-            self.render_box(
-                self.x,
-                self.y,
-                self.width,
-                self.height,
-                [0.45, 0.38, 0.30, 1.0],
-                screen_width,
-                screen_height,
-            );
-
-            // Render border (worn book edge - darker brown, opaque)
-            self.render_border(
-                self.x,
-                self.y,
-                self.width,
-                self.height,
-                [0.25, 0.18, 0.10, 1.0],
-                screen_width,
-                screen_height,
-            );
-
-            // Unbind VAO before text rendering
-            gl::BindVertexArray(0);
-
-            // Render title bar
-            self.render_title_bar(screen_width, screen_height);
-
-            // Render content
-            self.render_content(screen_width, screen_height);
-
-            // Render scroll indicator
-            self.render_scroll_indicator(screen_width, screen_height);
-
-            // Restore depth write and depth test state for subsequent rendering.
-            gl::DepthMask(gl::TRUE);
-            gl::Enable(gl::DEPTH_TEST);
-
-            gl::Disable(gl::BLEND);
-        }
+    /// Rebuilds the widget tree for the current content and scroll position
+    fn rebuild(&mut self) {
+        self.ui.set_root(self.build());
+        self.ui
+            .scroll_to(CONTENT, self.scroll_offset as f32 * LINE_HEIGHT);
+        let rect = self.ui.root_rect();
+        self.x = rect.x;
+        self.y = rect.y;
+        self.width = rect.width;
+        self.height = rect.height;
     }
 
-    /// Render the scrollable content
-    unsafe fn render_content(&mut self, screen_width: f32, screen_height: f32) {
-        let content_start_y = self.y + 60.0;
-        let margin = 15.0;
-        let max_visible_lines = ((self.height - 80.0) / LINE_HEIGHT) as usize;
-
-        // Render content area background (light beige/cream - opaque)
-        let content_height = self.height - 80.0;
-        self.render_box(
-            self.x + 5.0,
-            content_start_y - 5.0,
-            self.width - 10.0,
-            content_height + 10.0,
-            [0.55, 0.48, 0.40, 1.0],
-            screen_width,
-            screen_height,
-        );
-
-        let start_line = self.scroll_offset as usize;
-        let end_line = (start_line + max_visible_lines).min(self.content_lines.len());
-
-        for (i, line) in self.content_lines[start_line..end_line].iter().enumerate() {
-            let y = content_start_y + (i as f32 * LINE_HEIGHT);
-
-            // Determine color based on line content (white text on brown)
-            let color = if line.starts_with("╔")
-                || line.starts_with("╠")
-                || line.starts_with("╚")
-                || line.starts_with("║")
-                || line.starts_with("═")
-            {
-                [0.9, 0.85, 0.75, 1.0] // Border color (light beige)
-            } else if line.contains("•") {
-                [1.0, 1.0, 1.0, 1.0] // List item color (pure white for emphasis)
-            } else {
-                [0.95, 0.95, 0.95, 1.0] // Default text color (off-white)
-            };
-
-            self.text_renderer.render_text(
-                line,
-                self.x + margin,
-                y,
-                TEXT_SIZE - 1.0,
-                color,
-                screen_width,
-                screen_height,
-            );
-        }
-    }
-
-    /// Render scroll indicator showing position in content
-    unsafe fn render_scroll_indicator(&mut self, screen_width: f32, screen_height: f32) {
-        let footer_height = 35.0;
-        let footer_y = self.y + self.height - footer_height;
-
-        // Render footer background (medium brown - opaque)
-        self.render_box(
-            self.x,
-            footer_y,
-            self.width,
-            footer_height,
-            [0.42, 0.35, 0.28, 1.0],
-            screen_width,
-            screen_height,
-        );
-
-        // Footer top accent line (decorative book line - golden brown)
-        self.render_box(
-            self.x,
-            footer_y,
-            self.width,
-            2.0,
-            [0.5, 0.4, 0.25, 1.0],
-            screen_width,
-            screen_height,
-        );
-
-        if self.max_scroll > 0 {
-            let indicator_text = format!(
+    fn build(&self) -> Widget {
+        let title = match self.current_category {
+            EncyclopediaCategory::Units => "Units Encyclopedia",
+            EncyclopediaCategory::Terrain => "Terrain Encyclopedia",
+            EncyclopediaCategory::Mechanics => "Game Mechanics Encyclopedia",
+            EncyclopediaCategory::Items => "Item Catalogue",
+        };
+        let title_bar = Widget::column(0.0)
+            .width(Length::Fill)
+            .background([0.38, 0.28, 0.16, 1.0])
+            .child(
+                Widget::label(title, TITLE_TEXT_SIZE, [1.0, 1.0, 1.0, 1.0])
+                    .width(Length::Fill)
+                    .height(Length::Fixed(TITLE_BAR_HEIGHT - 2.0))
+                    .padding(Insets::symmetric(20.0, 0.0)),
+            )
+            .child(accent_line());
+
+        let lines = Widget::column(0.0).children(self.content_lines.iter().map(|line| {
+            Widget::label(line.as_str(), CONTENT_TEXT_SIZE, line_color(line))
+                .height(Length::Fixed(LINE_HEIGHT))
+        }));
+        let content = Widget::scroll_view(
+            CONTENT,
+            lines.padding(Insets::symmetric(CONTENT_MARGIN - 5.0, 0.0)),
+            LINE_HEIGHT,
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(Insets::symmetric(0.0, 5.0))
+        .margin(Insets::symmetric(5.0, CONTENT_PADDING - 5.0))
+        .background([0.55, 0.48, 0.40, 1.0]);
+
+        // Scroll position, when there is anything to scroll, and the close hint
+        let indicator = if self.max_scroll > 0 {
+            format!(
                 "↑/↓ to scroll | Line {}/{}",
                 self.scroll_offset + 1,
                 self.content_lines.len()
+            )
+        } else {
+            String::new()
+        };
+        let footer = Widget::column(0.0)
+            .width(Length::Fill)
+            .background([0.42, 0.35, 0.28, 1.0])
+            .child(accent_line())
+            .child(
+                Widget::row(0.0)
+                    .width(Length::Fill)
+                    .height(Length::Fixed(FOOTER_HEIGHT - 2.0))
+                    .padding(Insets::symmetric(CONTENT_MARGIN, 0.0))
+                    .align(Align::Center)
+                    .child(Widget::label(
+                        indicator,
+                        TEXT_SIZE - 2.0,
+                        [1.0, 1.0, 1.0, 1.0],
+                    ))
+                    .child(Widget::spacer().width(Length::Fill))
+                    .child(Widget::label(
+                        "Press E or ESC to close",
+                        TEXT_SIZE - 2.0,
+                        [1.0, 1.0, 1.0, 1.0],
+                    )),
             );
-            let indicator_y = footer_y + 12.0;
-
-            self.text_renderer.render_text(
-                &indicator_text,
-                self.x + 15.0,
-                indicator_y,
-                TEXT_SIZE - 2.0,
-                [1.0, 1.0, 1.0, 1.0], // White text
-                screen_width,
-                screen_height,
-            );
-        }
 
-        // Show ESC hint
-        let hint_text = "Press E or ESC to close";
-        self.text_renderer.render_text(
-            hint_text,
-            self.x + self.width - 180.0,
-            footer_y + 12.0,
-            TEXT_SIZE - 2.0,
-            [1.0, 1.0, 1.0, 1.0], // White text
-            screen_width,
-            screen_height,
-        );
+        let (width, height) = self.layout_size();
+        Widget::column(0.0)
+            .size(width, height)
+            .anchor(Anchor::Center)
+            .background([0.45, 0.38, 0.30, 1.0])
+            .border(3.0, [0.25, 0.18, 0.10, 1.0])
+            .child(title_bar)
+            .child(content)
+            .child(footer)
     }
 
-    /// Render a filled box
-    #[allow(clippy::too_many_arguments)]
-    unsafe fn render_box(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        color: [f32; 4],
-        screen_width: f32,
-        screen_height: f32,
-    ) {
-        gl::UseProgram(self.shader_program);
-
-        // Use proper CStrings for uniform names
-        let screen_name = std::ffi::CString::new("screenSize").unwrap();
-        let color_name = std::ffi::CString::new("color").unwrap();
-
-        let screen_size_loc = gl::GetUniformLocation(self.shader_program, screen_name.as_ptr());
-        let color_loc = gl::GetUniformLocation(self.shader_program, color_name.as_ptr());
-
-        // Provide actual screen dimensions so the vertex shader can convert correctly
-        gl::Uniform2f(screen_size_loc, screen_width, screen_height);
-        gl::Uniform4f(color_loc, color[0], color[1], color[2], color[3]);
-
-        let vertices: [f32; 12] = [
-            x,
-            y,
-            x + width,
-            y,
-            x + width,
-            y + height,
-            x,
-            y,
-            x + width,
-            y + height,
-            x,
-            y + height,
-        ];
-
-        gl::BindVertexArray(self.vao);
-        gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            (vertices.len() * std::mem::size_of::<f32>()) as GLsizeiptr,
-            vertices.as_ptr() as *const _,
-            gl::DYNAMIC_DRAW,
-        );
-
-        gl::VertexAttribPointer(
-            0,
-            2,
-            gl::FLOAT,
-            gl::FALSE,
-            2 * std::mem::size_of::<f32>() as GLsizei,
-            std::ptr::null(),
-        );
-        gl::EnableVertexAttribArray(0);
-
-        gl::DrawArrays(gl::TRIANGLES, 0, 6);
+    /// Appends the panel's draw commands
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
     }
 
-    /// Render a border around a box
-    #[allow(clippy::too_many_arguments)]
-    unsafe fn render_border(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        color: [f32; 4],
-        screen_width: f32,
-        screen_height: f32,
-    ) {
-        let border_width = 2.0;
-        // Top
-        self.render_box(
-            x,
-            y,
-            width,
-            border_width,
-            color,
-            screen_width,
-            screen_height,
-        );
-        // Bottom
-        self.render_box(
-            x,
-            y + height - border_width,
-            width,
-            border_width,
-            color,
-            screen_width,
-            screen_height,
-        );
-        // Left
-        self.render_box(
-            x,
-            y,
-            border_width,
-            height,
-            color,
-            screen_width,
-            screen_height,
-        );
-        // Right
-        self.render_box(
-            x + width - border_width,
-            y,
-            border_width,
-            height,
-            color,
-            screen_width,
-            screen_height,
-        );
+    /// Draws the panel with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend) {
+        let mut list = DrawList::new();
+        self.draw(&mut list);
+        backend.submit(&list);
     }
+}
 
-    /// Render the title bar of the encyclopedia panel
-    unsafe fn render_title_bar(&mut self, screen_width: f32, screen_height: f32) {
-        let title_bar_height = 45.0;
-        let title_y = self.y;
-
-        // Render title bar background (darker brown)
-        self.render_box(
-            self.x,
-            title_y,
-            self.width,
-            title_bar_height,
-            [0.38, 0.28, 0.16, 1.0],
-            screen_width,
-            screen_height,
-        );
-
-        // Render bottom accent line (golden brown)
-        self.render_box(
-            self.x,
-            title_y + title_bar_height - 2.0,
-            self.width,
-            2.0,
-            [0.5, 0.4, 0.25, 1.0],
-            screen_width,
-            screen_height,
-        );
-
-        // Render category title text
-        let title = match self.current_category {
-            EncyclopediaCategory::Units => "Units Encyclopedia",
-            EncyclopediaCategory::Terrain => "Terrain Encyclopedia",
-            EncyclopediaCategory::Mechanics => "Game Mechanics Encyclopedia",
-        };
+/// Two-pixel golden brown line under the title and above the footer
+fn accent_line() -> Widget {
+    Widget::spacer()
+        .width(Length::Fill)
+        .height(Length::Fixed(2.0))
+        .background([0.5, 0.4, 0.25, 1.0])
+}
 
-        self.text_renderer.render_text(
-            title,
-            self.x + 20.0,
-            title_y + 13.0,
-            TITLE_TEXT_SIZE,
-            [1.0, 1.0, 1.0, 1.0],
-            screen_width,
-            screen_height,
-        );
+/// Text color of a content line
+fn line_color(line: &str) -> Color {
+    if line.starts_with(['╔', '╠', '╚', '║', '═']) {
+        [0.9, 0.85, 0.75, 1.0] // Border color (light beige)
+    } else if line.contains('•') {
+        [1.0, 1.0, 1.0, 1.0] // List item color (pure white for emphasis)
+    } else {
+        [0.95, 0.95, 0.95, 1.0] // Default text color (off-white)
     }
 }
 
-impl Drop for EncyclopediaPanel {
-    fn drop(&mut self) {
-        unsafe {
-            gl::DeleteVertexArrays(1, &self.vao);
-            gl::DeleteBuffers(1, &self.vbo);
-            gl::DeleteProgram(self.shader_program);
-        }
+/// Splits a content line into lines at most `max_width` pixels wide
+fn fit_line(line: &str, max_width: f32) -> Vec<String> {
+    if text_width(line, CONTENT_TEXT_SIZE) <= max_width {
+        return vec![line.to_string()];
+    }
+    if line.starts_with(['╔', '╠', '╚', '║', '═']) {
+        return vec![truncate_text(line, CONTENT_TEXT_SIZE, max_width)];
     }
+    let body = line.trim_start();
+    let indent = &line[..line.len() - body.len()];
+    let indent_width = text_width(indent, CONTENT_TEXT_SIZE);
+    wrap_text(body, CONTENT_TEXT_SIZE, max_width - indent_width)
+        .into_iter()
+        .map(|wrapped| format!("{}{}", indent, wrapped))
+        .collect()
 }
@@ -0,0 +1,112 @@
+//! Evolution Panel UI Component
+//!
+//! Displays the evolution branches of a unit that leveled up so the player can
+//! pick one.
+
+use super::dialog::{self, DIALOG};
+use super::widget::{Ui, Widget};
+use crate::draw::{DrawBackend, DrawList};
+
+/// Size of the dialog box in pixels
+const WIDTH: f32 = 500.0;
+const HEIGHT: f32 = 300.0;
+
+/// Evolution choice dialog listing the unit types a unit can evolve into
+pub struct EvolutionPanel {
+    /// X coordinate of the panel's top-left corner
+    pub x: f32,
+    /// Y coordinate of the panel's top-left corner
+    pub y: f32,
+    /// Width of the panel in pixels
+    pub width: f32,
+    /// Height of the panel in pixels
+    pub height: f32,
+    /// Laid-out dialog
+    ui: Ui,
+    /// Unit name and options the dialog was built for
+    content: Option<(String, Vec<String>)>,
+}
+
+impl EvolutionPanel {
+    /// Creates a new evolution panel centered on screen
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        let mut panel = Self {
+            x: 0.0,
+            y: 0.0,
+            width: WIDTH,
+            height: HEIGHT,
+            ui: Ui::empty(screen_width, screen_height),
+            content: None,
+        };
+        panel.set_options("", &[]);
+        panel
+    }
+
+    /// Shows the evolution choice for `unit_name` with one line per option
+    pub fn set_options(&mut self, unit_name: &str, options: &[String]) {
+        if self
+            .content
+            .as_ref()
+            .is_some_and(|(name, current)| name == unit_name && current == options)
+        {
+            return;
+        }
+        self.content = Some((unit_name.to_string(), options.to_vec()));
+        self.ui.set_root(Self::build(unit_name, options));
+        self.update_bounds();
+    }
+
+    fn build(unit_name: &str, options: &[String]) -> Widget {
+        dialog::modal(dialog::dialog(
+            format!("{} can evolve!", unit_name),
+            "Choose an evolution (click or press its number):",
+            dialog::numbered_options(options.iter().map(String::as_str)),
+            "A branch must be chosen to continue",
+            WIDTH,
+            HEIGHT,
+        ))
+    }
+
+    fn update_bounds(&mut self) {
+        if let Some(rect) = self.ui.rect(DIALOG) {
+            self.x = rect.x;
+            self.y = rect.y;
+            self.width = rect.width;
+            self.height = rect.height;
+        }
+    }
+
+    /// Lays the panel out for a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.ui.resize(screen_width, screen_height);
+        self.update_bounds();
+    }
+
+    /// Draws the dialog `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.update_bounds();
+    }
+
+    /// Highlights the option under the cursor
+    pub fn cursor_moved(&mut self, x: f32, y: f32) {
+        self.ui.cursor_moved(x, y);
+    }
+
+    /// Appends the dialog's draw commands
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
+    }
+
+    /// Draws the panel with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend) {
+        let mut list = DrawList::new();
+        self.draw(&mut list);
+        backend.submit(&list);
+    }
+
+    /// Gets the option index at the given screen position, if any
+    pub fn get_option_at_position(&self, x: f32, y: f32) -> Option<usize> {
+        dialog::option_index(self.ui.hit_test(x, y))
+    }
+}
@@ -0,0 +1,182 @@
+//! Inventory Panel UI Component
+//!
+//! Shows a unit's equipped items and inventory, with the stat changes of
+//! equipping or unequipping the selected item, and the keys for equipping,
+//! dropping and giving items.
+
+use super::dialog::{self, DIALOG, OPTION_TEXT_COLOR, TEXT_SIZE};
+use super::widget::{Anchor, Insets, Length, Ui, Widget};
+use crate::draw::{DrawBackend, DrawList};
+
+/// Size of the dialog box in pixels
+const WIDTH: f32 = 420.0;
+const HEIGHT: f32 = 460.0;
+
+/// What the inventory dialog shows
+#[derive(Clone, PartialEq)]
+struct Content {
+    unit_name: String,
+    entries: Vec<(String, bool)>,
+    selected: Option<usize>,
+    details: Vec<String>,
+    giving: bool,
+}
+
+/// Inventory screen listing the items a unit carries
+pub struct InventoryPanel {
+    /// X coordinate of the panel's top-left corner
+    pub x: f32,
+    /// Y coordinate of the panel's top-left corner
+    pub y: f32,
+    /// Width of the panel in pixels
+    pub width: f32,
+    /// Height of the panel in pixels
+    pub height: f32,
+    /// Laid-out dialog
+    ui: Ui,
+    /// Content the dialog was built for
+    content: Option<Content>,
+}
+
+impl InventoryPanel {
+    /// Creates a new inventory panel on the left side of the screen
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        let mut panel = Self {
+            x: 0.0,
+            y: 0.0,
+            width: WIDTH,
+            height: HEIGHT,
+            ui: Ui::empty(screen_width, screen_height),
+            content: None,
+        };
+        panel.set_items("", &[], None, &[], false);
+        panel
+    }
+
+    /// Shows the items `unit_name` carries with one line per entry
+    ///
+    /// `entries` pairs each item's label with whether it is equipped; the
+    /// entry at `selected` is highlighted and `details` (such as the stat
+    /// changes of toggling it) are listed below. While `giving` is set the
+    /// instruction asks for the ally to give the selected item to.
+    pub fn set_items(
+        &mut self,
+        unit_name: &str,
+        entries: &[(String, bool)],
+        selected: Option<usize>,
+        details: &[String],
+        giving: bool,
+    ) {
+        let content = Content {
+            unit_name: unit_name.to_string(),
+            entries: entries.to_vec(),
+            selected,
+            details: details.to_vec(),
+            giving,
+        };
+        if self.content.as_ref() == Some(&content) {
+            return;
+        }
+        self.ui.set_root(Self::build(&content));
+        self.content = Some(content);
+        self.update_bounds();
+    }
+
+    fn build(content: &Content) -> Widget {
+        let instruction = if content.giving {
+            "Click an adjacent ally to give the item to:"
+        } else if content.entries.is_empty() {
+            "This unit carries no items."
+        } else {
+            "Select an item (click or press its number):"
+        };
+
+        // Equipped items are tagged and tinted
+        let entries = content
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, (label, equipped))| {
+                let (text, color) = if *equipped {
+                    (
+                        format!("{}. [E] {}", index + 1, label),
+                        [0.8, 1.0, 0.8, 1.0],
+                    )
+                } else {
+                    (format!("{}. {}", index + 1, label), OPTION_TEXT_COLOR)
+                };
+                let style = dialog::option_style(content.selected == Some(index), color);
+                dialog::option(index, text, TEXT_SIZE + 1.0, style)
+                    .padding(Insets::symmetric(20.0, 5.0))
+            });
+
+        let details = content.details.iter().map(|line| {
+            Widget::label(line.as_str(), TEXT_SIZE, [0.95, 0.85, 0.6, 1.0])
+                .margin(Insets::symmetric(20.0, 0.0))
+        });
+
+        let body = Widget::column(4.0)
+            .children(entries)
+            .child(Widget::spacer().height(Length::Fixed(6.0)))
+            .children(details);
+
+        dialog::dialog(
+            format!("{} - Inventory", content.unit_name),
+            instruction,
+            body,
+            "E equip/unequip  D drop  G give  B or ESC close",
+            WIDTH,
+            HEIGHT,
+        )
+        .anchor(Anchor::Left)
+        .margin(Insets::new(0.0, 0.0, 0.0, 20.0))
+    }
+
+    fn update_bounds(&mut self) {
+        if let Some(rect) = self.ui.rect(DIALOG) {
+            self.x = rect.x;
+            self.y = rect.y;
+            self.width = rect.width;
+            self.height = rect.height;
+        }
+    }
+
+    /// Lays the panel out for a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.ui.resize(screen_width, screen_height);
+        self.update_bounds();
+    }
+
+    /// Draws the dialog `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.update_bounds();
+    }
+
+    /// Highlights the entry under the cursor
+    pub fn cursor_moved(&mut self, x: f32, y: f32) {
+        self.ui.cursor_moved(x, y);
+    }
+
+    /// Appends the dialog's draw commands
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
+    }
+
+    /// Draws the panel with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend) {
+        let mut list = DrawList::new();
+        self.draw(&mut list);
+        backend.submit(&list);
+    }
+
+    /// Checks if a point is inside the panel
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        self.ui.contains(x, y)
+    }
+
+    /// Gets the entry index at the given screen position, if any
+    pub fn get_entry_at_position(&self, x: f32, y: f32) -> Option<usize> {
+        dialog::option_index(self.ui.hit_test(x, y))
+    }
+}
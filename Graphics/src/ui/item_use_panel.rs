@@ -0,0 +1,129 @@
+//! Item Use Panel UI Component
+//!
+//! Lists the consumables in a unit's inventory so the player can use one, and
+//! prompts for the target once an item is chosen.
+
+use super::dialog::{self, DIALOG};
+use super::widget::{Anchor, Insets, Ui, Widget};
+use crate::draw::{DrawBackend, DrawList};
+
+/// Size of the dialog box in pixels
+const WIDTH: f32 = 420.0;
+const HEIGHT: f32 = 300.0;
+
+/// Use-item dialog listing the consumables a unit carries
+pub struct ItemUsePanel {
+    /// X coordinate of the panel's top-left corner
+    pub x: f32,
+    /// Y coordinate of the panel's top-left corner
+    pub y: f32,
+    /// Width of the panel in pixels
+    pub width: f32,
+    /// Height of the panel in pixels
+    pub height: f32,
+    /// Laid-out dialog
+    ui: Ui,
+    /// Unit name, options and targeting flag the dialog was built for
+    content: Option<(String, Vec<String>, bool)>,
+}
+
+impl ItemUsePanel {
+    /// Creates a new item use panel on the left side of the screen
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        let mut panel = Self {
+            x: 0.0,
+            y: 0.0,
+            width: WIDTH,
+            height: HEIGHT,
+            ui: Ui::empty(screen_width, screen_height),
+            content: None,
+        };
+        panel.set_options("", &[], false);
+        panel
+    }
+
+    /// Shows the consumables of `unit_name` with one line per option
+    ///
+    /// While `targeting` is set the instruction asks for the target unit
+    /// instead of an item.
+    pub fn set_options(&mut self, unit_name: &str, options: &[String], targeting: bool) {
+        if self
+            .content
+            .as_ref()
+            .is_some_and(|(name, current, target)| {
+                name == unit_name && current == options && *target == targeting
+            })
+        {
+            return;
+        }
+        self.content = Some((unit_name.to_string(), options.to_vec(), targeting));
+        self.ui.set_root(Self::build(unit_name, options, targeting));
+        self.update_bounds();
+    }
+
+    fn build(unit_name: &str, options: &[String], targeting: bool) -> Widget {
+        let instruction = if targeting {
+            "Click the unit itself or an adjacent ally:"
+        } else {
+            "Choose an item (click or press its number):"
+        };
+        dialog::dialog(
+            format!("{} - Use Item", unit_name),
+            instruction,
+            dialog::numbered_options(options.iter().map(String::as_str)),
+            "Press U or ESC to close",
+            WIDTH,
+            HEIGHT,
+        )
+        .anchor(Anchor::Left)
+        .margin(Insets::new(0.0, 0.0, 0.0, 20.0))
+    }
+
+    fn update_bounds(&mut self) {
+        if let Some(rect) = self.ui.rect(DIALOG) {
+            self.x = rect.x;
+            self.y = rect.y;
+            self.width = rect.width;
+            self.height = rect.height;
+        }
+    }
+
+    /// Lays the panel out for a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.ui.resize(screen_width, screen_height);
+        self.update_bounds();
+    }
+
+    /// Draws the dialog `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+        self.update_bounds();
+    }
+
+    /// Highlights the option under the cursor
+    pub fn cursor_moved(&mut self, x: f32, y: f32) {
+        self.ui.cursor_moved(x, y);
+    }
+
+    /// Appends the dialog's draw commands
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
+    }
+
+    /// Draws the panel with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend) {
+        let mut list = DrawList::new();
+        self.draw(&mut list);
+        backend.submit(&list);
+    }
+
+    /// Checks if a point is inside the panel
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        self.ui.contains(x, y)
+    }
+
+    /// Gets the option index at the given screen position, if any
+    pub fn get_option_at_position(&self, x: f32, y: f32) -> Option<usize> {
+        dialog::option_index(self.ui.hit_test(x, y))
+    }
+}
@@ -0,0 +1,289 @@
+//! Minimap UI Component
+//!
+//! Shows the whole map in the bottom-left corner of the screen: terrain
+//! colors, structures, team-colored unit dots and the part of the map the
+//! camera currently shows. Clicking or dragging on it moves the camera.
+//! Hexes under fog of war are darkened and hide their units.
+
+use crate::core::{HexGrid, Hexagon};
+use crate::draw::{DrawBackend, DrawList};
+use crate::math::Vec2;
+
+/// Gap between the minimap and the screen edges
+const MARGIN: f32 = 10.0;
+
+/// Gap between the minimap border and the map drawn inside it
+const PADDING: f32 = 6.0;
+
+/// Brightness of hexes under fog of war
+const FOG_BRIGHTNESS: f32 = 0.35;
+
+/// Unit dot size relative to a hex cell
+const UNIT_DOT_SCALE: f32 = 0.6;
+
+/// Structure marker size relative to a hex cell
+const STRUCTURE_SCALE: f32 = 0.8;
+
+/// Overview of the whole map with click-to-jump camera control
+pub struct Minimap {
+    /// X coordinate of the minimap's top-left corner
+    pub x: f32,
+    /// Y coordinate of the minimap's top-left corner
+    pub y: f32,
+    /// Width of the minimap in pixels
+    pub width: f32,
+    /// Height of the minimap in pixels
+    pub height: f32,
+    /// Whether a press on the minimap is being dragged
+    dragging: bool,
+}
+
+/// Mapping between world coordinates and minimap pixels for one map
+struct MinimapLayout {
+    /// Top-left corner of the map's bounding box (smallest x, largest y)
+    world_origin: Vec2,
+    /// Pixels per world unit
+    scale: f32,
+    /// Pixel position of `world_origin`
+    origin: Vec2,
+    /// Size in pixels of one hex cell
+    cell: Vec2,
+}
+
+impl MinimapLayout {
+    fn to_minimap(&self, world_pos: Vec2) -> Vec2 {
+        Vec2::new(
+            self.origin.x + (world_pos.x - self.world_origin.x) * self.scale,
+            self.origin.y + (self.world_origin.y - world_pos.y) * self.scale,
+        )
+    }
+
+    fn to_world(&self, x: f32, y: f32) -> Vec2 {
+        Vec2::new(
+            self.world_origin.x + (x - self.origin.x) / self.scale,
+            self.world_origin.y - (y - self.origin.y) / self.scale,
+        )
+    }
+}
+
+impl Minimap {
+    /// Creates a new minimap in the bottom-left corner of the screen
+    pub fn new(_screen_width: f32, screen_height: f32) -> Self {
+        let width = 240.0;
+        let height = 180.0;
+
+        Self {
+            x: MARGIN,
+            y: screen_height - height - MARGIN,
+            width,
+            height,
+            dragging: false,
+        }
+    }
+
+    /// Keeps the minimap in the bottom-left corner of a resized screen
+    pub fn resize(&mut self, _screen_width: f32, screen_height: f32) {
+        self.y = screen_height - self.height - MARGIN;
+    }
+
+    /// Checks if a screen position is on the minimap
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    /// Whether the player is dragging on the minimap
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Handles a mouse press: when it lands on the minimap, centers the camera
+    /// on that spot and starts a drag.
+    ///
+    /// Returns `true` if the minimap took the press.
+    pub fn press(&mut self, x: f32, y: f32, hex_grid: &mut HexGrid) -> bool {
+        if !self.contains(x, y) {
+            return false;
+        }
+        self.dragging = true;
+        self.jump_camera(x, y, hex_grid);
+        true
+    }
+
+    /// Follows the cursor with the camera while dragging.
+    ///
+    /// Returns `true` if the camera moved.
+    pub fn drag(&mut self, x: f32, y: f32, hex_grid: &mut HexGrid) -> bool {
+        if !self.dragging {
+            return false;
+        }
+        // Keep the cursor position inside the minimap so dragging past its
+        // edge stops at the map border
+        let x = x.clamp(self.x, self.x + self.width);
+        let y = y.clamp(self.y, self.y + self.height);
+        self.jump_camera(x, y, hex_grid);
+        true
+    }
+
+    /// Ends a drag
+    pub fn release(&mut self) {
+        self.dragging = false;
+    }
+
+    fn jump_camera(&self, x: f32, y: f32, hex_grid: &mut HexGrid) {
+        if let Some(layout) = self.layout(hex_grid) {
+            hex_grid.camera.jump_to(layout.to_world(x, y));
+        }
+    }
+
+    /// Fits the map's bounding box into the minimap, keeping its proportions
+    fn layout(&self, hex_grid: &HexGrid) -> Option<MinimapLayout> {
+        let half_cell = Vec2::new(hex_grid.hex_size, hex_grid.hex_size * 3.0_f32.sqrt() / 2.0);
+        let mut centers = hex_grid.hexagons.values().map(|hex| hex.world_pos);
+        let first = centers.next()?;
+        let (min, max) = centers.fold((first, first), |(min, max), pos| {
+            (
+                Vec2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Vec2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        });
+        let min = min - half_cell;
+        let max = max + half_cell;
+
+        let inner_width = self.width - 2.0 * PADDING;
+        let inner_height = self.height - 2.0 * PADDING;
+        let scale = (inner_width / (max.x - min.x)).min(inner_height / (max.y - min.y));
+        let origin = Vec2::new(
+            self.x + PADDING + (inner_width - (max.x - min.x) * scale) / 2.0,
+            self.y + PADDING + (inner_height - (max.y - min.y) * scale) / 2.0,
+        );
+
+        Some(MinimapLayout {
+            world_origin: Vec2::new(min.x, max.y),
+            scale,
+            origin,
+            // Flat-top hexes sit 1.5 sizes apart horizontally
+            cell: Vec2::new(hex_grid.hex_size * 1.5 * scale, half_cell.y * 2.0 * scale),
+        })
+    }
+
+    /// Renders the map overview and the camera viewport
+    pub fn draw(&self, list: &mut DrawList, hex_grid: &HexGrid) {
+        // Background and border
+        list.rect(
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            [0.08, 0.08, 0.12, 0.9],
+        );
+        list.border(
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            2.0,
+            [0.6, 0.6, 0.65, 1.0],
+        );
+
+        let Some(layout) = self.layout(hex_grid) else {
+            return;
+        };
+
+        let mut hexagons: Vec<&Hexagon> = hex_grid.hexagons.values().collect();
+        // Hash map order varies between runs; sort so frames are reproducible
+        hexagons.sort_by_key(|hex| (hex.coord.q, hex.coord.r));
+
+        // Terrain cells
+        for hex in &hexagons {
+            let color = fogged(hex, hex.sprite.get_color_tint());
+            Self::cell(list, &layout, hex, 1.0, color);
+        }
+
+        // Structures, dimmed but still shown under fog
+        for hex in &hexagons {
+            if let Some(structure) = hex.structure_sprite {
+                let color = fogged(hex, structure.get_color_tint());
+                Self::cell(list, &layout, hex, STRUCTURE_SCALE, color);
+            }
+        }
+
+        // Team-colored unit dots, hidden under fog
+        for hex in &hexagons {
+            if hex.fogged {
+                continue;
+            }
+            let Some(unit_color) = hex.unit_color() else {
+                continue;
+            };
+            let [r, g, b] = hex.unit_tint.unwrap_or(unit_color);
+            Self::cell(list, &layout, hex, UNIT_DOT_SCALE, [r, g, b, 1.0]);
+        }
+
+        // Camera viewport: clip space spans two world units at zoom 1.0
+        let camera = &hex_grid.camera;
+        let half_view = 1.0 / camera.zoom();
+        let top_left = layout.to_minimap(Vec2::new(
+            camera.position.x - half_view,
+            camera.position.y + half_view,
+        ));
+        let bottom_right = layout.to_minimap(Vec2::new(
+            camera.position.x + half_view,
+            camera.position.y - half_view,
+        ));
+        let left = top_left.x.max(self.x);
+        let top = top_left.y.max(self.y);
+        let right = bottom_right.x.min(self.x + self.width);
+        let bottom = bottom_right.y.min(self.y + self.height);
+        if right > left && bottom > top {
+            list.border(
+                left,
+                top,
+                right - left,
+                bottom - top,
+                1.5,
+                [1.0, 1.0, 1.0, 0.9],
+            );
+        }
+    }
+
+    /// Renders the minimap with `backend`
+    pub fn render(&self, backend: &mut dyn DrawBackend, hex_grid: &HexGrid) {
+        let mut list = DrawList::new();
+        self.draw(&mut list, hex_grid);
+        backend.submit(&list);
+    }
+
+    /// Appends a rectangle centered on a hex, `scale` times a hex cell
+    fn cell(
+        list: &mut DrawList,
+        layout: &MinimapLayout,
+        hex: &Hexagon,
+        scale: f32,
+        color: [f32; 4],
+    ) {
+        let center = layout.to_minimap(hex.world_pos);
+        let width = layout.cell.x * scale;
+        let height = layout.cell.y * scale;
+        list.rect(
+            center.x - width / 2.0,
+            center.y - height / 2.0,
+            width,
+            height,
+            color,
+        );
+    }
+}
+
+/// Opaque minimap color of a hex, darkened when it is under fog of war
+fn fogged(hex: &Hexagon, [r, g, b]: [f32; 3]) -> [f32; 4] {
+    if hex.fogged {
+        [
+            r * FOG_BRIGHTNESS,
+            g * FOG_BRIGHTNESS,
+            b * FOG_BRIGHTNESS,
+            1.0,
+        ]
+    } else {
+        [r, g, b, 1.0]
+    }
+}
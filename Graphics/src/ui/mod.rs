@@ -1,17 +1,30 @@
+pub mod ai_inspector_panel;
 pub mod combat_panel;
+mod dialog;
 pub mod encyclopedia_panel;
+pub mod evolution_panel;
+pub mod inventory_panel;
+pub mod item_use_panel;
+pub mod minimap;
 pub mod recruitment_panel;
 pub mod submenu_panel_ui;
-pub mod text_renderer;
 pub mod ui_panel;
+pub mod widget;
 
+pub use ai_inspector_panel::AiInspectorPanel;
 pub use encyclopedia_panel::{EncyclopediaCategory, EncyclopediaPanel};
+pub use evolution_panel::EvolutionPanel;
+pub use inventory_panel::InventoryPanel;
+pub use item_use_panel::ItemUsePanel;
+pub use minimap::Minimap;
 pub use recruitment_panel::RecruitmentPanel;
 pub use submenu_panel_ui::SubmenuPanel;
-pub use text_renderer::TextRenderer;
 pub use ui_panel::AttackDisplayInfo;
-pub use ui_panel::Button;
+pub use ui_panel::TimeOfDayDisplayInfo;
 pub use ui_panel::UiPanel;
 pub use ui_panel::UnitDisplayInfo;
+pub use widget::{
+    Align, Anchor, ButtonState, ButtonStyle, Insets, Length, Rect, Ui, Widget, WidgetId,
+};
 
 pub use combat_panel::CombatPanel;
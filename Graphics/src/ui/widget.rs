@@ -0,0 +1,1211 @@
+//! Widget Tree
+//!
+//! Retained-mode layout for the UI panels. A panel describes its content once
+//! as a tree of [`Widget`]s and keeps it in a [`Ui`], which positions every
+//! widget for the current screen size, draws the tree as draw commands,
+//! tracks button hover and press states and answers which widget is under
+//! the cursor. Panels rebuild the tree when their content changes; the `Ui`
+//! carries hover states and scroll offsets over to the new tree by
+//! [`WidgetId`], and lays it out again when the window is resized.
+//!
+//! - Containers: [`Widget::column`], [`Widget::row`] and [`Widget::stack`]
+//! - Content: [`Widget::label`], [`Widget::paragraph`], [`Widget::button`],
+//!   [`Widget::custom`] and [`Widget::spacer`]
+//! - Scrolling: [`Widget::scroll_view`]
+//!
+//! Each widget has a width and a height [`Length`], padding inside its
+//! background and border, and a margin outside them. Inside a stack (and at
+//! the root) a widget is placed by its [`Anchor`]; columns and rows place
+//! children one after another and share the space left over between their
+//! [`Length::Fill`] children.
+//!
+//! A [`Ui`] can be scaled with [`Ui::set_scale`] to follow the player's UI
+//! scale setting: widgets keep their sizes in layout units and everything is
+//! drawn and hit-tested that many times larger.
+//!
+//! ```rust
+//! use graphics::ui::widget::{Align, Anchor, ButtonStyle, Insets, Length, Ui, Widget, WidgetId};
+//!
+//! const OK: WidgetId = WidgetId::new("ok");
+//!
+//! let dialog = Widget::column(10.0)
+//!     .align(Align::Center)
+//!     .width(Length::Fixed(300.0))
+//!     .padding(Insets::all(20.0))
+//!     .background([0.2, 0.2, 0.3, 1.0])
+//!     .anchor(Anchor::Center)
+//!     .child(Widget::label("Continue?", 16.0, [1.0, 1.0, 1.0, 1.0]))
+//!     .child(Widget::button(OK, "OK", 14.0, ButtonStyle::default()));
+//!
+//! let mut ui = Ui::new(dialog, 800.0, 600.0);
+//! let ok = ui.rect(OK).unwrap();
+//! assert_eq!(ui.hit_test(ok.x + 1.0, ok.y + 1.0), Some(OK));
+//!
+//! // Resizing keeps the dialog centered
+//! ui.resize(1024.0, 768.0);
+//! assert_eq!(ui.root_rect().x, (1024.0 - 300.0) / 2.0);
+//! ```
+
+use crate::draw::text::{wrap_text, FontStack};
+use crate::draw::{Color, DrawList};
+use std::collections::HashMap;
+
+/// Identifies a widget for hit-testing and for keeping its state when the
+/// tree is rebuilt
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidgetId {
+    /// What the widget is (e.g. `"end_turn"`)
+    pub name: &'static str,
+    /// Position among widgets of the same name, such as list entries
+    pub index: usize,
+}
+
+impl WidgetId {
+    /// Id of a single widget
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, index: 0 }
+    }
+
+    /// Id of one of several widgets with the same name
+    pub const fn indexed(name: &'static str, index: usize) -> Self {
+        Self { name, index }
+    }
+}
+
+/// Axis-aligned rectangle in screen pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Whether a point lies inside the rectangle (edges included)
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    /// Whether `other` lies completely inside the rectangle
+    pub fn encloses(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x + other.width <= self.x + self.width
+            && other.y + other.height <= self.y + self.height
+    }
+
+    /// The rectangle shrunk by `insets` on each side
+    pub fn inset(&self, insets: Insets) -> Rect {
+        Rect::new(
+            self.x + insets.left,
+            self.y + insets.top,
+            (self.width - insets.horizontal()).max(0.0),
+            (self.height - insets.vertical()).max(0.0),
+        )
+    }
+}
+
+/// Space on each side of a rectangle
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Insets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Insets {
+    /// No space on any side
+    pub const ZERO: Insets = Insets {
+        top: 0.0,
+        right: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+    };
+
+    /// The same space on every side
+    pub const fn all(value: f32) -> Self {
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// `horizontal` on the left and right, `vertical` on the top and bottom
+    pub const fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    pub const fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Left plus right
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// Top plus bottom
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+/// How long a widget is along one axis
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Length {
+    /// Exactly this many pixels
+    Fixed(f32),
+    /// As long as its content
+    #[default]
+    Fit,
+    /// All the space the parent has left, shared with other filling siblings
+    Fill,
+}
+
+/// Where a widget sits inside a stack or on the screen
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Fractions of the free space left of and above the widget
+    fn fractions(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::Top => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::Bottom => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// Placement across a container's axis, or of text inside a label
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl Align {
+    fn offset(self, free: f32) -> f32 {
+        match self {
+            Align::Start => 0.0,
+            Align::Center => free / 2.0,
+            Align::End => free,
+        }
+    }
+}
+
+/// Interaction state of a button
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonState {
+    #[default]
+    Normal,
+    /// The cursor is over the button
+    Hovered,
+    /// The mouse button went down on the button and has not been released
+    Pressed,
+}
+
+/// Colors of a button in each state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonStyle {
+    pub background: Color,
+    pub hovered: Color,
+    pub pressed: Color,
+    pub text: Color,
+    pub hovered_text: Color,
+    /// Outline thickness and color
+    pub border: Option<(f32, Color)>,
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self {
+            background: [0.2, 0.25, 0.35, 1.0],
+            hovered: [0.3, 0.4, 0.6, 1.0],
+            pressed: [0.15, 0.2, 0.3, 1.0],
+            text: [1.0, 1.0, 1.0, 1.0],
+            hovered_text: [1.0, 1.0, 0.8, 1.0],
+            border: None,
+        }
+    }
+}
+
+impl ButtonStyle {
+    /// Background color for a state
+    pub fn background_for(&self, state: ButtonState) -> Color {
+        match state {
+            ButtonState::Normal => self.background,
+            ButtonState::Hovered => self.hovered,
+            ButtonState::Pressed => self.pressed,
+        }
+    }
+
+    /// Label color for a state
+    pub fn text_for(&self, state: ButtonState) -> Color {
+        match state {
+            ButtonState::Normal => self.text,
+            ButtonState::Hovered | ButtonState::Pressed => self.hovered_text,
+        }
+    }
+}
+
+/// Draws a custom widget's content into its rectangle
+type Painter = Box<dyn Fn(&mut DrawList, Rect)>;
+
+enum Kind {
+    Column {
+        spacing: f32,
+        children: Vec<Widget>,
+    },
+    Row {
+        spacing: f32,
+        children: Vec<Widget>,
+    },
+    Stack {
+        children: Vec<Widget>,
+    },
+    Label {
+        text: String,
+        size: f32,
+        color: Color,
+        wrap: bool,
+        shadow: bool,
+    },
+    Button {
+        label: String,
+        size: f32,
+        style: ButtonStyle,
+        state: ButtonState,
+        enabled: bool,
+    },
+    ScrollView {
+        child: Box<Widget>,
+        offset: f32,
+        step: f32,
+    },
+    Custom(Painter),
+    Spacer,
+}
+
+/// A node of the widget tree
+pub struct Widget {
+    kind: Kind,
+    id: Option<WidgetId>,
+    width: Length,
+    height: Length,
+    padding: Insets,
+    margin: Insets,
+    background: Option<Color>,
+    border: Option<(f32, Color)>,
+    anchor: Anchor,
+    align: Align,
+    rect: Rect,
+}
+
+impl Widget {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            id: None,
+            width: Length::Fit,
+            height: Length::Fit,
+            padding: Insets::ZERO,
+            margin: Insets::ZERO,
+            background: None,
+            border: None,
+            anchor: Anchor::TopLeft,
+            align: Align::Start,
+            rect: Rect::default(),
+        }
+    }
+
+    /// Children laid out top to bottom, `spacing` pixels apart
+    pub fn column(spacing: f32) -> Self {
+        Self::new(Kind::Column {
+            spacing,
+            children: Vec::new(),
+        })
+    }
+
+    /// Children laid out left to right, `spacing` pixels apart
+    pub fn row(spacing: f32) -> Self {
+        Self::new(Kind::Row {
+            spacing,
+            children: Vec::new(),
+        })
+    }
+
+    /// Children drawn on top of each other, each placed by its anchor
+    pub fn stack() -> Self {
+        Self::new(Kind::Stack {
+            children: Vec::new(),
+        })
+    }
+
+    /// One line of text
+    pub fn label(text: impl Into<String>, size: f32, color: Color) -> Self {
+        Self::new(Kind::Label {
+            text: text.into(),
+            size,
+            color,
+            wrap: false,
+            shadow: false,
+        })
+    }
+
+    /// Text wrapped to the widget's width
+    pub fn paragraph(text: impl Into<String>, size: f32, color: Color) -> Self {
+        Self::new(Kind::Label {
+            text: text.into(),
+            size,
+            color,
+            wrap: true,
+            shadow: false,
+        })
+        .width(Length::Fill)
+    }
+
+    /// Clickable button; its label is centered unless aligned otherwise
+    pub fn button(id: WidgetId, label: impl Into<String>, size: f32, style: ButtonStyle) -> Self {
+        let mut button = Self::new(Kind::Button {
+            label: label.into(),
+            size,
+            style,
+            state: ButtonState::Normal,
+            enabled: true,
+        })
+        .padding(Insets::symmetric(16.0, 8.0))
+        .align(Align::Center);
+        button.id = Some(id);
+        button
+    }
+
+    /// Shows part of a taller child and scrolls it `step` pixels per line
+    pub fn scroll_view(id: WidgetId, child: Widget, step: f32) -> Self {
+        let mut view = Self::new(Kind::ScrollView {
+            child: Box::new(child),
+            offset: 0.0,
+            step,
+        });
+        view.id = Some(id);
+        view
+    }
+
+    /// Content drawn by `paint` into the widget's rectangle, for graphics
+    /// the other widgets cannot express (bars, charts, sprites)
+    pub fn custom(paint: impl Fn(&mut DrawList, Rect) + 'static) -> Self {
+        Self::new(Kind::Custom(Box::new(paint)))
+    }
+
+    /// Empty space; with a [`Length::Fill`] length it pushes its siblings apart
+    pub fn spacer() -> Self {
+        Self::new(Kind::Spacer)
+    }
+
+    /// Appends a child to a container
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget is not a column, row or stack.
+    pub fn child(mut self, child: Widget) -> Self {
+        self.children_mut()
+            .expect("only containers have children")
+            .push(child);
+        self
+    }
+
+    /// Appends several children to a container
+    pub fn children(mut self, children: impl IntoIterator<Item = Widget>) -> Self {
+        self.children_mut()
+            .expect("only containers have children")
+            .extend(children);
+        self
+    }
+
+    pub fn with_id(mut self, id: WidgetId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Fixed width and height
+    pub fn size(self, width: f32, height: f32) -> Self {
+        self.width(Length::Fixed(width))
+            .height(Length::Fixed(height))
+    }
+
+    /// Space between the widget's edges and its content
+    pub fn padding(mut self, padding: Insets) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Space kept free around the widget
+    pub fn margin(mut self, margin: Insets) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Outline drawn inside the widget's edges, on top of its content
+    pub fn border(mut self, thickness: f32, color: Color) -> Self {
+        self.border = Some((thickness, color));
+        self
+    }
+
+    /// Placement inside a stack or on the screen
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Placement of children across a column or row, or of a label's text
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Draws a label with a drop shadow
+    pub fn shadow(mut self) -> Self {
+        if let Kind::Label { shadow, .. } = &mut self.kind {
+            *shadow = true;
+        }
+        self
+    }
+
+    /// Whether a button can be hovered and clicked (buttons start enabled)
+    pub fn enabled(mut self, value: bool) -> Self {
+        if let Kind::Button { enabled, .. } = &mut self.kind {
+            *enabled = value;
+        }
+        self
+    }
+
+    pub fn id(&self) -> Option<WidgetId> {
+        self.id
+    }
+
+    /// Where the last layout put the widget
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// State of a button; `None` for other widgets
+    pub fn button_state(&self) -> Option<ButtonState> {
+        match &self.kind {
+            Kind::Button { state, .. } => Some(*state),
+            _ => None,
+        }
+    }
+
+    fn children_mut(&mut self) -> Option<&mut Vec<Widget>> {
+        match &mut self.kind {
+            Kind::Column { children, .. }
+            | Kind::Row { children, .. }
+            | Kind::Stack { children } => Some(children),
+            _ => None,
+        }
+    }
+
+    /// Direct children, the scroll view's content included
+    fn children_ref(&self) -> Vec<&Widget> {
+        match &self.kind {
+            Kind::Column { children, .. }
+            | Kind::Row { children, .. }
+            | Kind::Stack { children } => children.iter().collect(),
+            Kind::ScrollView { child, .. } => vec![child],
+            _ => Vec::new(),
+        }
+    }
+
+    fn for_each_mut(&mut self, visit: &mut dyn FnMut(&mut Widget)) {
+        visit(self);
+        match &mut self.kind {
+            Kind::Column { children, .. }
+            | Kind::Row { children, .. }
+            | Kind::Stack { children } => children
+                .iter_mut()
+                .for_each(|child| child.for_each_mut(visit)),
+            Kind::ScrollView { child, .. } => child.for_each_mut(visit),
+            _ => {}
+        }
+    }
+
+    fn find(&self, id: WidgetId) -> Option<&Widget> {
+        if self.id == Some(id) {
+            return Some(self);
+        }
+        self.children_ref()
+            .into_iter()
+            .find_map(|child| child.find(id))
+    }
+
+    /// Size the widget would like, without margin, when at most
+    /// `available_width` pixels wide
+    fn measure(&self, available_width: f32) -> (f32, f32) {
+        let inner_width = match self.width {
+            Length::Fixed(width) => width,
+            _ => available_width,
+        } - self.padding.horizontal();
+        let (content_width, content_height) = self.measure_content(inner_width.max(0.0));
+        let width = match self.width {
+            Length::Fixed(width) => width,
+            _ => content_width + self.padding.horizontal(),
+        };
+        let height = match self.height {
+            Length::Fixed(height) => height,
+            _ => content_height + self.padding.vertical(),
+        };
+        (width, height)
+    }
+
+    fn measure_content(&self, available_width: f32) -> (f32, f32) {
+        match &self.kind {
+            Kind::Column { spacing, children } => {
+                let sizes: Vec<(f32, f32)> = children
+                    .iter()
+                    .map(|child| outer_size(child, available_width))
+                    .collect();
+                (
+                    sizes.iter().map(|size| size.0).fold(0.0, f32::max),
+                    sizes.iter().map(|size| size.1).sum::<f32>() + gaps(*spacing, sizes.len()),
+                )
+            }
+            Kind::Row { spacing, children } => {
+                let sizes: Vec<(f32, f32)> = children
+                    .iter()
+                    .map(|child| outer_size(child, available_width))
+                    .collect();
+                (
+                    sizes.iter().map(|size| size.0).sum::<f32>() + gaps(*spacing, sizes.len()),
+                    sizes.iter().map(|size| size.1).fold(0.0, f32::max),
+                )
+            }
+            Kind::Stack { children } => children
+                .iter()
+                .map(|child| outer_size(child, available_width))
+                .fold((0.0, 0.0), |(w, h), (cw, ch)| (w.max(cw), h.max(ch))),
+            Kind::Label {
+                text, size, wrap, ..
+            } => {
+                let fonts = FontStack::shared();
+                if *wrap {
+                    let lines = wrap_text(text, *size, available_width);
+                    let width = lines
+                        .iter()
+                        .map(|line| fonts.measure(line, *size))
+                        .fold(0.0, f32::max);
+                    (width, lines.len() as f32 * fonts.line_height(*size))
+                } else {
+                    (fonts.measure(text, *size), fonts.line_height(*size))
+                }
+            }
+            Kind::Button { label, size, .. } => {
+                let fonts = FontStack::shared();
+                (fonts.measure(label, *size), fonts.line_height(*size))
+            }
+            Kind::ScrollView { child, .. } => outer_size(child, available_width),
+            Kind::Custom(_) | Kind::Spacer => (0.0, 0.0),
+        }
+    }
+
+    /// Positions the widget and its children inside `rect`
+    fn layout(&mut self, rect: Rect) {
+        self.rect = rect;
+        let inner = rect.inset(self.padding);
+        let align = self.align;
+        match &mut self.kind {
+            Kind::Column { spacing, children } => {
+                layout_line(children, inner, *spacing, align, true)
+            }
+            Kind::Row { spacing, children } => layout_line(children, inner, *spacing, align, false),
+            Kind::Stack { children } => {
+                for child in children {
+                    place_anchored(child, inner);
+                }
+            }
+            Kind::ScrollView {
+                child,
+                offset,
+                step: _,
+            } => {
+                let (_, content_height) = outer_size(child, inner.width);
+                let max_offset = (content_height - inner.height).max(0.0);
+                *offset = offset.clamp(0.0, max_offset);
+                let margin = child.margin;
+                child.layout(Rect::new(
+                    inner.x + margin.left,
+                    inner.y + margin.top - *offset,
+                    (inner.width - margin.horizontal()).max(0.0),
+                    (content_height - margin.vertical()).max(inner.height - margin.vertical()),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends the widget's draw commands, skipping widgets that do not fit
+    /// completely inside `clip`
+    fn draw(&self, list: &mut DrawList, clip: Option<Rect>) {
+        let visible = clip.is_none_or(|clip| clip.encloses(&self.rect));
+        let rect = self.rect;
+        if visible {
+            if let Some(color) = self.background {
+                list.rect(rect.x, rect.y, rect.width, rect.height, color);
+            }
+        }
+
+        let inner = rect.inset(self.padding);
+        match &self.kind {
+            Kind::Column { children, .. }
+            | Kind::Row { children, .. }
+            | Kind::Stack { children } => {
+                for child in children {
+                    child.draw(list, clip);
+                }
+            }
+            Kind::Label {
+                text,
+                size,
+                color,
+                wrap,
+                shadow,
+            } if visible => {
+                let fonts = FontStack::shared();
+                let lines = if *wrap {
+                    wrap_text(text, *size, inner.width)
+                } else {
+                    vec![text.clone()]
+                };
+                let line_height = fonts.line_height(*size);
+                let block_height = line_height * lines.len() as f32;
+                let mut y = inner.y + (inner.height - block_height).max(0.0) / 2.0;
+                for line in lines {
+                    let free = inner.width - fonts.measure(&line, *size);
+                    let x = inner.x + self.align.offset(free);
+                    if *shadow {
+                        list.shadowed_text(&line, x, y, *size, *color, 1.0);
+                    } else {
+                        list.text(line, x, y, *size, *color);
+                    }
+                    y += line_height;
+                }
+            }
+            Kind::Button {
+                label,
+                size,
+                style,
+                state,
+                ..
+            } if visible => {
+                list.rect(
+                    rect.x,
+                    rect.y,
+                    rect.width,
+                    rect.height,
+                    style.background_for(*state),
+                );
+                if let Some((thickness, color)) = style.border {
+                    list.border(rect.x, rect.y, rect.width, rect.height, thickness, color);
+                }
+                let fonts = FontStack::shared();
+                let x = inner.x + self.align.offset(inner.width - fonts.measure(label, *size));
+                let y = inner.y + (inner.height - fonts.line_height(*size)) / 2.0;
+                list.text(label.as_str(), x, y, *size, style.text_for(*state));
+            }
+            Kind::ScrollView { child, .. } => {
+                let viewport = match clip {
+                    Some(clip) if !clip.encloses(&inner) => clip,
+                    _ => inner,
+                };
+                child.draw(list, Some(viewport));
+            }
+            Kind::Custom(paint) if visible => paint(list, inner),
+            _ => {}
+        }
+
+        if visible {
+            if let Some((thickness, color)) = self.border {
+                list.border(rect.x, rect.y, rect.width, rect.height, thickness, color);
+            }
+        }
+    }
+
+    /// Topmost widget with an id under the point
+    fn hit_test(&self, x: f32, y: f32) -> Option<WidgetId> {
+        if !self.rect.contains(x, y) {
+            return None;
+        }
+        let child_hit = match &self.kind {
+            Kind::ScrollView { .. } if !self.rect.inset(self.padding).contains(x, y) => None,
+            _ => self
+                .children_ref()
+                .into_iter()
+                .rev()
+                .find_map(|child| child.hit_test(x, y)),
+        };
+        child_hit.or(match &self.kind {
+            Kind::Button { enabled: false, .. } => None,
+            _ => self.id,
+        })
+    }
+}
+
+/// Size of a widget including its margin
+fn outer_size(widget: &Widget, available_width: f32) -> (f32, f32) {
+    let margin = widget.margin;
+    let (width, height) = widget.measure((available_width - margin.horizontal()).max(0.0));
+    (width + margin.horizontal(), height + margin.vertical())
+}
+
+fn gaps(spacing: f32, count: usize) -> f32 {
+    spacing * count.saturating_sub(1) as f32
+}
+
+/// Lays out the children of a column (`vertical`) or row one after another
+fn layout_line(children: &mut [Widget], inner: Rect, spacing: f32, align: Align, vertical: bool) {
+    let main_length = |widget: &Widget| {
+        if vertical {
+            widget.height
+        } else {
+            widget.width
+        }
+    };
+    let cross_length = |widget: &Widget| {
+        if vertical {
+            widget.width
+        } else {
+            widget.height
+        }
+    };
+
+    let sizes: Vec<(f32, f32)> = children
+        .iter()
+        .map(|child| outer_size(child, inner.width))
+        .collect();
+    let main = |(width, height): (f32, f32)| if vertical { height } else { width };
+
+    let fixed: f32 = children
+        .iter()
+        .zip(&sizes)
+        .filter(|(child, _)| main_length(child) != Length::Fill)
+        .map(|(_, size)| main(*size))
+        .sum::<f32>()
+        + gaps(spacing, children.len());
+    let fill_count = children
+        .iter()
+        .filter(|child| main_length(child) == Length::Fill)
+        .count();
+    let (inner_main, inner_cross) = if vertical {
+        (inner.height, inner.width)
+    } else {
+        (inner.width, inner.height)
+    };
+    let fill_share = if fill_count > 0 {
+        ((inner_main - fixed) / fill_count as f32).max(0.0)
+    } else {
+        0.0
+    };
+
+    let mut cursor = 0.0;
+    for (child, (width, height)) in children.iter_mut().zip(sizes) {
+        let margin = child.margin;
+        let (margin_main, margin_cross) = if vertical {
+            (margin.vertical(), margin.horizontal())
+        } else {
+            (margin.horizontal(), margin.vertical())
+        };
+        let (outer_main, outer_cross) = if vertical {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        let outer_main = if main_length(child) == Length::Fill {
+            fill_share
+        } else {
+            outer_main
+        };
+        let outer_cross = if cross_length(child) == Length::Fill {
+            inner_cross
+        } else {
+            outer_cross.min(inner_cross)
+        };
+        let cross = align.offset(inner_cross - outer_cross);
+        let (child_main, child_cross) = (
+            (outer_main - margin_main).max(0.0),
+            (outer_cross - margin_cross).max(0.0),
+        );
+        let rect = if vertical {
+            Rect::new(
+                inner.x + cross + margin.left,
+                inner.y + cursor + margin.top,
+                child_cross,
+                child_main,
+            )
+        } else {
+            Rect::new(
+                inner.x + cursor + margin.left,
+                inner.y + cross + margin.top,
+                child_main,
+                child_cross,
+            )
+        };
+        child.layout(rect);
+        cursor += outer_main + spacing;
+    }
+}
+
+/// Lays out a widget inside `area` at its anchor
+fn place_anchored(widget: &mut Widget, area: Rect) {
+    let margin = widget.margin;
+    let space = area.inset(margin);
+    let (measured_width, measured_height) = widget.measure(space.width);
+    let width = match widget.width {
+        Length::Fill => space.width,
+        _ => measured_width,
+    };
+    let height = match widget.height {
+        Length::Fill => space.height,
+        _ => measured_height,
+    };
+    let (fx, fy) = widget.anchor.fractions();
+    widget.layout(Rect::new(
+        space.x + (space.width - width) * fx,
+        space.y + (space.height - height) * fy,
+        width,
+        height,
+    ));
+}
+
+/// A laid-out widget tree with its interaction state
+pub struct Ui {
+    root: Widget,
+    screen_width: f32,
+    screen_height: f32,
+    /// Screen pixels per layout unit
+    scale: f32,
+    cursor: Option<(f32, f32)>,
+    pressed: Option<WidgetId>,
+}
+
+impl Ui {
+    /// Lays out `root` on a `screen_width` x `screen_height` screen, placed
+    /// by its anchor and margin
+    pub fn new(root: Widget, screen_width: f32, screen_height: f32) -> Self {
+        let mut ui = Self {
+            root,
+            screen_width,
+            screen_height,
+            scale: 1.0,
+            cursor: None,
+            pressed: None,
+        };
+        ui.layout();
+        ui
+    }
+
+    /// An empty tree, for panels that build their content later
+    pub fn empty(screen_width: f32, screen_height: f32) -> Self {
+        Self::new(Widget::spacer(), screen_width, screen_height)
+    }
+
+    /// Replaces the tree, keeping the scroll offsets and pressed button of
+    /// widgets whose ids appear in both trees
+    pub fn set_root(&mut self, root: Widget) {
+        let mut offsets = HashMap::new();
+        self.root.for_each_mut(&mut |widget| {
+            if let (Some(id), Kind::ScrollView { offset, .. }) = (widget.id, &widget.kind) {
+                offsets.insert(id, *offset);
+            }
+        });
+        self.root = root;
+        self.root.for_each_mut(&mut |widget| {
+            if let (Some(id), Kind::ScrollView { offset, .. }) = (widget.id, &mut widget.kind) {
+                *offset = offsets.get(&id).copied().unwrap_or(0.0);
+            }
+        });
+        if self
+            .pressed
+            .is_some_and(|pressed| self.root.find(pressed).is_none())
+        {
+            self.pressed = None;
+        }
+        self.layout();
+    }
+
+    /// Lays the tree out again for a new screen size
+    pub fn resize(&mut self, screen_width: f32, screen_height: f32) {
+        self.screen_width = screen_width;
+        self.screen_height = screen_height;
+        self.layout();
+    }
+
+    /// Size of the screen the tree is laid out on, in layout units (screen
+    /// pixels divided by the scale)
+    pub fn screen_size(&self) -> (f32, f32) {
+        (
+            self.screen_width / self.scale,
+            self.screen_height / self.scale,
+        )
+    }
+
+    /// Draws the tree `scale` times its layout size and lays it out again on
+    /// the correspondingly smaller screen
+    pub fn set_scale(&mut self, scale: f32) {
+        if scale > 0.0 && scale != self.scale {
+            self.scale = scale;
+            self.layout();
+        }
+    }
+
+    /// Screen pixels per layout unit
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    fn layout(&mut self) {
+        let (width, height) = self.screen_size();
+        place_anchored(&mut self.root, Rect::new(0.0, 0.0, width, height));
+        self.update_button_states();
+    }
+
+    /// A screen point in layout units
+    fn to_layout(&self, x: f32, y: f32) -> (f32, f32) {
+        (x / self.scale, y / self.scale)
+    }
+
+    /// A rectangle in layout units on the screen
+    fn to_screen(&self, rect: Rect) -> Rect {
+        Rect::new(
+            rect.x * self.scale,
+            rect.y * self.scale,
+            rect.width * self.scale,
+            rect.height * self.scale,
+        )
+    }
+
+    /// Where the root widget is on the screen
+    pub fn root_rect(&self) -> Rect {
+        self.to_screen(self.root.rect)
+    }
+
+    /// Where a widget is on the screen
+    pub fn rect(&self, id: WidgetId) -> Option<Rect> {
+        self.root.find(id).map(|widget| self.to_screen(widget.rect))
+    }
+
+    /// A widget of the tree; its [`Widget::rect`] is in layout units
+    pub fn widget(&self, id: WidgetId) -> Option<&Widget> {
+        self.root.find(id)
+    }
+
+    /// Whether a point is on the root widget
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.root_rect().contains(x, y)
+    }
+
+    /// The topmost widget with an id under a point; disabled buttons and
+    /// content scrolled out of view are skipped
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<WidgetId> {
+        let (x, y) = self.to_layout(x, y);
+        self.root.hit_test(x, y)
+    }
+
+    /// Updates button hover states for a cursor position.
+    ///
+    /// Returns the widget under the cursor.
+    pub fn cursor_moved(&mut self, x: f32, y: f32) -> Option<WidgetId> {
+        self.cursor = Some((x, y));
+        self.update_button_states();
+        self.hit_test(x, y)
+    }
+
+    /// Forgets the cursor, e.g. when it leaves the window
+    pub fn cursor_left(&mut self) {
+        self.cursor = None;
+        self.update_button_states();
+    }
+
+    /// Starts pressing the button under a point.
+    ///
+    /// Returns the widget under the point.
+    pub fn press(&mut self, x: f32, y: f32) -> Option<WidgetId> {
+        self.cursor = Some((x, y));
+        let hit = self.hit_test(x, y);
+        self.pressed = hit.filter(|id| self.root.find(*id).is_some_and(Widget::is_button));
+        self.update_button_states();
+        hit
+    }
+
+    /// Ends a press. Returns the button clicked: the one pressed, if the
+    /// release happens over it as well.
+    pub fn release(&mut self, x: f32, y: f32) -> Option<WidgetId> {
+        self.cursor = Some((x, y));
+        let clicked = self
+            .pressed
+            .take()
+            .filter(|id| self.hit_test(x, y) == Some(*id));
+        self.update_button_states();
+        clicked
+    }
+
+    fn update_button_states(&mut self) {
+        let hovered = self.cursor.and_then(|(x, y)| self.hit_test(x, y));
+        let pressed = self.pressed;
+        self.root.for_each_mut(&mut |widget| {
+            let id = widget.id;
+            if let Kind::Button { state, enabled, .. } = &mut widget.kind {
+                *state = if !*enabled || id.is_none() || id != hovered {
+                    ButtonState::Normal
+                } else if id == pressed {
+                    ButtonState::Pressed
+                } else {
+                    ButtonState::Hovered
+                };
+            }
+        });
+    }
+
+    /// Scrolls the scroll view under a point by `lines` steps (positive
+    /// scrolls down).
+    ///
+    /// Returns whether a view scrolled.
+    pub fn scroll_at(&mut self, x: f32, y: f32, lines: f32) -> bool {
+        let (x, y) = self.to_layout(x, y);
+        let mut target = None;
+        self.root.for_each_mut(&mut |widget| {
+            if matches!(widget.kind, Kind::ScrollView { .. }) && widget.rect.contains(x, y) {
+                target = widget.id;
+            }
+        });
+        target.is_some_and(|id| self.scroll(id, lines))
+    }
+
+    /// Scrolls a scroll view by `lines` steps (positive scrolls down).
+    ///
+    /// Returns whether its offset changed.
+    pub fn scroll(&mut self, id: WidgetId, lines: f32) -> bool {
+        let before = self.scroll_offset(id);
+        self.root.for_each_mut(&mut |widget| {
+            if widget.id == Some(id) {
+                if let Kind::ScrollView { offset, step, .. } = &mut widget.kind {
+                    *offset += lines * *step;
+                }
+            }
+        });
+        self.layout();
+        self.scroll_offset(id) != before
+    }
+
+    /// How far a scroll view is scrolled, in layout units
+    pub fn scroll_offset(&self, id: WidgetId) -> f32 {
+        match self.root.find(id).map(|widget| &widget.kind) {
+            Some(Kind::ScrollView { offset, .. }) => *offset,
+            _ => 0.0,
+        }
+    }
+
+    /// Scrolls a scroll view to `offset` layout units from the top, clamped to
+    /// its content
+    pub fn scroll_to(&mut self, id: WidgetId, offset: f32) {
+        self.root.for_each_mut(&mut |widget| {
+            if widget.id == Some(id) {
+                if let Kind::ScrollView {
+                    offset: current, ..
+                } = &mut widget.kind
+                {
+                    *current = offset;
+                }
+            }
+        });
+        self.layout();
+    }
+
+    /// How far a scroll view can be scrolled, in layout units
+    pub fn max_scroll_offset(&self, id: WidgetId) -> f32 {
+        match self.root.find(id) {
+            Some(
+                view @ Widget {
+                    kind: Kind::ScrollView { child, .. },
+                    ..
+                },
+            ) => {
+                let viewport = view.rect.inset(view.padding).height;
+                (child.rect.height + child.margin.vertical() - viewport).max(0.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Appends the draw commands of the whole tree
+    pub fn draw(&self, list: &mut DrawList) {
+        if self.scale == 1.0 {
+            self.root.draw(list, None);
+        } else {
+            let mut layout = DrawList::new();
+            self.root.draw(&mut layout, None);
+            list.append_scaled(&layout, self.scale);
+        }
+    }
+}
+
+impl Widget {
+    fn is_button(&self) -> bool {
+        matches!(self.kind, Kind::Button { .. })
+    }
+}
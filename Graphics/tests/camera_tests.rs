@@ -0,0 +1,203 @@
+//! Camera Tests
+//!
+//! Tests for camera zoom, smooth panning, edge scrolling, map bounds and hex
+//! picking at different zoom levels.
+
+use graphics::core::camera::{EDGE_SCROLL_MARGIN, MAX_ZOOM, MIN_ZOOM};
+use graphics::core::hex_lookup::MapHexEntry;
+use graphics::{Camera, HexCoord, HexGrid, SpriteType, Vec2, WorldHexLookupTable};
+use std::collections::HashMap;
+
+const WINDOW: Vec2 = Vec2 { x: 800.0, y: 600.0 };
+
+fn assert_close(a: Vec2, b: Vec2) {
+    assert!(
+        a.distance(&b) < 1e-3,
+        "expected {:?} to be close to {:?}",
+        a,
+        b
+    );
+}
+
+fn map(radius: i32) -> HexGrid {
+    let mut tiles = Vec::new();
+    for q in -radius..=radius {
+        for r in -radius..=radius {
+            if (q + r).abs() <= radius {
+                tiles.push((HexCoord::new(q, r), SpriteType::Grasslands));
+            }
+        }
+    }
+    HexGrid::from_tiles(tiles)
+}
+
+#[test]
+fn test_screen_world_round_trip_when_zoomed() {
+    let mut camera = Camera::new();
+    camera.jump_to(Vec2::new(0.3, -0.2));
+    for zoom in [MIN_ZOOM, 1.0, 1.7, MAX_ZOOM] {
+        camera.set_zoom(zoom);
+        let screen = Vec2::new(123.0, 456.0);
+        let world = camera.screen_to_world(screen, WINDOW);
+        assert_close(camera.world_to_screen(world, WINDOW), screen);
+    }
+}
+
+#[test]
+fn test_zoom_is_clamped() {
+    let mut camera = Camera::new();
+    camera.set_zoom(100.0);
+    assert_eq!(camera.zoom(), MAX_ZOOM);
+    camera.set_zoom(0.01);
+    assert_eq!(camera.zoom(), MIN_ZOOM);
+}
+
+#[test]
+fn test_zoom_at_keeps_point_under_cursor() {
+    let mut camera = Camera::new();
+    let cursor = Vec2::new(600.0, 150.0);
+    let anchor = camera.screen_to_world(cursor, WINDOW);
+
+    camera.zoom_at(1.5, cursor, WINDOW);
+    assert_eq!(camera.zoom(), 1.5);
+    assert_close(camera.screen_to_world(cursor, WINDOW), anchor);
+
+    camera.zoom_at(0.5, cursor, WINDOW);
+    assert_close(camera.screen_to_world(cursor, WINDOW), anchor);
+}
+
+#[test]
+fn test_zooming_out_widens_the_view() {
+    let mut camera = Camera::new();
+    let far = Vec2::new(camera.view_distance * 1.5, 0.0);
+    assert!(!camera.can_see(far));
+    camera.set_zoom(MIN_ZOOM);
+    assert!(camera.can_see(far));
+}
+
+#[test]
+fn test_pan_glides_to_target() {
+    let mut camera = Camera::new();
+    camera.pan_to(Vec2::new(1.0, 0.5));
+    assert!(camera.is_panning());
+    assert_eq!(camera.position, Vec2::new(0.0, 0.0));
+
+    camera.update(1.0 / 60.0);
+    assert!(camera.position.x > 0.0 && camera.position.x < 1.0);
+
+    for _ in 0..120 {
+        camera.update(1.0 / 60.0);
+    }
+    assert!(!camera.is_panning());
+    assert_eq!(camera.position, Vec2::new(1.0, 0.5));
+}
+
+#[test]
+fn test_jump_moves_at_once() {
+    let mut camera = Camera::new();
+    camera.jump_to(Vec2::new(-0.4, 0.2));
+    assert_eq!(camera.position, Vec2::new(-0.4, 0.2));
+    assert!(!camera.is_panning());
+}
+
+#[test]
+fn test_bounds_clamp_panning_and_zoom() {
+    let mut camera = Camera::new();
+    camera.set_bounds(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0));
+
+    camera.move_by(5.0, -5.0);
+    assert_eq!(camera.target(), Vec2::new(1.0, -1.0));
+    camera.jump_to(Vec2::new(-3.0, 0.5));
+    assert_eq!(camera.position, Vec2::new(-1.0, 0.5));
+
+    camera.zoom_at(0.5, Vec2::new(0.0, 0.0), WINDOW);
+    let position = camera.position;
+    assert!((-1.0..=1.0).contains(&position.x) && (-1.0..=1.0).contains(&position.y));
+}
+
+#[test]
+fn test_grid_bounds_follow_the_map() {
+    let grid = map(2);
+    let (min, max) = grid.camera.bounds().expect("map grids bound the camera");
+    for hex in grid.hexagons.values() {
+        assert!(hex.world_pos.x >= min.x && hex.world_pos.x <= max.x);
+        assert!(hex.world_pos.y >= min.y && hex.world_pos.y <= max.y);
+    }
+
+    assert!(HexGrid::empty().camera.bounds().is_none());
+}
+
+#[test]
+fn test_edge_scroll_direction() {
+    let view = Vec2::new(450.0, 600.0);
+    let dt = 0.1;
+
+    let mut camera = Camera::new();
+    assert!(camera.edge_scroll(Vec2::new(445.0, 300.0), view, dt));
+    assert!(camera.target().x > 0.0 && camera.target().y == 0.0);
+
+    let mut camera = Camera::new();
+    assert!(camera.edge_scroll(Vec2::new(200.0, EDGE_SCROLL_MARGIN / 2.0), view, dt));
+    assert!(camera.target().y > 0.0, "top edge scrolls up the map");
+
+    let mut camera = Camera::new();
+    assert!(!camera.edge_scroll(Vec2::new(200.0, 300.0), view, dt));
+    // Past the view, e.g. over the side panel
+    assert!(!camera.edge_scroll(Vec2::new(700.0, 300.0), view, dt));
+    assert_eq!(camera.target(), Vec2::new(0.0, 0.0));
+}
+
+#[test]
+fn test_picking_hex_centers_at_every_zoom() {
+    let mut grid = map(3);
+    grid.camera.jump_to(Vec2::new(0.2, -0.1));
+    for zoom in [MIN_ZOOM, 0.8, 1.0, 1.6, MAX_ZOOM] {
+        grid.camera.set_zoom(zoom);
+        for hex in grid.hexagons.values() {
+            let screen = grid.camera.world_to_screen(hex.world_pos, WINDOW);
+            let inside = screen.x >= 0.0 && screen.x < WINDOW.x;
+            if inside && screen.y >= 0.0 && screen.y < WINDOW.y {
+                assert_eq!(
+                    grid.screen_to_hex_coord(screen, WINDOW),
+                    Some(hex.coord),
+                    "zoom {}",
+                    zoom
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lookup_table_picking_when_zoomed() {
+    let grid = map(2);
+    let entries: HashMap<HexCoord, MapHexEntry> = grid
+        .hexagons
+        .keys()
+        .map(|coord| {
+            (
+                *coord,
+                MapHexEntry {
+                    hex_coord: *coord,
+                    sprite_type: SpriteType::Grasslands,
+                    unit: None,
+                },
+            )
+        })
+        .collect();
+    let table = WorldHexLookupTable::from_hashmap(entries, grid.hex_size, 0.01).unwrap();
+
+    let mut camera = Camera::new();
+    for zoom in [MIN_ZOOM, 1.0, MAX_ZOOM] {
+        camera.set_zoom(zoom);
+        for hex in grid.hexagons.values() {
+            let screen = camera.world_to_screen(hex.world_pos, WINDOW);
+            if screen.x >= 0.0 && screen.x < WINDOW.x && screen.y >= 0.0 && screen.y < WINDOW.y {
+                assert_eq!(
+                    table.get_hex_at_screen_pos(&camera, screen, WINDOW),
+                    Some(hex.coord)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Minimap Tests
+//!
+//! Tests for the minimap overview: click-to-jump and drag camera control,
+//! team-colored unit dots and fog of war.
+
+use graphics::draw::{DrawCommand, DrawList};
+use graphics::{HexCoord, HexGrid, Minimap, SpriteType, Vec2};
+use std::collections::HashSet;
+
+const SCREEN_WIDTH: f32 = 800.0;
+const SCREEN_HEIGHT: f32 = 600.0;
+
+const TEAM_COLOR: [f32; 3] = [0.1, 0.3, 0.9];
+
+/// A map ten hexes wide, wider than one screen
+fn wide_map() -> HexGrid {
+    let tiles =
+        (-5..=5).flat_map(|q| (-2..=2).map(move |r| (HexCoord::new(q, r), SpriteType::Forest)));
+    let mut grid = HexGrid::from_tiles(tiles);
+    grid.set_unit_at(HexCoord::new(4, 0), SpriteType::Unit);
+    grid.set_unit_tint_at(HexCoord::new(4, 0), TEAM_COLOR);
+    grid
+}
+
+fn minimap() -> Minimap {
+    Minimap::new(SCREEN_WIDTH, SCREEN_HEIGHT)
+}
+
+fn center(minimap: &Minimap) -> (f32, f32) {
+    (
+        minimap.x + minimap.width / 2.0,
+        minimap.y + minimap.height / 2.0,
+    )
+}
+
+fn has_rect_with_color(list: &DrawList, color: [f32; 3]) -> bool {
+    list.commands().iter().any(|command| {
+        matches!(command, DrawCommand::Rect { color: [r, g, b, _], .. } if [*r, *g, *b] == color)
+    })
+}
+
+#[test]
+fn test_minimap_sits_in_bottom_left_corner() {
+    let minimap = minimap();
+    assert!(minimap.x < SCREEN_WIDTH / 2.0);
+    assert!(minimap.y + minimap.height <= SCREEN_HEIGHT);
+    assert!(minimap.y > SCREEN_HEIGHT / 2.0);
+}
+
+#[test]
+fn test_click_jumps_camera() {
+    let mut grid = wide_map();
+    let mut minimap = minimap();
+
+    // The map is centered on the origin, so the minimap center is the origin
+    let (x, y) = center(&minimap);
+    let right = minimap.x + minimap.width * 0.9;
+    assert!(minimap.press(right, y, &mut grid));
+    assert!(grid.camera.position.x > 0.5);
+    assert!(!grid.camera.is_panning(), "click-to-jump moves at once");
+    minimap.release();
+
+    assert!(minimap.press(x, y, &mut grid));
+    assert!(grid.camera.position.distance(&Vec2::new(0.0, 0.0)) < 0.05);
+}
+
+#[test]
+fn test_click_outside_is_ignored() {
+    let mut grid = wide_map();
+    let mut minimap = minimap();
+    assert!(!minimap.press(SCREEN_WIDTH - 5.0, 5.0, &mut grid));
+    assert!(!minimap.is_dragging());
+    assert_eq!(grid.camera.position, Vec2::new(0.0, 0.0));
+}
+
+#[test]
+fn test_drag_follows_cursor_until_release() {
+    let mut grid = wide_map();
+    let mut minimap = minimap();
+    let (x, y) = center(&minimap);
+
+    assert!(
+        !minimap.drag(x + 50.0, y, &mut grid),
+        "no drag without a press"
+    );
+
+    minimap.press(x, y, &mut grid);
+    assert!(minimap.drag(x - 60.0, y, &mut grid));
+    let dragged = grid.camera.position;
+    assert!(dragged.x < 0.0);
+
+    // Dragging past the minimap stops at its edge
+    minimap.drag(-500.0, y, &mut grid);
+    let (min, _) = grid.camera.bounds().unwrap();
+    assert!(grid.camera.position.x >= min.x);
+
+    minimap.release();
+    assert!(!minimap.is_dragging());
+    assert!(!minimap.drag(x, y, &mut grid));
+}
+
+#[test]
+fn test_unit_dots_use_team_color() {
+    let grid = wide_map();
+    let mut list = DrawList::new();
+    minimap().draw(&mut list, &grid);
+    assert!(has_rect_with_color(&list, TEAM_COLOR));
+}
+
+#[test]
+fn test_fog_hides_units() {
+    let mut grid = wide_map();
+    let visible: HashSet<HexCoord> = [HexCoord::new(0, 0)].into_iter().collect();
+    grid.set_fog(&visible);
+
+    let mut list = DrawList::new();
+    minimap().draw(&mut list, &grid);
+    assert!(!has_rect_with_color(&list, TEAM_COLOR));
+
+    // The visible hex keeps its terrain color
+    assert!(has_rect_with_color(
+        &list,
+        SpriteType::Forest.get_color_tint()
+    ));
+}
@@ -0,0 +1,403 @@
+//! Screenshot Tests
+//!
+//! Tests for the rendering and UI layout: frames are drawn with the software
+//! backend and compared with the golden PNGs in `tests/golden/`. Run with
+//! `UPDATE_GOLDEN=1` to rewrite the golden images after an intended visual
+//! change; mismatching frames are saved next to the test binaries for review.
+
+use graphics::draw::text::FontStack;
+use graphics::draw::{DrawBackend, DrawList, SoftwareBackend};
+use graphics::rendering::{
+    draw_frame, AttackOption, CombatConfirmation, CombatLogDisplay, CombatLogEntryType,
+    EffectsDisplay, MenuDisplay,
+};
+use graphics::Minimap;
+use graphics::{
+    AttackDisplayInfo, EvolutionPanel, HexCoord, HexGrid, HighlightType, InventoryPanel,
+    ItemUsePanel, SpriteType, TimeOfDayDisplayInfo, UiPanel, UnitDisplayInfo,
+};
+use std::path::{Path, PathBuf};
+
+const MAP_WIDTH: f32 = 480.0;
+const MAP_HEIGHT: f32 = 360.0;
+const SCREEN_WIDTH: f32 = 800.0;
+const SCREEN_HEIGHT: f32 = 600.0;
+
+/// Largest per-channel difference still treated as the same color
+const CHANNEL_TOLERANCE: u8 = 2;
+
+/// Share of pixels allowed to differ, for rounding on shape edges
+const PIXEL_TOLERANCE: f64 = 0.001;
+
+/// Draws text with the bundled font only, so the golden images do not depend
+/// on which fallback fonts this system has. Every test calls this before
+/// touching text; only the first call installs the stack.
+fn use_bundled_fonts() {
+    let _ = FontStack::install(FontStack::bundled());
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.png", name))
+}
+
+/// Draws `list` on a fresh image and compares it with the golden image `name`.
+fn assert_matches_golden(name: &str, list: &DrawList, width: f32, height: f32) {
+    let mut backend = SoftwareBackend::new(width as u32, height as u32);
+    backend.submit(list);
+
+    let path = golden_path(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        backend.save_png(&path).unwrap();
+        return;
+    }
+
+    let golden = image::open(&path)
+        .unwrap_or_else(|e| {
+            panic!(
+                "missing golden image {} ({}); run with UPDATE_GOLDEN=1",
+                path.display(),
+                e
+            )
+        })
+        .to_rgba8();
+    let actual = backend.image();
+    assert_eq!(golden.dimensions(), actual.dimensions(), "size of {}", name);
+
+    let differing = golden
+        .pixels()
+        .zip(actual.pixels())
+        .filter(|(expected, actual)| {
+            expected
+                .0
+                .iter()
+                .zip(actual.0)
+                .any(|(e, a)| e.abs_diff(a) > CHANNEL_TOLERANCE)
+        })
+        .count();
+    let allowed = (golden.len() / 4) as f64 * PIXEL_TOLERANCE;
+    if differing as f64 > allowed {
+        let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.png", name));
+        backend.save_png(&output).unwrap();
+        panic!(
+            "{} differs from {} in {} pixels; rendered frame saved to {}",
+            name,
+            path.display(),
+            differing,
+            output.display()
+        );
+    }
+}
+
+/// A small map: mixed terrain, a team-tinted unit standing on an item, a
+/// house and a defense value overlay.
+fn test_map() -> HexGrid {
+    let terrain = [
+        SpriteType::Grasslands,
+        SpriteType::Forest,
+        SpriteType::Hills,
+        SpriteType::Mountain,
+        SpriteType::Swamp,
+        SpriteType::Forest2,
+        SpriteType::HauntedWoods,
+    ];
+    let mut tiles = Vec::new();
+    for q in -3i32..=3 {
+        for r in -3i32..=3 {
+            if (q + r).abs() <= 3 {
+                let sprite = terrain[(q * 3 + r).rem_euclid(terrain.len() as i32) as usize];
+                tiles.push((HexCoord::new(q, r), sprite));
+            }
+        }
+    }
+    let mut grid = HexGrid::from_tiles(tiles);
+
+    grid.set_unit_at(HexCoord::new(0, 0), SpriteType::Unit);
+    grid.set_unit_tint_at(HexCoord::new(0, 0), [0.2, 0.4, 1.0]);
+    grid.set_item_at(HexCoord::new(0, 0), SpriteType::Item);
+    grid.set_item_at(HexCoord::new(-1, 1), SpriteType::Item);
+    grid.set_structure_at(HexCoord::new(1, -1), SpriteType::House);
+    grid.set_unit_at(HexCoord::new(1, 0), SpriteType::OrcWarrior);
+    grid.set_hex_text_overlay(HexCoord::new(0, 1), Some("60%".to_string()));
+    grid
+}
+
+fn frame(
+    grid: &HexGrid,
+    menu: &MenuDisplay,
+    effects: &EffectsDisplay,
+    combat: &CombatLogDisplay,
+    (width, height): (f32, f32),
+) -> DrawList {
+    let mut list = DrawList::new();
+    draw_frame(
+        &mut list,
+        grid,
+        (width, height),
+        menu,
+        effects,
+        combat,
+        None,
+    );
+    list
+}
+
+fn attack(name: &str, damage: u32, in_reach: bool, hit_chance: u8) -> AttackOption {
+    AttackOption {
+        name: name.to_string(),
+        damage,
+        range: 1,
+        range_type: "Melee".to_string(),
+        in_reach,
+        counter_index: in_reach.then_some(0),
+        time_of_day_bonus: 25,
+        hit_chance,
+        hit_chance_breakdown: vec![format!("Hit chance: {}%", hit_chance)],
+        forecast: None,
+    }
+}
+
+#[test]
+fn test_hex_grid() {
+    use_bundled_fonts();
+    let grid = test_map();
+    let list = frame(
+        &grid,
+        &MenuDisplay::new(),
+        &EffectsDisplay::new(),
+        &CombatLogDisplay::new(MAP_WIDTH, MAP_HEIGHT),
+        (MAP_WIDTH, MAP_HEIGHT),
+    );
+    assert_matches_golden("hex_grid", &list, MAP_WIDTH, MAP_HEIGHT);
+}
+
+#[test]
+fn test_zoomed_hex_grid() {
+    use_bundled_fonts();
+    let mut grid = test_map();
+    grid.camera.jump_to(graphics::Vec2::new(0.3, 0.0));
+    grid.camera.set_zoom(1.6);
+    let list = frame(
+        &grid,
+        &MenuDisplay::new(),
+        &EffectsDisplay::new(),
+        &CombatLogDisplay::new(MAP_WIDTH, MAP_HEIGHT),
+        (MAP_WIDTH, MAP_HEIGHT),
+    );
+    assert_matches_golden("hex_grid_zoomed", &list, MAP_WIDTH, MAP_HEIGHT);
+}
+
+#[test]
+fn test_highlights_and_fog() {
+    use_bundled_fonts();
+    let mut grid = test_map();
+    grid.highlight_hex(HexCoord::new(0, 0), HighlightType::Selected);
+    let reachable: Vec<HexCoord> = HexCoord::new(0, 0).neighbors().to_vec();
+    grid.highlight_hexes(&reachable, HighlightType::MovementRange);
+    let visible = grid
+        .hexagons
+        .keys()
+        .copied()
+        .filter(|coord| coord.q <= 1)
+        .collect();
+    grid.set_fog(&visible);
+
+    let list = frame(
+        &grid,
+        &MenuDisplay::new(),
+        &EffectsDisplay::new(),
+        &CombatLogDisplay::new(MAP_WIDTH, MAP_HEIGHT),
+        (MAP_WIDTH, MAP_HEIGHT),
+    );
+    assert_matches_golden("highlights", &list, MAP_WIDTH, MAP_HEIGHT);
+}
+
+#[test]
+fn test_floating_texts_and_combat_log() {
+    use_bundled_fonts();
+    let grid = test_map();
+    let mut effects = EffectsDisplay::new();
+    effects.spawn_floating_text(HexCoord::new(0, 0), "-7", [1.0, 0.3, 0.3]);
+    effects.spawn_floating_text(HexCoord::new(1, 0), "miss", [0.8, 0.8, 0.8]);
+    effects.update(0.3);
+    let mut combat = CombatLogDisplay::new(MAP_WIDTH, MAP_HEIGHT);
+    combat.show();
+    combat.add_entry(
+        "Knight hits Orc for 7".to_string(),
+        CombatLogEntryType::Attack,
+    );
+    combat.add_entry("Orc misses Knight".to_string(), CombatLogEntryType::Miss);
+
+    let list = frame(
+        &grid,
+        &MenuDisplay::new(),
+        &effects,
+        &combat,
+        (MAP_WIDTH, MAP_HEIGHT),
+    );
+    assert_matches_golden("effects_and_log", &list, MAP_WIDTH, MAP_HEIGHT);
+}
+
+#[test]
+fn test_minimap() {
+    use_bundled_fonts();
+    let mut grid = test_map();
+    grid.camera.jump_to(graphics::Vec2::new(0.3, -0.2));
+    grid.camera.set_zoom(2.0);
+    let visible = grid
+        .hexagons
+        .keys()
+        .copied()
+        .filter(|coord| coord.q >= 0)
+        .collect();
+    grid.set_fog(&visible);
+    let minimap = Minimap::new(MAP_WIDTH, MAP_HEIGHT);
+
+    let mut list = DrawList::new();
+    draw_frame(
+        &mut list,
+        &grid,
+        (MAP_WIDTH, MAP_HEIGHT),
+        &MenuDisplay::new(),
+        &EffectsDisplay::new(),
+        &CombatLogDisplay::new(MAP_WIDTH, MAP_HEIGHT),
+        Some(&minimap),
+    );
+    assert_matches_golden("minimap", &list, MAP_WIDTH, MAP_HEIGHT);
+}
+
+#[test]
+fn test_game_menu() {
+    use_bundled_fonts();
+    let grid = test_map();
+    let mut menu = MenuDisplay::new();
+    menu.update_for_screen_size(SCREEN_WIDTH, SCREEN_HEIGHT);
+    menu.show();
+    let button = menu.button_rect(1).unwrap();
+    menu.update_hover(button.x + 1.0, button.y + 1.0);
+
+    let list = frame(
+        &grid,
+        &menu,
+        &EffectsDisplay::new(),
+        &CombatLogDisplay::new(SCREEN_WIDTH, SCREEN_HEIGHT),
+        (SCREEN_WIDTH, SCREEN_HEIGHT),
+    );
+    assert_matches_golden("game_menu", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
+
+#[test]
+fn test_combat_dialog() {
+    use_bundled_fonts();
+    let grid = test_map();
+    let mut combat = CombatLogDisplay::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    combat.show_combat_confirmation(CombatConfirmation {
+        attacker_name: "Human Knight".to_string(),
+        attacker_hp: 38,
+        attacker_max_hp: 42,
+        attacker_attack: 9,
+        attacker_defense: 4,
+        attacker_attacks_per_round: 2,
+        attacker_attacks: vec![attack("Sword", 9, true, 60), attack("Lance", 12, false, 40)],
+        defender_name: "Orc Warrior".to_string(),
+        defender_hp: 30,
+        defender_max_hp: 30,
+        defender_attack: 7,
+        defender_defense: 3,
+        defender_attacks_per_round: 2,
+        defender_attacks: vec![attack("Axe", 7, true, 50)],
+    });
+
+    let list = frame(
+        &grid,
+        &MenuDisplay::new(),
+        &EffectsDisplay::new(),
+        &combat,
+        (SCREEN_WIDTH, SCREEN_HEIGHT),
+    );
+    assert_matches_golden("combat_dialog", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
+
+#[test]
+fn test_ui_panel() {
+    use_bundled_fonts();
+    let mut panel = UiPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    panel.set_time_of_day(TimeOfDayDisplayInfo {
+        name: "Dawn".to_string(),
+        lawful_bonus: 0,
+        phase_index: 0,
+        phase_bonuses: vec![0, 25, 25, 0, -25, -25],
+    });
+    panel.set_unit_info(UnitDisplayInfo {
+        name: "Aldric".to_string(),
+        race: "Human".to_string(),
+        class: "Knight".to_string(),
+        level: 2,
+        experience: 30,
+        health: 28,
+        max_health: 42,
+        terrain: "Grasslands".to_string(),
+        position_q: 0,
+        position_r: 0,
+        moves_left: 3,
+        max_moves: 5,
+        sprite_type: SpriteType::Unit,
+        attacks: vec![AttackDisplayInfo {
+            name: "Sword".to_string(),
+            damage: 9,
+            damage_type: "Slash".to_string(),
+            description: "A trusty blade".to_string(),
+        }],
+        inventory: vec![("Healing Potion".to_string(), "Consumable".to_string())],
+        resistances: vec![("Slash".to_string(), 20), ("Fire".to_string(), -10)],
+    });
+    panel.set_pickup_prompt("Iron Shield".to_string());
+
+    let mut list = DrawList::new();
+    panel.draw(&mut list);
+    assert_matches_golden("ui_panel", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
+
+#[test]
+fn test_evolution_panel() {
+    use_bundled_fonts();
+    let mut panel = EvolutionPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    let options = ["Human Knight".to_string(), "Human Lancer".to_string()];
+    panel.set_options("Human Squire", &options);
+
+    let mut list = DrawList::new();
+    panel.draw(&mut list);
+    assert_matches_golden("evolution_panel", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
+
+#[test]
+fn test_item_use_panel() {
+    use_bundled_fonts();
+    let mut panel = ItemUsePanel::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    let options = ["Healing Potion (heals 10)".to_string()];
+    panel.set_options("Aldric", &options, false);
+
+    let mut list = DrawList::new();
+    panel.draw(&mut list);
+    assert_matches_golden("item_use_panel", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
+
+#[test]
+fn test_inventory_panel() {
+    use_bundled_fonts();
+    let mut panel = InventoryPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    let entries = [
+        ("Iron Sword".to_string(), true),
+        ("Healing Potion".to_string(), false),
+    ];
+    let details = ["Iron Sword: +3 attack".to_string()];
+
+    panel.set_items("Aldric", &entries, Some(0), &details, false);
+
+    let mut list = DrawList::new();
+    panel.draw(&mut list);
+    assert_matches_golden("inventory_panel", &list, SCREEN_WIDTH, SCREEN_HEIGHT);
+}
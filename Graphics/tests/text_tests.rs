@@ -0,0 +1,177 @@
+//! Text Tests
+//!
+//! Tests for TrueType text: measurement, kerning, wrapping and truncation,
+//! fallback fonts and the glyph atlas cache.
+
+use graphics::draw::text::{FontStack, GlyphAtlas, GlyphId, DEFAULT_FONT};
+use graphics::draw::{DrawBackend, DrawList, SoftwareBackend};
+
+const SIZE: f32 = 16.0;
+
+fn fonts() -> FontStack {
+    FontStack::bundled()
+}
+
+#[test]
+fn test_width_grows_with_text_and_size() {
+    let fonts = fonts();
+    assert_eq!(fonts.measure("", SIZE), 0.0);
+    let short = fonts.measure("Hex", SIZE);
+    let long = fonts.measure("Hexagon", SIZE);
+    assert!(short > 0.0 && long > short);
+    assert!(fonts.measure("Hex", SIZE * 2.0) > short * 1.8);
+    // Proportional font: narrow letters take less room
+    assert!(fonts.measure("iiii", SIZE) < fonts.measure("WWWW", SIZE));
+}
+
+#[test]
+fn test_kerning_pulls_pairs_together() {
+    let fonts = fonts();
+    let pair = fonts.measure("AV", SIZE);
+    let apart = fonts.measure("A", SIZE) + fonts.measure("V", SIZE);
+    assert!(
+        pair < apart,
+        "AV {} should be narrower than {}",
+        pair,
+        apart
+    );
+}
+
+#[test]
+fn test_unicode_characters_have_glyphs() {
+    let fonts = fonts();
+    for ch in ['é', 'ß', 'Ж', '•', '←', '═'] {
+        assert_ne!(fonts.resolve(ch).index, 0, "no glyph for {}", ch);
+    }
+    assert!(!fonts.layout("é", 0.0, 0.0, SIZE).is_empty());
+}
+
+#[test]
+fn test_missing_characters_fall_back() {
+    let mut fonts = fonts();
+    // A private use character no font has draws the missing-glyph box
+    let missing = '\u{E000}';
+    assert_eq!(fonts.resolve(missing), GlyphId { font: 0, index: 0 });
+    assert!(fonts.measure("\u{E000}", SIZE) > 0.0);
+
+    // Fallbacks are only used for characters earlier fonts lack
+    fonts.push_fallback(DEFAULT_FONT).unwrap();
+    assert_eq!(fonts.len(), 2);
+    assert_eq!(fonts.resolve('A').font, 0);
+
+    assert!(fonts.push_fallback(b"not a font").is_err());
+    assert!(fonts.push_fallback_file("/no/such/font.ttf").is_err());
+    assert_eq!(fonts.len(), 2);
+}
+
+#[test]
+fn test_variation_selectors_draw_nothing() {
+    let fonts = fonts();
+    assert_eq!(fonts.measure("A\u{FE0F}", SIZE), fonts.measure("A", SIZE));
+}
+
+#[test]
+fn test_layout_places_glyphs_on_one_baseline() {
+    let fonts = fonts();
+    let glyphs = fonts.layout("Ax", 10.0, 20.0, SIZE);
+    assert_eq!(glyphs.len(), 2);
+    let baseline = 20.0 + fonts.ascent(SIZE);
+    for glyph in &glyphs {
+        assert!(glyph.y >= 20.0 && glyph.y + glyph.height as f32 <= baseline + 1.0);
+    }
+    assert!(glyphs[0].x >= 10.0 && glyphs[1].x > glyphs[0].x);
+    // The lowercase x is shorter than the capital A
+    assert!(glyphs[1].y > glyphs[0].y);
+    // Spaces take room but draw nothing
+    assert_eq!(fonts.layout(" ", 0.0, 0.0, SIZE).len(), 0);
+}
+
+#[test]
+fn test_wrap_fits_lines_into_width() {
+    let fonts = fonts();
+    let text = "The quick brown fox jumps over the lazy dog";
+    let max_width = fonts.measure("The quick brown", SIZE);
+    let lines = fonts.wrap(text, SIZE, max_width);
+    assert!(lines.len() >= 3);
+    for line in &lines {
+        assert!(
+            fonts.measure(line, SIZE) <= max_width,
+            "{:?} too wide",
+            line
+        );
+    }
+    assert_eq!(lines.join(" "), text);
+
+    // Explicit line breaks are kept and long words are split
+    assert_eq!(fonts.wrap("a\nb", SIZE, 100.0), vec!["a", "b"]);
+    let split = fonts.wrap("Wwwwwwwwwwwwwww", SIZE, max_width / 3.0);
+    assert!(split.len() > 1);
+    assert_eq!(split.concat(), "Wwwwwwwwwwwwwww");
+}
+
+#[test]
+fn test_truncate_adds_ellipsis() {
+    let fonts = fonts();
+    assert_eq!(fonts.truncate("Sword", SIZE, 200.0), "Sword");
+
+    let max_width = fonts.measure("Flaming Sw", SIZE);
+    let shortened = fonts.truncate("Flaming Sword of Doom", SIZE, max_width);
+    assert!(shortened.ends_with('…'));
+    assert!(shortened.starts_with("Flaming"));
+    assert!(fonts.measure(&shortened, SIZE) <= max_width);
+}
+
+#[test]
+fn test_atlas_caches_glyphs() {
+    let fonts = fonts();
+    let mut atlas = GlyphAtlas::new(128, 128);
+    assert!(atlas.take_dirty());
+
+    let glyphs = fonts.layout("AA", 0.0, 0.0, SIZE);
+    assert_eq!(glyphs[0].key, glyphs[1].key);
+    let first = atlas.region(&fonts, glyphs[0].key).unwrap();
+    assert!(atlas.take_dirty());
+    assert_eq!(atlas.region(&fonts, glyphs[1].key), Some(first));
+    assert!(!atlas.take_dirty(), "cached glyphs are not packed again");
+    assert_eq!(atlas.len(), 1);
+
+    // The glyph has ink and does not overlap the solid block
+    let solid = atlas.solid_region();
+    assert!(first.x >= solid.x + solid.width || first.y >= solid.y + solid.height);
+    assert!((0..first.height)
+        .flat_map(|y| (0..first.width).map(move |x| (x, y)))
+        .any(|(x, y)| atlas.coverage(first.x + x, first.y + y) > 0));
+    assert_eq!(atlas.coverage(0, 0), 255);
+}
+
+#[test]
+fn test_full_atlas_reports_no_room_until_reset() {
+    let fonts = fonts();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    let glyphs = fonts.layout("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 0.0, 0.0, 40.0);
+    let packed = glyphs
+        .iter()
+        .take_while(|glyph| atlas.region(&fonts, glyph.key).is_some())
+        .count();
+    assert!(packed < glyphs.len());
+
+    atlas.reset();
+    assert!(atlas.is_empty());
+    assert_eq!(atlas.coverage(0, 0), 255);
+    assert!(atlas.region(&fonts, glyphs[packed].key).is_some());
+}
+
+#[test]
+fn test_software_backend_draws_text_ink() {
+    let mut list = DrawList::new();
+    list.text("Hé ═", 4.0, 4.0, 20.0, [1.0, 1.0, 1.0, 1.0]);
+    let mut backend = SoftwareBackend::new(80, 32);
+    let background = backend.pixel(79, 31);
+    backend.submit(&list);
+
+    let inked = (0..80)
+        .flat_map(|x| (0..32).map(move |y| (x, y)))
+        .filter(|(x, y)| backend.pixel(*x, *y) != background)
+        .count();
+    assert!(inked > 20);
+}
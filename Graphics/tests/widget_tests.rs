@@ -0,0 +1,249 @@
+//! Widget Tests
+//!
+//! Tests for the widget layout system: column, row and stack layout, anchors,
+//! padding and margins, hit-testing, button states, scrolling and relayout.
+
+use graphics::ui::{
+    Align, Anchor, ButtonState, ButtonStyle, Insets, Length, Rect, Ui, Widget, WidgetId,
+};
+
+const SCREEN_WIDTH: f32 = 800.0;
+const SCREEN_HEIGHT: f32 = 600.0;
+
+const PANEL: WidgetId = WidgetId::new("panel");
+const FIRST: WidgetId = WidgetId::new("first");
+const SECOND: WidgetId = WidgetId::new("second");
+const OK: WidgetId = WidgetId::new("ok");
+const LIST: WidgetId = WidgetId::new("list");
+
+fn boxed(id: WidgetId, width: f32, height: f32) -> Widget {
+    Widget::spacer().with_id(id).size(width, height)
+}
+
+fn button(id: WidgetId) -> Widget {
+    Widget::button(id, "OK", 12.0, ButtonStyle::default()).size(100.0, 40.0)
+}
+
+/// A 100px high scroll view over ten 30px rows
+fn scrolling_list() -> Widget {
+    let rows = (0..10).map(|i| boxed(WidgetId::indexed("row", i), 50.0, 30.0));
+    Widget::scroll_view(LIST, Widget::column(0.0).children(rows), 30.0).size(200.0, 100.0)
+}
+
+#[test]
+fn test_column_stacks_children_with_spacing_and_padding() {
+    let root = Widget::column(10.0)
+        .padding(Insets::all(5.0))
+        .child(boxed(FIRST, 50.0, 20.0))
+        .child(boxed(SECOND, 80.0, 30.0));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.rect(FIRST), Some(Rect::new(5.0, 5.0, 50.0, 20.0)));
+    assert_eq!(ui.rect(SECOND), Some(Rect::new(5.0, 35.0, 80.0, 30.0)));
+    // Fit size wraps the children and the padding
+    assert_eq!(ui.root_rect(), Rect::new(0.0, 0.0, 90.0, 70.0));
+}
+
+#[test]
+fn test_row_centers_children_on_the_cross_axis() {
+    let root = Widget::row(4.0)
+        .align(Align::Center)
+        .child(boxed(FIRST, 50.0, 20.0))
+        .child(boxed(SECOND, 30.0, 40.0));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.rect(FIRST), Some(Rect::new(0.0, 10.0, 50.0, 20.0)));
+    assert_eq!(ui.rect(SECOND), Some(Rect::new(54.0, 0.0, 30.0, 40.0)));
+}
+
+#[test]
+fn test_fill_children_share_the_leftover_space() {
+    let root = Widget::row(0.0)
+        .size(300.0, 50.0)
+        .child(boxed(PANEL, 100.0, 50.0))
+        .child(Widget::spacer().with_id(FIRST).width(Length::Fill))
+        .child(Widget::spacer().with_id(SECOND).width(Length::Fill));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.rect(FIRST).unwrap().x, 100.0);
+    assert_eq!(ui.rect(FIRST).unwrap().width, 100.0);
+    assert_eq!(ui.rect(SECOND).unwrap().x, 200.0);
+    assert_eq!(ui.rect(SECOND).unwrap().width, 100.0);
+}
+
+#[test]
+fn test_margins_offset_children() {
+    let root = Widget::column(0.0)
+        .child(boxed(FIRST, 50.0, 20.0).margin(Insets::new(8.0, 0.0, 4.0, 6.0)))
+        .child(boxed(SECOND, 50.0, 20.0));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.rect(FIRST), Some(Rect::new(6.0, 8.0, 50.0, 20.0)));
+    assert_eq!(ui.rect(SECOND), Some(Rect::new(0.0, 32.0, 50.0, 20.0)));
+}
+
+#[test]
+fn test_root_is_placed_by_its_anchor() {
+    let centered = Ui::new(
+        boxed(PANEL, 200.0, 100.0).anchor(Anchor::Center),
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    );
+    assert_eq!(centered.root_rect(), Rect::new(300.0, 250.0, 200.0, 100.0));
+
+    let corner = Ui::new(
+        boxed(PANEL, 200.0, 100.0)
+            .anchor(Anchor::BottomRight)
+            .margin(Insets::all(10.0)),
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    );
+    assert_eq!(corner.root_rect(), Rect::new(590.0, 490.0, 200.0, 100.0));
+}
+
+#[test]
+fn test_stack_anchors_children_inside_it() {
+    let root = Widget::stack()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .child(boxed(FIRST, 100.0, 50.0).anchor(Anchor::Center))
+        .child(boxed(SECOND, 100.0, 50.0).anchor(Anchor::Bottom));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.rect(FIRST), Some(Rect::new(350.0, 275.0, 100.0, 50.0)));
+    assert_eq!(ui.rect(SECOND), Some(Rect::new(350.0, 550.0, 100.0, 50.0)));
+}
+
+#[test]
+fn test_resize_lays_the_tree_out_again() {
+    let mut ui = Ui::new(
+        boxed(PANEL, 200.0, 100.0).anchor(Anchor::Center),
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    );
+    ui.resize(1920.0, 1080.0);
+
+    assert_eq!(ui.screen_size(), (1920.0, 1080.0));
+    assert_eq!(ui.root_rect(), Rect::new(860.0, 490.0, 200.0, 100.0));
+}
+
+#[test]
+fn test_hit_test_finds_the_topmost_widget() {
+    let root = Widget::stack()
+        .with_id(PANEL)
+        .size(200.0, 200.0)
+        .child(boxed(FIRST, 100.0, 100.0))
+        .child(boxed(SECOND, 50.0, 50.0));
+    let ui = Ui::new(root, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    assert_eq!(ui.hit_test(25.0, 25.0), Some(SECOND));
+    assert_eq!(ui.hit_test(75.0, 75.0), Some(FIRST));
+    assert_eq!(ui.hit_test(150.0, 150.0), Some(PANEL));
+    assert_eq!(ui.hit_test(300.0, 300.0), None);
+}
+
+#[test]
+fn test_disabled_buttons_are_not_hit() {
+    let ui = Ui::new(button(OK).enabled(false), SCREEN_WIDTH, SCREEN_HEIGHT);
+    assert_eq!(ui.hit_test(10.0, 10.0), None);
+}
+
+#[test]
+fn test_button_hover_press_and_click() {
+    let mut ui = Ui::new(button(OK), SCREEN_WIDTH, SCREEN_HEIGHT);
+    let state = |ui: &Ui| ui.widget(OK).and_then(|widget| widget.button_state());
+    assert_eq!(state(&ui), Some(ButtonState::Normal));
+
+    assert_eq!(ui.cursor_moved(10.0, 10.0), Some(OK));
+    assert_eq!(state(&ui), Some(ButtonState::Hovered));
+
+    ui.press(10.0, 10.0);
+    assert_eq!(state(&ui), Some(ButtonState::Pressed));
+    assert_eq!(ui.release(20.0, 20.0), Some(OK));
+    assert_eq!(state(&ui), Some(ButtonState::Hovered));
+
+    ui.cursor_left();
+    assert_eq!(state(&ui), Some(ButtonState::Normal));
+}
+
+#[test]
+fn test_release_outside_the_pressed_button_is_not_a_click() {
+    let mut ui = Ui::new(button(OK), SCREEN_WIDTH, SCREEN_HEIGHT);
+    ui.press(10.0, 10.0);
+    assert_eq!(ui.release(300.0, 300.0), None);
+}
+
+#[test]
+fn test_scrolling_is_clamped_to_the_content() {
+    let mut ui = Ui::new(scrolling_list(), SCREEN_WIDTH, SCREEN_HEIGHT);
+    assert_eq!(ui.max_scroll_offset(LIST), 200.0);
+
+    assert!(ui.scroll(LIST, 2.0));
+    assert_eq!(ui.scroll_offset(LIST), 60.0);
+    assert_eq!(ui.rect(WidgetId::indexed("row", 2)).unwrap().y, 0.0);
+
+    assert!(ui.scroll_at(10.0, 10.0, 100.0));
+    assert_eq!(ui.scroll_offset(LIST), 200.0);
+    assert!(!ui.scroll(LIST, 1.0));
+
+    ui.scroll_to(LIST, -50.0);
+    assert_eq!(ui.scroll_offset(LIST), 0.0);
+}
+
+#[test]
+fn test_rows_scrolled_out_of_view_are_not_hit() {
+    let mut ui = Ui::new(scrolling_list(), SCREEN_WIDTH, SCREEN_HEIGHT);
+    ui.scroll(LIST, 3.0);
+
+    assert_eq!(ui.hit_test(10.0, 5.0), Some(WidgetId::indexed("row", 3)));
+    assert!(!ui.scroll_at(500.0, 500.0, 1.0));
+}
+
+#[test]
+fn test_set_root_keeps_scroll_offsets_and_presses() {
+    let mut ui = Ui::new(
+        Widget::column(0.0)
+            .child(scrolling_list())
+            .child(button(OK)),
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    );
+    ui.scroll(LIST, 2.0);
+    let ok = ui.rect(OK).unwrap();
+    ui.press(ok.x + 1.0, ok.y + 1.0);
+
+    ui.set_root(
+        Widget::column(0.0)
+            .child(scrolling_list())
+            .child(button(OK)),
+    );
+    assert_eq!(ui.scroll_offset(LIST), 60.0);
+    assert_eq!(ui.release(ok.x + 1.0, ok.y + 1.0), Some(OK));
+
+    // A press on a button that is gone after the rebuild is dropped
+    ui.press(ok.x + 1.0, ok.y + 1.0);
+    ui.set_root(scrolling_list());
+    ui.set_root(
+        Widget::column(0.0)
+            .child(scrolling_list())
+            .child(button(OK)),
+    );
+    assert_eq!(ui.release(ok.x + 1.0, ok.y + 1.0), None);
+}
+
+#[test]
+fn test_ui_scale_enlarges_layout_and_hit_areas() {
+    let mut ui = Ui::new(
+        boxed(PANEL, 200.0, 100.0).anchor(Anchor::Center),
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    );
+    ui.set_scale(2.0);
+
+    // Layout happens in a 400x300 space that is drawn twice as large
+    assert_eq!(ui.scale(), 2.0);
+    assert_eq!(ui.screen_size(), (400.0, 300.0));
+    assert_eq!(ui.root_rect(), Rect::new(200.0, 200.0, 400.0, 200.0));
+    assert_eq!(ui.hit_test(210.0, 210.0), Some(PANEL));
+    assert_eq!(ui.hit_test(190.0, 210.0), None);
+}
@@ -0,0 +1,217 @@
+use crate::cube::{CubeCoord, FractionalHex};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Axial coordinates for a hexagonal grid (flat-top orientation).
+///
+/// `HexCoord` uses axial coordinates (q, r) where q is the column and r is the
+/// row. It is the position type shared by rendering, units, AI and game logic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexCoord {
+    pub q: i32, // column
+    pub r: i32, // row
+}
+
+/// Unit offsets to the six neighbours, clockwise starting north.
+///
+/// Index order: north, northeast, southeast, south, southwest, northwest.
+pub const DIRECTIONS: [HexCoord; 6] = [
+    HexCoord { q: 0, r: -1 },
+    HexCoord { q: 1, r: -1 },
+    HexCoord { q: 1, r: 0 },
+    HexCoord { q: 0, r: 1 },
+    HexCoord { q: -1, r: 1 },
+    HexCoord { q: -1, r: 0 },
+];
+
+impl HexCoord {
+    /// The origin `(0, 0)`.
+    pub const ORIGIN: HexCoord = HexCoord { q: 0, r: 0 };
+
+    /// Construct a new `HexCoord`.
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Third cube coordinate, `s = -q - r`.
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Distance from the origin in hex steps.
+    pub fn length(self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// Get distance between two hex coordinates.
+    pub fn distance(self, other: HexCoord) -> i32 {
+        (self - other).length()
+    }
+
+    /// Unit offset for `direction` (taken modulo 6, see [`DIRECTIONS`]).
+    pub fn direction(direction: usize) -> HexCoord {
+        DIRECTIONS[direction % 6]
+    }
+
+    /// Neighbour in the given direction (see [`DIRECTIONS`]).
+    pub fn neighbor(self, direction: usize) -> HexCoord {
+        self + Self::direction(direction)
+    }
+
+    /// Get neighboring coordinates, clockwise starting north.
+    pub fn neighbors(self) -> [HexCoord; 6] {
+        DIRECTIONS.map(|d| self + d)
+    }
+
+    /// All hexes at exactly `radius` steps, clockwise starting southwest.
+    ///
+    /// A radius of 0 yields just `self`.
+    pub fn ring(self, radius: u32) -> Vec<HexCoord> {
+        if radius == 0 {
+            return vec![self];
+        }
+        let radius = radius as i32;
+        let mut hex = self + DIRECTIONS[4] * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for direction in 0..6 {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex.neighbor(direction);
+            }
+        }
+        ring
+    }
+
+    /// All hexes within `radius` steps: `self` first, then each ring outwards.
+    pub fn spiral(self, radius: u32) -> Vec<HexCoord> {
+        let mut hexes = vec![self];
+        for r in 1..=radius {
+            hexes.extend(self.ring(r));
+        }
+        hexes
+    }
+
+    /// Hexes on the straight line from `self` to `other`, both ends included.
+    ///
+    /// Uses linear interpolation in cube space. Lines that run exactly along a
+    /// hex edge are nudged consistently to one side.
+    pub fn line_to(self, other: HexCoord) -> Vec<HexCoord> {
+        self.line_nudged(other, 1.0)
+    }
+
+    /// Line with interpolation nudged by `sign * epsilon` to break edge ties.
+    pub(crate) fn line_nudged(self, other: HexCoord, sign: f32) -> Vec<HexCoord> {
+        const EPSILON: f32 = 1e-4;
+        let steps = self.distance(other);
+        let nudge = |h: HexCoord| FractionalHex {
+            q: h.q as f32 + sign * EPSILON,
+            r: h.r as f32 + sign * EPSILON,
+        };
+        let (a, b) = (nudge(self), nudge(other));
+        if steps == 0 {
+            return vec![self];
+        }
+        (0..=steps)
+            .map(|i| FractionalHex::lerp(a, b, i as f32 / steps as f32).round())
+            .collect()
+    }
+
+    /// Rotates 60° clockwise around the origin.
+    pub fn rotate_cw(self) -> HexCoord {
+        let c = CubeCoord::from(self);
+        CubeCoord {
+            x: -c.z,
+            y: -c.x,
+            z: -c.y,
+        }
+        .into()
+    }
+
+    /// Rotates 60° counter-clockwise around the origin.
+    pub fn rotate_ccw(self) -> HexCoord {
+        let c = CubeCoord::from(self);
+        CubeCoord {
+            x: -c.y,
+            y: -c.z,
+            z: -c.x,
+        }
+        .into()
+    }
+
+    /// Rotates around `center` by `steps` × 60° (positive is clockwise).
+    pub fn rotate_around(self, center: HexCoord, steps: i32) -> HexCoord {
+        let mut offset = self - center;
+        for _ in 0..steps.rem_euclid(6) {
+            offset = offset.rotate_cw();
+        }
+        center + offset
+    }
+
+    /// Reflects across the axis through the origin along which `q` is constant.
+    pub fn reflect_q(self) -> HexCoord {
+        HexCoord::new(self.q, self.s())
+    }
+
+    /// Reflects across the axis through the origin along which `r` is constant.
+    pub fn reflect_r(self) -> HexCoord {
+        HexCoord::new(self.s(), self.r)
+    }
+
+    /// Reflects across the axis through the origin along which `s` is constant.
+    pub fn reflect_s(self) -> HexCoord {
+        HexCoord::new(self.r, self.q)
+    }
+
+    /// Robust rounding function for axial coordinates.
+    ///
+    /// Converts fractional axial coordinates to the nearest integer `HexCoord`.
+    pub fn axial_round(q: f32, r: f32) -> Self {
+        FractionalHex { q, r }.round()
+    }
+
+    /// Center of this hex in pixel/world space (flat-top, `size` = corner radius).
+    pub fn to_pixel(self, size: f32) -> (f32, f32) {
+        let x = size * (3.0 / 2.0 * self.q as f32);
+        let y = size * (3.0_f32.sqrt() * (self.r as f32 + self.q as f32 / 2.0));
+        (x, y)
+    }
+
+    /// Hex containing the pixel/world position `(x, y)` (flat-top).
+    pub fn from_pixel(x: f32, y: f32, size: f32) -> Self {
+        let q = (2.0 / 3.0 * x) / size;
+        let r = (-1.0 / 3.0 * x + 3.0_f32.sqrt() / 3.0 * y) / size;
+        Self::axial_round(q, r)
+    }
+}
+
+impl Add for HexCoord {
+    type Output = HexCoord;
+
+    fn add(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = HexCoord;
+
+    fn sub(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for HexCoord {
+    type Output = HexCoord;
+
+    fn mul(self, k: i32) -> HexCoord {
+        HexCoord::new(self.q * k, self.r * k)
+    }
+}
+
+impl Neg for HexCoord {
+    type Output = HexCoord;
+
+    fn neg(self) -> HexCoord {
+        HexCoord::new(-self.q, -self.r)
+    }
+}
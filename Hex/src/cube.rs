@@ -0,0 +1,79 @@
+use crate::coord::HexCoord;
+
+/// Cube coordinates `(x, y, z)` with `x + y + z = 0`.
+///
+/// `x` is axial `q`, `z` is axial `r` and `y` is the derived `s`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CubeCoord {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl From<HexCoord> for CubeCoord {
+    fn from(hex: HexCoord) -> Self {
+        CubeCoord {
+            x: hex.q,
+            y: hex.s(),
+            z: hex.r,
+        }
+    }
+}
+
+impl From<CubeCoord> for HexCoord {
+    fn from(cube: CubeCoord) -> Self {
+        HexCoord::new(cube.x, cube.z)
+    }
+}
+
+/// A non-integer axial position, e.g. a point along a line or under the mouse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FractionalHex {
+    pub q: f32,
+    pub r: f32,
+}
+
+impl FractionalHex {
+    /// Linear interpolation between `a` and `b` (`t = 0` is `a`, `t = 1` is `b`).
+    pub fn lerp(a: FractionalHex, b: FractionalHex, t: f32) -> FractionalHex {
+        FractionalHex {
+            q: a.q + (b.q - a.q) * t,
+            r: a.r + (b.r - a.r) * t,
+        }
+    }
+
+    /// Nearest integer hex.
+    ///
+    /// Rounds in cube space and fixes the component with the largest rounding
+    /// error so that `x + y + z = 0` still holds.
+    pub fn round(self) -> HexCoord {
+        let x = self.q;
+        let z = self.r;
+        let y = -x - z;
+
+        let mut rx = x.round();
+        let ry = y.round();
+        let mut rz = z.round();
+
+        let x_diff = (rx - x).abs();
+        let y_diff = (ry - y).abs();
+        let z_diff = (rz - z).abs();
+
+        if x_diff > y_diff && x_diff > z_diff {
+            rx = -ry - rz;
+        } else if y_diff <= z_diff {
+            rz = -rx - ry;
+        }
+
+        HexCoord::new(rx as i32, rz as i32)
+    }
+}
+
+impl From<HexCoord> for FractionalHex {
+    fn from(hex: HexCoord) -> Self {
+        FractionalHex {
+            q: hex.q as f32,
+            r: hex.r as f32,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Line of sight and field of view.
+//!
+//! Both functions take a `blocks_sight` predicate so the caller decides what
+//! is opaque (terrain, structures, units...). Blocking hexes are themselves
+//! visible; only the hexes behind them are hidden.
+
+use crate::coord::HexCoord;
+
+/// Returns true when nothing between `from` and `to` blocks sight.
+///
+/// The endpoints are never checked. When the line runs exactly along hex
+/// edges, sight is granted if either side of the edge is clear.
+pub fn has_line_of_sight(
+    from: HexCoord,
+    to: HexCoord,
+    blocks_sight: impl Fn(HexCoord) -> bool,
+) -> bool {
+    [1.0, -1.0].into_iter().any(|sign| {
+        let line = from.line_nudged(to, sign);
+        let interior = &line[1..line.len().saturating_sub(1).max(1)];
+        interior.iter().all(|hex| !blocks_sight(*hex))
+    })
+}
+
+/// All hexes within `radius` of `origin` that `origin` can see.
+///
+/// Returned in [`HexCoord::spiral`] order, starting with `origin`.
+pub fn field_of_view(
+    origin: HexCoord,
+    radius: u32,
+    blocks_sight: impl Fn(HexCoord) -> bool,
+) -> Vec<HexCoord> {
+    origin
+        .spiral(radius)
+        .into_iter()
+        .filter(|hex| has_line_of_sight(origin, *hex, &blocks_sight))
+        .collect()
+}
@@ -0,0 +1,41 @@
+//! # Hex Crate - Shared Hex Grid Math
+//!
+//! The `hex` crate owns the one [`HexCoord`] type used by every other crate
+//! (graphics, units, AI and game logic) together with the hex-grid toolkit
+//! built on top of it. It has no dependencies, so domain crates can work with
+//! map positions without linking the renderer.
+//!
+//! ## Coordinate Systems
+//!
+//! - **Axial** ([`HexCoord`]): `(q, r)`, the storage format everywhere
+//! - **Cube** ([`CubeCoord`]): `(x, y, z)` with `x + y + z = 0`, used for
+//!   rotation, reflection and rounding
+//! - **Fractional** ([`FractionalHex`]): non-integer axial positions produced
+//!   by interpolation and pixel conversion
+//! - **Offset** ([`OffsetCoord`]): `(col, row)` for rectangular map storage,
+//!   in any of the four [`OffsetLayout`]s
+//!
+//! The grid is **flat-top**: neighbours are listed north first, clockwise.
+//!
+//! ## Toolkit
+//!
+//! - Distance, neighbours and directions
+//! - Rings, spirals and ranges
+//! - Lines between hexes (linear interpolation in cube space)
+//! - Rotation and reflection around any centre
+//! - Line of sight and field of view ([`fov`])
+//! - Pixel conversion for flat-top layouts
+//!
+//! ## Features
+//!
+//! - `serde`: derives `Serialize`/`Deserialize` for [`HexCoord`]
+
+mod coord;
+mod cube;
+pub mod fov;
+mod offset;
+
+pub use coord::{HexCoord, DIRECTIONS};
+pub use cube::{CubeCoord, FractionalHex};
+pub use fov::{field_of_view, has_line_of_sight};
+pub use offset::{OffsetCoord, OffsetLayout};
@@ -0,0 +1,57 @@
+use crate::coord::HexCoord;
+
+/// How rows or columns are shifted in an offset (rectangular) layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    /// Flat-top, odd columns shoved down
+    OddQ,
+    /// Flat-top, even columns shoved down
+    EvenQ,
+    /// Pointy-top, odd rows shoved right
+    OddR,
+    /// Pointy-top, even rows shoved right
+    EvenR,
+}
+
+/// Offset coordinates `(col, row)` for rectangular map storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OffsetCoord {
+    pub col: i32,
+    pub row: i32,
+}
+
+impl HexCoord {
+    /// Converts to offset coordinates in `layout`.
+    pub fn to_offset(self, layout: OffsetLayout) -> OffsetCoord {
+        let (q, r) = (self.q, self.r);
+        match layout {
+            OffsetLayout::OddQ => OffsetCoord {
+                col: q,
+                row: r + (q - (q & 1)) / 2,
+            },
+            OffsetLayout::EvenQ => OffsetCoord {
+                col: q,
+                row: r + (q + (q & 1)) / 2,
+            },
+            OffsetLayout::OddR => OffsetCoord {
+                col: q + (r - (r & 1)) / 2,
+                row: r,
+            },
+            OffsetLayout::EvenR => OffsetCoord {
+                col: q + (r + (r & 1)) / 2,
+                row: r,
+            },
+        }
+    }
+
+    /// Converts offset coordinates in `layout` back to axial.
+    pub fn from_offset(offset: OffsetCoord, layout: OffsetLayout) -> HexCoord {
+        let (col, row) = (offset.col, offset.row);
+        match layout {
+            OffsetLayout::OddQ => HexCoord::new(col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => HexCoord::new(col, row - (col + (col & 1)) / 2),
+            OffsetLayout::OddR => HexCoord::new(col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => HexCoord::new(col - (row + (row & 1)) / 2, row),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+/// Tests for the shared hex-grid toolkit
+use hex::{
+    field_of_view, has_line_of_sight, CubeCoord, FractionalHex, HexCoord, OffsetCoord,
+    OffsetLayout, DIRECTIONS,
+};
+use std::collections::HashSet;
+
+#[test]
+fn test_distance_and_neighbors() {
+    let a = HexCoord::new(0, 0);
+    assert_eq!(a.distance(HexCoord::new(3, -1)), 3);
+    assert_eq!(a.distance(HexCoord::new(-2, -2)), 4);
+    assert_eq!(HexCoord::new(2, -5).length(), 5);
+
+    let neighbors = HexCoord::new(2, 3).neighbors();
+    assert_eq!(neighbors[0], HexCoord::new(2, 2)); // North
+    assert_eq!(neighbors[2], HexCoord::new(3, 3)); // Southeast
+    for n in neighbors {
+        assert_eq!(n.distance(HexCoord::new(2, 3)), 1);
+    }
+    assert_eq!(HexCoord::new(2, 3).neighbor(7), neighbors[1]);
+}
+
+#[test]
+fn test_rings_and_spirals() {
+    let center = HexCoord::new(1, -1);
+    assert_eq!(center.ring(0), vec![center]);
+    for radius in 1..4 {
+        let ring = center.ring(radius);
+        assert_eq!(ring.len(), 6 * radius as usize);
+        assert!(ring.iter().all(|h| h.distance(center) == radius as i32));
+        let unique: HashSet<_> = ring.iter().collect();
+        assert_eq!(unique.len(), ring.len());
+    }
+
+    let spiral = center.spiral(2);
+    assert_eq!(spiral.len(), 19);
+    assert_eq!(spiral[0], center);
+    assert!(spiral.iter().all(|h| h.distance(center) <= 2));
+}
+
+#[test]
+fn test_lines() {
+    let a = HexCoord::new(0, 0);
+    let b = HexCoord::new(4, -2);
+    let line = a.line_to(b);
+    assert_eq!(line.len(), 5);
+    assert_eq!(line.first(), Some(&a));
+    assert_eq!(line.last(), Some(&b));
+    for pair in line.windows(2) {
+        assert_eq!(pair[0].distance(pair[1]), 1);
+    }
+    assert_eq!(a.line_to(a), vec![a]);
+}
+
+#[test]
+fn test_rotation_and_reflection() {
+    let h = HexCoord::new(2, -1);
+    assert_eq!(DIRECTIONS[0].rotate_cw(), DIRECTIONS[1]);
+    assert_eq!(DIRECTIONS[5].rotate_cw(), DIRECTIONS[0]);
+    assert_eq!(h.rotate_cw().rotate_ccw(), h);
+
+    let mut rotated = h;
+    for _ in 0..6 {
+        rotated = rotated.rotate_cw();
+    }
+    assert_eq!(rotated, h);
+
+    let center = HexCoord::new(5, 5);
+    let p = center + HexCoord::new(0, -2);
+    assert_eq!(p.rotate_around(center, 1), center + HexCoord::new(2, -2));
+    assert_eq!(p.rotate_around(center, -1), p.rotate_around(center, 5));
+
+    assert_eq!(h.reflect_q(), HexCoord::new(2, -1));
+    assert_eq!(HexCoord::new(1, 2).reflect_q(), HexCoord::new(1, -3));
+    assert_eq!(HexCoord::new(1, 2).reflect_r(), HexCoord::new(-3, 2));
+    assert_eq!(HexCoord::new(1, 2).reflect_s(), HexCoord::new(2, 1));
+    assert_eq!(
+        HexCoord::new(1, 2).reflect_r().reflect_r(),
+        HexCoord::new(1, 2)
+    );
+}
+
+#[test]
+fn test_cube_and_offset_conversions() {
+    let h = HexCoord::new(3, -7);
+    let cube = CubeCoord::from(h);
+    assert_eq!(cube.x + cube.y + cube.z, 0);
+    assert_eq!(HexCoord::from(cube), h);
+
+    for layout in [
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+    ] {
+        for hex in HexCoord::new(0, 0).spiral(3) {
+            assert_eq!(HexCoord::from_offset(hex.to_offset(layout), layout), hex);
+        }
+    }
+    assert_eq!(
+        HexCoord::new(1, 0).to_offset(OffsetLayout::OddQ),
+        OffsetCoord { col: 1, row: 0 }
+    );
+    assert_eq!(
+        HexCoord::new(1, 0).to_offset(OffsetLayout::EvenQ),
+        OffsetCoord { col: 1, row: 1 }
+    );
+}
+
+#[test]
+fn test_rounding_and_pixels() {
+    assert_eq!(
+        FractionalHex { q: 0.4, r: 0.4 }.round(),
+        HexCoord::new(0, 1)
+    );
+    assert_eq!(HexCoord::axial_round(1.1, -0.9), HexCoord::new(1, -1));
+
+    for hex in HexCoord::new(0, 0).spiral(3) {
+        let (x, y) = hex.to_pixel(30.0);
+        assert_eq!(HexCoord::from_pixel(x + 3.0, y - 2.0, 30.0), hex);
+    }
+}
+
+#[test]
+fn test_line_of_sight_and_field_of_view() {
+    let origin = HexCoord::new(0, 0);
+    let wall = HexCoord::new(0, -1);
+    let blocks = |h: HexCoord| h == wall;
+
+    assert!(has_line_of_sight(origin, wall, blocks));
+    assert!(!has_line_of_sight(origin, HexCoord::new(0, -2), blocks));
+    assert!(has_line_of_sight(origin, HexCoord::new(2, -1), blocks));
+
+    let visible = field_of_view(origin, 2, blocks);
+    assert_eq!(visible[0], origin);
+    assert!(visible.contains(&wall));
+    assert!(!visible.contains(&HexCoord::new(0, -2)));
+    assert_eq!(visible.len(), 18);
+    assert_eq!(field_of_view(origin, 2, |_| false).len(), 19);
+}
@@ -0,0 +1,176 @@
+//! Damage types shared by items, units, structures and abilities.
+//!
+//! [`DamageType`] is the single list of damage types in the game. Weapons
+//! (`ItemAttack`), unit attacks, ability effects and resistances all use it,
+//! so an item's damage type reaches combat unchanged.
+//!
+//! Every damage type has an entry in the damage type registry with its
+//! display name, category and description; see [`DamageType::all`] and
+//! [`DamageType::info`]. To add a damage type, add a variant and its registry
+//! entry: resistance tables, the encyclopedia and the UI list every type in
+//! the registry.
+//!
+//! Example:
+//!
+//! ```rust
+//! use items::{DamageCategory, DamageType};
+//!
+//! assert_eq!(DamageType::Holy.name(), "Holy");
+//! assert_eq!(DamageType::Holy.category(), DamageCategory::Divine);
+//! assert_eq!(DamageType::from_name("lightning"), Some(DamageType::Lightning));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Type of damage dealt by an attack.
+///
+/// The variant order matches the registry order, which is also the order
+/// damage types are listed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Slash,
+    Pierce,
+    Blunt,
+    Crush,
+    Fire,
+    Ice,
+    Lightning,
+    Poison,
+    Holy,
+    Dark,
+}
+
+/// Broad family a damage type belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageCategory {
+    /// Blades, arrows and blunt force, stopped by armor
+    Physical,
+    /// Fire, ice, lightning and poison
+    Elemental,
+    /// Holy and dark magic
+    Divine,
+}
+
+/// Registry entry describing a damage type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DamageTypeInfo {
+    /// The damage type described
+    pub damage_type: DamageType,
+    /// Display name
+    pub name: &'static str,
+    /// Family of the damage type
+    pub category: DamageCategory,
+    /// Short description for tooltips and the encyclopedia
+    pub description: &'static str,
+}
+
+/// The damage type registry, in [`DamageType`] variant order.
+const DAMAGE_TYPES: [DamageTypeInfo; 10] = [
+    DamageTypeInfo {
+        damage_type: DamageType::Slash,
+        name: "Slash",
+        category: DamageCategory::Physical,
+        description: "Cutting blows from swords and axes",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Pierce,
+        name: "Pierce",
+        category: DamageCategory::Physical,
+        description: "Penetrating strikes from arrows and spears",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Blunt,
+        name: "Blunt",
+        category: DamageCategory::Physical,
+        description: "Bludgeoning from maces, clubs and shields",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Crush,
+        name: "Crush",
+        category: DamageCategory::Physical,
+        description: "Heavy impacts from hammers and siege engines",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Fire,
+        name: "Fire",
+        category: DamageCategory::Elemental,
+        description: "Flames and burning spells",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Ice,
+        name: "Ice",
+        category: DamageCategory::Elemental,
+        description: "Freezing cold",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Lightning,
+        name: "Lightning",
+        category: DamageCategory::Elemental,
+        description: "Arcing electricity that finds gaps in armor",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Poison,
+        name: "Poison",
+        category: DamageCategory::Elemental,
+        description: "Venoms and toxins",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Holy,
+        name: "Holy",
+        category: DamageCategory::Divine,
+        description: "Radiant power that sears the unholy",
+    },
+    DamageTypeInfo {
+        damage_type: DamageType::Dark,
+        name: "Dark",
+        category: DamageCategory::Divine,
+        description: "Necromantic and shadow magic",
+    },
+];
+
+impl DamageType {
+    /// Every damage type, in registry order.
+    pub fn all() -> impl Iterator<Item = DamageType> {
+        DAMAGE_TYPES.iter().map(|info| info.damage_type)
+    }
+
+    /// Registry entries of every damage type, in registry order.
+    pub fn registry() -> &'static [DamageTypeInfo] {
+        &DAMAGE_TYPES
+    }
+
+    /// Registry entry of this damage type.
+    pub fn info(&self) -> &'static DamageTypeInfo {
+        &DAMAGE_TYPES[*self as usize]
+    }
+
+    /// Display name of this damage type.
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+
+    /// Family this damage type belongs to.
+    pub fn category(&self) -> DamageCategory {
+        self.info().category
+    }
+
+    /// Short description of this damage type.
+    pub fn description(&self) -> &'static str {
+        self.info().description
+    }
+
+    /// Look up a damage type by display name, ignoring case.
+    pub fn from_name(name: &str) -> Option<DamageType> {
+        DAMAGE_TYPES
+            .iter()
+            .find(|info| info.name.eq_ignore_ascii_case(name))
+            .map(|info| info.damage_type)
+    }
+}
+
+impl fmt::Display for DamageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -169,6 +169,104 @@ impl Equipment {
 
         items
     }
+
+    /// Stat changes from equipping `item`, including the bonuses of any item
+    /// it would displace from its slot.
+    pub fn delta_if_equipped(&self, item: &Item) -> EquipmentDelta {
+        let mut after = self.clone();
+        after.equip_item(item.clone());
+        EquipmentDelta::between(self, &after)
+    }
+
+    /// Stat changes from unequipping the item with `item_id`.
+    ///
+    /// The delta is empty if the item is not equipped.
+    pub fn delta_if_unequipped(&self, item_id: ItemId) -> EquipmentDelta {
+        let mut after = self.clone();
+        after.unequip_item(item_id);
+        EquipmentDelta::between(self, &after)
+    }
+}
+
+/// Change in equipment bonuses between two loadouts.
+///
+/// Used to preview what equipping or unequipping an item would do before
+/// committing to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EquipmentDelta {
+    /// Change in attack bonus
+    pub attack: i32,
+    /// Change in defense bonus
+    pub defense: i32,
+    /// Change in movement modifier
+    pub movement: i32,
+    /// Change in maximum health bonus
+    pub health: i32,
+    /// Change in attack range modifier
+    pub range: i32,
+    /// Weapon range type override before the change
+    pub range_override_before: Option<RangeType>,
+    /// Weapon range type override after the change
+    pub range_override_after: Option<RangeType>,
+}
+
+impl EquipmentDelta {
+    /// Compute the delta from `before` to `after`.
+    pub fn between(before: &Equipment, after: &Equipment) -> Self {
+        Self {
+            attack: after.get_total_attack_bonus() - before.get_total_attack_bonus(),
+            defense: after.get_total_defense_bonus() - before.get_total_defense_bonus(),
+            movement: after.get_total_movement_modifier() - before.get_total_movement_modifier(),
+            health: after.get_total_health_bonus() - before.get_total_health_bonus(),
+            range: after.get_total_range_modifier() - before.get_total_range_modifier(),
+            range_override_before: before.get_range_type_override(),
+            range_override_after: after.get_range_type_override(),
+        }
+    }
+
+    /// Check if the change leaves every stat as it was.
+    pub fn is_empty(&self) -> bool {
+        self.attack == 0
+            && self.defense == 0
+            && self.movement == 0
+            && self.health == 0
+            && self.range == 0
+            && self.range_override_before == self.range_override_after
+    }
+
+    /// Short human-readable description, such as `"Attack +3, Range Melee -> Ranged"`.
+    ///
+    /// Returns `"No change"` for an empty delta.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = [
+            ("Attack", self.attack),
+            ("Defense", self.defense),
+            ("Movement", self.movement),
+            ("Health", self.health),
+            ("Range", self.range),
+        ]
+        .iter()
+        .filter(|(_, change)| *change != 0)
+        .map(|(stat, change)| format!("{} {:+}", stat, change))
+        .collect();
+
+        if self.range_override_before != self.range_override_after {
+            let describe = |range: Option<RangeType>| {
+                range.map_or("default".to_string(), |r| format!("{:?}", r))
+            };
+            parts.push(format!(
+                "Range type {} -> {}",
+                describe(self.range_override_before),
+                describe(self.range_override_after)
+            ));
+        }
+
+        if parts.is_empty() {
+            "No change".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
 }
 
 impl Default for Equipment {
@@ -1,4 +1,4 @@
-use crate::item_properties::{ItemProperties, RangeType};
+use crate::item_properties::{ConsumableEffect, ItemProperties, RangeType};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -14,6 +14,85 @@ pub enum ItemType {
     Consumable,
 }
 
+impl ItemType {
+    /// Get the name of this item type as a string.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemType::Weapon => "Weapon",
+            ItemType::Armor => "Armor",
+            ItemType::Accessory => "Accessory",
+            ItemType::Consumable => "Consumable",
+        }
+    }
+}
+
+/// How rare an item is, from common loot to unique artifacts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    /// Get the name of this rarity as a string.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+}
+
+/// Who may equip an item.
+///
+/// Empty race and class lists mean "anyone". Races and classes are matched
+/// by name, case-insensitively (e.g. `"Dwarf"`, `"Knight"`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemRequirements {
+    /// Minimum unit level
+    pub min_level: i32,
+    /// Races allowed to equip the item
+    pub races: Vec<String>,
+    /// Unit classes allowed to equip the item
+    pub classes: Vec<String>,
+}
+
+impl ItemRequirements {
+    /// Whether there are no requirements at all.
+    pub fn is_empty(&self) -> bool {
+        self.min_level <= 1 && self.races.is_empty() && self.classes.is_empty()
+    }
+
+    /// Checks a unit against these requirements.
+    ///
+    /// # Returns
+    ///
+    /// `Err(String)` naming the first requirement the unit fails
+    pub fn check(&self, level: i32, race: &str, class: &str) -> Result<(), String> {
+        let allows = |list: &[String], value: &str| {
+            list.is_empty() || list.iter().any(|v| v.eq_ignore_ascii_case(value))
+        };
+        if level < self.min_level {
+            return Err(format!("requires level {}", self.min_level));
+        }
+        if !allows(&self.races, race) {
+            return Err(format!("only for {}", self.races.join("/")));
+        }
+        if !allows(&self.classes, class) {
+            return Err(format!("only for {}", self.classes.join("/")));
+        }
+        Ok(())
+    }
+}
+
 /// Represents an item that can be equipped or used by a unit.
 ///
 /// `Item` stores metadata and the [`ItemProperties`] which describe the
@@ -31,6 +110,15 @@ pub struct Item {
     pub item_type: ItemType,
     /// Mechanical properties
     pub properties: ItemProperties,
+    /// How rare the item is
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Trade value in gold
+    #[serde(default)]
+    pub value: u32,
+    /// Who may equip the item
+    #[serde(default)]
+    pub requirements: ItemRequirements,
 }
 
 impl Item {
@@ -51,6 +139,9 @@ impl Item {
             description,
             item_type,
             properties,
+            rarity: Rarity::default(),
+            value: 0,
+            requirements: ItemRequirements::default(),
         }
     }
 
@@ -109,4 +200,34 @@ impl Item {
             _ => 0,
         }
     }
+
+    /// Get the remaining uses of a consumable, or `None` for other items.
+    pub fn remaining_uses(&self) -> Option<i32> {
+        match &self.properties {
+            ItemProperties::Consumable { uses, .. } => Some(*uses),
+            _ => None,
+        }
+    }
+
+    /// Get the effect of a consumable, or `None` for other items.
+    pub fn consumable_effect(&self) -> Option<&ConsumableEffect> {
+        match &self.properties {
+            ItemProperties::Consumable { effect, .. } => Some(effect),
+            _ => None,
+        }
+    }
+
+    /// Spend one use of a consumable.
+    ///
+    /// Returns the effect to apply, or `None` if the item is not a consumable
+    /// or has no uses left.
+    pub fn consume_use(&mut self) -> Option<ConsumableEffect> {
+        match &mut self.properties {
+            ItemProperties::Consumable { uses, effect } if *uses > 0 => {
+                *uses -= 1;
+                Some(effect.clone())
+            }
+            _ => None,
+        }
+    }
 }
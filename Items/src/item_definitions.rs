@@ -1,21 +1,12 @@
-use crate::{DamageType, Item, ItemAttack, ItemProperties};
+use crate::{Item, ItemRegistry};
 
 /// Create the Iron Sword item
+///
+/// Shorthand for creating `"iron_sword"` from the [built-in
+/// registry](ItemRegistry::builtin); other items are created from the
+/// registry by id.
 pub fn create_iron_sword() -> Item {
-    Item::new(
-        "Iron Sword".to_string(),
-        "A sturdy iron sword with a sharp blade.".to_string(),
-        ItemProperties::Weapon {
-            attack_bonus: 1,
-            range_modifier: 0,
-            range_type_override: None,
-            attacks: vec![ItemAttack::new("Slash", 8, 2, DamageType::Slash)],
-        },
-    )
+    ItemRegistry::builtin()
+        .create("iron_sword")
+        .expect("iron_sword is missing from items.json")
 }
-
-// TODO: Add more item definitions here
-// Examples:
-// pub fn create_steel_axe() -> Item { ... }
-// pub fn create_leather_armor() -> Item { ... }
-// pub fn create_health_potion() -> Item { ... }
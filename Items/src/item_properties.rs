@@ -1,18 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-/// Type of damage dealt by an attack.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum DamageType {
-    Slash,
-    Pierce,
-    Blunt,
-    Fire,
-    Ice,
-    Lightning,
-    Poison,
-    Holy,
-    Dark,
-}
+pub use crate::damage_type::DamageType;
 
 /// Represents a specific attack that an item provides.
 ///
@@ -47,9 +35,15 @@ impl ItemAttack {
     }
 }
 
-/// Range type for attacks.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Range type of an attack or unit.
+///
+/// This is the only range enum in the game: items override it on weapons,
+/// units use it as their range category and every attack carries one. It
+/// decides both an attack's base reach and which attacks may answer it
+/// (see [`RangeType::can_retaliate_against`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RangeType {
+    #[default]
     Melee,
     Ranged,
     Siege,
@@ -61,7 +55,7 @@ impl RangeType {
         match self {
             RangeType::Melee => 1,  // 1 hex away
             RangeType::Ranged => 3, // 3 hexes away
-            RangeType::Siege => 6,  // 6 hexes away
+            RangeType::Siege => 5,  // 5 hexes away
         }
     }
 
@@ -73,6 +67,16 @@ impl RangeType {
             RangeType::Siege => "Siege",
         }
     }
+
+    /// Whether an attack of this range type may answer an incoming attack of
+    /// range type `incoming`.
+    ///
+    /// Counter-attacks must match the incoming attack: melee answers melee,
+    /// ranged answers ranged and siege answers siege. Melee can never answer
+    /// a ranged or siege attack.
+    pub fn can_retaliate_against(&self, incoming: RangeType) -> bool {
+        *self == incoming
+    }
 }
 
 impl std::fmt::Display for RangeType {
@@ -0,0 +1,185 @@
+//! Item registry loaded from data files.
+//!
+//! Every item the game knows about is described once in an item database
+//! (a JSON array of [`ItemDefinition`]s) and looked up by its string id, e.g.
+//! `"iron_sword"`. The database bundled with the crate lives in
+//! `Items/data/items.json` and is available as [`ItemRegistry::builtin`].
+//!
+//! Example:
+//!
+//! ```rust
+//! use items::ItemRegistry;
+//!
+//! let registry = ItemRegistry::builtin();
+//! let sword = registry.create("iron_sword").unwrap();
+//! assert_eq!(sword.name, "Iron Sword");
+//! ```
+
+use crate::item::{Item, ItemRequirements, ItemType, Rarity};
+use crate::item_properties::ItemProperties;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The item database bundled with the crate.
+const BUILTIN_ITEMS: &str = include_str!("../data/items.json");
+
+lazy_static! {
+    static ref BUILTIN_REGISTRY: ItemRegistry =
+        ItemRegistry::from_json(BUILTIN_ITEMS).expect("bundled items.json is invalid");
+}
+
+/// One entry of the item database: a template for creating [`Item`]s.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemDefinition {
+    /// Stable id maps and code refer to the item by
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Flavor / tooltip description
+    pub description: String,
+    /// How rare the item is
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Trade value in gold
+    #[serde(default)]
+    pub value: u32,
+    /// Who may equip the item
+    #[serde(default)]
+    pub requirements: ItemRequirements,
+    /// Mechanical properties
+    pub properties: ItemProperties,
+}
+
+impl ItemDefinition {
+    /// High-level type of the items this definition creates.
+    pub fn item_type(&self) -> ItemType {
+        match self.properties {
+            ItemProperties::Weapon { .. } => ItemType::Weapon,
+            ItemProperties::Armor { .. } => ItemType::Armor,
+            ItemProperties::Accessory { .. } => ItemType::Accessory,
+            ItemProperties::Consumable { .. } => ItemType::Consumable,
+        }
+    }
+
+    /// Creates a new item (with its own UUID) from this definition.
+    pub fn create(&self) -> Item {
+        let mut item = Item::new(
+            self.name.clone(),
+            self.description.clone(),
+            self.properties.clone(),
+        );
+        item.rarity = self.rarity;
+        item.value = self.value;
+        item.requirements = self.requirements.clone();
+        item
+    }
+}
+
+/// All known item definitions, keyed by id.
+#[derive(Clone, Debug, Default)]
+pub struct ItemRegistry {
+    items: HashMap<String, ItemDefinition>,
+}
+
+impl ItemRegistry {
+    /// The item database bundled with the crate.
+    pub fn builtin() -> &'static ItemRegistry {
+        &BUILTIN_REGISTRY
+    }
+
+    /// Parses an item database: a JSON array of item definitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or two definitions share an id
+    /// or a name.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let definitions: Vec<ItemDefinition> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid item database: {}", e))?;
+        let mut registry = Self::default();
+        for definition in definitions {
+            registry.register(definition)?;
+        }
+        Ok(registry)
+    }
+
+    /// Loads an item database file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_json(&json)
+    }
+
+    /// Adds a definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the id or the name (case-insensitive) is already
+    /// taken, so [`ItemRegistry::resolve`] finds at most one item by name.
+    pub fn register(&mut self, definition: ItemDefinition) -> Result<(), String> {
+        if self.items.contains_key(&definition.id) {
+            return Err(format!("Duplicate item id '{}'", definition.id));
+        }
+        if let Some(existing) = self
+            .items
+            .values()
+            .find(|def| def.name.eq_ignore_ascii_case(&definition.name))
+        {
+            return Err(format!(
+                "Duplicate item name '{}' (ids '{}' and '{}')",
+                definition.name, existing.id, definition.id
+            ));
+        }
+        self.items.insert(definition.id.clone(), definition);
+        Ok(())
+    }
+
+    /// Gets a definition by id.
+    pub fn get(&self, id: &str) -> Option<&ItemDefinition> {
+        self.items.get(id)
+    }
+
+    /// Gets a definition by id, or by display name (case-insensitive).
+    ///
+    /// Lets older maps that name items (`"Iron Sword"`) keep working.
+    pub fn resolve(&self, id_or_name: &str) -> Option<&ItemDefinition> {
+        self.get(id_or_name).or_else(|| {
+            self.items
+                .values()
+                .find(|def| def.name.eq_ignore_ascii_case(id_or_name))
+        })
+    }
+
+    /// Creates a new item from the definition with the given id or name.
+    pub fn create(&self, id_or_name: &str) -> Option<Item> {
+        self.resolve(id_or_name).map(ItemDefinition::create)
+    }
+
+    /// All definitions, sorted by id.
+    pub fn all(&self) -> Vec<&ItemDefinition> {
+        let mut all: Vec<_> = self.items.values().collect();
+        all.sort_by(|a, b| a.id.cmp(&b.id));
+        all
+    }
+
+    /// All definitions of one item type, sorted by id.
+    pub fn by_type(&self, item_type: ItemType) -> Vec<&ItemDefinition> {
+        self.all()
+            .into_iter()
+            .filter(|def| def.item_type() == item_type)
+            .collect()
+    }
+
+    /// Number of definitions.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there are no definitions.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
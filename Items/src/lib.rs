@@ -6,8 +6,12 @@
 //!
 //! Key types:
 //! - [`Item`] and [`ItemId`]
-//! - [`Equipment`]
+//! - [`Equipment`] and [`EquipmentDelta`], a preview of the stat changes from
+//!   equipping or unequipping an item
 //! - [`ItemProperties`], [`ItemAttack`], [`RangeType`]
+//! - [`DamageType`], the damage type registry shared with units and combat
+//! - [`ItemRegistry`] and [`ItemDefinition`], the item database loaded from
+//!   `data/items.json`
 //!
 //! Example:
 //!
@@ -18,11 +22,15 @@
 //! println!("Created item: {}", sword.name);
 //! ```
 
+pub mod damage_type;
 mod equipment;
 mod item;
 pub mod item_definitions;
 pub mod item_properties;
+pub mod item_registry;
 
-pub use equipment::Equipment;
-pub use item::{Item, ItemId, ItemType};
-pub use item_properties::{ConsumableEffect, DamageType, ItemAttack, ItemProperties, RangeType};
+pub use damage_type::{DamageCategory, DamageType, DamageTypeInfo};
+pub use equipment::{Equipment, EquipmentDelta};
+pub use item::{Item, ItemId, ItemRequirements, ItemType, Rarity};
+pub use item_properties::{ConsumableEffect, ItemAttack, ItemProperties, RangeType};
+pub use item_registry::{ItemDefinition, ItemRegistry};
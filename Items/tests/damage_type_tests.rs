@@ -0,0 +1,32 @@
+//! Tests for the damage type registry
+
+use items::{DamageCategory, DamageType};
+use std::collections::HashSet;
+
+#[test]
+fn test_registry_matches_variant_order() {
+    for (index, damage_type) in DamageType::all().enumerate() {
+        assert_eq!(damage_type as usize, index);
+        assert_eq!(damage_type.info().damage_type, damage_type);
+    }
+    assert_eq!(DamageType::all().count(), DamageType::registry().len());
+}
+
+#[test]
+fn test_registry_names_are_unique() {
+    let names: HashSet<&str> = DamageType::all()
+        .map(|damage_type| damage_type.name())
+        .collect();
+    assert_eq!(names.len(), DamageType::registry().len());
+}
+
+#[test]
+fn test_from_name_round_trips() {
+    for damage_type in DamageType::all() {
+        assert_eq!(DamageType::from_name(damage_type.name()), Some(damage_type));
+    }
+    assert_eq!(DamageType::from_name("HOLY"), Some(DamageType::Holy));
+    assert_eq!(DamageType::from_name("Acid"), None);
+    assert_eq!(DamageType::Ice.category(), DamageCategory::Elemental);
+    assert_eq!(DamageType::Poison.to_string(), "Poison");
+}
@@ -0,0 +1,44 @@
+//! Tests for equipment slots and equip/unequip stat previews
+
+use items::{Equipment, ItemRegistry, RangeType};
+
+#[test]
+fn test_delta_if_equipped_includes_displaced_item() {
+    let registry = ItemRegistry::builtin();
+    let mut equipment = Equipment::new();
+    equipment.equip_item(registry.create("iron_sword").unwrap());
+
+    let bow = registry.create("longbow").unwrap();
+    let delta = equipment.delta_if_equipped(&bow);
+
+    // Sword and bow both give +1 attack, so only the range type changes
+    assert_eq!(delta.attack, 0);
+    assert_eq!(delta.range_override_before, None);
+    assert_eq!(delta.range_override_after, Some(RangeType::Ranged));
+    assert!(!delta.is_empty());
+    assert_eq!(delta.summary(), "Range type default -> Ranged");
+
+    // Previewing does not change the loadout
+    assert_eq!(equipment.weapon.as_ref().unwrap().name, "Iron Sword");
+}
+
+#[test]
+fn test_delta_if_unequipped_reverses_bonuses() {
+    let registry = ItemRegistry::builtin();
+    let mut equipment = Equipment::new();
+    let boots = registry.create("boots_of_speed").unwrap();
+    let boots_id = boots.id;
+
+    let equip = equipment.delta_if_equipped(&boots);
+    assert_eq!(equip.movement, 1);
+    assert_eq!(equip.summary(), "Movement +1");
+
+    equipment.equip_item(boots);
+    let unequip = equipment.delta_if_unequipped(boots_id);
+    assert_eq!(unequip.movement, -1);
+
+    // Unknown items change nothing
+    let unknown = equipment.delta_if_unequipped(uuid::Uuid::new_v4());
+    assert!(unknown.is_empty());
+    assert_eq!(unknown.summary(), "No change");
+}
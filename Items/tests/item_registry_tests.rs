@@ -0,0 +1,102 @@
+//! Tests for the item database, item requirements and consumable uses
+
+use items::{ConsumableEffect, ItemProperties, ItemRegistry, ItemRequirements, ItemType, Rarity};
+
+#[test]
+fn test_builtin_registry_covers_every_item_type() {
+    let registry = ItemRegistry::builtin();
+    for item_type in [
+        ItemType::Weapon,
+        ItemType::Armor,
+        ItemType::Accessory,
+        ItemType::Consumable,
+    ] {
+        assert!(
+            registry.by_type(item_type.clone()).len() >= 3,
+            "too few {} items",
+            item_type.name()
+        );
+    }
+    assert!(registry
+        .all()
+        .iter()
+        .any(|def| def.rarity == Rarity::Legendary));
+}
+
+#[test]
+fn test_create_copies_definition() {
+    let registry = ItemRegistry::builtin();
+    let definition = registry.get("war_hammer").unwrap();
+    let hammer = registry.create("war_hammer").unwrap();
+
+    assert_eq!(hammer.name, definition.name);
+    assert_eq!(hammer.item_type, ItemType::Weapon);
+    assert_eq!(hammer.rarity, definition.rarity);
+    assert_eq!(hammer.value, definition.value);
+    assert_eq!(hammer.requirements, definition.requirements);
+    assert!(matches!(hammer.properties, ItemProperties::Weapon { .. }));
+
+    // Every created item is a separate instance
+    assert_ne!(hammer.id, registry.create("war_hammer").unwrap().id);
+}
+
+#[test]
+fn test_resolve_by_id_or_name() {
+    let registry = ItemRegistry::builtin();
+    assert_eq!(registry.resolve("iron_sword").unwrap().name, "Iron Sword");
+    assert_eq!(registry.resolve("iron sword").unwrap().id, "iron_sword");
+    assert!(registry.resolve("excalibur").is_none());
+}
+
+#[test]
+fn test_from_json_rejects_duplicates_and_bad_data() {
+    let potion = r#"{"id": "potion", "name": "Potion", "description": "",
+        "properties": {"Consumable": {"uses": 1, "effect": {"Heal": {"amount": 5}}}}}"#;
+
+    let registry = ItemRegistry::from_json(&format!("[{}]", potion)).unwrap();
+    let definition = registry.get("potion").unwrap();
+    assert_eq!(definition.rarity, Rarity::Common);
+    assert!(definition.requirements.is_empty());
+
+    assert!(ItemRegistry::from_json(&format!("[{0}, {0}]", potion)).is_err());
+    let renamed = potion.replace(r#""id": "potion""#, r#""id": "potion_2""#);
+    let error = ItemRegistry::from_json(&format!("[{}, {}]", potion, renamed)).unwrap_err();
+    assert!(error.contains("Duplicate item name"));
+    assert!(ItemRegistry::from_json(r#"[{"id": "broken"}]"#).is_err());
+}
+
+#[test]
+fn test_requirements_check_level_race_and_class() {
+    let requirements = ItemRequirements {
+        min_level: 2,
+        races: vec!["Dwarf".to_string()],
+        classes: vec!["Warrior".to_string()],
+    };
+    assert!(requirements.check(2, "Dwarf", "Warrior").is_ok());
+    assert!(requirements.check(3, "dwarf", "warrior").is_ok());
+    assert!(requirements.check(1, "Dwarf", "Warrior").is_err());
+    assert!(requirements.check(2, "Elf", "Warrior").is_err());
+    assert!(requirements.check(2, "Dwarf", "Archer").is_err());
+    assert!(ItemRequirements::default()
+        .check(1, "Orc", "Swordsman")
+        .is_ok());
+}
+
+#[test]
+fn test_consume_use_counts_down_uses() {
+    let registry = ItemRegistry::builtin();
+    let mut draught = registry.create("restoration_draught").unwrap();
+    assert_eq!(draught.remaining_uses(), Some(2));
+
+    assert_eq!(
+        draught.consume_use(),
+        Some(ConsumableEffect::Restore { health: 15 })
+    );
+    assert!(draught.consume_use().is_some());
+    assert_eq!(draught.remaining_uses(), Some(0));
+    assert_eq!(draught.consume_use(), None);
+
+    let mut sword = registry.create("iron_sword").unwrap();
+    assert_eq!(sword.remaining_uses(), None);
+    assert_eq!(sword.consume_use(), None);
+}
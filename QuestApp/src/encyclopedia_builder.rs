@@ -1,5 +1,6 @@
 // === Encyclopedia Content Helpers (moved from main.rs) ===
 use encyclopedia::{Encyclopedia, EncyclopediaEntry, MechanicEntry};
+use items::DamageType;
 
 pub struct EncyclopediaBuilder {
     title: String,
@@ -21,6 +22,10 @@ impl EncyclopediaBuilder {
         self.description.push(line.into());
         self
     }
+    pub fn descriptions(mut self, lines: impl IntoIterator<Item = String>) -> Self {
+        self.description.extend(lines);
+        self
+    }
     pub fn stat(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.stats.push((key.into(), value.into()));
         self
@@ -63,15 +68,15 @@ impl EncyclopediaLibrary {
             .description("Combat is resolved using attack, defense, and damage types.")
             .description("")
             .description("Damage Types:")
-            .description("• Slash - Swords and bladed weapons")
-            .description("• Pierce - Arrows, spears, daggers")
-            .description("• Blunt - Hammers and clubs")
-            .description("• Crush - Heavy weapons and siege")
-            .description("• Fire - Magical fire damage")
-            .description("• Dark - Shadow and curse magic")
+            .descriptions(
+                DamageType::registry()
+                    .iter()
+                    .map(|info| format!("• {} - {}", info.name, info.description)),
+            )
             .stat("Hit Chance", "Base + Terrain Bonus - Enemy Evasion")
             .stat("Damage", "Attack - (Defense * Resistance)")
             .tip("Different units have different resistances to damage types")
+            .tip("Negative resistances are weaknesses that increase damage taken")
             .tip("Terrain provides bonuses to hit chance for certain races")
             .tip("Equipment can modify your attack range and damage type")
             .build()
@@ -290,11 +295,21 @@ pub fn get_units_content_comprehensive(encyclopedia: &Encyclopedia) -> Vec<Strin
             unit.stats.movement_speed,
             unit.stats.range_category
         ));
+        let resistances: Vec<String> = unit
+            .stats
+            .resistances
+            .iter()
+            .filter(|(_, value)| *value != 0)
+            .map(|(damage_type, value)| format!("{} {}%", damage_type.name(), value))
+            .collect();
+        if !resistances.is_empty() {
+            lines.push(format!("  Resistances: {}", resistances.join(", ")));
+        }
         if !unit.attacks.is_empty() {
             lines.push("  Attacks:".to_string());
             for atk in &unit.attacks {
                 lines.push(format!(
-                    "    - {} ({} dmg, {} range, {:?})",
+                    "    - {} ({} dmg, {} range, {})",
                     atk.name, atk.damage, atk.range, atk.damage_type
                 ));
             }
@@ -370,3 +385,37 @@ pub fn get_mechanics_content_comprehensive(encyclopedia: &Encyclopedia) -> Vec<S
     }
     lines
 }
+
+pub fn get_items_content_comprehensive(encyclopedia: &Encyclopedia) -> Vec<String> {
+    let mut lines = vec![
+        "╔═══════════════════════════════════════════════════════════════════════╗".to_string(),
+        "║                        🗡️  ITEM CATALOGUE                              ║".to_string(),
+        "╠═══════════════════════════════════════════════════════════════════════╣".to_string(),
+        "".to_string(),
+    ];
+    lines.extend(format_entry(&EncyclopediaLibrary::equipment_system()));
+    let mut current_type = None;
+    for item in encyclopedia.all_items() {
+        if current_type != Some(item.item_type.clone()) {
+            current_type = Some(item.item_type.clone());
+            lines.push(
+                "════════════════════════════════════════════════════════════════════════"
+                    .to_string(),
+            );
+            lines.push(format!("{}:", item.item_type.name().to_uppercase()));
+        }
+        lines.push(format!(
+            "• {} [{} - {} gold]",
+            item.name,
+            item.rarity.name(),
+            item.value
+        ));
+        lines.push(format!("  {}", item.description));
+        lines.push(format!("  Effects: {}", item.effects.join(", ")));
+        if !item.requirements.is_empty() {
+            lines.push(format!("  Requires: {}", item.requirements.join(", ")));
+        }
+        lines.push("".to_string());
+    }
+    lines
+}
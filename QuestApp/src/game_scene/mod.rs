@@ -20,6 +20,9 @@
 //! - [`CombatState`] - Handles combat confirmation dialogs (future implementation)
 //! - [`PickupState`] - Manages item pickup prompts (future implementation)
 //! - [`EncyclopediaState`] - Controls the in-game encyclopedia/wiki display
+//! - [`AiInspectorState`] - Controls the AI decision trace inspector
+//! - [`HotSeatState`] - Tracks handoffs, views and cameras in hot-seat games
+//! - [`UseItemState`] - Drives the use-item panel over a unit's consumables
 //!
 //! # Examples
 //!
@@ -36,11 +39,15 @@
 
 pub mod states;
 
+pub use states::ai_inspector::AiInspectorState;
 pub use states::combat::CombatState;
 pub use states::encyclopedia::EncyclopediaState;
 pub use states::exploring::ExploringState;
+pub use states::hot_seat::HotSeatState;
+pub use states::inventory::InventoryState;
 pub use states::pickup::PickupState;
 pub use states::submenu::SubmenuState;
+pub use states::use_item::UseItemState;
 pub use states::GameState;
 
 /// Game scene state manager
@@ -55,6 +62,10 @@ pub use states::GameState;
 /// - `combat` - Optional handler for combat confirmation (created on-demand)
 /// - `pickup` - Optional handler for item pickups (created on-demand)
 /// - `encyclopedia` - Persistent handler for encyclopedia display
+/// - `ai_inspector` - Persistent handler for the AI inspector overlay
+/// - `hot_seat` - Persistent handler for local hot-seat play
+/// - `use_item` - Persistent handler for the use-item panel
+/// - `inventory` - Persistent handler for the inventory screen
 ///
 /// # State Lifecycle
 ///
@@ -91,6 +102,18 @@ pub struct GameSceneState {
     /// Encyclopedia state handler
     pub encyclopedia: EncyclopediaState,
 
+    /// AI inspector state handler (persistent)
+    pub ai_inspector: AiInspectorState,
+
+    /// Hot-seat state handler (persistent)
+    pub hot_seat: HotSeatState,
+
+    /// Use-item panel state handler (persistent)
+    pub use_item: UseItemState,
+
+    /// Inventory screen state handler (persistent)
+    pub inventory: InventoryState,
+
     /// Game submenu state handler (persistent)
     #[allow(dead_code)]
     pub submenu: SubmenuState,
@@ -121,6 +144,10 @@ impl GameSceneState {
             combat: None,
             pickup: None,
             encyclopedia: EncyclopediaState::new(),
+            ai_inspector: AiInspectorState::new(),
+            hot_seat: HotSeatState::new(),
+            use_item: UseItemState::new(),
+            inventory: InventoryState::new(),
             submenu: SubmenuState::new(),
         }
     }
@@ -0,0 +1,75 @@
+//! AI Inspector State
+//!
+//! Handles the AI inspector overlay where the player can:
+//! - Review the decision trace of the last AI turn
+//! - Cycle through the AI units to see each one's goals and plan
+//! - See the focused unit's planned path and target highlighted on the map
+
+/// AI inspector state handler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AiInspectorState {
+    /// Whether the inspector panel is shown
+    pub visible: bool,
+    /// Index of the focused agent in the trace
+    focus_index: usize,
+}
+
+impl AiInspectorState {
+    /// Creates a new AI inspector state with the panel hidden
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use questapp::game_scene::states::ai_inspector::AiInspectorState;
+    ///
+    /// let inspector = AiInspectorState::new();
+    /// assert!(!inspector.is_visible());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            focus_index: 0,
+        }
+    }
+
+    /// Check if the inspector is visible
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Toggles visibility and returns the new value
+    pub fn toggle(&mut self) -> bool {
+        self.visible = !self.visible;
+        self.visible
+    }
+
+    /// Moves focus to the next agent, wrapping around
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_count` - Number of agents in the current trace
+    pub fn focus_next(&mut self, agent_count: usize) {
+        if agent_count == 0 {
+            self.focus_index = 0;
+        } else {
+            self.focus_index = (self.focus_index + 1) % agent_count;
+        }
+    }
+
+    /// Returns the focused agent index, clamped to the current trace
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_count` - Number of agents in the current trace
+    ///
+    /// # Returns
+    ///
+    /// `None` if the trace has no agents
+    pub fn focused_index(&self, agent_count: usize) -> Option<usize> {
+        if agent_count == 0 {
+            None
+        } else {
+            Some(self.focus_index.min(agent_count - 1))
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Hot-Seat State
+//!
+//! Tracks a local hot-seat game where several humans share one device:
+//! - Which human team's view (fog of war, camera) is on screen
+//! - The "pass the device" screen that hides the board between human turns
+//! - Each human team's camera position, restored when its turn comes back
+//! - Which units the player at the device may command
+
+use game::Team;
+use graphics::Vec2;
+use std::collections::HashMap;
+
+/// Hot-seat state handler
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HotSeatState {
+    /// Human teams taking turns at the device (empty when hot-seat is off)
+    human_teams: Vec<Team>,
+    /// Team whose view is shown on the board
+    viewing_team: Option<Team>,
+    /// Team the device is being passed to, while the board is hidden
+    handoff_team: Option<Team>,
+    /// Camera position each human team left its last turn with
+    cameras: HashMap<Team, Vec2>,
+}
+
+impl HotSeatState {
+    /// Creates a new hot-seat state with hot-seat mode off
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use questapp::game_scene::states::hot_seat::HotSeatState;
+    ///
+    /// let hot_seat = HotSeatState::new();
+    /// assert!(!hot_seat.is_enabled());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns hot-seat mode on for the given human teams
+    ///
+    /// # Arguments
+    ///
+    /// * `human_teams` - Teams played by humans sharing the device
+    pub fn enable(&mut self, human_teams: Vec<Team>) {
+        *self = Self {
+            human_teams,
+            ..Self::default()
+        };
+    }
+
+    /// Check if hot-seat mode is on
+    pub fn is_enabled(&self) -> bool {
+        !self.human_teams.is_empty()
+    }
+
+    /// Check if a team is played by one of the humans at the device
+    pub fn is_human(&self, team: Team) -> bool {
+        self.human_teams.contains(&team)
+    }
+
+    /// Team whose view is shown on the board, if any human has played yet
+    pub fn viewing_team(&self) -> Option<Team> {
+        self.viewing_team
+    }
+
+    /// Team the device is being passed to, while the board is hidden
+    pub fn handoff_team(&self) -> Option<Team> {
+        self.handoff_team
+    }
+
+    /// Check if the "pass the device" screen is hiding the board
+    pub fn is_board_hidden(&self) -> bool {
+        self.handoff_team.is_some()
+    }
+
+    /// Notes whose turn it is, raising the handoff screen when control
+    /// passes to a different human team
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team whose turn it is
+    /// * `camera` - Current camera position, saved for the team leaving the device
+    ///
+    /// # Returns
+    ///
+    /// `true` if the handoff screen was just raised
+    pub fn begin_turn(&mut self, team: Team, camera: Vec2) -> bool {
+        if !self.is_human(team) || self.viewing_team == Some(team) || self.handoff_team.is_some() {
+            return false;
+        }
+        if let Some(previous) = self.viewing_team {
+            self.cameras.insert(previous, camera);
+        }
+        self.handoff_team = Some(team);
+        true
+    }
+
+    /// The next player confirms they hold the device, revealing the board
+    ///
+    /// # Returns
+    ///
+    /// The camera position the team left its previous turn with, if any
+    pub fn confirm_handoff(&mut self) -> Option<Vec2> {
+        let team = self.handoff_team.take()?;
+        self.viewing_team = Some(team);
+        self.cameras.get(&team).copied()
+    }
+
+    /// Check if the player at the device may select and command a unit
+    ///
+    /// Outside hot-seat mode every unit may be commanded. In hot-seat mode
+    /// only the active team's units may, and only once the board is shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `active_team` - Team whose turn it is
+    /// * `unit_team` - Team of the unit to command
+    pub fn can_command(&self, active_team: Team, unit_team: Team) -> bool {
+        if !self.is_enabled() {
+            return true;
+        }
+        !self.is_board_hidden()
+            && self.viewing_team == Some(active_team)
+            && unit_team == active_team
+    }
+}
@@ -0,0 +1,117 @@
+//! Inventory State
+//!
+//! Handles the inventory screen where the player can:
+//! - Browse the items the selected unit has equipped and carries
+//! - Select one by number key or click to preview its stat changes
+//! - Equip or unequip it, drop it on the ground, or give it to an adjacent
+//!   ally (clicking the ally after choosing to give)
+
+use uuid::Uuid;
+
+/// Inventory screen state handler
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InventoryState {
+    /// Unit whose items are shown, while the screen is open
+    unit_id: Option<Uuid>,
+    /// Index of the selected entry in the unit's carried items
+    selected: Option<usize>,
+    /// Whether the selected item is about to be given and a receiver is awaited
+    giving: bool,
+}
+
+impl InventoryState {
+    /// Creates a new inventory state with the screen closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use questapp::game_scene::states::inventory::InventoryState;
+    ///
+    /// let state = InventoryState::new();
+    /// assert!(!state.is_open());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the screen for a unit's items, with nothing selected
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - UUID of the unit whose items are shown
+    pub fn open(&mut self, unit_id: Uuid) {
+        self.unit_id = Some(unit_id);
+        self.selected = None;
+        self.giving = false;
+    }
+
+    /// Closes the screen, dropping the selection
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Check if the screen is open
+    pub fn is_open(&self) -> bool {
+        self.unit_id.is_some()
+    }
+
+    /// Returns the unit whose items are shown
+    pub fn unit_id(&self) -> Option<Uuid> {
+        self.unit_id
+    }
+
+    /// Selects the entry at `index`, cancelling a pending hand-over
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index into the unit's carried items
+    /// * `entry_count` - Number of items the unit carries
+    ///
+    /// # Returns
+    ///
+    /// `true` if the index was in range and is now selected
+    pub fn select(&mut self, index: usize, entry_count: usize) -> bool {
+        if index >= entry_count {
+            return false;
+        }
+        self.selected = Some(index);
+        self.giving = false;
+        true
+    }
+
+    /// Returns the index of the selected entry
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Drops the selection if the unit now carries fewer items
+    ///
+    /// Call this after the unit's items change, so the selection never points
+    /// past the end of the list.
+    pub fn sync(&mut self, entry_count: usize) {
+        if self.selected.is_some_and(|index| index >= entry_count) {
+            self.selected = None;
+            self.giving = false;
+        }
+    }
+
+    /// Starts waiting for the ally to give the selected item to
+    ///
+    /// # Returns
+    ///
+    /// `false` if no item is selected
+    pub fn start_giving(&mut self) -> bool {
+        self.giving = self.selected.is_some();
+        self.giving
+    }
+
+    /// Stops waiting for a receiver, keeping the selection
+    pub fn stop_giving(&mut self) {
+        self.giving = false;
+    }
+
+    /// Check if the screen is waiting for the ally to give the item to
+    pub fn is_giving(&self) -> bool {
+        self.giving
+    }
+}
@@ -37,6 +37,10 @@
 //! - `PickupState` in [`pickup`]
 //! - `MenuState` in [`menu`]
 //! - `EncyclopediaState` in [`encyclopedia`]
+//! - `AiInspectorState` in [`ai_inspector`]
+//! - `HotSeatState` in [`hot_seat`]
+//! - `InventoryState` in [`inventory`]
+//! - `UseItemState` in [`use_item`]
 //!
 //! # Examples
 //!
@@ -57,12 +61,16 @@
 //! };
 //! ```
 
+pub mod ai_inspector;
 pub mod combat;
 pub mod encyclopedia;
 pub mod exploring;
+pub mod hot_seat;
+pub mod inventory;
 pub mod menu;
 pub mod pickup;
 pub mod submenu;
+pub mod use_item;
 
 use uuid::Uuid;
 
@@ -0,0 +1,83 @@
+//! Use Item State
+//!
+//! Handles the use-item panel where the player can:
+//! - Browse the consumables in the selected unit's inventory
+//! - Pick one by number key or click
+//! - Click the unit to use it on (the unit itself or an adjacent ally)
+
+use uuid::Uuid;
+
+/// Use-item panel state handler
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UseItemState {
+    /// Unit whose inventory is shown, while the panel is open
+    unit_id: Option<Uuid>,
+    /// Inventory slots of the listed consumables, in display order
+    slots: Vec<usize>,
+    /// Inventory slot of the chosen consumable, while waiting for a target
+    chosen_slot: Option<usize>,
+}
+
+impl UseItemState {
+    /// Creates a new use-item state with the panel closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use questapp::game_scene::states::use_item::UseItemState;
+    ///
+    /// let state = UseItemState::new();
+    /// assert!(!state.is_open());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the panel for a unit's consumables
+    ///
+    /// # Arguments
+    ///
+    /// * `unit_id` - UUID of the unit using an item
+    /// * `slots` - Inventory slots of the unit's consumables
+    pub fn open(&mut self, unit_id: Uuid, slots: Vec<usize>) {
+        self.unit_id = Some(unit_id);
+        self.slots = slots;
+        self.chosen_slot = None;
+    }
+
+    /// Closes the panel, dropping any chosen item
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Check if the panel is open
+    pub fn is_open(&self) -> bool {
+        self.unit_id.is_some()
+    }
+
+    /// Returns the unit whose inventory is shown
+    pub fn unit_id(&self) -> Option<Uuid> {
+        self.unit_id
+    }
+
+    /// Returns the inventory slots of the listed consumables
+    pub fn slots(&self) -> &[usize] {
+        &self.slots
+    }
+
+    /// Chooses the consumable listed at `index`
+    ///
+    /// # Returns
+    ///
+    /// The chosen inventory slot, or `None` if the index is out of range
+    pub fn choose(&mut self, index: usize) -> Option<usize> {
+        let slot = *self.slots.get(index)?;
+        self.chosen_slot = Some(slot);
+        Some(slot)
+    }
+
+    /// Returns the chosen inventory slot while waiting for a target
+    pub fn chosen_slot(&self) -> Option<usize> {
+        self.chosen_slot
+    }
+}
@@ -5,3 +5,5 @@
 //! other modules.
 
 pub mod game_scene;
+pub mod settings;
+pub mod statistics;
@@ -31,7 +31,8 @@
 //! - **Interactive unit selection** and movement
 //! - **Combat system** with attack selection and confirmation
 //! - **Item pickup** and inventory management
-//! - **Camera controls** for navigating the game world
+//! - **Camera controls** for navigating the game world (pan, zoom, edge scrolling,
+//!   following AI units during their turn)
 //! - **UI panels** for unit information and prompts
 //! - **In-game menu** for settings and game management
 //!
@@ -41,13 +42,33 @@
 //! - **Left Click**: Move selected unit, confirm actions, interact with UI
 //! - **Right Click**: Select unit, cancel actions
 //! - **Arrow Keys**: Move camera (scroll encyclopedia when open)
+//! - **Mouse Wheel**: Zoom in/out around the cursor (scroll encyclopedia when open)
+//! - **Map Edges**: Rest the cursor at an edge of the map view to scroll
+//! - **F**: Center the camera on the selected unit
+//! - **Minimap**: Click or drag on the overview in the bottom-left corner to move the camera
 //! - **E**: Toggle encyclopedia wiki
-//! - **1/2/3**: Switch encyclopedia categories (Units/Terrain/Mechanics) when open
+//! - **1/2/3/4**: Switch encyclopedia categories (Units/Terrain/Mechanics/Items) when open
 //! - **C**: Show detailed unit info in console
+//! - **S**: Print game statistics in console
 //! - **H**: Toggle hover debug mode (hex highlighting)
+//! - **T**: Toggle AI inspector (last AI turn's decision trace)
+//! - **Tab**: Focus next AI unit in the inspector (highlights its path and target)
+//! - **J**: Export the last AI trace as JSON (while the inspector is open)
+//! - **1-9**: Choose an evolution branch when a unit levels up (also clickable)
 //! - **SPACE**: End turn
 //! - **ESC**: Close encyclopedia/menu, deselect unit
 //!
+//! F, E, C, S, H, T, SPACE and the B/U/Y/N item keys are defaults that can be
+//! rebound on the Settings screen ([`settings::KeyBindings`]).
+//!
+//! ## Networked Games
+//!
+//! - `questapp --host [addr]` hosts the map for a second instance (default `0.0.0.0:7777`)
+//! - `questapp --join <addr>` joins a hosted game
+//!
+//! Both sides play their own teams; every action goes through
+//! [`game::network::NetSession`] so the two worlds stay in lockstep.
+//!
 //! ## Architecture
 //!
 //! The application uses the winit event loop with glutin for OpenGL context management.
@@ -57,6 +78,9 @@ mod encyclopedia_builder;
 mod game_scene;
 mod main_menu;
 mod scene_manager;
+mod settings;
+mod settings_scene;
+mod statistics;
 
 // Import the new game scene state management
 use game_scene::{GameSceneState, GameState};
@@ -71,40 +95,71 @@ use glutin::prelude::*;
 use glutin::surface::{Surface, SurfaceAttributesBuilder, WindowSurface};
 use glutin_winit::DisplayBuilder;
 use graphics::core::hexagon::SpriteType;
+use graphics::draw::text::text_width;
 use graphics::math::Vec2;
 use graphics::{
-    find_path, setup_dynamic_hexagons, AttackDisplayInfo, EncyclopediaCategory, EncyclopediaPanel,
-    HexCoord, HexGrid, HighlightType, Renderer, UiPanel, UnitAnimation, UnitDisplayInfo,
+    find_path, setup_dynamic_hexagons, AiInspectorPanel, AttackDisplayInfo, CombatLogEntryType,
+    DrawBackend, DrawList, EncyclopediaCategory, EncyclopediaPanel, EvolutionPanel, HexCoord,
+    HexGrid, HighlightType, InventoryPanel, ItemUsePanel, Renderer, TimeOfDayDisplayInfo, UiPanel,
+    UnitAnimation, UnitDisplayInfo,
 };
 
-use main_menu::MainMenuScene;
+use game::network::{GameSetup, NetEvent, NetSession, DEFAULT_PORT};
+use main_menu::{GameMode, MainMenuScene, MENU_BACKGROUND};
 use raw_window_handle::HasWindowHandle;
 use scene_manager::{Scene, SceneManager, SceneType};
+use settings::{Action, Settings};
+use settings_scene::SettingsScene;
+use statistics::GameStatistics;
+use std::collections::HashSet;
 use std::ffi::CString;
-use units::Terrain;
+use std::num::NonZeroU32;
 use winit::application::ApplicationHandler;
-use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::window::{Window, WindowId};
 
-/// Temporary helper to convert SpriteType to Terrain until proper terrain storage is implemented
-fn sprite_to_terrain(sprite: SpriteType) -> Terrain {
-    match sprite {
-        SpriteType::Forest | SpriteType::Forest2 => Terrain::Forest0,
-        SpriteType::Grasslands => Terrain::Grasslands,
-        SpriteType::HauntedWoods => Terrain::HauntedWoods,
-        SpriteType::Hills => Terrain::Hills,
-        SpriteType::Mountain => Terrain::Mountain,
-        SpriteType::Swamp => Terrain::Swamp,
-        _ => Terrain::Grasslands, // Default fallback
-    }
+/// Sprite a unit names; units without a known sprite get the generic one.
+fn unit_sprite(unit: &dyn units::Unit) -> SpriteType {
+    SpriteType::from_name(unit.sprite()).unwrap_or(SpriteType::Unit)
 }
 
-/// Screen width in pixels.
-const SCREEN_WIDTH: f32 = 1920.0;
+/// Maps the number keys 1-9 to a zero-based option index.
+fn digit_key_index(code: winit::keyboard::KeyCode) -> Option<usize> {
+    use winit::keyboard::KeyCode;
+    let digits = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+    digits.iter().position(|digit| *digit == code)
+}
 
-/// Screen height in pixels.
-const SCREEN_HEIGHT: f32 = 1080.0;
+/// Units offered by the recruitment panel, in display order.
+const RECRUITABLE_UNITS: [(&str, units::UnitType); 7] = [
+    ("Human Noble", units::UnitType::HumanNoble),
+    ("Human Squire", units::UnitType::HumanSquire),
+    ("Human Knight", units::UnitType::HumanKnight),
+    ("Human Prince", units::UnitType::HumanPrince),
+    ("Human Grand Knight", units::UnitType::HumanGrandKnight),
+    ("Human King", units::UnitType::HumanKing),
+    (
+        "Human Knight Commander",
+        units::UnitType::HumanKnightCommander,
+    ),
+];
+
+/// Zoom factor applied per mouse wheel notch.
+const ZOOM_STEP: f32 = 1.1;
+
+/// Touchpad scroll distance in pixels that counts as one wheel notch.
+const PIXELS_PER_SCROLL_LINE: f32 = 50.0;
 
 /// Main game application structure.
 ///
@@ -124,6 +179,7 @@ const SCREEN_HEIGHT: f32 = 1080.0;
 /// - `window`: The OS window for rendering
 /// - `gl_context`: OpenGL rendering context
 /// - `gl_surface`: OpenGL surface for the window
+/// - `window_size`: Current window size in pixels, updated on resize
 /// - `hex_grid`: The hexagonal grid system
 /// - `renderer`: Multi-layer renderer for terrain, units, and items
 /// - `game_world`: ScenarioWorld instance (game state, delegated logic)
@@ -133,9 +189,10 @@ const SCREEN_HEIGHT: f32 = 1080.0;
 /// - `show_unit_info`: Whether to display detailed unit info
 /// - `unit_info_text`: Cached unit information text
 /// - `cursor_position`: Current mouse cursor position (actively tracked)
+/// - `cursor_in_window`: Whether the cursor is over the window (for edge scrolling)
 /// - `movement_range`: Valid movement hexes for selected unit
 /// - `hower_debug_hex`: Hex currently under cursor (debug mode)
-/// - `hower_debug_enabled`: Whether hover debug mode is active (toggle with 'H')
+/// - `settings`: Player configuration (hover debug, key bindings, speeds, ...)
 /// - `pickup_prompt`: Active item pickup prompt (if any)
 ///
 /// # Example Usage
@@ -160,6 +217,7 @@ struct GameApp {
     window: Option<Window>,
     gl_context: Option<glutin::context::PossiblyCurrentContext>,
     gl_surface: Option<Surface<WindowSurface>>,
+    window_size: (f32, f32), // Current drawable size; panels are laid out for it
     hex_grid: HexGrid,
     renderer: Option<Renderer>,
     ui_panel: Option<UiPanel>,
@@ -167,12 +225,14 @@ struct GameApp {
     show_unit_info: bool,
     unit_info_text: Vec<String>,
     cursor_position: (f64, f64),       // Track cursor position for clicks
+    cursor_in_window: bool,            // Edge scrolling only while the cursor is inside
     hower_debug_hex: Option<HexCoord>, // Debug: hex under cursor
-    hower_debug_enabled: bool,         // Toggle for hower debug mode
+    settings: Settings,                // Loaded at startup, saved on every change
 
     // Scene management
     scene_manager: SceneManager,
     main_menu_scene: MainMenuScene,
+    settings_scene: SettingsScene,
     game_initialized: bool, // Track if game scene has been initialized
     exit_requested: bool,   // Flag to request application exit
 
@@ -187,8 +247,64 @@ struct GameApp {
     encyclopedia: Encyclopedia,                    // Encyclopedia data
     encyclopedia_panel: Option<EncyclopediaPanel>, // Encyclopedia UI panel
 
+    // AI inspector panel (decision trace of the last AI turn)
+    ai_inspector_panel: Option<AiInspectorPanel>,
+
+    // Evolution choice dialog (shown while a level-up waits for the player)
+    evolution_panel: Option<EvolutionPanel>,
+
+    // Use-item dialog over the selected unit's consumables
+    item_use_panel: Option<ItemUsePanel>,
+
+    // Inventory screen over the selected unit's items
+    inventory_panel: Option<InventoryPanel>,
+
     // Game state management (replaces scattered state variables)
     game_state: GameSceneState,
+
+    // Hexes the hot-seat viewing team can see (None when there is no fog)
+    fog_visible: Option<HashSet<HexCoord>>,
+
+    // Connection to the other player of a networked game
+    network: Option<NetSession>,
+
+    // Per-team statistics recorded from the world's game events
+    statistics: GameStatistics,
+}
+
+/// How the command line asked to start a networked game.
+#[derive(Debug, Clone, PartialEq)]
+enum NetLaunch {
+    /// `--host [addr]`: host the map and wait for a guest
+    Host(String),
+    /// `--join <addr>`: join a hosted game
+    Join(String),
+}
+
+impl NetLaunch {
+    /// Parses `--host [addr]` or `--join <addr>` from the program arguments.
+    ///
+    /// Addresses without a port get [`DEFAULT_PORT`].
+    fn from_args(mut args: impl Iterator<Item = String>) -> Option<Self> {
+        let with_port = |addr: String| {
+            if addr.contains(':') {
+                addr
+            } else {
+                format!("{}:{}", addr, DEFAULT_PORT)
+            }
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--host" => {
+                    let addr = args.next().unwrap_or_else(|| "0.0.0.0".to_string());
+                    return Some(Self::Host(with_port(addr)));
+                }
+                "--join" => return args.next().map(|addr| Self::Join(with_port(addr))),
+                _ => {}
+            }
+        }
+        None
+    }
 }
 
 /// Item pickup prompt state.
@@ -219,7 +335,17 @@ impl GameApp {
             .expect("Failed to read Maps/test_map.json");
 
         // Initialize game world (ScenarioWorld handles game logic)
-        let game_world = ScenarioWorld::new(map_json);
+        let mut game_world = ScenarioWorld::new(map_json);
+        // Keep AI turn traces so the AI inspector (T) can show them
+        game_world.ai_trace_enabled = true;
+
+        let settings = Settings::path()
+            .map(|path| Settings::load_or_default(&path))
+            .unwrap_or_default();
+        game_world.set_ai_turn_delay(settings.ai_turn_delay);
+        game_world.set_tactical_ai(settings.tactical_ai);
+        let (screen_width, screen_height) =
+            (settings.resolution.0 as f32, settings.resolution.1 as f32);
 
         // Architecture note: ScenarioWorld is the single source of truth for:
         // - Unit positions, stats, and inventory
@@ -241,6 +367,7 @@ impl GameApp {
             window: None,
             gl_context: None,
             gl_surface: None,
+            window_size: (screen_width, screen_height),
             hex_grid,
             renderer: None,
             ui_panel: None,
@@ -248,12 +375,14 @@ impl GameApp {
             show_unit_info: false,
             unit_info_text: Vec::new(),
             cursor_position: (0.0, 0.0),
+            cursor_in_window: false,
             hower_debug_hex: None,
-            hower_debug_enabled: true, // Start with debug enabled
 
             // Scene management - start at main menu
             scene_manager: SceneManager::new(),
-            main_menu_scene: MainMenuScene::new(SCREEN_WIDTH, SCREEN_HEIGHT),
+            main_menu_scene: MainMenuScene::new(screen_width, screen_height),
+            settings_scene: SettingsScene::new(settings.clone(), screen_width, screen_height),
+            settings,
             game_initialized: false,
             exit_requested: false,
 
@@ -268,15 +397,89 @@ impl GameApp {
             encyclopedia: Encyclopedia::new(),
             encyclopedia_panel: None,
 
+            // AI inspector
+            ai_inspector_panel: None,
+
+            // Evolution choice dialog
+            evolution_panel: None,
+
+            // Use-item dialog
+            item_use_panel: None,
+
+            // Inventory screen
+            inventory_panel: None,
+
             // Game state management
             game_state: GameSceneState::new(),
+
+            // Fog of war (hot-seat and networked games)
+            fog_visible: None,
+
+            // Single-player until start_network() connects
+            network: None,
+
+            // Game statistics
+            statistics: GameStatistics::new(),
         }
     }
 
+    /// Hosts or joins a networked game, replacing the local world.
+    ///
+    /// Joining blocks until the host answers; hosting returns at once and
+    /// accepts the guest while the game runs.
+    fn start_network(&mut self, launch: NetLaunch) -> std::io::Result<()> {
+        let (session, world) = match launch {
+            NetLaunch::Host(addr) => {
+                let map_json = std::fs::read_to_string("Maps/test_map.json")?;
+                let seed = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_or(0, |d| d.as_nanos() as u64);
+                let (session, world) = NetSession::host(&addr, GameSetup::new(map_json, seed))?;
+                println!("🌐 Hosting on {} - waiting for a player to join", addr);
+                (session, world)
+            }
+            NetLaunch::Join(addr) => {
+                println!("🌐 Joining {}...", addr);
+                let session = NetSession::join(&addr, std::time::Duration::from_secs(10))?;
+                println!("🌐 Joined {}", addr);
+                session
+            }
+        };
+        let names: Vec<&str> = session
+            .local_teams()
+            .iter()
+            .map(|team| world.teams.name(*team))
+            .collect();
+        println!("🌐 You play: {}", names.join(", "));
+        self.game_world = world;
+        self.game_world
+            .set_ai_turn_delay(self.settings.ai_turn_delay);
+        self.network = Some(session);
+        Ok(())
+    }
+
     /// Initialize the game scene - called when transitioning from menu to game
     fn initialize_game_scene(&mut self) {
         println!("🎮 Initializing Game Scene...");
 
+        // Networked worlds come started from their setup; the menu's mode is ignored
+        if self.network.is_some() {
+            self.update_hex_grid_units();
+            println!("✅ Networked game scene initialized!");
+            return;
+        }
+
+        // Hot-seat hands every fighting team to a human at this device
+        if self.main_menu_scene.game_mode() == GameMode::HotSeat {
+            let humans = self.game_world.enable_hot_seat();
+            let names: Vec<&str> = humans
+                .iter()
+                .map(|team| self.game_world.teams.name(*team))
+                .collect();
+            println!("🪑 Hot-seat mode: {}", names.join(", "));
+            self.game_state.hot_seat.enable(humans);
+        }
+
         // Hex grid is already initialized with terrain in new(), just update units
         self.update_hex_grid_units();
 
@@ -337,6 +540,157 @@ impl GameApp {
         prompt
     }
 
+    // ===== Hot-Seat Helpers =====
+
+    /// Raises the "pass the device" screen when a new human team's turn starts
+    fn sync_hot_seat(&mut self) {
+        let team = self.game_world.current_turn_team();
+        if self
+            .game_state
+            .hot_seat
+            .begin_turn(team, self.hex_grid.camera.target())
+        {
+            self.clear_selection();
+            println!(
+                "🪑 Pass the device to {}. {}",
+                self.game_world.teams.name(team),
+                self.hot_seat_prompt()
+            );
+        }
+    }
+
+    /// Tells the next player how to reveal the board: the End Turn key or a
+    /// click.
+    fn hot_seat_prompt(&self) -> String {
+        match self.settings.key_bindings.key(Action::EndTurn) {
+            Some(key) => format!("Press {} or click when ready", settings::key_label(key)),
+            None => "Click when ready".to_string(),
+        }
+    }
+
+    /// Reveals the board to the team the device was passed to
+    ///
+    /// Restores the camera the team left its last turn with, or centers it on
+    /// one of the team's units on its first turn.
+    fn confirm_hot_seat_handoff(&mut self) {
+        let Some(team) = self.game_state.hot_seat.handoff_team() else {
+            return;
+        };
+        let camera = self.game_state.hot_seat.confirm_handoff().or_else(|| {
+            self.game_world
+                .units
+                .values()
+                .filter(|unit| unit.team() == team)
+                .map(|unit| unit.position())
+                .min()
+                .and_then(|pos| self.hex_grid.hexagons.get(&pos))
+                .map(|hex| hex.world_pos)
+        });
+        if let Some(position) = camera {
+            self.hex_grid.camera.jump_to(position);
+        }
+        self.update_hex_grid_units();
+        println!("🪑 {} is at the device", self.game_world.teams.name(team));
+    }
+
+    /// Whether the hot-seat viewing team can see a hex (always true without fog)
+    fn is_hex_revealed(&self, hex: HexCoord) -> bool {
+        self.fog_visible
+            .as_ref()
+            .is_none_or(|visible| visible.contains(&hex))
+    }
+
+    /// Whether the player at the device may select and command a unit
+    ///
+    /// In networked games only this side's units may be commanded, on their
+    /// team's turn.
+    fn can_command_unit(&self, unit_id: uuid::Uuid) -> bool {
+        let active_team = self.game_world.current_turn_team();
+        self.get_unit(unit_id)
+            .is_some_and(|unit| match &self.network {
+                Some(session) => unit.team() == active_team && session.is_local_team(active_team),
+                None => self
+                    .game_state
+                    .hot_seat
+                    .can_command(active_team, unit.team()),
+            })
+    }
+
+    // ===== Network Helpers =====
+
+    /// Whether the player at this device may act for the team whose turn it is
+    fn is_local_turn(&self) -> bool {
+        match &self.network {
+            Some(session) => session.is_local_team(self.game_world.current_turn_team()),
+            None => self.game_world.is_current_team_player_controlled(),
+        }
+    }
+
+    /// Sends a command through the network session, applying it locally
+    fn submit_command(&mut self, team: Team, command: PlayerCommand) -> Result<(), String> {
+        let Some(session) = &mut self.network else {
+            return self.game_world.apply_command(team, &command);
+        };
+        let result = session.submit(&mut self.game_world, team, command);
+        if let Err(e) = &result {
+            println!("🌐 Command not sent: {}", e);
+        }
+        result
+    }
+
+    /// Handles network traffic: the guest joining, remote commands and resyncs
+    fn poll_network(&mut self) {
+        let Some(session) = &mut self.network else {
+            return;
+        };
+        let events = session.poll(&mut self.game_world);
+        if events.is_empty() {
+            return;
+        }
+        for event in &events {
+            match event {
+                NetEvent::PeerConnected => println!("🌐 The other player connected"),
+                NetEvent::PeerDisconnected => println!("🌐 The other player disconnected"),
+                NetEvent::CommandApplied { team, command } => {
+                    println!("🌐 {}: {:?}", self.game_world.teams.name(*team), command)
+                }
+                NetEvent::CommandRejected {
+                    command, reason, ..
+                } => println!("⚠️  Rejected remote {:?}: {}", command, reason),
+                NetEvent::Desync { seq } => {
+                    println!("⚠️  Desync detected after command {}; resyncing", seq)
+                }
+                NetEvent::Resynced => println!("🌐 Game resynced from host"),
+                NetEvent::ResyncFailed { reason } => {
+                    println!("⚠️  Could not rebuild the host's game: {}", reason)
+                }
+            }
+        }
+        if events.contains(&NetEvent::Resynced) {
+            self.active_animation = None;
+            self.clear_selection();
+        }
+        self.update_hex_grid_units();
+    }
+
+    /// Ends the current turn if it belongs to the player at this device
+    fn end_local_turn(&mut self) -> bool {
+        if !self.is_local_turn() {
+            println!("⚠️  Cannot end turn - not your turn!");
+            return false;
+        }
+        if self.network.is_some() {
+            let team = self.game_world.current_turn_team();
+            if self.submit_command(team, PlayerCommand::EndTurn).is_err() {
+                return false;
+            }
+        } else {
+            self.game_world.end_current_turn();
+        }
+        self.clear_selection(); // Clear any unit selection when turn ends
+        true
+    }
+
     /// Start animating unit movement along a path
     fn start_movement_animation(&mut self, unit_id: uuid::Uuid, path: Vec<HexCoord>) {
         if path.len() < 2 {
@@ -344,9 +698,8 @@ impl GameApp {
         }
 
         // Movement speed in hexes per second
-        const MOVEMENT_SPEED: f32 = 4.0; // Adjust this value to change animation speed
-
-        self.active_animation = Some(UnitAnimation::new(unit_id, path, MOVEMENT_SPEED));
+        let speed = self.settings.animation_speed;
+        self.active_animation = Some(UnitAnimation::new(unit_id, path, speed));
     }
 
     /// Update animation state and move unit along path
@@ -379,8 +732,614 @@ impl GameApp {
         }
     }
 
+    /// Updates the hover state of the panels and dialogs on screen
+    fn update_panel_hover(&mut self, x: f32, y: f32) {
+        if let Some(panel) = &mut self.ui_panel {
+            panel.cursor_moved(x, y);
+        }
+        if let Some(panel) = &mut self.evolution_panel {
+            panel.cursor_moved(x, y);
+        }
+        if let Some(panel) = &mut self.item_use_panel {
+            panel.cursor_moved(x, y);
+        }
+        if let Some(panel) = &mut self.inventory_panel {
+            panel.cursor_moved(x, y);
+        }
+        if let Some(renderer) = &mut self.renderer {
+            if let Some(panel) = &mut renderer.submenu_panel {
+                panel.cursor_moved(x, y);
+            }
+            if let Some(panel) = &mut renderer.recruitment_panel {
+                panel.cursor_moved(x, y);
+            }
+        }
+    }
+
+    /// Applies and saves the changes made on the settings screen, if any
+    fn apply_settings_changes(&mut self) {
+        let Some(settings) = self.settings_scene.take_changes() else {
+            return;
+        };
+        let previous = std::mem::replace(&mut self.settings, settings);
+
+        let mut applied_size = None;
+        if let Some(window) = &self.window {
+            if self.settings.resolution != previous.resolution {
+                let (width, height) = self.settings.resolution;
+                // Otherwise the panels follow in the Resized event
+                applied_size =
+                    window.request_inner_size(winit::dpi::LogicalSize::new(width, height));
+            }
+            if self.settings.fullscreen != previous.fullscreen {
+                window.set_fullscreen(
+                    self.settings
+                        .fullscreen
+                        .then_some(winit::window::Fullscreen::Borderless(None)),
+                );
+            }
+        }
+        if let Some(size) = applied_size {
+            self.resize(size.width, size.height);
+        }
+        if self.settings.ui_scale != previous.ui_scale {
+            self.apply_ui_scale();
+        }
+        if !self.settings.hover_debug {
+            self.clear_hower_debug_hex();
+        }
+        self.game_world
+            .set_ai_turn_delay(self.settings.ai_turn_delay);
+        self.game_world.set_tactical_ai(self.settings.tactical_ai);
+        self.save_settings();
+    }
+
+    /// Draws every panel, menu and scene at the configured UI scale
+    fn apply_ui_scale(&mut self) {
+        let scale = self.settings.ui_scale;
+        self.main_menu_scene.set_ui_scale(scale);
+        self.settings_scene.set_ui_scale(scale);
+        if let Some(renderer) = &mut self.renderer {
+            renderer.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.ui_panel {
+            panel.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.encyclopedia_panel {
+            panel.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.ai_inspector_panel {
+            panel.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.evolution_panel {
+            panel.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.item_use_panel {
+            panel.set_ui_scale(scale);
+        }
+        if let Some(panel) = &mut self.inventory_panel {
+            panel.set_ui_scale(scale);
+        }
+    }
+
+    /// Writes the settings to the user's config directory
+    fn save_settings(&self) {
+        let Some(path) = Settings::path() else {
+            println!("⚠️  No config directory - settings are not saved");
+            return;
+        };
+        if let Err(e) = self.settings.save(&path) {
+            println!("⚠️  {}", e);
+        }
+    }
+
+    /// Resizes the drawing surface and lays every panel out for the new
+    /// window size. Zero-sized (minimized) windows are ignored.
+    fn resize(&mut self, width: u32, height: u32) {
+        let (Some(non_zero_width), Some(non_zero_height)) =
+            (NonZeroU32::new(width), NonZeroU32::new(height))
+        else {
+            return;
+        };
+        if let (Some(gl_context), Some(gl_surface)) = (&self.gl_context, &self.gl_surface) {
+            gl_surface.resize(gl_context, non_zero_width, non_zero_height);
+            unsafe {
+                gl::Viewport(0, 0, width as i32, height as i32);
+            }
+        }
+
+        let (screen_width, screen_height) = (width as f32, height as f32);
+        self.window_size = (screen_width, screen_height);
+        self.main_menu_scene
+            .update_screen_size(screen_width, screen_height);
+        self.settings_scene
+            .update_screen_size(screen_width, screen_height);
+        if let Some(renderer) = &mut self.renderer {
+            renderer.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.ui_panel {
+            panel.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.encyclopedia_panel {
+            panel.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.ai_inspector_panel {
+            panel.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.evolution_panel {
+            panel.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.item_use_panel {
+            panel.resize(screen_width, screen_height);
+        }
+        if let Some(panel) = &mut self.inventory_panel {
+            panel.resize(screen_width, screen_height);
+        }
+    }
+
+    /// Scrolls the map while the cursor rests at an edge of the map view and
+    /// advances smooth camera panning
+    fn update_camera(&mut self, delta_time: f32) {
+        let cursor = Vec2::new(self.cursor_position.0 as f32, self.cursor_position.1 as f32);
+        // Dialogs and the minimap keep the cursor busy
+        let cursor_busy = self.encyclopedia_visible()
+            || self.has_pending_combat()
+            || self.renderer.as_ref().is_some_and(|renderer| {
+                renderer.menu_display.active
+                    || renderer.minimap.is_dragging()
+                    || renderer.minimap.contains(cursor.x, cursor.y)
+            });
+        if self.cursor_in_window && !cursor_busy {
+            // The map view ends where the UI panel starts
+            let (screen_width, screen_height) = self.window_size;
+            let view_width = self.ui_panel.as_ref().map_or(screen_width, |panel| panel.x);
+            self.hex_grid.camera.edge_scroll(
+                cursor,
+                Vec2::new(view_width, screen_height),
+                delta_time,
+            );
+        }
+        self.hex_grid.camera.update(delta_time);
+    }
+
+    /// Zooms the map around the cursor (scrolls the encyclopedia when open, and
+    /// the UI panel or AI inspector under the cursor)
+    fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let notches = match delta {
+            MouseScrollDelta::LineDelta(_, y) => y,
+            MouseScrollDelta::PixelDelta(position) => position.y as f32 / PIXELS_PER_SCROLL_LINE,
+        };
+        if notches == 0.0 {
+            return;
+        }
+
+        if self.encyclopedia_visible() {
+            if let Some(panel) = &mut self.encyclopedia_panel {
+                if notches > 0.0 {
+                    panel.scroll_up(3);
+                } else {
+                    panel.scroll_down(3);
+                }
+            }
+            return;
+        }
+
+        let cursor = Vec2::new(self.cursor_position.0 as f32, self.cursor_position.1 as f32);
+
+        // Over the AI inspector the wheel scrolls its trace
+        if self.game_state.ai_inspector.is_visible() {
+            if let Some(panel) = &mut self.ai_inspector_panel {
+                if panel.contains_point(cursor.x, cursor.y) {
+                    panel.scroll(-notches * 3.0);
+                    return;
+                }
+            }
+        }
+
+        // Over the UI panel the wheel scrolls the unit details
+        if let Some(panel) = &mut self.ui_panel {
+            if cursor.x >= panel.x {
+                panel.scroll_at(cursor.x, cursor.y, -notches);
+                return;
+            }
+        }
+
+        let (screen_width, screen_height) = self.window_size;
+        self.hex_grid.camera.zoom_at(
+            ZOOM_STEP.powf(notches),
+            cursor,
+            Vec2::new(screen_width, screen_height),
+        );
+    }
+
+    /// Reacts to the events the world emitted since the last frame: combat log
+    /// entries, floating damage numbers, movement animations and statistics.
+    fn process_game_events(&mut self, delta_time: f32) {
+        // Follow the acting unit while the AI plays its turn
+        let ai_playback = !self.game_world.is_current_team_player_controlled();
+        for event in self.game_world.drain_events() {
+            if let GameEvent::UnitMoved { unit_id, .. } | GameEvent::UnitHealed { unit_id, .. } =
+                &event
+            {
+                if let Some(unit) = self.game_world.units.get(unit_id) {
+                    self.statistics.track_unit(*unit_id, unit.team());
+                }
+            }
+            self.statistics.record(&event);
+
+            if let Some((message, entry_type)) = self.describe_event(&event) {
+                println!("{}", message);
+                if let Some(renderer) = &mut self.renderer {
+                    renderer.combat_log_display.add_entry(message, entry_type);
+                }
+            }
+
+            match event {
+                GameEvent::UnitMoved { unit_id, from, to } => {
+                    if ai_playback {
+                        self.hex_grid.focus_camera_on(to);
+                    }
+                    self.animate_world_move(unit_id, from, to);
+                }
+                GameEvent::StrikeResolved {
+                    target_position,
+                    counter,
+                    hit,
+                    damage,
+                    ..
+                } => {
+                    if ai_playback && !counter {
+                        self.hex_grid.focus_camera_on(target_position);
+                    }
+                    if let Some(renderer) = &mut self.renderer {
+                        if hit {
+                            renderer.effects_display.spawn_floating_text(
+                                target_position,
+                                format!("-{}", damage),
+                                [1.0, 0.3, 0.3],
+                            );
+                        } else {
+                            renderer.effects_display.spawn_floating_text(
+                                target_position,
+                                "miss",
+                                [0.8, 0.8, 0.8],
+                            );
+                        }
+                    }
+                }
+                GameEvent::UnitHealed {
+                    position, amount, ..
+                } => {
+                    if let Some(renderer) = &mut self.renderer {
+                        renderer.effects_display.spawn_floating_text(
+                            position,
+                            format!("+{}", amount),
+                            [0.3, 1.0, 0.3],
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(renderer) = &mut self.renderer {
+            renderer.effects_display.update(delta_time);
+        }
+    }
+
+    /// Combat log line for a game event, if it deserves one.
+    fn describe_event(&self, event: &GameEvent) -> Option<(String, CombatLogEntryType)> {
+        let unit_name = |unit_id: &uuid::Uuid| {
+            self.game_world
+                .units
+                .get(unit_id)
+                .map_or_else(|| "A unit".to_string(), |unit| unit.name().to_string())
+        };
+        let entry = match event {
+            GameEvent::UnitMoved { .. } | GameEvent::StructureDamaged { .. } => return None,
+            GameEvent::StrikeResolved {
+                striker_id,
+                target_id,
+                attack_name,
+                counter,
+                hit,
+                damage,
+                ..
+            } => {
+                let verb = if *counter { "counters" } else { "strikes" };
+                if *hit {
+                    (
+                        format!(
+                            "{} {} {} with {} for {} damage",
+                            unit_name(striker_id),
+                            verb,
+                            unit_name(target_id),
+                            attack_name,
+                            damage
+                        ),
+                        CombatLogEntryType::Damage,
+                    )
+                } else {
+                    (
+                        format!(
+                            "{} {} {} with {} and misses",
+                            unit_name(striker_id),
+                            verb,
+                            unit_name(target_id),
+                            attack_name
+                        ),
+                        CombatLogEntryType::Miss,
+                    )
+                }
+            }
+            GameEvent::UnitDied { name, .. } => {
+                (format!("{} was defeated", name), CombatLogEntryType::Death)
+            }
+            GameEvent::UnitHealed {
+                unit_id, amount, ..
+            } => (
+                format!("{} recovers {} HP", unit_name(unit_id), amount),
+                CombatLogEntryType::Heal,
+            ),
+            GameEvent::LeveledUp { name, level, .. } => (
+                format!("{} reached level {}", name, level),
+                CombatLogEntryType::Info,
+            ),
+            GameEvent::ItemPickedUp {
+                unit_id, item_name, ..
+            } => (
+                format!("{} picked up {}", unit_name(unit_id), item_name),
+                CombatLogEntryType::Info,
+            ),
+            GameEvent::ItemUsed {
+                unit_id,
+                item_name,
+                target_id,
+                ..
+            } => {
+                let message = if unit_id == target_id {
+                    format!("{} used {}", unit_name(unit_id), item_name)
+                } else {
+                    format!(
+                        "{} used {} on {}",
+                        unit_name(unit_id),
+                        item_name,
+                        unit_name(target_id)
+                    )
+                };
+                (message, CombatLogEntryType::Info)
+            }
+            GameEvent::ItemEquipped {
+                unit_id,
+                item_name,
+                equipped,
+                ..
+            } => {
+                let verb = if *equipped { "equipped" } else { "unequipped" };
+                (
+                    format!("{} {} {}", unit_name(unit_id), verb, item_name),
+                    CombatLogEntryType::Info,
+                )
+            }
+            GameEvent::ItemDropped {
+                unit_id, item_name, ..
+            } => (
+                format!("{} dropped {}", unit_name(unit_id), item_name),
+                CombatLogEntryType::Info,
+            ),
+            GameEvent::ItemGiven {
+                giver_id,
+                receiver_id,
+                item_name,
+                ..
+            } => (
+                format!(
+                    "{} gave {} to {}",
+                    unit_name(giver_id),
+                    item_name,
+                    unit_name(receiver_id)
+                ),
+                CombatLogEntryType::Info,
+            ),
+            GameEvent::UnitRecruited { name, team, .. } => (
+                format!("{} recruited {}", self.game_world.teams.name(*team), name),
+                CombatLogEntryType::Info,
+            ),
+            GameEvent::TurnStarted { team, turn } => (
+                format!("Turn {}: {}", turn, self.game_world.teams.name(*team)),
+                CombatLogEntryType::Info,
+            ),
+        };
+        Some(entry)
+    }
+
+    /// Animates a move the world made on its own (AI, network, combat), so
+    /// units glide instead of jumping. Moves already animated locally, or made
+    /// while another animation runs, are left as they are.
+    fn animate_world_move(&mut self, unit_id: uuid::Uuid, from: HexCoord, to: HexCoord) {
+        if self.active_animation.is_some() {
+            return;
+        }
+        let Some(unit) = self.game_world.units.get_mut(&unit_id) else {
+            return;
+        };
+        if unit.position() != to {
+            return;
+        }
+        if let Some(path) = find_path(from, to) {
+            unit.set_position(from);
+            self.start_movement_animation(unit_id, path);
+        }
+    }
+
+    /// Prints the game statistics to the console.
+    fn print_statistics(&self) {
+        println!("\n📊 Game statistics after {} turns", self.statistics.turns);
+        for line in self.statistics.summary_lines(&self.game_world.teams) {
+            println!("   {}", line);
+        }
+    }
+
+    /// Clears the hover debug highlighting
+    fn clear_hower_debug_hex(&mut self) {
+        if let Some(prev_hex) = self.hower_debug_hex.take() {
+            if let Some(hex) = self.hex_grid.hexagons.get_mut(&prev_hex) {
+                hex.color = [
+                    0.3 + 0.4 * ((prev_hex.q + prev_hex.r) % 3) as f32 / 3.0,
+                    0.4 + 0.3 * (prev_hex.q % 4) as f32 / 4.0,
+                    0.5 + 0.3 * (prev_hex.r % 5) as f32 / 5.0,
+                ];
+            }
+        }
+    }
+
+    /// Performs the in-game action bound to a key
+    fn perform_action(&mut self, action: Action) {
+        match action {
+            Action::EndTurn => {
+                // End current turn (only works if it's this player's turn)
+                self.end_local_turn();
+            }
+            Action::FocusUnit => {
+                // Center the camera on the selected unit
+                if let Some(unit) = self.selected_unit().and_then(|id| self.get_unit(id)) {
+                    let position = unit.position();
+                    self.hex_grid.focus_camera_on(position);
+                }
+            }
+            Action::Encyclopedia => {
+                // Toggle encyclopedia
+                let new_visible = !self.encyclopedia_visible();
+                self.set_encyclopedia_visible(new_visible);
+                if new_visible {
+                    let key = self.settings.key_bindings.key(Action::Encyclopedia);
+                    println!(
+                        "📚 Encyclopedia: Opened (Press {} or ESC to close)",
+                        key.map_or_else(|| "ESC".to_string(), settings::key_label)
+                    );
+                    // Update content based on current category
+                    self.update_encyclopedia_content();
+                } else {
+                    println!("📚 Encyclopedia: Closed");
+                }
+            }
+            Action::Inventory => {
+                // Open the inventory screen for the selected unit
+                self.open_inventory_panel();
+            }
+            Action::UseItem => {
+                // Toggle the use-item panel for the selected unit
+                if self.game_state.use_item.is_open() {
+                    self.game_state.use_item.close();
+                    println!("🎒 Use Item: Closed");
+                } else {
+                    self.open_use_item_panel();
+                }
+            }
+            Action::UnitInfo => {
+                // Show detailed unit info in console
+                if let Some(unit_id) = self.selected_unit() {
+                    self.call_unit_on_click(unit_id);
+                } else {
+                    println!("No unit selected. Click on a unit first!");
+                }
+            }
+            Action::Statistics => {
+                self.print_statistics();
+            }
+            Action::AiInspector => {
+                // Toggle AI inspector
+                if self.game_state.ai_inspector.toggle() {
+                    println!("🧠 AI Inspector: Opened (Tab: next unit, J: export JSON)");
+                    self.update_ai_inspector_highlights();
+                } else {
+                    println!("🧠 AI Inspector: Closed");
+                    self.update_highlight_display();
+                }
+            }
+            Action::HoverDebug => {
+                // Toggle hower debug mode
+                self.settings.hover_debug = !self.settings.hover_debug;
+                self.save_settings();
+                if self.settings.hover_debug {
+                    println!("🔍 hower DEBUG: Enabled - cursor will highlight hexes");
+                } else {
+                    println!("🔍 hower DEBUG: Disabled");
+                    self.clear_hower_debug_hex();
+                }
+            }
+            Action::AcceptPickup => {
+                // Accept item pickup
+                if let Some(prompt) = self.take_pickup_prompt() {
+                    self.handle_item_pickup(prompt.unit_id, prompt.item_id);
+                    // Clear UI prompt
+                    if let Some(ui_panel) = &mut self.ui_panel {
+                        ui_panel.clear_pickup_prompt();
+                    }
+                }
+            }
+            Action::DeclinePickup => {
+                // Decline item pickup
+                if let Some(prompt) = self.take_pickup_prompt() {
+                    println!("❌ Declined to pick up '{}'", prompt.item_name);
+                    // Clear UI prompt
+                    if let Some(ui_panel) = &mut self.ui_panel {
+                        ui_panel.clear_pickup_prompt();
+                    }
+                }
+            }
+        }
+    }
+
     /// Handle keyboard input for the game scene
     fn handle_game_keyboard_input(&mut self, physical_key: winit::keyboard::PhysicalKey) {
+        // The hot-seat handoff screen only listens for the next player's confirmation
+        if self.game_state.hot_seat.is_board_hidden() {
+            if let winit::keyboard::PhysicalKey::Code(code) = physical_key {
+                if self.settings.key_bindings.key(Action::EndTurn) == Some(code) {
+                    self.confirm_hot_seat_handoff();
+                }
+            }
+            return;
+        }
+
+        // A pending evolution choice takes all number keys
+        if self.game_world.pending_evolution().is_some() {
+            if let winit::keyboard::PhysicalKey::Code(code) = physical_key {
+                if let Some(index) = digit_key_index(code) {
+                    self.choose_evolution(index);
+                    return;
+                }
+            }
+        }
+
+        // An open use-item panel takes the number keys
+        if self.game_state.use_item.is_open() {
+            if let winit::keyboard::PhysicalKey::Code(code) = physical_key {
+                if let Some(index) = digit_key_index(code) {
+                    self.choose_item_to_use(index);
+                    return;
+                }
+            }
+        }
+
+        // An open inventory screen takes the number keys and its action keys
+        if self.game_state.inventory.is_open() {
+            if let winit::keyboard::PhysicalKey::Code(code) = physical_key {
+                if self.handle_inventory_key(code) {
+                    return;
+                }
+            }
+        }
+
+        // Bound keys trigger their action before the fixed controls below
+        if let winit::keyboard::PhysicalKey::Code(code) = physical_key {
+            if let Some(action) = self.settings.key_bindings.action_for(code) {
+                self.perform_action(action);
+                return;
+            }
+        }
+
         let move_speed = 0.1;
         match physical_key {
             winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowUp) => {
@@ -403,61 +1362,56 @@ impl GameApp {
                     self.hex_grid.move_camera(0.0, -move_speed);
                 }
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowLeft) => {
-                if !self.encyclopedia_visible() {
-                    self.hex_grid.move_camera(-move_speed, 0.0);
-                }
-            }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowRight) => {
-                if !self.encyclopedia_visible() {
-                    self.hex_grid.move_camera(move_speed, 0.0);
-                }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowLeft)
+                if !self.encyclopedia_visible() =>
+            {
+                self.hex_grid.move_camera(-move_speed, 0.0);
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit1) => {
-                if self.encyclopedia_visible() {
-                    if let Some(panel) = &mut self.encyclopedia_panel {
-                        panel.set_category(EncyclopediaCategory::Units);
-                        self.update_encyclopedia_content();
-                    }
-                }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::ArrowRight)
+                if !self.encyclopedia_visible() =>
+            {
+                self.hex_grid.move_camera(move_speed, 0.0);
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit2) => {
-                if self.encyclopedia_visible() {
-                    if let Some(panel) = &mut self.encyclopedia_panel {
-                        panel.set_category(EncyclopediaCategory::Terrain);
-                        self.update_encyclopedia_content();
-                    }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit1)
+                if self.encyclopedia_visible() =>
+            {
+                if let Some(panel) = &mut self.encyclopedia_panel {
+                    panel.set_category(EncyclopediaCategory::Units);
+                    self.update_encyclopedia_content();
                 }
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit3) => {
-                if self.encyclopedia_visible() {
-                    if let Some(panel) = &mut self.encyclopedia_panel {
-                        panel.set_category(EncyclopediaCategory::Mechanics);
-                        self.update_encyclopedia_content();
-                    }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit2)
+                if self.encyclopedia_visible() =>
+            {
+                if let Some(panel) = &mut self.encyclopedia_panel {
+                    panel.set_category(EncyclopediaCategory::Terrain);
+                    self.update_encyclopedia_content();
                 }
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyC) => {
-                // Show detailed unit info in console
-                if let Some(unit_id) = self.selected_unit() {
-                    self.call_unit_on_click(unit_id);
-                } else {
-                    println!("No unit selected. Click on a unit first!");
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit3)
+                if self.encyclopedia_visible() =>
+            {
+                if let Some(panel) = &mut self.encyclopedia_panel {
+                    panel.set_category(EncyclopediaCategory::Mechanics);
+                    self.update_encyclopedia_content();
                 }
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Space) => {
-                // End current turn (only works if it's a player turn)
-                if self.game_world.is_current_team_player_controlled() {
-                    self.game_world.end_current_turn();
-                    self.clear_selection(); // Clear any unit selection when turn ends
-                } else {
-                    println!("⚠️  Cannot end turn - not your turn!");
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Digit4)
+                if self.encyclopedia_visible() =>
+            {
+                if let Some(panel) = &mut self.encyclopedia_panel {
+                    panel.set_category(EncyclopediaCategory::Items);
+                    self.update_encyclopedia_content();
                 }
             }
             winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape) => {
-                // Priority 1: Check if recruitment is open, close it
+                // Priority 1: Check if recruitment or the use-item panel is open, close it
                 let mut handled = false;
-                if self.game_state.current_state == GameState::RecruitUnit {
+                if self.game_state.use_item.is_open() {
+                    self.game_state.use_item.close();
+                    println!("🎒 Use Item: Closed");
+                    handled = true;
+                } else if self.game_state.current_state == GameState::RecruitUnit {
                     self.game_state.transition_to(GameState::Exploring);
                     println!("🎖️ Recruitment: Closed");
                     handled = true;
@@ -498,57 +1452,21 @@ impl GameApp {
                     self.clear_selection();
                 }
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyE) => {
-                // Toggle encyclopedia
-                let new_visible = !self.encyclopedia_visible();
-                self.set_encyclopedia_visible(new_visible);
-                if new_visible {
-                    println!("📚 Encyclopedia: Opened (Press E or ESC to close)");
-                    // Update content based on current category
-                    self.update_encyclopedia_content();
-                } else {
-                    println!("📚 Encyclopedia: Closed");
-                }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Tab)
+                if self.game_state.ai_inspector.is_visible() =>
+            {
+                // Focus the next AI unit in the inspector
+                let agent_count = self
+                    .game_world
+                    .last_ai_trace()
+                    .map_or(0, |trace| trace.agents.len());
+                self.game_state.ai_inspector.focus_next(agent_count);
+                self.update_ai_inspector_highlights();
             }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyH) => {
-                // Toggle hower debug mode
-                self.hower_debug_enabled = !self.hower_debug_enabled;
-                if self.hower_debug_enabled {
-                    println!("🔍 hower DEBUG: Enabled - cursor will highlight hexes");
-                } else {
-                    println!("🔍 hower DEBUG: Disabled");
-                    // Clear any existing debug highlighting
-                    if let Some(prev_hex) = self.hower_debug_hex {
-                        if let Some(hex) = self.hex_grid.hexagons.get_mut(&prev_hex) {
-                            hex.color = [
-                                0.3 + 0.4 * ((prev_hex.q + prev_hex.r) % 3) as f32 / 3.0,
-                                0.4 + 0.3 * (prev_hex.q % 4) as f32 / 4.0,
-                                0.5 + 0.3 * (prev_hex.r % 5) as f32 / 5.0,
-                            ];
-                        }
-                    }
-                    self.hower_debug_hex = None;
-                }
-            }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyY) => {
-                // Accept item pickup
-                if let Some(prompt) = self.take_pickup_prompt() {
-                    self.handle_item_pickup(prompt.unit_id, prompt.item_id);
-                    // Clear UI prompt
-                    if let Some(ui_panel) = &mut self.ui_panel {
-                        ui_panel.clear_pickup_prompt();
-                    }
-                }
-            }
-            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyN) => {
-                // Decline item pickup
-                if let Some(prompt) = self.take_pickup_prompt() {
-                    println!("❌ Declined to pick up '{}'", prompt.item_name);
-                    // Clear UI prompt
-                    if let Some(ui_panel) = &mut self.ui_panel {
-                        ui_panel.clear_pickup_prompt();
-                    }
-                }
+            winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyJ)
+                if self.game_state.ai_inspector.is_visible() =>
+            {
+                self.export_ai_trace();
             }
             // (F1-F6 static encyclopedia hotkeys removed; only dynamic/automatic encyclopedia remains)
             winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::KeyG) => {
@@ -608,6 +1526,36 @@ impl GameApp {
     /// * `x` - Screen X coordinate of the click
     /// * `y` - Screen Y coordinate of the click
     fn handle_left_click(&mut self, x: f64, y: f64) {
+        // Any click on the hot-seat handoff screen reveals the board
+        if self.game_state.hot_seat.is_board_hidden() {
+            self.confirm_hot_seat_handoff();
+            return;
+        }
+
+        // Evolution choice dialog blocks every other click until a branch is chosen
+        if self.game_world.pending_evolution().is_some() {
+            let clicked = self
+                .evolution_panel
+                .as_ref()
+                .and_then(|panel| panel.get_option_at_position(x as f32, y as f32));
+            if let Some(index) = clicked {
+                self.choose_evolution(index);
+            }
+            return;
+        }
+
+        // The use-item panel takes clicks on its items, then on the target unit
+        if self.game_state.use_item.is_open() {
+            self.handle_use_item_click(x, y);
+            return;
+        }
+
+        // The inventory screen takes clicks on its items, then on the receiving ally
+        if self.game_state.inventory.is_open() {
+            self.handle_inventory_click(x, y);
+            return;
+        }
+
         // Priority 0: Check if clicking on combat confirmation dialog (highest priority)
         if self.has_pending_combat() {
             if let Some(renderer) = &mut self.renderer {
@@ -645,13 +1593,8 @@ impl GameApp {
                             }
                         }
 
-                        // Delegate combat execution to ScenarioWorld
-                        // (ScenarioWorld uses Combat crate for resolution logic)
-                        if let Err(e) = self.game_world.execute_pending_combat() {
-                            println!("❌ Combat failed: {}", e);
-                        }
                         renderer.combat_log_display.clear_combat_confirmation();
-                        self.clear_selection();
+                        self.resolve_pending_combat();
                     } else {
                         // Cancel combat
                         self.game_world.cancel_pending_combat();
@@ -690,19 +1633,8 @@ impl GameApp {
         if self.game_state.current_state == GameState::RecruitUnit {
             if let Some(renderer) = &self.renderer {
                 if let Some(panel) = &renderer.recruitment_panel {
-                    if let Some(index) = panel.get_unit_at_position(x as f32, y as f32, 7) {
-                        let human_units = [
-                            units::UnitType::HumanNoble,
-                            units::UnitType::HumanSquire,
-                            units::UnitType::HumanKnight,
-                            units::UnitType::HumanPrince,
-                            units::UnitType::HumanGrandKnight,
-                            units::UnitType::HumanKing,
-                            units::UnitType::HumanKnightCommander,
-                        ];
-
-                        if index < human_units.len() {
-                            let unit_type = human_units[index];
+                    if let Some(index) = panel.get_unit_at_position(x as f32, y as f32) {
+                        if let Some(&(_, unit_type)) = RECRUITABLE_UNITS.get(index) {
                             self.spawn_recruited_unit(unit_type);
                             self.game_state.transition_to(GameState::Exploring);
                         }
@@ -723,7 +1655,10 @@ impl GameApp {
                             println!("🎮 Menu: Closed - Continuing game");
                         }
                         MenuAction::Settings => {
-                            println!("⚙️  Settings: Not yet implemented");
+                            renderer.menu_display.hide();
+                            self.settings_scene
+                                .open(self.settings.clone(), SceneType::Game);
+                            self.scene_manager.transition_to(SceneType::Settings);
                         }
                         MenuAction::Save => {
                             println!("💾 Save Game: Not yet implemented");
@@ -750,16 +1685,22 @@ impl GameApp {
             }
         }
 
+        // Priority 4: Clicks on the minimap move the camera
+        if let Some(renderer) = &mut self.renderer {
+            if renderer
+                .minimap
+                .press(x as f32, y as f32, &mut self.hex_grid)
+            {
+                return;
+            }
+        }
+
         // Priority 2: Check if clicking on UI buttons
         if let Some(ui_panel) = &self.ui_panel {
             // Check end turn button
             if ui_panel.check_end_turn_button_click(x as f32, y as f32) {
-                if self.game_world.is_current_team_player_controlled() {
-                    self.game_world.end_current_turn();
-                    self.clear_selection();
+                if self.end_local_turn() {
                     println!("⏭️  Turn ended via UI button");
-                } else {
-                    println!("⚠️  Cannot end turn - not your turn!");
                 }
                 return; // Don't process hex click
             }
@@ -795,8 +1736,11 @@ impl GameApp {
             if self.selected_unit().is_none() {
                 // No unit selected: try to select one
                 if let Some(unit_id) = self.find_unit_at_hex(hex_coord) {
-                    self.select_unit(unit_id);
-                    // (Optional: print message)
+                    if self.can_command_unit(unit_id) {
+                        self.select_unit(unit_id);
+                    } else {
+                        println!("⚠️  That unit is not yours to command!");
+                    }
                 }
                 // If no unit, do nothing
             } else {
@@ -811,12 +1755,28 @@ impl GameApp {
                     // ScenarioWorld creates PendingCombat which QuestApp displays for confirmation
                     if let Err(e) = self.game_world.move_unit(unit_id, hex_coord) {
                         println!("Failed to initiate combat: {}", e);
+                    } else if !self.settings.confirm_attacks {
+                        // Attack at once with the attack ScenarioWorld picked
+                        println!("⚔️  Attacking without confirmation");
+                        self.resolve_pending_combat();
                     } else {
                         // ScenarioWorld created PendingCombat - display confirmation UI
                         // (Combat execution is deferred until player confirms)
                         if let Some(pending) = &self.game_world.pending_combat {
                             if let Some(renderer) = &mut self.renderer {
-                                use graphics::{AttackOption, CombatConfirmation};
+                                use graphics::{AttackForecast, AttackOption, CombatConfirmation};
+
+                                let attack_forecast =
+                                    |forecast: &game::CombatForecast| AttackForecast {
+                                        expected_damage_dealt: forecast.expected_damage_dealt(),
+                                        expected_damage_taken: forecast.expected_damage_taken(),
+                                        kill_chance: forecast.kill_chance(),
+                                        death_chance: forecast.death_chance(),
+                                        defender_hp_distribution: forecast
+                                            .defender_hp_distribution(),
+                                        attacker_hp_distribution: forecast
+                                            .attacker_hp_distribution(),
+                                    };
 
                                 // Convert game AttackInfo to graphics AttackOption
                                 let attacker_attacks = pending
@@ -826,6 +1786,13 @@ impl GameApp {
                                         name: attack.name.clone(),
                                         damage: attack.damage,
                                         range: attack.range,
+                                        range_type: attack.range_type.name().to_string(),
+                                        in_reach: attack.in_reach,
+                                        counter_index: attack.counter_index,
+                                        time_of_day_bonus: attack.time_of_day_bonus,
+                                        hit_chance: attack.hit_chance.chance,
+                                        hit_chance_breakdown: attack.hit_chance.lines(),
+                                        forecast: attack.forecast.as_ref().map(attack_forecast),
                                     })
                                     .collect();
 
@@ -836,6 +1803,13 @@ impl GameApp {
                                         name: attack.name.clone(),
                                         damage: attack.damage,
                                         range: attack.range,
+                                        range_type: attack.range_type.name().to_string(),
+                                        in_reach: attack.in_reach,
+                                        counter_index: attack.counter_index,
+                                        time_of_day_bonus: attack.time_of_day_bonus,
+                                        hit_chance: attack.hit_chance.chance,
+                                        hit_chance_breakdown: attack.hit_chance.lines(),
+                                        forecast: attack.forecast.as_ref().map(attack_forecast),
                                     })
                                     .collect();
 
@@ -871,7 +1845,20 @@ impl GameApp {
                     .contains(&hex_coord)
                 {
                     // Valid move - animate movement (non-combat)
-                    if let Some(game_unit) = self.game_world.units.get(&unit_id) {
+                    if self.network.is_some() {
+                        // Networked moves land at once so both worlds agree
+                        let from = self.get_unit(unit_id).map(|unit| unit.position());
+                        if let Some(from) = from {
+                            let team = self.game_world.current_turn_team();
+                            let command = PlayerCommand::Move {
+                                from,
+                                to: hex_coord,
+                            };
+                            if self.submit_command(team, command).is_ok() {
+                                println!("🚶 Unit moved to {:?}", hex_coord);
+                            }
+                        }
+                    } else if let Some(game_unit) = self.game_world.units.get(&unit_id) {
                         let start_pos = game_unit.position();
 
                         // Find path from current position to target using graphics::find_path
@@ -940,6 +1927,10 @@ impl GameApp {
     /// * `x` - Screen X coordinate of the click
     /// * `y` - Screen Y coordinate of the click
     fn handle_right_click(&mut self, x: f64, y: f64) {
+        if self.game_state.hot_seat.is_board_hidden() {
+            return;
+        }
+
         // --- Player Control Flow: unselect unit or open game submenu ---
         if self.selected_unit().is_some() {
             // If unit is selected, unselect it
@@ -1011,6 +2002,8 @@ impl GameApp {
     /// - Pickup prompts
     fn clear_selection(&mut self) {
         self.game_state.exploring.deselect_unit();
+        self.game_state.use_item.close();
+        self.game_state.inventory.close();
         self.show_unit_info = false;
         self.unit_info_text.clear();
 
@@ -1059,107 +2052,602 @@ impl GameApp {
         }
     }
 
-    /// Checks if the target hex contains an enemy unit.
-    ///
-    /// Determines whether the specified hex coordinate contains a unit that is
-    /// on a different team than the attacker.
-    ///
-    /// # Arguments
-    ///
-    /// * `attacker_id` - UUID of the attacking unit
-    /// * `target_hex` - Hex coordinate to check for enemy units
-    ///
-    /// # Returns
-    ///
-    /// `true` if an enemy unit is present at the target hex, `false` otherwise.
-    ///
-    /// # Examples
-    ///
-    /// ```text
-    /// if self.has_enemy_unit(player_unit, hex_coord) {
-    ///     // There's an enemy at this position
-    /// }
-    /// ```
-    fn has_enemy_unit(&self, attacker_id: uuid::Uuid, target_hex: HexCoord) -> bool {
-        if let Some(attacker) = self.game_world.units.get(&attacker_id) {
-            let attacker_team = attacker.team();
+    /// Checks if the target hex contains an enemy unit.
+    ///
+    /// Determines whether the specified hex coordinate contains a unit whose
+    /// team is hostile to the attacker's team.
+    ///
+    /// # Arguments
+    ///
+    /// * `attacker_id` - UUID of the attacking unit
+    /// * `target_hex` - Hex coordinate to check for enemy units
+    ///
+    /// # Returns
+    ///
+    /// `true` if an enemy unit is present at the target hex, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// if self.has_enemy_unit(player_unit, hex_coord) {
+    ///     // There's an enemy at this position
+    /// }
+    /// ```
+    fn has_enemy_unit(&self, attacker_id: uuid::Uuid, target_hex: HexCoord) -> bool {
+        if let Some(attacker) = self.game_world.units.get(&attacker_id) {
+            let attacker_team = attacker.team();
+
+            // Check all units at target hex
+            for unit in self.game_world.units.values() {
+                if unit.position() == target_hex
+                    && unit.id() != attacker_id
+                    && self.is_hex_revealed(target_hex)
+                {
+                    return self
+                        .game_world
+                        .teams
+                        .are_hostile(unit.team(), attacker_team);
+                }
+            }
+        }
+        false
+    }
+
+    /// Updates hex grid highlighting to show selected unit, movement range, and attack targets.
+    ///
+    /// Clears all existing highlights and applies new ones based on the currently
+    /// selected unit. Highlights include:
+    /// - Yellow highlight for selected unit's position
+    /// - Red highlight for enemies within attack range
+    /// - Blue highlight for valid movement hexes
+    fn update_highlight_display(&mut self) {
+        // Clear existing highlights
+        self.hex_grid.clear_all_highlights();
+
+        // Highlight selected unit position
+        if let Some(unit_id) = self.selected_unit() {
+            if let Some(game_unit) = self.game_world.units.get(&unit_id) {
+                self.hex_grid
+                    .highlight_hex(game_unit.position(), HighlightType::Selected);
+
+                // Highlight enemies within attack range in red
+                let unit_pos = game_unit.position();
+                let attacker_team = game_unit.team();
+                let attack_range = game_unit.unit().combat_stats().attack_range;
+
+                for enemy in self.game_world.units.values() {
+                    if enemy.id() != unit_id
+                        && self
+                            .game_world
+                            .teams
+                            .are_hostile(enemy.team(), attacker_team)
+                    {
+                        let enemy_pos = enemy.position();
+                        let distance = unit_pos.distance(enemy_pos);
+                        if distance <= attack_range && distance > 0 {
+                            // Enemy within range - highlight in red
+                            self.hex_grid
+                                .highlight_hex(enemy_pos, HighlightType::Selected);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Highlight movement range in blue and display defense values
+        self.hex_grid.highlight_hexes(
+            self.game_state.exploring.movement_range(),
+            HighlightType::MovementRange,
+        );
+
+        // Display defense value on each movement range tile (terrain-based)
+        if let Some(unit_id) = self.game_state.exploring.selected_unit() {
+            if let Some(game_unit) = self.game_world.units.get(&unit_id) {
+                let movement_range = self.game_state.exploring.movement_range();
+                println!(
+                    "🛡️  Setting defense overlay on {} tiles",
+                    movement_range.len()
+                );
+                for &hex_coord in movement_range {
+                    // Get terrain at this position
+                    if let Some(terrain_tile) = self.game_world.get_terrain(hex_coord) {
+                        let terrain = terrain_tile.defense_class();
+                        // Calculate terrain-based defense for this unit at this position
+                        let defense = game_unit.unit().get_terrain_hit_chance(terrain);
+                        self.hex_grid
+                            .set_hex_text_overlay(hex_coord, Some(format!("DEF:{}", defense)));
+                        println!(
+                            "   - Set DEF:{} on tile {:?} ({:?})",
+                            defense, hex_coord, terrain
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves the pending combat with its selected attack and clears the
+    /// selection.
+    fn resolve_pending_combat(&mut self) {
+        if self.network.is_some() {
+            // Networked attacks are resolved as a lockstep command
+            self.submit_pending_attack();
+        } else if let Err(e) = self.game_world.execute_pending_combat() {
+            // Delegate combat execution to ScenarioWorld
+            // (ScenarioWorld uses Combat crate for resolution logic)
+            println!("❌ Combat failed: {}", e);
+        }
+        self.clear_selection();
+    }
+
+    /// Sends the confirmed combat dialog's attack as a lockstep command.
+    fn submit_pending_attack(&mut self) {
+        let Some(pending) = self.game_world.pending_combat.take() else {
+            return;
+        };
+        let (Some(attacker), Some(defender)) = (
+            self.get_unit(pending.attacker_id)
+                .map(|unit| unit.position()),
+            self.get_unit(pending.defender_id)
+                .map(|unit| unit.position()),
+        ) else {
+            return;
+        };
+        let team = self.game_world.current_turn_team();
+        let command = PlayerCommand::Attack {
+            attacker,
+            defender,
+            attack_index: pending.selected_attack_index,
+        };
+        if let Err(e) = self.submit_command(team, command) {
+            println!("❌ Combat failed: {}", e);
+        }
+    }
+
+    /// Evolves the unit waiting for an evolution choice into option `index`.
+    fn choose_evolution(&mut self, index: usize) {
+        let Some(unit_id) = self.game_world.pending_evolution().map(|p| p.unit_id) else {
+            return;
+        };
+        let result = match self.get_unit(unit_id).map(|unit| unit.team()) {
+            Some(team) if self.network.is_some() => {
+                self.submit_command(team, PlayerCommand::ChooseEvolution { option: index })
+            }
+            _ => self.game_world.choose_evolution(index),
+        };
+        match result {
+            Ok(()) => {
+                self.update_hex_grid_units();
+                self.update_unit_info_display(unit_id);
+            }
+            Err(e) => println!("⚠️  Evolution failed: {}", e),
+        }
+    }
+
+    /// Returns the unit name and one line per option for the evolution dialog.
+    fn evolution_dialog_content(&self) -> Option<(String, Vec<String>)> {
+        let pending = self.game_world.pending_evolution()?;
+        let unit_name = self.get_unit(pending.unit_id)?.name();
+        let options = pending
+            .options
+            .iter()
+            .map(
+                |unit_type| match units::UnitFactory::create(unit_type.as_str(), None, None) {
+                    Ok(unit) => {
+                        let best_attack = unit
+                            .get_attacks()
+                            .iter()
+                            .map(|attack| attack.damage)
+                            .max()
+                            .unwrap_or(0);
+                        format!(
+                            "{} (HP {}, attack {}, moves {})",
+                            unit_type,
+                            unit.combat_stats().max_health,
+                            best_attack,
+                            unit.combat_stats().movement_speed
+                        )
+                    }
+                    Err(_) => unit_type.to_string(),
+                },
+            )
+            .collect();
+        Some((unit_name, options))
+    }
+
+    // ===== Use Item Helpers =====
+
+    /// Opens the use-item panel over the selected unit's consumables
+    fn open_use_item_panel(&mut self) {
+        let Some(unit_id) = self.selected_unit() else {
+            println!("❌ No unit selected. Select a unit first!");
+            return;
+        };
+        if !self.can_command_unit(unit_id) || !self.is_local_turn() {
+            println!("⚠️  Cannot use items - not your turn!");
+            return;
+        }
+        let slots: Vec<usize> = self
+            .get_unit(unit_id)
+            .map(|unit| {
+                unit.unit()
+                    .inventory()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| item.remaining_uses().unwrap_or(0) > 0)
+                    .map(|(slot, _)| slot)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if slots.is_empty() {
+            println!("🎒 This unit carries no consumables");
+            return;
+        }
+        self.game_state.inventory.close();
+        self.game_state.use_item.open(unit_id, slots);
+        println!("🎒 Use Item: Opened (press a number, U or ESC to close)");
+    }
+
+    /// Chooses the listed consumable at `index` and asks for its target
+    ///
+    /// A unit with no adjacent allies uses the item on itself straight away.
+    fn choose_item_to_use(&mut self, index: usize) {
+        let Some(unit_id) = self.game_state.use_item.unit_id() else {
+            return;
+        };
+        if self.game_state.use_item.choose(index).is_none() {
+            return;
+        }
+        if self.game_world.item_targets(unit_id).len() == 1 {
+            self.use_chosen_item(unit_id);
+        } else {
+            println!("🎒 Click the unit itself or an adjacent ally");
+        }
+    }
+
+    /// Handles a click while the use-item panel is open
+    fn handle_use_item_click(&mut self, x: f64, y: f64) {
+        let clicked = self
+            .item_use_panel
+            .as_ref()
+            .and_then(|panel| panel.get_option_at_position(x as f32, y as f32));
+        if let Some(index) = clicked {
+            self.choose_item_to_use(index);
+            return;
+        }
+        let Some(unit_id) = self.game_state.use_item.unit_id() else {
+            return;
+        };
+        if self.game_state.use_item.chosen_slot().is_none() {
+            return;
+        }
+        let target = self
+            .screen_to_hex_coord(x, y)
+            .and_then(|hex| self.find_unit_at_hex(hex))
+            .filter(|target| self.game_world.item_targets(unit_id).contains(target));
+        match target {
+            Some(target_id) => self.use_chosen_item(target_id),
+            None => println!("⚠️  Items can only be used on the unit or an adjacent ally"),
+        }
+    }
+
+    /// Uses the chosen consumable on `target_id` and closes the panel
+    fn use_chosen_item(&mut self, target_id: uuid::Uuid) {
+        let (Some(unit_id), Some(slot)) = (
+            self.game_state.use_item.unit_id(),
+            self.game_state.use_item.chosen_slot(),
+        ) else {
+            return;
+        };
+        self.game_state.use_item.close();
+
+        let result = if self.network.is_some() {
+            let (Some(unit), Some(target)) = (
+                self.get_unit(unit_id).map(|unit| unit.position()),
+                self.get_unit(target_id).map(|unit| unit.position()),
+            ) else {
+                return;
+            };
+            let team = self.game_world.current_turn_team();
+            self.submit_command(team, PlayerCommand::UseItem { unit, slot, target })
+        } else {
+            let item_id = self
+                .get_unit(unit_id)
+                .and_then(|unit| unit.unit().inventory().get(slot).map(|item| item.id));
+            match item_id {
+                Some(item_id) => self
+                    .game_world
+                    .use_item(unit_id, item_id, target_id)
+                    .map(|_| ()),
+                None => Err("Item not found in inventory".to_string()),
+            }
+        };
+        match result {
+            Ok(()) => {
+                self.update_hex_grid_units();
+                self.update_unit_info_display(target_id);
+            }
+            Err(e) => println!("⚠️  {}", e),
+        }
+    }
+
+    /// Returns the unit name and one line per consumable for the use-item panel
+    fn use_item_dialog_content(&self) -> Option<(String, Vec<String>)> {
+        let unit = self.get_unit(self.game_state.use_item.unit_id()?)?;
+        let inventory = unit.unit().inventory();
+        let options = self
+            .game_state
+            .use_item
+            .slots()
+            .iter()
+            .filter_map(|slot| inventory.get(*slot))
+            .map(|item| {
+                let uses = item.remaining_uses().unwrap_or(0);
+                format!(
+                    "{} ({} use{})",
+                    item.name,
+                    uses,
+                    if uses == 1 { "" } else { "s" }
+                )
+            })
+            .collect();
+        Some((unit.name(), options))
+    }
+
+    // ===== Inventory Helpers =====
+
+    /// Opens the inventory screen over the selected unit's items
+    fn open_inventory_panel(&mut self) {
+        let Some(unit_id) = self.selected_unit() else {
+            println!("❌ No unit selected. Select a unit first!");
+            return;
+        };
+        if !self.can_command_unit(unit_id) || !self.is_local_turn() {
+            println!("⚠️  Cannot manage items - not your turn!");
+            return;
+        }
+        self.game_state.use_item.close();
+        self.game_state.inventory.open(unit_id);
+        println!(
+            "🎒 Inventory: Opened (number: select, E: equip, D: drop, G: give, B or ESC to close)"
+        );
+    }
+
+    /// Handles a key press while the inventory screen is open
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was used by the inventory screen
+    fn handle_inventory_key(&mut self, code: winit::keyboard::KeyCode) -> bool {
+        use winit::keyboard::KeyCode;
+
+        if let Some(index) = digit_key_index(code) {
+            self.select_inventory_entry(index);
+            return true;
+        }
+        match code {
+            _ if self.settings.key_bindings.key(Action::Inventory) == Some(code) => {
+                self.game_state.inventory.close();
+                println!("🎒 Inventory: Closed");
+            }
+            KeyCode::Escape => {
+                if self.game_state.inventory.is_giving() {
+                    self.game_state.inventory.stop_giving();
+                } else {
+                    self.game_state.inventory.close();
+                    println!("🎒 Inventory: Closed");
+                }
+            }
+            KeyCode::KeyE => self.toggle_selected_equipment(),
+            KeyCode::KeyD => self.drop_selected_item(),
+            KeyCode::KeyG => {
+                if self.game_state.inventory.start_giving() {
+                    println!("🤝 Click an adjacent ally to give the item to");
+                } else {
+                    println!("⚠️  Select an item first");
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Selects the carried item at `index` and prints its stat changes
+    fn select_inventory_entry(&mut self, index: usize) {
+        let Some(unit_id) = self.game_state.inventory.unit_id() else {
+            return;
+        };
+        let count = self.game_world.carried_items(unit_id).len();
+        if self.game_state.inventory.select(index, count) {
+            let (item, _) = self.game_world.carried_items(unit_id)[index];
+            let delta = self.game_world.item_delta(unit_id, item.id);
+            println!(
+                "🎒 {}: {}",
+                item.name,
+                delta.map_or("Consumable".to_string(), |delta| delta.summary())
+            );
+        }
+    }
+
+    /// Handles a click while the inventory screen is open
+    fn handle_inventory_click(&mut self, x: f64, y: f64) {
+        let Some(unit_id) = self.game_state.inventory.unit_id() else {
+            return;
+        };
+        let clicked = self
+            .inventory_panel
+            .as_ref()
+            .and_then(|panel| panel.get_entry_at_position(x as f32, y as f32));
+        if let Some(index) = clicked {
+            self.select_inventory_entry(index);
+            return;
+        }
+        if !self.game_state.inventory.is_giving() {
+            return;
+        }
+        let receiver = self
+            .screen_to_hex_coord(x, y)
+            .and_then(|hex| self.find_unit_at_hex(hex))
+            .filter(|receiver| {
+                *receiver != unit_id && self.game_world.item_targets(unit_id).contains(receiver)
+            });
+        match receiver {
+            Some(receiver_id) => self.give_selected_item(receiver_id),
+            None => println!("⚠️  Items can only be given to an adjacent ally"),
+        }
+    }
+
+    /// Equips the selected item, or unequips it if it is already equipped
+    fn toggle_selected_equipment(&mut self) {
+        let Some((unit, slot, equipped)) = self.selected_inventory_entry() else {
+            println!("⚠️  Select an item first");
+            return;
+        };
+        let command = if equipped {
+            PlayerCommand::Unequip { unit, slot }
+        } else {
+            PlayerCommand::Equip { unit, slot }
+        };
+        self.submit_inventory_command(command);
+    }
+
+    /// Drops the selected item onto the unit's hex
+    fn drop_selected_item(&mut self) {
+        let Some((unit, slot, _)) = self.selected_inventory_entry() else {
+            println!("⚠️  Select an item first");
+            return;
+        };
+        self.submit_inventory_command(PlayerCommand::Drop { unit, slot });
+    }
+
+    /// Gives the selected item to `receiver_id`
+    fn give_selected_item(&mut self, receiver_id: uuid::Uuid) {
+        let Some((unit, slot, _)) = self.selected_inventory_entry() else {
+            return;
+        };
+        let Some(to) = self.get_unit(receiver_id).map(|unit| unit.position()) else {
+            return;
+        };
+        self.game_state.inventory.stop_giving();
+        self.submit_inventory_command(PlayerCommand::Give { unit, slot, to });
+    }
+
+    /// Returns the inventory unit's hex, the selected slot and whether the
+    /// selected item is equipped
+    fn selected_inventory_entry(&self) -> Option<(HexCoord, usize, bool)> {
+        let unit_id = self.game_state.inventory.unit_id()?;
+        let slot = self.game_state.inventory.selected()?;
+        let (_, equipped) = *self.game_world.carried_items(unit_id).get(slot)?;
+        Some((self.get_unit(unit_id)?.position(), slot, equipped))
+    }
+
+    /// Applies an inventory command for the current team and refreshes the
+    /// board and the unit details
+    fn submit_inventory_command(&mut self, command: PlayerCommand) {
+        let Some(unit_id) = self.game_state.inventory.unit_id() else {
+            return;
+        };
+        let team = self.game_world.current_turn_team();
+        match self.submit_command(team, command) {
+            Ok(()) => {
+                let count = self.game_world.carried_items(unit_id).len();
+                self.game_state.inventory.sync(count);
+                self.update_hex_grid_units();
+                self.update_unit_info_display(unit_id);
+            }
+            Err(e) => println!("⚠️  {}", e),
+        }
+    }
+
+    /// Returns the unit name, one entry per carried item and the details of
+    /// the selected item for the inventory screen
+    #[allow(clippy::type_complexity)]
+    fn inventory_dialog_content(&self) -> Option<(String, Vec<(String, bool)>, Vec<String>)> {
+        let unit_id = self.game_state.inventory.unit_id()?;
+        let unit = self.get_unit(unit_id)?;
+        let carried = self.game_world.carried_items(unit_id);
+        let entries = carried
+            .iter()
+            .map(|(item, equipped)| {
+                let label = match item.remaining_uses() {
+                    Some(uses) => format!("{} ({} uses)", item.name, uses),
+                    _ => format!("{} ({:?})", item.name, item.item_type),
+                };
+                (label, *equipped)
+            })
+            .collect();
 
-            // Check all units at target hex
-            for unit in self.game_world.units.values() {
-                if unit.position() == target_hex && unit.id() != attacker_id {
-                    return unit.team() != attacker_team;
-                }
+        let mut details = Vec::new();
+        if let Some((item, equipped)) = self
+            .game_state
+            .inventory
+            .selected()
+            .and_then(|index| carried.get(index))
+        {
+            details.push(item.description.clone());
+            match self.game_world.item_delta(unit_id, item.id) {
+                Some(delta) => details.push(format!(
+                    "{}: {}",
+                    if *equipped { "Unequip" } else { "Equip" },
+                    delta.summary()
+                )),
+                None => details.push("Consumable - press U to use it".to_string()),
             }
         }
-        false
+        Some((unit.name(), entries, details))
     }
 
-    /// Updates hex grid highlighting to show selected unit, movement range, and attack targets.
-    ///
-    /// Clears all existing highlights and applies new ones based on the currently
-    /// selected unit. Highlights include:
-    /// - Yellow highlight for selected unit's position
-    /// - Red highlight for enemies within attack range
-    /// - Blue highlight for valid movement hexes
-    fn update_highlight_display(&mut self) {
-        // Clear existing highlights
-        self.hex_grid.clear_all_highlights();
-
-        // Highlight selected unit position
-        if let Some(unit_id) = self.selected_unit() {
-            if let Some(game_unit) = self.game_world.units.get(&unit_id) {
-                self.hex_grid
-                    .highlight_hex(game_unit.position(), HighlightType::Selected);
-
-                // Highlight enemies within attack range in red
-                let unit_pos = game_unit.position();
-                let attacker_team = game_unit.team();
-                let attack_range = game_unit.unit().combat_stats().attack_range;
+    /// Returns the agent trace focused in the AI inspector, if any.
+    fn focused_ai_agent(&self) -> Option<&game::AgentTrace> {
+        let trace = self.game_world.last_ai_trace()?;
+        let index = self
+            .game_state
+            .ai_inspector
+            .focused_index(trace.agents.len())?;
+        trace.agents.get(index)
+    }
 
-                for enemy in self.game_world.units.values() {
-                    if enemy.id() != unit_id && enemy.team() != attacker_team {
-                        let enemy_pos = enemy.position();
-                        let distance = unit_pos.distance(enemy_pos);
-                        if distance <= attack_range && distance > 0 {
-                            // Enemy within range - highlight in red
-                            self.hex_grid
-                                .highlight_hex(enemy_pos, HighlightType::Selected);
-                        }
-                    }
-                }
+    /// Returns the text shown in the AI inspector panel.
+    fn ai_inspector_lines(&self) -> Vec<String> {
+        match self.game_world.last_ai_trace() {
+            Some(trace) => {
+                let focus = self.focused_ai_agent().map(|agent| agent.agent.as_str());
+                trace.summary_lines(focus)
             }
+            None => vec!["No AI turn recorded yet.".to_string()],
         }
+    }
 
-        // Highlight movement range in blue and display defense values
-        self.hex_grid.highlight_hexes(
-            self.game_state.exploring.movement_range(),
-            HighlightType::MovementRange,
-        );
+    /// Highlights the focused AI unit's planned path (blue) and target (yellow).
+    fn update_ai_inspector_highlights(&mut self) {
+        self.hex_grid.clear_all_highlights();
 
-        // Display defense value on each movement range tile (terrain-based)
-        if let Some(unit_id) = self.game_state.exploring.selected_unit() {
-            if let Some(game_unit) = self.game_world.units.get(&unit_id) {
-                let movement_range = self.game_state.exploring.movement_range();
-                println!(
-                    "🛡️  Setting defense overlay on {} tiles",
-                    movement_range.len()
-                );
-                for &hex_coord in movement_range {
-                    // Get terrain at this position
-                    if let Some(terrain_tile) = self.game_world.get_terrain(hex_coord) {
-                        // Convert sprite type to terrain (temporary mapping until terrain is stored properly)
-                        let terrain = sprite_to_terrain(terrain_tile.sprite_type());
-                        // Calculate terrain-based defense for this unit at this position
-                        let defense = game_unit.unit().get_terrain_hit_chance(terrain);
-                        self.hex_grid
-                            .set_hex_text_overlay(hex_coord, Some(format!("DEF:{}", defense)));
-                        println!(
-                            "   - Set DEF:{} on tile {:?} ({:?})",
-                            defense, hex_coord, terrain
-                        );
-                    }
-                }
-            }
+        let Some(agent) = self.focused_ai_agent() else {
+            return;
+        };
+        let path = agent.path.clone();
+        let target = agent.target;
+
+        self.hex_grid
+            .highlight_hexes(&path, HighlightType::MovementRange);
+        if let Some(target) = target {
+            self.hex_grid.highlight_hex(target, HighlightType::Selected);
+        }
+    }
+
+    /// Writes the last AI trace to `ai_trace_turn<N>.json` in the working directory.
+    fn export_ai_trace(&self) {
+        let Some(trace) = self.game_world.last_ai_trace() else {
+            println!("🧠 No AI trace to export yet");
+            return;
+        };
+        let path = format!("ai_trace_turn{}.json", trace.turn + 1);
+        match trace
+            .to_json()
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()))
+        {
+            Ok(()) => println!("🧠 AI trace exported to {}", path),
+            Err(e) => println!("⚠️  Failed to export AI trace: {}", e),
         }
     }
 
@@ -1180,6 +2668,11 @@ impl GameApp {
             // This will call the actual Unit's on_click() method!
             game_obj.show_details();
 
+            let effects = self.game_world.status_effect_summary(unit_id);
+            if !effects.is_empty() {
+                println!("Status effects: {}", effects.join(", "));
+            }
+
             println!("═══════════════════════════════════\n");
         } else {
             println!("Unit with ID {} not found!", unit_id);
@@ -1199,7 +2692,7 @@ impl GameApp {
     fn screen_to_hex_coord(&self, x: f64, y: f64) -> Option<HexCoord> {
         // Prefer geometric conversion via renderer's camera and grid
         let screen = Vec2::new(x as f32, y as f32);
-        let window = Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let window = Vec2::new(self.window_size.0, self.window_size.1);
         self.hex_grid.screen_to_hex_coord(screen, window)
     }
 
@@ -1215,6 +2708,10 @@ impl GameApp {
     ///
     /// `Some(unit_id)` if a unit is found at the coordinate, `None` otherwise.
     fn find_unit_at_hex(&self, hex_coord: HexCoord) -> Option<uuid::Uuid> {
+        // Units under fog of war can't be found
+        if !self.is_hex_revealed(hex_coord) {
+            return None;
+        }
         // Delegate to ScenarioWorld for unit queries
         self.game_world
             .units
@@ -1284,53 +2781,18 @@ impl GameApp {
     /// * `unit_id` - UUID of the unit picking up the item
     /// * `item_id` - UUID of the interactive object containing the item
     fn handle_item_pickup(&mut self, unit_id: uuid::Uuid, item_id: uuid::Uuid) {
-        // Query ScenarioWorld for item and unit
-        if let Some(item_obj) = self.game_world.interactive_objects.get_mut(&item_id) {
-            if let Some(item) = item_obj.take_item() {
-                // Add item to unit's inventory and auto-equip
-                if let Some(game_unit) = self.game_world.units.get_mut(&unit_id) {
-                    let item_name = item.name.clone();
-                    let item_internal_id = item.id;
-                    let item_type = item.item_type.clone();
-
-                    // Add to inventory first
-                    game_unit.unit_mut().add_item_to_inventory(item);
-                    println!("✅ Picked up '{}'!", item_name);
-
-                    // Try to auto-equip the item (will fail for consumables)
-                    if item_type != items::ItemType::Consumable {
-                        match game_unit.unit_mut().equip_item(item_internal_id) {
-                            Ok(_) => {
-                                let unit = game_unit.unit();
-                                let stats = unit.combat_stats();
-                                println!("⚔️  Auto-equipped '{}' ({:?})!", item_name, item_type);
-                                println!(
-                                    "📊 Current Stats - ATK: {} (+{}), HP: {}/{}, Movement: {}",
-                                    stats.get_total_attack(),
-                                    stats.attack_modifier,
-                                    stats.health,
-                                    stats.max_health,
-                                    stats.movement_speed
-                                );
-                            }
-                            Err(e) => {
-                                println!("📦 '{}' added to inventory ({})", item_name, e);
-                            }
-                        }
-                    } else {
-                        println!("💊 Consumable '{}' stored in inventory", item_name);
-                    }
-
-                    // Remove the interactive object from the world (it's been picked up)
-                    self.game_world.remove_interactive_object(item_id);
-                } else {
-                    println!("⚠️  Unit not found!");
-                }
-            } else {
-                println!("⚠️  Item no longer available!");
-            }
-        } else {
-            println!("⚠️  Item object not found!");
+        if self.network.is_some() {
+            let Some(unit) = self.get_unit(unit_id).map(|unit| unit.position()) else {
+                println!("⚠️  Unit not found!");
+                return;
+            };
+            let team = self.game_world.current_turn_team();
+            let _ = self.submit_command(team, PlayerCommand::Pickup { unit });
+            return;
+        }
+        // ScenarioWorld moves the item into the inventory and auto-equips it
+        if let Err(e) = self.game_world.pickup_item(unit_id, item_id) {
+            println!("⚠️  {}!", e);
         }
     }
 
@@ -1373,6 +2835,7 @@ impl GameApp {
                     .map(|attack| AttackDisplayInfo {
                         name: attack.name,
                         damage: attack.damage,
+                        damage_type: attack.damage_type.name().to_string(),
                         description: attack.description,
                     })
                     .collect();
@@ -1405,9 +2868,15 @@ impl GameApp {
                     position_r: position.r,
                     moves_left: game_unit.moves_left() as u32,
                     max_moves: stats.movement_speed as u32,
-                    sprite_type: unit.sprite(),
+                    sprite_type: unit_sprite(unit),
                     attacks,
                     inventory,
+                    resistances: stats
+                        .resistances
+                        .iter()
+                        .filter(|(_, value)| *value != 0)
+                        .map(|(damage_type, value)| (damage_type.name().to_string(), value))
+                        .collect(),
                 };
                 ui_panel.set_unit_info(display_info);
             }
@@ -1423,12 +2892,13 @@ impl GameApp {
             .recruit_position()
             .unwrap_or_else(|| HexCoord::new(0, 0));
 
-        // Determine team: use selected unit's team, or default to Player
+        // Determine team: use selected unit's team, or default to the team whose turn it is
+        let current_team = self.game_world.current_turn_team();
         let team = if let Some(unit_id) = self.selected_unit() {
             if let Some(game_unit) = self.game_world.units.get(&unit_id) {
                 game_unit.team()
             } else {
-                game::objects::Team::Player
+                current_team
             }
         } else {
             // Check if there's a unit at the spawn position to inherit team from
@@ -1436,26 +2906,38 @@ impl GameApp {
                 if let Some(game_unit) = self.game_world.units.get(&unit_id) {
                     game_unit.team()
                 } else {
-                    game::objects::Team::Player
+                    current_team
                 }
             } else {
-                game::objects::Team::Player
+                current_team
             }
         };
 
-        // Create the unit using the factory
         let unit_type_str = unit_type.as_str();
-        match units::UnitFactory::create(unit_type_str, None, Some(spawn_position)) {
-            Ok(unit) => {
-                // Create a GameUnit with inherited or player team
-                let game_unit = game::objects::GameUnit::new_with_team(unit, team);
-
-                // Add to the game world
-                let unit_id = self.game_world.add_unit(game_unit);
+        if self.network.is_some() {
+            // Only the side whose turn it is may recruit, for its own team
+            let command = PlayerCommand::Recruit {
+                unit_type: unit_type_str.to_string(),
+                at: spawn_position,
+            };
+            if self.submit_command(team, command).is_ok() {
+                println!("🎖️ Recruited {} at {:?}", unit_type_str, spawn_position);
+            }
+            return;
+        }
 
+        // ScenarioWorld creates the unit and places it
+        match self
+            .game_world
+            .recruit_unit(team, unit_type_str, spawn_position)
+        {
+            Ok(unit_id) => {
                 println!(
-                    "🎖️ Recruited {} at {:?} for team {:?} (ID: {})",
-                    unit_type_str, spawn_position, team, unit_id
+                    "🎖️ Recruited {} at {:?} for team {} (ID: {})",
+                    unit_type_str,
+                    spawn_position,
+                    self.game_world.teams.name(team),
+                    unit_id
                 );
             }
             Err(e) => {
@@ -1464,6 +2946,19 @@ impl GameApp {
         }
     }
 
+    /// Builds the UI panel's day graph data from the scenario's day/night cycle.
+    fn time_of_day_display(&self) -> TimeOfDayDisplayInfo {
+        let cycle = &self.game_world.day_cycle;
+        let turn = self.game_world.turn_number();
+        let current = cycle.phase_for_turn(turn);
+        TimeOfDayDisplayInfo {
+            name: current.name.clone(),
+            lawful_bonus: current.lawful_bonus,
+            phase_index: cycle.phase_index(turn),
+            phase_bonuses: cycle.phases().iter().map(|p| p.lawful_bonus).collect(),
+        }
+    }
+
     /// Renders UI elements (currently handled by UiPanel).
     ///
     /// Renders additional UI elements like turn information.
@@ -1474,48 +2969,101 @@ impl GameApp {
         // Display turn information
         if let Some(renderer) = &mut self.renderer {
             let current_team = self.game_world.current_turn_team();
+            let team_name = self.game_world.teams.name(current_team);
+            let team_color = self.game_world.teams.color(current_team);
             let turn_number = self.game_world.turn_number();
-            let is_player_turn = self.game_world.is_current_team_player_controlled();
+            let is_player_turn = match &self.network {
+                Some(session) => session.is_local_team(current_team),
+                None => self.game_world.is_current_team_player_controlled(),
+            };
+            let waiting_for_peer = self
+                .network
+                .as_ref()
+                .is_some_and(|session| !session.is_connected());
 
-            let turn_text = if is_player_turn {
+            let turn_text = if waiting_for_peer {
+                format!(
+                    "Turn {}: Waiting for the other player to connect",
+                    turn_number + 1
+                )
+            } else if is_player_turn {
+                format!("Turn {}: {}'s Turn (Your Turn)", turn_number + 1, team_name)
+            } else if self.network.is_some() {
                 format!(
-                    "Turn {}: {:?}'s Turn (Your Turn)",
+                    "Turn {}: {}'s Turn (Other Player)",
                     turn_number + 1,
-                    current_team
+                    team_name
                 )
             } else {
                 let time_remaining = self.game_world.ai_turn_time_remaining();
                 format!(
-                    "Turn {}: {:?}'s Turn (AI - {:.1}s)",
+                    "Turn {}: {}'s Turn (AI - {:.1}s)",
                     turn_number + 1,
-                    current_team,
+                    team_name,
                     time_remaining
                 )
             };
 
             // Render turn info at top of screen
-            renderer.text_renderer.render_text(
+            let (screen_width, screen_height) = self.window_size;
+            let mut list = DrawList::new();
+            list.text(
                 &turn_text,
                 10.0,
-                SCREEN_HEIGHT - 30.0,
+                screen_height - 30.0,
                 0.5,
-                [1.0, 1.0, 1.0, 1.0],
-                SCREEN_WIDTH,
-                SCREEN_HEIGHT,
+                [team_color[0], team_color[1], team_color[2], 1.0],
             );
 
             // Show "End Turn" button for player turns
-            if is_player_turn {
-                renderer.text_renderer.render_text(
-                    "[SPACE] End Turn",
-                    SCREEN_WIDTH - 200.0,
-                    SCREEN_HEIGHT - 30.0,
+            if let Some(key) = self
+                .settings
+                .key_bindings
+                .key(Action::EndTurn)
+                .filter(|_| is_player_turn)
+            {
+                list.text(
+                    format!("[{}] End Turn", settings::key_label(key).to_uppercase()),
+                    screen_width - 200.0,
+                    screen_height - 30.0,
                     0.4,
                     [0.8, 1.0, 0.8, 1.0],
-                    SCREEN_WIDTH,
-                    SCREEN_HEIGHT,
                 );
             }
+            renderer.backend.submit(&list);
+        }
+    }
+
+    /// Renders the hot-seat "pass the device" screen in place of the board.
+    fn render_hot_seat_handoff(&mut self) {
+        let Some(team) = self.game_state.hot_seat.handoff_team() else {
+            return;
+        };
+        let team_name = self.game_world.teams.name(team).to_string();
+        let team_color = self.game_world.teams.color(team);
+        let prompt = self.hot_seat_prompt();
+        if let Some(renderer) = &mut self.renderer {
+            renderer.backend.clear(MENU_BACKGROUND);
+
+            let mut list = DrawList::new();
+            let title = format!("Pass the device to {}", team_name);
+            let (screen_width, screen_height) = self.window_size;
+            let centered_x = |text: &str, size: f32| (screen_width - text_width(text, size)) / 2.0;
+            list.text(
+                &title,
+                centered_x(&title, 24.0),
+                screen_height / 2.0 - 40.0,
+                24.0,
+                [team_color[0], team_color[1], team_color[2], 1.0],
+            );
+            list.text(
+                &prompt,
+                centered_x(&prompt, 12.0),
+                screen_height / 2.0 + 20.0,
+                12.0,
+                [0.9, 0.9, 1.0, 1.0],
+            );
+            renderer.backend.submit(&list);
         }
     }
 
@@ -1530,6 +3078,7 @@ impl GameApp {
         // Clear existing unit, structure, and item sprites (keep terrain)
         for hex in self.hex_grid.hexagons.values_mut() {
             hex.set_unit_sprite(None);
+            hex.set_unit_tint(None);
             hex.set_structure_sprite(None);
             hex.set_item_sprite(None);
         }
@@ -1553,11 +3102,27 @@ impl GameApp {
             self.hex_grid.set_structure_at(pos, sprite);
         }
 
-        // Query ScenarioWorld for current unit positions
+        // In hot-seat and networked games the viewing team only sees what its units can see
+        let viewing_team = match &self.network {
+            Some(session) => session.local_teams().first().copied(),
+            None => self.game_state.hot_seat.viewing_team(),
+        };
+        self.fog_visible = viewing_team.map(|team| self.game_world.visible_hexes(team));
+        match &self.fog_visible {
+            Some(visible) => self.hex_grid.set_fog(visible),
+            None => self.hex_grid.clear_fog(),
+        }
+
+        // Query ScenarioWorld for current unit positions, tinted with their team color
         for unit in self.game_world.units.values() {
             let pos = unit.position();
-            let sprite = unit.unit().sprite();
+            if !self.is_hex_revealed(pos) {
+                continue;
+            }
+            let sprite = unit_sprite(unit.unit());
             self.hex_grid.set_unit_at(pos, sprite);
+            self.hex_grid
+                .set_unit_tint_at(pos, self.game_world.teams.color(unit.team()));
         }
 
         // Add items on the ground
@@ -1570,8 +3135,8 @@ impl GameApp {
     /// Updates the encyclopedia panel content based on the current category
     fn update_encyclopedia_content(&mut self) {
         use crate::encyclopedia_builder::{
-            get_mechanics_content_comprehensive, get_terrain_content_comprehensive,
-            get_units_content_comprehensive,
+            get_items_content_comprehensive, get_mechanics_content_comprehensive,
+            get_terrain_content_comprehensive, get_units_content_comprehensive,
         };
 
         let current_category = if let Some(panel) = &self.encyclopedia_panel {
@@ -1586,6 +3151,7 @@ impl GameApp {
             EncyclopediaCategory::Mechanics => {
                 get_mechanics_content_comprehensive(&self.encyclopedia)
             }
+            EncyclopediaCategory::Items => get_items_content_comprehensive(&self.encyclopedia),
         };
 
         if let Some(panel) = &mut self.encyclopedia_panel {
@@ -1730,6 +3296,7 @@ impl GameApp {
                             .map(|attack| AttackDisplayInfo {
                                 name: attack.name,
                                 damage: attack.damage,
+                                damage_type: attack.damage_type.name().to_string(),
                                 description: attack.description,
                             })
                             .collect();
@@ -1762,9 +3329,15 @@ impl GameApp {
                             position_r: position.r,
                             moves_left: game_unit.moves_left() as u32,
                             max_moves: stats.movement_speed as u32,
-                            sprite_type: unit.sprite(),
+                            sprite_type: unit_sprite(unit),
                             attacks,
                             inventory,
+                            resistances: stats
+                                .resistances
+                                .iter()
+                                .filter(|(_, value)| *value != 0)
+                                .map(|(damage_type, value)| (damage_type.name().to_string(), value))
+                                .collect(),
                         };
 
                         if let Some(ui_panel) = &mut self.ui_panel {
@@ -1784,7 +3357,7 @@ impl GameApp {
             }
 
             // Debug highlighting (optional - can be toggled with 'H' key)
-            if self.hower_debug_enabled {
+            if self.settings.hover_debug {
                 // Clear previous debug highlighting
                 if let Some(prev_hex) = self.hower_debug_hex {
                     if let Some(hex) = self.hex_grid.hexagons.get_mut(&prev_hex) {
@@ -1836,12 +3409,19 @@ impl ApplicationHandler for GameApp {
     /// 7. Loads guide encyclopedia entries
     /// 8. Prints control instructions to console
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        // Window size from the settings; fullscreen reports its size on the first resize
+        let (screen_width, screen_height) = self.window_size;
         let window_attributes = winit::window::WindowAttributes::default()
             .with_title("QuestQuest - Interactive Game Window")
             .with_inner_size(winit::dpi::LogicalSize::new(
-                SCREEN_WIDTH as u32,
-                SCREEN_HEIGHT as u32,
-            ));
+                screen_width as u32,
+                screen_height as u32,
+            ))
+            .with_fullscreen(
+                self.settings
+                    .fullscreen
+                    .then_some(winit::window::Fullscreen::Borderless(None)),
+            );
 
         let template = glutin::config::ConfigTemplateBuilder::new().with_depth_size(24); // Request 24-bit depth buffer for proper layering
         let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
@@ -1873,8 +3453,8 @@ impl ApplicationHandler for GameApp {
 
         let surface_attributes = SurfaceAttributesBuilder::<WindowSurface>::new().build(
             window.window_handle().unwrap().into(),
-            std::num::NonZeroU32::new(SCREEN_WIDTH as u32).unwrap(),
-            std::num::NonZeroU32::new(SCREEN_HEIGHT as u32).unwrap(),
+            NonZeroU32::new(screen_width as u32).unwrap(),
+            NonZeroU32::new(screen_height as u32).unwrap(),
         );
 
         let gl_surface = unsafe {
@@ -1896,8 +3476,7 @@ impl ApplicationHandler for GameApp {
         });
 
         unsafe {
-            gl::Viewport(0, 0, SCREEN_WIDTH as i32, SCREEN_HEIGHT as i32);
-            gl::ClearColor(0.05, 0.05, 0.1, 1.0);
+            gl::Viewport(0, 0, screen_width as i32, screen_height as i32);
         }
 
         let (vao, shader_program, dynamic_vbo) = unsafe { setup_dynamic_hexagons() };
@@ -1905,45 +3484,48 @@ impl ApplicationHandler for GameApp {
             vao,
             shader_program,
             dynamic_vbo,
-            SCREEN_WIDTH,
-            SCREEN_HEIGHT,
+            screen_width,
+            screen_height,
         ) {
             Ok(renderer) => {
                 self.renderer = Some(renderer);
 
                 // Initialize UI panel
-                match UiPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT) {
-                    Ok(ui_panel) => {
-                        self.ui_panel = Some(ui_panel);
-                        println!("✅ UI Panel initialized!");
-
-                        // Create a separate text renderer for the main menu
-                        match graphics::ui::text_renderer::TextRenderer::new() {
-                            Ok(text_renderer) => {
-                                let shared_renderer =
-                                    std::rc::Rc::new(std::cell::RefCell::new(text_renderer));
-                                self.main_menu_scene.set_text_renderer(shared_renderer);
-                            }
-                            Err(e) => {
-                                println!("Failed to create text renderer for main menu: {}", e);
-                            }
-                        }
+                self.ui_panel = Some(UiPanel::new(screen_width, screen_height));
+                println!("✅ UI Panel initialized!");
+
+                // Create a separate draw backend for the main menu
+                match graphics::GlBackend::new(screen_width, screen_height) {
+                    Ok(backend) => {
+                        let shared_backend = std::rc::Rc::new(std::cell::RefCell::new(backend));
+                        self.main_menu_scene
+                            .set_draw_backend(shared_backend.clone());
+                        self.settings_scene.set_draw_backend(shared_backend);
                     }
                     Err(e) => {
-                        println!("⚠️  Failed to create UI panel: {}", e);
+                        println!("Failed to create draw backend for main menu: {}", e);
                     }
                 }
 
                 // Initialize Encyclopedia panel
-                match EncyclopediaPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT) {
-                    Ok(panel) => {
-                        self.encyclopedia_panel = Some(panel);
-                        println!("✅ Encyclopedia Panel initialized!");
-                    }
-                    Err(e) => {
-                        println!("⚠️  Failed to create Encyclopedia panel: {}", e);
-                    }
-                }
+                self.encyclopedia_panel = Some(EncyclopediaPanel::new(screen_width, screen_height));
+                println!("✅ Encyclopedia Panel initialized!");
+
+                // Initialize AI inspector panel
+                self.ai_inspector_panel = Some(AiInspectorPanel::new(screen_width, screen_height));
+                println!("✅ AI Inspector Panel initialized!");
+
+                // Initialize evolution choice panel
+                self.evolution_panel = Some(EvolutionPanel::new(screen_width, screen_height));
+                println!("✅ Evolution Panel initialized!");
+
+                // Initialize use-item panel
+                self.item_use_panel = Some(ItemUsePanel::new(screen_width, screen_height));
+                println!("✅ Item Use Panel initialized!");
+
+                // Initialize inventory panel
+                self.inventory_panel = Some(InventoryPanel::new(screen_width, screen_height));
+                println!("✅ Inventory Panel initialized!");
 
                 // Populate hex grid with terrain (for game scene)
                 // This is radius 8 for the game world
@@ -1961,6 +3543,9 @@ impl ApplicationHandler for GameApp {
                     }
                 }
 
+                // Draw the panels at the configured UI scale
+                self.apply_ui_scale();
+
                 println!("🎮 QuestQuest Started!");
                 println!("� Showing Main Menu...");
                 println!();
@@ -2010,12 +3595,10 @@ impl ApplicationHandler for GameApp {
             let current = self.scene_manager.current_scene();
 
             match current {
-                SceneType::Game => {
-                    // Initialize game scene if not already done
-                    if !self.game_initialized {
-                        self.initialize_game_scene();
-                        self.game_initialized = true;
-                    }
+                // Initialize game scene if not already done
+                SceneType::Game if !self.game_initialized => {
+                    self.initialize_game_scene();
+                    self.game_initialized = true;
                 }
                 SceneType::MainMenu => {
                     // Return to main menu
@@ -2041,9 +3624,16 @@ impl ApplicationHandler for GameApp {
             WindowEvent::CloseRequested => {
                 event_loop.exit();
             }
+            WindowEvent::Resized(size) => {
+                self.resize(size.width, size.height);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
             WindowEvent::CursorMoved { position, .. } => {
                 // Store cursor position for click handling
                 self.cursor_position = (position.x, position.y);
+                self.cursor_in_window = true;
 
                 // Handle cursor movement based on current scene
                 match self.scene_manager.current_scene() {
@@ -2051,6 +3641,10 @@ impl ApplicationHandler for GameApp {
                         self.main_menu_scene
                             .handle_cursor_move(position.x, position.y);
                     }
+                    SceneType::Settings => {
+                        self.settings_scene
+                            .handle_cursor_move(position.x, position.y);
+                    }
                     SceneType::Game => {
                         // Check combat state first to avoid borrow conflicts
                         let has_combat = self.has_pending_combat();
@@ -2073,6 +3667,18 @@ impl ApplicationHandler for GameApp {
                             }
                         }
 
+                        // Highlight panel buttons and dialog options under the cursor
+                        self.update_panel_hover(position.x as f32, position.y as f32);
+
+                        // Dragging on the minimap pans the camera
+                        if let Some(renderer) = &mut self.renderer {
+                            renderer.minimap.drag(
+                                position.x as f32,
+                                position.y as f32,
+                                &mut self.hex_grid,
+                            );
+                        }
+
                         // hower DEBUG: Highlight hex under cursor
                         self.hower(position.x, position.y);
                     }
@@ -2084,6 +3690,34 @@ impl ApplicationHandler for GameApp {
                     window.request_redraw();
                 }
             }
+            WindowEvent::MouseInput {
+                state: ElementState::Released,
+                button: MouseButton::Left,
+                ..
+            } => {
+                if let Some(renderer) = &mut self.renderer {
+                    renderer.minimap.release();
+                }
+                if let Some(ui_panel) = &mut self.ui_panel {
+                    let (x, y) = self.cursor_position;
+                    ui_panel.release(x as f32, y as f32);
+                }
+            }
+            WindowEvent::CursorEntered { .. } => {
+                self.cursor_in_window = true;
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor_in_window = false;
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                if matches!(self.scene_manager.current_scene(), SceneType::Game) {
+                    self.handle_mouse_wheel(delta);
+                }
+
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
             WindowEvent::MouseInput {
                 state: ElementState::Pressed,
                 button,
@@ -2099,11 +3733,30 @@ impl ApplicationHandler for GameApp {
                             self.cursor_position.1,
                             is_left,
                         ) {
+                            if new_scene == SceneType::Settings {
+                                self.settings_scene
+                                    .open(self.settings.clone(), SceneType::MainMenu);
+                            }
+                            self.scene_manager.transition_to(new_scene);
+                        }
+                    }
+                    SceneType::Settings => {
+                        let new_scene = self.settings_scene.handle_click(
+                            self.cursor_position.0,
+                            self.cursor_position.1,
+                            is_left,
+                        );
+                        self.apply_settings_changes();
+                        if let Some(new_scene) = new_scene {
                             self.scene_manager.transition_to(new_scene);
                         }
                     }
                     SceneType::Game => match button {
                         MouseButton::Left => {
+                            if let Some(ui_panel) = &mut self.ui_panel {
+                                let (x, y) = self.cursor_position;
+                                ui_panel.press(x as f32, y as f32);
+                            }
                             self.handle_left_click(self.cursor_position.0, self.cursor_position.1);
                         }
                         MouseButton::Right => {
@@ -2118,27 +3771,35 @@ impl ApplicationHandler for GameApp {
                     window.request_redraw();
                 }
             }
-            WindowEvent::KeyboardInput { event, .. } => {
-                if event.state == winit::event::ElementState::Pressed {
-                    // Handle keyboard input based on current scene
-                    match self.scene_manager.current_scene() {
-                        SceneType::MainMenu => {
-                            if let winit::keyboard::PhysicalKey::Code(key_code) = event.physical_key
-                            {
-                                if let Some(new_scene) = self.main_menu_scene.handle_key(key_code) {
-                                    self.scene_manager.transition_to(new_scene);
-                                }
+            WindowEvent::KeyboardInput { event, .. }
+                if event.state == winit::event::ElementState::Pressed =>
+            {
+                // Handle keyboard input based on current scene
+                match self.scene_manager.current_scene() {
+                    SceneType::MainMenu => {
+                        if let winit::keyboard::PhysicalKey::Code(key_code) = event.physical_key {
+                            if let Some(new_scene) = self.main_menu_scene.handle_key(key_code) {
+                                self.scene_manager.transition_to(new_scene);
                             }
                         }
-                        SceneType::Game => {
-                            self.handle_game_keyboard_input(event.physical_key);
+                    }
+                    SceneType::Settings => {
+                        if let winit::keyboard::PhysicalKey::Code(key_code) = event.physical_key {
+                            let new_scene = self.settings_scene.handle_key(key_code);
+                            self.apply_settings_changes();
+                            if let Some(new_scene) = new_scene {
+                                self.scene_manager.transition_to(new_scene);
+                            }
                         }
-                        _ => {}
                     }
-
-                    if let Some(window) = &self.window {
-                        window.request_redraw();
+                    SceneType::Game => {
+                        self.handle_game_keyboard_input(event.physical_key);
                     }
+                    _ => {}
+                }
+
+                if let Some(window) = &self.window {
+                    window.request_redraw();
                 }
             }
             WindowEvent::RedrawRequested => {
@@ -2147,6 +3808,9 @@ impl ApplicationHandler for GameApp {
                 let delta_time = (now - self.last_update_time).as_secs_f32();
                 self.last_update_time = now;
 
+                // Keep talking to the other player whatever scene is shown
+                self.poll_network();
+
                 // Render based on current scene
                 match self.scene_manager.current_scene() {
                     SceneType::MainMenu => {
@@ -2159,20 +3823,39 @@ impl ApplicationHandler for GameApp {
                             gl_surface.swap_buffers(gl_context).unwrap();
                         }
                     }
+                    SceneType::Settings => {
+                        self.settings_scene.render();
+
+                        if let (Some(gl_context), Some(gl_surface)) =
+                            (&self.gl_context, &self.gl_surface)
+                        {
+                            gl_surface.swap_buffers(gl_context).unwrap();
+                        }
+                    }
                     SceneType::Game => {
                         // Update movement animation
                         self.update_animation(delta_time);
 
+                        // Edge scrolling and smooth panning
+                        self.update_camera(delta_time);
+
                         // AI execution: Delegate to ScenarioWorld (which uses AI crate)
                         // QuestApp only tracks which team had AI run to avoid duplicate execution
                         let current_team = self.game_world.current_turn_team();
                         if !self.game_world.is_current_team_player_controlled() {
                             // Check if this is a new AI turn (team changed or first AI turn)
                             if self.last_ai_turn_team != Some(current_team) {
-                                println!("🤖 AI executing for team {:?}", current_team);
+                                println!(
+                                    "🤖 AI executing for team {}",
+                                    self.game_world.teams.name(current_team)
+                                );
                                 // Delegate to ScenarioWorld which uses AI crate for planning
                                 self.game_world.run_ai_for_current_team();
                                 self.last_ai_turn_team = Some(current_team);
+
+                                if self.game_state.ai_inspector.is_visible() {
+                                    self.update_ai_inspector_highlights();
+                                }
                             }
                         } else {
                             // Reset tracker when it's a player turn
@@ -2183,16 +3866,33 @@ impl ApplicationHandler for GameApp {
                         // ScenarioWorld handles turn advancement, AI action processing
                         self.game_world.update(delta_time);
 
+                        // React to what happened this frame
+                        self.process_game_events(delta_time);
+
+                        // Hide the board while the device changes hands
+                        self.sync_hot_seat();
+                        if self.game_state.hot_seat.is_board_hidden() {
+                            self.render_hot_seat_handoff();
+                            if let (Some(gl_context), Some(gl_surface)) =
+                                (&self.gl_context, &self.gl_surface)
+                            {
+                                gl_surface.swap_buffers(gl_context).unwrap();
+                            }
+                            return;
+                        }
+
                         // Update unit positions on hex grid before rendering
                         self.update_hex_grid_units();
 
                         // Render all game layers and UI elements
+                        let time_of_day = self.time_of_day_display();
                         if let Some(renderer) = &mut self.renderer {
                             renderer.render(&self.hex_grid);
 
                             // Render UI panel
                             if let Some(ui_panel) = &mut self.ui_panel {
-                                ui_panel.render(SCREEN_WIDTH, SCREEN_HEIGHT, renderer);
+                                ui_panel.set_time_of_day(time_of_day);
+                                ui_panel.render(&mut renderer.backend);
                             }
                         }
 
@@ -2201,8 +3901,21 @@ impl ApplicationHandler for GameApp {
 
                         // Render encyclopedia panel if visible
                         if self.encyclopedia_visible() {
-                            if let Some(encyclopedia_panel) = &mut self.encyclopedia_panel {
-                                encyclopedia_panel.render(SCREEN_WIDTH, SCREEN_HEIGHT);
+                            if let (Some(renderer), Some(encyclopedia_panel)) =
+                                (&mut self.renderer, &self.encyclopedia_panel)
+                            {
+                                encyclopedia_panel.render(&mut renderer.backend);
+                            }
+                        }
+
+                        // Render AI inspector if visible
+                        if self.game_state.ai_inspector.is_visible() {
+                            let lines = self.ai_inspector_lines();
+                            if let (Some(renderer), Some(panel)) =
+                                (&mut self.renderer, &mut self.ai_inspector_panel)
+                            {
+                                panel.set_lines(&lines);
+                                panel.render(&mut renderer.backend);
                             }
                         }
 
@@ -2213,18 +3926,21 @@ impl ApplicationHandler for GameApp {
                                 if renderer.submenu_panel.is_none() {
                                     let submenu_x = self.cursor_position.0 as f32;
                                     let submenu_y = self.cursor_position.1 as f32;
-                                    if let Ok(panel) = graphics::SubmenuPanel::new(
+                                    let (screen_width, screen_height) = self.window_size;
+                                    let mut panel = graphics::SubmenuPanel::new(
                                         submenu_x,
                                         submenu_y,
                                         vec!["Recruit".to_string()],
-                                    ) {
-                                        renderer.submenu_panel = Some(panel);
-                                    }
+                                        screen_width,
+                                        screen_height,
+                                    );
+                                    panel.set_ui_scale(self.settings.ui_scale);
+                                    renderer.submenu_panel = Some(panel);
                                 }
 
                                 // Render the submenu
-                                if let Some(submenu) = &mut renderer.submenu_panel {
-                                    submenu.render(SCREEN_WIDTH, SCREEN_HEIGHT);
+                                if let Some(submenu) = &renderer.submenu_panel {
+                                    submenu.render(&mut renderer.backend);
                                 }
                             }
                         } else if let Some(renderer) = &mut self.renderer {
@@ -2237,25 +3953,19 @@ impl ApplicationHandler for GameApp {
                             if let Some(renderer) = &mut self.renderer {
                                 // Create recruitment panel if it doesn't exist
                                 if renderer.recruitment_panel.is_none() {
-                                    if let Ok(panel) =
-                                        graphics::RecruitmentPanel::new(SCREEN_WIDTH, SCREEN_HEIGHT)
-                                    {
-                                        renderer.recruitment_panel = Some(panel);
-                                    }
+                                    let (screen_width, screen_height) = self.window_size;
+                                    let mut panel = graphics::RecruitmentPanel::new(
+                                        screen_width,
+                                        screen_height,
+                                    );
+                                    panel.set_units(&RECRUITABLE_UNITS.map(|(name, _)| name));
+                                    panel.set_ui_scale(self.settings.ui_scale);
+                                    renderer.recruitment_panel = Some(panel);
                                 }
 
                                 // Render the recruitment panel
-                                if let Some(panel) = &mut renderer.recruitment_panel {
-                                    let human_units = [
-                                        ("Human Noble", 0),
-                                        ("Human Squire", 1),
-                                        ("Human Knight", 2),
-                                        ("Human Prince", 3),
-                                        ("Human Grand Knight", 4),
-                                        ("Human King", 5),
-                                        ("Human Knight Commander", 6),
-                                    ];
-                                    panel.render(SCREEN_WIDTH, SCREEN_HEIGHT, &human_units);
+                                if let Some(panel) = &renderer.recruitment_panel {
+                                    panel.render(&mut renderer.backend);
                                 }
                             }
                         } else if let Some(renderer) = &mut self.renderer {
@@ -2263,6 +3973,40 @@ impl ApplicationHandler for GameApp {
                             renderer.recruitment_panel = None;
                         }
 
+                        // Render the use-item panel while it is open
+                        if let Some((unit_name, options)) = self.use_item_dialog_content() {
+                            let targeting = self.game_state.use_item.chosen_slot().is_some();
+                            if let (Some(renderer), Some(panel)) =
+                                (&mut self.renderer, &mut self.item_use_panel)
+                            {
+                                panel.set_options(&unit_name, &options, targeting);
+                                panel.render(&mut renderer.backend);
+                            }
+                        }
+
+                        // Render the inventory screen while it is open
+                        if let Some((unit_name, entries, details)) = self.inventory_dialog_content()
+                        {
+                            let selected = self.game_state.inventory.selected();
+                            let giving = self.game_state.inventory.is_giving();
+                            if let (Some(renderer), Some(panel)) =
+                                (&mut self.renderer, &mut self.inventory_panel)
+                            {
+                                panel.set_items(&unit_name, &entries, selected, &details, giving);
+                                panel.render(&mut renderer.backend);
+                            }
+                        }
+
+                        // Render evolution choice on top of everything else
+                        if let Some((unit_name, options)) = self.evolution_dialog_content() {
+                            if let (Some(renderer), Some(panel)) =
+                                (&mut self.renderer, &mut self.evolution_panel)
+                            {
+                                panel.set_options(&unit_name, &options);
+                                panel.render(&mut renderer.backend);
+                            }
+                        }
+
                         if let (Some(gl_context), Some(gl_surface)) =
                             (&self.gl_context, &self.gl_surface)
                         {
@@ -2271,9 +4015,8 @@ impl ApplicationHandler for GameApp {
                     }
                     _ => {
                         // Other scenes (Settings, SavedGames) - just clear for now
-                        unsafe {
-                            gl::ClearColor(0.1, 0.1, 0.15, 1.0);
-                            gl::Clear(gl::COLOR_BUFFER_BIT);
+                        if let Some(renderer) = &mut self.renderer {
+                            renderer.backend.clear(MENU_BACKGROUND);
                         }
 
                         if let (Some(gl_context), Some(gl_surface)) =
@@ -2301,21 +4044,32 @@ impl ApplicationHandler for GameApp {
 /// - **Left Click**: Move units, interact with UI
 /// - **Right Click**: Select units
 /// - **Arrow Keys**: Move camera (scroll encyclopedia when open)
+/// - **Mouse Wheel**: Zoom around the cursor
+/// - **F**: Center the camera on the selected unit
 /// - **E**: Toggle encyclopedia wiki
-/// - **1/2/3**: Switch encyclopedia categories when open
+/// - **1/2/3/4**: Switch encyclopedia categories when open
 /// - **C**: Show unit info
 /// - **H**: Toggle hover debug
-/// - **SPACE**: End turn
+/// - **SPACE**: End turn (or reveal the board on the hot-seat handoff screen)
 /// - **ESC**: Close encyclopedia/menu, deselect unit
 ///
 /// # Panics
 ///
 /// Panics if the event loop or window creation fails.
 fn main() {
+    // RUST_LOG filters the output, e.g. RUST_LOG=ai::trace=debug for AI traces
+    env_logger::init();
+
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
     let mut app = GameApp::new();
+    if let Some(launch) = NetLaunch::from_args(std::env::args().skip(1)) {
+        if let Err(e) = app.start_network(launch) {
+            eprintln!("❌ Could not start the networked game: {}", e);
+            std::process::exit(1);
+        }
+    }
 
     println!("🎮 Starting QuestQuest Interactive Game Window...");
     println!("📚 Press E to open the Encyclopedia at any time!");
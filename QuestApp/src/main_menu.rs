@@ -1,24 +1,28 @@
 //! Main Menu Scene
 //!
 //! The initial scene shown when the game starts, providing options to start
-//! a scenario, load saved games, adjust settings, or exit.
+//! a scenario (alone or hot-seat), load saved games, adjust settings, or exit.
 
 use crate::scene_manager::{Scene, SceneType};
-use graphics::ui::text_renderer::TextRenderer;
+use graphics::draw::{Color, DrawBackend, DrawList, GlBackend};
+use graphics::ui::{Align, Anchor, ButtonStyle, Length, Ui, Widget, WidgetId};
 use std::cell::RefCell;
 use std::rc::Rc;
 
-/// A clickable button on the main menu
-#[derive(Clone)]
-struct MenuButton {
-    /// Display label
-    label: String,
-    /// Position (center x, center y)
-    position: (f32, f32),
-    /// Size (width, height)
-    size: (f32, f32),
-    /// Action to perform when clicked
-    action: MenuButtonAction,
+/// One of the menu buttons, indexed by its position in the menu
+/// Background color of the menus and other full-screen scenes
+pub const MENU_BACKGROUND: Color = [0.1, 0.1, 0.15, 1.0];
+
+const MENU_BUTTON: WidgetId = WidgetId::new("main_menu_button");
+
+/// How the scenario started from the main menu is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// One human against the scenario's AI teams
+    #[default]
+    SinglePlayer,
+    /// Every fighting team is a human sharing this device
+    HotSeat,
 }
 
 /// Actions that menu buttons can trigger
@@ -26,6 +30,8 @@ struct MenuButton {
 enum MenuButtonAction {
     /// Load the game scenario
     Scenarios,
+    /// Load the game scenario in hot-seat mode
+    HotSeat,
     /// Show saved games screen
     SavedGames,
     /// Open settings menu
@@ -34,168 +40,113 @@ enum MenuButtonAction {
     Exit,
 }
 
-impl MenuButton {
-    /// Create a new menu button
-    fn new(label: impl Into<String>, action: MenuButtonAction, position: (f32, f32)) -> Self {
-        Self {
-            label: label.into(),
-            position,
-            size: (300.0, 60.0), // Default button size
-            action,
-        }
-    }
-
-    /// Check if a point is inside this button
-    fn contains(&self, x: f32, y: f32) -> bool {
-        let half_width = self.size.0 / 2.0;
-        let half_height = self.size.1 / 2.0;
-
-        x >= self.position.0 - half_width
-            && x <= self.position.0 + half_width
-            && y >= self.position.1 - half_height
-            && y <= self.position.1 + half_height
-    }
-}
+/// Menu buttons from top to bottom
+const BUTTONS: [(&str, MenuButtonAction); 5] = [
+    ("Scenarios", MenuButtonAction::Scenarios),
+    ("Hot-Seat", MenuButtonAction::HotSeat),
+    ("Saved Games", MenuButtonAction::SavedGames),
+    ("Settings", MenuButtonAction::Settings),
+    ("Exit", MenuButtonAction::Exit),
+];
 
 /// Main Menu Scene
 pub struct MainMenuScene {
-    /// Menu buttons
-    buttons: Vec<MenuButton>,
+    /// Title and buttons, centered on the screen
+    ui: Ui,
 
-    /// Screen dimensions
-    screen_width: f32,
-    screen_height: f32,
+    /// Backend drawing the menu into the window
+    draw_backend: Option<Rc<RefCell<GlBackend>>>,
 
-    /// Text renderer for drawing UI elements
-    text_renderer: Option<Rc<RefCell<TextRenderer>>>,
+    /// Mode of the last scenario started from this menu
+    game_mode: GameMode,
 }
 
 impl MainMenuScene {
     /// Create a new main menu scene
     pub fn new(screen_width: f32, screen_height: f32) -> Self {
-        let center_x = screen_width / 2.0;
-        let start_y = screen_height / 2.0 - 150.0;
-        let button_spacing = 80.0;
-
-        let buttons = vec![
-            MenuButton::new(
-                "Scenarios",
-                MenuButtonAction::Scenarios,
-                (center_x, start_y),
-            ),
-            MenuButton::new(
-                "Saved Games",
-                MenuButtonAction::SavedGames,
-                (center_x, start_y + button_spacing),
-            ),
-            MenuButton::new(
-                "Settings",
-                MenuButtonAction::Settings,
-                (center_x, start_y + button_spacing * 2.0),
-            ),
-            MenuButton::new(
-                "Exit",
-                MenuButtonAction::Exit,
-                (center_x, start_y + button_spacing * 3.0),
-            ),
-        ];
-
         Self {
-            buttons,
-            screen_width,
-            screen_height,
-            text_renderer: None,
+            ui: Ui::new(Self::build(), screen_width, screen_height),
+            draw_backend: None,
+            game_mode: GameMode::default(),
         }
     }
 
-    /// Set the text renderer for this scene
-    pub fn set_text_renderer(&mut self, text_renderer: Rc<RefCell<TextRenderer>>) {
-        self.text_renderer = Some(text_renderer);
+    /// Title above a column of 300x60 buttons
+    fn build() -> Widget {
+        let style = ButtonStyle {
+            background: [0.18, 0.18, 0.26, 1.0],
+            hovered: [0.26, 0.28, 0.4, 1.0],
+            pressed: [0.14, 0.14, 0.2, 1.0],
+            text: [0.9, 0.9, 1.0, 1.0],
+            hovered_text: [1.0, 1.0, 1.0, 1.0],
+            border: Some((2.0, [0.35, 0.35, 0.5, 1.0])),
+        };
+        let buttons = BUTTONS.iter().enumerate().map(|(index, (label, _))| {
+            Widget::button(
+                WidgetId::indexed(MENU_BUTTON.name, index),
+                *label,
+                12.0,
+                style,
+            )
+            .size(300.0, 60.0)
+        });
+
+        Widget::column(20.0)
+            .anchor(Anchor::Center)
+            .align(Align::Center)
+            .child(
+                Widget::label("QuestQuest", 24.0, [1.0, 1.0, 1.0, 1.0]).height(Length::Fixed(80.0)),
+            )
+            .children(buttons)
     }
 
-    /// Update screen dimensions (e.g., on window resize)
-    #[allow(dead_code)]
-    pub fn update_screen_size(&mut self, width: f32, height: f32) {
-        self.screen_width = width;
-        self.screen_height = height;
+    /// Mode of the last scenario started from this menu
+    pub fn game_mode(&self) -> GameMode {
+        self.game_mode
+    }
 
-        // Recenter buttons
-        let center_x = width / 2.0;
-        let start_y = height / 2.0 - 150.0;
-        let button_spacing = 80.0;
+    /// Set the backend this scene draws with
+    pub fn set_draw_backend(&mut self, draw_backend: Rc<RefCell<GlBackend>>) {
+        self.draw_backend = Some(draw_backend);
+    }
 
-        for (i, button) in self.buttons.iter_mut().enumerate() {
-            button.position = (center_x, start_y + button_spacing * i as f32);
+    /// Update screen dimensions (e.g., on window resize)
+    pub fn update_screen_size(&mut self, width: f32, height: f32) {
+        self.ui.resize(width, height);
+        if let Some(draw_backend) = &self.draw_backend {
+            draw_backend.borrow_mut().set_screen_size(width, height);
         }
     }
 
+    /// Draw the menu `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+    }
+
     /// Check which button was clicked, if any
     fn check_button_click(&self, x: f32, y: f32) -> Option<MenuButtonAction> {
-        for button in &self.buttons {
-            if button.contains(x, y) {
-                return Some(button.action);
-            }
-        }
-        None
+        self.ui
+            .hit_test(x, y)
+            .filter(|id| id.name == MENU_BUTTON.name)
+            .and_then(|id| BUTTONS.get(id.index))
+            .map(|(_, action)| *action)
     }
 
     /// Render the main menu
     pub fn render_menu(&mut self) {
-        if let Some(text_renderer) = &self.text_renderer {
-            let mut renderer = text_renderer.borrow_mut();
-
-            unsafe {
-                // Clear background
-                gl::ClearColor(0.1, 0.1, 0.15, 1.0);
-                gl::Clear(gl::COLOR_BUFFER_BIT);
-            }
-
-            // Font aspect ratio (5x7 bitmap font)
-            let font_aspect = 5.0 / 7.0;
-
-            // Title
-            let title = "QuestQuest";
-            let title_size = 24.0; // Character height in pixels
-            let title_y = self.screen_height / 2.0 - 280.0;
-
-            // Calculate title width and center it
-            let title_char_width = title_size * font_aspect;
-            let title_width = title.len() as f32 * title_char_width;
-            let title_x = (self.screen_width - title_width) / 2.0;
-
-            renderer.render_text(
-                title,
-                title_x,
-                title_y,
-                title_size,
-                [1.0, 1.0, 1.0, 1.0],
-                self.screen_width,
-                self.screen_height,
-            );
-
-            // Render buttons
-            for button in &self.buttons {
-                // Button text - centered at button position
-                let button_size = 12.0; // Character height in pixels
-                let button_char_width = button_size * font_aspect;
-                let button_text_width = button.label.len() as f32 * button_char_width;
-
-                // Center text at button position
-                let text_x = button.position.0 - (button_text_width / 2.0);
-                let text_y = button.position.1 - (button_size / 2.0); // Center vertically too
-
-                renderer.render_text(
-                    &button.label,
-                    text_x,
-                    text_y,
-                    button_size,
-                    [0.9, 0.9, 1.0, 1.0], // Slightly blue-tinted white
-                    self.screen_width,
-                    self.screen_height,
-                );
-            }
+        if let Some(draw_backend) = &self.draw_backend {
+            let mut list = DrawList::new();
+            self.draw(&mut list);
+            let mut draw_backend = draw_backend.borrow_mut();
+            draw_backend.clear(MENU_BACKGROUND);
+            draw_backend.submit(&list);
         }
     }
+
+    /// Appends the menu title and buttons
+    pub fn draw(&self, list: &mut DrawList) {
+        self.ui.draw(list);
+    }
 }
 
 impl Scene for MainMenuScene {
@@ -226,6 +177,12 @@ impl Scene for MainMenuScene {
             match action {
                 MenuButtonAction::Scenarios => {
                     println!("🎮 Loading game scenario...");
+                    self.game_mode = GameMode::SinglePlayer;
+                    return Some(SceneType::Game);
+                }
+                MenuButtonAction::HotSeat => {
+                    println!("🎮 Loading game scenario in hot-seat mode...");
+                    self.game_mode = GameMode::HotSeat;
                     return Some(SceneType::Game);
                 }
                 MenuButtonAction::SavedGames => {
@@ -258,7 +215,7 @@ impl Scene for MainMenuScene {
         }
     }
 
-    fn handle_cursor_move(&mut self, _x: f64, _y: f64) {
-        // Could add hover effects here later
+    fn handle_cursor_move(&mut self, x: f64, y: f64) {
+        self.ui.cursor_moved(x as f32, y as f32);
     }
 }
@@ -0,0 +1,393 @@
+//! Settings
+//!
+//! Player configuration persisted as JSON in the user's config directory:
+//! window resolution, animation and AI playback speed, the AI mode, attack
+//! confirmation, UI scale, debug overlays and key bindings.
+//!
+//! Fields missing from the file keep their defaults, so files written by
+//! older versions still load.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use winit::keyboard::KeyCode;
+
+/// Window sizes offered by the settings screen
+pub const RESOLUTIONS: [(u32, u32); 5] = [
+    (1280, 720),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+    (3840, 2160),
+];
+
+/// Unit movement animation speeds in hexes per second
+pub const ANIMATION_SPEEDS: [f32; 5] = [2.0, 4.0, 6.0, 8.0, 12.0];
+
+/// Seconds an AI team waits before playing its turn
+pub const AI_TURN_DELAYS: [f32; 6] = [0.0, 0.5, 1.0, 2.0, 3.0, 5.0];
+
+/// UI scale factors offered by the settings screen
+pub const UI_SCALES: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Smallest window the game lays out for
+const MIN_RESOLUTION: (u32, u32) = (640, 480);
+
+/// Largest window the game lays out for (8K UHD)
+const MAX_RESOLUTION: (u32, u32) = (7680, 4320);
+
+/// The option after (`step` 1) or before (`step` -1) `current`, wrapping
+/// around.
+///
+/// A value that is not one of the options steps from the first option.
+pub fn cycle<T: Copy + PartialEq>(options: &[T], current: T, step: i32) -> T {
+    let index = options
+        .iter()
+        .position(|option| *option == current)
+        .unwrap_or(0) as i32;
+    let len = options.len() as i32;
+    options[(index + step).rem_euclid(len) as usize]
+}
+
+/// Player configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Window size in pixels
+    pub resolution: (u32, u32),
+    /// Borderless fullscreen on the current monitor
+    pub fullscreen: bool,
+    /// Unit movement animation speed in hexes per second
+    pub animation_speed: f32,
+    /// Seconds an AI team waits before playing its turn
+    pub ai_turn_delay: f32,
+    /// Play AI turns with tactical search instead of the GOAP planner
+    pub tactical_ai: bool,
+    /// Ask before resolving an attack
+    pub confirm_attacks: bool,
+    /// Size of the UI panels relative to their default size
+    pub ui_scale: f32,
+    /// Highlight the hex under the cursor
+    pub hover_debug: bool,
+    /// Keys of the in-game actions
+    pub key_bindings: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            resolution: (1920, 1080),
+            fullscreen: false,
+            animation_speed: 4.0,
+            ai_turn_delay: 3.0,
+            tactical_ai: false,
+            confirm_attacks: true,
+            ui_scale: 1.0,
+            hover_debug: false,
+            key_bindings: KeyBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Where the settings are stored: `questquest/settings.json` in the
+    /// user's config directory (`None` if it cannot be determined).
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("questquest").join("settings.json"))
+    }
+
+    /// Reads settings from a JSON file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let settings: Self = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        Ok(settings.sanitized())
+    }
+
+    /// Reads settings from a JSON file, falling back to the defaults if it
+    /// does not exist or cannot be read.
+    pub fn load_or_default(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load(path).unwrap_or_else(|e| {
+            eprintln!("⚠️  {} - using default settings", e);
+            Self::default()
+        })
+    }
+
+    /// Writes the settings as JSON, creating the directory if needed.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// The settings with out-of-range values clamped and every action bound.
+    pub fn sanitized(mut self) -> Self {
+        self.resolution = (
+            self.resolution.0.clamp(MIN_RESOLUTION.0, MAX_RESOLUTION.0),
+            self.resolution.1.clamp(MIN_RESOLUTION.1, MAX_RESOLUTION.1),
+        );
+        self.animation_speed = clamp_or(self.animation_speed, 0.5, 30.0, 4.0);
+        self.ai_turn_delay = clamp_or(self.ai_turn_delay, 0.0, 10.0, 3.0);
+        self.ui_scale = clamp_or(self.ui_scale, 0.5, 3.0, 1.0);
+        self.key_bindings = self.key_bindings.sanitized();
+        self
+    }
+}
+
+/// `value` clamped to `min..=max`, or `default` if it is not a number.
+fn clamp_or(value: f32, min: f32, max: f32, default: f32) -> f32 {
+    if value.is_nan() {
+        default
+    } else {
+        value.clamp(min, max)
+    }
+}
+
+/// The platform's per-user config directory.
+fn config_dir() -> Option<PathBuf> {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(windows) {
+        var("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".config")))
+    }
+}
+
+/// In-game actions that can be bound to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    EndTurn,
+    FocusUnit,
+    Encyclopedia,
+    Inventory,
+    UseItem,
+    UnitInfo,
+    Statistics,
+    AiInspector,
+    HoverDebug,
+    AcceptPickup,
+    DeclinePickup,
+}
+
+impl Action {
+    /// Every action, in the order the settings screen lists them
+    pub const ALL: [Action; 11] = [
+        Action::EndTurn,
+        Action::FocusUnit,
+        Action::Encyclopedia,
+        Action::Inventory,
+        Action::UseItem,
+        Action::UnitInfo,
+        Action::Statistics,
+        Action::AiInspector,
+        Action::HoverDebug,
+        Action::AcceptPickup,
+        Action::DeclinePickup,
+    ];
+
+    /// Name shown on the settings screen
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::EndTurn => "End turn",
+            Action::FocusUnit => "Focus selected unit",
+            Action::Encyclopedia => "Encyclopedia",
+            Action::Inventory => "Inventory",
+            Action::UseItem => "Use item",
+            Action::UnitInfo => "Unit info (console)",
+            Action::Statistics => "Statistics (console)",
+            Action::AiInspector => "AI inspector",
+            Action::HoverDebug => "Hover debug",
+            Action::AcceptPickup => "Pick up item",
+            Action::DeclinePickup => "Leave item",
+        }
+    }
+
+    /// Key the action is bound to out of the box
+    pub fn default_key(self) -> KeyCode {
+        match self {
+            Action::EndTurn => KeyCode::Space,
+            Action::FocusUnit => KeyCode::KeyF,
+            Action::Encyclopedia => KeyCode::KeyE,
+            Action::Inventory => KeyCode::KeyB,
+            Action::UseItem => KeyCode::KeyU,
+            Action::UnitInfo => KeyCode::KeyC,
+            Action::Statistics => KeyCode::KeyS,
+            Action::AiInspector => KeyCode::KeyT,
+            Action::HoverDebug => KeyCode::KeyH,
+            Action::AcceptPickup => KeyCode::KeyY,
+            Action::DeclinePickup => KeyCode::KeyN,
+        }
+    }
+}
+
+/// Keys that actions can be bound to.
+///
+/// Escape, the arrow keys and the digits stay reserved for closing dialogs,
+/// moving the camera and picking numbered options.
+const BINDABLE_KEYS: [KeyCode; 44] = [
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Space,
+    KeyCode::Enter,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::Insert,
+    KeyCode::Delete,
+];
+
+/// Whether actions can be bound to `code`.
+pub fn is_bindable(code: KeyCode) -> bool {
+    BINDABLE_KEYS.contains(&code)
+}
+
+/// Name a key is stored under in the settings file ("KeyE", "Space", "F5").
+pub fn key_name(code: KeyCode) -> String {
+    format!("{:?}", code)
+}
+
+/// The bindable key stored under `name`.
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS
+        .iter()
+        .copied()
+        .find(|code| key_name(*code) == name)
+}
+
+/// Short name of a key for display ("E", "Space", "F5").
+pub fn key_label(code: KeyCode) -> String {
+    let name = key_name(code);
+    match name.strip_prefix("Key") {
+        Some(letter) => letter.to_string(),
+        None => name,
+    }
+}
+
+/// Which key triggers each action, one key per action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyBindings {
+    /// Key names by action, as written to the settings file
+    keys: BTreeMap<Action, String>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: Action::ALL
+                .iter()
+                .map(|action| (*action, key_name(action.default_key())))
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Key bound to `action`, if any.
+    pub fn key(&self, action: Action) -> Option<KeyCode> {
+        self.keys.get(&action).and_then(|name| parse_key(name))
+    }
+
+    /// Action bound to `code`, if any.
+    pub fn action_for(&self, code: KeyCode) -> Option<Action> {
+        Action::ALL
+            .iter()
+            .copied()
+            .find(|action| self.key(*action) == Some(code))
+    }
+
+    /// Binds `action` to `code`.
+    ///
+    /// An action already on `code` takes over the key `action` had, so every
+    /// key stays bound to at most one action. Returns false for keys that
+    /// cannot be bound.
+    pub fn bind(&mut self, action: Action, code: KeyCode) -> bool {
+        if !is_bindable(code) {
+            return false;
+        }
+        let previous = self.key(action);
+        if let Some(other) = self.action_for(code).filter(|other| *other != action) {
+            match previous {
+                Some(previous) => self.keys.insert(other, key_name(previous)),
+                None => self.keys.remove(&other),
+            };
+        }
+        self.keys.insert(action, key_name(code));
+        true
+    }
+
+    /// The bindings with unknown keys replaced by the defaults and missing
+    /// actions bound.
+    ///
+    /// An action whose key is unknown or already taken falls back to its own
+    /// default key, or stays unbound if that key is taken too.
+    fn sanitized(self) -> Self {
+        let mut keys = BTreeMap::new();
+        let mut taken = Vec::new();
+        let mut missing = Vec::new();
+        for action in Action::ALL {
+            match self.key(action) {
+                Some(code) if !taken.contains(&code) => {
+                    taken.push(code);
+                    keys.insert(action, key_name(code));
+                }
+                _ => missing.push(action),
+            }
+        }
+        for action in missing {
+            let default = action.default_key();
+            if !taken.contains(&default) {
+                taken.push(default);
+                keys.insert(action, key_name(default));
+            }
+        }
+        Self { keys }
+    }
+}
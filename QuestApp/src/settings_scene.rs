@@ -0,0 +1,363 @@
+//! Settings Scene
+//!
+//! Edits the player's [`Settings`]: display, playback speed and interface
+//! options on the left, key bindings on the right. Every change is handed to
+//! the app through [`SettingsScene::take_changes`], which applies and saves
+//! it at once.
+
+use crate::main_menu::MENU_BACKGROUND;
+use crate::scene_manager::{Scene, SceneType};
+use crate::settings::{
+    self, Action, Settings, AI_TURN_DELAYS, ANIMATION_SPEEDS, RESOLUTIONS, UI_SCALES,
+};
+use graphics::draw::{DrawBackend, DrawList, GlBackend};
+use graphics::ui::{Align, Anchor, ButtonStyle, Insets, Length, Ui, Widget, WidgetId};
+use std::cell::RefCell;
+use std::rc::Rc;
+use winit::keyboard::KeyCode;
+
+/// "<" button of an option, indexed like [`SettingOption::ALL`]
+const PREVIOUS: WidgetId = WidgetId::new("settings_previous");
+/// ">" button of an option, indexed like [`SettingOption::ALL`]
+const NEXT: WidgetId = WidgetId::new("settings_next");
+/// Key button of an action, indexed like [`Action::ALL`]
+const BINDING: WidgetId = WidgetId::new("settings_binding");
+const RESET: WidgetId = WidgetId::new("settings_reset");
+const BACK: WidgetId = WidgetId::new("settings_back");
+
+const TITLE_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const HEADING_COLOR: [f32; 4] = [0.9, 0.8, 0.5, 1.0];
+const TEXT_COLOR: [f32; 4] = [0.85, 0.85, 0.9, 1.0];
+
+/// Height of an option or key binding row
+const ROW_HEIGHT: f32 = 30.0;
+
+/// Options edited with "<" and ">" buttons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingOption {
+    Resolution,
+    Fullscreen,
+    AnimationSpeed,
+    AiTurnDelay,
+    TacticalAi,
+    ConfirmAttacks,
+    UiScale,
+    HoverDebug,
+}
+
+impl SettingOption {
+    /// Options from top to bottom
+    const ALL: [SettingOption; 8] = [
+        SettingOption::Resolution,
+        SettingOption::Fullscreen,
+        SettingOption::AnimationSpeed,
+        SettingOption::AiTurnDelay,
+        SettingOption::TacticalAi,
+        SettingOption::ConfirmAttacks,
+        SettingOption::UiScale,
+        SettingOption::HoverDebug,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SettingOption::Resolution => "Resolution",
+            SettingOption::Fullscreen => "Fullscreen",
+            SettingOption::AnimationSpeed => "Animation speed",
+            SettingOption::AiTurnDelay => "AI turn delay",
+            SettingOption::TacticalAi => "Tactical AI",
+            SettingOption::ConfirmAttacks => "Confirm attacks",
+            SettingOption::UiScale => "UI scale",
+            SettingOption::HoverDebug => "Hover debug",
+        }
+    }
+
+    /// Current value as shown between the buttons
+    fn value(self, settings: &Settings) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" }.to_string();
+        match self {
+            SettingOption::Resolution => {
+                format!("{} x {}", settings.resolution.0, settings.resolution.1)
+            }
+            SettingOption::Fullscreen => on_off(settings.fullscreen),
+            SettingOption::AnimationSpeed => format!("{} hexes/s", settings.animation_speed),
+            SettingOption::AiTurnDelay if settings.ai_turn_delay == 0.0 => "None".to_string(),
+            SettingOption::AiTurnDelay => format!("{} s", settings.ai_turn_delay),
+            SettingOption::TacticalAi => on_off(settings.tactical_ai),
+            SettingOption::ConfirmAttacks => on_off(settings.confirm_attacks),
+            SettingOption::UiScale => format!("{}%", (settings.ui_scale * 100.0).round()),
+            SettingOption::HoverDebug => on_off(settings.hover_debug),
+        }
+    }
+
+    /// Moves the option to its next (`step` 1) or previous (`step` -1) value
+    fn step(self, settings: &mut Settings, step: i32) {
+        match self {
+            SettingOption::Resolution => {
+                settings.resolution = settings::cycle(&RESOLUTIONS, settings.resolution, step)
+            }
+            SettingOption::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingOption::AnimationSpeed => {
+                settings.animation_speed =
+                    settings::cycle(&ANIMATION_SPEEDS, settings.animation_speed, step)
+            }
+            SettingOption::AiTurnDelay => {
+                settings.ai_turn_delay =
+                    settings::cycle(&AI_TURN_DELAYS, settings.ai_turn_delay, step)
+            }
+            SettingOption::TacticalAi => settings.tactical_ai = !settings.tactical_ai,
+            SettingOption::ConfirmAttacks => settings.confirm_attacks = !settings.confirm_attacks,
+            SettingOption::UiScale => {
+                settings.ui_scale = settings::cycle(&UI_SCALES, settings.ui_scale, step)
+            }
+            SettingOption::HoverDebug => settings.hover_debug = !settings.hover_debug,
+        }
+    }
+}
+
+/// Settings Scene
+pub struct SettingsScene {
+    /// Settings being edited
+    settings: Settings,
+
+    /// Whether `settings` changed since the last `take_changes`
+    changed: bool,
+
+    /// Action waiting for its new key
+    rebinding: Option<Action>,
+
+    /// Scene the Back button returns to
+    return_to: SceneType,
+
+    /// Options, key bindings and footer, centered on the screen
+    ui: Ui,
+
+    /// Backend drawing the scene into the window
+    draw_backend: Option<Rc<RefCell<GlBackend>>>,
+}
+
+impl SettingsScene {
+    /// Create a settings scene editing `settings`
+    pub fn new(settings: Settings, screen_width: f32, screen_height: f32) -> Self {
+        let mut ui = Ui::empty(screen_width, screen_height);
+        ui.set_scale(settings.ui_scale);
+        let mut scene = Self {
+            settings,
+            changed: false,
+            rebinding: None,
+            return_to: SceneType::MainMenu,
+            ui,
+            draw_backend: None,
+        };
+        scene.rebuild();
+        scene
+    }
+
+    /// Start editing `settings`; Back returns to `return_to`
+    pub fn open(&mut self, settings: Settings, return_to: SceneType) {
+        self.settings = settings;
+        self.changed = false;
+        self.rebinding = None;
+        self.return_to = return_to;
+        self.rebuild();
+    }
+
+    /// The edited settings, if they changed since the last call
+    pub fn take_changes(&mut self) -> Option<Settings> {
+        if std::mem::take(&mut self.changed) {
+            Some(self.settings.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Set the backend this scene draws with
+    pub fn set_draw_backend(&mut self, draw_backend: Rc<RefCell<GlBackend>>) {
+        self.draw_backend = Some(draw_backend);
+    }
+
+    /// Update screen dimensions (e.g., on window resize)
+    pub fn update_screen_size(&mut self, width: f32, height: f32) {
+        self.ui.resize(width, height);
+    }
+
+    /// Draw the scene `scale` times its default size
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui.set_scale(scale);
+    }
+
+    fn rebuild(&mut self) {
+        self.ui.set_root(self.build());
+    }
+
+    /// Title above the option and key binding columns and the footer buttons
+    fn build(&self) -> Widget {
+        let style = ButtonStyle {
+            background: [0.18, 0.18, 0.26, 1.0],
+            hovered: [0.26, 0.28, 0.4, 1.0],
+            pressed: [0.14, 0.14, 0.2, 1.0],
+            text: [0.9, 0.9, 1.0, 1.0],
+            hovered_text: [1.0, 1.0, 1.0, 1.0],
+            border: Some((2.0, [0.35, 0.35, 0.5, 1.0])),
+        };
+        let heading =
+            |text: &str| Widget::label(text, 14.0, HEADING_COLOR).height(Length::Fixed(30.0));
+        let name = |text: &str| Widget::label(text, 11.0, TEXT_COLOR).width(Length::Fixed(200.0));
+
+        let options = SettingOption::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                Widget::row(8.0)
+                    .align(Align::Center)
+                    .child(name(option.label()))
+                    .child(
+                        Widget::button(WidgetId::indexed(PREVIOUS.name, index), "<", 11.0, style)
+                            .size(ROW_HEIGHT, ROW_HEIGHT),
+                    )
+                    .child(
+                        Widget::label(option.value(&self.settings), 11.0, TITLE_COLOR)
+                            .width(Length::Fixed(150.0)),
+                    )
+                    .child(
+                        Widget::button(WidgetId::indexed(NEXT.name, index), ">", 11.0, style)
+                            .size(ROW_HEIGHT, ROW_HEIGHT),
+                    )
+            });
+        let bindings = Action::ALL.iter().enumerate().map(|(index, action)| {
+            let key = if self.rebinding == Some(*action) {
+                "Press a key...".to_string()
+            } else {
+                self.settings
+                    .key_bindings
+                    .key(*action)
+                    .map_or_else(|| "-".to_string(), settings::key_label)
+            };
+            Widget::row(8.0)
+                .align(Align::Center)
+                .child(name(action.label()))
+                .child(
+                    Widget::button(WidgetId::indexed(BINDING.name, index), key, 11.0, style)
+                        .size(160.0, ROW_HEIGHT),
+                )
+        });
+
+        Widget::column(20.0)
+            .anchor(Anchor::Center)
+            .align(Align::Center)
+            .padding(Insets::all(24.0))
+            .background([0.12, 0.12, 0.18, 0.95])
+            .border(2.0, [0.35, 0.35, 0.5, 1.0])
+            .child(Widget::label("Settings", 24.0, TITLE_COLOR).height(Length::Fixed(50.0)))
+            .child(
+                Widget::row(48.0)
+                    .child(
+                        Widget::column(6.0)
+                            .child(heading("Options"))
+                            .children(options),
+                    )
+                    .child(
+                        Widget::column(6.0)
+                            .child(heading("Key Bindings"))
+                            .children(bindings),
+                    ),
+            )
+            .child(
+                Widget::row(20.0)
+                    .child(
+                        Widget::button(RESET, "Reset to Defaults", 12.0, style).size(220.0, 44.0),
+                    )
+                    .child(Widget::button(BACK, "Back", 12.0, style).size(220.0, 44.0)),
+            )
+    }
+
+    /// Records an edit and shows the new values
+    fn mark_changed(&mut self) {
+        self.changed = true;
+        self.rebuild();
+    }
+
+    /// Render the settings screen
+    pub fn render_settings(&mut self) {
+        if let Some(draw_backend) = &self.draw_backend {
+            let mut list = DrawList::new();
+            self.ui.draw(&mut list);
+            let mut draw_backend = draw_backend.borrow_mut();
+            draw_backend.clear(MENU_BACKGROUND);
+            draw_backend.submit(&list);
+        }
+    }
+}
+
+impl Scene for SettingsScene {
+    fn on_enter(&mut self) {
+        println!("⚙️  Entering Settings");
+    }
+
+    fn on_exit(&mut self) {
+        println!("⚙️  Exiting Settings");
+    }
+
+    fn update(&mut self, _delta_time: f32) {
+        // Settings don't need per-frame updates
+    }
+
+    fn render(&mut self) {
+        self.render_settings();
+    }
+
+    fn handle_click(&mut self, x: f64, y: f64, is_left_button: bool) -> Option<SceneType> {
+        if !is_left_button {
+            return None;
+        }
+
+        // Clicking anywhere cancels a pending rebind
+        if self.rebinding.take().is_some() {
+            self.rebuild();
+        }
+
+        let id = self.ui.hit_test(x as f32, y as f32)?;
+        if id == BACK {
+            return Some(self.return_to);
+        }
+        if id == RESET {
+            println!("⚙️  Settings reset to defaults");
+            self.settings = Settings::default();
+            self.mark_changed();
+        } else if id.name == PREVIOUS.name || id.name == NEXT.name {
+            let step = if id.name == NEXT.name { 1 } else { -1 };
+            if let Some(option) = SettingOption::ALL.get(id.index) {
+                option.step(&mut self.settings, step);
+                self.mark_changed();
+            }
+        } else if id.name == BINDING.name {
+            self.rebinding = Action::ALL.get(id.index).copied();
+            self.rebuild();
+        }
+        None
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> Option<SceneType> {
+        let Some(action) = self.rebinding.take() else {
+            return (key == KeyCode::Escape).then_some(self.return_to);
+        };
+
+        if key != KeyCode::Escape {
+            if self.settings.key_bindings.bind(action, key) {
+                println!(
+                    "⌨️  {} bound to {}",
+                    action.label(),
+                    settings::key_label(key)
+                );
+                self.changed = true;
+            } else {
+                println!("⌨️  {:?} cannot be bound", key);
+            }
+        }
+        self.rebuild();
+        None
+    }
+
+    fn handle_cursor_move(&mut self, x: f64, y: f64) {
+        self.ui.cursor_moved(x as f32, y as f32);
+    }
+}
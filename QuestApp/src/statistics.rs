@@ -0,0 +1,162 @@
+//! Game Statistics
+//!
+//! Per-team tallies of what happened in a game, recorded from the
+//! [`GameEvent`] stream `ScenarioWorld` emits.
+
+use game::{GameEvent, Team, TeamRoster};
+use std::collections::BTreeMap;
+
+/// What one team did over the game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamStatistics {
+    /// Strikes made, counter-attacks included
+    pub strikes: u32,
+    /// Strikes that hit
+    pub hits: u32,
+    /// Damage dealt by the team's strikes
+    pub damage_dealt: u32,
+    /// Damage taken from enemy strikes
+    pub damage_taken: u32,
+    /// Enemy units defeated
+    pub kills: u32,
+    /// Own units lost
+    pub losses: u32,
+    /// Health restored to the team's units
+    pub healing: u32,
+    /// Level-ups and evolutions
+    pub level_ups: u32,
+    /// Items picked up from the ground
+    pub items_picked_up: u32,
+    /// Hexes moved onto
+    pub moves: u32,
+}
+
+impl TeamStatistics {
+    /// Share of strikes that hit, in percent (0 without strikes).
+    pub fn accuracy(&self) -> u32 {
+        (self.hits * 100).checked_div(self.strikes).unwrap_or(0)
+    }
+}
+
+/// Statistics of a game, per team.
+#[derive(Debug, Clone, Default)]
+pub struct GameStatistics {
+    teams: BTreeMap<Team, TeamStatistics>,
+    /// Team of every unit seen, for events that only carry a unit id
+    unit_teams: BTreeMap<uuid::Uuid, Team>,
+    /// Turns started
+    pub turns: u32,
+}
+
+impl GameStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Statistics of one team (all zero if it has done nothing yet).
+    #[allow(dead_code)]
+    pub fn team(&self, team: Team) -> TeamStatistics {
+        self.teams.get(&team).cloned().unwrap_or_default()
+    }
+
+    /// Records one game event.
+    pub fn record(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::UnitMoved { unit_id, .. } => {
+                if let Some(team) = self.unit_teams.get(unit_id).copied() {
+                    self.entry(team).moves += 1;
+                }
+            }
+            GameEvent::StrikeResolved {
+                striker_id,
+                striker_team,
+                target_id,
+                target_team,
+                hit,
+                damage,
+                ..
+            } => {
+                self.unit_teams.insert(*striker_id, *striker_team);
+                self.unit_teams.insert(*target_id, *target_team);
+                let striker = self.entry(*striker_team);
+                striker.strikes += 1;
+                if *hit {
+                    striker.hits += 1;
+                    striker.damage_dealt += damage;
+                    self.entry(*target_team).damage_taken += damage;
+                }
+            }
+            GameEvent::UnitDied {
+                unit_id,
+                team,
+                killer_id,
+                ..
+            } => {
+                self.entry(*team).losses += 1;
+                if let Some(killer_team) = killer_id.and_then(|id| self.unit_teams.get(&id)) {
+                    let killer_team = *killer_team;
+                    self.entry(killer_team).kills += 1;
+                }
+                self.unit_teams.remove(unit_id);
+            }
+            GameEvent::UnitHealed {
+                unit_id, amount, ..
+            } => {
+                if let Some(team) = self.unit_teams.get(unit_id).copied() {
+                    self.entry(team).healing += amount;
+                }
+            }
+            GameEvent::LeveledUp { unit_id, team, .. } => {
+                self.unit_teams.insert(*unit_id, *team);
+                self.entry(*team).level_ups += 1;
+            }
+            GameEvent::ItemPickedUp { unit_id, team, .. } => {
+                self.unit_teams.insert(*unit_id, *team);
+                self.entry(*team).items_picked_up += 1;
+            }
+            GameEvent::UnitRecruited { unit_id, team, .. } => {
+                self.unit_teams.insert(*unit_id, *team);
+            }
+            GameEvent::TurnStarted { .. } => self.turns += 1,
+            GameEvent::ItemUsed { .. }
+            | GameEvent::ItemEquipped { .. }
+            | GameEvent::ItemDropped { .. }
+            | GameEvent::ItemGiven { .. }
+            | GameEvent::StructureDamaged { .. } => {}
+        }
+    }
+
+    /// Remembers which team a unit belongs to, so events carrying only the
+    /// unit's id are credited to its team.
+    pub fn track_unit(&mut self, unit_id: uuid::Uuid, team: Team) {
+        self.unit_teams.insert(unit_id, team);
+    }
+
+    /// One line per team that has done anything, for the console.
+    pub fn summary_lines(&self, roster: &TeamRoster) -> Vec<String> {
+        self.teams
+            .iter()
+            .map(|(team, stats)| {
+                format!(
+                    "{}: {} hits / {} strikes ({}%), {} dealt, {} taken, {} kills, {} lost, {} healed, {} level-ups, {} items, {} moves",
+                    roster.name(*team),
+                    stats.hits,
+                    stats.strikes,
+                    stats.accuracy(),
+                    stats.damage_dealt,
+                    stats.damage_taken,
+                    stats.kills,
+                    stats.losses,
+                    stats.healing,
+                    stats.level_ups,
+                    stats.items_picked_up,
+                    stats.moves
+                )
+            })
+            .collect()
+    }
+
+    fn entry(&mut self, team: Team) -> &mut TeamStatistics {
+        self.teams.entry(team).or_default()
+    }
+}
@@ -0,0 +1,50 @@
+//! AI Inspector State Tests
+//!
+//! Tests for the AiInspectorState handler including visibility
+//! toggling and agent focus cycling.
+
+use questapp::game_scene::states::ai_inspector::AiInspectorState;
+use questapp::game_scene::GameSceneState;
+
+#[test]
+fn test_ai_inspector_starts_hidden() {
+    let state = AiInspectorState::new();
+    assert!(!state.is_visible());
+    assert_eq!(state, AiInspectorState::default());
+}
+
+#[test]
+fn test_ai_inspector_toggle() {
+    let mut state = AiInspectorState::new();
+    assert!(state.toggle());
+    assert!(state.is_visible());
+    assert!(!state.toggle());
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_ai_inspector_focus_cycles_through_agents() {
+    let mut state = AiInspectorState::new();
+    assert_eq!(state.focused_index(3), Some(0));
+
+    state.focus_next(3);
+    assert_eq!(state.focused_index(3), Some(1));
+    state.focus_next(3);
+    state.focus_next(3);
+    assert_eq!(state.focused_index(3), Some(0));
+}
+
+#[test]
+fn test_ai_inspector_focus_clamps_to_trace() {
+    let mut state = AiInspectorState::new();
+    state.focus_next(5);
+    state.focus_next(5);
+    assert_eq!(state.focused_index(2), Some(1));
+    assert_eq!(state.focused_index(0), None);
+}
+
+#[test]
+fn test_game_scene_has_hidden_ai_inspector() {
+    let scene = GameSceneState::new();
+    assert!(!scene.ai_inspector.is_visible());
+}
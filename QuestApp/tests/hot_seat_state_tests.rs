@@ -0,0 +1,79 @@
+//! Hot-Seat State Tests
+//!
+//! Tests for the HotSeatState handler including device handoffs,
+//! per-team cameras and command restrictions.
+
+use game::Team;
+use graphics::Vec2;
+use questapp::game_scene::states::hot_seat::HotSeatState;
+use questapp::game_scene::GameSceneState;
+
+fn two_humans() -> HotSeatState {
+    let mut state = HotSeatState::new();
+    state.enable(vec![Team::PLAYER, Team::ENEMY]);
+    state
+}
+
+#[test]
+fn test_hot_seat_starts_disabled_and_allows_every_command() {
+    let mut state = HotSeatState::new();
+    assert!(!state.is_enabled());
+    assert!(!state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0)));
+    assert!(!state.is_board_hidden());
+    assert!(state.can_command(Team::PLAYER, Team::ENEMY));
+    assert!(!GameSceneState::new().hot_seat.is_enabled());
+}
+
+#[test]
+fn test_handoff_hides_board_until_confirmed() {
+    let mut state = two_humans();
+    assert!(state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0)));
+    assert!(state.is_board_hidden());
+    assert_eq!(state.handoff_team(), Some(Team::PLAYER));
+    // Repeated notifications for the same turn don't raise it again
+    assert!(!state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0)));
+
+    assert_eq!(state.confirm_handoff(), None);
+    assert!(!state.is_board_hidden());
+    assert_eq!(state.viewing_team(), Some(Team::PLAYER));
+    assert!(!state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0)));
+}
+
+#[test]
+fn test_ai_turns_keep_the_last_human_view() {
+    let mut state = two_humans();
+    state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0));
+    state.confirm_handoff();
+
+    assert!(!state.begin_turn(Team::NEUTRAL, Vec2::new(0.0, 0.0)));
+    assert_eq!(state.viewing_team(), Some(Team::PLAYER));
+    assert!(!state.is_board_hidden());
+}
+
+#[test]
+fn test_each_team_gets_its_camera_back() {
+    let mut state = two_humans();
+    state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0));
+    state.confirm_handoff();
+
+    state.begin_turn(Team::ENEMY, Vec2::new(1.0, 2.0));
+    assert_eq!(state.confirm_handoff(), None);
+
+    state.begin_turn(Team::PLAYER, Vec2::new(-3.0, 0.5));
+    assert_eq!(state.confirm_handoff(), Some(Vec2::new(1.0, 2.0)));
+
+    state.begin_turn(Team::ENEMY, Vec2::new(0.0, 0.0));
+    assert_eq!(state.confirm_handoff(), Some(Vec2::new(-3.0, 0.5)));
+}
+
+#[test]
+fn test_only_the_active_team_can_be_commanded() {
+    let mut state = two_humans();
+    state.begin_turn(Team::PLAYER, Vec2::new(0.0, 0.0));
+    assert!(!state.can_command(Team::PLAYER, Team::PLAYER));
+
+    state.confirm_handoff();
+    assert!(state.can_command(Team::PLAYER, Team::PLAYER));
+    assert!(!state.can_command(Team::PLAYER, Team::ENEMY));
+    assert!(!state.can_command(Team::NEUTRAL, Team::NEUTRAL));
+}
@@ -0,0 +1,75 @@
+//! Inventory State Tests
+//!
+//! Tests for the InventoryState handler including opening, selecting
+//! items, giving and closing the screen.
+
+use questapp::game_scene::states::inventory::InventoryState;
+use questapp::game_scene::GameSceneState;
+use uuid::Uuid;
+
+#[test]
+fn test_inventory_starts_closed() {
+    let state = InventoryState::new();
+    assert!(!state.is_open());
+    assert_eq!(state.unit_id(), None);
+    assert_eq!(state.selected(), None);
+    assert!(!state.is_giving());
+}
+
+#[test]
+fn test_inventory_select_checks_range() {
+    let mut state = InventoryState::new();
+    let unit_id = Uuid::new_v4();
+    state.open(unit_id);
+
+    assert_eq!(state.unit_id(), Some(unit_id));
+    assert!(!state.select(3, 3));
+    assert_eq!(state.selected(), None);
+    assert!(state.select(2, 3));
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn test_inventory_giving_needs_selection() {
+    let mut state = InventoryState::new();
+    state.open(Uuid::new_v4());
+
+    assert!(!state.start_giving());
+    state.select(0, 1);
+    assert!(state.start_giving());
+    assert!(state.is_giving());
+
+    // Selecting another item cancels the hand-over
+    state.select(0, 1);
+    assert!(!state.is_giving());
+}
+
+#[test]
+fn test_inventory_sync_drops_stale_selection() {
+    let mut state = InventoryState::new();
+    state.open(Uuid::new_v4());
+    state.select(1, 2);
+    state.start_giving();
+
+    state.sync(2);
+    assert_eq!(state.selected(), Some(1));
+    state.sync(1);
+    assert_eq!(state.selected(), None);
+    assert!(!state.is_giving());
+}
+
+#[test]
+fn test_inventory_close_resets_everything() {
+    let mut state = InventoryState::new();
+    state.open(Uuid::new_v4());
+    state.select(0, 1);
+    state.close();
+
+    assert_eq!(state, InventoryState::default());
+}
+
+#[test]
+fn test_game_scene_starts_with_inventory_closed() {
+    let scene = GameSceneState::new();
+    assert!(!scene.inventory.is_open());
+}
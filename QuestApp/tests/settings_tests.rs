@@ -0,0 +1,152 @@
+//! Settings Tests
+//!
+//! Tests for loading, saving and sanitizing the player's settings and for
+//! rebinding keys.
+
+use questapp::settings::{self, Action, KeyBindings, Settings, RESOLUTIONS};
+use std::path::PathBuf;
+use winit::keyboard::KeyCode;
+
+/// A settings file path unique to one test
+fn temp_settings_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!(
+            "questquest_settings_{}_{}",
+            name,
+            std::process::id()
+        ))
+        .join("settings.json")
+}
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let path = temp_settings_path("round_trip");
+    let mut original = Settings {
+        resolution: (2560, 1440),
+        fullscreen: true,
+        animation_speed: 8.0,
+        ai_turn_delay: 0.5,
+        tactical_ai: true,
+        confirm_attacks: false,
+        ui_scale: 1.5,
+        hover_debug: true,
+        ..Settings::default()
+    };
+    original.key_bindings.bind(Action::EndTurn, KeyCode::Enter);
+
+    original.save(&path).unwrap();
+    let loaded = Settings::load(&path).unwrap();
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+    assert_eq!(loaded, original);
+}
+
+#[test]
+fn test_missing_fields_keep_their_defaults() {
+    let settings: Settings = serde_json::from_str(r#"{ "ui_scale": 1.25 }"#).unwrap();
+
+    assert_eq!(settings.ui_scale, 1.25);
+    assert_eq!(settings.resolution, Settings::default().resolution);
+    assert!(settings.confirm_attacks);
+    assert_eq!(settings.key_bindings, KeyBindings::default());
+}
+
+#[test]
+fn test_missing_file_loads_the_defaults() {
+    let path = temp_settings_path("missing");
+    assert!(Settings::load(&path).is_err());
+    assert_eq!(Settings::load_or_default(&path), Settings::default());
+}
+
+#[test]
+fn test_invalid_json_is_an_error() {
+    let path = temp_settings_path("invalid");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "{ not json").unwrap();
+
+    let result = Settings::load(&path);
+    let fallback = Settings::load_or_default(&path);
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+    assert!(result.unwrap_err().contains("Failed to parse"));
+    assert_eq!(fallback, Settings::default());
+}
+
+#[test]
+fn test_out_of_range_values_are_clamped() {
+    let settings = Settings {
+        resolution: (100, 100),
+        animation_speed: 1000.0,
+        ai_turn_delay: -2.0,
+        ui_scale: f32::NAN,
+        ..Settings::default()
+    }
+    .sanitized();
+
+    assert_eq!(settings.resolution, (640, 480));
+    assert_eq!(settings.animation_speed, 30.0);
+    assert_eq!(settings.ai_turn_delay, 0.0);
+    assert_eq!(settings.ui_scale, 1.0);
+
+    let huge = Settings {
+        resolution: (100_000, 60_000),
+        ..Settings::default()
+    }
+    .sanitized();
+    assert_eq!(huge.resolution, (7680, 4320));
+}
+
+#[test]
+fn test_unknown_and_duplicate_keys_fall_back_to_defaults() {
+    let json = r#"{ "key_bindings": { "EndTurn": "KeyE", "FocusUnit": "Escape" } }"#;
+    let settings = serde_json::from_str::<Settings>(json).unwrap().sanitized();
+    let bindings = &settings.key_bindings;
+
+    assert_eq!(bindings.key(Action::EndTurn), Some(KeyCode::KeyE));
+    assert_eq!(bindings.key(Action::FocusUnit), Some(KeyCode::KeyF));
+    // E is taken by End Turn, so the encyclopedia has no key left
+    assert_eq!(bindings.key(Action::Encyclopedia), None);
+    assert_eq!(bindings.key(Action::Inventory), Some(KeyCode::KeyB));
+}
+
+#[test]
+fn test_binding_a_taken_key_swaps_the_keys() {
+    let mut bindings = KeyBindings::default();
+    assert!(bindings.bind(Action::EndTurn, KeyCode::KeyE));
+
+    assert_eq!(bindings.key(Action::EndTurn), Some(KeyCode::KeyE));
+    assert_eq!(bindings.key(Action::Encyclopedia), Some(KeyCode::Space));
+    assert_eq!(bindings.action_for(KeyCode::KeyE), Some(Action::EndTurn));
+    assert_eq!(
+        bindings.action_for(KeyCode::Space),
+        Some(Action::Encyclopedia)
+    );
+}
+
+#[test]
+fn test_reserved_keys_cannot_be_bound() {
+    let mut bindings = KeyBindings::default();
+
+    assert!(!bindings.bind(Action::EndTurn, KeyCode::Escape));
+    assert!(!bindings.bind(Action::EndTurn, KeyCode::Digit1));
+    assert_eq!(bindings.key(Action::EndTurn), Some(KeyCode::Space));
+    assert_eq!(bindings.action_for(KeyCode::ArrowUp), None);
+}
+
+#[test]
+fn test_key_names_and_labels() {
+    assert_eq!(settings::key_name(KeyCode::KeyE), "KeyE");
+    assert_eq!(settings::parse_key("F5"), Some(KeyCode::F5));
+    assert_eq!(settings::parse_key("Escape"), None);
+    assert_eq!(settings::key_label(KeyCode::KeyE), "E");
+    assert_eq!(settings::key_label(KeyCode::Space), "Space");
+}
+
+#[test]
+fn test_cycle_wraps_around_the_options() {
+    assert_eq!(settings::cycle(&RESOLUTIONS, (1920, 1080), 1), (2560, 1440));
+    assert_eq!(settings::cycle(&RESOLUTIONS, (3840, 2160), 1), (1280, 720));
+    assert_eq!(settings::cycle(&RESOLUTIONS, (1280, 720), -1), (3840, 2160));
+    // Values that are not an option step from the first one
+    assert_eq!(settings::cycle(&RESOLUTIONS, (800, 600), 1), (1600, 900));
+}
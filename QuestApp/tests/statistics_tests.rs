@@ -0,0 +1,118 @@
+//! Game Statistics Tests
+//!
+//! Tests for GameStatistics recording strikes, kills, healing and other
+//! game events per team.
+
+use game::{GameEvent, HexCoord, Team, TeamRoster};
+use questapp::statistics::GameStatistics;
+use uuid::Uuid;
+
+fn strike(striker: Uuid, target: Uuid, hit: bool, damage: u32) -> GameEvent {
+    GameEvent::StrikeResolved {
+        striker_id: striker,
+        striker_team: Team::PLAYER,
+        target_id: target,
+        target_team: Team::ENEMY,
+        target_position: HexCoord::new(1, 0),
+        attack_name: "Sword".to_string(),
+        counter: false,
+        hit,
+        damage: if hit { damage } else { 0 },
+    }
+}
+
+#[test]
+fn test_strikes_count_hits_and_damage_for_both_teams() {
+    let mut stats = GameStatistics::new();
+    let (hero, goblin) = (Uuid::new_v4(), Uuid::new_v4());
+    stats.record(&strike(hero, goblin, true, 7));
+    stats.record(&strike(hero, goblin, false, 0));
+
+    let player = stats.team(Team::PLAYER);
+    assert_eq!(player.strikes, 2);
+    assert_eq!(player.hits, 1);
+    assert_eq!(player.damage_dealt, 7);
+    assert_eq!(player.accuracy(), 50);
+    assert_eq!(stats.team(Team::ENEMY).damage_taken, 7);
+}
+
+#[test]
+fn test_deaths_credit_the_killer_team() {
+    let mut stats = GameStatistics::new();
+    let (hero, goblin) = (Uuid::new_v4(), Uuid::new_v4());
+    stats.record(&strike(hero, goblin, true, 20));
+    stats.record(&GameEvent::UnitDied {
+        unit_id: goblin,
+        name: "Goblin".to_string(),
+        team: Team::ENEMY,
+        position: HexCoord::new(1, 0),
+        killer_id: Some(hero),
+    });
+
+    assert_eq!(stats.team(Team::PLAYER).kills, 1);
+    assert_eq!(stats.team(Team::ENEMY).losses, 1);
+    assert_eq!(stats.team(Team::ENEMY).kills, 0);
+}
+
+#[test]
+fn test_unit_events_need_a_known_team() {
+    let mut stats = GameStatistics::new();
+    let hero = Uuid::new_v4();
+    let moved = GameEvent::UnitMoved {
+        unit_id: hero,
+        from: HexCoord::new(0, 0),
+        to: HexCoord::new(1, 0),
+    };
+    stats.record(&moved);
+    assert_eq!(stats.team(Team::PLAYER).moves, 0);
+
+    stats.track_unit(hero, Team::PLAYER);
+    stats.record(&moved);
+    stats.record(&GameEvent::UnitHealed {
+        unit_id: hero,
+        position: HexCoord::new(1, 0),
+        amount: 5,
+    });
+    assert_eq!(stats.team(Team::PLAYER).moves, 1);
+    assert_eq!(stats.team(Team::PLAYER).healing, 5);
+}
+
+#[test]
+fn test_turns_level_ups_and_pickups() {
+    let mut stats = GameStatistics::new();
+    let hero = Uuid::new_v4();
+    stats.record(&GameEvent::TurnStarted {
+        team: Team::PLAYER,
+        turn: 1,
+    });
+    stats.record(&GameEvent::LeveledUp {
+        unit_id: hero,
+        name: "Hero".to_string(),
+        team: Team::PLAYER,
+        level: 2,
+    });
+    stats.record(&GameEvent::ItemPickedUp {
+        unit_id: hero,
+        team: Team::PLAYER,
+        item_name: "Potion".to_string(),
+        position: HexCoord::new(0, 0),
+    });
+
+    assert_eq!(stats.turns, 1);
+    assert_eq!(stats.team(Team::PLAYER).level_ups, 1);
+    assert_eq!(stats.team(Team::PLAYER).items_picked_up, 1);
+}
+
+#[test]
+fn test_summary_lists_only_active_teams() {
+    let mut roster = TeamRoster::new();
+    roster.add_team("Heroes", [0.0, 0.0, 1.0]).unwrap();
+    roster.add_team("Goblins", [1.0, 0.0, 0.0]).unwrap();
+    let mut stats = GameStatistics::new();
+    assert!(stats.summary_lines(&roster).is_empty());
+
+    stats.record(&strike(Uuid::new_v4(), Uuid::new_v4(), true, 4));
+    let lines = stats.summary_lines(&roster);
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Heroes: 1 hits / 1 strikes (100%)"));
+}
@@ -0,0 +1,67 @@
+//! Use Item State Tests
+//!
+//! Tests for the UseItemState handler including opening, choosing
+//! an item and closing the panel.
+
+use questapp::game_scene::states::use_item::UseItemState;
+use questapp::game_scene::GameSceneState;
+use uuid::Uuid;
+
+#[test]
+fn test_use_item_starts_closed() {
+    let state = UseItemState::new();
+    assert!(!state.is_open());
+    assert_eq!(state.unit_id(), None);
+    assert_eq!(state.chosen_slot(), None);
+}
+
+#[test]
+fn test_use_item_open_lists_slots() {
+    let mut state = UseItemState::new();
+    let unit_id = Uuid::new_v4();
+    state.open(unit_id, vec![0, 2]);
+
+    assert!(state.is_open());
+    assert_eq!(state.unit_id(), Some(unit_id));
+    assert_eq!(state.slots(), &[0, 2]);
+    assert_eq!(state.chosen_slot(), None);
+}
+
+#[test]
+fn test_use_item_choose_maps_index_to_slot() {
+    let mut state = UseItemState::new();
+    state.open(Uuid::new_v4(), vec![1, 3]);
+
+    assert_eq!(state.choose(5), None);
+    assert_eq!(state.chosen_slot(), None);
+    assert_eq!(state.choose(1), Some(3));
+    assert_eq!(state.chosen_slot(), Some(3));
+}
+
+#[test]
+fn test_use_item_close_resets_everything() {
+    let mut state = UseItemState::new();
+    state.open(Uuid::new_v4(), vec![0]);
+    state.choose(0);
+    state.close();
+
+    assert_eq!(state, UseItemState::default());
+}
+
+#[test]
+fn test_use_item_reopen_drops_previous_choice() {
+    let mut state = UseItemState::new();
+    state.open(Uuid::new_v4(), vec![0]);
+    state.choose(0);
+
+    let other = Uuid::new_v4();
+    state.open(other, vec![4]);
+    assert_eq!(state.unit_id(), Some(other));
+    assert_eq!(state.chosen_slot(), None);
+}
+
+#[test]
+fn test_game_scene_starts_with_use_item_closed() {
+    let scene = GameSceneState::new();
+    assert!(!scene.use_item.is_open());
+}
@@ -1,6 +1,6 @@
 //! Demonstration of Human Knight Commander's Leadership Aura
 
-use graphics::HexCoord;
+use hex::HexCoord;
 use units::UnitFactory;
 
 fn main() {
@@ -19,7 +19,7 @@
 //! ));
 //! ```
 
-use graphics::HexCoord;
+use hex::HexCoord;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -509,6 +509,22 @@ impl AbilityState {
     pub fn set_cooldown(&mut self, ability_id: AbilityId, cooldown: u32) {
         self.cooldowns.insert(ability_id, cooldown);
     }
+
+    /// Net change to a stat from active buffs minus active debuffs
+    pub fn stat_modifier(&self, stat: &BuffStat) -> i32 {
+        self.active_effects
+            .values()
+            .map(|instance| match &instance.effect {
+                ActiveEffect::Buff {
+                    stat: s, amount, ..
+                } if s == stat => *amount,
+                ActiveEffect::Debuff {
+                    stat: s, amount, ..
+                } if s == stat => -*amount,
+                _ => 0,
+            })
+            .sum()
+    }
 }
 
 /// An active effect instance with remaining duration.
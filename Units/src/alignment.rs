@@ -0,0 +1,89 @@
+//! Unit alignments and time-of-day damage scaling.
+//!
+//! Every unit has an [`Alignment`] that decides how the time of day changes
+//! the damage it deals. A time-of-day phase carries a *lawful bonus*: lawful
+//! units gain it, chaotic units gain its opposite and neutral units ignore it.
+//! With a lawful bonus of +25 at midday, a lawful unit deals 25% more damage
+//! and a chaotic unit 25% less; at night the signs flip.
+//!
+//! The default alignment comes from the unit's race
+//! ([`Race::default_alignment`](crate::Race::default_alignment)).
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use units::Alignment;
+//!
+//! assert_eq!(Alignment::Lawful.scale_damage(8, 25), 10);
+//! assert_eq!(Alignment::Chaotic.scale_damage(8, 25), 6);
+//! assert_eq!(Alignment::Neutral.scale_damage(8, 25), 8);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Which times of day favour a unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Alignment {
+    /// Stronger by day, weaker at night
+    Lawful,
+    /// Unaffected by the time of day
+    #[default]
+    Neutral,
+    /// Stronger at night, weaker by day
+    Chaotic,
+}
+
+impl Alignment {
+    /// Every alignment, in declaration order.
+    pub const ALL: [Alignment; 3] = [Alignment::Lawful, Alignment::Neutral, Alignment::Chaotic];
+
+    /// Display name of the alignment.
+    pub fn name(self) -> &'static str {
+        match self {
+            Alignment::Lawful => "Lawful",
+            Alignment::Neutral => "Neutral",
+            Alignment::Chaotic => "Chaotic",
+        }
+    }
+
+    /// Looks up an alignment by its [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// Damage bonus in percent for a time of day.
+    ///
+    /// # Arguments
+    ///
+    /// * `lawful_bonus` - The time of day's bonus for lawful units, in percent
+    ///
+    /// # Returns
+    ///
+    /// The percentage added to this alignment's damage (negative = less damage)
+    pub fn damage_bonus(self, lawful_bonus: i32) -> i32 {
+        match self {
+            Alignment::Lawful => lawful_bonus,
+            Alignment::Neutral => 0,
+            Alignment::Chaotic => -lawful_bonus,
+        }
+    }
+
+    /// Scales a damage value by the time-of-day bonus.
+    ///
+    /// The result is rounded to the nearest integer and never drops below 1
+    /// for a non-zero base damage.
+    ///
+    /// # Arguments
+    ///
+    /// * `damage` - Base damage of the attack
+    /// * `lawful_bonus` - The time of day's bonus for lawful units, in percent
+    pub fn scale_damage(self, damage: u32, lawful_bonus: i32) -> u32 {
+        let percent = (100 + self.damage_bonus(lawful_bonus)).max(0) as f32;
+        let scaled = (damage as f32 * percent / 100.0).round() as u32;
+        if damage > 0 {
+            scaled.max(1)
+        } else {
+            0
+        }
+    }
+}
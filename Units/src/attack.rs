@@ -2,8 +2,13 @@
 //!
 //! This module provides the [`Attack`] structure that represents specific
 //! combat actions units can perform, including damage, range, and damage type.
+//!
+//! Every attack has a [`RangeType`]. It decides which attacks may answer it:
+//! a defender counter-attacks only with an attack of the same range type that
+//! reaches the attacker (see [`Attack::can_retaliate`]).
 
-use crate::combat::DamageType;
+use crate::combat::{DamageType, RangeType};
+use items::ItemAttack;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -16,8 +21,22 @@ pub struct Attack {
     pub damage_type: DamageType,
     /// Range of this attack in hexes (1 = melee only)
     pub range: i32,
+    /// Number of strikes per use; each strike rolls to hit separately
+    #[serde(default = "default_attack_times")]
+    pub attack_times: u32,
+    /// Melee, ranged or siege; decides which attacks may answer this one
+    #[serde(default)]
+    pub range_type: RangeType,
     /// Description of the attack for tooltips and logs
     pub description: String,
+    /// Magical attacks ignore the target's defenses and always have a
+    /// [`MAGIC_HIT_CHANCE`](crate::combat::MAGIC_HIT_CHANCE) to hit
+    #[serde(default)]
+    pub magical: bool,
+}
+
+fn default_attack_times() -> u32 {
+    1
 }
 
 impl Attack {
@@ -33,7 +52,8 @@ impl Attack {
     ///
     /// # Returns
     ///
-    /// A new `Attack` instance. Range is clamped to a minimum of 1.
+    /// A new `Attack` instance. Range is clamped to a minimum of 1; attacks
+    /// with a range of 1 are melee, longer ones are ranged.
     pub fn new(
         name: impl Into<String>,
         damage: u32,
@@ -41,12 +61,20 @@ impl Attack {
         range: i32,
         description: impl Into<String>,
     ) -> Self {
+        let range = range.max(1); // At least melee range
         Self {
             name: name.into(),
             damage,
             damage_type,
-            range: range.max(1), // At least melee range
+            range,
+            attack_times: 1,
+            range_type: if range > 1 {
+                RangeType::Ranged
+            } else {
+                RangeType::Melee
+            },
             description: description.into(),
+            magical: false,
         }
     }
 
@@ -56,7 +84,7 @@ impl Attack {
     ///
     /// * `name` - The attack's display name
     /// * `damage` - Base damage dealt
-    /// * `_attack_times` - (Currently unused) Number of strikes
+    /// * `attack_times` - Number of strikes per use
     /// * `damage_type` - Type of damage dealt
     ///
     /// # Returns
@@ -65,10 +93,10 @@ impl Attack {
     pub fn melee(
         name: impl Into<String>,
         damage: u32,
-        _attack_times: u32,
+        attack_times: u32,
         damage_type: DamageType,
     ) -> Self {
-        Self::new(name, damage, damage_type, 1, "A basic melee attack")
+        Self::new(name, damage, damage_type, 1, "A basic melee attack").times(attack_times)
     }
 
     /// Creates a basic ranged attack with specified range.
@@ -77,7 +105,7 @@ impl Attack {
     ///
     /// * `name` - The attack's display name
     /// * `damage` - Base damage dealt
-    /// * `_attack_times` - (Currently unused) Number of shots
+    /// * `attack_times` - Number of shots per use
     /// * `damage_type` - Type of damage dealt
     /// * `range` - Maximum range in hexes
     ///
@@ -87,11 +115,11 @@ impl Attack {
     pub fn ranged(
         name: impl Into<String>,
         damage: u32,
-        _attack_times: u32,
+        attack_times: u32,
         damage_type: DamageType,
         range: i32,
     ) -> Self {
-        Self::new(name, damage, damage_type, range, "A ranged attack")
+        Self::new(name, damage, damage_type, range, "A ranged attack").times(attack_times)
     }
 
     /// Creates a siege attack for attacking structures or fortifications.
@@ -100,7 +128,7 @@ impl Attack {
     ///
     /// * `name` - The attack's display name
     /// * `damage` - Base damage dealt
-    /// * `_attack_times` - (Currently unused) Number of volleys
+    /// * `attack_times` - Number of volleys per use
     /// * `damage_type` - Type of damage dealt
     /// * `range` - Maximum range in hexes
     ///
@@ -110,11 +138,82 @@ impl Attack {
     pub fn siege(
         name: impl Into<String>,
         damage: u32,
-        _attack_times: u32,
+        attack_times: u32,
+        damage_type: DamageType,
+        range: i32,
+    ) -> Self {
+        Self {
+            range_type: RangeType::Siege,
+            ..Self::new(name, damage, damage_type, range, "A siege attack").times(attack_times)
+        }
+    }
+
+    /// Creates a magical ranged attack.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The attack's display name
+    /// * `damage` - Base damage dealt
+    /// * `attack_times` - Number of casts per use
+    /// * `damage_type` - Type of damage dealt
+    /// * `range` - Maximum range in hexes
+    ///
+    /// # Returns
+    ///
+    /// A magical attack whose hit chance ignores terrain, structures and buffs.
+    pub fn magic(
+        name: impl Into<String>,
+        damage: u32,
+        attack_times: u32,
         damage_type: DamageType,
         range: i32,
     ) -> Self {
-        Self::new(name, damage, damage_type, range, "A siege attack")
+        Self {
+            magical: true,
+            range_type: RangeType::Ranged,
+            ..Self::new(name, damage, damage_type, range, "A magical attack").times(attack_times)
+        }
+    }
+
+    /// Creates an attack granted by an equipped weapon.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_attack` - The weapon's attack
+    /// * `range_type` - The weapon's range type (melee unless it overrides it)
+    /// * `range_modifier` - Extra reach from the weapon
+    ///
+    /// # Returns
+    ///
+    /// An attack of the given range type whose range is the range type's base
+    /// range plus `range_modifier`, at least 1.
+    pub fn from_item_attack(
+        item_attack: &ItemAttack,
+        range_type: RangeType,
+        range_modifier: i32,
+    ) -> Self {
+        let description = match range_type {
+            RangeType::Melee => "A basic melee attack",
+            RangeType::Ranged => "A ranged attack",
+            RangeType::Siege => "A siege attack",
+        };
+        Self {
+            range_type,
+            ..Self::new(
+                item_attack.name.clone(),
+                item_attack.damage,
+                item_attack.damage_type,
+                range_type.base_range() + range_modifier,
+                description,
+            )
+            .times(item_attack.attack_times)
+        }
+    }
+
+    /// Returns this attack striking `attack_times` times per use (at least 1).
+    pub fn times(mut self, attack_times: u32) -> Self {
+        self.attack_times = attack_times.max(1);
+        self
     }
 
     /// Checks if this attack can reach a target at the given distance.
@@ -129,6 +228,18 @@ impl Attack {
     pub fn can_reach(&self, distance: i32) -> bool {
         distance > 0 && distance <= self.range
     }
+
+    /// Checks if this attack may be used to counter `incoming` from a target
+    /// at the given distance.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both attacks have the same range type (see
+    /// [`RangeType::can_retaliate_against`]) and this attack reaches the
+    /// attacker, `false` otherwise.
+    pub fn can_retaliate(&self, incoming: &Attack, distance: i32) -> bool {
+        self.range_type.can_retaliate_against(incoming.range_type) && self.can_reach(distance)
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +253,7 @@ mod tests {
         assert_eq!(attack.damage, 15);
         assert_eq!(attack.damage_type, DamageType::Slash);
         assert_eq!(attack.range, 1);
+        assert_eq!(attack.range_type, RangeType::Melee);
     }
 
     #[test]
@@ -151,6 +263,15 @@ mod tests {
         assert_eq!(attack.damage, 12);
         assert_eq!(attack.damage_type, DamageType::Pierce);
         assert_eq!(attack.range, 3);
+        assert_eq!(attack.range_type, RangeType::Ranged);
+        assert!(!attack.magical);
+    }
+
+    #[test]
+    fn test_create_magic_attack() {
+        let attack = Attack::magic("Arcane Bolt", 16, 1, DamageType::Fire, 3);
+        assert!(attack.magical);
+        assert_eq!(attack.range, 3);
     }
 
     #[test]
@@ -164,4 +285,40 @@ mod tests {
         assert!(ranged.can_reach(3));
         assert!(!ranged.can_reach(4));
     }
+
+    #[test]
+    fn test_retaliation_matches_range_type() {
+        let sword = Attack::melee("Sword", 10, 1, DamageType::Slash);
+        let bow = Attack::ranged("Bow", 10, 1, DamageType::Pierce, 3);
+        let catapult = Attack::siege("Catapult", 20, 1, DamageType::Crush, 5);
+
+        assert!(sword.can_retaliate(&sword, 1));
+        assert!(!sword.can_retaliate(&bow, 1));
+        assert!(bow.can_retaliate(&bow, 3));
+        assert!(!bow.can_retaliate(&bow, 4));
+        assert!(!bow.can_retaliate(&sword, 1));
+        assert!(!bow.can_retaliate(&catapult, 3));
+        assert!(catapult.can_retaliate(&catapult, 5));
+    }
+
+    #[test]
+    fn test_item_attack_uses_weapon_range() {
+        let item_attack = ItemAttack::new("Volley", 9, 1, DamageType::Pierce);
+        let attack = Attack::from_item_attack(&item_attack, RangeType::Ranged, 1);
+        assert_eq!(attack.range_type, RangeType::Ranged);
+        assert_eq!(attack.range, 4);
+
+        let attack = Attack::from_item_attack(&item_attack, RangeType::Melee, 0);
+        assert_eq!(attack.range_type, RangeType::Melee);
+        assert_eq!(attack.range, 1);
+    }
+
+    #[test]
+    fn test_attack_times() {
+        let attack = Attack::melee("Flurry", 4, 3, DamageType::Slash);
+        assert_eq!(attack.attack_times, 3);
+
+        let attack = Attack::new("Jab", 3, DamageType::Blunt, 1, "A quick jab").times(0);
+        assert_eq!(attack.attack_times, 1);
+    }
 }
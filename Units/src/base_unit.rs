@@ -4,11 +4,13 @@
 //! shared by all concrete unit implementations. It handles stat caching, equipment
 //! bonuses, and level progression.
 
+use crate::alignment::Alignment;
 use crate::attack::Attack;
 use crate::combat::{CombatStats, DamageType};
+use crate::movement_type::MovementType;
 use crate::unit_race::{Race, Terrain};
 use crate::unit_type::UnitType;
-use graphics::{HexCoord, SpriteType};
+use hex::HexCoord;
 use items::{Equipment, Item};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -58,6 +60,12 @@ pub struct BaseUnit {
     /// Optional per-unit terrain -> defense mappings (percentage 0-100).
     /// If present, these override the race-based terrain defense values.
     pub terrain_defenses: Option<HashMap<Terrain, u8>>,
+    /// How the unit moves across terrain (defaults to the race's movement type)
+    #[serde(default)]
+    pub movement_type: MovementType,
+    /// Which times of day favour the unit (defaults to the race's alignment)
+    #[serde(default)]
+    pub alignment: Alignment,
 
     // Cached values (recalculated when equipment/level changes)
     pub cached_defense: i32,
@@ -65,8 +73,8 @@ pub struct BaseUnit {
     pub cached_movement: i32,
     pub cached_max_health: i32,
 
-    // Visual representation
-    pub sprite_type: SpriteType,
+    // Visual representation: name of the sprite that renders the unit
+    pub sprite: String,
 
     // Evolution chain
     pub evolution_previous: Option<UnitType>,
@@ -102,10 +110,10 @@ impl BaseUnit {
     ///
     /// A new `BaseUnit` instance at level 1 with 0 experience.
     ///
-    /// Convenience constructor for units that automatically uses `SpriteType::Unit`.
+    /// Convenience constructor for units that use the generic unit sprite.
     ///
     /// This is the recommended constructor for creating game units, as it eliminates
-    /// the need to specify the sprite type which is always `SpriteType::Unit` for units.
+    /// the need to specify the sprite, which is the generic `"Unit"` sprite.
     ///
     /// # Arguments
     ///
@@ -134,24 +142,25 @@ impl BaseUnit {
             race,
             unit_type,
             description,
-            SpriteType::Unit,
+            "Unit",
             evolution_previous,
             evolution_next,
             combat_stats,
         )
     }
 
-    /// Creates a new base unit with a custom sprite type.
+    /// Creates a new base unit with a custom sprite.
     ///
-    /// This is the lower-level constructor that allows specifying any sprite type.
-    /// Most code should use `BaseUnit::new()` instead, which defaults to `SpriteType::Unit`.
+    /// This is the lower-level constructor that allows naming any sprite; the
+    /// renderer decides how the name is drawn. Most code should use
+    /// `BaseUnit::new()` instead, which uses the generic `"Unit"` sprite.
     ///
     /// # TODO: Refactor for Production
     ///
     /// This constructor has too many arguments (10/7 allowed by Clippy).
     /// For production, consider one of these improvements:
     /// - **Builder Pattern**: Create `BaseUnitBuilder` for fluent construction
-    /// - **Config Struct**: Group related params (unit_type, description, sprite_type, evolutions)
+    /// - **Config Struct**: Group related params (unit_type, description, sprite, evolutions)
     ///   into a `UnitDefinition` or `UnitTemplate` struct
     /// - **Factory Method**: Move construction logic to a factory that loads unit definitions
     ///   from configuration/data files
@@ -162,7 +171,7 @@ impl BaseUnit {
         race: Race,
         unit_type: String,
         description: String,
-        sprite_type: SpriteType,
+        sprite: &str,
         evolution_previous: Option<UnitType>,
         evolution_next: Vec<UnitType>,
         combat_stats: CombatStats,
@@ -188,7 +197,9 @@ impl BaseUnit {
             cached_movement: base_movement,
             cached_max_health: max_health,
             terrain_defenses: None,
-            sprite_type,
+            movement_type: race.default_movement_type(),
+            alignment: race.default_alignment(),
+            sprite: sprite.to_string(),
             evolution_previous,
             evolution_next,
             attacks: Vec::new(), // Will be set by unit constructor
@@ -287,16 +298,14 @@ impl BaseUnit {
         self.combat_stats.health =
             (self.cached_max_health as f32 * current_health_percentage) as i32;
 
-        // Update range from equipment if overridden
-        if let Some(_range_override) = self.equipment.get_range_type_override() {
-            // Range type override from equipment
-            self.combat_stats.attack_range = self.combat_stats.range_category.base_range()
-                + self.equipment.get_total_range_modifier();
-        } else {
-            // Keep current range category, just update range modifier from equipment
-            self.combat_stats.attack_range = self.combat_stats.range_category.base_range()
-                + self.equipment.get_total_range_modifier();
-        }
+        // Update range from equipment: a weapon's range type override replaces
+        // the unit's own range category
+        let range_type = self
+            .equipment
+            .get_range_type_override()
+            .unwrap_or(self.combat_stats.range_category);
+        self.combat_stats.attack_range =
+            range_type.base_range() + self.equipment.get_total_range_modifier();
 
         // Ensure minimum range of 1
         self.combat_stats.attack_range = self.combat_stats.attack_range.max(1);
@@ -309,6 +318,24 @@ impl BaseUnit {
         self.apply_passive_bonuses();
     }
 
+    /// Removes the level and equipment bonuses that `recalculate_stats` folded
+    /// into `combat_stats.max_health` and `combat_stats.movement_speed`.
+    ///
+    /// Call this before changing equipment and follow it with
+    /// `recalculate_stats`, so the bonuses of removed items are dropped
+    /// instead of stacking on top of the previous totals. Current health keeps
+    /// its percentage of maximum health.
+    pub fn remove_equipment_bonuses(&mut self) {
+        let current_health_percentage = self.combat_stats.health_percentage();
+        let level_health_bonus = (self.level - 1) * 5;
+
+        self.combat_stats.max_health -=
+            level_health_bonus + self.equipment.get_total_health_bonus();
+        self.combat_stats.movement_speed -= self.equipment.get_total_movement_modifier();
+        self.combat_stats.health =
+            (self.combat_stats.max_health as f32 * current_health_percentage).round() as i32;
+    }
+
     /// Calculate terrain hit chance for the given terrain.
     ///
     /// This should be called when determining combat effectiveness based on the terrain
@@ -527,7 +554,7 @@ impl BaseUnit {
     ///
     /// * `name` - Display name of the attack (e.g., "Sword Slash")
     /// * `damage` - Base damage dealt by this attack
-    /// * `attack_times` - Number of strikes (currently unused but kept for compatibility)
+    /// * `attack_times` - Number of strikes per use
     /// * `damage_type` - Type of damage dealt (affects enemy resistances)
     ///
     /// # Returns
@@ -551,7 +578,7 @@ impl BaseUnit {
     ///
     /// * `name` - Display name of the attack (e.g., "Bow Shot")
     /// * `damage` - Base damage dealt by this attack
-    /// * `attack_times` - Number of shots (currently unused but kept for compatibility)
+    /// * `attack_times` - Number of shots per use
     /// * `damage_type` - Type of damage dealt (affects enemy resistances)
     /// * `range` - Maximum range in hexes (minimum 1)
     ///
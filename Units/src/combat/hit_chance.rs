@@ -0,0 +1,168 @@
+//! Per-strike hit chance calculation.
+//!
+//! The chance for a strike to hit is read from the defender's side:
+//!
+//! 1. **Terrain**: the defender's hit chance on the terrain it stands on
+//!    ([`Unit::get_terrain_hit_chance`])
+//! 2. **Structure**: a structure sheltering the defender lowers the chance by
+//!    its [`Structure::defense_bonus`]
+//! 3. **Buffs/debuffs**: active `Defense` and `DodgeChance` buffs on the
+//!    defender lower the chance, debuffs raise it
+//! 4. **Attack type**: magical attacks ignore all of the above and always hit
+//!    with [`MAGIC_HIT_CHANCE`]
+//!
+//! The result is clamped to 0-100. [`HitChanceBreakdown`] keeps every step so
+//! the UI can explain the number.
+
+use crate::ability::{ActiveEffect, BuffStat};
+use crate::attack::Attack;
+use crate::structures::Structure;
+use crate::unit_race::Terrain;
+use crate::unit_trait::Unit;
+use serde::{Deserialize, Serialize};
+
+/// Hit chance of magical attacks, regardless of the defender's defenses.
+pub const MAGIC_HIT_CHANCE: u8 = 70;
+
+/// A single adjustment to the base hit chance.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HitChanceModifier {
+    /// What caused the adjustment (e.g. "Stone Wall", "Defense buff")
+    pub source: String,
+    /// Percentage points added to the hit chance (negative = harder to hit)
+    pub amount: i32,
+}
+
+/// How the hit chance of one strike was computed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HitChanceBreakdown {
+    /// Name of the terrain the defender stands on
+    pub terrain: String,
+    /// Defender's hit chance on that terrain
+    pub base: u8,
+    /// Structure and buff adjustments, in the order they were applied
+    pub modifiers: Vec<HitChanceModifier>,
+    /// Whether the strike is magical (fixed [`MAGIC_HIT_CHANCE`])
+    pub magical: bool,
+    /// Final chance to hit (0-100)
+    pub chance: u8,
+}
+
+impl HitChanceBreakdown {
+    /// Creates a breakdown from the defender's terrain hit chance alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `terrain` - Name of the terrain the defender stands on
+    /// * `base` - Defender's hit chance on that terrain
+    pub fn new(terrain: impl Into<String>, base: u8) -> Self {
+        let base = base.min(100);
+        Self {
+            terrain: terrain.into(),
+            base,
+            modifiers: Vec::new(),
+            magical: false,
+            chance: base,
+        }
+    }
+
+    /// Adds an adjustment; zero adjustments are ignored.
+    pub fn with_modifier(mut self, source: impl Into<String>, amount: i32) -> Self {
+        if amount != 0 {
+            self.modifiers.push(HitChanceModifier {
+                source: source.into(),
+                amount,
+            });
+            self.recompute();
+        }
+        self
+    }
+
+    /// Marks the strike as magical, fixing the chance at [`MAGIC_HIT_CHANCE`].
+    pub fn with_magical(mut self, magical: bool) -> Self {
+        self.magical = magical;
+        self.recompute();
+        self
+    }
+
+    /// Computes the hit chance of `attack` against `defender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `defender` - The unit being struck
+    /// * `terrain_name` - Name of the terrain the defender stands on
+    /// * `defense_class` - Defense class of that terrain
+    /// * `structure` - Structure sheltering the defender, if any
+    /// * `attack` - The attack being used
+    pub fn for_strike(
+        defender: &dyn Unit,
+        terrain_name: &str,
+        defense_class: Terrain,
+        structure: Option<&dyn Structure>,
+        attack: &Attack,
+    ) -> Self {
+        let mut breakdown = Self::new(terrain_name, defender.get_terrain_hit_chance(defense_class));
+
+        if let Some(structure) = structure {
+            breakdown = breakdown.with_modifier(structure.name(), -structure.defense_bonus());
+        }
+
+        // Sorted so the breakdown does not depend on HashMap order
+        let mut effects: Vec<(&'static str, i32)> = defender
+            .ability_state()
+            .active_effects
+            .values()
+            .filter_map(|instance| match &instance.effect {
+                ActiveEffect::Buff { stat, amount, .. } => {
+                    buff_label(stat, false).map(|label| (label, -amount))
+                }
+                ActiveEffect::Debuff { stat, amount, .. } => {
+                    buff_label(stat, true).map(|label| (label, *amount))
+                }
+                _ => None,
+            })
+            .collect();
+        effects.sort();
+        for (label, amount) in effects {
+            breakdown = breakdown.with_modifier(label, amount);
+        }
+
+        breakdown.with_magical(attack.magical)
+    }
+
+    /// Human-readable lines explaining the chance, ending with the total.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("{}: {}%", self.terrain, self.base)];
+        lines.extend(
+            self.modifiers
+                .iter()
+                .map(|m| format!("{}: {:+}%", m.source, m.amount)),
+        );
+        if self.magical {
+            lines.push(format!("Magical: always {}%", MAGIC_HIT_CHANCE));
+        }
+        lines.push(format!("Hit chance: {}%", self.chance));
+        lines
+    }
+
+    fn recompute(&mut self) {
+        self.chance = if self.magical {
+            MAGIC_HIT_CHANCE
+        } else {
+            let total: i32 =
+                self.base as i32 + self.modifiers.iter().map(|m| m.amount).sum::<i32>();
+            total.clamp(0, 100) as u8
+        };
+    }
+}
+
+/// Label for buffs that change how easily a unit is hit.
+fn buff_label(stat: &BuffStat, debuff: bool) -> Option<&'static str> {
+    match (stat, debuff) {
+        (BuffStat::Defense, false) => Some("Defense buff"),
+        (BuffStat::Defense, true) => Some("Defense debuff"),
+        (BuffStat::DodgeChance, false) => Some("Dodge buff"),
+        (BuffStat::DodgeChance, true) => Some("Dodge debuff"),
+        _ => None,
+    }
+}
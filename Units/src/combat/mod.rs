@@ -1,88 +1,108 @@
 //! Combat types and utilities used by units and combat resolver.
 //!
-//! This module centralizes damage types, resistances, range categories,
-//! combat statistics, and combat result/action types. It was previously
-//! defined in the separate `combat` crate; for workspace cohesion these
-//! types live here so other crates (like `combat`) can depend on `units`.
+//! This module centralizes resistances, combat statistics, and combat
+//! result/action types, and re-exports the shared [`DamageType`] registry and
+//! [`RangeType`] from the `items` crate. It was previously defined in the separate
+//! `combat` crate; for workspace cohesion these types live here so other
+//! crates (like `combat`) can depend on `units`.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum DamageType {
-    Blunt,
-    Pierce,
-    Fire,
-    Dark,
-    Slash,
-    Crush,
-}
+pub mod hit_chance;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum RangeCategory {
-    Melee,
-    Range,
-    Siege,
-}
+pub use items::{DamageCategory, DamageType, RangeType};
 
-impl RangeCategory {
-    pub fn base_range(&self) -> i32 {
-        match self {
-            RangeCategory::Melee => 1,
-            RangeCategory::Range => 3,
-            RangeCategory::Siege => 5,
-        }
-    }
-}
+pub use hit_chance::{HitChanceBreakdown, HitChanceModifier, MAGIC_HIT_CHANCE};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Highest resistance: immune to the damage type.
+pub const MAX_RESISTANCE: i32 = 100;
+
+/// Lowest resistance: a weakness that doubles the damage taken.
+pub const MIN_RESISTANCE: i32 = -100;
+
+/// Percentage damage reduction per damage type.
+///
+/// Positive values reduce incoming damage of a type, negative values are
+/// weaknesses that increase it. Values are clamped to
+/// [`MIN_RESISTANCE`]..=[`MAX_RESISTANCE`]; damage types without an entry
+/// have no resistance.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Resistances {
-    pub blunt: u8,
-    pub pierce: u8,
-    pub fire: u8,
-    pub dark: u8,
-    pub slash: u8,
-    pub crush: u8,
+    values: BTreeMap<DamageType, i32>,
 }
 
 impl Resistances {
-    pub fn new(blunt: u8, pierce: u8, fire: u8, dark: u8, slash: u8, crush: u8) -> Self {
-        Self {
-            blunt: blunt.min(100),
-            pierce: pierce.min(100),
-            fire: fire.min(100),
-            dark: dark.min(100),
-            slash: slash.min(100),
-            crush: crush.min(100),
+    /// Creates resistances of 0 against every damage type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates resistances from `(damage type, percent)` pairs.
+    ///
+    /// Damage types that are not listed have no resistance.
+    pub fn from_pairs(pairs: &[(DamageType, i32)]) -> Self {
+        let mut resistances = Self::new();
+        for &(damage_type, value) in pairs {
+            resistances.set_resistance(damage_type, value);
         }
+        resistances
     }
 
-    pub fn get_resistance(&self, damage_type: DamageType) -> u8 {
-        match damage_type {
-            DamageType::Blunt => self.blunt,
-            DamageType::Pierce => self.pierce,
-            DamageType::Fire => self.fire,
-            DamageType::Dark => self.dark,
-            DamageType::Slash => self.slash,
-            DamageType::Crush => self.crush,
+    /// Returns these resistances with `damage_type` set to `value`.
+    pub fn with(mut self, damage_type: DamageType, value: i32) -> Self {
+        self.set_resistance(damage_type, value);
+        self
+    }
+
+    /// Returns the resistance against `damage_type`, in percent.
+    pub fn get_resistance(&self, damage_type: DamageType) -> i32 {
+        self.values.get(&damage_type).copied().unwrap_or(0)
+    }
+
+    /// Sets the resistance against `damage_type`, clamped to
+    /// [`MIN_RESISTANCE`]..=[`MAX_RESISTANCE`].
+    pub fn set_resistance(&mut self, damage_type: DamageType, value: i32) {
+        let clamped = value.clamp(MIN_RESISTANCE, MAX_RESISTANCE);
+        if clamped == 0 {
+            self.values.remove(&damage_type);
+        } else {
+            self.values.insert(damage_type, clamped);
+        }
+    }
+
+    /// Factor damage of `damage_type` is multiplied by: below 1 for
+    /// resistances, above 1 for weaknesses.
+    pub fn damage_multiplier(&self, damage_type: DamageType) -> f32 {
+        1.0 - self.get_resistance(damage_type) as f32 / 100.0
+    }
+
+    /// Adds `other`'s resistances to these, type by type.
+    pub fn add(&mut self, other: &Resistances) {
+        for (&damage_type, &value) in &other.values {
+            self.set_resistance(damage_type, self.get_resistance(damage_type) + value);
         }
     }
 
-    pub fn set_resistance(&mut self, damage_type: DamageType, value: u8) {
-        let clamped = value.min(100);
-        match damage_type {
-            DamageType::Blunt => self.blunt = clamped,
-            DamageType::Pierce => self.pierce = clamped,
-            DamageType::Fire => self.fire = clamped,
-            DamageType::Dark => self.dark = clamped,
-            DamageType::Slash => self.slash = clamped,
-            DamageType::Crush => self.crush = clamped,
+    /// Subtracts `other`'s resistances from these, type by type.
+    pub fn subtract(&mut self, other: &Resistances) {
+        for (&damage_type, &value) in &other.values {
+            self.set_resistance(damage_type, self.get_resistance(damage_type) - value);
         }
     }
-}
 
-impl Default for Resistances {
-    fn default() -> Self {
-        Self::new(0, 0, 0, 0, 0, 0)
+    /// Every damage type in registry order with its resistance, including
+    /// types with no resistance.
+    pub fn iter(&self) -> impl Iterator<Item = (DamageType, i32)> + '_ {
+        DamageType::all().map(move |damage_type| (damage_type, self.get_resistance(damage_type)))
+    }
+
+    /// Damage types the unit is weak against (negative resistance).
+    pub fn weaknesses(&self) -> Vec<DamageType> {
+        self.iter()
+            .filter(|(_, value)| *value < 0)
+            .map(|(damage_type, _)| damage_type)
+            .collect()
     }
 }
 
@@ -95,7 +115,7 @@ pub struct CombatStats {
     pub attack_strength: u32,
     pub attacks_per_round: u32,
     pub movement_speed: i32,
-    pub range_category: RangeCategory,
+    pub range_category: RangeType,
     pub attack_range: i32,
     pub resistances: Resistances,
     pub terrain_hit_chance: u8,
@@ -107,7 +127,7 @@ impl CombatStats {
         max_health: i32,
         base_attack: u32,
         movement_speed: i32,
-        range_category: RangeCategory,
+        range_category: RangeType,
         resistances: Resistances,
     ) -> Self {
         Self::new_with_attacks(
@@ -125,7 +145,7 @@ impl CombatStats {
         max_health: i32,
         base_attack: u32,
         movement_speed: i32,
-        range_category: RangeCategory,
+        range_category: RangeType,
         resistances: Resistances,
         attack_strength: u32,
         attacks_per_round: u32,
@@ -167,8 +187,7 @@ impl CombatStats {
         base_damage: u32,
         damage_type: DamageType,
     ) -> u32 {
-        let resistance = self.resistances.get_resistance(damage_type);
-        let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
+        let resistance_multiplier = self.resistances.damage_multiplier(damage_type);
         let actual_damage = (base_damage as f32 * resistance_multiplier) as u32;
 
         self.health = (self.health - actual_damage as i32).max(0);
@@ -194,8 +213,7 @@ impl CombatStats {
 
     pub fn calculate_damage_to(&self, target: &CombatStats, damage_type: DamageType) -> u32 {
         let base_damage = self.get_total_attack();
-        let resistance = target.resistances.get_resistance(damage_type);
-        let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
+        let resistance_multiplier = target.resistances.damage_multiplier(damage_type);
         let final_damage = (base_damage as f32 * resistance_multiplier) as u32;
         final_damage.max(1)
     }
@@ -205,8 +223,7 @@ impl CombatStats {
         target: &CombatStats,
         damage_type: DamageType,
     ) -> u32 {
-        let resistance = target.resistances.get_resistance(damage_type);
-        let resistance_multiplier = 1.0 - (resistance as f32 / 100.0);
+        let resistance_multiplier = target.resistances.damage_multiplier(damage_type);
 
         let modified_strength = (self.attack_strength as i32 + self.attack_modifier).max(0) as u32;
         let total_damage = modified_strength * self.attacks_per_round;
@@ -12,15 +12,22 @@
 //! - **[`Race`]**: Character races with terrain-specific bonuses
 //! - **[`UnitClass`]**: Character classes with unique resistances and abilities
 //! - **[`Attack`]**: Attack definitions with damage types and ranges
+//! - **[`TerrainRegistry`]**: Terrain definitions (movement, defense class, sight) and map features
+//! - **[`MovementType`]**: Per-unit terrain movement costs (foot, mounted, flying, ...)
+//! - **[`Alignment`]**: Lawful/neutral/chaotic damage scaling by time of day
+//! - **[`TeamRoster`]**: Scenario teams with colors, alliances and turn order
 
 #![allow(dead_code)]
 
 pub mod ability; // Ability system (passive, active, aura)
+pub mod alignment; // Unit alignments and time-of-day damage scaling
 pub mod attack;
 pub mod base_unit;
 pub mod combat;
+pub mod movement_type; // Movement types and terrain cost tables
 pub mod structures; // Structure system (walls, towers, buildings, etc.)
-pub mod team; // Team affiliation for units and structures
+pub mod team; // Team ids, team roster, diplomacy and turn order
+pub mod terrain_registry; // Terrain definitions and overlay features
 pub mod unit_factory;
 pub mod unit_race;
 pub mod unit_registry; // Dynamic unit registry
@@ -35,14 +42,17 @@ pub use ability::{
     AuraAbility, AuraEffect, AuraTarget, BuffStat, PassiveAbility, PassiveEffect, PassiveTrigger,
     TargetType,
 };
+pub use alignment::Alignment;
 pub use attack::Attack;
 pub use base_unit::BaseUnit;
 pub use items::{ConsumableEffect, Equipment, Item, ItemProperties, ItemType, RangeType};
-pub use team::Team;
+pub use movement_type::MovementType;
+pub use team::{Stance, Team, TeamInfo, TeamRoster};
+pub use terrain_registry::{TerrainDefinition, TerrainFeature, TerrainRegistry};
 pub use unit_factory::UnitFactory;
 pub use unit_race::{Race, Terrain};
 pub use unit_registry::{UnitRegistry, UnitTypeInfo};
-pub use unit_trait::UnitId;
+pub use unit_trait::{UnitClone, UnitId};
 pub use unit_type::UnitType;
 
 // Export the trait-based Unit interface
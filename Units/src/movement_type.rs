@@ -0,0 +1,157 @@
+//! Movement types and their terrain cost tables.
+//!
+//! Every unit has a [`MovementType`] that decides how many movement points it
+//! pays to enter each terrain and which terrains it cannot enter at all. The
+//! default comes from the unit's race
+//! ([`Race::default_movement_type`](crate::Race::default_movement_type)) and
+//! unit definitions may override it (e.g. knights are mounted).
+//!
+//! Costs are looked up by [`TerrainDefinition::id`]; terrains missing from a
+//! table keep their base [`movement_cost`](TerrainDefinition::movement_cost)
+//! and impassability. Listing a terrain in a cost table makes it passable for
+//! that movement type even if the terrain itself is impassable.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use units::{MovementType, TerrainRegistry};
+//!
+//! let registry = TerrainRegistry::builtin();
+//! let forest = registry.get("forest").unwrap();
+//! assert_eq!(MovementType::Woodland.movement_cost(forest, &[]), Some(1));
+//! assert_eq!(MovementType::Mounted.movement_cost(forest, &[]), Some(3));
+//! ```
+
+use crate::terrain_registry::{TerrainDefinition, TerrainFeature};
+use serde::{Deserialize, Serialize};
+
+/// How a unit moves across terrain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MovementType {
+    /// Ordinary infantry; pays the terrain's base cost
+    #[default]
+    Foot,
+    /// Horses and riders; fast on open ground, slow in woods and swamps
+    Mounted,
+    /// Small, nimble units; cross rough terrain easily
+    Elusive,
+    /// Swamp and river dwellers
+    Amphibious,
+    /// Walking dead; at home in haunted woods and swamps
+    Undead,
+    /// Flyers; every terrain costs 1, mountains included
+    Flying,
+    /// Forest folk; move through woods like open ground
+    Woodland,
+    /// Mountain folk; climb hills and cross mountains
+    Mountainfoot,
+}
+
+impl MovementType {
+    /// Every movement type, in declaration order.
+    pub const ALL: [MovementType; 8] = [
+        MovementType::Foot,
+        MovementType::Mounted,
+        MovementType::Elusive,
+        MovementType::Amphibious,
+        MovementType::Undead,
+        MovementType::Flying,
+        MovementType::Woodland,
+        MovementType::Mountainfoot,
+    ];
+
+    /// Display name of the movement type.
+    pub fn name(self) -> &'static str {
+        match self {
+            MovementType::Foot => "Foot",
+            MovementType::Mounted => "Mounted",
+            MovementType::Elusive => "Elusive",
+            MovementType::Amphibious => "Amphibious",
+            MovementType::Undead => "Undead",
+            MovementType::Flying => "Flying",
+            MovementType::Woodland => "Woodland",
+            MovementType::Mountainfoot => "Mountainfoot",
+        }
+    }
+
+    /// Looks up a movement type by its [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.name() == name)
+    }
+
+    /// Terrain costs that differ from the terrain's base cost.
+    fn cost_table(self) -> &'static [(&'static str, i32)] {
+        match self {
+            MovementType::Foot | MovementType::Flying => &[],
+            MovementType::Mounted => &[
+                ("forest", 3),
+                ("dense_forest", 4),
+                ("haunted_woods", 3),
+                ("hills", 2),
+                ("swamp", 4),
+            ],
+            MovementType::Elusive => &[("hills", 2), ("swamp", 2)],
+            MovementType::Amphibious => &[("swamp", 1)],
+            MovementType::Undead => &[("haunted_woods", 1), ("swamp", 2)],
+            MovementType::Woodland => &[("forest", 1), ("dense_forest", 1)],
+            MovementType::Mountainfoot => &[("hills", 1), ("mountain", 3)],
+        }
+    }
+
+    /// Terrains this movement type can never enter.
+    fn impassable_terrains(self) -> &'static [&'static str] {
+        match self {
+            MovementType::Mounted => &["mountain"],
+            _ => &[],
+        }
+    }
+
+    /// Whether unbridged rivers cost this movement type nothing extra.
+    fn ignores_rivers(self) -> bool {
+        matches!(self, MovementType::Amphibious | MovementType::Flying)
+    }
+
+    /// Movement points needed to enter a tile.
+    ///
+    /// Overlay features follow
+    /// [`TerrainDefinition::movement_cost_with`], except that movement types
+    /// which ignore rivers pay nothing extra for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `terrain` - The tile's terrain definition
+    /// * `features` - Overlay features on the tile
+    ///
+    /// # Returns
+    ///
+    /// The movement cost, or `None` if this movement type cannot enter the tile
+    pub fn movement_cost(
+        self,
+        terrain: &TerrainDefinition,
+        features: &[TerrainFeature],
+    ) -> Option<i32> {
+        if self == MovementType::Flying {
+            return Some(1);
+        }
+        if self.impassable_terrains().contains(&terrain.id.as_str()) {
+            return None;
+        }
+        let base = match self.cost_table().iter().find(|(id, _)| *id == terrain.id) {
+            Some((_, cost)) => *cost,
+            None if terrain.impassable => return None,
+            None => terrain.movement_cost,
+        };
+
+        let cost = if self.ignores_rivers() && features.contains(&TerrainFeature::River) {
+            let features: Vec<TerrainFeature> = features
+                .iter()
+                .copied()
+                .filter(|f| *f != TerrainFeature::River)
+                .collect();
+            TerrainDefinition::cost_with_features(base, &features)
+        } else {
+            TerrainDefinition::cost_with_features(base, features)
+        };
+        Some(cost)
+    }
+}
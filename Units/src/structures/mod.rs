@@ -54,6 +54,6 @@ pub mod structure_units;
 pub use occupancy::{can_occupy, is_occupying, OccupancyBonus};
 pub use structure_factory::StructureFactory;
 pub use structure_stats::StructureStats;
-pub use structure_trait::{Structure, StructureId};
+pub use structure_trait::{Structure, StructureClone, StructureId};
 pub use structure_type::{StructureCategory, StructureType};
 pub use structure_units::{House, StoneWall};
@@ -48,24 +48,7 @@ impl OccupancyBonus {
         stats.attack_modifier += self.attack;
 
         // Apply resistance bonuses
-        stats.resistances.blunt = (stats.resistances.blunt as i32
-            + self.resistance_bonuses.blunt as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.pierce = (stats.resistances.pierce as i32
-            + self.resistance_bonuses.pierce as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.fire = (stats.resistances.fire as i32
-            + self.resistance_bonuses.fire as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.dark = (stats.resistances.dark as i32
-            + self.resistance_bonuses.dark as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.slash = (stats.resistances.slash as i32
-            + self.resistance_bonuses.slash as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.crush = (stats.resistances.crush as i32
-            + self.resistance_bonuses.crush as i32)
-            .clamp(0, 100) as u8;
+        stats.resistances.add(&self.resistance_bonuses);
     }
 
     /// Removes this bonus from a unit's combat stats.
@@ -73,24 +56,7 @@ impl OccupancyBonus {
         stats.attack_modifier -= self.attack;
 
         // Remove resistance bonuses
-        stats.resistances.blunt = (stats.resistances.blunt as i32
-            - self.resistance_bonuses.blunt as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.pierce = (stats.resistances.pierce as i32
-            - self.resistance_bonuses.pierce as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.fire = (stats.resistances.fire as i32
-            - self.resistance_bonuses.fire as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.dark = (stats.resistances.dark as i32
-            - self.resistance_bonuses.dark as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.slash = (stats.resistances.slash as i32
-            - self.resistance_bonuses.slash as i32)
-            .clamp(0, 100) as u8;
-        stats.resistances.crush = (stats.resistances.crush as i32
-            - self.resistance_bonuses.crush as i32)
-            .clamp(0, 100) as u8;
+        stats.resistances.subtract(&self.resistance_bonuses);
     }
 }
 
@@ -6,7 +6,7 @@
 use super::structure_units::house::House;
 use super::structure_units::stone_wall::StoneWall;
 use crate::team::Team;
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Factory for creating structure instances.
 pub struct StructureFactory;
@@ -31,12 +31,12 @@ impl StructureFactory {
     ///
     /// ```rust,no_run
     /// use units::structures::StructureFactory;
-    /// use graphics::HexCoord;
+    /// use hex::HexCoord;
     /// use units::Team;
     ///
     /// let wall = StructureFactory::create_stone_wall(
     ///     HexCoord::new(10, 10),
-    ///     Team::Player,
+    ///     Team::PLAYER,
     /// );
     /// ```
     pub fn create_stone_wall(position: HexCoord, team: Team) -> Box<StoneWall> {
@@ -62,12 +62,12 @@ impl StructureFactory {
     ///
     /// ```rust,no_run
     /// use units::structures::StructureFactory;
-    /// use graphics::HexCoord;
+    /// use hex::HexCoord;
     /// use units::Team;
     ///
     /// let house = StructureFactory::create_house(
     ///     HexCoord::new(10, 10),
-    ///     Team::Player,
+    ///     Team::PLAYER,
     /// );
     /// ```
     pub fn create_house(position: HexCoord, team: Team) -> Box<House> {
@@ -9,7 +9,7 @@ use crate::combat::Resistances;
 use crate::team::Team;
 use crate::unit_race::Terrain;
 use crate::unit_trait::UnitId;
-use graphics::HexCoord;
+use hex::HexCoord;
 use uuid::Uuid;
 
 use super::structure_type::StructureType;
@@ -17,6 +17,29 @@ use super::structure_type::StructureType;
 /// Unique identifier for structures.
 pub type StructureId = Uuid;
 
+/// Object-safe cloning for boxed structures.
+///
+/// Implemented automatically for every `Structure` that is `Clone`.
+pub trait StructureClone {
+    /// Returns a boxed deep copy of this structure.
+    fn clone_box(&self) -> Box<dyn Structure>;
+}
+
+impl<T> StructureClone for T
+where
+    T: 'static + Structure + Clone,
+{
+    fn clone_box(&self) -> Box<dyn Structure> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Structure> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
 /// Core trait that all structures must implement.
 ///
 /// The `Structure` trait provides a unified interface for all structure types,
@@ -26,18 +49,18 @@ pub type StructureId = Uuid;
 ///
 /// ```rust,no_run
 /// use units::structures::{Structure, StructureFactory};
-/// use graphics::HexCoord;
+/// use hex::HexCoord;
 /// use units::Team;
 ///
 /// let wall = StructureFactory::create_stone_wall(
 ///     HexCoord::new(5, 5),
-///     Team::Player,
+///     Team::PLAYER,
 /// );
 ///
 /// println!("Structure: {}", wall.name());
 /// println!("Durability: {}/{}", wall.current_durability(), wall.max_durability());
 /// ```
-pub trait Structure {
+pub trait Structure: StructureClone {
     // ===== Identity =====
 
     /// Returns the structure's unique identifier.
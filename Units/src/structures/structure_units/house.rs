@@ -5,14 +5,14 @@
 //! units to rest and recover.
 
 use crate::attack::Attack;
-use crate::combat::Resistances;
+use crate::combat::{DamageType, Resistances};
 use crate::structures::structure_stats::StructureStats;
 use crate::structures::structure_trait::{Structure, StructureId};
 use crate::structures::structure_type::StructureType;
 use crate::team::Team;
 use crate::unit_race::Terrain;
 use crate::unit_trait::UnitId;
-use graphics::HexCoord;
+use hex::HexCoord;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -36,10 +36,10 @@ use uuid::Uuid;
 ///
 /// ```rust,no_run
 /// use units::structures::{House, Structure};
-/// use graphics::HexCoord;
+/// use hex::HexCoord;
 /// use units::Team;
 ///
-/// let mut house = House::new(HexCoord::new(5, 5), Team::Player);
+/// let mut house = House::new(HexCoord::new(5, 5), Team::PLAYER);
 /// assert_eq!(house.max_durability(), 200);
 /// assert_eq!(house.defense_bonus(), 5);
 /// assert_eq!(house.healing_per_turn(), 10);
@@ -75,14 +75,14 @@ impl House {
         stats.repair_rate = 3; // Repairs 3 HP/turn when occupied
 
         // Resistances - wood and thatch construction
-        stats.resistances = Resistances::new(
-            40, // blunt - moderate resistance
-            30, // pierce - low resistance
-            20, // fire - vulnerable (wood burns!)
-            40, // dark - moderate resistance
-            35, // slash - moderate resistance
-            30, // crush - low resistance
-        );
+        stats.resistances = Resistances::from_pairs(&[
+            (DamageType::Blunt, 40),  // moderate resistance
+            (DamageType::Pierce, 30), // low resistance
+            (DamageType::Fire, -25),  // weakness - wood burns!
+            (DamageType::Dark, 40),   // moderate resistance
+            (DamageType::Slash, 35),  // moderate resistance
+            (DamageType::Crush, 30),  // low resistance
+        ]);
 
         // Siege weapons can damage buildings
         stats.siege_vulnerability = 2.0;
@@ -177,8 +177,7 @@ impl Structure for House {
 
         // Apply crush resistance for siege damage
         if is_siege {
-            let resistance = self.stats.resistances.crush as f32 / 100.0;
-            final_damage *= 1.0 - resistance;
+            final_damage *= self.stats.resistances.damage_multiplier(DamageType::Crush);
         }
 
         let final_damage = final_damage.max(0.0) as u32;
@@ -5,14 +5,14 @@
 //! vulnerable to siege weapons.
 
 use crate::attack::Attack;
-use crate::combat::Resistances;
+use crate::combat::{DamageType, Resistances};
 use crate::structures::structure_stats::StructureStats;
 use crate::structures::structure_trait::{Structure, StructureId};
 use crate::structures::structure_type::StructureType;
 use crate::team::Team;
 use crate::unit_race::Terrain;
 use crate::unit_trait::UnitId;
-use graphics::HexCoord;
+use hex::HexCoord;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -35,10 +35,10 @@ use uuid::Uuid;
 ///
 /// ```rust,no_run
 /// use units::structures::{StoneWall, Structure};
-/// use graphics::HexCoord;
+/// use hex::HexCoord;
 /// use units::Team;
 ///
-/// let mut wall = StoneWall::new(HexCoord::new(5, 5), Team::Player);
+/// let mut wall = StoneWall::new(HexCoord::new(5, 5), Team::PLAYER);
 /// assert_eq!(wall.max_durability(), 500);
 /// assert_eq!(wall.defense_bonus(), 15);
 /// assert!(wall.blocks_movement());
@@ -73,14 +73,14 @@ impl StoneWall {
         stats.repair_rate = 5; // Repairs 5 HP/turn when occupied
 
         // Resistances - stone is tough!
-        stats.resistances = Resistances::new(
-            70, // blunt - good resistance
-            60, // pierce - decent resistance
-            90, // fire - excellent resistance (stone doesn't burn)
-            50, // dark - moderate resistance
-            80, // slash - excellent resistance
-            50, // crush - moderate (siege weapons use crush)
-        );
+        stats.resistances = Resistances::from_pairs(&[
+            (DamageType::Blunt, 70),  // good resistance
+            (DamageType::Pierce, 60), // decent resistance
+            (DamageType::Fire, 90),   // excellent resistance (stone doesn't burn)
+            (DamageType::Dark, 50),   // moderate resistance
+            (DamageType::Slash, 80),  // excellent resistance
+            (DamageType::Crush, 50),  // moderate (siege weapons use crush)
+        ]);
 
         // Siege weapons are designed to break walls
         stats.siege_vulnerability = 2.5;
@@ -174,8 +174,7 @@ impl Structure for StoneWall {
         // For simplicity, we'll apply resistance based on crush damage type
         // (most siege weapons deal crush damage)
         if is_siege {
-            let resistance = self.stats.resistances.crush as f32 / 100.0;
-            final_damage *= 1.0 - resistance;
+            final_damage *= self.stats.resistances.damage_multiplier(DamageType::Crush);
         }
 
         let final_damage = final_damage.max(0.0) as u32;
@@ -291,24 +290,24 @@ mod tests {
 
     #[test]
     fn test_stone_wall_creation() {
-        let wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
         assert_eq!(wall.name(), "Stone Wall");
         assert_eq!(wall.max_durability(), 500);
         assert_eq!(wall.current_durability(), 500);
-        assert_eq!(wall.team(), Team::Player);
+        assert_eq!(wall.team(), Team::PLAYER);
     }
 
     #[test]
     fn test_stone_wall_blocks_movement() {
-        let wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
         assert!(wall.blocks_movement());
-        assert!(!wall.can_pass_through(Team::Player));
-        assert!(!wall.can_pass_through(Team::Enemy));
+        assert!(!wall.can_pass_through(Team::PLAYER));
+        assert!(!wall.can_pass_through(Team::ENEMY));
     }
 
     #[test]
     fn test_stone_wall_occupation() {
-        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
         let unit1_id = Uuid::new_v4();
         let unit2_id = Uuid::new_v4();
 
@@ -324,7 +323,7 @@ mod tests {
 
     #[test]
     fn test_stone_wall_damage() {
-        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
 
         // Normal damage
         let damage_dealt = wall.take_damage(100, false);
@@ -338,7 +337,7 @@ mod tests {
 
     #[test]
     fn test_stone_wall_repair() {
-        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let mut wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
 
         wall.take_damage(200, false);
         let initial = wall.current_durability();
@@ -350,7 +349,7 @@ mod tests {
 
     #[test]
     fn test_stone_wall_bonuses() {
-        let wall = StoneWall::new(HexCoord::new(0, 0), Team::Player);
+        let wall = StoneWall::new(HexCoord::new(0, 0), Team::PLAYER);
 
         assert_eq!(wall.defense_bonus(), 15);
         assert_eq!(wall.vision_bonus(), 1);
@@ -1,62 +1,310 @@
-//! Team affiliation type for units and structures.
+//! Team identifiers and the scenario team roster.
 //!
-//! This module defines the `Team` enum used throughout the game to determine
-//! friend-or-foe relationships and control ownership of entities.
+//! A [`Team`] is a small id handed out by a [`TeamRoster`]. The roster holds
+//! everything a scenario says about its teams: names (the ids used in map
+//! files), colors, who is player-controlled, the diplomacy matrix between
+//! teams and the turn order. Any number of teams is supported, so free-for-all,
+//! 2v2 and several AI factions are all just different rosters.
+//!
+//! Teams without an explicit [`Stance`] towards each other are hostile; a team
+//! is always allied with itself.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use units::{Stance, TeamRoster};
+//!
+//! let mut roster = TeamRoster::new();
+//! let red = roster.add_team("Red", [0.8, 0.2, 0.2]).unwrap();
+//! let blue = roster.add_team("Blue", [0.2, 0.3, 0.9]).unwrap();
+//! let green = roster.add_team("Green", [0.2, 0.7, 0.2]).unwrap();
+//! roster.set_stance(blue, green, Stance::Allied);
+//!
+//! assert!(roster.are_hostile(red, blue));
+//! assert!(roster.are_allied(blue, green));
+//! assert_eq!(roster.find("Green"), Some(green));
+//! assert_eq!(roster.turn_order(), &[red, blue, green]);
+//! ```
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-/// Team affiliation for units and structures in the game world.
+/// Team affiliation of units and structures.
 ///
-/// Determines friend-or-foe relationships for combat and movement validation.
-/// Units and structures of the same team cannot attack each other, while
-/// entities of different teams can engage in combat.
+/// Teams are identified by the id their [`TeamRoster`] assigned. The
+/// constants below are the teams of [`TeamRoster::default`], which legacy maps
+/// without a `Teams` section use.
 ///
 /// # Examples
 ///
 /// ```
 /// use units::Team;
 ///
-/// let player_team = Team::Player;
-/// let enemy_team = Team::Enemy;
+/// let player_team = Team::PLAYER;
+/// let enemy_team = Team::ENEMY;
 /// assert_ne!(player_team, enemy_team);
+/// assert_eq!(Team::new(1), Team::ENEMY);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Team {
-    /// Player-controlled units and structures
-    Player,
-    /// Enemy-controlled units and structures that can be attacked
-    Enemy,
-    /// Neutral entities that don't participate in combat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Team(u8);
+
+impl Team {
+    /// Player team of the default roster
+    pub const PLAYER: Team = Team(0);
+    /// Enemy team of the default roster
+    pub const ENEMY: Team = Team(1);
+    /// Neutral team of the default roster
+    pub const NEUTRAL: Team = Team(2);
+
+    /// Creates a team handle from a raw roster id.
+    pub const fn new(id: u8) -> Self {
+        Team(id)
+    }
+
+    /// Raw roster id of the team.
+    pub fn id(self) -> u8 {
+        self.0
+    }
+}
+
+/// Diplomatic stance between two teams.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Stance {
+    /// Fight alongside each other; cannot attack each other
+    Allied,
+    /// Ignore each other; neither side attacks
     Neutral,
+    /// Enemies that attack each other
+    #[default]
+    Hostile,
 }
 
-impl Team {
-    /// Checks if this team is hostile to another team.
+impl Stance {
+    /// Every stance, in declaration order.
+    pub const ALL: [Stance; 3] = [Stance::Allied, Stance::Neutral, Stance::Hostile];
+
+    /// Name used for this stance in map files.
+    pub fn name(self) -> &'static str {
+        match self {
+            Stance::Allied => "Allied",
+            Stance::Neutral => "Neutral",
+            Stance::Hostile => "Hostile",
+        }
+    }
+
+    /// Looks up a stance by its [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.name() == name)
+    }
+}
+
+/// Everything a scenario defines about one team.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TeamInfo {
+    /// Roster id of the team
+    pub id: Team,
+    /// Scenario name, used to refer to the team in map files
+    pub name: String,
+    /// RGB color used to tint the team's units
+    pub color: [f32; 3],
+    /// Whether a human controls the team
+    pub player_controlled: bool,
+    /// Free-form victory goal shown to the player
+    pub goal: String,
+}
+
+/// The teams of a scenario, their diplomacy and turn order.
+///
+/// Stances are symmetric. Teams are added to the end of the turn order as
+/// they are created; [`set_turn_order`](Self::set_turn_order) replaces it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TeamRoster {
+    teams: Vec<TeamInfo>,
+    stances: HashMap<(Team, Team), Stance>,
+    turn_order: Vec<Team>,
+}
+
+impl TeamRoster {
+    /// Most teams a roster can hold; team ids are a single byte.
+    pub const MAX_TEAMS: usize = 256;
+
+    /// Colors assigned to teams created without one, in creation order.
+    pub const DEFAULT_COLORS: [[f32; 3]; 8] = [
+        [0.25, 0.45, 0.95], // Blue
+        [0.90, 0.25, 0.20], // Red
+        [0.65, 0.65, 0.65], // Gray
+        [0.25, 0.75, 0.30], // Green
+        [0.95, 0.80, 0.20], // Yellow
+        [0.65, 0.35, 0.85], // Purple
+        [0.95, 0.55, 0.15], // Orange
+        [0.20, 0.80, 0.85], // Teal
+    ];
+
+    /// Creates an empty roster.
+    pub fn new() -> Self {
+        Self {
+            teams: Vec::new(),
+            stances: HashMap::new(),
+            turn_order: Vec::new(),
+        }
+    }
+
+    /// Adds a team and appends it to the turn order.
     ///
     /// # Arguments
     ///
-    /// * `other` - The other team to check against
+    /// * `name` - Scenario name of the team
+    /// * `color` - RGB tint for the team's units
     ///
     /// # Returns
     ///
-    /// `true` if the teams are hostile to each other
-    pub fn is_hostile_to(&self, other: Team) -> bool {
-        matches!(
-            (self, other),
-            (Team::Player, Team::Enemy) | (Team::Enemy, Team::Player)
-        )
+    /// The id of the new team, or `Err(String)` if the roster already holds
+    /// [`TeamRoster::MAX_TEAMS`] teams
+    pub fn add_team(&mut self, name: impl Into<String>, color: [f32; 3]) -> Result<Team, String> {
+        let id = u8::try_from(self.teams.len())
+            .map_err(|_| format!("A scenario can have at most {} teams", Self::MAX_TEAMS))?;
+        Ok(self.push_team(Team(id), name.into(), color))
+    }
+
+    fn push_team(&mut self, id: Team, name: String, color: [f32; 3]) -> Team {
+        self.teams.push(TeamInfo {
+            id,
+            name,
+            color,
+            player_controlled: false,
+            goal: String::new(),
+        });
+        self.turn_order.push(id);
+        id
+    }
+
+    /// Color for the next team added without an explicit one.
+    pub fn next_default_color(&self) -> [f32; 3] {
+        Self::DEFAULT_COLORS[self.teams.len() % Self::DEFAULT_COLORS.len()]
     }
 
-    /// Checks if this team is allied with another team.
+    /// All teams, in creation (id) order.
+    pub fn teams(&self) -> &[TeamInfo] {
+        &self.teams
+    }
+
+    /// Number of teams in the roster.
+    pub fn len(&self) -> usize {
+        self.teams.len()
+    }
+
+    /// Whether the roster has no teams.
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty()
+    }
+
+    /// Looks up a team's information.
+    pub fn get(&self, team: Team) -> Option<&TeamInfo> {
+        self.teams.get(team.0 as usize)
+    }
+
+    /// Looks up a team's information for editing.
+    pub fn get_mut(&mut self, team: Team) -> Option<&mut TeamInfo> {
+        self.teams.get_mut(team.0 as usize)
+    }
+
+    /// Finds a team by its scenario name.
+    pub fn find(&self, name: &str) -> Option<Team> {
+        self.teams.iter().find(|t| t.name == name).map(|t| t.id)
+    }
+
+    /// Scenario name of a team, or `"Unknown"` for ids not in the roster.
+    pub fn name(&self, team: Team) -> &str {
+        self.get(team).map_or("Unknown", |t| t.name.as_str())
+    }
+
+    /// Unit tint of a team, or white for ids not in the roster.
+    pub fn color(&self, team: Team) -> [f32; 3] {
+        self.get(team).map_or([1.0, 1.0, 1.0], |t| t.color)
+    }
+
+    /// Sets the stance between two different teams (in both directions).
     ///
-    /// # Arguments
+    /// Setting a stance of a team towards itself is ignored.
+    pub fn set_stance(&mut self, a: Team, b: Team, stance: Stance) {
+        if a != b {
+            self.stances.insert(Self::pair(a, b), stance);
+        }
+    }
+
+    /// Stance between two teams.
     ///
-    /// * `other` - The other team to check against
+    /// A team is allied with itself; pairs without an explicit stance are
+    /// hostile.
+    pub fn stance(&self, a: Team, b: Team) -> Stance {
+        if a == b {
+            return Stance::Allied;
+        }
+        self.stances
+            .get(&Self::pair(a, b))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Whether units of the two teams fight each other.
+    pub fn are_hostile(&self, a: Team, b: Team) -> bool {
+        self.stance(a, b) == Stance::Hostile
+    }
+
+    /// Whether the two teams are the same team or allies.
+    pub fn are_allied(&self, a: Team, b: Team) -> bool {
+        self.stance(a, b) == Stance::Allied
+    }
+
+    /// Teams in the order they take their turns.
+    pub fn turn_order(&self) -> &[Team] {
+        &self.turn_order
+    }
+
+    /// Replaces the turn order.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the teams are allied or the same
-    pub fn is_allied_with(&self, other: Team) -> bool {
-        self == &other || *self == Team::Neutral || other == Team::Neutral
+    /// Returns an error naming the problem if the order is empty, repeats a
+    /// team or names a team that is not in the roster.
+    pub fn set_turn_order(&mut self, order: Vec<Team>) -> Result<(), String> {
+        if order.is_empty() {
+            return Err("turn order must name at least one team".to_string());
+        }
+        for (i, team) in order.iter().enumerate() {
+            if self.get(*team).is_none() {
+                return Err(format!("turn order names unknown team {}", team.0));
+            }
+            if order[..i].contains(team) {
+                return Err(format!("turn order repeats team '{}'", self.name(*team)));
+            }
+        }
+        self.turn_order = order;
+        Ok(())
+    }
+
+    fn pair(a: Team, b: Team) -> (Team, Team) {
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+}
+
+impl Default for TeamRoster {
+    /// The classic three-team setup: a player-controlled `Player` team, a
+    /// hostile AI `Enemy` team and a `Neutral` team at peace with both.
+    fn default() -> Self {
+        let mut roster = TeamRoster::new();
+        let player = roster.push_team(Team::PLAYER, "Player".into(), Self::DEFAULT_COLORS[0]);
+        let enemy = roster.push_team(Team::ENEMY, "Enemy".into(), Self::DEFAULT_COLORS[1]);
+        let neutral = roster.push_team(Team::NEUTRAL, "Neutral".into(), Self::DEFAULT_COLORS[2]);
+        if let Some(info) = roster.get_mut(player) {
+            info.player_controlled = true;
+        }
+        roster.set_stance(neutral, player, Stance::Neutral);
+        roster.set_stance(neutral, enemy, Stance::Neutral);
+        roster
     }
 }
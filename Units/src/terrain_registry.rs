@@ -0,0 +1,291 @@
+//! Terrain definition registry.
+//!
+//! Gameplay terrain is described by [`TerrainDefinition`]s: an id used in map
+//! files, a display name, the base movement cost, the defense class used for
+//! race hit chances ([`Terrain`]), whether it can be entered at all, whether it
+//! blocks line of sight and whether structures can be built on it. Each
+//! definition also names the sprite that renders it, so sprites map onto
+//! terrain rather than being the terrain.
+//!
+//! Tiles may additionally carry overlay [`TerrainFeature`]s (roads, rivers,
+//! bridges, villages) that modify the base terrain.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use units::{TerrainFeature, TerrainRegistry};
+//!
+//! let registry = TerrainRegistry::builtin();
+//! let hills = registry.get("hills").unwrap();
+//! assert_eq!(hills.movement_cost, 3);
+//! assert_eq!(hills.movement_cost_with(&[TerrainFeature::Road]), 1);
+//! ```
+
+use crate::unit_race::Terrain;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+lazy_static! {
+    /// Registry holding the built-in terrain definitions
+    static ref BUILTIN_REGISTRY: TerrainRegistry = TerrainRegistry::new();
+}
+
+/// Overlay features that can be placed on top of a terrain tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TerrainFeature {
+    /// Paved road; movement costs 1 regardless of terrain
+    Road,
+    /// Shallow river; movement costs at least 3 unless bridged
+    River,
+    /// Bridge over a river; movement costs 1
+    Bridge,
+    /// Village; movement costs 1 and structures cannot be built
+    Village,
+}
+
+impl TerrainFeature {
+    /// Every feature, in declaration order.
+    pub const ALL: [TerrainFeature; 4] = [
+        TerrainFeature::Road,
+        TerrainFeature::River,
+        TerrainFeature::Bridge,
+        TerrainFeature::Village,
+    ];
+
+    /// Name used for this feature in map files.
+    pub fn name(self) -> &'static str {
+        match self {
+            TerrainFeature::Road => "Road",
+            TerrainFeature::River => "River",
+            TerrainFeature::Bridge => "Bridge",
+            TerrainFeature::Village => "Village",
+        }
+    }
+
+    /// Looks up a feature by its map file [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.name() == name)
+    }
+}
+
+/// Gameplay definition of a terrain type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TerrainDefinition {
+    /// Identifier used in map files (e.g. `"grasslands"`)
+    pub id: String,
+    /// Human-readable name
+    pub name: String,
+    /// Movement points needed to enter a tile of this terrain
+    pub movement_cost: i32,
+    /// Defense class used for race-specific hit chances
+    pub defense_class: Terrain,
+    /// Whether units can never enter the terrain
+    pub impassable: bool,
+    /// Whether the terrain blocks line of sight
+    pub blocks_line_of_sight: bool,
+    /// Whether structures can be built on the terrain
+    pub buildable: bool,
+    /// Name of the sprite used to render the terrain (e.g. `"Grasslands"`)
+    pub sprite: String,
+}
+
+impl TerrainDefinition {
+    /// Creates a terrain definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier used in map files
+    /// * `name` - Human-readable name
+    /// * `movement_cost` - Base movement cost
+    /// * `defense_class` - Defense class for race hit chances
+    /// * `sprite` - Name of the sprite used to render the terrain
+    ///
+    /// The terrain starts passable and buildable and does not block line of sight.
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        movement_cost: i32,
+        defense_class: Terrain,
+        sprite: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            movement_cost,
+            defense_class,
+            impassable: false,
+            blocks_line_of_sight: false,
+            buildable: true,
+            sprite: sprite.into(),
+        }
+    }
+
+    /// Sets whether units can never enter the terrain.
+    pub fn with_impassable(mut self, impassable: bool) -> Self {
+        self.impassable = impassable;
+        self
+    }
+
+    /// Sets whether the terrain blocks line of sight.
+    pub fn with_blocks_line_of_sight(mut self, blocks: bool) -> Self {
+        self.blocks_line_of_sight = blocks;
+        self
+    }
+
+    /// Sets whether structures can be built on the terrain.
+    pub fn with_buildable(mut self, buildable: bool) -> Self {
+        self.buildable = buildable;
+        self
+    }
+
+    /// Movement cost of this terrain with the given overlay features.
+    ///
+    /// Roads, bridges and villages make any tile cost 1; an unbridged river
+    /// costs at least 3.
+    pub fn movement_cost_with(&self, features: &[TerrainFeature]) -> i32 {
+        Self::cost_with_features(self.movement_cost, features)
+    }
+
+    /// Applies the overlay feature rules of
+    /// [`movement_cost_with`](Self::movement_cost_with) to a base cost.
+    pub(crate) fn cost_with_features(base: i32, features: &[TerrainFeature]) -> i32 {
+        let has = |feature| features.contains(&feature);
+        if has(TerrainFeature::Road) || has(TerrainFeature::Bridge) || has(TerrainFeature::Village)
+        {
+            1
+        } else if has(TerrainFeature::River) {
+            base.max(3)
+        } else {
+            base
+        }
+    }
+
+    /// Whether structures can be built here with the given overlay features.
+    ///
+    /// Villages, rivers and bridges already occupy the tile.
+    pub fn buildable_with(&self, features: &[TerrainFeature]) -> bool {
+        self.buildable
+            && !features.iter().any(|f| {
+                matches!(
+                    f,
+                    TerrainFeature::Village | TerrainFeature::River | TerrainFeature::Bridge
+                )
+            })
+    }
+}
+
+/// Registry of terrain definitions, looked up by id or by sprite.
+#[derive(Clone, Debug)]
+pub struct TerrainRegistry {
+    terrains: HashMap<String, TerrainDefinition>,
+}
+
+impl TerrainRegistry {
+    /// Id of the terrain used when nothing else applies.
+    pub const DEFAULT_TERRAIN: &'static str = "grasslands";
+
+    /// Creates a registry containing the built-in terrain definitions.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for definition in builtin_terrains() {
+            registry.register(definition);
+        }
+        registry
+    }
+
+    /// Creates a registry without any terrain definitions.
+    pub fn empty() -> Self {
+        Self {
+            terrains: HashMap::new(),
+        }
+    }
+
+    /// The shared registry of built-in terrain definitions.
+    pub fn builtin() -> &'static TerrainRegistry {
+        &BUILTIN_REGISTRY
+    }
+
+    /// Adds a definition, replacing any existing one with the same id.
+    pub fn register(&mut self, definition: TerrainDefinition) {
+        self.terrains.insert(definition.id.clone(), definition);
+    }
+
+    /// Gets a terrain definition by id.
+    pub fn get(&self, id: &str) -> Option<&TerrainDefinition> {
+        self.terrains.get(id)
+    }
+
+    /// Gets the terrain rendered by the sprite named `sprite`, if any.
+    ///
+    /// Used to read legacy maps that only specify a `SpriteType`.
+    pub fn for_sprite(&self, sprite: &str) -> Option<&TerrainDefinition> {
+        self.all().into_iter().find(|t| t.sprite == sprite)
+    }
+
+    /// The default terrain definition (grasslands).
+    pub fn default_terrain(&self) -> Option<&TerrainDefinition> {
+        self.get(Self::DEFAULT_TERRAIN)
+    }
+
+    /// All terrain definitions, sorted by id.
+    pub fn all(&self) -> Vec<&TerrainDefinition> {
+        let mut terrains: Vec<&TerrainDefinition> = self.terrains.values().collect();
+        terrains.sort_by(|a, b| a.id.cmp(&b.id));
+        terrains
+    }
+
+    /// Number of registered terrain definitions.
+    pub fn len(&self) -> usize {
+        self.terrains.len()
+    }
+
+    /// Returns true if no terrain is registered.
+    pub fn is_empty(&self) -> bool {
+        self.terrains.is_empty()
+    }
+}
+
+impl Default for TerrainRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The terrain types shipped with the game.
+fn builtin_terrains() -> Vec<TerrainDefinition> {
+    vec![
+        TerrainDefinition::new(
+            "grasslands",
+            "Grasslands",
+            1,
+            Terrain::Grasslands,
+            "Grasslands",
+        ),
+        TerrainDefinition::new("forest", "Forest", 2, Terrain::Forest0, "Forest")
+            .with_blocks_line_of_sight(true),
+        TerrainDefinition::new(
+            "dense_forest",
+            "Dense Forest",
+            2,
+            Terrain::Forest1,
+            "Forest2",
+        )
+        .with_blocks_line_of_sight(true),
+        TerrainDefinition::new(
+            "haunted_woods",
+            "Haunted Woods",
+            2,
+            Terrain::HauntedWoods,
+            "HauntedWoods",
+        )
+        .with_blocks_line_of_sight(true)
+        .with_buildable(false),
+        TerrainDefinition::new("hills", "Hills", 3, Terrain::Hills, "Hills"),
+        TerrainDefinition::new("mountain", "Mountain", 4, Terrain::Mountain, "Mountain")
+            .with_impassable(true)
+            .with_blocks_line_of_sight(true)
+            .with_buildable(false),
+        TerrainDefinition::new("swamp", "Swamp", 3, Terrain::Swamp, "Swamp").with_buildable(false),
+    ]
+}
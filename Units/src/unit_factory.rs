@@ -9,7 +9,7 @@
 use crate::unit_registry::UnitRegistry;
 use crate::unit_trait::Unit;
 use crate::units::*;
-use graphics::HexCoord;
+use hex::HexCoord;
 use lazy_static::lazy_static;
 
 lazy_static! {
@@ -37,7 +37,7 @@ impl UnitFactory {
     ///
     /// ```rust,no_run
     /// use units::UnitFactory;
-    /// use graphics::HexCoord;
+    /// use hex::HexCoord;
     ///
     /// // Create with all defaults
     /// let unit = UnitFactory::create("Human Warrior", None, None).unwrap();
@@ -91,6 +91,11 @@ impl UnitFactory {
             .collect()
     }
 
+    /// Gets the class of a unit type (e.g. "Warrior" for "Dwarf Warrior")
+    pub fn class_of(type_name: &str) -> Option<&'static str> {
+        get_registry().get(type_name).map(|info| info.class)
+    }
+
     /// Checks if a unit type exists
     pub fn exists(type_name: &str) -> bool {
         get_registry().is_registered(type_name)
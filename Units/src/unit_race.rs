@@ -4,6 +4,8 @@
 //! along with terrain types and their interactions. Each race has different
 //! defensive bonuses based on the terrain they're standing on.
 
+use crate::alignment::Alignment;
+use crate::movement_type::MovementType;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -72,6 +74,58 @@ impl Race {
         self.get_base_defense(terrain)
     }
 
+    /// Returns the movement type used by units of this race unless their
+    /// definition overrides it.
+    ///
+    /// # Returns
+    ///
+    /// The race's default [`MovementType`].
+    pub fn default_movement_type(self) -> MovementType {
+        match self {
+            Race::Elf => MovementType::Woodland,
+            Race::Dwarf | Race::Gnome => MovementType::Mountainfoot,
+            Race::Halfling | Race::Goblin | Race::Kobold => MovementType::Elusive,
+            Race::Lizardfolk | Race::Triton => MovementType::Amphibious,
+            Race::Skeleton | Race::Zombie | Race::Undead => MovementType::Undead,
+            Race::Human
+            | Race::HalfElf
+            | Race::HalfOrc
+            | Race::Tiefling
+            | Race::Dragonborn
+            | Race::Orc
+            | Race::Hobgoblin
+            | Race::Changeling => MovementType::Foot,
+        }
+    }
+
+    /// Returns the alignment used by units of this race unless their
+    /// definition overrides it.
+    ///
+    /// # Returns
+    ///
+    /// The race's default [`Alignment`].
+    pub fn default_alignment(self) -> Alignment {
+        match self {
+            Race::Human | Race::Dwarf | Race::Dragonborn => Alignment::Lawful,
+            Race::Orc
+            | Race::Goblin
+            | Race::Hobgoblin
+            | Race::Kobold
+            | Race::Tiefling
+            | Race::Skeleton
+            | Race::Zombie
+            | Race::Undead => Alignment::Chaotic,
+            Race::Elf
+            | Race::Halfling
+            | Race::Gnome
+            | Race::HalfElf
+            | Race::HalfOrc
+            | Race::Lizardfolk
+            | Race::Triton
+            | Race::Changeling => Alignment::Neutral,
+        }
+    }
+
     /// Returns the display name of the race.
     ///
     /// # Returns
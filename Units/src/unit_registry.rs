@@ -19,7 +19,7 @@
 
 use crate::unit_race::Terrain;
 use crate::unit_trait::Unit;
-use graphics::HexCoord;
+use hex::HexCoord;
 use std::collections::HashMap;
 
 /// Type alias for a unit constructor function
@@ -8,8 +8,7 @@ use crate::attack::Attack;
 use crate::combat::CombatStats;
 use crate::unit_race::{Race, Terrain};
 use crate::unit_type::UnitType;
-use graphics::HexCoord;
-use graphics::SpriteType;
+use hex::HexCoord;
 use items::{Equipment, Item, ItemId};
 use uuid::Uuid;
 
@@ -18,6 +17,30 @@ use uuid::Uuid;
 /// Each unit has a UUID to uniquely identify it in the game world.
 pub type UnitId = Uuid;
 
+/// Object-safe cloning for boxed units.
+///
+/// Implemented automatically for every `Unit` that is `Clone`, which makes
+/// `Box<dyn Unit>` (and therefore whole game worlds) cloneable.
+pub trait UnitClone {
+    /// Returns a boxed deep copy of this unit.
+    fn clone_box(&self) -> Box<dyn Unit>;
+}
+
+impl<T> UnitClone for T
+where
+    T: 'static + Unit + Clone,
+{
+    fn clone_box(&self) -> Box<dyn Unit> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Unit> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
 /// Core trait that all units must implement.
 ///
 /// This trait provides a unified interface for all unit types in the game,
@@ -37,7 +60,10 @@ pub type UnitId = Uuid;
 /// - `attacks()` - Returns the unit's attack list
 ///
 /// All other methods have default implementations that delegate to the BaseUnit.
-pub trait Unit {
+///
+/// Concrete units must also derive `Clone` so that `Box<dyn Unit>` can be
+/// cloned through [`UnitClone`] (used by tactical AI search over world copies).
+pub trait Unit: UnitClone {
     // ===== Core Required Methods =====
     // These MUST be implemented by each unit type
 
@@ -132,15 +158,11 @@ pub trait Unit {
         &self.base().combat_stats
     }
 
-    fn get_resistance(&self, damage_type: crate::combat::DamageType) -> u8 {
-        match damage_type {
-            crate::combat::DamageType::Blunt => self.combat_stats().resistances.blunt,
-            crate::combat::DamageType::Pierce => self.combat_stats().resistances.pierce,
-            crate::combat::DamageType::Fire => self.combat_stats().resistances.fire,
-            crate::combat::DamageType::Dark => self.combat_stats().resistances.dark,
-            crate::combat::DamageType::Slash => self.combat_stats().resistances.slash,
-            crate::combat::DamageType::Crush => self.combat_stats().resistances.crush,
-        }
+    /// Returns the unit's resistance against `damage_type`, in percent.
+    ///
+    /// Negative values are weaknesses.
+    fn get_resistance(&self, damage_type: crate::combat::DamageType) -> i32 {
+        self.combat_stats().resistances.get_resistance(damage_type)
     }
 
     /// Returns a mutable reference to the unit's combat statistics.
@@ -187,12 +209,20 @@ pub trait Unit {
     ///
     /// Returns an error if:
     /// - The item is not in the inventory
-    /// - The item cannot be equipped by this unit
+    /// - The item cannot be equipped by this unit (level, race or class
+    ///   requirements)
     /// - The equipment slot is incompatible
     fn equip_item(&mut self, item_id: ItemId) -> Result<(), String> {
+        let class = crate::unit_factory::UnitFactory::class_of(self.unit_type()).unwrap_or("");
+        let (level, race) = (self.level(), self.race().to_string());
         let base = self.base_mut();
         if let Some(pos) = base.inventory.iter().position(|item| item.id == item_id) {
+            base.inventory[pos]
+                .requirements
+                .check(level, &race, class)
+                .map_err(|reason| format!("{} {}", base.inventory[pos].name, reason))?;
             let item = base.inventory.remove(pos);
+            base.remove_equipment_bonuses();
             if let Some(old_item) = base.equipment.equip_item(item) {
                 base.inventory.push(old_item);
             }
@@ -214,6 +244,15 @@ pub trait Unit {
     /// Returns an error if the item is not currently equipped.
     fn unequip_item(&mut self, item_id: ItemId) -> Result<(), String> {
         let base = self.base_mut();
+        if !base
+            .equipment
+            .get_all_equipped()
+            .iter()
+            .any(|item| item.id == item_id)
+        {
+            return Err("Item not equipped".to_string());
+        }
+        base.remove_equipment_bonuses();
         if let Some(item) = base.equipment.unequip_item(item_id) {
             base.inventory.push(item);
             base.recalculate_stats();
@@ -396,6 +435,29 @@ pub trait Unit {
         self.base().get_terrain_hit_chance(terrain)
     }
 
+    /// Gets the unit's movement type.
+    ///
+    /// The movement type decides the movement cost of each terrain and which
+    /// terrains the unit cannot enter.
+    ///
+    /// # Returns
+    ///
+    /// The unit's [`MovementType`](crate::MovementType)
+    fn movement_type(&self) -> crate::movement_type::MovementType {
+        self.base().movement_type
+    }
+
+    /// Gets the unit's alignment.
+    ///
+    /// The alignment decides how the time of day scales the unit's damage.
+    ///
+    /// # Returns
+    ///
+    /// The unit's [`Alignment`](crate::Alignment)
+    fn alignment(&self) -> crate::alignment::Alignment {
+        self.base().alignment
+    }
+
     // ===== Utility Methods (Default Implementations) =====
 
     /// Checks if the unit is alive.
@@ -445,46 +507,26 @@ pub trait Unit {
     fn get_attacks(&self) -> Vec<Attack> {
         use items::item_properties::ItemProperties;
 
-        /// Helper function to convert item damage types to combat damage types.
-        fn item_damage_type_to_combat(
-            dt: items::item_properties::DamageType,
-        ) -> crate::combat::DamageType {
-            match dt {
-                items::item_properties::DamageType::Slash => crate::combat::DamageType::Slash,
-                items::item_properties::DamageType::Pierce => crate::combat::DamageType::Pierce,
-                items::item_properties::DamageType::Blunt => crate::combat::DamageType::Blunt,
-                items::item_properties::DamageType::Fire => crate::combat::DamageType::Fire,
-                items::item_properties::DamageType::Dark => crate::combat::DamageType::Dark,
-                _ => crate::combat::DamageType::Slash,
-            }
-        }
-
         // Start with the unit's innate attacks
         let mut all_attacks = self.attacks().to_vec();
 
-        // Add attacks from equipped weapon
-        if let Some(weapon) = &self.base().equipment.weapon {
-            if let ItemProperties::Weapon { attacks, .. } = &weapon.properties {
-                for item_attack in attacks {
-                    all_attacks.push(Attack::melee(
-                        item_attack.name.clone(),
-                        item_attack.damage,
-                        item_attack.attack_times,
-                        item_damage_type_to_combat(item_attack.damage_type),
-                    ));
-                }
-            }
-        }
-
-        // Add attacks from equipped accessories (if any provide attacks)
-        for accessory in &self.base().equipment.accessories {
-            if let ItemProperties::Weapon { attacks, .. } = &accessory.properties {
+        // Add attacks from equipped weapon and weapon-like accessories, with
+        // the weapon's range type (melee unless overridden) and reach
+        let equipment = &self.base().equipment;
+        for item in equipment.weapon.iter().chain(&equipment.accessories) {
+            if let ItemProperties::Weapon {
+                attacks,
+                range_modifier,
+                range_type_override,
+                ..
+            } = &item.properties
+            {
+                let range_type = range_type_override.unwrap_or_default();
                 for item_attack in attacks {
-                    all_attacks.push(Attack::melee(
-                        item_attack.name.clone(),
-                        item_attack.damage,
-                        item_attack.attack_times,
-                        item_damage_type_to_combat(item_attack.damage_type),
+                    all_attacks.push(Attack::from_item_attack(
+                        item_attack,
+                        range_type,
+                        *range_modifier,
                     ));
                 }
             }
@@ -493,6 +535,20 @@ pub trait Unit {
         all_attacks
     }
 
+    /// Get the attack this unit answers `incoming` with, when attacked from
+    /// `distance` hexes away.
+    ///
+    /// # Returns
+    ///
+    /// The first of [`Unit::get_attacks`] that may retaliate (same range type
+    /// and within reach, see [`Attack::can_retaliate`]), or `None` if the unit
+    /// cannot counter-attack
+    fn counter_attack(&self, incoming: &Attack, distance: i32) -> Option<Attack> {
+        self.get_attacks()
+            .into_iter()
+            .find(|attack| attack.can_retaliate(incoming, distance))
+    }
+
     /// Get the unit's defense value (base hit chance for enemies)
     ///
     /// This returns the percentage chance (0-100) that an enemy has to hit this unit
@@ -516,20 +572,21 @@ pub trait Unit {
     ///
     /// # Returns
     ///
-    /// The `SpriteType` that should be used to render this unit on the hex grid.
+    /// The name of the sprite that should be used to render this unit on the
+    /// hex grid (e.g. `"DwarfWarrior"`); the renderer maps names to textures.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// # use units::{Unit, UnitFactory, Terrain};
-    /// # use graphics::{HexCoord, SpriteType};
+    /// # use hex::HexCoord;
     /// # let dwarf = UnitFactory::create("Dwarf Warrior", Some("Thorin".to_string()), Some(HexCoord::new(0, 0))).unwrap();
     /// let sprite = dwarf.sprite();
-    /// assert_eq!(sprite, SpriteType::DwarfWarrior);
+    /// assert_eq!(sprite, "DwarfWarrior");
     /// ```
-    fn sprite(&self) -> SpriteType {
-        // Default implementation reads from BaseUnit's sprite_type field
-        self.base().sprite_type
+    fn sprite(&self) -> &str {
+        // Default implementation reads from BaseUnit's sprite field
+        &self.base().sprite
     }
 
     // ===== Display Methods (Default Implementations) =====
@@ -593,7 +650,7 @@ pub trait Unit {
     ///
     /// ```rust,no_run
     /// # use units::{Unit, UnitFactory, Terrain, UnitType};
-    /// # use graphics::HexCoord;
+    /// # use hex::HexCoord;
     /// # let warrior = UnitFactory::create("Dwarf Warrior", None, None).unwrap();
     /// if let Some(prev) = warrior.evolution_previous() {
     ///     println!("Evolved from: {}", prev);
@@ -613,7 +670,7 @@ pub trait Unit {
     ///
     /// ```rust,no_run
     /// # use units::{Unit, UnitFactory, Terrain, UnitType};
-    /// # use graphics::HexCoord;
+    /// # use hex::HexCoord;
     /// # let young_warrior = UnitFactory::create("Dwarf Young Warrior", None, None).unwrap();
     /// let evolutions = young_warrior.evolution_next();
     /// for evolution in evolutions {
@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 use std::collections::HashMap;
 
 /// Level 3 Dwarf Veteran Warrior - Maximum evolution defender unit.
@@ -23,6 +23,7 @@ use std::collections::HashMap;
 /// - **Movement**: 3 + race bonus
 /// - **Range**: Melee
 /// - **XP per Level**: 450, 800, 1250... (level² × 50)
+#[derive(Clone)]
 pub struct DwarfVeteranWarrior {
     base: BaseUnit,
 }
@@ -41,12 +42,14 @@ impl DwarfVeteranWarrior {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (legendary dwarven armor - nearly impenetrable)
-    const RESISTANCE_BLUNT: u8 = 40;
-    const RESISTANCE_PIERCE: u8 = 32;
-    const RESISTANCE_FIRE: u8 = 28;
-    const RESISTANCE_DARK: u8 = 18;
-    const RESISTANCE_SLASH: u8 = 42;
-    const RESISTANCE_CRUSH: u8 = 45;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 40),
+        (DamageType::Pierce, 32),
+        (DamageType::Fire, 28),
+        (DamageType::Dark, 18),
+        (DamageType::Slash, 42),
+        (DamageType::Crush, 45),
+    ];
 
     // Terrain Defenses (hit chance % - lower is better defense)
     // Veteran Warriors excel in mountains and hills, their natural domain
@@ -59,7 +62,7 @@ impl DwarfVeteranWarrior {
     const DEFENSE_SWAMP: u8 = 50;
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Dwarf;
@@ -123,14 +126,7 @@ impl DwarfVeteranWarrior {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -142,7 +138,7 @@ impl DwarfVeteranWarrior {
             Self::RACE,
             Self::UNIT_TYPE.to_string(),
             "An elite dwarven warrior who has survived countless battles. Veterans are the pinnacle of dwarven martial prowess, nearly impervious in mountain terrain. Their legendary resilience and devastating attacks make them feared opponents.".to_string(),
-            graphics::SpriteType::DwarfWarrior,
+            "DwarfWarrior",
             Some(crate::unit_type::UnitType::DwarfWarrior),
             vec![],
             combat_stats,
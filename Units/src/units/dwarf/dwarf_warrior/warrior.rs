@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 2 Dwarf Warrior - Intermediate defender unit.
 ///
@@ -20,6 +20,7 @@ use graphics::HexCoord;
 /// - **Movement**: 3 + race bonus
 /// - **Range**: Melee
 /// - **XP to Next Level**: 200 (level² × 50)
+#[derive(Clone)]
 pub struct DwarfWarrior {
     base: BaseUnit,
 }
@@ -42,15 +43,17 @@ impl DwarfWarrior {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (dwarven plate armor - trained defender)
-    const RESISTANCE_BLUNT: u8 = 32;
-    const RESISTANCE_PIERCE: u8 = 25;
-    const RESISTANCE_FIRE: u8 = 22;
-    const RESISTANCE_DARK: u8 = 15;
-    const RESISTANCE_SLASH: u8 = 35;
-    const RESISTANCE_CRUSH: u8 = 38;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 32),
+        (DamageType::Pierce, 25),
+        (DamageType::Fire, 22),
+        (DamageType::Dark, 15),
+        (DamageType::Slash, 35),
+        (DamageType::Crush, 38),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Dwarf;
@@ -59,8 +62,8 @@ impl DwarfWarrior {
     const UNIT_TYPE: &'static str = "Dwarf Warrior";
 
     /// Returns the sprite for this unit type
-    pub fn sprite(&self) -> graphics::SpriteType {
-        graphics::SpriteType::DwarfWarrior
+    pub fn sprite(&self) -> &'static str {
+        "DwarfWarrior"
     }
 
     // ===== ATTACK DEFINITIONS =====
@@ -109,14 +112,7 @@ impl DwarfWarrior {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -128,7 +124,7 @@ impl DwarfWarrior {
             Self::RACE,
             Self::UNIT_TYPE.to_string(),
             "A battle-tested dwarven warrior with formidable defensive capabilities. Masters of mountain warfare, they wield axe and shield with deadly efficiency. Can evolve into elite Veteran Warriors with further experience.".to_string(),
-            graphics::SpriteType::DwarfWarrior,
+            "DwarfWarrior",
             Some(crate::unit_type::UnitType::DwarfYoungWarrior),
             vec![crate::unit_type::UnitType::DwarfVeteranWarrior],
             combat_stats,
@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 1 Dwarf Warrior - Entry level defender unit.
 ///
@@ -20,6 +20,7 @@ use graphics::HexCoord;
 /// - **Movement**: 3 + race bonus (slower than orcs)
 /// - **Range**: Melee
 /// - **XP to Next Level**: 50 (level² × 50)
+#[derive(Clone)]
 pub struct DwarfYoungWarrior {
     base: BaseUnit,
 }
@@ -42,15 +43,17 @@ impl DwarfYoungWarrior {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (dwarven armor - naturally high resistance)
-    const RESISTANCE_BLUNT: u8 = 25;
-    const RESISTANCE_PIERCE: u8 = 20;
-    const RESISTANCE_FIRE: u8 = 18; // Dwarves work with fire
-    const RESISTANCE_DARK: u8 = 12;
-    const RESISTANCE_SLASH: u8 = 28;
-    const RESISTANCE_CRUSH: u8 = 30; // Very resistant to crushing
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 25),
+        (DamageType::Pierce, 20),
+        (DamageType::Fire, 18), // Dwarves work with fire
+        (DamageType::Dark, 12),
+        (DamageType::Slash, 28),
+        (DamageType::Crush, 30), // Very resistant to crushing
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Dwarf;
@@ -97,14 +100,7 @@ impl DwarfYoungWarrior {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -116,7 +112,7 @@ impl DwarfYoungWarrior {
             Self::RACE,
             Self::UNIT_TYPE.to_string(),
             "An inexperienced but sturdy dwarven fighter beginning their martial training. Young Warriors excel in mountainous terrain and possess natural dwarven resilience. With experience, they evolve into seasoned Dwarf Warriors.".to_string(),
-            graphics::SpriteType::DwarfWarrior,
+            "DwarfWarrior",
             None,
             vec![crate::unit_type::UnitType::DwarfWarrior],
             combat_stats,
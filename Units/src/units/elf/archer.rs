@@ -1,9 +1,10 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
+#[derive(Clone)]
 pub struct ElfArcher {
     base: BaseUnit,
 }
@@ -11,19 +12,19 @@ pub struct ElfArcher {
 impl ElfArcher {
     pub fn new(name: String, position: HexCoord) -> Self {
         let combat_stats = CombatStats::new_with_attacks(
-            85,                   // health
-            13,                   // base attack (elves are skilled archers)
-            6,                    // movement speed (very fast)
-            RangeCategory::Range, // range category
-            Resistances::new(
+            85,                // health
+            13,                // base attack (elves are skilled archers)
+            6,                 // movement speed (very fast)
+            RangeType::Ranged, // range category
+            Resistances::from_pairs(&[
                 // resistances (light armor)
-                10, // blunt
-                15, // pierce
-                10, // fire
-                5,  // dark
-                20, // slash
-                10, // crush
-            ),
+                (DamageType::Blunt, 10),
+                (DamageType::Pierce, 15),
+                (DamageType::Fire, 10),
+                (DamageType::Dark, 5),
+                (DamageType::Slash, 20),
+                (DamageType::Crush, 10),
+            ]),
             13, // attack_strength
             1,  // attacks_per_round
         );
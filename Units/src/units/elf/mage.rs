@@ -1,9 +1,10 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
+#[derive(Clone)]
 pub struct ElfMage {
     base: BaseUnit,
 }
@@ -11,19 +12,19 @@ pub struct ElfMage {
 impl ElfMage {
     pub fn new(name: String, position: HexCoord) -> Self {
         let combat_stats = CombatStats::new_with_attacks(
-            75,                   // health
-            11,                   // base attack
-            5,                    // movement speed
-            RangeCategory::Range, // range category (magic attacks are like ranged)
-            Resistances::new(
+            75,                // health
+            11,                // base attack
+            5,                 // movement speed
+            RangeType::Ranged, // range category (magic attacks are like ranged)
+            Resistances::from_pairs(&[
                 // resistances (magical robes)
-                5,  // blunt
-                5,  // pierce
-                25, // fire (strong fire resistance)
-                25, // dark
-                5,  // slash
-                5,  // crush
-            ),
+                (DamageType::Blunt, 5),
+                (DamageType::Pierce, 5),
+                (DamageType::Fire, 25), // strong fire resistance
+                (DamageType::Dark, 25),
+                (DamageType::Slash, 5),
+                (DamageType::Crush, 5),
+            ]),
             11, // attack_strength
             1,  // attacks_per_round
         );
@@ -40,9 +41,9 @@ impl ElfMage {
         );
 
         base.attacks = vec![
-            Attack::ranged("Arcane Bolt", 16, 1, DamageType::Fire, 3),
-            Attack::ranged("Lightning Strike", 14, 1, DamageType::Fire, 3),
-            Attack::ranged("Nature's Wrath", 12, 1, DamageType::Pierce, 2),
+            Attack::magic("Arcane Bolt", 16, 1, DamageType::Fire, 3),
+            Attack::magic("Lightning Strike", 14, 1, DamageType::Fire, 3),
+            Attack::magic("Nature's Wrath", 12, 1, DamageType::Pierce, 2),
         ];
 
         Self { base }
@@ -1,9 +1,10 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
+#[derive(Clone)]
 pub struct ElfWarrior {
     base: BaseUnit,
 }
@@ -11,19 +12,19 @@ pub struct ElfWarrior {
 impl ElfWarrior {
     pub fn new(name: String, position: HexCoord) -> Self {
         let combat_stats = CombatStats::new_with_attacks(
-            100,                  // health (lighter armor than humans)
-            14,                   // base attack
-            4,                    // movement speed (elves are fast)
-            RangeCategory::Melee, // range category
-            Resistances::new(
+            100,              // health (lighter armor than humans)
+            14,               // base attack
+            4,                // movement speed (elves are fast)
+            RangeType::Melee, // range category
+            Resistances::from_pairs(&[
                 // resistances (medium armor)
-                20, // blunt
-                25, // pierce (chainmail)
-                15, // fire
-                10, // dark
-                25, // slash
-                20, // crush
-            ),
+                (DamageType::Blunt, 20),
+                (DamageType::Pierce, 25), // chainmail
+                (DamageType::Fire, 15),
+                (DamageType::Dark, 10),
+                (DamageType::Slash, 25),
+                (DamageType::Crush, 20),
+            ]),
             14, // attack_strength
             1,  // attacks_per_round
         );
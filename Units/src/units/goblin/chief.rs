@@ -1,9 +1,10 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::Race;
-use graphics::HexCoord;
+use hex::HexCoord;
 
+#[derive(Clone)]
 pub struct GoblinChief {
     base: BaseUnit,
 }
@@ -13,19 +14,19 @@ impl GoblinChief {
         // Define combat stats specific to Goblin Chief
         // Stronger than regular goblins, still agile
         let combat_stats = CombatStats::new_with_attacks(
-            90,                   // health (moderate)
-            12,                   // base attack (decent)
-            4,                    // movement speed (fast)
-            RangeCategory::Melee, // range category
-            Resistances::new(
+            90,               // health (moderate)
+            12,               // base attack (decent)
+            4,                // movement speed (fast)
+            RangeType::Melee, // range category
+            Resistances::from_pairs(&[
                 // resistances (better armor than grunt)
-                20, // blunt
-                25, // pierce
-                15, // fire
-                20, // dark
-                25, // slash
-                15, // crush
-            ),
+                (DamageType::Blunt, 20),
+                (DamageType::Pierce, 25),
+                (DamageType::Fire, 15),
+                (DamageType::Dark, 20),
+                (DamageType::Slash, 25),
+                (DamageType::Crush, 15),
+            ]),
             12, // attack_strength
             1,  // attacks_per_round
         );
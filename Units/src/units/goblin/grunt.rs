@@ -1,9 +1,10 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::Race;
-use graphics::HexCoord;
+use hex::HexCoord;
 
+#[derive(Clone)]
 pub struct GoblinGrunt {
     base: BaseUnit,
 }
@@ -13,19 +14,19 @@ impl GoblinGrunt {
         // Define combat stats specific to Goblin Grunt
         // Goblins are weak but evasive
         let combat_stats = CombatStats::new_with_attacks(
-            60,                   // health (low)
-            8,                    // base attack (weak)
-            4,                    // movement speed (fast)
-            RangeCategory::Melee, // range category
-            Resistances::new(
+            60,               // health (low)
+            8,                // base attack (weak)
+            4,                // movement speed (fast)
+            RangeType::Melee, // range category
+            Resistances::from_pairs(&[
                 // resistances (light armor, agile)
-                15, // blunt
-                20, // pierce
-                10, // fire
-                15, // dark
-                20, // slash
-                10, // crush
-            ),
+                (DamageType::Blunt, 15),
+                (DamageType::Pierce, 20),
+                (DamageType::Fire, 10),
+                (DamageType::Dark, 15),
+                (DamageType::Slash, 20),
+                (DamageType::Crush, 10),
+            ]),
             8, // attack_strength (weak)
             1, // attacks_per_round
         );
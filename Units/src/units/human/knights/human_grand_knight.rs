@@ -1,8 +1,9 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
+use crate::movement_type::MovementType;
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 3 Human Grand Knight
 ///
@@ -23,6 +24,7 @@ use graphics::HexCoord;
 /// - **Movement**: 5
 /// - **Range**: Melee
 /// - **XP to Next Level**: N/A (max level)
+#[derive(Clone)]
 pub struct HumanGrandKnight {
     base: BaseUnit,
 }
@@ -45,19 +47,24 @@ impl HumanGrandKnight {
     const ATTACKS_PER_ROUND: u32 = 2;
 
     // Resistances - Maximum holy protection
-    const RESISTANCE_BLUNT: u8 = 35;
-    const RESISTANCE_PIERCE: u8 = 35;
-    const RESISTANCE_FIRE: u8 = 25;
-    const RESISTANCE_DARK: u8 = 50;
-    const RESISTANCE_SLASH: u8 = 35;
-    const RESISTANCE_CRUSH: u8 = 30;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 35),
+        (DamageType::Pierce, 35),
+        (DamageType::Fire, 25),
+        (DamageType::Dark, 50),
+        (DamageType::Slash, 35),
+        (DamageType::Crush, 30),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Human;
 
+    // Movement type - knights ride into battle
+    const MOVEMENT_TYPE: MovementType = MovementType::Mounted;
+
     // Unit type identifier
     const UNIT_TYPE: &'static str = "Human Grand Knight";
 
@@ -112,14 +119,7 @@ impl HumanGrandKnight {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -139,6 +139,7 @@ impl HumanGrandKnight {
         // Set level explicitly
         base.level = Self::LEVEL;
         base.experience = Self::STARTING_EXPERIENCE;
+        base.movement_type = Self::MOVEMENT_TYPE;
 
         // Define available attacks - grand knights have multiple powerful attacks
         base.attacks = vec![Self::legendary_strike(), Self::crushing_blow()];
@@ -1,8 +1,9 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
+use crate::movement_type::MovementType;
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 2 Human Knight Commander
 ///
@@ -22,6 +23,7 @@ use graphics::HexCoord;
 /// - **Movement**: 4
 /// - **Range**: Melee
 /// - **XP to Next Level**: 360 (level² × 40, moderate leveling)
+#[derive(Clone)]
 pub struct HumanKnightCommander {
     base: BaseUnit,
 }
@@ -44,19 +46,24 @@ impl HumanKnightCommander {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances - Enhanced holy protection
-    const RESISTANCE_BLUNT: u8 = 30;
-    const RESISTANCE_PIERCE: u8 = 25;
-    const RESISTANCE_FIRE: u8 = 15;
-    const RESISTANCE_DARK: u8 = 35;
-    const RESISTANCE_SLASH: u8 = 30;
-    const RESISTANCE_CRUSH: u8 = 20;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 30),
+        (DamageType::Pierce, 25),
+        (DamageType::Fire, 15),
+        (DamageType::Dark, 35),
+        (DamageType::Slash, 30),
+        (DamageType::Crush, 20),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Human;
 
+    // Movement type - knights ride into battle
+    const MOVEMENT_TYPE: MovementType = MovementType::Mounted;
+
     // Unit type identifier
     const UNIT_TYPE: &'static str = "Human Knight Commander";
 
@@ -103,14 +110,7 @@ impl HumanKnightCommander {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -130,6 +130,7 @@ impl HumanKnightCommander {
         // Set level explicitly
         base.level = Self::LEVEL;
         base.experience = Self::STARTING_EXPERIENCE;
+        base.movement_type = Self::MOVEMENT_TYPE;
 
         // Define available attacks
         base.attacks = vec![Self::commanding_strike()];
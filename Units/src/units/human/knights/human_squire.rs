@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 1 Human Squire
 ///
@@ -21,6 +21,7 @@ use graphics::HexCoord;
 /// - **Movement**: 3
 /// - **Range**: Melee
 /// - **XP to Next Level**: 100 (level² × 25, faster leveling for starter units)
+#[derive(Clone)]
 pub struct HumanSquire {
     base: BaseUnit,
 }
@@ -43,15 +44,17 @@ impl HumanSquire {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances - Higher physical resistances due to moderate armor
-    const RESISTANCE_BLUNT: u8 = 25;
-    const RESISTANCE_PIERCE: u8 = 20;
-    const RESISTANCE_FIRE: u8 = 5;
-    const RESISTANCE_DARK: u8 = 0;
-    const RESISTANCE_SLASH: u8 = 25;
-    const RESISTANCE_CRUSH: u8 = 10;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 25),
+        (DamageType::Pierce, 20),
+        (DamageType::Fire, 5),
+        (DamageType::Dark, 0),
+        (DamageType::Slash, 25),
+        (DamageType::Crush, 10),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Human;
@@ -102,14 +105,7 @@ impl HumanSquire {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
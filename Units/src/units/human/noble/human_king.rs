@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 3 Human King
 ///
@@ -22,6 +22,7 @@ use graphics::HexCoord;
 /// - **Movement**: 5
 /// - **Range**: Melee
 /// - **XP to Next Level**: N/A (max level)
+#[derive(Clone)]
 pub struct HumanKing {
     base: BaseUnit,
 }
@@ -44,15 +45,17 @@ impl HumanKing {
     const ATTACKS_PER_ROUND: u32 = 2;
 
     // Resistances
-    const RESISTANCE_BLUNT: u8 = 30;
-    const RESISTANCE_PIERCE: u8 = 30;
-    const RESISTANCE_FIRE: u8 = 10;
-    const RESISTANCE_DARK: u8 = 30;
-    const RESISTANCE_SLASH: u8 = 30;
-    const RESISTANCE_CRUSH: u8 = 20;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 30),
+        (DamageType::Pierce, 30),
+        (DamageType::Fire, 10),
+        (DamageType::Dark, 30),
+        (DamageType::Slash, 30),
+        (DamageType::Crush, 20),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Human;
@@ -111,14 +114,7 @@ impl HumanKing {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 1 Human Noble
 ///
@@ -22,6 +22,7 @@ use graphics::HexCoord;
 /// - **Movement**: 4
 /// - **Range**: Melee
 /// - **XP to Next Level**: 100 (level² × 25, faster leveling for starter units)
+#[derive(Clone)]
 pub struct HumanNoble {
     base: BaseUnit,
 }
@@ -44,15 +45,17 @@ impl HumanNoble {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances
-    const RESISTANCE_BLUNT: u8 = 20;
-    const RESISTANCE_PIERCE: u8 = 10;
-    const RESISTANCE_FIRE: u8 = 0;
-    const RESISTANCE_DARK: u8 = 10;
-    const RESISTANCE_SLASH: u8 = 10;
-    const RESISTANCE_CRUSH: u8 = 0;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 20),
+        (DamageType::Pierce, 10),
+        (DamageType::Fire, 0),
+        (DamageType::Dark, 10),
+        (DamageType::Slash, 10),
+        (DamageType::Crush, 0),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Human;
@@ -103,14 +106,7 @@ impl HumanNoble {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 3 Orc Elite Swordsman - Maximum evolution warrior unit.
 ///
@@ -21,6 +21,7 @@ use graphics::HexCoord;
 /// - **Movement**: 4 + race bonus
 /// - **Range**: Melee
 /// - **XP per Level**: 800, 1250, 1800... (level² × 50)
+#[derive(Clone)]
 pub struct OrcEliteSwordsman {
     base: BaseUnit,
 }
@@ -43,15 +44,17 @@ impl OrcEliteSwordsman {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (heavy armor - elite warrior)
-    const RESISTANCE_BLUNT: u8 = 35;
-    const RESISTANCE_PIERCE: u8 = 25;
-    const RESISTANCE_FIRE: u8 = 15;
-    const RESISTANCE_DARK: u8 = 20;
-    const RESISTANCE_SLASH: u8 = 40;
-    const RESISTANCE_CRUSH: u8 = 30;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 35),
+        (DamageType::Pierce, 25),
+        (DamageType::Fire, 15),
+        (DamageType::Dark, 20),
+        (DamageType::Slash, 40),
+        (DamageType::Crush, 30),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Orc;
@@ -115,14 +118,7 @@ impl OrcEliteSwordsman {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -134,7 +130,7 @@ impl OrcEliteSwordsman {
             Self::RACE,
             Self::UNIT_TYPE.to_string(),
             "A battle-hardened orc warrior of exceptional prowess. Elite Swordsmen are veterans of countless conflicts, wielding their blades with deadly precision. Their ferocity in combat is matched only by their tactical cunning.".to_string(),
-            graphics::SpriteType::OrcWarrior,
+            "OrcWarrior",
             Some(crate::unit_type::UnitType::OrcSwordsman),
             vec![],
             combat_stats,
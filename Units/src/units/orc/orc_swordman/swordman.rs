@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 2 Orc Swordsman - Intermediate warrior unit.
 ///
@@ -20,6 +20,7 @@ use graphics::HexCoord;
 /// - **Movement**: 4 + race bonus
 /// - **Range**: Melee
 /// - **XP to Next Level**: 450 (level² × 50)
+#[derive(Clone)]
 pub struct OrcSwordsman {
     base: BaseUnit,
 }
@@ -42,15 +43,17 @@ impl OrcSwordsman {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (medium armor - trained warrior)
-    const RESISTANCE_BLUNT: u8 = 28;
-    const RESISTANCE_PIERCE: u8 = 20;
-    const RESISTANCE_FIRE: u8 = 12;
-    const RESISTANCE_DARK: u8 = 17;
-    const RESISTANCE_SLASH: u8 = 32;
-    const RESISTANCE_CRUSH: u8 = 25;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 28),
+        (DamageType::Pierce, 20),
+        (DamageType::Fire, 12),
+        (DamageType::Dark, 17),
+        (DamageType::Slash, 32),
+        (DamageType::Crush, 25),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Orc;
@@ -104,14 +107,7 @@ impl OrcSwordsman {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -123,7 +119,7 @@ impl OrcSwordsman {
             Self::RACE,
             Self::UNIT_TYPE.to_string(),
             "A capable orc warrior with proven combat skills. Swordsmen are the backbone of orc warbands, combining raw strength with battle-tested tactics. Can evolve into Elite Swordsmen through further victories.".to_string(),
-            graphics::SpriteType::OrcWarrior,
+            "OrcWarrior",
             Some(crate::unit_type::UnitType::OrcYoungSwordsman),
             vec![crate::unit_type::UnitType::OrcEliteSwordsman],
             combat_stats,
@@ -1,8 +1,8 @@
 use crate::attack::Attack;
 use crate::base_unit::BaseUnit;
-use crate::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use crate::combat::{CombatStats, DamageType, RangeType, Resistances};
 use crate::unit_race::{Race, Terrain};
-use graphics::HexCoord;
+use hex::HexCoord;
 
 /// Level 1 Orc Swordsman - Entry level warrior unit.
 ///
@@ -20,6 +20,7 @@ use graphics::HexCoord;
 /// - **Movement**: 4 + race bonus
 /// - **Range**: Melee
 /// - **XP to Next Level**: 200 (level² × 50)
+#[derive(Clone)]
 pub struct OrcYoungSwordsman {
     base: BaseUnit,
 }
@@ -42,15 +43,17 @@ impl OrcYoungSwordsman {
     const ATTACKS_PER_ROUND: u32 = 1;
 
     // Resistances (light armor - young and inexperienced)
-    const RESISTANCE_BLUNT: u8 = 0;
-    const RESISTANCE_PIERCE: u8 = 15;
-    const RESISTANCE_FIRE: u8 = 10;
-    const RESISTANCE_DARK: u8 = 20;
-    const RESISTANCE_SLASH: u8 = 10;
-    const RESISTANCE_CRUSH: u8 = 10;
+    const RESISTANCES: &'static [(DamageType, i32)] = &[
+        (DamageType::Blunt, 0),
+        (DamageType::Pierce, 15),
+        (DamageType::Fire, 10),
+        (DamageType::Dark, 20),
+        (DamageType::Slash, 10),
+        (DamageType::Crush, 10),
+    ];
 
     // Range category
-    const RANGE_CATEGORY: RangeCategory = RangeCategory::Melee;
+    const RANGE_CATEGORY: RangeType = RangeType::Melee;
 
     // Race
     const RACE: Race = Race::Orc;
@@ -111,14 +114,7 @@ impl OrcYoungSwordsman {
             Self::BASE_ATTACK,
             Self::BASE_MOVEMENT,
             Self::RANGE_CATEGORY,
-            Resistances::new(
-                Self::RESISTANCE_BLUNT,
-                Self::RESISTANCE_PIERCE,
-                Self::RESISTANCE_FIRE,
-                Self::RESISTANCE_DARK,
-                Self::RESISTANCE_SLASH,
-                Self::RESISTANCE_CRUSH,
-            ),
+            Resistances::from_pairs(Self::RESISTANCES),
             Self::ATTACK_STRENGTH,
             Self::ATTACKS_PER_ROUND,
         );
@@ -1,17 +1,18 @@
 //! Tests for the unit abilities system
 
-use graphics::HexCoord;
-use units::combat::{CombatStats, DamageType, RangeCategory, Resistances};
+use hex::HexCoord;
+use units::combat::{CombatStats, DamageType, RangeType, Resistances};
 use units::{ability::*, Attack, BaseUnit, Race, Unit};
 
 // Test unit wrapper that implements Unit trait
+#[derive(Clone)]
 struct TestUnit {
     base: BaseUnit,
 }
 
 impl TestUnit {
     fn new() -> Self {
-        let stats = CombatStats::new(100, 10, 4, RangeCategory::Melee, Resistances::default());
+        let stats = CombatStats::new(100, 10, 4, RangeType::Melee, Resistances::default());
 
         let base = BaseUnit::new(
             "Test Unit".to_string(),
@@ -43,7 +44,7 @@ impl Unit for TestUnit {
 }
 
 fn create_test_unit() -> BaseUnit {
-    let stats = CombatStats::new(100, 10, 4, RangeCategory::Melee, Resistances::default());
+    let stats = CombatStats::new(100, 10, 4, RangeType::Melee, Resistances::default());
 
     BaseUnit::new(
         "Test Unit".to_string(),
@@ -363,6 +364,49 @@ fn test_ability_state_effects() {
     assert!(!state.active_effects.contains_key(&ability_id));
 }
 
+#[test]
+fn test_stat_modifier_sums_buffs_and_debuffs() {
+    let mut state = AbilityState::new();
+    for (effect, duration) in [
+        (
+            ActiveEffect::Buff {
+                stat: BuffStat::Attack,
+                amount: 3,
+                duration: 2,
+            },
+            2,
+        ),
+        (
+            ActiveEffect::Buff {
+                stat: BuffStat::Attack,
+                amount: 2,
+                duration: 1,
+            },
+            1,
+        ),
+        (
+            ActiveEffect::Debuff {
+                stat: BuffStat::Attack,
+                amount: 1,
+                duration: 2,
+            },
+            2,
+        ),
+    ] {
+        state.add_effect(
+            Uuid::new_v4(),
+            ActiveEffectInstance::new(effect, duration, None),
+        );
+    }
+
+    assert_eq!(state.stat_modifier(&BuffStat::Attack), 4);
+    assert_eq!(state.stat_modifier(&BuffStat::Defense), 0);
+
+    // The one-turn buff expires
+    state.tick();
+    assert_eq!(state.stat_modifier(&BuffStat::Attack), 2);
+}
+
 #[test]
 fn test_cannot_use_ability_on_cooldown() {
     let mut unit = TestUnit::new();
@@ -0,0 +1,40 @@
+use units::{Alignment, Race, UnitFactory};
+
+#[test]
+fn test_damage_bonus_follows_alignment() {
+    assert_eq!(Alignment::Lawful.damage_bonus(25), 25);
+    assert_eq!(Alignment::Chaotic.damage_bonus(25), -25);
+    assert_eq!(Alignment::Neutral.damage_bonus(25), 0);
+    assert_eq!(Alignment::Chaotic.damage_bonus(-25), 25);
+}
+
+#[test]
+fn test_scale_damage_rounds_and_keeps_at_least_one() {
+    assert_eq!(Alignment::Lawful.scale_damage(10, 25), 13);
+    assert_eq!(Alignment::Chaotic.scale_damage(10, 25), 8);
+    assert_eq!(Alignment::Neutral.scale_damage(10, -25), 10);
+    assert_eq!(Alignment::Lawful.scale_damage(1, -25), 1);
+    assert_eq!(Alignment::Lawful.scale_damage(0, 25), 0);
+}
+
+#[test]
+fn test_alignment_names_round_trip() {
+    for alignment in Alignment::ALL {
+        assert_eq!(Alignment::from_name(alignment.name()), Some(alignment));
+    }
+    assert_eq!(Alignment::from_name("Liminal"), None);
+}
+
+#[test]
+fn test_units_get_race_default_alignment() {
+    assert_eq!(Race::Undead.default_alignment(), Alignment::Chaotic);
+
+    let dwarf = UnitFactory::create("Dwarf Warrior", None, None).unwrap();
+    assert_eq!(dwarf.alignment(), Alignment::Lawful);
+
+    let orc = UnitFactory::create("Orc Young Swordsman", None, None).unwrap();
+    assert_eq!(orc.alignment(), Alignment::Chaotic);
+
+    let elf = UnitFactory::create("Elf Warrior", None, None).unwrap();
+    assert_eq!(elf.alignment(), Alignment::Neutral);
+}
@@ -1,4 +1,4 @@
-use graphics::HexCoord;
+use hex::HexCoord;
 use units::UnitFactory;
 
 #[test]
@@ -1,6 +1,6 @@
 //! Test for Human Knight Commander's leadership aura ability
 
-use graphics::HexCoord;
+use hex::HexCoord;
 use units::UnitFactory;
 
 #[test]
@@ -0,0 +1,85 @@
+use units::{MovementType, Race, TerrainFeature, TerrainRegistry, UnitFactory};
+
+fn cost(movement_type: MovementType, terrain_id: &str, features: &[TerrainFeature]) -> Option<i32> {
+    let terrain = TerrainRegistry::builtin().get(terrain_id).unwrap();
+    movement_type.movement_cost(terrain, features)
+}
+
+#[test]
+fn test_foot_pays_base_terrain_cost() {
+    for terrain in TerrainRegistry::builtin().all() {
+        let expected = if terrain.impassable {
+            None
+        } else {
+            Some(terrain.movement_cost)
+        };
+        assert_eq!(MovementType::Foot.movement_cost(terrain, &[]), expected);
+    }
+}
+
+#[test]
+fn test_racial_terrain_specialties() {
+    assert_eq!(cost(MovementType::Woodland, "forest", &[]), Some(1));
+    assert_eq!(cost(MovementType::Mountainfoot, "hills", &[]), Some(1));
+    assert_eq!(cost(MovementType::Mountainfoot, "mountain", &[]), Some(3));
+    assert_eq!(cost(MovementType::Amphibious, "swamp", &[]), Some(1));
+    assert_eq!(cost(MovementType::Undead, "haunted_woods", &[]), Some(1));
+    assert_eq!(cost(MovementType::Mounted, "forest", &[]), Some(3));
+    assert_eq!(cost(MovementType::Mounted, "mountain", &[]), None);
+    assert_eq!(cost(MovementType::Foot, "mountain", &[]), None);
+}
+
+#[test]
+fn test_flying_and_features() {
+    for terrain in TerrainRegistry::builtin().all() {
+        assert_eq!(MovementType::Flying.movement_cost(terrain, &[]), Some(1));
+    }
+
+    assert_eq!(
+        cost(MovementType::Mounted, "swamp", &[TerrainFeature::Road]),
+        Some(1)
+    );
+    assert_eq!(
+        cost(MovementType::Woodland, "forest", &[TerrainFeature::River]),
+        Some(3)
+    );
+    assert_eq!(
+        cost(
+            MovementType::Amphibious,
+            "grasslands",
+            &[TerrainFeature::River]
+        ),
+        Some(1)
+    );
+    assert_eq!(
+        cost(MovementType::Foot, "mountain", &[TerrainFeature::Road]),
+        None
+    );
+}
+
+#[test]
+fn test_movement_type_names_round_trip() {
+    for movement_type in MovementType::ALL {
+        assert_eq!(
+            MovementType::from_name(movement_type.name()),
+            Some(movement_type)
+        );
+    }
+    assert_eq!(MovementType::from_name("Swimming"), None);
+}
+
+#[test]
+fn test_units_get_race_default_or_definition_override() {
+    let elf = UnitFactory::create("Elf Warrior", None, None).unwrap();
+    assert_eq!(elf.movement_type(), Race::Elf.default_movement_type());
+    assert_eq!(elf.movement_type(), MovementType::Woodland);
+
+    let dwarf = UnitFactory::create("Dwarf Warrior", None, None).unwrap();
+    assert_eq!(dwarf.movement_type(), MovementType::Mountainfoot);
+
+    let squire = UnitFactory::create("Human Squire", None, None).unwrap();
+    assert_eq!(squire.movement_type(), MovementType::Foot);
+
+    let knight = UnitFactory::create("Human Knight", None, None).unwrap();
+    assert_eq!(knight.movement_type(), MovementType::Mounted);
+}
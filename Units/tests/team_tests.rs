@@ -0,0 +1,87 @@
+use units::{Stance, Team, TeamRoster};
+
+#[test]
+fn test_default_roster_matches_team_constants() {
+    let roster = TeamRoster::default();
+    assert_eq!(roster.find("Player"), Some(Team::PLAYER));
+    assert_eq!(roster.find("Enemy"), Some(Team::ENEMY));
+    assert_eq!(roster.find("Neutral"), Some(Team::NEUTRAL));
+    assert_eq!(
+        roster.turn_order(),
+        &[Team::PLAYER, Team::ENEMY, Team::NEUTRAL]
+    );
+    assert!(roster.get(Team::PLAYER).unwrap().player_controlled);
+    assert!(!roster.get(Team::ENEMY).unwrap().player_controlled);
+}
+
+#[test]
+fn test_default_roster_keeps_neutral_out_of_fights() {
+    let roster = TeamRoster::default();
+    assert!(roster.are_hostile(Team::PLAYER, Team::ENEMY));
+    assert!(!roster.are_hostile(Team::PLAYER, Team::NEUTRAL));
+    assert!(!roster.are_hostile(Team::ENEMY, Team::NEUTRAL));
+    assert!(!roster.are_allied(Team::PLAYER, Team::NEUTRAL));
+}
+
+#[test]
+fn test_stances_are_symmetric_and_default_to_hostile() {
+    let mut roster = TeamRoster::new();
+    let a = roster.add_team("A", [1.0, 0.0, 0.0]).unwrap();
+    let b = roster.add_team("B", [0.0, 1.0, 0.0]).unwrap();
+    let c = roster.add_team("C", [0.0, 0.0, 1.0]).unwrap();
+
+    assert_eq!(roster.stance(a, b), Stance::Hostile);
+    roster.set_stance(b, a, Stance::Allied);
+    assert!(roster.are_allied(a, b));
+    assert!(roster.are_allied(b, a));
+    assert!(roster.are_hostile(a, c));
+    assert!(roster.are_hostile(c, b));
+}
+
+#[test]
+fn test_team_is_always_allied_with_itself() {
+    let mut roster = TeamRoster::new();
+    let a = roster.add_team("A", [1.0, 0.0, 0.0]).unwrap();
+    roster.set_stance(a, a, Stance::Hostile);
+    assert!(roster.are_allied(a, a));
+}
+
+#[test]
+fn test_turn_order_validation() {
+    let mut roster = TeamRoster::new();
+    let a = roster.add_team("A", [1.0, 0.0, 0.0]).unwrap();
+    let b = roster.add_team("B", [0.0, 1.0, 0.0]).unwrap();
+
+    assert!(roster.set_turn_order(vec![]).is_err());
+    assert!(roster.set_turn_order(vec![a, a]).is_err());
+    assert!(roster.set_turn_order(vec![a, Team::new(7)]).is_err());
+    assert_eq!(roster.turn_order(), &[a, b]);
+
+    roster.set_turn_order(vec![b, a]).unwrap();
+    assert_eq!(roster.turn_order(), &[b, a]);
+}
+
+#[test]
+fn test_names_and_colors_fall_back_for_unknown_teams() {
+    let mut roster = TeamRoster::new();
+    let color = roster.next_default_color();
+    let a = roster.add_team("A", color).unwrap();
+    assert_eq!(roster.color(a), TeamRoster::DEFAULT_COLORS[0]);
+    assert_eq!(roster.name(a), "A");
+    assert_eq!(roster.name(Team::new(9)), "Unknown");
+    assert_eq!(roster.color(Team::new(9)), [1.0, 1.0, 1.0]);
+    assert_eq!(Stance::from_name("Allied"), Some(Stance::Allied));
+    assert_eq!(Stance::from_name("Friendly"), None);
+}
+
+#[test]
+fn test_roster_refuses_teams_beyond_the_limit() {
+    let mut roster = TeamRoster::new();
+    for i in 0..TeamRoster::MAX_TEAMS {
+        roster
+            .add_team(format!("Team {}", i), [1.0, 1.0, 1.0])
+            .unwrap();
+    }
+    assert!(roster.add_team("One too many", [1.0, 1.0, 1.0]).is_err());
+    assert_eq!(roster.len(), TeamRoster::MAX_TEAMS);
+}
@@ -0,0 +1,82 @@
+use units::{Terrain, TerrainDefinition, TerrainFeature, TerrainRegistry};
+
+#[test]
+fn test_builtin_terrains_cover_all_terrain_sprites() {
+    let registry = TerrainRegistry::builtin();
+    let sprites = [
+        "Forest",
+        "Forest2",
+        "Grasslands",
+        "HauntedWoods",
+        "Hills",
+        "Mountain",
+        "Swamp",
+    ];
+    for sprite in sprites {
+        let terrain = registry
+            .for_sprite(sprite)
+            .unwrap_or_else(|| panic!("no terrain for {}", sprite));
+        assert_eq!(terrain.sprite, sprite);
+    }
+    assert!(registry.for_sprite("DwarfWarrior").is_none());
+}
+
+#[test]
+fn test_forest_sprites_have_distinct_defense_classes() {
+    let registry = TerrainRegistry::builtin();
+    let forest = registry.for_sprite("Forest").unwrap();
+    let dense = registry.for_sprite("Forest2").unwrap();
+
+    assert_eq!(forest.defense_class, Terrain::Forest0);
+    assert_eq!(dense.defense_class, Terrain::Forest1);
+    assert_ne!(forest.id, dense.id);
+}
+
+#[test]
+fn test_terrain_properties() {
+    let registry = TerrainRegistry::builtin();
+    let mountain = registry.get("mountain").unwrap();
+    assert!(mountain.impassable);
+    assert!(mountain.blocks_line_of_sight);
+    assert!(!mountain.buildable);
+
+    let grasslands = registry.default_terrain().unwrap();
+    assert_eq!(grasslands.id, "grasslands");
+    assert_eq!(grasslands.movement_cost, 1);
+    assert!(!grasslands.blocks_line_of_sight);
+    assert!(grasslands.buildable);
+}
+
+#[test]
+fn test_features_modify_movement_and_building() {
+    let swamp = TerrainRegistry::builtin().get("swamp").unwrap();
+    let grasslands = TerrainRegistry::builtin().get("grasslands").unwrap();
+
+    assert_eq!(swamp.movement_cost_with(&[TerrainFeature::Road]), 1);
+    assert_eq!(grasslands.movement_cost_with(&[TerrainFeature::River]), 3);
+    assert_eq!(
+        grasslands.movement_cost_with(&[TerrainFeature::River, TerrainFeature::Bridge]),
+        1
+    );
+    assert!(grasslands.buildable_with(&[TerrainFeature::Road]));
+    assert!(!grasslands.buildable_with(&[TerrainFeature::Village]));
+    assert_eq!(
+        TerrainFeature::from_name("Village"),
+        Some(TerrainFeature::Village)
+    );
+    assert_eq!(TerrainFeature::from_name("Lava"), None);
+}
+
+#[test]
+fn test_register_custom_terrain() {
+    let mut registry = TerrainRegistry::new();
+    let count = registry.len();
+    registry.register(
+        TerrainDefinition::new("tundra", "Tundra", 2, Terrain::Hills, "Hills")
+            .with_buildable(false),
+    );
+
+    assert_eq!(registry.len(), count + 1);
+    assert_eq!(registry.get("tundra").unwrap().name, "Tundra");
+    assert!(TerrainRegistry::empty().is_empty());
+}
@@ -2,7 +2,7 @@
 // Each unit type implements Unit trait with base(), base_mut(), and attacks() methods
 
 use ::units::*;
-use graphics::HexCoord;
+use hex::HexCoord;
 
 // ===== New Trait Pattern Tests =====
 
@@ -49,3 +49,106 @@ fn test_goblin_units() {
     // Chief should be stronger than grunt
     assert!(chief.combat_stats().max_health > grunt.combat_stats().max_health);
 }
+
+#[test]
+fn test_equip_respects_item_requirements() {
+    let registry = items::ItemRegistry::builtin();
+    let mut young = UnitFactory::create_dwarf_young_warrior("Y".to_string(), HexCoord::new(0, 0));
+    let mut warrior = UnitFactory::create_dwarf_warrior("W".to_string(), HexCoord::new(0, 0));
+    let mut elf = UnitFactory::create("Elf Warrior", None, None).unwrap();
+
+    // War hammers are for dwarves of level 2 and up
+    for unit in [&mut young, &mut warrior, &mut elf] {
+        let hammer = registry.create("war_hammer").unwrap();
+        let hammer_id = hammer.id;
+        unit.add_item_to_inventory(hammer);
+        let result = unit.equip_item(hammer_id);
+        assert_eq!(
+            result.is_ok(),
+            unit.level() >= 2 && unit.race() == Race::Dwarf,
+            "{}: {:?}",
+            unit.unit_type(),
+            result
+        );
+    }
+
+    // A rejected item stays in the inventory
+    assert_eq!(young.inventory().len(), 1);
+    assert!(warrior.inventory().is_empty());
+    assert!(warrior.equipment().weapon.is_some());
+}
+
+#[test]
+fn test_equip_and_unequip_restore_stats() {
+    let registry = items::ItemRegistry::builtin();
+    let mut unit = UnitFactory::create_dwarf_warrior("W".to_string(), HexCoord::new(0, 0));
+    let health = unit.combat_stats().max_health;
+    let movement = unit.combat_stats().movement_speed;
+
+    let ring = registry.create("ring_of_vigor").unwrap();
+    let boots = registry.create("boots_of_speed").unwrap();
+    let (ring_id, boots_id) = (ring.id, boots.id);
+    unit.add_item_to_inventory(ring);
+    unit.add_item_to_inventory(boots);
+
+    unit.equip_item(ring_id).unwrap();
+    unit.equip_item(boots_id).unwrap();
+    assert_eq!(unit.combat_stats().max_health, health + 5);
+    assert_eq!(unit.combat_stats().movement_speed, movement + 1);
+
+    // Bonuses do not stack up across repeated changes
+    unit.unequip_item(ring_id).unwrap();
+    unit.equip_item(ring_id).unwrap();
+    assert_eq!(unit.combat_stats().max_health, health + 5);
+
+    unit.unequip_item(ring_id).unwrap();
+    unit.unequip_item(boots_id).unwrap();
+    assert_eq!(unit.combat_stats().max_health, health);
+    assert_eq!(unit.combat_stats().movement_speed, movement);
+    assert_eq!(unit.combat_stats().health, health);
+}
+
+#[test]
+fn test_resistances_and_weaknesses() {
+    use ::units::combat::{DamageType, Resistances};
+
+    let mut resistances =
+        Resistances::from_pairs(&[(DamageType::Slash, 30), (DamageType::Fire, -25)]);
+    assert_eq!(resistances.get_resistance(DamageType::Slash), 30);
+    assert_eq!(resistances.get_resistance(DamageType::Lightning), 0);
+    assert_eq!(resistances.weaknesses(), vec![DamageType::Fire]);
+    assert!((resistances.damage_multiplier(DamageType::Fire) - 1.25).abs() < f32::EPSILON);
+
+    // Bonuses are added and removed per type, clamped to the valid range
+    let bonus = Resistances::new()
+        .with(DamageType::Slash, 90)
+        .with(DamageType::Fire, 25);
+    resistances.add(&bonus);
+    assert_eq!(resistances.get_resistance(DamageType::Slash), 100);
+    assert_eq!(resistances.get_resistance(DamageType::Fire), 0);
+    assert!(resistances.weaknesses().is_empty());
+    resistances.subtract(&bonus);
+    assert_eq!(resistances.get_resistance(DamageType::Slash), 10);
+    assert_eq!(resistances.get_resistance(DamageType::Fire), -25);
+
+    // Every registered damage type is listed
+    assert_eq!(resistances.iter().count(), DamageType::all().count());
+}
+
+#[test]
+fn test_weapon_attack_keeps_item_damage_type() {
+    use ::units::combat::DamageType;
+
+    let registry = items::ItemRegistry::builtin();
+    let mut unit = UnitFactory::create_dwarf_warrior("W".to_string(), HexCoord::new(0, 0));
+    unit.base_mut()
+        .equipment
+        .equip_item(registry.create("holy_lance").unwrap());
+
+    let charge = unit
+        .get_attacks()
+        .into_iter()
+        .find(|attack| attack.name == "Charge")
+        .unwrap();
+    assert_eq!(charge.damage_type, DamageType::Holy);
+}
@@ -11,8 +11,8 @@ fn test_position() {
     let unit = UnitFactory::create(
         "Elf Archer",
         Some("Legolas".to_string()),
-        Some(graphics::HexCoord::new(2, 3)),
+        Some(hex::HexCoord::new(2, 3)),
     )
     .expect("Failed to create unit");
-    assert_eq!(unit.position(), graphics::HexCoord::new(2, 3));
+    assert_eq!(unit.position(), hex::HexCoord::new(2, 3));
 }
@@ -33,17 +33,16 @@
 //! **Purpose:** Isolated combat calculation engine
 //!
 //! **Responsibilities:**
-//! - Turn-based combat resolution
-//! - Damage calculation with resistances
-//! - Hit chance rolls
-//! - Multi-attack sequencing
-//! - Combat result reporting
+//! - Exchange forecasts and rolls
+//! - Multi-strike sequencing
+//! - Outcome distributions (kill and death chances)
 //!
 //! **Key Types:**
 //! - `CombatStats`: All combat-related numbers
-//! - `DamageType`: Type of damage (6 variants)
-//! - `Resistances`: Damage reduction values
-//! - `resolve_combat()`: Main algorithm
+//! - `DamageType`: Type of damage, from the shared damage type registry
+//! - `Resistances`: Damage reduction per damage type (negative for weaknesses)
+//! - `ForecastSide`: One combatant's health, damage per hit, hit chance and strikes
+//! - `forecast()` / `roll_exchange()`: Outcome distribution and rolled result of an exchange
 //!
 //! ## Units Crate: Unit System
 //!
@@ -116,11 +115,8 @@
 //!
 //! ### 3. Combat Resolution (Combat Crate)
 //! ```text
-//! let result = resolve_combat(
-//!     &mut attacker_stats,
-//!     &mut defender_stats,
-//!     damage_type
-//! );
+//! let (attacker_hits, defender_hits) =
+//!     roll_exchange(&attacker_side, &defender_side, |chance| rng.gen_range(0..100) < chance);
 //! ```
 //!
 //! ### 4. Visual Update (Graphics Crate)
@@ -155,14 +151,14 @@
 //! ```text
 //! impl GameWorld {
 //!     fn initiate_combat(&mut self, attacker_id: Uuid, defender_id: Uuid) {
-//!         let mut attacker_stats = /* get from unit */;
-//!         let mut defender_stats = /* get from unit */;
+//!         // Hit chances and damage per hit come from the world
+//!         let (attacker_side, defender_side) = self.exchange_sides(attacker_id, defender_id, attack)?;
 //!         
-//!         // Combat crate resolves
-//!         let result = resolve_combat(&mut attacker_stats, &mut defender_stats, damage_type);
+//!         // Combat crate rolls the strikes
+//!         let (attacker_hits, defender_hits) = roll_exchange(&attacker_side, &defender_side, roll);
 //!         
-//!         // Game handles result
-//!         self.apply_combat_result(result);
+//!         // Game applies the damage
+//!         self.apply_strikes(attacker_hits, defender_hits);
 //!     }
 //! }
 //! ```
@@ -340,11 +336,11 @@
 //!
 //! ```text
 //! // Combat crate: Pure calculation
-//! pub fn resolve_combat(
-//!     attacker: &mut CombatStats,
-//!     defender: &mut CombatStats,
-//!     damage_type: DamageType,
-//! ) -> CombatResult {
+//! pub fn roll_exchange(
+//!     attacker: &ForecastSide,
+//!     defender: &ForecastSide,
+//!     roll: impl FnMut(u8) -> bool,
+//! ) -> (u32, u32) {
 //!     // Only numbers, no game state
 //! }
 //!
@@ -414,60 +410,52 @@
 //!
 //! ## Adding New Damage Types
 //!
+//! Damage types live in one registry in `items::damage_type`, shared by items,
+//! units, abilities and structures.
+//!
 //! 1. **Extend enum:**
 //! ```text
 //! pub enum DamageType {
 //!     // ... existing types
-//!     Lightning,
-//!     Poison,
+//!     Acid,
 //! }
 //! ```
 //!
-//! 2. **Update Resistances:**
+//! 2. **Add a registry entry** in the same position:
 //! ```text
-//! pub struct Resistances {
-//!     // ... existing
-//!     pub lightning: u8,
-//!     pub poison: u8,
-//! }
+//! DamageTypeInfo {
+//!     damage_type: DamageType::Acid,
+//!     name: "Acid",
+//!     category: DamageCategory::Elemental,
+//!     description: "Corrosive liquids that eat through armor",
+//! },
 //! ```
 //!
-//! 3. **Update methods:**
+//! 3. **Give units resistances** where needed (negative for weaknesses):
 //! ```text
-//! impl Resistances {
-//!     pub fn get_resistance(&self, damage_type: DamageType) -> u8 {
-//!         match damage_type {
-//!             // ... existing cases
-//!             DamageType::Lightning => self.lightning,
-//!             DamageType::Poison => self.poison,
-//!         }
-//!     }
-//! }
+//! const RESISTANCES: &'static [(DamageType, i32)] = &[(DamageType::Acid, -25)];
 //! ```
 //!
+//! `Resistances`, the encyclopedia and the unit info panel list every
+//! registered type, so nothing else needs updating.
+//!
 //! ## Adding New Terrain Types
 //!
-//! 1. **Extend SpriteType:**
+//! 1. **Extend SpriteType** (Graphics crate):
 //! ```text
 //! pub enum SpriteType {
 //!     // ... existing
 //!     Lava,
-//!     Ice,
 //! }
 //! ```
+//! Add the variant to `SpriteType::ALL` and give it a name in `SpriteType::name`.
 //!
-//! 2. **Update movement costs:**
+//! 2. **Register the terrain** (Units crate), naming its sprite:
 //! ```text
-//! impl TerrainTile {
-//!     pub fn new(position: HexCoord, sprite_type: SpriteType) -> Self {
-//!         let movement_cost = match sprite_type {
-//!             // ... existing
-//!             SpriteType::Lava => 5.0,
-//!             SpriteType::Ice => 0.5,
-//!         };
-//!         // ...
-//!     }
-//! }
+//! registry.register(
+//!     TerrainDefinition::new("lava", "Lava", 5, Terrain::Mountains, "Lava")
+//!         .with_buildable(false),
+//! );
 //! ```
 //!
 //! 3. **Add textures:**
@@ -173,12 +173,9 @@
 //!
 //! ### 4. Combat Resolution ([`combat`])
 //! ```text
-//! // Isolated combat calculation
-//! let result = resolve_combat(
-//!     &mut attacker_stats,
-//!     &mut defender_stats,
-//!     damage_type
-//! );
+//! // Isolated combat calculation on both sides' hit chances and damage
+//! let (attacker_hits, defender_hits) =
+//!     roll_exchange(&attacker_side, &defender_side, |chance| rng.gen_range(0..100) < chance);
 //! ```
 //!
 //! ### 5. State Update & Rendering
@@ -308,15 +305,12 @@
 //! ### Resolving Combat
 //!
 //! ```text
-//! use combat::{resolve_combat, DamageType};
-//!
-//! let result = resolve_combat(
-//!     &mut attacker.combat_stats,
-//!     &mut defender.combat_stats,
-//!     DamageType::Slash
-//! );
+//! // Roll the exchange with the selected attack; events report every strike
+//! world.execute_pending_combat()?;
 //!
-//! println!("Damage dealt: {}", result.attacker_damage_dealt);
+//! for event in world.drain_events() {
+//!     println!("{:?}", event);
+//! }
 //! ```
 //!
 //! ## Running the Application